@@ -0,0 +1,150 @@
+#![no_std]
+#![no_main]
+
+use bsp::entry;
+use bsp::hal;
+use defmt::*;
+use defmt_rtt as _;
+use embedded_hal::digital::v2::*;
+use embedded_hal::prelude::*;
+use fugit::ExtU32;
+use hal::pac;
+use panic_probe as _;
+#[allow(clippy::wildcard_imports)]
+use usb_device::class_prelude::*;
+use usb_device::prelude::*;
+use usbd_human_interface_device::device::gamepad::{
+    DualReportGamepadConfig, GamepadState, HatSwitch,
+};
+use usbd_human_interface_device::prelude::*;
+
+use rp_pico as bsp;
+
+#[entry]
+fn main() -> ! {
+    let mut pac = pac::Peripherals::take().unwrap();
+
+    let mut watchdog = hal::Watchdog::new(pac.WATCHDOG);
+    let clocks = hal::clocks::init_clocks_and_plls(
+        bsp::XOSC_CRYSTAL_FREQ,
+        pac.XOSC,
+        pac.CLOCKS,
+        pac.PLL_SYS,
+        pac.PLL_USB,
+        &mut pac.RESETS,
+        &mut watchdog,
+    )
+    .ok()
+    .unwrap();
+
+    let timer = hal::Timer::new(pac.TIMER, &mut pac.RESETS);
+
+    let sio = hal::Sio::new(pac.SIO);
+    let pins = hal::gpio::Pins::new(
+        pac.IO_BANK0,
+        pac.PADS_BANK0,
+        sio.gpio_bank0,
+        &mut pac.RESETS,
+    );
+
+    info!("Starting");
+
+    //USB
+    let usb_bus = UsbBusAllocator::new(hal::usb::UsbBus::new(
+        pac.USBCTRL_REGS,
+        pac.USBCTRL_DPRAM,
+        clocks.usb_clock,
+        true,
+        &mut pac.RESETS,
+    ));
+
+    let mut gamepad = UsbHidClassBuilder::new()
+        .add_device(DualReportGamepadConfig::default())
+        .build(&usb_bus);
+
+    //https://pid.codes
+    let mut usb_dev = UsbDeviceBuilder::new(&usb_bus, UsbVidPid(0x1209, 0x0001))
+        .manufacturer("usbd-human-interface-device")
+        .product("Rusty gamepad")
+        .serial_number("TEST")
+        .build();
+
+    //GPIO pins
+    let mut led_pin = pins.gpio13.into_push_pull_output();
+
+    let input_pins: [&dyn InputPin<Error = core::convert::Infallible>; 12] = [
+        &pins.gpio0.into_pull_up_input(),
+        &pins.gpio1.into_pull_up_input(),
+        &pins.gpio2.into_pull_up_input(),
+        &pins.gpio3.into_pull_up_input(),
+        &pins.gpio4.into_pull_up_input(),
+        &pins.gpio5.into_pull_up_input(),
+        &pins.gpio6.into_pull_up_input(),
+        &pins.gpio7.into_pull_up_input(),
+        &pins.gpio8.into_pull_up_input(),
+        &pins.gpio9.into_pull_up_input(),
+        &pins.gpio10.into_pull_up_input(),
+        &pins.gpio11.into_pull_up_input(),
+    ];
+
+    led_pin.set_low().ok();
+
+    let mut input_count_down = timer.count_down();
+    input_count_down.start(10.millis());
+
+    let mut tick_count_down = timer.count_down();
+    tick_count_down.start(1.millis());
+
+    loop {
+        // Poll every 10ms
+        if input_count_down.wait().is_ok() {
+            match gamepad.device().write_report(&get_state(&input_pins)) {
+                Err(UsbHidError::WouldBlock) => {}
+                Ok(_) => {}
+                Err(e) => {
+                    core::panic!("Failed to write gamepad report: {:?}", e)
+                }
+            }
+        }
+
+        //Tick once per ms, sends the second report layout if the endpoint was busy
+        if tick_count_down.wait().is_ok() {
+            match gamepad.tick() {
+                Err(UsbHidError::WouldBlock) => {}
+                Ok(_) => {}
+                Err(e) => {
+                    core::panic!("Failed to process gamepad tick: {:?}", e)
+                }
+            };
+        }
+
+        if usb_dev.poll(&mut [&mut gamepad]) {}
+    }
+}
+
+fn get_state(pins: &[&dyn InputPin<Error = core::convert::Infallible>; 12]) -> GamepadState {
+    // Read out 8 buttons first
+    let mut buttons = 0;
+    for (idx, &pin) in pins[..8].iter().enumerate() {
+        if pin.is_low().unwrap() {
+            buttons |= 1 << idx;
+        }
+    }
+
+    // We're using digital switches as a D-PAD
+    //    10
+    //  8    9
+    //    11
+    let hat = HatSwitch::from_buttons(
+        pins[10].is_low().unwrap(),
+        pins[9].is_low().unwrap(),
+        pins[11].is_low().unwrap(),
+        pins[8].is_low().unwrap(),
+    );
+
+    GamepadState {
+        buttons,
+        hat,
+        ..Default::default()
+    }
+}
@@ -0,0 +1,97 @@
+#![no_std]
+#![no_main]
+
+use bsp::entry;
+use bsp::hal;
+use defmt::*;
+use defmt_rtt as _;
+use embedded_hal::digital::v2::*;
+use hal::pac;
+use panic_probe as _;
+#[allow(clippy::wildcard_imports)]
+use usb_device::class_prelude::*;
+use usb_device::prelude::*;
+use usbd_human_interface_device::device::raw::RawHidConfig;
+use usbd_human_interface_device::prelude::*;
+
+use rp_pico as bsp;
+
+#[entry]
+fn main() -> ! {
+    let mut pac = pac::Peripherals::take().unwrap();
+
+    let mut watchdog = hal::Watchdog::new(pac.WATCHDOG);
+    let clocks = hal::clocks::init_clocks_and_plls(
+        bsp::XOSC_CRYSTAL_FREQ,
+        pac.XOSC,
+        pac.CLOCKS,
+        pac.PLL_SYS,
+        pac.PLL_USB,
+        &mut pac.RESETS,
+        &mut watchdog,
+    )
+    .ok()
+    .unwrap();
+
+    let sio = hal::Sio::new(pac.SIO);
+    let pins = hal::gpio::Pins::new(
+        pac.IO_BANK0,
+        pac.PADS_BANK0,
+        sio.gpio_bank0,
+        &mut pac.RESETS,
+    );
+
+    info!("Starting");
+
+    //USB
+    let usb_bus = UsbBusAllocator::new(hal::usb::UsbBus::new(
+        pac.USBCTRL_REGS,
+        pac.USBCTRL_DPRAM,
+        clocks.usb_clock,
+        true,
+        &mut pac.RESETS,
+    ));
+
+    let mut raw_hid = UsbHidClassBuilder::new()
+        .add_device(RawHidConfig::default())
+        .build(&usb_bus);
+
+    //https://pid.codes
+    let mut usb_dev = UsbDeviceBuilder::new(&usb_bus, UsbVidPid(0x1209, 0x0001))
+        .manufacturer("usbd-human-interface-device")
+        .product("Raw HID echo")
+        .serial_number("TEST")
+        .build();
+
+    //GPIO pins
+    let mut led_pin = pins.gpio13.into_push_pull_output();
+    led_pin.set_low().ok();
+
+    // Report received from the host, waiting to be echoed back
+    let mut pending = None;
+
+    loop {
+        if usb_dev.poll(&mut [&mut raw_hid]) && pending.is_none() {
+            match raw_hid.device().read_report() {
+                Err(UsbError::WouldBlock) => {}
+                Err(e) => {
+                    core::panic!("Failed to read raw report: {:?}", e)
+                }
+                Ok(report) => {
+                    led_pin.toggle().ok();
+                    pending = Some(report);
+                }
+            }
+        }
+
+        if let Some(report) = &pending {
+            match raw_hid.device().write_report(report) {
+                Err(UsbHidError::WouldBlock) => {}
+                Ok(_) => pending = None,
+                Err(e) => {
+                    core::panic!("Failed to write raw report: {:?}", e)
+                }
+            }
+        }
+    }
+}
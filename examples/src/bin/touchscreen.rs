@@ -0,0 +1,147 @@
+#![no_std]
+#![no_main]
+
+use bsp::entry;
+use bsp::hal;
+use defmt::*;
+use defmt_rtt as _;
+use embedded_hal::digital::v2::*;
+use embedded_hal::prelude::*;
+use fugit::ExtU32;
+use hal::pac;
+use panic_probe as _;
+#[allow(clippy::wildcard_imports)]
+use usb_device::class_prelude::*;
+use usb_device::prelude::*;
+use usbd_human_interface_device::device::touchscreen::{
+    TouchReport, TouchscreenConfig, TouchscreenMode, MAX_COORDINATE,
+};
+use usbd_human_interface_device::prelude::*;
+
+use rp_pico as bsp;
+
+#[entry]
+fn main() -> ! {
+    let mut pac = pac::Peripherals::take().unwrap();
+
+    let mut watchdog = hal::Watchdog::new(pac.WATCHDOG);
+    let clocks = hal::clocks::init_clocks_and_plls(
+        bsp::XOSC_CRYSTAL_FREQ,
+        pac.XOSC,
+        pac.CLOCKS,
+        pac.PLL_SYS,
+        pac.PLL_USB,
+        &mut pac.RESETS,
+        &mut watchdog,
+    )
+    .ok()
+    .unwrap();
+
+    let timer = hal::Timer::new(pac.TIMER, &mut pac.RESETS);
+
+    let sio = hal::Sio::new(pac.SIO);
+    let pins = hal::gpio::Pins::new(
+        pac.IO_BANK0,
+        pac.PADS_BANK0,
+        sio.gpio_bank0,
+        &mut pac.RESETS,
+    );
+
+    info!("Starting");
+
+    //USB
+    let usb_bus = UsbBusAllocator::new(hal::usb::UsbBus::new(
+        pac.USBCTRL_REGS,
+        pac.USBCTRL_DPRAM,
+        clocks.usb_clock,
+        true,
+        &mut pac.RESETS,
+    ));
+
+    let mut touchscreen = UsbHidClassBuilder::new()
+        .add_device(TouchscreenConfig::default())
+        .build(&usb_bus);
+
+    //https://pid.codes
+    let mut usb_dev = UsbDeviceBuilder::new(&usb_bus, UsbVidPid(0x1209, 0x0001))
+        .manufacturer("usbd-human-interface-device")
+        .product("Touchscreen simulator")
+        .serial_number("TEST")
+        .build();
+
+    //GPIO pins
+    let mut led_pin = pins.gpio13.into_push_pull_output();
+
+    // Hold to touch the screen, the contact traces a square around the middle of the screen
+    let touch_pin = pins.gpio0.into_pull_up_input();
+    // Switch between the digitizer and mouse compatible collections
+    let mode_pin = pins.gpio1.into_pull_up_input();
+
+    led_pin.set_low().ok();
+
+    let mut input_count_down = timer.count_down();
+    input_count_down.start(10.millis());
+
+    let mut step: u16 = 0;
+    let mut mode_pressed = false;
+
+    loop {
+        // Poll every 10ms
+        if input_count_down.wait().is_ok() {
+            let pressed = mode_pin.is_low().unwrap();
+            if pressed && !mode_pressed {
+                let mode = match touchscreen.device().mode() {
+                    TouchscreenMode::Digitizer => TouchscreenMode::Mouse,
+                    TouchscreenMode::Mouse => TouchscreenMode::Digitizer,
+                };
+                match touchscreen.device().set_mode(mode) {
+                    Err(UsbHidError::WouldBlock) => {}
+                    Ok(_) => {
+                        led_pin
+                            .set_state(PinState::from(mode == TouchscreenMode::Mouse))
+                            .ok();
+                    }
+                    Err(e) => {
+                        core::panic!("Failed to change touchscreen mode: {:?}", e)
+                    }
+                }
+            }
+            mode_pressed = pressed;
+
+            let touching = touch_pin.is_low().unwrap();
+            if touching {
+                step = (step + 1) % 400;
+            }
+            let (x, y) = square(step);
+
+            let report = TouchReport {
+                tip_switch: touching,
+                in_range: touching,
+                x,
+                y,
+            };
+            match touchscreen.device().write_report(&report) {
+                Err(UsbHidError::WouldBlock) => {}
+                Ok(_) => {}
+                Err(e) => {
+                    core::panic!("Failed to write touchscreen report: {:?}", e)
+                }
+            }
+        }
+
+        if usb_dev.poll(&mut [&mut touchscreen]) {}
+    }
+}
+
+/// Position along a square path around the middle of the screen, `step` from 0 to 399
+fn square(step: u16) -> (u16, u16) {
+    const MIN: u16 = MAX_COORDINATE / 4;
+    const SIDE: u16 = MAX_COORDINATE / 2;
+    let offset = (step % 100) * SIDE / 100;
+    match step / 100 {
+        0 => (MIN + offset, MIN),
+        1 => (MIN + SIDE, MIN + offset),
+        2 => (MIN + SIDE - offset, MIN + SIDE),
+        _ => (MIN, MIN + SIDE - offset),
+    }
+}
@@ -0,0 +1,417 @@
+//! Capability discovery for composite devices
+//!
+//! [`UsbHidClass::write_capabilities`](crate::usb_class::UsbHidClass::write_capabilities)
+//! summarizes the report descriptor of each interface of a device into a [`tlv`](crate::tlv)
+//! payload, which firmware such as the Xous HID server can hand to clients so they can discover
+//! which interfaces, reports and top level usages the device currently offers.
+//!
+//! The payload has schema version [`CAPABILITY_VERSION`] and is a sequence of records, in
+//! interface order. Each interface starts with a [`tag::INTERFACE`] record, and the records that
+//! follow, up to the next [`tag::INTERFACE`], describe that interface:
+//!
+//! | Tag                    | Value                                                        |
+//! |------------------------|--------------------------------------------------------------|
+//! | [`tag::INTERFACE`]     | Interface number, `u8`                                       |
+//! | [`tag::APPLICATION`]   | Top level collection usage page and usage, `u16` LE each     |
+//! | [`tag::REPORT`]        | Report ID (0 if none), [`ReportKind`] and length in bytes, `u16` LE |
+//!
+//! Report lengths exclude the report ID byte. Records are decoded with [`Capability::from_tlv`]:
+//!
+//! ```rust
+//! # use xous_usb_hid::capability::*;
+//! # use xous_usb_hid::tlv::TlvReader;
+//! # let payload = [CAPABILITY_VERSION, tag::INTERFACE, 1, 0];
+//! for capability in TlvReader::new(&payload).unwrap().filter_map(|t| Capability::from_tlv(&t)) {
+//!     match capability {
+//!         Capability::Interface(n) => { /* new interface */ }
+//!         Capability::Application(usage) => { /* e.g. Desktop/Keyboard */ }
+//!         Capability::Report(report) => { /* report ID and length */ }
+//!     }
+//! }
+//! ```
+
+use crate::tlv::{Tlv, TlvWriter};
+use crate::UsbHidError;
+use heapless::Vec;
+
+pub use crate::protocol::capability::VERSION as CAPABILITY_VERSION;
+
+/// Most top level collections summarized per interface
+pub const MAX_APPLICATIONS: usize = 4;
+
+/// Most distinct reports summarized per interface
+pub const MAX_REPORTS: usize = 16;
+
+/// Record types of the capability payload
+pub mod tag {
+    pub use crate::protocol::capability::{
+        TAG_APPLICATION as APPLICATION, TAG_INTERFACE as INTERFACE, TAG_REPORT as REPORT,
+    };
+}
+
+/// Direction of a report
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+#[repr(u8)]
+pub enum ReportKind {
+    Input = 1,
+    Output = 2,
+    Feature = 3,
+}
+
+impl ReportKind {
+    fn from_u8(kind: u8) -> Option<Self> {
+        match kind {
+            1 => Some(Self::Input),
+            2 => Some(Self::Output),
+            3 => Some(Self::Feature),
+            _ => None,
+        }
+    }
+}
+
+/// Usage of a top level (application) collection
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct ApplicationUsage {
+    pub usage_page: u16,
+    pub usage: u16,
+}
+
+/// A report declared by a report descriptor
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct ReportCapability {
+    /// Report ID, 0 if the descriptor doesn't use report IDs
+    pub id: u8,
+    pub kind: ReportKind,
+    /// Length in bytes, excluding the report ID
+    pub len: u16,
+}
+
+/// A decoded capability record
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum Capability {
+    Interface(u8),
+    Application(ApplicationUsage),
+    Report(ReportCapability),
+}
+
+impl Capability {
+    /// Decode a record, `None` for unknown or malformed records
+    #[must_use]
+    pub fn from_tlv(tlv: &Tlv<'_>) -> Option<Self> {
+        match (tlv.tag, tlv.value) {
+            (tag::INTERFACE, &[n]) => Some(Self::Interface(n)),
+            (tag::APPLICATION, &[p0, p1, u0, u1]) => Some(Self::Application(ApplicationUsage {
+                usage_page: u16::from_le_bytes([p0, p1]),
+                usage: u16::from_le_bytes([u0, u1]),
+            })),
+            (tag::REPORT, &[id, kind, l0, l1]) => Some(Self::Report(ReportCapability {
+                id,
+                kind: ReportKind::from_u8(kind)?,
+                len: u16::from_le_bytes([l0, l1]),
+            })),
+            _ => None,
+        }
+    }
+}
+
+/// Top level usages and report lengths of a report descriptor
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct DescriptorSummary {
+    applications: Vec<ApplicationUsage, MAX_APPLICATIONS>,
+    /// Report ID, kind and length in bits
+    reports: Vec<(u8, ReportKind, u32), MAX_REPORTS>,
+}
+
+impl DescriptorSummary {
+    /// Summarize a report descriptor. Push and Pop items aren't supported
+    pub fn new(descriptor: &[u8]) -> Result<Self, UsbHidError> {
+        let mut summary = Self::default();
+        let mut usage_page = 0u16;
+        let mut report_size = 0u32;
+        let mut report_count = 0u32;
+        let mut report_id = 0u8;
+        let mut usage = None;
+        let mut depth = 0usize;
+
+        let mut data = descriptor;
+        while let Some((&prefix, rest)) = data.split_first() {
+            // Long items carry no information used here
+            if prefix == 0xFE {
+                let len = usize::from(*rest.first().ok_or(UsbHidError::SerializationError)?);
+                data = rest.get(2 + len..).ok_or(UsbHidError::SerializationError)?;
+                continue;
+            }
+
+            let size = match prefix & 0x03 {
+                3 => 4,
+                n => usize::from(n),
+            };
+            let bytes = rest.get(..size).ok_or(UsbHidError::SerializationError)?;
+            data = &rest[size..];
+            let mut value = [0; 4];
+            value[..size].copy_from_slice(bytes);
+            let [b0, b1, b2, b3] = value;
+            let low = u16::from_le_bytes([b0, b1]);
+
+            match (prefix >> 2) & 0x03 {
+                // Main
+                0 => {
+                    let kind = match prefix >> 4 {
+                        0x8 => Some(ReportKind::Input),
+                        0x9 => Some(ReportKind::Output),
+                        0xB => Some(ReportKind::Feature),
+                        0xA => {
+                            if depth == 0 && b0 == 0x01 {
+                                let (page, id) = usage.unwrap_or((usage_page, 0));
+                                summary
+                                    .applications
+                                    .push(ApplicationUsage {
+                                        usage_page: page,
+                                        usage: id,
+                                    })
+                                    .map_err(|_| UsbHidError::SerializationError)?;
+                            }
+                            depth += 1;
+                            None
+                        }
+                        0xC => {
+                            depth = depth.saturating_sub(1);
+                            None
+                        }
+                        _ => None,
+                    };
+                    if let Some(kind) = kind {
+                        summary.add_bits(
+                            report_id,
+                            kind,
+                            report_size.saturating_mul(report_count),
+                        )?;
+                    }
+                    usage = None;
+                }
+                // Global
+                1 => match prefix >> 4 {
+                    0x0 => usage_page = low,
+                    0x7 => report_size = u32::from_le_bytes(value),
+                    0x8 => report_id = b0,
+                    0x9 => report_count = u32::from_le_bytes(value),
+                    0xA | 0xB => return Err(UsbHidError::SerializationError),
+                    _ => {}
+                },
+                // Local Usage, only the first usage of a collection is needed
+                2 if prefix >> 4 == 0x0 && usage.is_none() => {
+                    usage = Some(if size == 4 {
+                        (u16::from_le_bytes([b2, b3]), low)
+                    } else {
+                        (usage_page, low)
+                    });
+                }
+                _ => {}
+            }
+        }
+
+        Ok(summary)
+    }
+
+    fn add_bits(&mut self, id: u8, kind: ReportKind, bits: u32) -> Result<(), UsbHidError> {
+        if let Some(report) = self
+            .reports
+            .iter_mut()
+            .find(|(i, k, _)| *i == id && *k == kind)
+        {
+            report.2 = report.2.saturating_add(bits);
+            Ok(())
+        } else {
+            self.reports
+                .push((id, kind, bits))
+                .map_err(|_| UsbHidError::SerializationError)
+        }
+    }
+
+    /// Top level collections, in descriptor order
+    #[must_use]
+    pub fn applications(&self) -> &[ApplicationUsage] {
+        &self.applications
+    }
+
+    /// Reports, in the order they are first declared
+    pub fn reports(&self) -> impl Iterator<Item = ReportCapability> + '_ {
+        self.reports
+            .iter()
+            .map(|&(id, kind, bits)| ReportCapability {
+                id,
+                kind,
+                len: u16::try_from(bits.div_ceil(8)).unwrap_or(u16::MAX),
+            })
+    }
+
+    /// Longest report of the given kind, in bytes excluding the report ID
+    #[must_use]
+    pub fn max_len(&self, kind: ReportKind) -> u16 {
+        self.reports()
+            .filter(|r| r.kind == kind)
+            .map(|r| r.len)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Append the records for interface `interface` to a capability payload
+    pub fn write(&self, interface: u8, writer: &mut TlvWriter<'_>) -> Result<(), UsbHidError> {
+        writer.u8(tag::INTERFACE, interface)?;
+        for application in &self.applications {
+            let [p0, p1] = application.usage_page.to_le_bytes();
+            let [u0, u1] = application.usage.to_le_bytes();
+            writer.field(tag::APPLICATION, &[p0, p1, u0, u1])?;
+        }
+        for report in self.reports() {
+            let [l0, l1] = report.len.to_le_bytes();
+            writer.field(tag::REPORT, &[report.id, report.kind as u8, l0, l1])?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+    use crate::device::gamepad::autofire::{AutofireSettings, AUTOFIRE_GAMEPAD_REPORT_DESCRIPTOR};
+    use crate::device::gamepad::{GamepadReport, DUAL_REPORT_GAMEPAD_DESCRIPTOR};
+    use crate::device::headset::{HeadsetReport, HEADSET_REPORT_DESCRIPTOR, HEADSET_REPORT_ID};
+    use crate::device::keyboard::BOOT_KEYBOARD_REPORT_DESCRIPTOR;
+    use crate::device::mouse::surface::{
+        SurfaceStatus, SURFACE_MOUSE_REPORT_DESCRIPTOR, SURFACE_STATUS_REPORT_ID,
+    };
+    use crate::device::pen::{PenReport, PEN_REPORT_DESCRIPTOR};
+    use crate::device::radial_controller::{
+        RadialControllerReport, RADIAL_CONTROLLER_REPORT_DESCRIPTOR, RADIAL_CONTROLLER_REPORT_ID,
+    };
+    use crate::device::simulation::{
+        FlightControlsReport, RacingWheelReport, FLIGHT_CONTROLS_REPORT_DESCRIPTOR,
+        RACING_WHEEL_REPORT_DESCRIPTOR,
+    };
+    use std::vec::Vec;
+
+    #[test]
+    fn boot_keyboard_summary() {
+        let summary = DescriptorSummary::new(BOOT_KEYBOARD_REPORT_DESCRIPTOR).unwrap();
+        assert_eq!(
+            summary.applications(),
+            [ApplicationUsage {
+                usage_page: 0x01,
+                usage: 0x06
+            }]
+        );
+        assert_eq!(
+            summary.reports().collect::<Vec<_>>(),
+            [
+                ReportCapability {
+                    id: 0,
+                    kind: ReportKind::Input,
+                    len: 8
+                },
+                ReportCapability {
+                    id: 0,
+                    kind: ReportKind::Output,
+                    len: 1
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn report_ids_round_trip() {
+        let summary = DescriptorSummary::new(DUAL_REPORT_GAMEPAD_DESCRIPTOR).unwrap();
+        let ids: Vec<_> = summary.reports().map(|r| r.id).collect();
+        assert_eq!(ids, [1, 2]);
+
+        let mut buf = [0; 64];
+        let mut writer = TlvWriter::new(&mut buf, CAPABILITY_VERSION).unwrap();
+        summary.write(3, &mut writer).unwrap();
+        let len = writer.finish();
+
+        let decoded: Vec<_> = crate::tlv::TlvReader::new(&buf[..len])
+            .unwrap()
+            .filter_map(|t| Capability::from_tlv(&t))
+            .collect();
+        assert_eq!(decoded[0], Capability::Interface(3));
+        assert_eq!(
+            decoded[1..]
+                .iter()
+                .filter_map(|c| match c {
+                    Capability::Report(r) => Some(*r),
+                    _ => None,
+                })
+                .collect::<Vec<_>>(),
+            summary.reports().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn device_reports_match_descriptors() {
+        // Descriptor, report ID, kind and length of the serialized report, including the report ID
+        let reports: [(&[u8], u8, ReportKind, usize); 8] = [
+            (
+                PEN_REPORT_DESCRIPTOR,
+                0,
+                ReportKind::Input,
+                PenReport::default().to_bytes().len(),
+            ),
+            (
+                FLIGHT_CONTROLS_REPORT_DESCRIPTOR,
+                0,
+                ReportKind::Input,
+                FlightControlsReport::new().to_bytes().len(),
+            ),
+            (
+                RACING_WHEEL_REPORT_DESCRIPTOR,
+                0,
+                ReportKind::Input,
+                RacingWheelReport::new().to_bytes().len(),
+            ),
+            (
+                HEADSET_REPORT_DESCRIPTOR,
+                HEADSET_REPORT_ID,
+                ReportKind::Input,
+                HeadsetReport::default().to_bytes().len(),
+            ),
+            (
+                RADIAL_CONTROLLER_REPORT_DESCRIPTOR,
+                RADIAL_CONTROLLER_REPORT_ID,
+                ReportKind::Input,
+                RadialControllerReport::default().to_bytes().len(),
+            ),
+            (
+                AUTOFIRE_GAMEPAD_REPORT_DESCRIPTOR,
+                0,
+                ReportKind::Input,
+                GamepadReport::new().to_bytes().len(),
+            ),
+            (
+                AUTOFIRE_GAMEPAD_REPORT_DESCRIPTOR,
+                0,
+                ReportKind::Feature,
+                AutofireSettings::default().to_bytes().len(),
+            ),
+            (
+                SURFACE_MOUSE_REPORT_DESCRIPTOR,
+                SURFACE_STATUS_REPORT_ID,
+                ReportKind::Input,
+                1 + SurfaceStatus::default().to_bytes().len(),
+            ),
+        ];
+        for (descriptor, id, kind, len) in reports {
+            let summary = DescriptorSummary::new(descriptor).unwrap();
+            let report = summary
+                .reports()
+                .find(|r| r.id == id && r.kind == kind)
+                .unwrap();
+            assert_eq!(usize::from(report.len) + usize::from(id != 0), len);
+        }
+    }
+}
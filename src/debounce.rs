@@ -0,0 +1,78 @@
+//! Switch debouncing
+//!
+//! Debouncers are ticked once per ms, in step with [`UsbHidClass::tick()`](crate::usb_class::UsbHidClass::tick),
+//! with the raw switch state and report the debounced state.
+
+use fugit::MillisDurationU32;
+
+/// Debounces a single switch, a change in state is accepted once it has been stable for the
+/// debounce period
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Debouncer {
+    period_ms: u32,
+    stable_ms: u32,
+    pressed: bool,
+}
+
+impl Default for Debouncer {
+    fn default() -> Self {
+        Self::new(MillisDurationU32::millis(5))
+    }
+}
+
+impl Debouncer {
+    #[must_use]
+    pub const fn new(period: MillisDurationU32) -> Self {
+        Self {
+            period_ms: period.ticks(),
+            stable_ms: 0,
+            pressed: false,
+        }
+    }
+
+    /// Update with the raw switch state, must be called every 1ms. Returns the debounced state
+    pub fn tick(&mut self, raw: bool) -> bool {
+        if raw == self.pressed {
+            self.stable_ms = 0;
+        } else {
+            self.stable_ms += 1;
+            if self.stable_ms >= self.period_ms {
+                self.pressed = raw;
+                self.stable_ms = 0;
+            }
+        }
+        self.pressed
+    }
+
+    /// Debounced switch state
+    #[must_use]
+    pub fn is_pressed(&self) -> bool {
+        self.pressed
+    }
+
+    pub fn reset(&mut self) {
+        self.stable_ms = 0;
+        self.pressed = false;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use fugit::ExtU32;
+
+    #[test]
+    fn bounces_are_filtered() {
+        let mut debouncer = Debouncer::new(3.millis());
+        for raw in [true, false, true, true, false] {
+            assert!(!debouncer.tick(raw));
+        }
+        assert!(!debouncer.tick(true));
+        assert!(!debouncer.tick(true));
+        assert!(debouncer.tick(true));
+        assert!(debouncer.tick(false));
+        assert!(debouncer.tick(true));
+        assert!(debouncer.is_pressed());
+    }
+}
@@ -6,6 +6,36 @@ pub(crate) const USB_CLASS_HID: u8 = 0x03;
 pub(crate) const SPEC_VERSION_1_11: u16 = 0x0111; //1.11 in BCD
 pub(crate) const COUNTRY_CODE_NOT_SUPPORTED: u8 = 0x0;
 
+/// Report descriptor items for a vendor defined feature report carrying the 4 byte
+/// [`report_descriptor_hash`], see
+/// [`InterfaceBuilder::descriptor_hash_report`](crate::interface::InterfaceBuilder::descriptor_hash_report).
+/// Place at the end of an application collection, after a Report ID item if the descriptor uses
+/// report IDs
+#[rustfmt::skip]
+pub const DESCRIPTOR_HASH_FEATURE_ITEMS: &[u8] = &[
+    0x06, 0x00, 0xFF, // Usage Page (Vendor Defined 0xFF00),
+    0x09, 0x48, //       Usage (0x48),
+    0x15, 0x00, //       Logical Minimum (0),
+    0x26, 0xFF, 0x00, // Logical Maximum (255),
+    0x75, 0x08, //       Report Size (8),
+    0x95, 0x04, //       Report Count (4),
+    0xB1, 0x02, //       Feature (Data, Variable, Absolute),
+];
+
+/// 32 bit FNV-1a hash of a report descriptor, letting host software detect descriptor changes
+/// across firmware updates
+#[must_use]
+pub const fn report_descriptor_hash(descriptor: &[u8]) -> u32 {
+    let mut hash: u32 = 0x811C_9DC5;
+    let mut i = 0;
+    while i < descriptor.len() {
+        hash ^= descriptor[i] as u32;
+        hash = hash.wrapping_mul(0x0100_0193);
+        i += 1;
+    }
+    hash
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Ord, PartialOrd, TryFromPrimitive, IntoPrimitive)]
 #[repr(u8)]
 pub enum InterfaceProtocol {
@@ -28,6 +58,21 @@ pub(crate) enum InterfaceSubClass {
     Boot = 0x01,
 }
 
+impl InterfaceProtocol {
+    /// Length of the boot protocol input report, `None` if the interface isn't a boot device
+    ///
+    /// Boot reports are defined in Appendix B of [Device Class Definition for Human Interface
+    /// Devices (Hid) Version 1.11](<https://www.usb.org/sites/default/files/hid1_11.pdf>)
+    #[must_use]
+    pub const fn boot_report_len(self) -> Option<usize> {
+        match self {
+            Self::None => None,
+            Self::Keyboard => Some(8),
+            Self::Mouse => Some(3),
+        }
+    }
+}
+
 impl From<InterfaceProtocol> for InterfaceSubClass {
     fn from(protocol: InterfaceProtocol) -> Self {
         if protocol == InterfaceProtocol::None {
@@ -0,0 +1,215 @@
+//! Scheduled activity reports, e.g. for screensaver inhibit gadgets
+//!
+//! An [`ActivityGenerator`] produces a minimal input sequence, a key tap or a one pixel mouse
+//! jiggle that returns to where it started, after a configurable interval without input. Random
+//! jitter can be added to the interval so the activity doesn't look machine generated.
+//!
+//! The generator is meant to run next to real input on the same device without interfering with
+//! it:
+//!
+//! * Real input counts as activity, [`ActivityGenerator::user_activity`] restarts the interval
+//!   and cancels the rest of a sequence in progress. The next real report replaces any key the
+//!   generator pressed
+//! * [`ActivityStep`]s only describe the generated part of a report, the application merges them
+//!   with the keys and buttons currently held, so a held mouse button isn't released by a jiggle
+//!
+//! ```rust
+//! # use xous_usb_hid::device::activity::{Activity, ActivityGenerator, ActivityStep};
+//! # use xous_usb_hid::device::mouse::WheelMouseReport;
+//! # use xous_usb_hid::page::Keyboard;
+//! # use fugit::ExtU32;
+//! let mut activity = ActivityGenerator::new(Activity::KeyTap(Keyboard::F15), 60_000.millis())
+//!     .jitter(5_000.millis(), 0x1234_5678);
+//!
+//! // every 1ms
+//! activity.tick();
+//!
+//! // on real input
+//! activity.user_activity();
+//!
+//! // once per poll cycle
+//! # let buttons = 0;
+//! match activity.step() {
+//!     Some(ActivityStep::KeyPress(key)) => { /* keyboard.write_report(held keys + key) */ }
+//!     Some(ActivityStep::KeyRelease) => { /* keyboard.write_report(held keys) */ }
+//!     Some(ActivityStep::MouseMove(x)) => {
+//!         let report = WheelMouseReport { buttons, x, ..WheelMouseReport::new() };
+//!         // mouse.write_report(&report)
+//!     }
+//!     None => {}
+//! }
+//! // once the report has been accepted
+//! activity.advance();
+//! ```
+
+use crate::page::Keyboard;
+use fugit::{ExtU32, MillisDurationU32};
+
+/// Input sequence sent by an [`ActivityGenerator`]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Activity {
+    /// Press and release a key, [`Keyboard::F15`] and above are ignored by most applications
+    KeyTap(Keyboard),
+    /// Move the pointer one pixel right and back
+    MouseJiggle,
+}
+
+impl Activity {
+    fn step(self, step: u8) -> Option<ActivityStep> {
+        match (self, step) {
+            (Self::KeyTap(key), 0) => Some(ActivityStep::KeyPress(key)),
+            (Self::KeyTap(_), 1) => Some(ActivityStep::KeyRelease),
+            (Self::MouseJiggle, 0) => Some(ActivityStep::MouseMove(1)),
+            (Self::MouseJiggle, 1) => Some(ActivityStep::MouseMove(-1)),
+            _ => None,
+        }
+    }
+}
+
+/// Generated part of a report, to be merged with the real input state
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ActivityStep {
+    /// Report the held keys plus this key
+    KeyPress(Keyboard),
+    /// Report the held keys only
+    KeyRelease,
+    /// Report the held buttons with this horizontal movement
+    MouseMove(i8),
+}
+
+/// Sends an [`Activity`] after an interval without input, see the [module docs](self)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ActivityGenerator {
+    activity: Activity,
+    interval: MillisDurationU32,
+    jitter: MillisDurationU32,
+    rng: u32,
+    remaining: MillisDurationU32,
+    step: Option<u8>,
+}
+
+impl ActivityGenerator {
+    /// Generator sending `activity` every `interval` without input
+    #[must_use]
+    pub fn new(activity: Activity, interval: MillisDurationU32) -> Self {
+        Self {
+            activity,
+            interval,
+            jitter: 0.millis(),
+            rng: 0,
+            remaining: interval,
+            step: None,
+        }
+    }
+
+    /// Add up to `jitter` to every interval, random numbers are generated from `seed`, e.g. a
+    /// chip unique ID
+    #[must_use]
+    pub fn jitter(mut self, jitter: MillisDurationU32, seed: u32) -> Self {
+        self.jitter = jitter;
+        // xorshift gets stuck at zero
+        self.rng = seed.max(1);
+        self.restart();
+        self
+    }
+
+    /// Call every 1ms
+    pub fn tick(&mut self) {
+        if self.step.is_some() {
+            return;
+        }
+        if self.remaining.ticks() > 0 {
+            self.remaining -= 1.millis();
+        }
+        if self.remaining.ticks() == 0 {
+            trace!("Sending activity {:?}", self.activity);
+            self.step = Some(0);
+        }
+    }
+
+    /// Call on real input, restarts the interval and cancels the sequence in progress
+    pub fn user_activity(&mut self) {
+        self.step = None;
+        self.restart();
+    }
+
+    /// Next part of the sequence to send, `None` while waiting for the interval to pass
+    #[must_use]
+    pub fn step(&self) -> Option<ActivityStep> {
+        self.activity.step(self.step?)
+    }
+
+    /// Move on to the next part of the sequence, once the current one has been accepted
+    pub fn advance(&mut self) {
+        let Some(step) = self.step else { return };
+        if self.activity.step(step + 1).is_some() {
+            self.step = Some(step + 1);
+        } else {
+            self.step = None;
+            self.restart();
+        }
+    }
+
+    fn restart(&mut self) {
+        self.remaining = self.interval;
+        if self.jitter.ticks() > 0 {
+            self.rng ^= self.rng << 13;
+            self.rng ^= self.rng >> 17;
+            self.rng ^= self.rng << 5;
+            self.remaining += (self.rng % (self.jitter.ticks() + 1)).millis();
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn activity_waits_for_idle_interval() {
+        let mut activity = ActivityGenerator::new(Activity::KeyTap(Keyboard::F15), 3.millis());
+
+        activity.tick();
+        activity.tick();
+        activity.user_activity();
+        activity.tick();
+        activity.tick();
+        assert_eq!(activity.step(), None);
+        activity.tick();
+        assert_eq!(activity.step(), Some(ActivityStep::KeyPress(Keyboard::F15)));
+
+        // Not accepted yet, the step is kept
+        activity.tick();
+        assert_eq!(activity.step(), Some(ActivityStep::KeyPress(Keyboard::F15)));
+        activity.advance();
+        assert_eq!(activity.step(), Some(ActivityStep::KeyRelease));
+        activity.advance();
+        assert_eq!(activity.step(), None);
+
+        // Real input cancels the sequence in progress
+        let mut jiggle = ActivityGenerator::new(Activity::MouseJiggle, 1.millis());
+        jiggle.tick();
+        assert_eq!(jiggle.step(), Some(ActivityStep::MouseMove(1)));
+        jiggle.user_activity();
+        assert_eq!(jiggle.step(), None);
+    }
+
+    #[test]
+    fn jitter_stays_in_range() {
+        let mut activity =
+            ActivityGenerator::new(Activity::MouseJiggle, 10.millis()).jitter(5.millis(), 42);
+
+        for _ in 0..20 {
+            let mut ticks = 0;
+            while activity.step().is_none() {
+                activity.tick();
+                ticks += 1;
+            }
+            assert!((10..=15).contains(&ticks), "{ticks}");
+            activity.advance();
+            activity.advance();
+        }
+    }
+}
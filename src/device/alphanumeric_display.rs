@@ -0,0 +1,484 @@
+//! Character displays driven by the host, such as 7-segment or character LCD status displays
+//!
+//! [`AlphanumericDisplay`] implements an alphanumeric display collection of the Auxiliary Display
+//! page, see [`ALPHANUMERIC_DISPLAY_REPORT_DESCRIPTOR`]. Hosts find the size of the display in
+//! the display attributes feature report and write it with output reports, which the device
+//! applies to a [`DisplayBuffer`] of `ROWS` by `COLUMNS` character cells:
+//!
+//! * Display control: clear the display and switch it on or off
+//! * Cursor position: move the cursor to a row and column
+//! * Character: write up to [`CHARACTERS_PER_REPORT`] characters at the cursor, moving it along
+//!
+//! The cells hold ASCII characters, or segment bits for 7-segment displays with
+//! [`CharacterSet::SevenSegment`], see [`seven_segment`] for segment bit order. Showing the
+//! cells on the hardware is up to the application.
+//!
+//! ```rust
+//! # use xous_usb_hid::device::alphanumeric_display::*;
+//! let mut buffer = DisplayBuffer::<2, 16>::new(CharacterSet::Ascii);
+//!
+//! // for every output report read by display.read_report()
+//! buffer.apply(&[CURSOR_POSITION_REPORT_ID, 1, 0]).unwrap();
+//! buffer.apply(&[CHARACTER_REPORT_ID, b'C', b'P', b'U', 0]).unwrap();
+//! assert_eq!(&buffer.row(1).unwrap()[..4], b"CPU ");
+//! ```
+
+use crate::usb_class::prelude::*;
+use fugit::{ExtU32, MillisDurationU32};
+use usb_device::bus::UsbBus;
+use usb_device::class_prelude::UsbBusAllocator;
+use usb_device::UsbError;
+
+/// Report ID of the display attributes feature report
+pub const DISPLAY_ATTRIBUTES_REPORT_ID: u8 = 0x01;
+/// Report ID of the display control output report
+pub const DISPLAY_CONTROL_REPORT_ID: u8 = 0x02;
+/// Report ID of the cursor position output report
+pub const CURSOR_POSITION_REPORT_ID: u8 = 0x03;
+/// Report ID of the character output report
+pub const CHARACTER_REPORT_ID: u8 = 0x04;
+
+/// Characters written by a single character report
+pub const CHARACTERS_PER_REPORT: usize = 4;
+
+/// Alphanumeric display descriptor
+///
+/// * Report ID 1, Display Attributes: Rows and Columns, 8 bits each, ASCII Character Set and
+///   7-Segment Direct Map bits, feature (4 bytes)
+/// * Report ID 2, Display Control: Clear Display and Display Enable bits, output (2 bytes)
+/// * Report ID 3, Cursor Position: Row and Column, 8 bits each, output (3 bytes)
+/// * Report ID 4, Character: [`CHARACTERS_PER_REPORT`] Display Data bytes, output (5 bytes)
+#[rustfmt::skip]
+pub const ALPHANUMERIC_DISPLAY_REPORT_DESCRIPTOR: &[u8] = &[
+    0x05, 0x14,       // Usage Page (Auxiliary Display),
+    0x09, 0x01,       // Usage (Alphanumeric Display),
+    0xA1, 0x01,       // Collection (Application),
+    0x15, 0x00,       //   Logical Minimum (0),
+    0x09, 0x20,       //   Usage (Display Attributes Report),
+    0xA1, 0x02,       //   Collection (Logical),
+    0x85, 0x01,       //     Report ID (1),
+    0x09, 0x35,       //     Usage (Rows),
+    0x09, 0x36,       //     Usage (Columns),
+    0x26, 0xFF, 0x00, //     Logical Maximum (255),
+    0x75, 0x08,       //     Report Size (8),
+    0x95, 0x02,       //     Report Count (2),
+    0xB1, 0x03,       //     Feature (Constant, Variable, Absolute),
+    0x09, 0x21,       //     Usage (ASCII Character Set),
+    0x09, 0x43,       //     Usage (7-Segment Direct Map),
+    0x25, 0x01,       //     Logical Maximum (1),
+    0x75, 0x01,       //     Report Size (1),
+    0xB1, 0x03,       //     Feature (Constant, Variable, Absolute),
+    0x95, 0x06,       //     Report Count (6),
+    0xB1, 0x03,       //     Feature (Constant, Variable, Absolute),
+    0xC0,             //   End Collection,
+    0x09, 0x24,       //   Usage (Display Control Report),
+    0xA1, 0x02,       //   Collection (Logical),
+    0x85, 0x02,       //     Report ID (2),
+    0x09, 0x25,       //     Usage (Clear Display),
+    0x09, 0x26,       //     Usage (Display Enable),
+    0x95, 0x02,       //     Report Count (2),
+    0x91, 0x02,       //     Output (Data, Variable, Absolute),
+    0x95, 0x06,       //     Report Count (6),
+    0x91, 0x03,       //     Output (Constant, Variable, Absolute),
+    0xC0,             //   End Collection,
+    0x09, 0x32,       //   Usage (Cursor Position Report),
+    0xA1, 0x02,       //   Collection (Logical),
+    0x85, 0x03,       //     Report ID (3),
+    0x09, 0x33,       //     Usage (Row),
+    0x09, 0x34,       //     Usage (Column),
+    0x26, 0xFF, 0x00, //     Logical Maximum (255),
+    0x75, 0x08,       //     Report Size (8),
+    0x95, 0x02,       //     Report Count (2),
+    0x91, 0x02,       //     Output (Data, Variable, Absolute),
+    0xC0,             //   End Collection,
+    0x09, 0x2B,       //   Usage (Character Report),
+    0xA1, 0x02,       //   Collection (Logical),
+    0x85, 0x04,       //     Report ID (4),
+    0x09, 0x2C,       //     Usage (Display Data),
+    0x95, 0x04,       //     Report Count (4),
+    0x92, 0x02, 0x01, //     Output (Data, Variable, Absolute, Buffered Bytes),
+    0xC0,             //   End Collection,
+    0xC0,             // End Collection
+];
+
+/// What the display cells hold
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Default, Hash)]
+pub enum CharacterSet {
+    /// ASCII characters, a blank cell is a space. Zero bytes in character reports are padding
+    /// and skipped
+    #[default]
+    Ascii,
+    /// Segment bits as returned by [`seven_segment`], a blank cell is 0
+    SevenSegment,
+}
+
+impl CharacterSet {
+    const fn blank(self) -> u8 {
+        match self {
+            Self::Ascii => b' ',
+            Self::SevenSegment => 0,
+        }
+    }
+}
+
+/// Segment bits of an ASCII character on a 7-segment display, 0 for characters it can't show
+///
+/// Bits 0 to 6 are segments a to g, clockwise from the top with g in the middle, bit 7 is the
+/// decimal point
+///
+/// ```rust
+/// # use xous_usb_hid::device::alphanumeric_display::seven_segment;
+/// assert_eq!(seven_segment(b'7'), 0b000_0111);
+/// ```
+#[must_use]
+pub const fn seven_segment(c: u8) -> u8 {
+    const DIGITS: [u8; 16] = [
+        0x3F, 0x06, 0x5B, 0x4F, 0x66, 0x6D, 0x7D, 0x07, 0x7F, 0x6F, 0x77, 0x7C, 0x39, 0x5E, 0x79,
+        0x71,
+    ];
+    match c {
+        b'0'..=b'9' => DIGITS[(c - b'0') as usize],
+        b'A'..=b'F' => DIGITS[(c - b'A') as usize + 10],
+        b'a'..=b'f' => DIGITS[(c - b'a') as usize + 10],
+        b'-' => 0x40,
+        b'_' => 0x08,
+        b'.' => 0x80,
+        _ => 0,
+    }
+}
+
+/// Output report from the host, see [`DisplayBuffer::apply`]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum DisplayCommand {
+    /// Display control report
+    Control { clear: bool, enable: bool },
+    /// Cursor position report, out of range positions are ignored
+    CursorPosition { row: u8, column: u8 },
+    /// Character report, written at the cursor
+    Characters([u8; CHARACTERS_PER_REPORT]),
+}
+
+impl DisplayCommand {
+    /// Decode an output report, including the report ID
+    pub fn from_bytes(data: &[u8]) -> usb_device::Result<Self> {
+        match *data {
+            [DISPLAY_CONTROL_REPORT_ID, flags] => Ok(Self::Control {
+                clear: flags & 0b01 != 0,
+                enable: flags & 0b10 != 0,
+            }),
+            [CURSOR_POSITION_REPORT_ID, row, column] => Ok(Self::CursorPosition { row, column }),
+            [CHARACTER_REPORT_ID, a, b, c, d] => Ok(Self::Characters([a, b, c, d])),
+            _ => Err(UsbError::ParseError),
+        }
+    }
+
+    /// Encode as an output report, e.g. for host tools
+    #[must_use]
+    pub fn to_bytes(&self) -> ([u8; 1 + CHARACTERS_PER_REPORT], usize) {
+        match *self {
+            Self::Control { clear, enable } => {
+                let flags = u8::from(clear) | u8::from(enable) << 1;
+                ([DISPLAY_CONTROL_REPORT_ID, flags, 0, 0, 0], 2)
+            }
+            Self::CursorPosition { row, column } => {
+                ([CURSOR_POSITION_REPORT_ID, row, column, 0, 0], 3)
+            }
+            Self::Characters([a, b, c, d]) => ([CHARACTER_REPORT_ID, a, b, c, d], 5),
+        }
+    }
+}
+
+/// Character cells of a display with `ROWS` rows of `COLUMNS` characters, written by the host
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct DisplayBuffer<const ROWS: usize, const COLUMNS: usize> {
+    character_set: CharacterSet,
+    cells: [[u8; COLUMNS]; ROWS],
+    cursor: (usize, usize),
+    enabled: bool,
+}
+
+impl<const ROWS: usize, const COLUMNS: usize> Default for DisplayBuffer<ROWS, COLUMNS> {
+    fn default() -> Self {
+        Self::new(CharacterSet::default())
+    }
+}
+
+impl<const ROWS: usize, const COLUMNS: usize> DisplayBuffer<ROWS, COLUMNS> {
+    /// Blank display, switched on, with the cursor at the first cell
+    #[must_use]
+    pub const fn new(character_set: CharacterSet) -> Self {
+        Self {
+            character_set,
+            cells: [[character_set.blank(); COLUMNS]; ROWS],
+            cursor: (0, 0),
+            enabled: true,
+        }
+    }
+
+    #[must_use]
+    pub const fn character_set(&self) -> CharacterSet {
+        self.character_set
+    }
+
+    #[must_use]
+    pub const fn cells(&self) -> &[[u8; COLUMNS]; ROWS] {
+        &self.cells
+    }
+
+    #[must_use]
+    pub fn row(&self, row: usize) -> Option<&[u8; COLUMNS]> {
+        self.cells.get(row)
+    }
+
+    /// Row and column the next character is written to
+    #[must_use]
+    pub const fn cursor(&self) -> (usize, usize) {
+        self.cursor
+    }
+
+    /// `false` while the host has switched the display off, the cells are kept
+    #[must_use]
+    pub const fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Blank all cells and move the cursor to the first cell
+    pub fn clear(&mut self) {
+        self.cells = [[self.character_set.blank(); COLUMNS]; ROWS];
+        self.cursor = (0, 0);
+    }
+
+    /// Apply an output report from the host, including the report ID, returns the command applied
+    pub fn apply(&mut self, data: &[u8]) -> usb_device::Result<DisplayCommand> {
+        let command = DisplayCommand::from_bytes(data)?;
+        match command {
+            DisplayCommand::Control { clear, enable } => {
+                if clear {
+                    self.clear();
+                }
+                self.enabled = enable;
+            }
+            DisplayCommand::CursorPosition { row, column } => {
+                let (row, column) = (usize::from(row), usize::from(column));
+                if row < ROWS && column < COLUMNS {
+                    self.cursor = (row, column);
+                }
+            }
+            DisplayCommand::Characters(characters) => {
+                for c in characters {
+                    if c != 0 || self.character_set == CharacterSet::SevenSegment {
+                        self.write(c);
+                    }
+                }
+            }
+        }
+        Ok(command)
+    }
+
+    /// Write `c` at the cursor and move it to the next cell, wrapping to the next row and from
+    /// the last cell to the first
+    fn write(&mut self, c: u8) {
+        let (row, column) = self.cursor;
+        let Some(cell) = self.cells.get_mut(row).and_then(|r| r.get_mut(column)) else {
+            return;
+        };
+        *cell = c;
+        self.cursor = if column + 1 < COLUMNS {
+            (row, column + 1)
+        } else {
+            ((row + 1) % ROWS, 0)
+        };
+    }
+}
+
+/// Alphanumeric display, see the [module docs](self)
+pub struct AlphanumericDisplay<'a, B: UsbBus, const ROWS: usize, const COLUMNS: usize> {
+    interface: Interface<'a, B, InNone, OutBytes8, Reports8>,
+    buffer: DisplayBuffer<ROWS, COLUMNS>,
+}
+
+impl<B: UsbBus, const ROWS: usize, const COLUMNS: usize> AlphanumericDisplay<'_, B, ROWS, COLUMNS> {
+    /// Apply the next output report from the host to the display buffer, returns the command
+    /// applied
+    pub fn read_report(&mut self) -> usb_device::Result<DisplayCommand> {
+        let mut data = [0; 8];
+        let n = self.interface.read_report(&mut data)?;
+        self.buffer.apply(&data[..n])
+    }
+
+    #[must_use]
+    pub fn buffer(&self) -> &DisplayBuffer<ROWS, COLUMNS> {
+        &self.buffer
+    }
+}
+
+impl<'a, B: UsbBus, const ROWS: usize, const COLUMNS: usize> DeviceClass<'a>
+    for AlphanumericDisplay<'a, B, ROWS, COLUMNS>
+{
+    type I = Interface<'a, B, InNone, OutBytes8, Reports8>;
+
+    fn interface(&mut self) -> &mut Self::I {
+        &mut self.interface
+    }
+
+    fn reset(&mut self) {}
+
+    fn tick(&mut self) -> Result<(), UsbHidError> {
+        Ok(())
+    }
+
+    fn next_deadline(&mut self) -> Option<MillisDurationU32> {
+        None
+    }
+}
+
+pub struct AlphanumericDisplayConfig<'a, const ROWS: usize, const COLUMNS: usize> {
+    interface: InterfaceConfig<'a, InNone, OutBytes8, Reports8>,
+    character_set: CharacterSet,
+}
+
+impl<const ROWS: usize, const COLUMNS: usize> Default
+    for AlphanumericDisplayConfig<'_, ROWS, COLUMNS>
+{
+    fn default() -> Self {
+        Self::for_character_set(CharacterSet::Ascii)
+    }
+}
+
+impl<'a, const ROWS: usize, const COLUMNS: usize> AlphanumericDisplayConfig<'a, ROWS, COLUMNS> {
+    /// `interface` must use [`ALPHANUMERIC_DISPLAY_REPORT_DESCRIPTOR`] and keep the display
+    /// attributes feature report
+    #[must_use]
+    pub fn new(
+        interface: InterfaceConfig<'a, InNone, OutBytes8, Reports8>,
+        character_set: CharacterSet,
+    ) -> Self {
+        Self {
+            interface,
+            character_set,
+        }
+    }
+
+    /// Default interface, with display attributes for `ROWS` by `COLUMNS` cells of
+    /// `character_set`
+    #[must_use]
+    pub fn for_character_set(character_set: CharacterSet) -> Self {
+        let rows = u8::try_from(ROWS).unwrap_or(u8::MAX);
+        let columns = u8::try_from(COLUMNS).unwrap_or(u8::MAX);
+        let flags = match character_set {
+            CharacterSet::Ascii => 0b01,
+            CharacterSet::SevenSegment => 0b10,
+        };
+        Self::new(
+            unwrap!(unwrap!(unwrap!(unwrap!(InterfaceBuilder::new_static(
+                ALPHANUMERIC_DISPLAY_REPORT_DESCRIPTOR
+            ))
+            .description("Alphanumeric Display")
+            .feature_report(
+                DISPLAY_ATTRIBUTES_REPORT_ID,
+                &[DISPLAY_ATTRIBUTES_REPORT_ID, rows, columns, flags]
+            ))
+            .in_endpoint(100.millis()))
+            .with_out_endpoint(10.millis()))
+            .build(),
+            character_set,
+        )
+    }
+}
+
+impl<'a, B: UsbBus + 'a, const ROWS: usize, const COLUMNS: usize> UsbAllocatable<'a, B>
+    for AlphanumericDisplayConfig<'a, ROWS, COLUMNS>
+{
+    type Allocated = AlphanumericDisplay<'a, B, ROWS, COLUMNS>;
+
+    fn allocate(self, usb_alloc: &'a UsbBusAllocator<B>) -> Self::Allocated {
+        Self::Allocated {
+            interface: Interface::new(usb_alloc, self.interface),
+            buffer: DisplayBuffer::new(self.character_set),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+    use crate::page::AlphanumericDisplay as Usage;
+
+    #[test]
+    fn descriptor_uses_alphanumeric_display_page() {
+        let usages: [u8; 14] = [
+            Usage::AlphanumericDisplay.into(),
+            Usage::DisplayAttributesReport.into(),
+            Usage::Rows.into(),
+            Usage::Columns.into(),
+            Usage::AsciiCharacterSet.into(),
+            Usage::SevenSegmentDirectMap.into(),
+            Usage::DisplayControlReport.into(),
+            Usage::ClearDisplay.into(),
+            Usage::DisplayEnable.into(),
+            Usage::CursorPositionReport.into(),
+            Usage::Row.into(),
+            Usage::Column.into(),
+            Usage::CharacterReport.into(),
+            Usage::DisplayData.into(),
+        ];
+        for usage in usages {
+            assert!(ALPHANUMERIC_DISPLAY_REPORT_DESCRIPTOR
+                .windows(2)
+                .any(|item| item == [0x09, usage]));
+        }
+    }
+
+    #[test]
+    fn characters_wrap_at_the_cursor() {
+        let mut buffer = DisplayBuffer::<2, 3>::new(CharacterSet::Ascii);
+        buffer.apply(&[CURSOR_POSITION_REPORT_ID, 0, 1]).unwrap();
+        buffer
+            .apply(&[CHARACTER_REPORT_ID, b'a', 0, b'b', b'c'])
+            .unwrap();
+        assert_eq!(buffer.cells(), &[*b" ab", *b"c  "]);
+        assert_eq!(buffer.cursor(), (1, 1));
+
+        // Out of range positions are ignored, writes wrap back to the first cell
+        buffer.apply(&[CURSOR_POSITION_REPORT_ID, 2, 0]).unwrap();
+        buffer
+            .apply(&[CHARACTER_REPORT_ID, b'd', b'e', b'f', b'g'])
+            .unwrap();
+        assert_eq!(buffer.cells(), &[*b"fgb", *b"cde"]);
+
+        let command = buffer.apply(&[DISPLAY_CONTROL_REPORT_ID, 0b01]).unwrap();
+        assert_eq!(
+            command,
+            DisplayCommand::Control {
+                clear: true,
+                enable: false
+            }
+        );
+        assert_eq!(buffer.cells(), &[*b"   "; 2]);
+        assert!(!buffer.is_enabled());
+        let (bytes, len) = command.to_bytes();
+        assert_eq!(bytes[..len], [DISPLAY_CONTROL_REPORT_ID, 0b01]);
+
+        assert!(buffer.apply(&[CHARACTER_REPORT_ID, b'x']).is_err());
+        assert!(buffer
+            .apply(&[DISPLAY_ATTRIBUTES_REPORT_ID, 2, 3, 1])
+            .is_err());
+    }
+
+    #[test]
+    fn seven_segment_cells() {
+        let mut buffer = DisplayBuffer::<1, 4>::new(CharacterSet::SevenSegment);
+        let digits = [b'1', b'2', b'.', b'x'].map(seven_segment);
+        buffer
+            .apply(&[CHARACTER_REPORT_ID, 0, digits[0], digits[1], digits[2]])
+            .unwrap();
+        assert_eq!(buffer.cells(), &[[0, 0x06, 0x5B, 0x80]]);
+        assert_eq!(digits[3], 0);
+        assert_eq!(seven_segment(b'F'), seven_segment(b'f'));
+    }
+}
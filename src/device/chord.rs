@@ -0,0 +1,179 @@
+//! Chords spanning a keyboard and a consumer control device
+//!
+//! Some media key combinations need keys from both the keyboard and consumer pages, e.g.
+//! Shift+Volume Up for fine volume steps. The keyboard and consumer reports travel on separate
+//! interfaces, so the host only sees the modifier held if the keyboard report arrives before the
+//! consumer report, and the consumer usage is released before the modifier.
+//!
+//! A [`Chord`] sends its four reports in that order, as many as the endpoints accept in a single
+//! call to [`Chord::poll`]:
+//!
+//! 1. Keyboard press
+//! 2. Consumer press
+//! 3. Consumer release
+//! 4. Keyboard release
+//!
+//! ```rust
+//! # use xous_usb_hid::device::chord::{Chord, ChordReport};
+//! # use xous_usb_hid::device::keyboard::typing::{Keystroke, Modifiers};
+//! # use xous_usb_hid::page::{Consumer, Keyboard};
+//! let mut chord = Chord::new(
+//!     Keystroke::new(Modifiers::LEFT_SHIFT, Keyboard::NoEventIndicated),
+//!     Consumer::VolumeIncrement,
+//! );
+//!
+//! // once per poll cycle, until it is done
+//! chord
+//!     .poll(
+//!         |keystroke| Ok(()), // keyboard.device().write_report(keystroke)
+//!         |report| Ok(()),    // consumer.device().write_report(report).map(|_| ())
+//!     )
+//!     .ok();
+//! assert!(chord.is_done());
+//! ```
+
+use crate::device::consumer::MultipleConsumerReport;
+use crate::device::keyboard::typing::Keystroke;
+use crate::page::Consumer;
+use crate::UsbHidError;
+
+/// A report to be sent as part of a [`Chord`]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChordReport {
+    Keyboard(Keystroke),
+    Consumer(Consumer),
+}
+
+/// Keyboard keystroke and consumer usage pressed together, see the [module docs](self)
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Chord {
+    keystroke: Keystroke,
+    usage: Consumer,
+    step: u8,
+}
+
+impl Chord {
+    const STEPS: u8 = 4;
+
+    #[must_use]
+    pub const fn new(keystroke: Keystroke, usage: Consumer) -> Self {
+        Self {
+            keystroke,
+            usage,
+            step: 0,
+        }
+    }
+
+    /// Next report to send, `None` once the chord has been sent
+    #[must_use]
+    pub fn report(&self) -> Option<ChordReport> {
+        match self.step {
+            0 => Some(ChordReport::Keyboard(self.keystroke)),
+            1 => Some(ChordReport::Consumer(self.usage)),
+            2 => Some(ChordReport::Consumer(Consumer::Unassigned)),
+            3 => Some(ChordReport::Keyboard(Keystroke::RELEASE)),
+            _ => None,
+        }
+    }
+
+    /// Move on to the next report, once the current one has been accepted
+    pub fn advance(&mut self) {
+        self.step = Self::STEPS.min(self.step + 1);
+    }
+
+    /// `true` once all reports have been sent
+    #[must_use]
+    pub fn is_done(&self) -> bool {
+        self.step >= Self::STEPS
+    }
+
+    /// Send as many of the remaining reports as the devices accept, in order
+    ///
+    /// Stops at the first report that isn't accepted, returning `Ok(())` if it would block so that
+    /// it is retried on the next call, or any other error. [`UsbHidError::Duplicate`] counts as
+    /// sent, as the host already has that state
+    pub fn poll<K, C>(
+        &mut self,
+        mut write_keyboard: K,
+        mut write_consumer: C,
+    ) -> Result<(), UsbHidError>
+    where
+        K: FnMut(Keystroke) -> Result<(), UsbHidError>,
+        C: FnMut(&MultipleConsumerReport) -> Result<(), UsbHidError>,
+    {
+        while let Some(report) = self.report() {
+            let result = match report {
+                ChordReport::Keyboard(keystroke) => write_keyboard(keystroke),
+                ChordReport::Consumer(usage) => {
+                    write_consumer(&MultipleConsumerReport::new().codes([
+                        usage,
+                        Consumer::Unassigned,
+                        Consumer::Unassigned,
+                        Consumer::Unassigned,
+                    ]))
+                }
+            };
+            match result {
+                Ok(()) | Err(UsbHidError::Duplicate) => self.advance(),
+                Err(UsbHidError::WouldBlock) => return Ok(()),
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+    use crate::device::keyboard::typing::Modifiers;
+    use crate::page::Keyboard;
+    use core::cell::RefCell;
+    use std::vec::Vec;
+
+    #[test]
+    fn reports_are_ordered_across_devices() {
+        let sent = RefCell::new(Vec::new());
+        let shift = Keystroke::new(Modifiers::LEFT_SHIFT, Keyboard::NoEventIndicated);
+        let mut chord = Chord::new(shift, Consumer::VolumeIncrement);
+
+        let poll = |chord: &mut Chord, consumer_busy: bool| {
+            chord.poll(
+                |k| {
+                    sent.borrow_mut().push(ChordReport::Keyboard(k));
+                    Ok(())
+                },
+                |r| {
+                    if consumer_busy {
+                        return Err(UsbHidError::WouldBlock);
+                    }
+                    sent.borrow_mut().push(ChordReport::Consumer(r.codes[0]));
+                    Ok(())
+                },
+            )
+        };
+
+        // The keyboard release waits for the consumer reports
+        poll(&mut chord, true).unwrap();
+        assert_eq!(
+            chord.report(),
+            Some(ChordReport::Consumer(Consumer::VolumeIncrement))
+        );
+        poll(&mut chord, false).unwrap();
+        assert!(chord.is_done());
+
+        assert_eq!(
+            *sent.borrow(),
+            [
+                ChordReport::Keyboard(shift),
+                ChordReport::Consumer(Consumer::VolumeIncrement),
+                ChordReport::Consumer(Consumer::Unassigned),
+                ChordReport::Keyboard(Keystroke::RELEASE),
+            ]
+        );
+    }
+}
@@ -1,6 +1,6 @@
 //!HID consumer control
 
-use fugit::ExtU32;
+use fugit::{ExtU32, MillisDurationU32};
 use packed_struct::prelude::*;
 #[allow(clippy::wildcard_imports)]
 use usb_device::class_prelude::*;
@@ -8,6 +8,9 @@ use usb_device::class_prelude::*;
 use crate::page::Consumer;
 use crate::usb_class::prelude::*;
 
+pub mod guard;
+pub mod usage_list;
+
 ///Consumer control report descriptor - Four `u16` consumer control usage codes as an array (8 bytes)
 #[rustfmt::skip]
 pub const MULTIPLE_CODE_REPORT_DESCRIPTOR: &[u8] = &[
@@ -24,6 +27,24 @@ pub const MULTIPLE_CODE_REPORT_DESCRIPTOR: &[u8] = &[
     0xC0, // End Collection
 ];
 
+/// Consumer control report descriptor - Four `u16` consumer control usage codes as an array,
+/// declaring the whole consumer page so that any usage can be sent (8 bytes)
+#[rustfmt::skip]
+pub const FULL_CONSUMER_CONTROL_REPORT_DESCRIPTOR: &[u8] = &[
+    0x05, 0x0C,                   // Usage Page (Consumer),
+    0x09, 0x01,                   // Usage (Consumer Control),
+    0xA1, 0x01,                   // Collection (Application),
+    0x75, 0x10,                   //   Report Size (16),
+    0x95, 0x04,                   //   Report Count (4),
+    0x15, 0x00,                   //   Logical Minimum (0),
+    0x27, 0xFF, 0xFF, 0x00, 0x00, //   Logical Maximum (0xFFFF),
+    0x19, 0x00,                   //   Usage Minimum (0),
+    0x2A, 0xFF, 0xFF,             //   Usage Maximum (0xFFFF),
+    0x81, 0x00,                   //   Input (Data, Array, Absolute),
+    0xC0,                         // End Collection
+];
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Default, PackedStruct)]
 #[packed_struct(endian = "lsb", size_bytes = "8")]
 pub struct MultipleConsumerReport {
@@ -31,6 +52,99 @@ pub struct MultipleConsumerReport {
     pub codes: [Consumer; 4],
 }
 
+impl MultipleConsumerReport {
+    /// Report with no usages asserted
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            codes: [Consumer::Unassigned; 4],
+        }
+    }
+
+    #[must_use]
+    pub const fn codes(mut self, codes: [Consumer; 4]) -> Self {
+        self.codes = codes;
+        self
+    }
+
+    /// Add `usage` to the first free slot, returns `false` if all slots are taken or `usage` is
+    /// [`Consumer::Unassigned`]
+    pub fn press(&mut self, usage: Consumer) -> bool {
+        if usage == Consumer::Unassigned {
+            return false;
+        }
+        if self.is_pressed(usage) {
+            return true;
+        }
+        match self.codes.iter_mut().find(|c| **c == Consumer::Unassigned) {
+            Some(code) => {
+                *code = usage;
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn release(&mut self, usage: Consumer) {
+        for code in self.codes.iter_mut().filter(|c| **c == usage) {
+            *code = Consumer::Unassigned;
+        }
+    }
+
+    #[must_use]
+    pub fn is_pressed(&self, usage: Consumer) -> bool {
+        usage != Consumer::Unassigned && self.codes.contains(&usage)
+    }
+
+    /// Release all usages
+    pub fn clear(&mut self) {
+        *self = Self::new();
+    }
+}
+
+/// Raw consumer control usage ID, for usages that [`Consumer`] doesn't cover yet
+///
+/// ```rust
+/// # use xous_usb_hid::device::consumer::ConsumerCode;
+/// # use xous_usb_hid::page::Consumer;
+/// assert_eq!(ConsumerCode::from(Consumer::PlayPause), ConsumerCode(0xCD));
+/// assert_eq!(ConsumerCode(0xCD).known(), Some(Consumer::PlayPause));
+/// assert_eq!(ConsumerCode(0x0FFF).known(), None);
+/// ```
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
+pub struct ConsumerCode(pub u16);
+
+impl ConsumerCode {
+    /// The matching [`Consumer`] variant, `None` for usages missing from the enum
+    #[must_use]
+    pub fn known(self) -> Option<Consumer> {
+        let usage = Consumer::from(self.0);
+        (u16::from(usage) == self.0).then_some(usage)
+    }
+}
+
+impl From<Consumer> for ConsumerCode {
+    fn from(usage: Consumer) -> Self {
+        Self(usage.into())
+    }
+}
+
+impl From<ConsumerCode> for u16 {
+    fn from(code: ConsumerCode) -> Self {
+        code.0
+    }
+}
+
+/// Same layout as [`MultipleConsumerReport`]
+fn pack_codes(codes: [ConsumerCode; 4]) -> [u8; 8] {
+    let mut data = [0; 8];
+    for (bytes, code) in data.chunks_exact_mut(2).zip(codes) {
+        bytes.copy_from_slice(&code.0.to_le_bytes());
+    }
+    data
+}
+
 #[allow(clippy::doc_markdown)]
 ///Fixed functionality consumer control report descriptor
 /// 
@@ -69,7 +183,8 @@ pub const FIXED_FUNCTION_REPORT_DESCRIPTOR: &[u8] = &[
     0xC0, //        End Collection
 ];
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq, PackedStruct)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Default, PackedStruct)]
 #[packed_struct(endian = "lsb", bit_numbering = "lsb0", size_bytes = "1")]
 pub struct FixedFunctionReport {
     #[packed_field(bits = "0")]
@@ -88,6 +203,64 @@ pub struct FixedFunctionReport {
     pub volume_decrement: bool,
 }
 
+impl FixedFunctionReport {
+    /// Report with no functions asserted
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            next: false,
+            previous: false,
+            stop: false,
+            play_pause: false,
+            mute: false,
+            volume_increment: false,
+            volume_decrement: false,
+        }
+    }
+
+    #[must_use]
+    pub const fn next(mut self, next: bool) -> Self {
+        self.next = next;
+        self
+    }
+
+    #[must_use]
+    pub const fn previous(mut self, previous: bool) -> Self {
+        self.previous = previous;
+        self
+    }
+
+    #[must_use]
+    pub const fn stop(mut self, stop: bool) -> Self {
+        self.stop = stop;
+        self
+    }
+
+    #[must_use]
+    pub const fn play_pause(mut self, play_pause: bool) -> Self {
+        self.play_pause = play_pause;
+        self
+    }
+
+    #[must_use]
+    pub const fn mute(mut self, mute: bool) -> Self {
+        self.mute = mute;
+        self
+    }
+
+    #[must_use]
+    pub const fn volume_increment(mut self, volume_increment: bool) -> Self {
+        self.volume_increment = volume_increment;
+        self
+    }
+
+    #[must_use]
+    pub const fn volume_decrement(mut self, volume_decrement: bool) -> Self {
+        self.volume_decrement = volume_decrement;
+        self
+    }
+}
+
 pub struct ConsumerControl<'a, B: UsbBus> {
     interface: Interface<'a, B, InBytes8, OutNone, ReportSingle>,
 }
@@ -100,6 +273,13 @@ impl<'a, B: UsbBus> ConsumerControl<'a, B> {
         })?;
         self.interface.write_report(&data)
     }
+
+    /// Write a report with raw usage IDs, for usages that [`Consumer`] doesn't cover yet. The
+    /// default report descriptor declares usages up to 0x029C, higher usages need a custom
+    /// descriptor
+    pub fn write_codes(&mut self, codes: [ConsumerCode; 4]) -> usb_device::Result<usize> {
+        self.interface.write_report(&pack_codes(codes))
+    }
 }
 
 impl<'a, B: UsbBus> DeviceClass<'a> for ConsumerControl<'a, B> {
@@ -114,6 +294,10 @@ impl<'a, B: UsbBus> DeviceClass<'a> for ConsumerControl<'a, B> {
     fn tick(&mut self) -> Result<(), crate::UsbHidError> {
         Ok(())
     }
+
+    fn next_deadline(&mut self) -> Option<MillisDurationU32> {
+        None
+    }
 }
 
 pub struct ConsumerControlConfig<'a> {
@@ -131,11 +315,11 @@ impl<'a> Default for ConsumerControlConfig<'a> {
     #[must_use]
     fn default() -> Self {
         Self::new(
-            unwrap!(
-                unwrap!(InterfaceBuilder::new(MULTIPLE_CODE_REPORT_DESCRIPTOR))
-                    .description("Consumer Control")
-                    .in_endpoint(50.millis())
-            )
+            unwrap!(unwrap!(InterfaceBuilder::new_static(
+                MULTIPLE_CODE_REPORT_DESCRIPTOR
+            ))
+            .description("Consumer Control")
+            .in_endpoint(50.millis()))
             .without_out_endpoint()
             .build(),
         )
@@ -178,6 +362,10 @@ impl<'a, B: UsbBus> DeviceClass<'a> for ConsumerControlFixed<'a, B> {
     fn tick(&mut self) -> Result<(), crate::UsbHidError> {
         Ok(())
     }
+
+    fn next_deadline(&mut self) -> Option<MillisDurationU32> {
+        None
+    }
 }
 
 pub struct ConsumerControlFixedConfig<'a> {
@@ -194,11 +382,11 @@ impl<'a> Default for ConsumerControlFixedConfig<'a> {
     #[must_use]
     fn default() -> Self {
         Self::new(
-            unwrap!(
-                unwrap!(InterfaceBuilder::new(FIXED_FUNCTION_REPORT_DESCRIPTOR))
-                    .description("Consumer Control")
-                    .in_endpoint(50.millis())
-            )
+            unwrap!(unwrap!(InterfaceBuilder::new_static(
+                FIXED_FUNCTION_REPORT_DESCRIPTOR
+            ))
+            .description("Consumer Control")
+            .in_endpoint(50.millis()))
             .without_out_endpoint()
             .build(),
         )
@@ -214,3 +402,180 @@ impl<'a, B: UsbBus + 'a> UsbAllocatable<'a, B> for ConsumerControlFixedConfig<'a
         }
     }
 }
+
+/// Consumer control sending any [`Consumer`] usage, see
+/// [`FULL_CONSUMER_CONTROL_REPORT_DESCRIPTOR`]
+///
+/// Up to four usages are held at once. Like [`NKROKeyboard`](crate::device::keyboard::NKROKeyboard),
+/// every change is sent to the host straight away, and a change that couldn't be sent is kept and
+/// sent by the next call to [`FullConsumerControl::flush`] or any other change.
+///
+/// ```rust
+/// # use xous_usb_hid::device::consumer::MultipleConsumerReport;
+/// # use xous_usb_hid::page::Consumer;
+/// // consumer.press(Consumer::ALCalculator), consumer.release_all()
+/// let mut report = MultipleConsumerReport::new();
+/// assert!(report.press(Consumer::ALCalculator));
+/// assert!(report.press(Consumer::ACZoomIn));
+/// assert!(report.is_pressed(Consumer::ALCalculator));
+/// ```
+pub struct FullConsumerControl<'a, B: UsbBus> {
+    interface: ManagedIdleInterface<'a, B, MultipleConsumerReport, InBytes8, OutNone>,
+    state: MultipleConsumerReport,
+}
+
+impl<B: UsbBus> FullConsumerControl<'_, B> {
+    /// Press a usage, returns [`UsbHidError::SerializationError`] if four usages are already
+    /// pressed or `usage` is [`Consumer::Unassigned`]
+    pub fn press(&mut self, usage: Consumer) -> Result<(), UsbHidError> {
+        if !self.state.press(usage) {
+            return Err(UsbHidError::SerializationError);
+        }
+        self.flush()
+    }
+
+    pub fn release(&mut self, usage: Consumer) -> Result<(), UsbHidError> {
+        self.state.release(usage);
+        self.flush()
+    }
+
+    /// Release all usages
+    pub fn release_all(&mut self) -> Result<(), UsbHidError> {
+        self.state.clear();
+        self.flush()
+    }
+
+    /// Replace the pressed usages with `report`
+    pub fn write_report(&mut self, report: &MultipleConsumerReport) -> Result<(), UsbHidError> {
+        self.state = *report;
+        self.flush()
+    }
+
+    /// Send the pressed usages, if the host doesn't have them yet
+    pub fn flush(&mut self) -> Result<(), UsbHidError> {
+        match self.interface.write_report(&self.state) {
+            Err(UsbHidError::Duplicate) => Ok(()),
+            result => result,
+        }
+    }
+
+    /// Usages currently pressed
+    #[must_use]
+    pub fn state(&self) -> &MultipleConsumerReport {
+        &self.state
+    }
+}
+
+impl<'a, B: UsbBus> DeviceClass<'a> for FullConsumerControl<'a, B> {
+    type I = Interface<'a, B, InBytes8, OutNone, ReportSingle>;
+
+    fn interface(&mut self) -> &mut Self::I {
+        self.interface.interface()
+    }
+
+    fn reset(&mut self) {
+        self.interface.reset();
+    }
+
+    fn endpoint_halt_cleared(&mut self) {
+        self.interface.endpoint_halt_cleared();
+    }
+
+    fn configured(&mut self) {
+        self.interface.configured();
+    }
+
+    fn tick(&mut self) -> Result<(), UsbHidError> {
+        self.interface.tick()
+    }
+
+    fn next_deadline(&mut self) -> Option<MillisDurationU32> {
+        self.interface.next_deadline()
+    }
+
+    fn advance(&mut self, elapsed: MillisDurationU32) {
+        self.interface.advance(elapsed);
+    }
+}
+
+pub struct FullConsumerControlConfig<'a> {
+    interface: ManagedIdleInterfaceConfig<'a, MultipleConsumerReport, InBytes8, OutNone>,
+}
+
+impl Default for FullConsumerControlConfig<'_> {
+    fn default() -> Self {
+        Self::new(ManagedIdleInterfaceConfig::new(
+            unwrap!(unwrap!(InterfaceBuilder::new_static(
+                FULL_CONSUMER_CONTROL_REPORT_DESCRIPTOR
+            ))
+            .description("Consumer Control")
+            .in_endpoint(50.millis()))
+            .without_out_endpoint()
+            .build(),
+        ))
+    }
+}
+
+impl<'a> FullConsumerControlConfig<'a> {
+    #[must_use]
+    pub fn new(
+        interface: ManagedIdleInterfaceConfig<'a, MultipleConsumerReport, InBytes8, OutNone>,
+    ) -> Self {
+        Self { interface }
+    }
+}
+
+impl<'a, B: UsbBus + 'a> UsbAllocatable<'a, B> for FullConsumerControlConfig<'a> {
+    type Allocated = FullConsumerControl<'a, B>;
+
+    fn allocate(self, usb_alloc: &'a UsbBusAllocator<B>) -> Self::Allocated {
+        Self::Allocated {
+            interface: self.interface.allocate(usb_alloc),
+            state: MultipleConsumerReport::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    #[test]
+    fn codes_match_report_layout() {
+        let report = MultipleConsumerReport::new().codes([
+            Consumer::VolumeIncrement,
+            Consumer::Unassigned,
+            Consumer::ACPan,
+            Consumer::Unassigned,
+        ]);
+        let codes = report.codes.map(ConsumerCode::from);
+        assert!(codes.iter().all(|c| c.known().is_some()));
+
+        assert_eq!(pack_codes(codes), report.pack().unwrap());
+    }
+
+    #[test]
+    fn press_fills_free_slots() {
+        let mut report = MultipleConsumerReport::new();
+        assert!(!report.press(Consumer::Unassigned));
+        assert!(report.press(Consumer::Mute));
+        assert!(report.press(Consumer::ContactMisc));
+        assert!(report.press(Consumer::Mute));
+        assert!(report.press(Consumer::PlayPause));
+        assert!(report.press(Consumer::ACPan));
+        assert!(!report.press(Consumer::VolumeIncrement));
+
+        report.release(Consumer::ContactMisc);
+        assert!(!report.is_pressed(Consumer::ContactMisc));
+        assert!(report.press(Consumer::VolumeIncrement));
+        assert_eq!(
+            report.pack().unwrap(),
+            [0xE2, 0x00, 0xE9, 0x00, 0xCD, 0x00, 0x38, 0x02]
+        );
+
+        report.clear();
+        assert_eq!(report, MultipleConsumerReport::new());
+    }
+}
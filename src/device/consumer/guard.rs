@@ -0,0 +1,193 @@
+//! Confirmation guard for power usages
+//!
+//! A bouncy or accidentally brushed button mapped to a power usage can shut down or suspend the
+//! host. A [`ConfirmationGuard`] debounces the button and only asserts the usage once the press
+//! has been confirmed, either by holding the button or by pressing it twice.
+
+use crate::debounce::Debouncer;
+use crate::page::{Consumer, Desktop};
+use fugit::MillisDurationU32;
+
+/// How a press is confirmed before the guarded usage is asserted
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Confirmation {
+    /// Assert as soon as the debounced button is pressed
+    Immediate,
+    /// Assert once the button has been held for the duration
+    Hold(MillisDurationU32),
+    /// Assert on a second press starting within the duration of the first press being released
+    DoublePress(MillisDurationU32),
+}
+
+impl Default for Confirmation {
+    fn default() -> Self {
+        Self::Hold(MillisDurationU32::millis(1000))
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum GuardState {
+    Idle,
+    Pressed {
+        ms: u32,
+    },
+    Released {
+        ms: u32,
+    },
+    Confirmed,
+    /// Held through a change of confirmation, wait for release
+    Rejected,
+}
+
+/// Debounces a button and holds back its usage until the press is confirmed
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ConfirmationGuard {
+    confirmation: Confirmation,
+    debouncer: Debouncer,
+    state: GuardState,
+}
+
+impl Default for ConfirmationGuard {
+    fn default() -> Self {
+        Self::new(Confirmation::default(), Debouncer::default())
+    }
+}
+
+impl ConfirmationGuard {
+    #[must_use]
+    pub fn new(confirmation: Confirmation, debouncer: Debouncer) -> Self {
+        Self {
+            confirmation,
+            debouncer,
+            state: GuardState::Idle,
+        }
+    }
+
+    #[must_use]
+    pub fn confirmation(&self) -> Confirmation {
+        self.confirmation
+    }
+
+    /// Change how presses are confirmed, any press in progress is discarded
+    pub fn set_confirmation(&mut self, confirmation: Confirmation) {
+        self.confirmation = confirmation;
+        self.state = GuardState::Rejected;
+    }
+
+    pub fn reset(&mut self) {
+        self.debouncer.reset();
+        self.state = GuardState::Idle;
+    }
+
+    /// Update with the raw button state, must be called every 1ms. Returns `true` while the
+    /// guarded usage should be reported as pressed
+    pub fn tick(&mut self, raw: bool) -> bool {
+        let pressed = self.debouncer.tick(raw);
+
+        self.state = match (self.state, pressed, self.confirmation) {
+            (GuardState::Pressed { ms }, true, Confirmation::Hold(hold)) => {
+                if ms + 1 >= hold.ticks() {
+                    GuardState::Confirmed
+                } else {
+                    GuardState::Pressed { ms: ms + 1 }
+                }
+            }
+            (GuardState::Idle, true, Confirmation::Immediate)
+            | (GuardState::Released { .. }, true, Confirmation::DoublePress(_)) => {
+                GuardState::Confirmed
+            }
+            (GuardState::Idle | GuardState::Released { .. }, true, _) => {
+                GuardState::Pressed { ms: 0 }
+            }
+            (
+                GuardState::Pressed { .. } | GuardState::Confirmed | GuardState::Rejected,
+                true,
+                _,
+            ) => self.state,
+            (GuardState::Pressed { .. }, false, Confirmation::DoublePress(_)) => {
+                GuardState::Released { ms: 0 }
+            }
+            (GuardState::Released { ms }, false, Confirmation::DoublePress(window))
+                if ms + 1 < window.ticks() =>
+            {
+                GuardState::Released { ms: ms + 1 }
+            }
+            (_, false, _) => GuardState::Idle,
+        };
+
+        self.state == GuardState::Confirmed
+    }
+}
+
+/// `true` for consumer usages that can power down or suspend the host
+#[must_use]
+pub fn consumer_needs_confirmation(usage: Consumer) -> bool {
+    matches!(
+        usage,
+        Consumer::Power | Consumer::Sleep | Consumer::SleepAfter | Consumer::SleepMode
+    )
+}
+
+/// `true` for desktop usages that can power down or suspend the host
+#[must_use]
+pub fn desktop_needs_confirmation(usage: Desktop) -> bool {
+    matches!(usage, Desktop::SystemPowerDown | Desktop::SystemSleep)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use fugit::ExtU32;
+
+    fn run(guard: &mut ConfirmationGuard, raw: bool, ms: u32) -> bool {
+        let mut asserted = false;
+        for _ in 0..ms {
+            asserted = guard.tick(raw);
+        }
+        asserted
+    }
+
+    #[test]
+    fn hold_to_confirm() {
+        let mut guard =
+            ConfirmationGuard::new(Confirmation::Hold(100.millis()), Debouncer::new(5.millis()));
+        assert!(!run(&mut guard, true, 50));
+        assert!(!run(&mut guard, false, 50));
+        assert!(!run(&mut guard, true, 100));
+        assert!(run(&mut guard, true, 10));
+        assert!(!run(&mut guard, false, 10));
+    }
+
+    #[test]
+    fn double_press_to_confirm() {
+        let mut guard = ConfirmationGuard::new(
+            Confirmation::DoublePress(200.millis()),
+            Debouncer::new(5.millis()),
+        );
+        assert!(!run(&mut guard, true, 50));
+        assert!(!run(&mut guard, false, 300));
+        assert!(!run(&mut guard, true, 50));
+        assert!(!run(&mut guard, false, 100));
+        assert!(run(&mut guard, true, 10));
+        assert!(!run(&mut guard, false, 10));
+    }
+
+    #[test]
+    fn bounce_does_not_count_as_double_press() {
+        let mut guard = ConfirmationGuard::new(
+            Confirmation::DoublePress(200.millis()),
+            Debouncer::new(5.millis()),
+        );
+        for _ in 0..20 {
+            assert!(!run(&mut guard, true, 1));
+            assert!(!run(&mut guard, false, 1));
+        }
+    }
+
+    #[test]
+    fn power_usages() {
+        assert!(consumer_needs_confirmation(Consumer::Power));
+        assert!(!consumer_needs_confirmation(Consumer::PlayPause));
+        assert!(desktop_needs_confirmation(Desktop::SystemSleep));
+    }
+}
@@ -0,0 +1,280 @@
+//! Consumer control with a usage list chosen at runtime, e.g. for macropads
+//!
+//! The report descriptor is generated from a list of up to [`MAX_USAGES`] usages, and the report
+//! is a bitset with one bit per usage, in list order. The list can be loaded from flash at start
+//! up, so the usages a macropad sends can be reconfigured by a host tool without reflashing. The
+//! host only reads the descriptor when the device enumerates, so the device has to be rebuilt
+//! and reconnected for a new list to take effect.
+//!
+//! ```rust
+//! # use xous_usb_hid::device::consumer::usage_list::*;
+//! # use xous_usb_hid::device::consumer::ConsumerCode;
+//! # use xous_usb_hid::page::Consumer;
+//! // e.g. read from flash
+//! let usages = [Consumer::PlayPause.into(), Consumer::Mute.into(), ConsumerCode(0x0199)];
+//!
+//! let descriptor = ConsumerUsageListDescriptor::new(&usages).unwrap();
+//! let config = ConsumerUsageListConfig::for_descriptor(&descriptor);
+//! ```
+
+use crate::device::consumer::ConsumerCode;
+use crate::usb_class::prelude::*;
+use fugit::{ExtU32, MillisDurationU32};
+use usb_device::bus::UsbBus;
+use usb_device::class_prelude::UsbBusAllocator;
+
+/// Most usages a [`ConsumerUsageList`] can report
+pub const MAX_USAGES: usize = 32;
+
+const HEADER_LEN: usize = 14;
+const MAX_DESCRIPTOR_LEN: usize = HEADER_LEN + 3 * MAX_USAGES + 9;
+
+/// Generated report descriptor for a list of consumer usages
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ConsumerUsageListDescriptor {
+    bytes: [u8; MAX_DESCRIPTOR_LEN],
+    len: usize,
+    usages: [ConsumerCode; MAX_USAGES],
+    count: usize,
+}
+
+impl ConsumerUsageListDescriptor {
+    /// Descriptor reporting `usages`, fails with [`UsbHidError::SerializationError`] if the list
+    /// is empty, longer than [`MAX_USAGES`] or contains a usage twice
+    pub fn new(usages: &[ConsumerCode]) -> Result<Self, UsbHidError> {
+        if usages.is_empty()
+            || usages.len() > MAX_USAGES
+            || usages
+                .iter()
+                .enumerate()
+                .any(|(i, u)| usages[..i].contains(u))
+        {
+            error!("Unsupported consumer usage list");
+            return Err(UsbHidError::SerializationError);
+        }
+        let count = usages.len();
+        #[allow(clippy::cast_possible_truncation)]
+        let (report_count, padding) = (count as u8, (count.div_ceil(8) * 8 - count) as u8);
+
+        let mut descriptor = Self {
+            bytes: [0; MAX_DESCRIPTOR_LEN],
+            len: 0,
+            usages: [ConsumerCode::default(); MAX_USAGES],
+            count,
+        };
+        descriptor.usages[..count].copy_from_slice(usages);
+
+        #[rustfmt::skip]
+        descriptor.extend(&[
+            0x05, 0x0C, // Usage Page (Consumer),
+            0x09, 0x01, // Usage (Consumer Control),
+            0xA1, 0x01, // Collection (Application),
+            0x15, 0x00, //   Logical Minimum (0),
+            0x25, 0x01, //   Logical Maximum (1),
+            0x75, 0x01, //   Report Size (1),
+            0x95, report_count, //   Report Count (usages),
+        ]);
+        for usage in usages {
+            let [low, high] = usage.0.to_le_bytes();
+            descriptor.extend(&[0x0A, low, high]); //   Usage (usage),
+        }
+        descriptor.extend(&[0x81, 0x02]); //   Input (Data, Variable, Absolute),
+        if padding > 0 {
+            #[rustfmt::skip]
+            descriptor.extend(&[
+                0x75, padding, //   Report Size (padding),
+                0x95, 0x01, //   Report Count (1),
+                0x81, 0x03, //   Input (Constant, Variable, Absolute),
+            ]);
+        }
+        descriptor.extend(&[0xC0]); // End Collection
+        Ok(descriptor)
+    }
+
+    fn extend(&mut self, items: &[u8]) {
+        self.bytes[self.len..self.len + items.len()].copy_from_slice(items);
+        self.len += items.len();
+    }
+
+    #[must_use]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..self.len]
+    }
+
+    /// Usages reported, in report order
+    #[must_use]
+    pub fn usages(&self) -> &[ConsumerCode] {
+        &self.usages[..self.count]
+    }
+
+    /// Length in bytes of the report described
+    #[must_use]
+    pub fn report_len(&self) -> usize {
+        self.count.div_ceil(8)
+    }
+
+    /// Bit of `usage` in the report, `None` if it isn't in the list
+    #[must_use]
+    pub fn bit(&self, usage: impl Into<ConsumerCode>) -> Option<usize> {
+        let usage = usage.into();
+        self.usages().iter().position(|&u| u == usage)
+    }
+}
+
+/// Consumer control reporting the usages of a [`ConsumerUsageListDescriptor`]
+pub struct ConsumerUsageList<'a, B: UsbBus> {
+    interface: Interface<'a, B, InBytes8, OutNone, ReportSingle>,
+    descriptor: &'a ConsumerUsageListDescriptor,
+    state: u32,
+}
+
+impl<B: UsbBus> ConsumerUsageList<'_, B> {
+    /// Assert a usage, returns [`UsbHidError::SerializationError`] if it isn't in the list
+    pub fn press(&mut self, usage: impl Into<ConsumerCode>) -> Result<(), UsbHidError> {
+        self.set(usage.into(), true)
+    }
+
+    /// Release a usage, returns [`UsbHidError::SerializationError`] if it isn't in the list
+    pub fn release(&mut self, usage: impl Into<ConsumerCode>) -> Result<(), UsbHidError> {
+        self.set(usage.into(), false)
+    }
+
+    /// Release all usages
+    pub fn release_all(&mut self) -> Result<(), UsbHidError> {
+        self.state = 0;
+        self.flush()
+    }
+
+    #[must_use]
+    pub fn is_pressed(&self, usage: impl Into<ConsumerCode>) -> bool {
+        self.descriptor
+            .bit(usage)
+            .is_some_and(|bit| self.state & (1 << bit) != 0)
+    }
+
+    /// Send the asserted usages, e.g. to retry after [`UsbHidError::WouldBlock`]
+    pub fn flush(&mut self) -> Result<(), UsbHidError> {
+        let data = self.state.to_le_bytes();
+        self.interface
+            .write_report(&data[..self.descriptor.report_len()])
+            .map(|_| ())
+            .map_err(UsbHidError::from)
+    }
+
+    fn set(&mut self, usage: ConsumerCode, pressed: bool) -> Result<(), UsbHidError> {
+        let bit = self.descriptor.bit(usage).ok_or_else(|| {
+            warn!("Consumer usage {:x} not in the usage list", usage.0);
+            UsbHidError::SerializationError
+        })?;
+        if pressed {
+            self.state |= 1 << bit;
+        } else {
+            self.state &= !(1 << bit);
+        }
+        self.flush()
+    }
+}
+
+impl<'a, B: UsbBus> DeviceClass<'a> for ConsumerUsageList<'a, B> {
+    type I = Interface<'a, B, InBytes8, OutNone, ReportSingle>;
+
+    fn interface(&mut self) -> &mut Self::I {
+        &mut self.interface
+    }
+
+    fn reset(&mut self) {
+        self.state = 0;
+    }
+
+    fn tick(&mut self) -> Result<(), UsbHidError> {
+        Ok(())
+    }
+
+    fn next_deadline(&mut self) -> Option<MillisDurationU32> {
+        None
+    }
+}
+
+pub struct ConsumerUsageListConfig<'a> {
+    interface: InterfaceConfig<'a, InBytes8, OutNone, ReportSingle>,
+    descriptor: &'a ConsumerUsageListDescriptor,
+}
+
+impl<'a> ConsumerUsageListConfig<'a> {
+    /// `interface` must use the report descriptor of `descriptor`
+    #[must_use]
+    pub fn new(
+        interface: InterfaceConfig<'a, InBytes8, OutNone, ReportSingle>,
+        descriptor: &'a ConsumerUsageListDescriptor,
+    ) -> Self {
+        Self {
+            interface,
+            descriptor,
+        }
+    }
+
+    /// Default configuration for a generated descriptor
+    #[must_use]
+    pub fn for_descriptor(descriptor: &'a ConsumerUsageListDescriptor) -> Self {
+        Self::new(
+            unwrap!(unwrap!(InterfaceBuilder::new(descriptor.as_bytes()))
+                .description("Consumer Control")
+                .in_endpoint(50.millis()))
+            .without_out_endpoint()
+            .build(),
+            descriptor,
+        )
+    }
+}
+
+impl<'a, B: UsbBus + 'a> UsbAllocatable<'a, B> for ConsumerUsageListConfig<'a> {
+    type Allocated = ConsumerUsageList<'a, B>;
+
+    fn allocate(self, usb_alloc: &'a UsbBusAllocator<B>) -> Self::Allocated {
+        Self::Allocated {
+            interface: Interface::new(usb_alloc, self.interface),
+            descriptor: self.descriptor,
+            state: 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+    use crate::page::Consumer;
+    use std::vec::Vec;
+
+    #[test]
+    fn descriptor_from_usage_list() {
+        let usages = [
+            ConsumerCode::from(Consumer::PlayPause),
+            ConsumerCode::from(Consumer::Mute),
+            ConsumerCode(0x0199),
+        ];
+        let descriptor = ConsumerUsageListDescriptor::new(&usages).unwrap();
+        assert_eq!(descriptor.report_len(), 1);
+        assert_eq!(descriptor.bit(Consumer::Mute), Some(1));
+        assert_eq!(descriptor.bit(Consumer::Stop), None);
+        assert_eq!(descriptor.usages(), usages);
+        assert_eq!(
+            &descriptor.as_bytes()[12..],
+            [
+                0x95, 0x03, 0x0A, 0xCD, 0x00, 0x0A, 0xE2, 0x00, 0x0A, 0x99, 0x01, 0x81, 0x02, 0x75,
+                0x05, 0x95, 0x01, 0x81, 0x03, 0xC0
+            ]
+        );
+
+        let full: Vec<_> = (1..=32).map(ConsumerCode).collect();
+        let descriptor = ConsumerUsageListDescriptor::new(&full).unwrap();
+        assert_eq!(descriptor.report_len(), 4);
+        assert_eq!(descriptor.as_bytes().len(), MAX_DESCRIPTOR_LEN - 6);
+
+        assert!(ConsumerUsageListDescriptor::new(&[]).is_err());
+        assert!(ConsumerUsageListDescriptor::new(&[ConsumerCode(1); 2]).is_err());
+        let too_many: Vec<_> = (1..=33).map(ConsumerCode).collect();
+        assert!(ConsumerUsageListDescriptor::new(&too_many).is_err());
+    }
+}
@@ -1,9 +1,13 @@
 //! HID FIDO Universal 2nd Factor (U2F)
 use crate::usb_class::prelude::*;
-use fugit::ExtU32;
+use fugit::{ExtU32, MillisDurationU32};
 use usb_device::bus::UsbBus;
 use usb_device::class_prelude::UsbBusAllocator;
 
+pub mod transport;
+
+use transport::{ChannelTimeouts, DEFAULT_MESSAGE_TIMEOUT};
+
 /// Raw FIDO report descriptor.
 /// 
 /// See the [FIDO U2F HID Protocol Specification](https://fidoalliance.org/specs/fido-u2f-v1.2-ps-20170411/fido-u2f-hid-protocol-v1.2-ps-20170411.html)
@@ -28,11 +32,18 @@ pub const FIDO_REPORT_DESCRIPTOR: &[u8] = &[
     0xC0,       // End Collection
 ];
 
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 #[repr(C, align(8))]
 pub struct RawFidoReport {
     pub packet: [u8; 64],
 }
+impl RawFidoReport {
+    #[must_use]
+    pub const fn new(packet: [u8; 64]) -> Self {
+        Self { packet }
+    }
+}
 impl Default for RawFidoReport {
     fn default() -> Self {
         Self { packet: [0u8; 64] }
@@ -41,6 +52,7 @@ impl Default for RawFidoReport {
 
 pub struct RawFido<'a, B: UsbBus> {
     interface: Interface<'a, B, InBytes64, OutBytes64, ReportSingle>,
+    timeouts: ChannelTimeouts,
 }
 
 impl<'a, B: UsbBus> RawFido<'a, B> {
@@ -66,27 +78,39 @@ impl<'a, B: UsbBus> DeviceClass<'a> for RawFido<'a, B> {
         &mut self.interface
     }
 
-    fn reset(&mut self) {}
+    fn reset(&mut self) {
+        self.timeouts.reset();
+    }
 
     fn tick(&mut self) -> Result<(), UsbHidError> {
+        self.timeouts.tick();
         Ok(())
     }
+
+    fn next_deadline(&mut self) -> Option<MillisDurationU32> {
+        self.timeouts.next_deadline()
+    }
+
+    fn advance(&mut self, elapsed: MillisDurationU32) {
+        self.timeouts.advance(elapsed);
+    }
 }
 
 pub struct RawFidoConfig<'a> {
     interface: InterfaceConfig<'a, InBytes64, OutBytes64, ReportSingle>,
+    message_timeout: MillisDurationU32,
 }
 
 impl<'a> Default for RawFidoConfig<'a> {
     #[must_use]
     fn default() -> Self {
         Self::new(
-            unwrap!(
-                unwrap!(unwrap!(InterfaceBuilder::new(FIDO_REPORT_DESCRIPTOR))
+            unwrap!(unwrap!(
+                unwrap!(InterfaceBuilder::new_static(FIDO_REPORT_DESCRIPTOR))
                     .description("U2F Token")
-                    .in_endpoint(5.millis()))
-                .with_out_endpoint(5.millis())
+                    .in_endpoint(5.millis())
             )
+            .with_out_endpoint(5.millis()))
             .build(),
         )
     }
@@ -95,7 +119,18 @@ impl<'a> Default for RawFidoConfig<'a> {
 impl<'a> RawFidoConfig<'a> {
     #[must_use]
     pub fn new(interface: InterfaceConfig<'a, InBytes64, OutBytes64, ReportSingle>) -> Self {
-        Self { interface }
+        Self {
+            interface,
+            message_timeout: DEFAULT_MESSAGE_TIMEOUT,
+        }
+    }
+
+    /// Time allowed for the host to send all packets of a request message, see
+    /// [`FidoTransport::poll_timeout`](transport::FidoTransport::poll_timeout)
+    #[must_use]
+    pub fn message_timeout(mut self, timeout: MillisDurationU32) -> Self {
+        self.message_timeout = timeout;
+        self
     }
 }
 
@@ -105,6 +140,7 @@ impl<'a, B: UsbBus + 'a> UsbAllocatable<'a, B> for RawFidoConfig<'a> {
     fn allocate(self, usb_alloc: &'a UsbBusAllocator<B>) -> Self::Allocated {
         Self::Allocated {
             interface: Interface::new(usb_alloc, self.interface),
+            timeouts: ChannelTimeouts::new(self.message_timeout),
         }
     }
 }
@@ -0,0 +1,322 @@
+//! CTAPHID frame transport for authenticator stacks
+//!
+//! [`FidoTransport`] is the interface an authenticator stack, such as the Xous vault U2F
+//! service, needs from a USB transport: request frames are polled as they arrive, response
+//! frames are written back, and channels whose request message stalls part way through are
+//! reported so they can be answered with [`ERR_MSG_TIMEOUT`]. [`RawFido`] implements it, and
+//! [`ChannelTimeouts`] is available for other transports.
+//!
+//! Every frame is a [`RawFidoReport`], either an initialization packet or a continuation packet:
+//!
+//! | Byte   | Initialization packet             | Continuation packet      |
+//! |--------|-----------------------------------|--------------------------|
+//! | 0..4   | Channel ID, big endian            | Channel ID, big endian   |
+//! | 4      | Command, with bit 7 set           | Sequence number, 0..0x7F |
+//! | 5..7   | Message length, big endian        | Data                     |
+//! | 7..64  | Data                              | Data                     |
+//!
+//! ```rust
+//! # use xous_usb_hid::device::fido::transport::*;
+//! # use xous_usb_hid::device::fido::RawFidoReport;
+//! # use xous_usb_hid::UsbHidError;
+//! fn service<T: FidoTransport>(transport: &mut T) -> Result<(), UsbHidError> {
+//!     while let Some(frame) = transport.poll_request()? {
+//!         // hand the frame to the authenticator stack
+//!     }
+//!     if let Some(cid) = transport.poll_timeout() {
+//!         transport.send_response(&RawFidoReport::error(cid, ERR_MSG_TIMEOUT))?;
+//!     }
+//!     Ok(())
+//! }
+//! ```
+//!
+//! [`RawFido`] tracks timeouts in [`DeviceClass::tick`](crate::usb_class::prelude::DeviceClass::tick),
+//! so `UsbHidClass::tick()` must be called every 1ms.
+
+use super::{RawFido, RawFidoReport};
+use crate::protocol::fido::{
+    OFFSET_CID, OFFSET_COMMAND, OFFSET_CONT_DATA, OFFSET_INIT_DATA, OFFSET_LEN,
+};
+use crate::UsbHidError;
+use fugit::{ExtU32, MillisDurationU32};
+use heapless::Vec;
+use usb_device::bus::UsbBus;
+use usb_device::UsbError;
+
+pub use crate::protocol::fido::{BROADCAST_CID, CMD_ERROR, ERR_MSG_TIMEOUT};
+
+/// Most channels with a request message in progress tracked by [`ChannelTimeouts`]
+pub const MAX_CHANNELS: usize = 8;
+
+/// Default time allowed for the host to complete a request message
+pub const DEFAULT_MESSAGE_TIMEOUT: MillisDurationU32 = MillisDurationU32::from_ticks(500);
+
+impl RawFidoReport {
+    /// Initialization packet carrying the first part of a message of `len` bytes
+    ///
+    /// `data` beyond the capacity of the packet is ignored
+    #[must_use]
+    pub fn init(cid: u32, command: u8, len: u16, data: &[u8]) -> Self {
+        let mut report = Self::default();
+        report.packet[OFFSET_CID..OFFSET_COMMAND].copy_from_slice(&cid.to_be_bytes());
+        report.packet[OFFSET_COMMAND] = command | 0x80;
+        report.packet[OFFSET_LEN..OFFSET_INIT_DATA].copy_from_slice(&len.to_be_bytes());
+        let n = data.len().min(report.packet.len() - OFFSET_INIT_DATA);
+        report.packet[OFFSET_INIT_DATA..OFFSET_INIT_DATA + n].copy_from_slice(&data[..n]);
+        report
+    }
+
+    /// Continuation packet with sequence number `seq`
+    ///
+    /// `data` beyond the capacity of the packet is ignored
+    #[must_use]
+    pub fn continuation(cid: u32, seq: u8, data: &[u8]) -> Self {
+        let mut report = Self::default();
+        report.packet[OFFSET_CID..OFFSET_COMMAND].copy_from_slice(&cid.to_be_bytes());
+        report.packet[OFFSET_COMMAND] = seq & 0x7F;
+        let n = data.len().min(report.packet.len() - OFFSET_CONT_DATA);
+        report.packet[OFFSET_CONT_DATA..OFFSET_CONT_DATA + n].copy_from_slice(&data[..n]);
+        report
+    }
+
+    /// `CTAPHID_ERROR` response on channel `cid`
+    #[must_use]
+    pub fn error(cid: u32, code: u8) -> Self {
+        Self::init(cid, CMD_ERROR, 1, &[code])
+    }
+
+    #[must_use]
+    pub fn cid(&self) -> u32 {
+        let [b0, b1, b2, b3, ..] = self.packet;
+        u32::from_be_bytes([b0, b1, b2, b3])
+    }
+
+    /// Command without bit 7, `None` for continuation packets
+    #[must_use]
+    pub fn command(&self) -> Option<u8> {
+        let command = self.packet[OFFSET_COMMAND];
+        (command & 0x80 != 0).then_some(command & 0x7F)
+    }
+
+    /// Sequence number, `None` for initialization packets
+    #[must_use]
+    pub fn seq(&self) -> Option<u8> {
+        let seq = self.packet[OFFSET_COMMAND];
+        (seq & 0x80 == 0).then_some(seq)
+    }
+
+    /// Length of the whole message, `None` for continuation packets
+    #[must_use]
+    pub fn message_len(&self) -> Option<u16> {
+        self.command()?;
+        Some(u16::from_be_bytes([
+            self.packet[OFFSET_LEN],
+            self.packet[OFFSET_LEN + 1],
+        ]))
+    }
+
+    /// Message data carried by the packet, including any zero padding after the end of the
+    /// message
+    #[must_use]
+    pub fn data(&self) -> &[u8] {
+        if self.command().is_some() {
+            &self.packet[OFFSET_INIT_DATA..]
+        } else {
+            &self.packet[OFFSET_CONT_DATA..]
+        }
+    }
+}
+
+/// Frame level access to a FIDO interface, see the [module docs](self)
+pub trait FidoTransport {
+    /// Next request frame received from the host, `None` if there isn't one
+    fn poll_request(&mut self) -> Result<Option<RawFidoReport>, UsbHidError>;
+    /// Send a response frame. Fails with [`UsbHidError::WouldBlock`] while the previous frame is
+    /// still waiting to be read by the host
+    fn send_response(&mut self, frame: &RawFidoReport) -> Result<(), UsbHidError>;
+    /// A channel whose request message wasn't completed within the timeout. Every expired
+    /// channel is returned once, and further continuation packets on it are not tracked
+    fn poll_timeout(&mut self) -> Option<u32>;
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct PendingMessage {
+    cid: u32,
+    remaining: u16,
+    elapsed: MillisDurationU32,
+}
+
+/// Tracks request messages in progress per channel, see the [module docs](self)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ChannelTimeouts {
+    timeout: MillisDurationU32,
+    pending: Vec<PendingMessage, MAX_CHANNELS>,
+}
+
+impl ChannelTimeouts {
+    /// Expire messages not completed within `timeout` of their initialization packet
+    #[must_use]
+    pub fn new(timeout: MillisDurationU32) -> Self {
+        Self {
+            timeout,
+            pending: Vec::new(),
+        }
+    }
+
+    /// Follow a request frame received from the host
+    pub fn received(&mut self, frame: &RawFidoReport) {
+        let cid = frame.cid();
+        if let Some(len) = frame.message_len() {
+            self.pending.retain(|p| p.cid != cid);
+            let carried = u16::try_from(frame.data().len()).unwrap_or(u16::MAX);
+            let remaining = len.saturating_sub(carried);
+            if remaining == 0 {
+                return;
+            }
+            let message = PendingMessage {
+                cid,
+                remaining,
+                elapsed: 0.millis(),
+            };
+            if self.pending.push(message).is_err() {
+                warn!("Too many FIDO channels, not tracking {:x}", cid);
+            }
+        } else if let Some(i) = self.pending.iter().position(|p| p.cid == cid) {
+            let carried = u16::try_from(frame.data().len()).unwrap_or(u16::MAX);
+            let message = &mut self.pending[i];
+            message.remaining = message.remaining.saturating_sub(carried);
+            if message.remaining == 0 {
+                self.pending.swap_remove(i);
+            }
+        }
+    }
+
+    /// `true` if channel `cid` has a request message in progress
+    #[must_use]
+    pub fn is_pending(&self, cid: u32) -> bool {
+        self.pending.iter().any(|p| p.cid == cid)
+    }
+
+    /// Call every 1ms
+    pub fn tick(&mut self) {
+        for message in &mut self.pending {
+            if message.elapsed < self.timeout {
+                message.elapsed += 1.millis();
+            }
+        }
+    }
+
+    /// Count `elapsed` ticks skipped by
+    /// [`UsbHidClass::tick_until`](crate::usb_class::UsbHidClass::tick_until)
+    pub fn advance(&mut self, elapsed: MillisDurationU32) {
+        for message in &mut self.pending {
+            if message.elapsed < self.timeout {
+                message.elapsed = (message.elapsed + elapsed).min(self.timeout);
+            }
+        }
+    }
+
+    /// Time until the next message times out, `None` if no message is in progress
+    #[must_use]
+    pub fn next_deadline(&self) -> Option<MillisDurationU32> {
+        self.pending
+            .iter()
+            .filter_map(|p| self.timeout.checked_sub(p.elapsed))
+            .filter(|t| t.ticks() > 0)
+            .min()
+    }
+
+    /// A channel whose message has timed out, it is no longer tracked
+    pub fn expired(&mut self) -> Option<u32> {
+        let i = self
+            .pending
+            .iter()
+            .position(|p| p.elapsed >= self.timeout)?;
+        let cid = self.pending.swap_remove(i).cid;
+        warn!("FIDO message on channel {:x} timed out", cid);
+        Some(cid)
+    }
+
+    /// Forget all messages in progress, e.g. after a bus reset
+    pub fn reset(&mut self) {
+        self.pending.clear();
+    }
+}
+
+impl<B: UsbBus> FidoTransport for RawFido<'_, B> {
+    fn poll_request(&mut self) -> Result<Option<RawFidoReport>, UsbHidError> {
+        match self.read_report() {
+            Ok(frame) => {
+                self.timeouts.received(&frame);
+                Ok(Some(frame))
+            }
+            Err(UsbError::WouldBlock) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    fn send_response(&mut self, frame: &RawFidoReport) -> Result<(), UsbHidError> {
+        self.write_report(frame)
+    }
+
+    fn poll_timeout(&mut self) -> Option<u32> {
+        self.timeouts.expired()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn frame_fields() {
+        let init = RawFidoReport::init(0x0102_0304, 0x03, 100, &[0xAA; 64]);
+        assert_eq!(&init.packet[..8], [1, 2, 3, 4, 0x83, 0, 100, 0xAA]);
+        assert_eq!(init.cid(), 0x0102_0304);
+        assert_eq!(init.command(), Some(0x03));
+        assert_eq!(init.message_len(), Some(100));
+        assert_eq!(init.seq(), None);
+        assert_eq!(init.data().len(), 57);
+
+        let cont = RawFidoReport::continuation(0x0102_0304, 0, &[0xBB]);
+        assert_eq!(cont.command(), None);
+        assert_eq!(cont.seq(), Some(0));
+        assert_eq!(cont.message_len(), None);
+        assert_eq!(cont.data().len(), 59);
+
+        let error = RawFidoReport::error(BROADCAST_CID, ERR_MSG_TIMEOUT);
+        assert_eq!(
+            &error.packet[..8],
+            [0xFF, 0xFF, 0xFF, 0xFF, 0xBF, 0, 1, 0x05]
+        );
+    }
+
+    #[test]
+    fn channels_time_out_independently() {
+        let mut timeouts = ChannelTimeouts::new(3.millis());
+
+        // Fits in a single packet, nothing to wait for
+        timeouts.received(&RawFidoReport::init(1, 0x03, 57, &[]));
+        assert!(!timeouts.is_pending(1));
+
+        timeouts.received(&RawFidoReport::init(1, 0x03, 100, &[]));
+        timeouts.tick();
+        timeouts.received(&RawFidoReport::init(2, 0x03, 200, &[]));
+        assert!(timeouts.is_pending(1));
+
+        // Channel 1 completes with its first continuation packet
+        timeouts.received(&RawFidoReport::continuation(1, 0, &[]));
+        assert!(!timeouts.is_pending(1));
+
+        timeouts.tick();
+        timeouts.tick();
+        assert_eq!(timeouts.expired(), None);
+        timeouts.tick();
+        assert_eq!(timeouts.expired(), Some(2));
+        assert_eq!(timeouts.expired(), None);
+
+        // Continuation packets on an expired channel are ignored
+        timeouts.received(&RawFidoReport::continuation(2, 1, &[]));
+        assert!(!timeouts.is_pending(2));
+    }
+}
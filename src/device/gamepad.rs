@@ -0,0 +1,583 @@
+//!HID gamepads
+use crate::usb_class::prelude::*;
+use core::default::Default;
+use fugit::{ExtU32, MillisDurationU32};
+use usb_device::bus::UsbBus;
+use usb_device::class_prelude::UsbBusAllocator;
+use usb_device::UsbError;
+
+pub mod autofire;
+
+/// Report ID of the legacy, `DirectInput` friendly, report of [`DualReportGamepad`]
+pub const LEGACY_GAMEPAD_REPORT_ID: u8 = 0x01;
+/// Report ID of the modern report of [`DualReportGamepad`]
+pub const MODERN_GAMEPAD_REPORT_ID: u8 = 0x02;
+
+/// Dual report gamepad descriptor - two top level collections describing the same state
+///
+/// * Report ID 1, Joystick: X, Y, Z, Rz unsigned 8 bit axes, 12 buttons and a hat switch (7 bytes)
+/// * Report ID 2, Gamepad: X, Y, Z, Rz signed 8 bit axes, brake and accelerator triggers,
+///   16 buttons and a hat switch (10 bytes)
+#[rustfmt::skip]
+pub const DUAL_REPORT_GAMEPAD_DESCRIPTOR: &[u8] = &[
+    0x05, 0x01,       // Usage Page (Generic Desktop),
+    0x09, 0x04,       // Usage (Joystick),
+    0xA1, 0x01,       // Collection (Application),
+    0x85, 0x01,       //   Report ID (1),
+    0x09, 0x01,       //   Usage (Pointer),
+    0xA1, 0x00,       //   Collection (Physical),
+    0x09, 0x30,       //     Usage (X),
+    0x09, 0x31,       //     Usage (Y),
+    0x09, 0x32,       //     Usage (Z),
+    0x09, 0x35,       //     Usage (Rz),
+    0x15, 0x00,       //     Logical Minimum (0),
+    0x26, 0xFF, 0x00, //     Logical Maximum (255),
+    0x75, 0x08,       //     Report Size (8),
+    0x95, 0x04,       //     Report Count (4),
+    0x81, 0x02,       //     Input (Data, Variable, Absolute),
+    0xC0,             //   End Collection,
+    0x05, 0x09,       //   Usage Page (Button),
+    0x19, 0x01,       //   Usage Minimum (1),
+    0x29, 0x0C,       //   Usage Maximum (12),
+    0x15, 0x00,       //   Logical Minimum (0),
+    0x25, 0x01,       //   Logical Maximum (1),
+    0x75, 0x01,       //   Report Size (1),
+    0x95, 0x0C,       //   Report Count (12),
+    0x81, 0x02,       //   Input (Data, Variable, Absolute),
+    0x05, 0x01,       //   Usage Page (Generic Desktop),
+    0x09, 0x39,       //   Usage (Hat Switch),
+    0x15, 0x00,       //   Logical Minimum (0),
+    0x25, 0x07,       //   Logical Maximum (7),
+    0x35, 0x00,       //   Physical Minimum (0),
+    0x46, 0x3B, 0x01, //   Physical Maximum (315),
+    0x65, 0x14,       //   Unit (Degrees),
+    0x75, 0x04,       //   Report Size (4),
+    0x95, 0x01,       //   Report Count (1),
+    0x81, 0x42,       //   Input (Data, Variable, Absolute, Null State),
+    0x65, 0x00,       //   Unit (None),
+    0xC0,             // End Collection,
+    0x05, 0x01,       // Usage Page (Generic Desktop),
+    0x09, 0x05,       // Usage (Gamepad),
+    0xA1, 0x01,       // Collection (Application),
+    0x85, 0x02,       //   Report ID (2),
+    0x09, 0x30,       //   Usage (X),
+    0x09, 0x31,       //   Usage (Y),
+    0x09, 0x32,       //   Usage (Z),
+    0x09, 0x35,       //   Usage (Rz),
+    0x15, 0x81,       //   Logical Minimum (-127),
+    0x25, 0x7F,       //   Logical Maximum (127),
+    0x75, 0x08,       //   Report Size (8),
+    0x95, 0x04,       //   Report Count (4),
+    0x81, 0x02,       //   Input (Data, Variable, Absolute),
+    0x05, 0x02,       //   Usage Page (Simulation Controls),
+    0x09, 0xC5,       //   Usage (Brake),
+    0x09, 0xC4,       //   Usage (Accelerator),
+    0x15, 0x00,       //   Logical Minimum (0),
+    0x26, 0xFF, 0x00, //   Logical Maximum (255),
+    0x75, 0x08,       //   Report Size (8),
+    0x95, 0x02,       //   Report Count (2),
+    0x81, 0x02,       //   Input (Data, Variable, Absolute),
+    0x05, 0x09,       //   Usage Page (Button),
+    0x19, 0x01,       //   Usage Minimum (1),
+    0x29, 0x10,       //   Usage Maximum (16),
+    0x15, 0x00,       //   Logical Minimum (0),
+    0x25, 0x01,       //   Logical Maximum (1),
+    0x75, 0x01,       //   Report Size (1),
+    0x95, 0x10,       //   Report Count (16),
+    0x81, 0x02,       //   Input (Data, Variable, Absolute),
+    0x05, 0x01,       //   Usage Page (Generic Desktop),
+    0x09, 0x39,       //   Usage (Hat Switch),
+    0x15, 0x00,       //   Logical Minimum (0),
+    0x25, 0x07,       //   Logical Maximum (7),
+    0x35, 0x00,       //   Physical Minimum (0),
+    0x46, 0x3B, 0x01, //   Physical Maximum (315),
+    0x65, 0x14,       //   Unit (Degrees),
+    0x75, 0x04,       //   Report Size (4),
+    0x95, 0x01,       //   Report Count (1),
+    0x81, 0x42,       //   Input (Data, Variable, Absolute, Null State),
+    0x65, 0x00,       //   Unit (None),
+    0x75, 0x04,       //   Report Size (4),
+    0x95, 0x01,       //   Report Count (1),
+    0x81, 0x03,       //   Input (Constant, Variable, Absolute),
+    0xC0,             // End Collection
+];
+
+/// Hat switch / D-pad position, clockwise from up
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Default, Hash)]
+#[repr(u8)]
+pub enum HatSwitch {
+    Up = 0,
+    UpRight = 1,
+    Right = 2,
+    DownRight = 3,
+    Down = 4,
+    DownLeft = 5,
+    Left = 6,
+    UpLeft = 7,
+    /// Centered, reported as the out of range null state
+    #[default]
+    Centered = 8,
+}
+
+impl HatSwitch {
+    /// Hat position from the state of four D-pad buttons, opposing directions cancel out
+    #[must_use]
+    #[allow(clippy::fn_params_excessive_bools)]
+    pub fn from_buttons(up: bool, right: bool, down: bool, left: bool) -> Self {
+        match (up && !down, right && !left, down && !up, left && !right) {
+            (true, false, _, false) => Self::Up,
+            (true, true, _, _) => Self::UpRight,
+            (false, true, false, _) => Self::Right,
+            (_, true, true, _) => Self::DownRight,
+            (_, false, true, false) => Self::Down,
+            (_, _, true, true) => Self::DownLeft,
+            (false, _, false, true) => Self::Left,
+            (true, _, _, true) => Self::UpLeft,
+            _ => Self::Centered,
+        }
+    }
+}
+
+/// State of a gamepad, independent of the report layout used to send it
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Default, Hash)]
+pub struct GamepadState {
+    /// Button `n` is bit `n - 1`
+    pub buttons: u16,
+    pub left_x: i8,
+    pub left_y: i8,
+    pub right_x: i8,
+    pub right_y: i8,
+    pub left_trigger: u8,
+    pub right_trigger: u8,
+    pub hat: HatSwitch,
+}
+
+impl GamepadState {
+    /// Legacy report, including the report ID: unsigned axes, the first 12 buttons, no triggers
+    #[must_use]
+    pub fn legacy_report(&self) -> [u8; 7] {
+        let [buttons_low, buttons_high] = self.buttons.to_le_bytes();
+        [
+            LEGACY_GAMEPAD_REPORT_ID,
+            unsigned_axis(self.left_x),
+            unsigned_axis(self.left_y),
+            unsigned_axis(self.right_x),
+            unsigned_axis(self.right_y),
+            buttons_low,
+            (buttons_high & 0x0F) | (self.hat as u8) << 4,
+        ]
+    }
+
+    /// Modern report, including the report ID
+    #[must_use]
+    pub fn modern_report(&self) -> [u8; 10] {
+        let [buttons_low, buttons_high] = self.buttons.to_le_bytes();
+        [
+            MODERN_GAMEPAD_REPORT_ID,
+            self.left_x.to_le_bytes()[0],
+            self.left_y.to_le_bytes()[0],
+            self.right_x.to_le_bytes()[0],
+            self.right_y.to_le_bytes()[0],
+            self.left_trigger,
+            self.right_trigger,
+            buttons_low,
+            buttons_high,
+            self.hat as u8,
+        ]
+    }
+}
+
+/// Map a signed axis centred on 0 onto an unsigned axis centred on 128
+fn unsigned_axis(v: i8) -> u8 {
+    v.to_le_bytes()[0] ^ 0x80
+}
+
+/// Gamepad reporting the same state with two report layouts, see [`DUAL_REPORT_GAMEPAD_DESCRIPTOR`]
+///
+/// Hosts and games that only understand one of the layouts pick the collection they recognise.
+///
+/// **Note:** If the endpoint is busy the second report is sent later by
+/// [`UsbHidClass::tick()`], which must be called every 1ms.
+pub struct DualReportGamepad<'a, B: UsbBus> {
+    interface: Interface<'a, B, InBytes16, OutNone, Reports8>,
+    state: GamepadState,
+    legacy_pending: bool,
+    modern_pending: bool,
+}
+
+impl<B: UsbBus> DualReportGamepad<'_, B> {
+    /// Update the gamepad state, queuing both reports
+    ///
+    /// Returns [`UsbHidError::WouldBlock`] if either report could not be sent yet, it will be sent
+    /// by a later call to `write_report` or `tick`
+    pub fn write_report(&mut self, state: &GamepadState) -> Result<(), UsbHidError> {
+        self.state = *state;
+        self.legacy_pending = true;
+        self.modern_pending = true;
+        self.flush()
+    }
+
+    #[must_use]
+    pub fn state(&self) -> &GamepadState {
+        &self.state
+    }
+
+    fn flush(&mut self) -> Result<(), UsbHidError> {
+        if self.legacy_pending {
+            Self::write(&mut self.interface, &self.state.legacy_report())?;
+            self.legacy_pending = false;
+        }
+        if self.modern_pending {
+            Self::write(&mut self.interface, &self.state.modern_report())?;
+            self.modern_pending = false;
+        }
+        Ok(())
+    }
+
+    fn write(
+        interface: &mut Interface<'_, B, InBytes16, OutNone, Reports8>,
+        data: &[u8],
+    ) -> Result<(), UsbHidError> {
+        match interface.write_report(data) {
+            Ok(_) => Ok(()),
+            Err(UsbError::WouldBlock) => Err(UsbHidError::WouldBlock),
+            Err(e) => Err(UsbHidError::UsbError(e)),
+        }
+    }
+}
+
+impl<'a, B: UsbBus> DeviceClass<'a> for DualReportGamepad<'a, B> {
+    type I = Interface<'a, B, InBytes16, OutNone, Reports8>;
+
+    fn interface(&mut self) -> &mut Self::I {
+        &mut self.interface
+    }
+
+    fn reset(&mut self) {
+        self.legacy_pending = false;
+        self.modern_pending = false;
+    }
+
+    fn tick(&mut self) -> Result<(), UsbHidError> {
+        match self.flush() {
+            Err(UsbHidError::WouldBlock) => Ok(()),
+            r => r,
+        }
+    }
+
+    fn next_deadline(&mut self) -> Option<MillisDurationU32> {
+        (self.legacy_pending || self.modern_pending).then_some(1.millis())
+    }
+}
+
+pub struct DualReportGamepadConfig<'a> {
+    interface: InterfaceConfig<'a, InBytes16, OutNone, Reports8>,
+}
+
+impl Default for DualReportGamepadConfig<'_> {
+    fn default() -> Self {
+        Self::new(
+            unwrap!(
+                unwrap!(InterfaceBuilder::new_static(DUAL_REPORT_GAMEPAD_DESCRIPTOR))
+                    .boot_device(InterfaceProtocol::None)
+                    .description("Gamepad")
+                    .in_endpoint(10.millis())
+            )
+            .without_out_endpoint()
+            .build(),
+        )
+    }
+}
+
+impl<'a> DualReportGamepadConfig<'a> {
+    #[must_use]
+    pub fn new(interface: InterfaceConfig<'a, InBytes16, OutNone, Reports8>) -> Self {
+        Self { interface }
+    }
+}
+
+impl<'a, B: UsbBus + 'a> UsbAllocatable<'a, B> for DualReportGamepadConfig<'a> {
+    type Allocated = DualReportGamepad<'a, B>;
+
+    fn allocate(self, usb_alloc: &'a UsbBusAllocator<B>) -> Self::Allocated {
+        Self::Allocated {
+            interface: Interface::new(usb_alloc, self.interface),
+            state: GamepadState::default(),
+            legacy_pending: false,
+            modern_pending: false,
+        }
+    }
+}
+
+/// Gamepad with 16 buttons, two sticks and a D-pad, reporting [`GamepadReport`]
+#[rustfmt::skip]
+pub const GAMEPAD_REPORT_DESCRIPTOR: &[u8] = &[
+    0x05, 0x01,       // Usage Page (Generic Desktop),
+    0x09, 0x05,       // Usage (Gamepad),
+    0xA1, 0x01,       // Collection (Application),
+    0x05, 0x09,       //   Usage Page (Button),
+    0x19, 0x01,       //   Usage Minimum (1),
+    0x29, 0x10,       //   Usage Maximum (16),
+    0x15, 0x00,       //   Logical Minimum (0),
+    0x25, 0x01,       //   Logical Maximum (1),
+    0x75, 0x01,       //   Report Size (1),
+    0x95, 0x10,       //   Report Count (16),
+    0x81, 0x02,       //   Input (Data, Variable, Absolute),
+    0x05, 0x01,       //   Usage Page (Generic Desktop),
+    0x09, 0x01,       //   Usage (Pointer),
+    0xA1, 0x00,       //   Collection (Physical),
+    0x09, 0x30,       //     Usage (X),
+    0x09, 0x31,       //     Usage (Y),
+    0x09, 0x32,       //     Usage (Z),
+    0x09, 0x35,       //     Usage (Rz),
+    0x15, 0x81,       //     Logical Minimum (-127),
+    0x25, 0x7F,       //     Logical Maximum (127),
+    0x75, 0x08,       //     Report Size (8),
+    0x95, 0x04,       //     Report Count (4),
+    0x81, 0x02,       //     Input (Data, Variable, Absolute),
+    0xC0,             //   End Collection,
+    0x09, 0x39,       //   Usage (Hat Switch),
+    0x15, 0x00,       //   Logical Minimum (0),
+    0x25, 0x07,       //   Logical Maximum (7),
+    0x35, 0x00,       //   Physical Minimum (0),
+    0x46, 0x3B, 0x01, //   Physical Maximum (315),
+    0x65, 0x14,       //   Unit (Degrees),
+    0x75, 0x04,       //   Report Size (4),
+    0x95, 0x01,       //   Report Count (1),
+    0x81, 0x42,       //   Input (Data, Variable, Absolute, Null State),
+    0x65, 0x00,       //   Unit (None),
+    0x75, 0x04,       //   Report Size (4),
+    0x95, 0x01,       //   Report Count (1),
+    0x81, 0x03,       //   Input (Constant, Variable, Absolute),
+    0xC0,             // End Collection
+];
+
+/// Report of a [`Gamepad`]
+///
+/// The left stick is reported as X and Y and the right stick as Z and Rz, the mapping most hosts
+/// and games expect from a generic gamepad
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Default, Hash)]
+pub struct GamepadReport {
+    /// Button `n` is bit `n - 1`
+    pub buttons: u16,
+    pub left_x: i8,
+    pub left_y: i8,
+    pub right_x: i8,
+    pub right_y: i8,
+    pub hat: HatSwitch,
+}
+
+impl GamepadReport {
+    /// Centred report with no buttons pressed
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            buttons: 0,
+            left_x: 0,
+            left_y: 0,
+            right_x: 0,
+            right_y: 0,
+            hat: HatSwitch::Centered,
+        }
+    }
+
+    /// Button `n` is bit `n - 1`
+    #[must_use]
+    pub const fn buttons(mut self, buttons: u16) -> Self {
+        self.buttons = buttons;
+        self
+    }
+
+    /// Press or release button `n`, 1 to 16, other buttons are ignored
+    #[must_use]
+    pub const fn button(mut self, n: u8, pressed: bool) -> Self {
+        if n >= 1 && n <= 16 {
+            let mask = 1 << (n - 1);
+            if pressed {
+                self.buttons |= mask;
+            } else {
+                self.buttons &= !mask;
+            }
+        }
+        self
+    }
+
+    #[must_use]
+    pub const fn left_stick(mut self, x: i8, y: i8) -> Self {
+        self.left_x = x;
+        self.left_y = y;
+        self
+    }
+
+    #[must_use]
+    pub const fn right_stick(mut self, x: i8, y: i8) -> Self {
+        self.right_x = x;
+        self.right_y = y;
+        self
+    }
+
+    /// D-pad position, see [`HatSwitch::from_buttons`]
+    #[must_use]
+    pub const fn hat(mut self, hat: HatSwitch) -> Self {
+        self.hat = hat;
+        self
+    }
+
+    /// Report as sent to the host
+    #[must_use]
+    pub fn to_bytes(&self) -> [u8; 7] {
+        let [buttons_low, buttons_high] = self.buttons.to_le_bytes();
+        [
+            buttons_low,
+            buttons_high,
+            self.left_x.to_le_bytes()[0],
+            self.left_y.to_le_bytes()[0],
+            self.right_x.to_le_bytes()[0],
+            self.right_y.to_le_bytes()[0],
+            self.hat as u8,
+        ]
+    }
+}
+
+/// Gamepad with 16 buttons, two sticks and a D-pad, see [`GAMEPAD_REPORT_DESCRIPTOR`]
+pub struct Gamepad<'a, B: UsbBus> {
+    interface: Interface<'a, B, InBytes8, OutNone, ReportSingle>,
+}
+
+impl<B: UsbBus> Gamepad<'_, B> {
+    pub fn write_report(&mut self, report: &GamepadReport) -> Result<(), UsbHidError> {
+        self.interface
+            .write_report(&report.to_bytes())
+            .map(|_| ())
+            .map_err(UsbHidError::from)
+    }
+}
+
+impl<'a, B: UsbBus> DeviceClass<'a> for Gamepad<'a, B> {
+    type I = Interface<'a, B, InBytes8, OutNone, ReportSingle>;
+
+    fn interface(&mut self) -> &mut Self::I {
+        &mut self.interface
+    }
+
+    fn reset(&mut self) {}
+
+    fn tick(&mut self) -> Result<(), UsbHidError> {
+        Ok(())
+    }
+
+    fn next_deadline(&mut self) -> Option<MillisDurationU32> {
+        None
+    }
+}
+
+pub struct GamepadConfig<'a> {
+    interface: InterfaceConfig<'a, InBytes8, OutNone, ReportSingle>,
+}
+
+impl Default for GamepadConfig<'_> {
+    fn default() -> Self {
+        Self::new(
+            unwrap!(
+                unwrap!(InterfaceBuilder::new_static(GAMEPAD_REPORT_DESCRIPTOR))
+                    .boot_device(InterfaceProtocol::None)
+                    .description("Gamepad")
+                    .in_endpoint(10.millis())
+            )
+            .without_out_endpoint()
+            .build(),
+        )
+    }
+}
+
+impl<'a> GamepadConfig<'a> {
+    #[must_use]
+    pub fn new(interface: InterfaceConfig<'a, InBytes8, OutNone, ReportSingle>) -> Self {
+        Self { interface }
+    }
+}
+
+impl<'a, B: UsbBus + 'a> UsbAllocatable<'a, B> for GamepadConfig<'a> {
+    type Allocated = Gamepad<'a, B>;
+
+    fn allocate(self, usb_alloc: &'a UsbBusAllocator<B>) -> Self::Allocated {
+        Self::Allocated {
+            interface: Interface::new(usb_alloc, self.interface),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn both_layouts_encode_the_same_state() {
+        let state = GamepadState {
+            buttons: 0b1001_0000_0000_0101,
+            left_x: -127,
+            left_y: 0,
+            right_x: 127,
+            right_y: -1,
+            left_trigger: 0x10,
+            right_trigger: 0xFF,
+            hat: HatSwitch::Left,
+        };
+
+        assert_eq!(
+            state.legacy_report(),
+            [0x01, 0x01, 0x80, 0xFF, 0x7F, 0x05, 0x60]
+        );
+        assert_eq!(
+            state.modern_report(),
+            [0x02, 0x81, 0x00, 0x7F, 0xFF, 0x10, 0xFF, 0x05, 0x90, 0x06]
+        );
+    }
+
+    #[test]
+    fn centered_hat_is_null() {
+        let state = GamepadState::default();
+        assert_eq!(state.legacy_report()[6], 0x80);
+        assert_eq!(state.modern_report()[9], 0x08);
+    }
+
+    #[test]
+    fn gamepad_report_builder() {
+        let report = GamepadReport::new()
+            .button(1, true)
+            .button(16, true)
+            .button(17, true)
+            .left_stick(-127, 0)
+            .right_stick(127, -1)
+            .hat(HatSwitch::Left);
+        assert_eq!(
+            report.to_bytes(),
+            [0x01, 0x80, 0x81, 0x00, 0x7F, 0xFF, 0x06]
+        );
+        assert_eq!(report.button(1, false).buttons, 0x8000);
+        assert_eq!(GamepadReport::default().to_bytes()[6], 0x08);
+    }
+
+    #[test]
+    fn hat_from_buttons() {
+        assert_eq!(
+            HatSwitch::from_buttons(true, false, false, false),
+            HatSwitch::Up
+        );
+        assert_eq!(
+            HatSwitch::from_buttons(false, false, true, true),
+            HatSwitch::DownLeft
+        );
+        assert_eq!(
+            HatSwitch::from_buttons(true, true, true, false),
+            HatSwitch::Right
+        );
+        assert_eq!(
+            HatSwitch::from_buttons(true, true, true, true),
+            HatSwitch::Centered
+        );
+    }
+}
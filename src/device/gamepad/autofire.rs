@@ -0,0 +1,465 @@
+//! Turbo buttons for gamepads and fight sticks
+//!
+//! [`Autofire`] repeatedly presses and releases the buttons it is enabled for while they are
+//! held, at a rate and duty cycle given by [`AutofireSettings`]. [`AutofireGamepad`] applies it to
+//! a [`Gamepad`](super::Gamepad) style report from `tick`, and exposes the settings as a vendor
+//! defined feature report so that a host tool can configure them. The settings can also be
+//! changed on the device, e.g. with a turbo button held while pressing the button to toggle.
+//!
+//! The feature report, without a report ID, is [`AUTOFIRE_REPORT_LEN`] bytes:
+//!
+//! | Byte | Content                                                          |
+//! |------|------------------------------------------------------------------|
+//! | 0..2 | Autofire buttons, button `n` is bit `n - 1`, little endian       |
+//! | 2    | Rate, 1 to [`MAX_RATE_HZ`] presses per second                    |
+//! | 3    | Duty cycle, 1 to 99 percent of the period the button is pressed  |
+//!
+//! ```rust
+//! # use xous_usb_hid::device::gamepad::autofire::*;
+//! let mut autofire = Autofire::new(AutofireSettings::new(0b0001, 25, 50));
+//!
+//! // on every scan, gamepad.write_report(&report) with the buttons held
+//! assert_eq!(autofire.apply(0b0011), 0b0011);
+//!
+//! // every 1ms, from AutofireGamepad::tick
+//! for _ in 0..20 {
+//!     autofire.tick();
+//! }
+//! assert_eq!(autofire.apply(0b0011), 0b0010);
+//! ```
+
+use crate::device::gamepad::GamepadReport;
+use crate::interface::InterfaceClass;
+use crate::usb_class::prelude::*;
+use fugit::{ExtU32, MillisDurationU32};
+use usb_device::bus::UsbBus;
+use usb_device::class_prelude::UsbBusAllocator;
+
+use crate::protocol::autofire::{
+    MAX_DUTY_PERCENT, OFFSET_BUTTONS, OFFSET_DUTY, OFFSET_RATE, REPORT_ID,
+};
+pub use crate::protocol::autofire::{MAX_RATE_HZ, REPORT_LEN as AUTOFIRE_REPORT_LEN};
+
+/// [`GAMEPAD_REPORT_DESCRIPTOR`](super::GAMEPAD_REPORT_DESCRIPTOR) with a vendor defined feature
+/// report of [`AUTOFIRE_REPORT_LEN`] bytes for the autofire settings
+#[rustfmt::skip]
+pub const AUTOFIRE_GAMEPAD_REPORT_DESCRIPTOR: &[u8] = &[
+    0x05, 0x01,                   // Usage Page (Generic Desktop),
+    0x09, 0x05,                   // Usage (Gamepad),
+    0xA1, 0x01,                   // Collection (Application),
+    0x05, 0x09,                   //   Usage Page (Button),
+    0x19, 0x01,                   //   Usage Minimum (1),
+    0x29, 0x10,                   //   Usage Maximum (16),
+    0x15, 0x00,                   //   Logical Minimum (0),
+    0x25, 0x01,                   //   Logical Maximum (1),
+    0x75, 0x01,                   //   Report Size (1),
+    0x95, 0x10,                   //   Report Count (16),
+    0x81, 0x02,                   //   Input (Data, Variable, Absolute),
+    0x05, 0x01,                   //   Usage Page (Generic Desktop),
+    0x09, 0x01,                   //   Usage (Pointer),
+    0xA1, 0x00,                   //   Collection (Physical),
+    0x09, 0x30,                   //     Usage (X),
+    0x09, 0x31,                   //     Usage (Y),
+    0x09, 0x32,                   //     Usage (Z),
+    0x09, 0x35,                   //     Usage (Rz),
+    0x15, 0x81,                   //     Logical Minimum (-127),
+    0x25, 0x7F,                   //     Logical Maximum (127),
+    0x75, 0x08,                   //     Report Size (8),
+    0x95, 0x04,                   //     Report Count (4),
+    0x81, 0x02,                   //     Input (Data, Variable, Absolute),
+    0xC0,                         //   End Collection,
+    0x09, 0x39,                   //   Usage (Hat Switch),
+    0x15, 0x00,                   //   Logical Minimum (0),
+    0x25, 0x07,                   //   Logical Maximum (7),
+    0x35, 0x00,                   //   Physical Minimum (0),
+    0x46, 0x3B, 0x01,             //   Physical Maximum (315),
+    0x65, 0x14,                   //   Unit (Degrees),
+    0x75, 0x04,                   //   Report Size (4),
+    0x95, 0x01,                   //   Report Count (1),
+    0x81, 0x42,                   //   Input (Data, Variable, Absolute, Null State),
+    0x65, 0x00,                   //   Unit (None),
+    0x45, 0x00,                   //   Physical Maximum (0),
+    0x75, 0x04,                   //   Report Size (4),
+    0x95, 0x01,                   //   Report Count (1),
+    0x81, 0x03,                   //   Input (Constant, Variable, Absolute),
+    0x06, 0x00, 0xFF,             //   Usage Page (Vendor Defined 0xFF00),
+    0x09, 0x01,                   //   Usage (0x01),
+    0x15, 0x00,                   //   Logical Minimum (0),
+    0x27, 0xFF, 0xFF, 0x00, 0x00, //   Logical Maximum (65535),
+    0x75, 0x10,                   //   Report Size (16),
+    0xB1, 0x02,                   //   Feature (Data, Variable, Absolute), ;Autofire buttons
+    0x09, 0x02,                   //   Usage (0x02),
+    0x15, 0x01,                   //   Logical Minimum (1),
+    0x25, 0x32,                   //   Logical Maximum (50),
+    0x75, 0x08,                   //   Report Size (8),
+    0xB1, 0x02,                   //   Feature (Data, Variable, Absolute), ;Rate
+    0x09, 0x03,                   //   Usage (0x03),
+    0x25, 0x63,                   //   Logical Maximum (99),
+    0xB1, 0x02,                   //   Feature (Data, Variable, Absolute), ;Duty cycle
+    0xC0,                         // End Collection
+];
+
+/// Which buttons autofire and how fast
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct AutofireSettings {
+    /// Button `n` is bit `n - 1`
+    pub buttons: u16,
+    /// 1 to [`MAX_RATE_HZ`] presses per second
+    pub rate_hz: u8,
+    /// 1 to 99 percent of the period the button is pressed
+    pub duty_percent: u8,
+}
+
+impl Default for AutofireSettings {
+    /// No autofire buttons, 10 presses per second with a 50% duty cycle
+    fn default() -> Self {
+        Self::new(0, 10, 50)
+    }
+}
+
+impl AutofireSettings {
+    /// Settings with the rate and duty cycle clamped to their range
+    #[must_use]
+    pub fn new(buttons: u16, rate_hz: u8, duty_percent: u8) -> Self {
+        Self {
+            buttons,
+            rate_hz: rate_hz.clamp(1, MAX_RATE_HZ),
+            duty_percent: duty_percent.clamp(1, MAX_DUTY_PERCENT),
+        }
+    }
+
+    /// Enable or disable autofire for button `n`, 1 to 16, other buttons are ignored
+    #[must_use]
+    pub const fn button(mut self, n: u8, enabled: bool) -> Self {
+        if n >= 1 && n <= 16 {
+            let mask = 1 << (n - 1);
+            if enabled {
+                self.buttons |= mask;
+            } else {
+                self.buttons &= !mask;
+            }
+        }
+        self
+    }
+
+    /// Feature report, see the [module docs](self)
+    #[must_use]
+    pub fn to_bytes(&self) -> [u8; AUTOFIRE_REPORT_LEN] {
+        let mut data = [0; AUTOFIRE_REPORT_LEN];
+        data[OFFSET_BUTTONS..OFFSET_BUTTONS + 2].copy_from_slice(&self.buttons.to_le_bytes());
+        data[OFFSET_RATE] = self.rate_hz;
+        data[OFFSET_DUTY] = self.duty_percent;
+        data
+    }
+
+    /// Decode a feature report, `None` if it has the wrong length or a value out of range
+    #[must_use]
+    pub fn from_bytes(data: &[u8]) -> Option<Self> {
+        if data.len() != AUTOFIRE_REPORT_LEN {
+            return None;
+        }
+        let buttons = u16::from_le_bytes([data[OFFSET_BUTTONS], data[OFFSET_BUTTONS + 1]]);
+        let (rate_hz, duty_percent) = (data[OFFSET_RATE], data[OFFSET_DUTY]);
+        let settings = Self::new(buttons, rate_hz, duty_percent);
+        (settings.rate_hz == rate_hz && settings.duty_percent == duty_percent).then_some(settings)
+    }
+
+    fn period_ms(self) -> u32 {
+        1000 / u32::from(self.rate_hz)
+    }
+
+    /// Time pressed in every period, at least 1ms pressed and 1ms released
+    fn pressed_ms(self) -> u32 {
+        let period = self.period_ms();
+        (period * u32::from(self.duty_percent) / 100).clamp(1, period - 1)
+    }
+}
+
+/// Autofire engine, see the [module docs](self)
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Default)]
+pub struct Autofire {
+    settings: AutofireSettings,
+    held: u16,
+    phase_ms: u32,
+}
+
+impl Autofire {
+    #[must_use]
+    pub const fn new(settings: AutofireSettings) -> Self {
+        Self {
+            settings,
+            held: 0,
+            phase_ms: 0,
+        }
+    }
+
+    #[must_use]
+    pub fn settings(&self) -> AutofireSettings {
+        self.settings
+    }
+
+    /// Change the settings, restarting the period
+    pub fn set_settings(&mut self, settings: AutofireSettings) {
+        self.settings = settings;
+        self.phase_ms = 0;
+    }
+
+    /// Enable or disable autofire for button `n`, e.g. from a turbo button on the device
+    pub fn toggle(&mut self, n: u8) {
+        let enabled = self.is_enabled(n);
+        self.set_settings(self.settings.button(n, !enabled));
+    }
+
+    #[must_use]
+    pub fn is_enabled(&self, n: u8) -> bool {
+        (1..=16).contains(&n) && self.settings.buttons & 1 << (n - 1) != 0
+    }
+
+    /// Buttons to report for the buttons `held`, button `n` is bit `n - 1`
+    ///
+    /// Autofire buttons are pressed as soon as they are held, and released for the rest of the
+    /// period once the duty cycle has passed
+    pub fn apply(&mut self, held: u16) -> u16 {
+        if self.firing(held) == 0 {
+            self.phase_ms = 0;
+        }
+        self.held = held;
+        if self.phase_ms < self.settings.pressed_ms() {
+            held
+        } else {
+            held & !self.firing(held)
+        }
+    }
+
+    /// Must be called every 1ms, returns `true` if autofire buttons are held and the buttons
+    /// reported by [`Autofire::apply`] changed
+    pub fn tick(&mut self) -> bool {
+        if self.firing(self.held) == 0 {
+            return false;
+        }
+        self.phase_ms = (self.phase_ms + 1) % self.settings.period_ms();
+        self.phase_ms == 0 || self.phase_ms == self.settings.pressed_ms()
+    }
+
+    fn firing(&self, held: u16) -> u16 {
+        held & self.settings.buttons
+    }
+}
+
+/// Gamepad with autofire buttons, see [`AUTOFIRE_GAMEPAD_REPORT_DESCRIPTOR`]
+///
+/// **Note:** Autofire presses and releases are sent by [`UsbHidClass::tick()`], which must be
+/// called every 1ms, or by [`UsbHidClass::tick_until()`].
+pub struct AutofireGamepad<'a, B: UsbBus> {
+    interface: Interface<'a, B, InBytes8, OutNone, ReportSingle>,
+    autofire: Autofire,
+    report: GamepadReport,
+    pending: bool,
+}
+
+impl<B: UsbBus> AutofireGamepad<'_, B> {
+    /// Update the buttons held and stick positions
+    ///
+    /// Returns [`UsbHidError::WouldBlock`] if the report could not be sent yet, it will be sent by
+    /// a later call to `write_report` or `tick`
+    pub fn write_report(&mut self, report: &GamepadReport) -> Result<(), UsbHidError> {
+        self.sync_settings();
+        self.report = *report;
+        self.pending = true;
+        self.flush()
+    }
+
+    #[must_use]
+    pub fn settings(&self) -> AutofireSettings {
+        self.autofire.settings()
+    }
+
+    /// Change the autofire settings on the device, the host reads them back from the feature
+    /// report
+    pub fn set_settings(&mut self, settings: AutofireSettings) {
+        self.autofire.set_settings(settings);
+        self.store_settings();
+    }
+
+    /// Enable or disable autofire for button `n`
+    pub fn toggle(&mut self, n: u8) {
+        self.autofire.toggle(n);
+        self.store_settings();
+    }
+
+    fn store_settings(&mut self) {
+        let settings = self.autofire.settings().to_bytes();
+        self.interface.set_feature_report(REPORT_ID, &settings);
+        self.pending = true;
+    }
+
+    /// Settings in the feature report, `None` if the host has written invalid ones
+    fn host_settings(&self) -> Option<AutofireSettings> {
+        self.interface
+            .feature_report_with_id(REPORT_ID)
+            .and_then(AutofireSettings::from_bytes)
+    }
+
+    fn sync_settings(&mut self) {
+        if let Some(settings) = self
+            .host_settings()
+            .filter(|s| *s != self.autofire.settings())
+        {
+            debug!("Autofire buttons set to {:X} by the host", settings.buttons);
+            self.autofire.set_settings(settings);
+            self.pending = true;
+        }
+    }
+
+    fn flush(&mut self) -> Result<(), UsbHidError> {
+        if !self.pending {
+            return Ok(());
+        }
+        let report = self
+            .report
+            .buttons(self.autofire.apply(self.report.buttons));
+        self.interface
+            .write_report(&report.to_bytes())
+            .map_err(UsbHidError::from)?;
+        self.pending = false;
+        Ok(())
+    }
+}
+
+impl<'a, B: UsbBus> DeviceClass<'a> for AutofireGamepad<'a, B> {
+    type I = Interface<'a, B, InBytes8, OutNone, ReportSingle>;
+
+    fn interface(&mut self) -> &mut Self::I {
+        &mut self.interface
+    }
+
+    /// Keeps the autofire settings, the interface has just reset the feature report to its
+    /// initial value
+    fn reset(&mut self) {
+        self.store_settings();
+        self.pending = false;
+    }
+
+    fn tick(&mut self) -> Result<(), UsbHidError> {
+        self.sync_settings();
+        self.pending |= self.autofire.tick();
+        match self.flush() {
+            Err(UsbHidError::WouldBlock) => Ok(()),
+            r => r,
+        }
+    }
+
+    fn next_deadline(&mut self) -> Option<MillisDurationU32> {
+        // Settings set by the host are only taken in by `tick` and `write_report`
+        if self
+            .host_settings()
+            .is_some_and(|s| s != self.autofire.settings())
+        {
+            return Some(1.millis());
+        }
+        let firing = self.autofire.firing(self.autofire.held) != 0;
+        (self.pending || firing).then_some(1.millis())
+    }
+}
+
+pub struct AutofireGamepadConfig<'a> {
+    interface: InterfaceConfig<'a, InBytes8, OutNone, ReportSingle>,
+    settings: AutofireSettings,
+}
+
+impl Default for AutofireGamepadConfig<'_> {
+    fn default() -> Self {
+        Self::new(
+            unwrap!(unwrap!(unwrap!(InterfaceBuilder::new_static(
+                AUTOFIRE_GAMEPAD_REPORT_DESCRIPTOR
+            ))
+            .boot_device(InterfaceProtocol::None)
+            .description("Gamepad")
+            .feature_report(REPORT_ID, &AutofireSettings::default().to_bytes()))
+            .in_endpoint(10.millis()))
+            .without_out_endpoint()
+            .build(),
+        )
+    }
+}
+
+impl<'a> AutofireGamepadConfig<'a> {
+    /// `interface` must keep feature report 0, see
+    /// [`InterfaceBuilder::feature_report`](crate::interface::InterfaceBuilder::feature_report)
+    #[must_use]
+    pub fn new(interface: InterfaceConfig<'a, InBytes8, OutNone, ReportSingle>) -> Self {
+        Self {
+            interface,
+            settings: AutofireSettings::default(),
+        }
+    }
+
+    /// Initial settings, e.g. loaded from flash
+    #[must_use]
+    pub fn settings(mut self, settings: AutofireSettings) -> Self {
+        self.settings = settings;
+        self
+    }
+}
+
+impl<'a, B: UsbBus + 'a> UsbAllocatable<'a, B> for AutofireGamepadConfig<'a> {
+    type Allocated = AutofireGamepad<'a, B>;
+
+    fn allocate(self, usb_alloc: &'a UsbBusAllocator<B>) -> Self::Allocated {
+        let mut gamepad = Self::Allocated {
+            interface: Interface::new(usb_alloc, self.interface),
+            autofire: Autofire::new(self.settings),
+            report: GamepadReport::new(),
+            pending: false,
+        };
+        gamepad.store_settings();
+        gamepad.pending = false;
+        gamepad
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn autofire_follows_rate_and_duty_cycle() {
+        // 40ms period, pressed for 10ms
+        let mut autofire = Autofire::new(AutofireSettings::new(0b0101, 25, 25));
+        assert_eq!(autofire.apply(0b0010), 0b0010);
+        assert!(!autofire.tick());
+
+        assert_eq!(autofire.apply(0b0111), 0b0111);
+        let changes: [usize; 3] =
+            core::array::from_fn(|_| (1..=40).find(|_| autofire.tick()).unwrap_or_default());
+        assert_eq!(changes, [10, 30, 10]);
+        assert_eq!(autofire.apply(0b0111), 0b0010);
+
+        // Released and held again, the period restarts pressed
+        assert_eq!(autofire.apply(0b0010), 0b0010);
+        assert_eq!(autofire.apply(0b0100), 0b0100);
+
+        autofire.toggle(3);
+        assert!(!autofire.is_enabled(3));
+        assert_eq!(autofire.settings().buttons, 0b0001);
+    }
+
+    #[test]
+    fn settings_feature_report() {
+        let settings = AutofireSettings::default().button(16, true).button(1, true);
+        let bytes = settings.to_bytes();
+        assert_eq!(bytes, [0x01, 0x80, 10, 50]);
+        assert_eq!(AutofireSettings::from_bytes(&bytes), Some(settings));
+
+        assert_eq!(AutofireSettings::from_bytes(&[0x01, 0x80, 51, 50]), None);
+        assert_eq!(AutofireSettings::from_bytes(&[0x01, 0x80, 10, 0]), None);
+        assert_eq!(AutofireSettings::from_bytes(&bytes[..3]), None);
+        assert_eq!(AutofireSettings::new(0, 0, 100).to_bytes(), [0, 0, 1, 99]);
+
+        // 50 presses per second at 99%: still released for 1ms
+        let fast = AutofireSettings::new(1, MAX_RATE_HZ, 99);
+        assert_eq!((fast.period_ms(), fast.pressed_ms()), (20, 19));
+    }
+}
@@ -0,0 +1,344 @@
+//! Telephony headsets for unified communication applications
+//!
+//! [`Headset`] reports the hook switch, phone mute, flash and redial buttons of the Telephony
+//! page, and receives the off-hook, mute and ring LEDs of the LED page from the host.
+//!
+//! The host drives the call state: it sets the ring LED for an incoming call, and the off-hook
+//! LED once a call is active. Answering or hanging up on the device changes the hook switch,
+//! and calls started or ended on the host change the off-hook LED, which the hook switch then
+//! follows so that both sides agree. Mute is toggled by the host when the mute button is
+//! pressed, the mute LED tells the device the current state.
+//!
+//! ```rust,no_run
+//! # use xous_usb_hid::device::headset::*;
+//! # use usb_device::bus::UsbBus;
+//! # fn answer<B: UsbBus>(headset: &mut Headset<'_, B>) {
+//! // after every usb_dev.poll()
+//! if let Ok(leds) = headset.read_report() {
+//!     // ring while leds.ring, light the mute LED while leds.mute
+//! }
+//!
+//! // answer button pressed
+//! if headset.leds().ring {
+//!     headset.set_off_hook(true).ok();
+//! }
+//! # }
+//! ```
+
+use crate::usb_class::prelude::*;
+use fugit::{ExtU32, MillisDurationU32};
+use usb_device::bus::UsbBus;
+use usb_device::class_prelude::UsbBusAllocator;
+use usb_device::UsbError;
+
+/// Report ID of the button input report
+pub const HEADSET_REPORT_ID: u8 = 0x01;
+/// Report ID of the LED output report
+pub const HEADSET_LEDS_REPORT_ID: u8 = 0x02;
+
+/// Headset descriptor
+///
+/// * Report ID 1, Headset: Hook Switch bit, Phone Mute, Flash and Redial one-shot bits (2 bytes)
+/// * Report ID 2, LEDs: Off-Hook, Mute and Ring bits, output (2 bytes)
+#[rustfmt::skip]
+pub const HEADSET_REPORT_DESCRIPTOR: &[u8] = &[
+    0x05, 0x0B, // Usage Page (Telephony),
+    0x09, 0x05, // Usage (Headset),
+    0xA1, 0x01, // Collection (Application),
+    0x85, 0x01, //   Report ID (1),
+    0x15, 0x00, //   Logical Minimum (0),
+    0x25, 0x01, //   Logical Maximum (1),
+    0x75, 0x01, //   Report Size (1),
+    0x95, 0x01, //   Report Count (1),
+    0x09, 0x20, //   Usage (Hook Switch),
+    0x81, 0x22, //   Input (Data, Variable, Absolute, No Preferred State),
+    0x09, 0x2F, //   Usage (Phone Mute),
+    0x09, 0x21, //   Usage (Flash),
+    0x09, 0x24, //   Usage (Redial),
+    0x95, 0x03, //   Report Count (3),
+    0x81, 0x06, //   Input (Data, Variable, Relative),
+    0x95, 0x04, //   Report Count (4),
+    0x81, 0x03, //   Input (Constant, Variable, Absolute),
+    0x85, 0x02, //   Report ID (2),
+    0x05, 0x08, //   Usage Page (LEDs),
+    0x09, 0x17, //   Usage (Off-Hook),
+    0x09, 0x09, //   Usage (Mute),
+    0x09, 0x18, //   Usage (Ring),
+    0x95, 0x03, //   Report Count (3),
+    0x91, 0x22, //   Output (Data, Variable, Absolute, No Preferred State),
+    0x95, 0x05, //   Report Count (5),
+    0x91, 0x03, //   Output (Constant, Variable, Absolute),
+    0xC0,       // End Collection
+];
+
+/// Buttons of a headset
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Default, Hash)]
+pub struct HeadsetReport {
+    /// Off hook, a call is active
+    pub hook_switch: bool,
+    /// Toggle mute, pressed for a single report
+    pub phone_mute: bool,
+    /// Hold, swap or merge calls, pressed for a single report
+    pub flash: bool,
+    /// Call the last number again, pressed for a single report
+    pub redial: bool,
+}
+
+impl HeadsetReport {
+    /// Report as sent to the host
+    #[must_use]
+    pub fn to_bytes(&self) -> [u8; 2] {
+        let buttons = u8::from(self.hook_switch)
+            | u8::from(self.phone_mute) << 1
+            | u8::from(self.flash) << 2
+            | u8::from(self.redial) << 3;
+        [HEADSET_REPORT_ID, buttons]
+    }
+}
+
+/// Headset LEDs set by the host
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Default, Hash)]
+pub struct HeadsetLeds {
+    /// A call is active
+    pub off_hook: bool,
+    /// The microphone is muted
+    pub mute: bool,
+    /// A call is coming in
+    pub ring: bool,
+}
+
+impl HeadsetLeds {
+    /// Decode an LED output report, including the report ID
+    pub fn from_bytes(data: &[u8]) -> usb_device::Result<Self> {
+        match *data {
+            [HEADSET_LEDS_REPORT_ID, leds] => Ok(Self {
+                off_hook: leds & 0b001 != 0,
+                mute: leds & 0b010 != 0,
+                ring: leds & 0b100 != 0,
+            }),
+            _ => Err(UsbError::ParseError),
+        }
+    }
+}
+
+/// Headset, see the [module docs](self)
+///
+/// **Note:** Button releases are sent by [`UsbHidClass::tick()`], which must be called every
+/// 1ms.
+pub struct Headset<'a, B: UsbBus> {
+    interface: Interface<'a, B, InBytes8, OutBytes8, Reports8>,
+    report: HeadsetReport,
+    leds: HeadsetLeds,
+    pending: bool,
+    release: bool,
+}
+
+impl<B: UsbBus> Headset<'_, B> {
+    /// Answer or start a call with `true`, hang up with `false`
+    ///
+    /// Returns [`UsbHidError::WouldBlock`] if the report could not be sent yet, it will be sent
+    /// by `tick`
+    pub fn set_off_hook(&mut self, off_hook: bool) -> Result<(), UsbHidError> {
+        self.update(|r| r.hook_switch = off_hook);
+        self.flush()
+    }
+
+    /// Press the mute button, the host toggles mute and updates the mute LED
+    pub fn toggle_mute(&mut self) -> Result<(), UsbHidError> {
+        self.update(|r| r.phone_mute = true);
+        self.flush()
+    }
+
+    /// Press the flash button
+    pub fn flash(&mut self) -> Result<(), UsbHidError> {
+        self.update(|r| r.flash = true);
+        self.flush()
+    }
+
+    /// Press the redial button
+    pub fn redial(&mut self) -> Result<(), UsbHidError> {
+        self.update(|r| r.redial = true);
+        self.flush()
+    }
+
+    /// `true` while a call is active, as set by the device or the host
+    #[must_use]
+    pub fn is_off_hook(&self) -> bool {
+        self.report.hook_switch
+    }
+
+    /// LEDs last set by the host
+    #[must_use]
+    pub fn leds(&self) -> HeadsetLeds {
+        self.leds
+    }
+
+    /// Read an LED report from the host. The hook switch follows the off-hook LED, so that calls
+    /// started or ended on the host are reported back as such
+    pub fn read_report(&mut self) -> usb_device::Result<HeadsetLeds> {
+        let mut data = [0; 8];
+        let n = self.interface.read_report(&mut data)?;
+        let leds = HeadsetLeds::from_bytes(&data[..n])?;
+        if leds.off_hook != self.leds.off_hook && leds.off_hook != self.report.hook_switch {
+            debug!("Hook switch set to {} by the host", leds.off_hook);
+            self.update(|r| r.hook_switch = leds.off_hook);
+        }
+        self.leds = leds;
+        Ok(leds)
+    }
+
+    /// Change the report to send, one-shot buttons already sent are released by it
+    fn update(&mut self, change: impl FnOnce(&mut HeadsetReport)) {
+        if self.release {
+            self.release_one_shots();
+        }
+        change(&mut self.report);
+        self.pending = true;
+    }
+
+    fn release_one_shots(&mut self) {
+        self.report = HeadsetReport {
+            hook_switch: self.report.hook_switch,
+            ..HeadsetReport::default()
+        };
+        self.release = false;
+    }
+
+    /// Send a pending report, and the release of the one-shot buttons on a later call, so that
+    /// the host reads the press before the release
+    fn flush(&mut self) -> Result<(), UsbHidError> {
+        if self.pending {
+            self.write()?;
+            self.pending = false;
+            self.release = self.report.phone_mute || self.report.flash || self.report.redial;
+        } else if self.release {
+            self.release_one_shots();
+            self.pending = true;
+            self.write()?;
+            self.pending = false;
+        }
+        Ok(())
+    }
+
+    fn write(&mut self) -> Result<(), UsbHidError> {
+        self.interface
+            .write_report(&self.report.to_bytes())
+            .map(|_| ())
+            .map_err(UsbHidError::from)
+    }
+}
+
+impl<'a, B: UsbBus> DeviceClass<'a> for Headset<'a, B> {
+    type I = Interface<'a, B, InBytes8, OutBytes8, Reports8>;
+
+    fn interface(&mut self) -> &mut Self::I {
+        &mut self.interface
+    }
+
+    fn reset(&mut self) {
+        self.report = HeadsetReport::default();
+        self.leds = HeadsetLeds::default();
+        self.pending = false;
+        self.release = false;
+    }
+
+    fn tick(&mut self) -> Result<(), UsbHidError> {
+        match self.flush() {
+            Err(UsbHidError::WouldBlock) => Ok(()),
+            r => r,
+        }
+    }
+
+    fn next_deadline(&mut self) -> Option<MillisDurationU32> {
+        (self.pending || self.release).then_some(1.millis())
+    }
+}
+
+pub struct HeadsetConfig<'a> {
+    interface: InterfaceConfig<'a, InBytes8, OutBytes8, Reports8>,
+}
+
+impl Default for HeadsetConfig<'_> {
+    fn default() -> Self {
+        Self::new(
+            unwrap!(unwrap!(
+                unwrap!(InterfaceBuilder::new_static(HEADSET_REPORT_DESCRIPTOR))
+                    .description("Headset")
+                    .in_endpoint(10.millis())
+            )
+            .with_out_endpoint(10.millis()))
+            .build(),
+        )
+    }
+}
+
+impl<'a> HeadsetConfig<'a> {
+    #[must_use]
+    pub fn new(interface: InterfaceConfig<'a, InBytes8, OutBytes8, Reports8>) -> Self {
+        Self { interface }
+    }
+}
+
+impl<'a, B: UsbBus + 'a> UsbAllocatable<'a, B> for HeadsetConfig<'a> {
+    type Allocated = Headset<'a, B>;
+
+    fn allocate(self, usb_alloc: &'a UsbBusAllocator<B>) -> Self::Allocated {
+        Self::Allocated {
+            interface: Interface::new(usb_alloc, self.interface),
+            report: HeadsetReport::default(),
+            leds: HeadsetLeds::default(),
+            pending: false,
+            release: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+    use crate::page::{Leds, Telephony};
+
+    #[test]
+    fn headset_reports() {
+        let report = HeadsetReport {
+            hook_switch: true,
+            redial: true,
+            ..Default::default()
+        };
+        assert_eq!(report.to_bytes(), [HEADSET_REPORT_ID, 0b1001]);
+
+        let leds = HeadsetLeds::from_bytes(&[HEADSET_LEDS_REPORT_ID, 0b110]).unwrap();
+        assert_eq!(
+            leds,
+            HeadsetLeds {
+                off_hook: false,
+                mute: true,
+                ring: true
+            }
+        );
+        assert!(HeadsetLeds::from_bytes(&[HEADSET_REPORT_ID, 0b110]).is_err());
+    }
+
+    #[test]
+    fn descriptor_uses_telephony_and_led_pages() {
+        let usages: [u8; 8] = [
+            Telephony::Headset.into(),
+            Telephony::HookSwitch.into(),
+            Telephony::PhoneMute.into(),
+            Telephony::Flash.into(),
+            Telephony::Redial.into(),
+            Leds::OffHook.into(),
+            Leds::Mute.into(),
+            Leds::Ring.into(),
+        ];
+        for usage in usages {
+            assert!(HEADSET_REPORT_DESCRIPTOR
+                .windows(2)
+                .any(|item| item == [0x09, usage]));
+        }
+    }
+}
@@ -1,11 +1,14 @@
 //!HID joystick
+use crate::device::gamepad::HatSwitch;
 use crate::usb_class::prelude::*;
 use core::default::Default;
-use fugit::ExtU32;
+use fugit::{ExtU32, MillisDurationU32};
 use packed_struct::prelude::*;
 use usb_device::bus::UsbBus;
 use usb_device::class_prelude::UsbBusAllocator;
 
+pub mod button_box;
+
 #[rustfmt::skip]
 pub const JOYSTICK_DESCRIPTOR: &[u8] = &[
     0x05, 0x01, // Usage Page (Generic Desktop)         5,   1
@@ -32,6 +35,7 @@ pub const JOYSTICK_DESCRIPTOR: &[u8] = &[
     0xc0,       // End Collection                       192
 ];
 
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Default, PackedStruct)]
 #[packed_struct(endian = "lsb", size_bytes = "3")]
 pub struct JoystickReport {
@@ -43,6 +47,37 @@ pub struct JoystickReport {
     pub buttons: u8,
 }
 
+impl JoystickReport {
+    /// Centred report with no buttons pressed
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            x: 0,
+            y: 0,
+            buttons: 0,
+        }
+    }
+
+    #[must_use]
+    pub const fn x(mut self, x: i8) -> Self {
+        self.x = x;
+        self
+    }
+
+    #[must_use]
+    pub const fn y(mut self, y: i8) -> Self {
+        self.y = y;
+        self
+    }
+
+    /// Button `n` is bit `n - 1`
+    #[must_use]
+    pub const fn buttons(mut self, buttons: u8) -> Self {
+        self.buttons = buttons;
+        self
+    }
+}
+
 pub struct Joystick<'a, B: UsbBus> {
     interface: Interface<'a, B, InBytes8, OutNone, ReportSingle>,
 }
@@ -72,6 +107,10 @@ impl<'a, B: UsbBus> DeviceClass<'a> for Joystick<'a, B> {
     fn tick(&mut self) -> Result<(), UsbHidError> {
         Ok(())
     }
+
+    fn next_deadline(&mut self) -> Option<MillisDurationU32> {
+        None
+    }
 }
 
 pub struct JoystickConfig<'a> {
@@ -82,7 +121,7 @@ impl<'a> Default for JoystickConfig<'a> {
     #[must_use]
     fn default() -> Self {
         Self::new(
-            unwrap!(unwrap!(InterfaceBuilder::new(JOYSTICK_DESCRIPTOR))
+            unwrap!(unwrap!(InterfaceBuilder::new_static(JOYSTICK_DESCRIPTOR))
                 .boot_device(InterfaceProtocol::None)
                 .description("Joystick")
                 .in_endpoint(10.millis()))
@@ -108,3 +147,240 @@ impl<'a, B: UsbBus + 'a> UsbAllocatable<'a, B> for JoystickConfig<'a> {
         }
     }
 }
+
+/// Joystick with X, Y, Z and Rz axes, a throttle slider, an 8-way hat switch and 32 buttons,
+/// reporting [`HatJoystickReport`]
+#[rustfmt::skip]
+pub const HAT_JOYSTICK_DESCRIPTOR: &[u8] = &[
+    0x05, 0x01,                   // Usage Page (Generic Desktop),
+    0x09, 0x04,                   // Usage (Joystick),
+    0xA1, 0x01,                   // Collection (Application),
+    0x05, 0x09,                   //   Usage Page (Button),
+    0x19, 0x01,                   //   Usage Minimum (1),
+    0x29, 0x20,                   //   Usage Maximum (32),
+    0x15, 0x00,                   //   Logical Minimum (0),
+    0x25, 0x01,                   //   Logical Maximum (1),
+    0x75, 0x01,                   //   Report Size (1),
+    0x95, 0x20,                   //   Report Count (32),
+    0x81, 0x02,                   //   Input (Data, Variable, Absolute),
+    0x05, 0x01,                   //   Usage Page (Generic Desktop),
+    0x09, 0x01,                   //   Usage (Pointer),
+    0xA1, 0x00,                   //   Collection (Physical),
+    0x09, 0x30,                   //     Usage (X),
+    0x09, 0x31,                   //     Usage (Y),
+    0x09, 0x32,                   //     Usage (Z),
+    0x09, 0x35,                   //     Usage (Rz),
+    0x16, 0x01, 0x80,             //     Logical Minimum (-32767),
+    0x26, 0xFF, 0x7F,             //     Logical Maximum (32767),
+    0x75, 0x10,                   //     Report Size (16),
+    0x95, 0x04,                   //     Report Count (4),
+    0x81, 0x02,                   //     Input (Data, Variable, Absolute),
+    0xC0,                         //   End Collection,
+    0x09, 0x36,                   //   Usage (Slider),
+    0x15, 0x00,                   //   Logical Minimum (0),
+    0x27, 0xFF, 0xFF, 0x00, 0x00, //   Logical Maximum (65535),
+    0x75, 0x10,                   //   Report Size (16),
+    0x95, 0x01,                   //   Report Count (1),
+    0x81, 0x02,                   //   Input (Data, Variable, Absolute),
+    0x09, 0x39,                   //   Usage (Hat Switch),
+    0x15, 0x00,                   //   Logical Minimum (0),
+    0x25, 0x07,                   //   Logical Maximum (7),
+    0x35, 0x00,                   //   Physical Minimum (0),
+    0x46, 0x3B, 0x01,             //   Physical Maximum (315),
+    0x65, 0x14,                   //   Unit (Degrees),
+    0x75, 0x04,                   //   Report Size (4),
+    0x95, 0x01,                   //   Report Count (1),
+    0x81, 0x42,                   //   Input (Data, Variable, Absolute, Null State),
+    0x65, 0x00,                   //   Unit (None),
+    0x75, 0x04,                   //   Report Size (4),
+    0x95, 0x01,                   //   Report Count (1),
+    0x81, 0x03,                   //   Input (Constant, Variable, Absolute),
+    0xC0,                         // End Collection
+];
+
+/// Report of a [`HatJoystick`], [`HatJoystickReport::new`] is centred with the throttle closed
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Default, Hash)]
+pub struct HatJoystickReport {
+    /// Button `n` is bit `n - 1`
+    pub buttons: u32,
+    pub x: i16,
+    pub y: i16,
+    pub z: i16,
+    pub rz: i16,
+    pub throttle: u16,
+    pub hat: HatSwitch,
+}
+
+impl HatJoystickReport {
+    /// Centred report with the throttle closed and no buttons pressed
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            buttons: 0,
+            x: 0,
+            y: 0,
+            z: 0,
+            rz: 0,
+            throttle: 0,
+            hat: HatSwitch::Centered,
+        }
+    }
+
+    /// Button `n` is bit `n - 1`
+    #[must_use]
+    pub const fn buttons(mut self, buttons: u32) -> Self {
+        self.buttons = buttons;
+        self
+    }
+
+    /// Press or release button `n`, 1 to 32, other buttons are ignored
+    #[must_use]
+    pub const fn button(mut self, n: u8, pressed: bool) -> Self {
+        if n >= 1 && n <= 32 {
+            let mask = 1 << (n - 1);
+            if pressed {
+                self.buttons |= mask;
+            } else {
+                self.buttons &= !mask;
+            }
+        }
+        self
+    }
+
+    /// Stick position, -32767 to 32767
+    #[must_use]
+    pub const fn stick(mut self, x: i16, y: i16) -> Self {
+        self.x = x;
+        self.y = y;
+        self
+    }
+
+    /// Twist or second stick position, -32767 to 32767
+    #[must_use]
+    pub const fn z_rz(mut self, z: i16, rz: i16) -> Self {
+        self.z = z;
+        self.rz = rz;
+        self
+    }
+
+    #[must_use]
+    pub const fn throttle(mut self, throttle: u16) -> Self {
+        self.throttle = throttle;
+        self
+    }
+
+    #[must_use]
+    pub const fn hat(mut self, hat: HatSwitch) -> Self {
+        self.hat = hat;
+        self
+    }
+
+    /// Report as sent to the host, axes of -32768 are sent as -32767
+    #[must_use]
+    pub fn to_bytes(&self) -> [u8; 15] {
+        let mut data = [0; 15];
+        data[..4].copy_from_slice(&self.buttons.to_le_bytes());
+        for (i, axis) in [self.x, self.y, self.z, self.rz].into_iter().enumerate() {
+            data[4 + 2 * i..6 + 2 * i].copy_from_slice(&axis.max(-i16::MAX).to_le_bytes());
+        }
+        data[12..14].copy_from_slice(&self.throttle.to_le_bytes());
+        data[14] = self.hat as u8;
+        data
+    }
+}
+
+/// Joystick with a hat switch, see [`HAT_JOYSTICK_DESCRIPTOR`]
+pub struct HatJoystick<'a, B: UsbBus> {
+    interface: Interface<'a, B, InBytes16, OutNone, ReportSingle>,
+}
+
+impl<B: UsbBus> HatJoystick<'_, B> {
+    pub fn write_report(&mut self, report: &HatJoystickReport) -> Result<(), UsbHidError> {
+        self.interface
+            .write_report(&report.to_bytes())
+            .map(|_| ())
+            .map_err(UsbHidError::from)
+    }
+}
+
+impl<'a, B: UsbBus> DeviceClass<'a> for HatJoystick<'a, B> {
+    type I = Interface<'a, B, InBytes16, OutNone, ReportSingle>;
+
+    fn interface(&mut self) -> &mut Self::I {
+        &mut self.interface
+    }
+
+    fn reset(&mut self) {}
+
+    fn tick(&mut self) -> Result<(), UsbHidError> {
+        Ok(())
+    }
+
+    fn next_deadline(&mut self) -> Option<MillisDurationU32> {
+        None
+    }
+}
+
+pub struct HatJoystickConfig<'a> {
+    interface: InterfaceConfig<'a, InBytes16, OutNone, ReportSingle>,
+}
+
+impl Default for HatJoystickConfig<'_> {
+    fn default() -> Self {
+        Self::new(
+            unwrap!(
+                unwrap!(InterfaceBuilder::new_static(HAT_JOYSTICK_DESCRIPTOR))
+                    .boot_device(InterfaceProtocol::None)
+                    .description("Joystick")
+                    .in_endpoint(10.millis())
+            )
+            .without_out_endpoint()
+            .build(),
+        )
+    }
+}
+
+impl<'a> HatJoystickConfig<'a> {
+    #[must_use]
+    pub fn new(interface: InterfaceConfig<'a, InBytes16, OutNone, ReportSingle>) -> Self {
+        Self { interface }
+    }
+}
+
+impl<'a, B: UsbBus + 'a> UsbAllocatable<'a, B> for HatJoystickConfig<'a> {
+    type Allocated = HatJoystick<'a, B>;
+
+    fn allocate(self, usb_alloc: &'a UsbBusAllocator<B>) -> Self::Allocated {
+        Self::Allocated {
+            interface: Interface::new(usb_alloc, self.interface),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn hat_joystick_report_layout() {
+        let report = HatJoystickReport::new()
+            .button(1, true)
+            .button(32, true)
+            .stick(i16::MIN, 1)
+            .z_rz(0x1234, -1)
+            .throttle(0xFFFF)
+            .hat(HatSwitch::DownRight);
+        assert_eq!(
+            report.to_bytes(),
+            [
+                0x01, 0x00, 0x00, 0x80, 0x01, 0x80, 0x01, 0x00, 0x34, 0x12, 0xFF, 0xFF, 0xFF, 0xFF,
+                0x03
+            ]
+        );
+
+        let centred = HatJoystickReport::default().to_bytes();
+        assert_eq!(centred[..14], [0; 14]);
+        assert_eq!(centred[14], 0x08);
+    }
+}
@@ -0,0 +1,296 @@
+//! Joysticks with large numbers of buttons, e.g. button boxes for simulators
+//!
+//! The report descriptor is generated for the number of buttons, padded to a whole number of
+//! bytes, and the report is a bitset of `BYTES` bytes with button `n` at bit `n - 1`:
+//!
+//! ```rust
+//! # use xous_usb_hid::device::joystick::button_box::*;
+//! static DESCRIPTOR: ButtonBoxDescriptor = ButtonBoxDescriptor::new(100);
+//!
+//! let config = ButtonBoxConfig::for_descriptor(&DESCRIPTOR);
+//!
+//! let mut report = ButtonBoxReport::<{ report_len(100) }>::new();
+//! report.set(1, true);
+//! report.set(100, true);
+//! assert_eq!(report.pressed().collect::<Vec<_>>(), [1, 100]);
+//! ```
+
+use crate::usb_class::prelude::*;
+use fugit::{ExtU32, MillisDurationU32};
+use usb_device::bus::UsbBus;
+use usb_device::class_prelude::UsbBusAllocator;
+
+/// Most buttons a [`ButtonBox`] can report
+pub const MAX_BUTTONS: u16 = 512;
+
+const MAX_DESCRIPTOR_LEN: usize = 31;
+
+/// Length in bytes of the report for `buttons` buttons
+#[must_use]
+pub const fn report_len(buttons: u16) -> usize {
+    (buttons as usize).div_ceil(8)
+}
+
+/// Generated button box report descriptor
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ButtonBoxDescriptor {
+    bytes: [u8; MAX_DESCRIPTOR_LEN],
+    len: usize,
+    buttons: u16,
+}
+
+impl ButtonBoxDescriptor {
+    /// Descriptor for a joystick with `buttons` buttons
+    ///
+    /// # Panics
+    ///
+    /// If `buttons` is zero or more than [`MAX_BUTTONS`]
+    #[must_use]
+    pub const fn new(buttons: u16) -> Self {
+        core::assert!(
+            buttons > 0 && buttons <= MAX_BUTTONS,
+            "Unsupported number of buttons"
+        );
+        let [buttons_low, buttons_high] = buttons.to_le_bytes();
+        #[allow(clippy::cast_possible_truncation)]
+        let padding = (report_len(buttons) * 8 - buttons as usize) as u8;
+
+        let mut bytes = [0; MAX_DESCRIPTOR_LEN];
+        #[rustfmt::skip]
+        let items: [u8; 24] = [
+            0x05, 0x01, // Usage Page (Generic Desktop),
+            0x09, 0x04, // Usage (Joystick),
+            0xA1, 0x01, // Collection (Application),
+            0x05, 0x09, //   Usage Page (Button),
+            0x19, 0x01, //   Usage Minimum (1),
+            0x2A, buttons_low, buttons_high, //   Usage Maximum (buttons),
+            0x15, 0x00, //   Logical Minimum (0),
+            0x25, 0x01, //   Logical Maximum (1),
+            0x75, 0x01, //   Report Size (1),
+            0x96, buttons_low, buttons_high, //   Report Count (buttons),
+            0x81, 0x02, //   Input (Data, Variable, Absolute),
+        ];
+        let mut len = 0;
+        while len < items.len() {
+            bytes[len] = items[len];
+            len += 1;
+        }
+
+        if padding > 0 {
+            #[rustfmt::skip]
+            let items: [u8; 6] = [
+                0x75, padding, //   Report Size (padding),
+                0x95, 0x01, //   Report Count (1),
+                0x81, 0x03, //   Input (Constant, Variable, Absolute),
+            ];
+            let mut i = 0;
+            while i < items.len() {
+                bytes[len] = items[i];
+                len += 1;
+                i += 1;
+            }
+        }
+
+        bytes[len] = 0xC0; // End Collection
+        len += 1;
+
+        Self {
+            bytes,
+            len,
+            buttons,
+        }
+    }
+
+    #[must_use]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..self.len]
+    }
+
+    #[must_use]
+    pub const fn buttons(&self) -> u16 {
+        self.buttons
+    }
+
+    /// Length in bytes of the report described
+    #[must_use]
+    pub const fn report_len(&self) -> usize {
+        report_len(self.buttons)
+    }
+}
+
+/// Bitset of pressed buttons, button `n` is bit `n - 1`
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ButtonBoxReport<const BYTES: usize> {
+    buttons: [u8; BYTES],
+}
+
+impl<const BYTES: usize> Default for ButtonBoxReport<BYTES> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const BYTES: usize> ButtonBoxReport<BYTES> {
+    /// Report with no buttons pressed
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            buttons: [0; BYTES],
+        }
+    }
+
+    /// Set the state of button `button`, numbered from 1. Buttons outside the report are ignored
+    pub fn set(&mut self, button: usize, pressed: bool) {
+        let Some(bit) = button.checked_sub(1) else {
+            return;
+        };
+        if let Some(byte) = self.buttons.get_mut(bit / 8) {
+            if pressed {
+                *byte |= 1 << (bit % 8);
+            } else {
+                *byte &= !(1 << (bit % 8));
+            }
+        }
+    }
+
+    #[must_use]
+    pub fn is_pressed(&self, button: usize) -> bool {
+        button.checked_sub(1).is_some_and(|bit| {
+            self.buttons
+                .get(bit / 8)
+                .is_some_and(|byte| byte & (1 << (bit % 8)) != 0)
+        })
+    }
+
+    /// Release all buttons
+    pub fn clear(&mut self) {
+        self.buttons = [0; BYTES];
+    }
+
+    /// Pressed buttons, in ascending order
+    pub fn pressed(&self) -> impl Iterator<Item = usize> + '_ {
+        (1..=BYTES * 8).filter(|&b| self.is_pressed(b))
+    }
+
+    #[must_use]
+    pub fn as_bytes(&self) -> &[u8; BYTES] {
+        &self.buttons
+    }
+}
+
+impl<const BYTES: usize> FromIterator<usize> for ButtonBoxReport<BYTES> {
+    fn from_iter<T: IntoIterator<Item = usize>>(iter: T) -> Self {
+        let mut report = Self::new();
+        for button in iter {
+            report.set(button, true);
+        }
+        report
+    }
+}
+
+/// Joystick with only buttons, up to [`MAX_BUTTONS`], see [`ButtonBoxDescriptor`]
+pub struct ButtonBox<'a, B: UsbBus> {
+    interface: Interface<'a, B, InBytes64, OutNone, ReportSingle>,
+}
+
+impl<B: UsbBus> ButtonBox<'_, B> {
+    /// Write a report, `BYTES` must match the [`ButtonBoxDescriptor::report_len`] of the
+    /// descriptor the device was configured with
+    pub fn write_report<const BYTES: usize>(
+        &mut self,
+        report: &ButtonBoxReport<BYTES>,
+    ) -> Result<(), UsbHidError> {
+        self.interface
+            .write_report(report.as_bytes())
+            .map(|_| ())
+            .map_err(UsbHidError::from)
+    }
+}
+
+impl<'a, B: UsbBus> DeviceClass<'a> for ButtonBox<'a, B> {
+    type I = Interface<'a, B, InBytes64, OutNone, ReportSingle>;
+
+    fn interface(&mut self) -> &mut Self::I {
+        &mut self.interface
+    }
+
+    fn reset(&mut self) {}
+
+    fn tick(&mut self) -> Result<(), UsbHidError> {
+        Ok(())
+    }
+
+    fn next_deadline(&mut self) -> Option<MillisDurationU32> {
+        None
+    }
+}
+
+pub struct ButtonBoxConfig<'a> {
+    interface: InterfaceConfig<'a, InBytes64, OutNone, ReportSingle>,
+}
+
+impl<'a> ButtonBoxConfig<'a> {
+    #[must_use]
+    pub fn new(interface: InterfaceConfig<'a, InBytes64, OutNone, ReportSingle>) -> Self {
+        Self { interface }
+    }
+
+    /// Default configuration for a generated descriptor
+    #[must_use]
+    pub fn for_descriptor(descriptor: &'a ButtonBoxDescriptor) -> Self {
+        Self::new(
+            unwrap!(unwrap!(InterfaceBuilder::new(descriptor.as_bytes()))
+                .boot_device(InterfaceProtocol::None)
+                .description("Button Box")
+                .in_endpoint(10.millis()))
+            .without_out_endpoint()
+            .build(),
+        )
+    }
+}
+
+impl<'a, B: UsbBus + 'a> UsbAllocatable<'a, B> for ButtonBoxConfig<'a> {
+    type Allocated = ButtonBox<'a, B>;
+
+    fn allocate(self, usb_alloc: &'a UsbBusAllocator<B>) -> Self::Allocated {
+        Self::Allocated {
+            interface: Interface::new(usb_alloc, self.interface),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn descriptor_padding() {
+        let descriptor = ButtonBoxDescriptor::new(128);
+        assert_eq!(descriptor.report_len(), 16);
+        assert_eq!(descriptor.as_bytes().len(), 25);
+        assert_eq!(&descriptor.as_bytes()[10..13], [0x2A, 0x80, 0x00]);
+        assert_eq!(&descriptor.as_bytes()[19..22], [0x96, 0x80, 0x00]);
+
+        let descriptor = ButtonBoxDescriptor::new(300);
+        assert_eq!(descriptor.report_len(), 38);
+        assert_eq!(
+            &descriptor.as_bytes()[19..],
+            [0x96, 0x2C, 0x01, 0x81, 0x02, 0x75, 0x04, 0x95, 0x01, 0x81, 0x03, 0xC0]
+        );
+    }
+
+    #[test]
+    fn report_bitset() {
+        let mut report: ButtonBoxReport<{ report_len(20) }> =
+            [1, 9, 20, 21, 0].into_iter().collect();
+        assert_eq!(report.as_bytes(), &[0x01, 0x01, 0x18]);
+        assert!(report.is_pressed(21));
+        report.set(9, false);
+        report.set(25, true);
+        assert_eq!(report.as_bytes(), &[0x01, 0x00, 0x18]);
+        assert!(!report.is_pressed(0));
+        report.clear();
+        assert_eq!(report.pressed().count(), 0);
+    }
+}
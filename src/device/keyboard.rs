@@ -1,13 +1,91 @@
 //!HID keyboards
 
 use crate::page::Keyboard;
+use crate::protocol;
 use crate::usb_class::prelude::*;
-use fugit::ExtU32;
+use fugit::{ExtU32, MillisDurationU32};
 use packed_struct::prelude::*;
 #[allow(clippy::wildcard_imports)]
 use usb_device::class_prelude::*;
 use usb_device::UsbError;
 
+pub mod layout;
+pub mod lock_state;
+pub mod passthrough;
+pub mod remap;
+pub mod sticky;
+pub mod typing;
+pub mod wake;
+
+/// Raw keyboard usage ID, accepted anywhere [`Keyboard`] is, for reserved, vendor or newer
+/// usages missing from the enum
+///
+/// ```rust
+/// # use xous_usb_hid::device::keyboard::{BootKeyboardReport, KeyboardUsage};
+/// # use xous_usb_hid::page::Keyboard;
+/// let report = BootKeyboardReport::new([KeyboardUsage(0xA5), Keyboard::A.into()]);
+/// assert_eq!(report.keys[0], KeyboardUsage(0xA5));
+/// assert_eq!(report.keys[1], Keyboard::A);
+/// assert_eq!(KeyboardUsage(0xA5).known(), None);
+/// ```
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
+#[repr(transparent)]
+pub struct KeyboardUsage(pub u8);
+
+impl KeyboardUsage {
+    /// The matching [`Keyboard`] variant, `None` for usages missing from the enum
+    #[must_use]
+    pub fn known(self) -> Option<Keyboard> {
+        let key = Keyboard::from(self.0);
+        (u8::from(key) == self.0).then_some(key)
+    }
+}
+
+impl From<Keyboard> for KeyboardUsage {
+    fn from(key: Keyboard) -> Self {
+        Self(key.into())
+    }
+}
+
+impl From<u8> for KeyboardUsage {
+    fn from(id: u8) -> Self {
+        Self(id)
+    }
+}
+
+impl From<KeyboardUsage> for u8 {
+    fn from(usage: KeyboardUsage) -> Self {
+        usage.0
+    }
+}
+
+impl PartialEq<Keyboard> for KeyboardUsage {
+    fn eq(&self, other: &Keyboard) -> bool {
+        self.0 == u8::from(*other)
+    }
+}
+
+impl PrimitiveEnum for KeyboardUsage {
+    type Primitive = u8;
+
+    fn from_primitive(val: u8) -> Option<Self> {
+        Some(Self(val))
+    }
+
+    fn to_primitive(&self) -> u8 {
+        self.0
+    }
+
+    fn from_str(s: &str) -> Option<Self> {
+        Keyboard::from_str(s).map(Self::from)
+    }
+
+    fn from_str_lower(s: &str) -> Option<Self> {
+        Keyboard::from_str_lower(s).map(Self::from)
+    }
+}
+
 /// Interface implementing the HID boot keyboard specification
 ///
 /// **Note:** This is a managed interfaces that support HID idle, [`UsbHidClass::tick()`] must be called every 1ms.
@@ -19,10 +97,11 @@ impl<'a, B> BootKeyboard<'a, B>
 where
     B: UsbBus,
 {
-    pub fn write_report<K: IntoIterator<Item = Keyboard>>(
-        &mut self,
-        keys: K,
-    ) -> Result<(), UsbHidError> {
+    pub fn write_report<K>(&mut self, keys: K) -> Result<(), UsbHidError>
+    where
+        K: IntoIterator,
+        K::Item: Into<KeyboardUsage>,
+    {
         self.interface
             .write_report(&BootKeyboardReport::new(keys))
             .map(|_| ())
@@ -38,6 +117,14 @@ where
             },
         }
     }
+
+    /// Read an LED report or a lock sync feature report, for use with
+    /// [`BOOT_KEYBOARD_LOCK_SYNC_REPORT_DESCRIPTOR`](lock_state::BOOT_KEYBOARD_LOCK_SYNC_REPORT_DESCRIPTOR)
+    pub fn read_host_report(&mut self) -> usb_device::Result<lock_state::HostReport> {
+        let data = &mut [0; lock_state::LOCK_SYNC_REPORT_LEN];
+        let n = self.interface.read_report(data)?;
+        lock_state::HostReport::unpack(&data[..n])
+    }
 }
 
 impl<'a, B> DeviceClass<'a> for BootKeyboard<'a, B>
@@ -54,9 +141,25 @@ where
         self.interface.reset();
     }
 
+    fn endpoint_halt_cleared(&mut self) {
+        self.interface.endpoint_halt_cleared();
+    }
+
+    fn configured(&mut self) {
+        self.interface.configured();
+    }
+
     fn tick(&mut self) -> Result<(), UsbHidError> {
         self.interface.tick()
     }
+
+    fn next_deadline(&mut self) -> Option<MillisDurationU32> {
+        self.interface.next_deadline()
+    }
+
+    fn advance(&mut self, elapsed: MillisDurationU32) {
+        self.interface.advance(elapsed);
+    }
 }
 
 pub struct BootKeyboardConfig<'a> {
@@ -67,7 +170,7 @@ impl<'a> Default for BootKeyboardConfig<'a> {
     #[must_use]
     fn default() -> Self {
         Self::new(ManagedIdleInterfaceConfig::new(
-            unwrap!(unwrap!(unwrap!(unwrap!(InterfaceBuilder::new(
+            unwrap!(unwrap!(unwrap!(unwrap!(InterfaceBuilder::new_static(
                 BOOT_KEYBOARD_REPORT_DESCRIPTOR
             ))
             .boot_device(InterfaceProtocol::Keyboard)
@@ -89,6 +192,39 @@ impl<'a> BootKeyboardConfig<'a> {
     ) -> Self {
         Self { interface }
     }
+
+    /// Boot keyboard with the feature report of the [`remap`] protocol
+    #[must_use]
+    pub fn with_remap_table() -> Self {
+        Self::new(ManagedIdleInterfaceConfig::new(
+            unwrap!(
+                unwrap!(unwrap!(unwrap!(unwrap!(InterfaceBuilder::new_static(
+                    remap::BOOT_KEYBOARD_REMAP_REPORT_DESCRIPTOR
+                ))
+                .boot_device(InterfaceProtocol::Keyboard)
+                .description("Keyboard")
+                .idle_default(500.millis()))
+                .feature_report(protocol::remap::REPORT_ID, &[0; remap::REMAP_REPORT_LEN]))
+                .in_endpoint(10.millis()))
+                .with_out_endpoint(100.millis())
+            )
+            .build(),
+        ))
+    }
+
+    /// See [`ManagedIdleInterfaceConfig::min_report_spacing`]
+    #[must_use]
+    pub fn min_report_spacing(mut self, spacing: MillisDurationU32) -> Self {
+        self.interface = self.interface.min_report_spacing(spacing);
+        self
+    }
+
+    /// See [`ManagedIdleInterfaceConfig::settle_time`]
+    #[must_use]
+    pub fn settle_time(mut self, settle_time: MillisDurationU32) -> Self {
+        self.interface = self.interface.settle_time(settle_time);
+        self
+    }
 }
 
 impl<'a, B: UsbBus + 'a> UsbAllocatable<'a, B> for BootKeyboardConfig<'a> {
@@ -102,6 +238,7 @@ impl<'a, B: UsbBus + 'a> UsbAllocatable<'a, B> for BootKeyboardConfig<'a> {
 }
 
 /// Report indicating the currently lit keyboard LEDs
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Default, PackedStruct)]
 #[packed_struct(endian = "lsb", bit_numbering = "lsb0", size_bytes = "1")]
 pub struct KeyboardLedsReport {
@@ -117,7 +254,52 @@ pub struct KeyboardLedsReport {
     pub kana: bool,
 }
 
+impl KeyboardLedsReport {
+    /// Report with all LEDs off
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            num_lock: false,
+            caps_lock: false,
+            scroll_lock: false,
+            compose: false,
+            kana: false,
+        }
+    }
+
+    #[must_use]
+    pub const fn num_lock(mut self, num_lock: bool) -> Self {
+        self.num_lock = num_lock;
+        self
+    }
+
+    #[must_use]
+    pub const fn caps_lock(mut self, caps_lock: bool) -> Self {
+        self.caps_lock = caps_lock;
+        self
+    }
+
+    #[must_use]
+    pub const fn scroll_lock(mut self, scroll_lock: bool) -> Self {
+        self.scroll_lock = scroll_lock;
+        self
+    }
+
+    #[must_use]
+    pub const fn compose(mut self, compose: bool) -> Self {
+        self.compose = compose;
+        self
+    }
+
+    #[must_use]
+    pub const fn kana(mut self, kana: bool) -> Self {
+        self.kana = kana;
+        self
+    }
+}
+
 /// Report implementing the HID boot keyboard specification
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Default, PackedStruct)]
 #[packed_struct(endian = "lsb", bit_numbering = "msb0", size_bytes = "8")]
 pub struct BootKeyboardReport {
@@ -137,44 +319,51 @@ pub struct BootKeyboardReport {
     pub left_shift: bool,
     #[packed_field(bits = "7")]
     pub left_ctrl: bool,
+    /// Usage IDs rather than [`Keyboard`], so that usages missing from the enum can be sent. Use
+    /// `Keyboard::A.into()` to set a key and [`KeyboardUsage::known`] to match on the enum
     #[packed_field(bytes = "2..8", ty = "enum", element_size_bytes = "1")]
-    pub keys: [Keyboard; 6],
+    pub keys: [KeyboardUsage; 6],
 }
 
 impl BootKeyboardReport {
-    pub fn new<K: IntoIterator<Item = Keyboard>>(keys: K) -> Self {
+    pub fn new<K>(keys: K) -> Self
+    where
+        K: IntoIterator,
+        K::Item: Into<KeyboardUsage>,
+    {
         let mut report = Self::default();
 
         let mut error = false;
         let mut i = 0;
         for k in keys {
-            match k {
-                Keyboard::LeftControl => {
+            let k = k.into();
+            match k.known() {
+                Some(Keyboard::LeftControl) => {
                     report.left_ctrl = true;
                 }
-                Keyboard::LeftShift => {
+                Some(Keyboard::LeftShift) => {
                     report.left_shift = true;
                 }
-                Keyboard::LeftAlt => {
+                Some(Keyboard::LeftAlt) => {
                     report.left_alt = true;
                 }
-                Keyboard::LeftGUI => {
+                Some(Keyboard::LeftGUI) => {
                     report.left_gui = true;
                 }
-                Keyboard::RightControl => {
+                Some(Keyboard::RightControl) => {
                     report.right_ctrl = true;
                 }
-                Keyboard::RightShift => {
+                Some(Keyboard::RightShift) => {
                     report.right_shift = true;
                 }
-                Keyboard::RightAlt => {
+                Some(Keyboard::RightAlt) => {
                     report.right_alt = true;
                 }
-                Keyboard::RightGUI => {
+                Some(Keyboard::RightGUI) => {
                     report.right_gui = true;
                 }
-                Keyboard::NoEventIndicated => {}
-                Keyboard::ErrorRollOver | Keyboard::POSTFail | Keyboard::ErrorUndefine => {
+                Some(Keyboard::NoEventIndicated) => {}
+                Some(Keyboard::ErrorRollOver | Keyboard::POSTFail | Keyboard::ErrorUndefine) => {
                     if !error {
                         error = true;
                         i = report.keys.len();
@@ -192,7 +381,7 @@ impl BootKeyboardReport {
                     } else {
                         error = true;
                         i = report.keys.len();
-                        report.keys.fill(Keyboard::ErrorRollOver);
+                        report.keys.fill(Keyboard::ErrorRollOver.into());
                     }
                 }
             }
@@ -298,6 +487,7 @@ pub const NKRO_BOOT_KEYBOARD_REPORT_DESCRIPTOR: &[u8] = &[
 ///
 /// This is compatible with the HID boot specification but key data must be duplicated across both
 /// the [`NKROBootKeyboardReport::boot_keys`] and [`NKROBootKeyboardReport::nkro_keys`] fields
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Default, PackedStruct)]
 #[packed_struct(endian = "lsb", bit_numbering = "msb0", size_bytes = "25")]
 pub struct NKROBootKeyboardReport {
@@ -317,47 +507,53 @@ pub struct NKROBootKeyboardReport {
     pub left_shift: bool,
     #[packed_field(bits = "7")]
     pub left_ctrl: bool,
+    /// Usage IDs rather than [`Keyboard`], see [`BootKeyboardReport::keys`]
     #[packed_field(bytes = "2..8", ty = "enum", element_size_bytes = "1")]
-    pub boot_keys: [Keyboard; 6],
+    pub boot_keys: [KeyboardUsage; 6],
     //The usb lsb/lsb0 expected ordering isn't compatible with pact structs
     #[packed_field(bytes = "8..25", element_size_bits = "8")]
     pub nkro_keys: [u8; 17],
 }
 
 impl NKROBootKeyboardReport {
-    pub fn new<K: IntoIterator<Item = Keyboard>>(keys: K) -> Self {
+    pub fn new<K>(keys: K) -> Self
+    where
+        K: IntoIterator,
+        K::Item: Into<KeyboardUsage>,
+    {
         let mut report = Self::default();
 
         let mut boot_keys_error = false;
         let mut i = 0;
         for k in keys {
-            match k {
-                Keyboard::LeftControl => {
+            let k = k.into();
+            match k.known() {
+                Some(Keyboard::LeftControl) => {
                     report.left_ctrl = true;
                 }
-                Keyboard::LeftShift => {
+                Some(Keyboard::LeftShift) => {
                     report.left_shift = true;
                 }
-                Keyboard::LeftAlt => {
+                Some(Keyboard::LeftAlt) => {
                     report.left_alt = true;
                 }
-                Keyboard::LeftGUI => {
+                Some(Keyboard::LeftGUI) => {
                     report.left_gui = true;
                 }
-                Keyboard::RightControl => {
+                Some(Keyboard::RightControl) => {
                     report.right_ctrl = true;
                 }
-                Keyboard::RightShift => {
+                Some(Keyboard::RightShift) => {
                     report.right_shift = true;
                 }
-                Keyboard::RightAlt => {
+                Some(Keyboard::RightAlt) => {
                     report.right_alt = true;
                 }
-                Keyboard::RightGUI => {
+                Some(Keyboard::RightGUI) => {
                     report.right_gui = true;
                 }
-                Keyboard::NoEventIndicated => {}
-                Keyboard::ErrorRollOver | Keyboard::POSTFail | Keyboard::ErrorUndefine => {
+                Some(Keyboard::NoEventIndicated) => {}
+                Some(Keyboard::ErrorRollOver | Keyboard::POSTFail | Keyboard::ErrorUndefine) => {
                     report.nkro_keys[0] |= 1 << u8::from(k);
 
                     if !boot_keys_error {
@@ -383,7 +579,7 @@ impl NKROBootKeyboardReport {
                     } else {
                         boot_keys_error = true;
                         i = report.boot_keys.len();
-                        report.boot_keys.fill(Keyboard::ErrorRollOver);
+                        report.boot_keys.fill(Keyboard::ErrorRollOver.into());
                     }
                 }
             }
@@ -403,10 +599,11 @@ impl<'a, B> NKROBootKeyboard<'a, B>
 where
     B: UsbBus,
 {
-    pub fn write_report<K: IntoIterator<Item = Keyboard>>(
-        &mut self,
-        keys: K,
-    ) -> Result<(), UsbHidError> {
+    pub fn write_report<K>(&mut self, keys: K) -> Result<(), UsbHidError>
+    where
+        K: IntoIterator,
+        K::Item: Into<KeyboardUsage>,
+    {
         self.interface
             .write_report(&NKROBootKeyboardReport::new(keys))
             .map(|_| ())
@@ -422,6 +619,14 @@ where
             },
         }
     }
+
+    /// Read an LED report or a lock sync feature report, for use with
+    /// [`BOOT_KEYBOARD_LOCK_SYNC_REPORT_DESCRIPTOR`](lock_state::BOOT_KEYBOARD_LOCK_SYNC_REPORT_DESCRIPTOR)
+    pub fn read_host_report(&mut self) -> usb_device::Result<lock_state::HostReport> {
+        let data = &mut [0; lock_state::LOCK_SYNC_REPORT_LEN];
+        let n = self.interface.read_report(data)?;
+        lock_state::HostReport::unpack(&data[..n])
+    }
 }
 
 pub struct NKROBootKeyboardConfig<'a> {
@@ -432,7 +637,7 @@ impl<'a> Default for NKROBootKeyboardConfig<'a> {
     #[must_use]
     fn default() -> Self {
         Self::new(ManagedIdleInterfaceConfig::new(
-            unwrap!(unwrap!(unwrap!(unwrap!(InterfaceBuilder::new(
+            unwrap!(unwrap!(unwrap!(unwrap!(InterfaceBuilder::new_static(
                 NKRO_BOOT_KEYBOARD_REPORT_DESCRIPTOR
             ))
             .description("NKRO Keyboard")
@@ -452,6 +657,20 @@ impl<'a> NKROBootKeyboardConfig<'a> {
     ) -> Self {
         Self { interface }
     }
+
+    /// See [`ManagedIdleInterfaceConfig::min_report_spacing`]
+    #[must_use]
+    pub fn min_report_spacing(mut self, spacing: MillisDurationU32) -> Self {
+        self.interface = self.interface.min_report_spacing(spacing);
+        self
+    }
+
+    /// See [`ManagedIdleInterfaceConfig::settle_time`]
+    #[must_use]
+    pub fn settle_time(mut self, settle_time: MillisDurationU32) -> Self {
+        self.interface = self.interface.settle_time(settle_time);
+        self
+    }
 }
 
 impl<'a, B: UsbBus + 'a> UsbAllocatable<'a, B> for NKROBootKeyboardConfig<'a> {
@@ -478,9 +697,25 @@ where
         self.interface.reset();
     }
 
+    fn endpoint_halt_cleared(&mut self) {
+        self.interface.endpoint_halt_cleared();
+    }
+
+    fn configured(&mut self) {
+        self.interface.configured();
+    }
+
     fn tick(&mut self) -> core::result::Result<(), UsbHidError> {
         self.interface.tick()
     }
+
+    fn next_deadline(&mut self) -> Option<MillisDurationU32> {
+        self.interface.next_deadline()
+    }
+
+    fn advance(&mut self, elapsed: MillisDurationU32) {
+        self.interface.advance(elapsed);
+    }
 }
 
 /// HID Keyboard report descriptor implementing an NKRO keyboard as a bitmap.
@@ -524,6 +759,264 @@ pub const NKRO_COMPACT_KEYBOARD_REPORT_DESCRIPTOR: &[u8] = &[
     0xc0                            // End Collection
 ];
 
+/// Report for [`NKRO_COMPACT_KEYBOARD_REPORT_DESCRIPTOR`], a modifier byte followed by a bitmap of
+/// the keys 0x00 to 0x87
+///
+/// ```rust
+/// # use xous_usb_hid::device::keyboard::NKROKeyboardReport;
+/// # use xous_usb_hid::page::Keyboard;
+/// let mut report = NKROKeyboardReport::new([Keyboard::LeftShift, Keyboard::A]);
+/// report.press(Keyboard::B);
+/// report.release(Keyboard::A);
+/// assert!(report.is_pressed(Keyboard::B));
+/// assert!(!report.is_pressed(Keyboard::A));
+/// ```
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Default, PackedStruct)]
+#[packed_struct(endian = "lsb", bit_numbering = "msb0", size_bytes = "18")]
+pub struct NKROKeyboardReport {
+    /// Bit `n` is the modifier `0xE0 + n`, Left Control to Right GUI
+    #[packed_field(bytes = "0")]
+    pub modifiers: u8,
+    /// Bit `n % 8` of byte `n / 8` is the key `n`
+    #[packed_field(bytes = "1..18", element_size_bits = "8")]
+    pub keys: [u8; 17],
+}
+
+impl NKROKeyboardReport {
+    const MODIFIERS: core::ops::RangeInclusive<u8> = 0xE0..=0xE7;
+
+    /// Report with `keys` pressed
+    pub fn new<K>(keys: K) -> Self
+    where
+        K: IntoIterator,
+        K::Item: Into<KeyboardUsage>,
+    {
+        let mut report = Self::default();
+        for k in keys {
+            report.press(k);
+        }
+        report
+    }
+
+    /// Returns `false` if the report can't represent the key
+    pub fn press(&mut self, key: impl Into<KeyboardUsage>) -> bool {
+        self.set(key.into(), true)
+    }
+
+    /// Returns `false` if the report can't represent the key
+    pub fn release(&mut self, key: impl Into<KeyboardUsage>) -> bool {
+        self.set(key.into(), false)
+    }
+
+    #[must_use]
+    pub fn is_pressed(&self, key: impl Into<KeyboardUsage>) -> bool {
+        self.bit(key.into())
+            .is_some_and(|(byte, mask)| byte & mask != 0)
+    }
+
+    /// Release all keys and modifiers
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    fn set(&mut self, key: KeyboardUsage, pressed: bool) -> bool {
+        let Some((byte, mask)) = self.bit_mut(key) else {
+            return false;
+        };
+        if pressed {
+            *byte |= mask;
+        } else {
+            *byte &= !mask;
+        }
+        true
+    }
+
+    fn bit(&self, key: KeyboardUsage) -> Option<(u8, u8)> {
+        let mut report = *self;
+        report.bit_mut(key).map(|(byte, mask)| (*byte, mask))
+    }
+
+    fn bit_mut(&mut self, key: KeyboardUsage) -> Option<(&mut u8, u8)> {
+        let id = u8::from(key);
+        if Self::MODIFIERS.contains(&id) {
+            Some((&mut self.modifiers, 1 << (id - Self::MODIFIERS.start())))
+        } else if id == u8::from(Keyboard::NoEventIndicated) {
+            None
+        } else {
+            let byte = self.keys.get_mut(usize::from(id / 8))?;
+            Some((byte, 1 << (id % 8)))
+        }
+    }
+}
+
+/// Interface implementing an NKRO keyboard with a key bitmap, see
+/// [`NKRO_COMPACT_KEYBOARD_REPORT_DESCRIPTOR`]
+///
+/// Keys are pressed and released individually, every change is sent to the host straight away.
+/// If it couldn't be sent, e.g. because it would block, the change is kept and sent by the next
+/// call to [`NKROKeyboard::flush`] or any other change.
+///
+/// This isn't compatible with the HID boot specification, use [`NKROBootKeyboard`] for hosts
+/// such as BIOSes.
+///
+/// **Note:** This is a managed interfaces that support HID idle, [`UsbHidClass::tick()`] must be called every 1ms/ at 1kHz.
+pub struct NKROKeyboard<'a, B: UsbBus> {
+    interface: ManagedIdleInterface<'a, B, NKROKeyboardReport, InBytes32, OutBytes8>,
+    state: NKROKeyboardReport,
+}
+
+impl<B> NKROKeyboard<'_, B>
+where
+    B: UsbBus,
+{
+    /// Press a key, returns [`UsbHidError::SerializationError`] if the report can't represent it
+    pub fn press(&mut self, key: impl Into<KeyboardUsage>) -> Result<(), UsbHidError> {
+        if !self.state.press(key) {
+            return Err(UsbHidError::SerializationError);
+        }
+        self.flush()
+    }
+
+    /// Release a key, returns [`UsbHidError::SerializationError`] if the report can't represent
+    /// it
+    pub fn release(&mut self, key: impl Into<KeyboardUsage>) -> Result<(), UsbHidError> {
+        if !self.state.release(key) {
+            return Err(UsbHidError::SerializationError);
+        }
+        self.flush()
+    }
+
+    /// Release all keys and modifiers
+    pub fn release_all(&mut self) -> Result<(), UsbHidError> {
+        self.state.clear();
+        self.flush()
+    }
+
+    /// Replace the pressed keys with `keys`, keys the report can't represent are ignored
+    pub fn write_report<K>(&mut self, keys: K) -> Result<(), UsbHidError>
+    where
+        K: IntoIterator,
+        K::Item: Into<KeyboardUsage>,
+    {
+        self.state = NKROKeyboardReport::new(keys);
+        self.flush()
+    }
+
+    /// Send the pressed keys, if the host doesn't have them yet
+    pub fn flush(&mut self) -> Result<(), UsbHidError> {
+        match self.interface.write_report(&self.state) {
+            Err(UsbHidError::Duplicate) => Ok(()),
+            result => result,
+        }
+    }
+
+    /// Keys currently pressed
+    #[must_use]
+    pub fn state(&self) -> &NKROKeyboardReport {
+        &self.state
+    }
+
+    pub fn read_report(&mut self) -> usb_device::Result<KeyboardLedsReport> {
+        let data = &mut [0];
+        match self.interface.read_report(data) {
+            Err(e) => Err(e),
+            Ok(_) => match KeyboardLedsReport::unpack(data) {
+                Ok(r) => Ok(r),
+                Err(_) => Err(UsbError::ParseError),
+            },
+        }
+    }
+}
+
+pub struct NKROKeyboardConfig<'a> {
+    interface: ManagedIdleInterfaceConfig<'a, NKROKeyboardReport, InBytes32, OutBytes8>,
+}
+
+impl Default for NKROKeyboardConfig<'_> {
+    fn default() -> Self {
+        Self::new(ManagedIdleInterfaceConfig::new(
+            unwrap!(unwrap!(unwrap!(unwrap!(InterfaceBuilder::new_static(
+                NKRO_COMPACT_KEYBOARD_REPORT_DESCRIPTOR
+            ))
+            .description("NKRO Keyboard")
+            .idle_default(500.millis()))
+            .in_endpoint(10.millis()))
+            .with_out_endpoint(100.millis()))
+            .build(),
+        ))
+    }
+}
+
+impl<'a> NKROKeyboardConfig<'a> {
+    #[must_use]
+    pub fn new(
+        interface: ManagedIdleInterfaceConfig<'a, NKROKeyboardReport, InBytes32, OutBytes8>,
+    ) -> Self {
+        Self { interface }
+    }
+
+    /// See [`ManagedIdleInterfaceConfig::min_report_spacing`]
+    #[must_use]
+    pub fn min_report_spacing(mut self, spacing: MillisDurationU32) -> Self {
+        self.interface = self.interface.min_report_spacing(spacing);
+        self
+    }
+
+    /// See [`ManagedIdleInterfaceConfig::settle_time`]
+    #[must_use]
+    pub fn settle_time(mut self, settle_time: MillisDurationU32) -> Self {
+        self.interface = self.interface.settle_time(settle_time);
+        self
+    }
+}
+
+impl<'a, B: UsbBus + 'a> UsbAllocatable<'a, B> for NKROKeyboardConfig<'a> {
+    type Allocated = NKROKeyboard<'a, B>;
+
+    fn allocate(self, usb_alloc: &'a UsbBusAllocator<B>) -> Self::Allocated {
+        Self::Allocated {
+            interface: self.interface.allocate(usb_alloc),
+            state: NKROKeyboardReport::default(),
+        }
+    }
+}
+
+impl<'a, B> DeviceClass<'a> for NKROKeyboard<'a, B>
+where
+    B: UsbBus,
+{
+    type I = Interface<'a, B, InBytes32, OutBytes8, ReportSingle>;
+
+    fn interface(&mut self) -> &mut Self::I {
+        self.interface.interface()
+    }
+
+    fn reset(&mut self) {
+        self.interface.reset();
+    }
+
+    fn endpoint_halt_cleared(&mut self) {
+        self.interface.endpoint_halt_cleared();
+    }
+
+    fn configured(&mut self) {
+        self.interface.configured();
+    }
+
+    fn tick(&mut self) -> core::result::Result<(), UsbHidError> {
+        self.interface.tick()
+    }
+
+    fn next_deadline(&mut self) -> Option<MillisDurationU32> {
+        self.interface.next_deadline()
+    }
+
+    fn advance(&mut self, elapsed: MillisDurationU32) {
+        self.interface.advance(elapsed);
+    }
+}
+
 #[cfg(test)]
 mod test {
     #![allow(clippy::unwrap_used)]
@@ -531,9 +1024,19 @@ mod test {
 
     use packed_struct::prelude::*;
 
-    use crate::device::keyboard::{BootKeyboardReport, KeyboardLedsReport};
+    use crate::device::keyboard::{
+        BootKeyboardReport, KeyboardLedsReport, KeyboardUsage, NKROBootKeyboardReport,
+        NKROKeyboardReport,
+    };
     use crate::page::Keyboard;
 
+    #[test]
+    fn leds_fluent_setters() {
+        const LEDS: KeyboardLedsReport = KeyboardLedsReport::new().caps_lock(true).kana(true);
+        assert_eq!(LEDS.pack().unwrap(), [0x12]);
+        assert_eq!(KeyboardLedsReport::new(), KeyboardLedsReport::default());
+    }
+
     #[test]
     fn leds_num_lock() {
         assert_eq!(
@@ -574,6 +1077,47 @@ mod test {
         );
     }
 
+    #[test]
+    fn nkro_keyboard_bitmap() {
+        let mut report = NKROKeyboardReport::new([Keyboard::LeftShift, Keyboard::A]);
+        assert!(report.press(KeyboardUsage(0x87)));
+        assert!(report.press(Keyboard::RightGUI));
+        assert!(!report.press(Keyboard::MediaPlayPause));
+        assert!(!report.press(Keyboard::NoEventIndicated));
+
+        let bytes = report.pack().unwrap();
+        assert_eq!(bytes[0], 0b1000_0010);
+        assert_eq!(bytes[1], 1 << 4);
+        assert_eq!(bytes[17], 1 << 7);
+        assert!(bytes[2..17].iter().all(|&b| b == 0));
+
+        assert!(report.release(Keyboard::LeftShift));
+        assert!(!report.is_pressed(Keyboard::LeftShift));
+        report.clear();
+        assert_eq!(report, NKROKeyboardReport::default());
+    }
+
+    #[test]
+    fn unlisted_usages_pass_through() {
+        let keys = [
+            KeyboardUsage(0xA5),
+            Keyboard::LeftShift.into(),
+            KeyboardUsage(0x04),
+        ];
+
+        let bytes = BootKeyboardReport::new(keys).pack().unwrap();
+        assert_eq!(bytes, [0x02, 0x00, 0xA5, 0x04, 0x00, 0x00, 0x00, 0x00]);
+
+        let report = NKROBootKeyboardReport::new(keys);
+        assert_eq!(
+            report.boot_keys[..2],
+            [KeyboardUsage(0xA5), KeyboardUsage(0x04)]
+        );
+        // Usages above the NKRO bitmap only appear in the boot keys
+        assert_eq!(report.nkro_keys[0], 1 << 4);
+        assert!(report.nkro_keys[1..].iter().all(|&b| b == 0));
+    }
+
     #[test]
     fn boot_keyboard_report_mixed() {
         let bytes = BootKeyboardReport::new([
@@ -0,0 +1,331 @@
+//! Keyboard layouts, mapping characters to the keystrokes typing them
+//!
+//! The host interprets keyboard usages according to its configured layout, so typing text needs
+//! to know which layout that is. Characters that need `AltGr` on the layout are typed with
+//! [`Modifiers::RIGHT_ALT`] held in the same report as the key. Characters only reachable
+//! through dead keys are not mapped.
+//!
+//! ```
+//! # use xous_usb_hid::device::keyboard::layout::{GermanQwertz, Layout};
+//! # use xous_usb_hid::device::keyboard::typing::{Keystroke, Modifiers};
+//! # use xous_usb_hid::page::Keyboard;
+//! assert_eq!(
+//!     GermanQwertz.keystroke('@'),
+//!     Some(Keystroke::new(Modifiers::RIGHT_ALT, Keyboard::Q))
+//! );
+//! ```
+
+use crate::device::keyboard::typing::{Keystroke, Modifiers};
+use crate::page::Keyboard;
+
+const NONE: Modifiers = Modifiers::NONE;
+const SHIFT: Modifiers = Modifiers::LEFT_SHIFT;
+const ALT_GR: Modifiers = Modifiers::RIGHT_ALT;
+
+/// Host keyboard layout
+pub trait Layout {
+    /// Keystroke typing `c`, or `None` if the layout can't type it with a single keystroke
+    fn keystroke(&self, c: char) -> Option<Keystroke>;
+}
+
+/// US English QWERTY
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Default, Hash)]
+pub struct UsQwerty;
+
+/// German QWERTZ (T1)
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Default, Hash)]
+pub struct GermanQwertz;
+
+/// French AZERTY
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Default, Hash)]
+pub struct FrenchAzerty;
+
+impl Layout for UsQwerty {
+    fn keystroke(&self, c: char) -> Option<Keystroke> {
+        let (modifiers, key) = match c {
+            '1'..='9' | '0' => (NONE, digit(c)),
+            '!' => (SHIFT, Keyboard::Keyboard1),
+            '@' => (SHIFT, Keyboard::Keyboard2),
+            '#' => (SHIFT, Keyboard::Keyboard3),
+            '$' => (SHIFT, Keyboard::Keyboard4),
+            '%' => (SHIFT, Keyboard::Keyboard5),
+            '^' => (SHIFT, Keyboard::Keyboard6),
+            '&' => (SHIFT, Keyboard::Keyboard7),
+            '*' => (SHIFT, Keyboard::Keyboard8),
+            '(' => (SHIFT, Keyboard::Keyboard9),
+            ')' => (SHIFT, Keyboard::Keyboard0),
+            '-' => (NONE, Keyboard::Minus),
+            '_' => (SHIFT, Keyboard::Minus),
+            '=' => (NONE, Keyboard::Equal),
+            '+' => (SHIFT, Keyboard::Equal),
+            '[' => (NONE, Keyboard::LeftBrace),
+            '{' => (SHIFT, Keyboard::LeftBrace),
+            ']' => (NONE, Keyboard::RightBrace),
+            '}' => (SHIFT, Keyboard::RightBrace),
+            '\\' => (NONE, Keyboard::Backslash),
+            '|' => (SHIFT, Keyboard::Backslash),
+            ';' => (NONE, Keyboard::Semicolon),
+            ':' => (SHIFT, Keyboard::Semicolon),
+            '\'' => (NONE, Keyboard::Apostrophe),
+            '"' => (SHIFT, Keyboard::Apostrophe),
+            '`' => (NONE, Keyboard::Grave),
+            '~' => (SHIFT, Keyboard::Grave),
+            ',' => (NONE, Keyboard::Comma),
+            '<' => (SHIFT, Keyboard::Comma),
+            '.' => (NONE, Keyboard::Dot),
+            '>' => (SHIFT, Keyboard::Dot),
+            '/' => (NONE, Keyboard::ForwardSlash),
+            '?' => (SHIFT, Keyboard::ForwardSlash),
+            _ => return common(c),
+        };
+        Some(Keystroke::new(modifiers, key))
+    }
+}
+
+impl Layout for GermanQwertz {
+    fn keystroke(&self, c: char) -> Option<Keystroke> {
+        let (modifiers, key) = match c {
+            '1'..='9' | '0' => (NONE, digit(c)),
+            '!' => (SHIFT, Keyboard::Keyboard1),
+            '"' => (SHIFT, Keyboard::Keyboard2),
+            '²' => (ALT_GR, Keyboard::Keyboard2),
+            '§' => (SHIFT, Keyboard::Keyboard3),
+            '³' => (ALT_GR, Keyboard::Keyboard3),
+            '$' => (SHIFT, Keyboard::Keyboard4),
+            '%' => (SHIFT, Keyboard::Keyboard5),
+            '&' => (SHIFT, Keyboard::Keyboard6),
+            '/' => (SHIFT, Keyboard::Keyboard7),
+            '{' => (ALT_GR, Keyboard::Keyboard7),
+            '(' => (SHIFT, Keyboard::Keyboard8),
+            '[' => (ALT_GR, Keyboard::Keyboard8),
+            ')' => (SHIFT, Keyboard::Keyboard9),
+            ']' => (ALT_GR, Keyboard::Keyboard9),
+            '=' => (SHIFT, Keyboard::Keyboard0),
+            '}' => (ALT_GR, Keyboard::Keyboard0),
+            'ß' => (NONE, Keyboard::Minus),
+            '?' => (SHIFT, Keyboard::Minus),
+            '\\' => (ALT_GR, Keyboard::Minus),
+            'ü' => (NONE, Keyboard::LeftBrace),
+            'Ü' => (SHIFT, Keyboard::LeftBrace),
+            '+' => (NONE, Keyboard::RightBrace),
+            '*' => (SHIFT, Keyboard::RightBrace),
+            '~' => (ALT_GR, Keyboard::RightBrace),
+            '#' => (NONE, Keyboard::NonUSHash),
+            '\'' => (SHIFT, Keyboard::NonUSHash),
+            'ö' => (NONE, Keyboard::Semicolon),
+            'Ö' => (SHIFT, Keyboard::Semicolon),
+            'ä' => (NONE, Keyboard::Apostrophe),
+            'Ä' => (SHIFT, Keyboard::Apostrophe),
+            '°' => (SHIFT, Keyboard::Grave),
+            ',' => (NONE, Keyboard::Comma),
+            ';' => (SHIFT, Keyboard::Comma),
+            '.' => (NONE, Keyboard::Dot),
+            ':' => (SHIFT, Keyboard::Dot),
+            '-' => (NONE, Keyboard::ForwardSlash),
+            '_' => (SHIFT, Keyboard::ForwardSlash),
+            '<' => (NONE, Keyboard::NonUSBackslash),
+            '>' => (SHIFT, Keyboard::NonUSBackslash),
+            '|' => (ALT_GR, Keyboard::NonUSBackslash),
+            '@' => (ALT_GR, Keyboard::Q),
+            '€' => (ALT_GR, Keyboard::E),
+            'µ' => (ALT_GR, Keyboard::M),
+            _ => {
+                return common(c)
+                    .map(|k| remap(k, &[(Keyboard::Y, Keyboard::Z), (Keyboard::Z, Keyboard::Y)]))
+            }
+        };
+        Some(Keystroke::new(modifiers, key))
+    }
+}
+
+impl Layout for FrenchAzerty {
+    fn keystroke(&self, c: char) -> Option<Keystroke> {
+        let (modifiers, key) = match c {
+            '1'..='9' | '0' => (SHIFT, digit(c)),
+            '&' => (NONE, Keyboard::Keyboard1),
+            'é' => (NONE, Keyboard::Keyboard2),
+            '"' => (NONE, Keyboard::Keyboard3),
+            '#' => (ALT_GR, Keyboard::Keyboard3),
+            '\'' => (NONE, Keyboard::Keyboard4),
+            '{' => (ALT_GR, Keyboard::Keyboard4),
+            '(' => (NONE, Keyboard::Keyboard5),
+            '[' => (ALT_GR, Keyboard::Keyboard5),
+            '-' => (NONE, Keyboard::Keyboard6),
+            '|' => (ALT_GR, Keyboard::Keyboard6),
+            'è' => (NONE, Keyboard::Keyboard7),
+            '_' => (NONE, Keyboard::Keyboard8),
+            '\\' => (ALT_GR, Keyboard::Keyboard8),
+            'ç' => (NONE, Keyboard::Keyboard9),
+            '^' => (ALT_GR, Keyboard::Keyboard9),
+            'à' => (NONE, Keyboard::Keyboard0),
+            '@' => (ALT_GR, Keyboard::Keyboard0),
+            ')' => (NONE, Keyboard::Minus),
+            '°' => (SHIFT, Keyboard::Minus),
+            ']' => (ALT_GR, Keyboard::Minus),
+            '=' => (NONE, Keyboard::Equal),
+            '+' => (SHIFT, Keyboard::Equal),
+            '}' => (ALT_GR, Keyboard::Equal),
+            '$' => (NONE, Keyboard::RightBrace),
+            '£' => (SHIFT, Keyboard::RightBrace),
+            '¤' => (ALT_GR, Keyboard::RightBrace),
+            'ù' => (NONE, Keyboard::Apostrophe),
+            '%' => (SHIFT, Keyboard::Apostrophe),
+            '*' => (NONE, Keyboard::NonUSHash),
+            'µ' => (SHIFT, Keyboard::NonUSHash),
+            '²' => (NONE, Keyboard::Grave),
+            ',' => (NONE, Keyboard::M),
+            '?' => (SHIFT, Keyboard::M),
+            ';' => (NONE, Keyboard::Comma),
+            '.' => (SHIFT, Keyboard::Comma),
+            ':' => (NONE, Keyboard::Dot),
+            '/' => (SHIFT, Keyboard::Dot),
+            '!' => (NONE, Keyboard::ForwardSlash),
+            '§' => (SHIFT, Keyboard::ForwardSlash),
+            '<' => (NONE, Keyboard::NonUSBackslash),
+            '>' => (SHIFT, Keyboard::NonUSBackslash),
+            '€' => (ALT_GR, Keyboard::E),
+            _ => {
+                return common(c).map(|k| {
+                    remap(
+                        k,
+                        &[
+                            (Keyboard::A, Keyboard::Q),
+                            (Keyboard::Q, Keyboard::A),
+                            (Keyboard::W, Keyboard::Z),
+                            (Keyboard::Z, Keyboard::W),
+                            (Keyboard::M, Keyboard::Semicolon),
+                        ],
+                    )
+                })
+            }
+        };
+        Some(Keystroke::new(modifiers, key))
+    }
+}
+
+/// Letters, in their QWERTY positions, and whitespace
+fn common(c: char) -> Option<Keystroke> {
+    match c {
+        ' ' => Some(Keystroke::key(Keyboard::Space)),
+        '\n' => Some(Keystroke::key(Keyboard::ReturnEnter)),
+        '\t' => Some(Keystroke::key(Keyboard::Tab)),
+        'a'..='z' | 'A'..='Z' => {
+            let modifiers = if c.is_ascii_uppercase() { SHIFT } else { NONE };
+            #[allow(clippy::cast_possible_truncation)]
+            let offset = c.to_ascii_lowercase() as u8 - b'a';
+            Some(Keystroke::new(
+                modifiers,
+                Keyboard::from(u8::from(Keyboard::A) + offset),
+            ))
+        }
+        _ => None,
+    }
+}
+
+fn digit(c: char) -> Keyboard {
+    match c {
+        '0' => Keyboard::Keyboard0,
+        #[allow(clippy::cast_possible_truncation)]
+        c => Keyboard::from(u8::from(Keyboard::Keyboard1) + (c as u8 - b'1')),
+    }
+}
+
+fn remap(keystroke: Keystroke, swaps: &[(Keyboard, Keyboard)]) -> Keystroke {
+    swaps
+        .iter()
+        .find(|(from, _)| *from == keystroke.key)
+        .map_or(keystroke, |&(_, to)| {
+            Keystroke::new(keystroke.modifiers, to)
+        })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::device::keyboard::BootKeyboardReport;
+    use packed_struct::PackedStruct;
+
+    #[test]
+    fn german_alt_gr() {
+        for (c, key) in [
+            ('@', Keyboard::Q),
+            ('€', Keyboard::E),
+            ('{', Keyboard::Keyboard7),
+            ('[', Keyboard::Keyboard8),
+            (']', Keyboard::Keyboard9),
+            ('}', Keyboard::Keyboard0),
+            ('\\', Keyboard::Minus),
+            ('~', Keyboard::RightBrace),
+            ('|', Keyboard::NonUSBackslash),
+        ] {
+            assert_eq!(
+                GermanQwertz.keystroke(c),
+                Some(Keystroke::new(ALT_GR, key)),
+                "{c}"
+            );
+        }
+        assert_eq!(
+            GermanQwertz.keystroke('Z'),
+            Some(Keystroke::new(SHIFT, Keyboard::Y))
+        );
+        assert_eq!(
+            GermanQwertz.keystroke('y'),
+            Some(Keystroke::key(Keyboard::Z))
+        );
+        assert_eq!(GermanQwertz.keystroke('^'), None);
+    }
+
+    #[test]
+    fn french_alt_gr() {
+        for (c, key) in [
+            ('@', Keyboard::Keyboard0),
+            ('#', Keyboard::Keyboard3),
+            ('{', Keyboard::Keyboard4),
+            ('[', Keyboard::Keyboard5),
+            ('|', Keyboard::Keyboard6),
+            ('\\', Keyboard::Keyboard8),
+            ('^', Keyboard::Keyboard9),
+            (']', Keyboard::Minus),
+            ('}', Keyboard::Equal),
+            ('€', Keyboard::E),
+        ] {
+            assert_eq!(
+                FrenchAzerty.keystroke(c),
+                Some(Keystroke::new(ALT_GR, key)),
+                "{c}"
+            );
+        }
+        assert_eq!(
+            FrenchAzerty.keystroke('1'),
+            Some(Keystroke::new(SHIFT, Keyboard::Keyboard1))
+        );
+        assert_eq!(
+            FrenchAzerty.keystroke('a'),
+            Some(Keystroke::key(Keyboard::Q))
+        );
+        assert_eq!(
+            FrenchAzerty.keystroke('M'),
+            Some(Keystroke::new(SHIFT, Keyboard::Semicolon))
+        );
+        assert_eq!(
+            FrenchAzerty.keystroke(','),
+            Some(Keystroke::key(Keyboard::M))
+        );
+    }
+
+    #[test]
+    fn alt_gr_and_key_in_one_report() {
+        let keystroke = GermanQwertz.keystroke('@').unwrap_or_default();
+        let report = BootKeyboardReport::new(keystroke);
+        assert!(report.right_alt);
+        assert!(!report.left_alt);
+        assert_eq!(report.keys[0], Keyboard::Q);
+        assert_eq!(
+            report.pack().ok().map(|r| r[..3].to_vec()),
+            Some(std::vec![0x40, 0x00, u8::from(Keyboard::Q)])
+        );
+    }
+}
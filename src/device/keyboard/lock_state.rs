@@ -0,0 +1,213 @@
+//! Lock key state tracking
+//!
+//! Hosts report the state of Num, Caps and Scroll Lock through the LED output report, but some
+//! hosts, e.g. KVM switches, never send it. [`LockStatePredictor`] follows the lock key presses
+//! sent by the keyboard to keep a best guess of the host's lock state, corrected by LED reports
+//! when they do arrive.
+//!
+//! Host tools can also force the predicted state with a vendor defined feature report, see
+//! [`BOOT_KEYBOARD_LOCK_SYNC_REPORT_DESCRIPTOR`] and [`LockSyncReport`]. As the feature report
+//! is part of the keyboard collection, hosts that claim keyboards exclusively (e.g. Windows) won't
+//! let host tools send it.
+//!
+//! ```
+//! # use xous_usb_hid::device::keyboard::lock_state::{HostReport, LockStatePredictor};
+//! # use xous_usb_hid::page::Keyboard;
+//! let mut predictor = LockStatePredictor::new();
+//!
+//! // keyboard.write_report(keys)
+//! predictor.keys_sent([Keyboard::CapsLock]);
+//! predictor.keys_sent([]);
+//! assert!(predictor.state().caps_lock);
+//!
+//! // if let Ok(report) = keyboard.read_host_report()
+//! predictor.update(HostReport::LockSync(Default::default()));
+//! assert!(!predictor.state().caps_lock);
+//! ```
+
+use crate::device::keyboard::KeyboardLedsReport;
+use crate::page::Keyboard;
+use packed_struct::prelude::*;
+use usb_device::UsbError;
+
+pub use crate::protocol::lock_sync::{
+    COMMAND as LOCK_SYNC_COMMAND, REPORT_LEN as LOCK_SYNC_REPORT_LEN,
+};
+
+/// [`BOOT_KEYBOARD_REPORT_DESCRIPTOR`](crate::device::keyboard::BOOT_KEYBOARD_REPORT_DESCRIPTOR)
+/// with a two byte vendor defined feature report to force the lock state, see [`LockSyncReport`]
+#[rustfmt::skip]
+pub const BOOT_KEYBOARD_LOCK_SYNC_REPORT_DESCRIPTOR: &[u8] = &[
+    0x05, 0x01, // Usage Page (Generic Desktop),
+    0x09, 0x06, // Usage (Keyboard),
+    0xA1, 0x01, // Collection (Application),
+    0x75, 0x01, //     Report Size (1),
+    0x95, 0x08, //     Report Count (8),
+    0x05, 0x07, //     Usage Page (Key Codes),
+    0x19, 0xE0, //     Usage Minimum (224),
+    0x29, 0xE7, //     Usage Maximum (231),
+    0x15, 0x00, //     Logical Minimum (0),
+    0x25, 0x01, //     Logical Maximum (1),
+    0x81, 0x02, //     Input (Data, Variable, Absolute), ;Modifier byte
+    0x95, 0x01, //     Report Count (1),
+    0x75, 0x08, //     Report Size (8),
+    0x81, 0x01, //     Input (Constant), ;Reserved byte
+    0x95, 0x05, //     Report Count (5),
+    0x75, 0x01, //     Report Size (1),
+    0x05, 0x08, //     Usage Page (LEDs),
+    0x19, 0x01, //     Usage Minimum (1),
+    0x29, 0x05, //     Usage Maximum (5),
+    0x91, 0x02, //     Output (Data, Variable, Absolute), ;LED report
+    0x95, 0x01, //     Report Count (1),
+    0x75, 0x03, //     Report Size (3),
+    0x91, 0x01, //     Output (Constant), ;LED report padding
+    0x95, 0x06, //     Report Count (6),
+    0x75, 0x08, //     Report Size (8),
+    0x15, 0x00, //     Logical Minimum (0),
+    0x26, 0xFF, 0x00, //     Logical Maximum(255),
+    0x05, 0x07, //     Usage Page (Key Codes),
+    0x19, 0x00, //     Usage Minimum (0),
+    0x2A, 0xFF, 0x00, //     Usage Maximum (255),
+    0x81, 0x00, //     Input (Data, Array),
+    0x06, 0x00, 0xFF, //     Usage Page (Vendor Defined 0xFF00),
+    0x09, 0x01, //     Usage (0x01),
+    0x95, 0x02, //     Report Count (2),
+    0x75, 0x08, //     Report Size (8),
+    0xB1, 0x02, //     Feature (Data, Variable, Absolute), ;Lock sync report
+    0xC0, // End Collection
+];
+
+/// Feature report forcing the predicted lock state: [`LOCK_SYNC_COMMAND`] followed by the lock
+/// state in the layout of [`KeyboardLedsReport`]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub struct LockSyncReport {
+    pub leds: KeyboardLedsReport,
+}
+
+impl LockSyncReport {
+    #[must_use]
+    pub fn pack(&self) -> [u8; LOCK_SYNC_REPORT_LEN] {
+        [LOCK_SYNC_COMMAND, self.leds.pack().map_or(0, |[b]| b)]
+    }
+
+    pub fn unpack(data: &[u8]) -> usb_device::Result<Self> {
+        match *data {
+            [LOCK_SYNC_COMMAND, leds] => Ok(Self {
+                leds: KeyboardLedsReport::unpack(&[leds]).map_err(|_| UsbError::ParseError)?,
+            }),
+            _ => Err(UsbError::ParseError),
+        }
+    }
+}
+
+/// Report received from the host by a keyboard
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HostReport {
+    /// LED output report
+    Leds(KeyboardLedsReport),
+    /// Lock sync feature report
+    LockSync(LockSyncReport),
+}
+
+impl HostReport {
+    /// Parse a report, distinguished by length
+    pub fn unpack(data: &[u8]) -> usb_device::Result<Self> {
+        match data.len() {
+            1 => KeyboardLedsReport::unpack(&[data[0]])
+                .map(Self::Leds)
+                .map_err(|_| UsbError::ParseError),
+            LOCK_SYNC_REPORT_LEN => LockSyncReport::unpack(data).map(Self::LockSync),
+            _ => Err(UsbError::ParseError),
+        }
+    }
+}
+
+/// Best guess of the host's lock key state
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub struct LockStatePredictor {
+    state: KeyboardLedsReport,
+    held: KeyboardLedsReport,
+}
+
+impl LockStatePredictor {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Predicted lock state
+    #[must_use]
+    pub fn state(&self) -> KeyboardLedsReport {
+        self.state
+    }
+
+    /// Update the prediction with the keys of a report sent to the host, a lock key toggles its
+    /// lock when pressed
+    pub fn keys_sent<K: IntoIterator<Item = Keyboard>>(&mut self, keys: K) {
+        let mut held = KeyboardLedsReport::default();
+        for k in keys {
+            match k {
+                Keyboard::KeypadNumLockAndClear => held.num_lock = true,
+                Keyboard::CapsLock => held.caps_lock = true,
+                Keyboard::ScrollLock => held.scroll_lock = true,
+                _ => {}
+            }
+        }
+
+        self.state.num_lock ^= held.num_lock && !self.held.num_lock;
+        self.state.caps_lock ^= held.caps_lock && !self.held.caps_lock;
+        self.state.scroll_lock ^= held.scroll_lock && !self.held.scroll_lock;
+        self.held = held;
+    }
+
+    /// Replace the prediction with the state reported by the host or forced by a host tool
+    pub fn update(&mut self, report: HostReport) {
+        self.state = match report {
+            HostReport::Leds(leds) | HostReport::LockSync(LockSyncReport { leds }) => leds,
+        };
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn lock_keys_toggle_on_press() {
+        let mut predictor = LockStatePredictor::new();
+        predictor.keys_sent([Keyboard::CapsLock, Keyboard::A]);
+        predictor.keys_sent([Keyboard::CapsLock]);
+        assert!(predictor.state().caps_lock);
+        predictor.keys_sent([]);
+        predictor.keys_sent([Keyboard::CapsLock, Keyboard::KeypadNumLockAndClear]);
+        assert_eq!(predictor.state(), KeyboardLedsReport::new().num_lock(true));
+
+        predictor.update(HostReport::Leds(
+            KeyboardLedsReport::new().scroll_lock(true),
+        ));
+        assert_eq!(
+            predictor.state(),
+            KeyboardLedsReport::new().scroll_lock(true)
+        );
+    }
+
+    #[test]
+    fn host_reports() {
+        let sync = LockSyncReport {
+            leds: KeyboardLedsReport::new().caps_lock(true),
+        };
+        assert_eq!(sync.pack(), [LOCK_SYNC_COMMAND, 0x02]);
+        assert_eq!(
+            HostReport::unpack(&sync.pack()).ok(),
+            Some(HostReport::LockSync(sync))
+        );
+        assert_eq!(
+            HostReport::unpack(&[0x01]).ok(),
+            Some(HostReport::Leds(KeyboardLedsReport::new().num_lock(true)))
+        );
+        assert!(HostReport::unpack(&[0x00, 0x02]).is_err());
+        assert!(HostReport::unpack(&[]).is_err());
+    }
+}
@@ -0,0 +1,203 @@
+//! Forwarding of locally typed characters to the USB host
+//!
+//! On a device such as the Precursor, the Xous keyboard service delivers keystrokes as
+//! characters. A [`KeyBridge`] translates each of them into a [`Keystroke`] on the host keyboard
+//! layout and paces the resulting reports, so the device can be used as a keyboard for the host.
+//!
+//! Characters are translated as follows:
+//!
+//! * Shifted and `AltGr` characters are typed with the modifiers the host [`Layout`] needs
+//! * `←`, `→`, `↑` and `↓` are the arrow keys
+//! * `\r` and `\n` are Enter, `\u{8}` is Backspace, `\u{7f}` is Delete and `\u{1b}` is Escape
+//! * `\u{11}` to `\u{14}` are F1 to F4, as sent for the Precursor function keys
+//! * Any other control character `\u{1}` to `\u{1a}` is Ctrl with the corresponding letter
+//!
+//! ```
+//! # use xous_usb_hid::device::keyboard::layout::UsQwerty;
+//! # use xous_usb_hid::device::keyboard::passthrough::KeyBridge;
+//! # use fugit::ExtU32;
+//! let mut bridge = KeyBridge::<_, 32>::new(UsQwerty, 8.millis());
+//!
+//! // for each character received from the keyboard service
+//! bridge.receive('A').ok();
+//!
+//! // every 1ms
+//! bridge.tick();
+//! if let Some(keystroke) = bridge.report() {
+//!     // keyboard.write_report(keystroke) - advance once the report has been accepted
+//!     bridge.advance();
+//! }
+//! ```
+
+use crate::device::keyboard::layout::Layout;
+use crate::device::keyboard::typing::{Keystroke, Modifiers, ReleasePolicy, Typist};
+use crate::page::Keyboard;
+use crate::UsbHidError;
+use fugit::{ExtU32, MillisDurationU32};
+
+/// Keystroke typing `c` on the host, see the [module docs](self) for the translation
+#[must_use]
+pub fn translate<L: Layout>(c: char, layout: &L) -> Option<Keystroke> {
+    let key = match c {
+        '←' => Keyboard::LeftArrow,
+        '→' => Keyboard::RightArrow,
+        '↑' => Keyboard::UpArrow,
+        '↓' => Keyboard::DownArrow,
+        '\r' | '\n' => Keyboard::ReturnEnter,
+        '\u{8}' => Keyboard::DeleteBackspace,
+        '\u{7f}' => Keyboard::DeleteForward,
+        '\u{1b}' => Keyboard::Escape,
+        '\u{11}' => Keyboard::F1,
+        '\u{12}' => Keyboard::F2,
+        '\u{13}' => Keyboard::F3,
+        '\u{14}' => Keyboard::F4,
+        '\u{1}'..='\u{1a}' if c != '\t' => {
+            let letter = char::from(b'a' + (c as u8) - 1);
+            let keystroke = layout.keystroke(letter)?;
+            return Some(Keystroke::new(
+                keystroke.modifiers.union(Modifiers::LEFT_CTRL),
+                keystroke.key,
+            ));
+        }
+        _ => return layout.keystroke(c),
+    };
+    Some(Keystroke::key(key))
+}
+
+/// Translates and paces characters typed on the device, see the [module docs](self)
+#[derive(Clone, Debug)]
+pub struct KeyBridge<L, const N: usize> {
+    layout: L,
+    typist: Typist<N>,
+    spacing: MillisDurationU32,
+    since_report: MillisDurationU32,
+}
+
+impl<L: Layout, const N: usize> KeyBridge<L, N> {
+    /// Bridge typing on `layout`, sending reports at least `spacing` apart
+    #[must_use]
+    pub fn new(layout: L, spacing: MillisDurationU32) -> Self {
+        Self {
+            layout,
+            typist: Typist::new(),
+            spacing,
+            since_report: spacing,
+        }
+    }
+
+    /// Only release keys between keystrokes where needed, see [`ReleasePolicy::Conflicting`]
+    #[must_use]
+    pub fn release_policy(mut self, release_policy: ReleasePolicy) -> Self {
+        self.typist.set_release_policy(release_policy);
+        self
+    }
+
+    /// Queue a character received from the keyboard service
+    ///
+    /// Fails with [`UsbHidError::SerializationError`] if the character can't be typed on the
+    /// layout and with [`UsbHidError::WouldBlock`] if the queue is full
+    pub fn receive(&mut self, c: char) -> Result<(), UsbHidError> {
+        let keystroke = translate(c, &self.layout).ok_or_else(|| {
+            warn!("No keystroke for {:?}", c);
+            UsbHidError::SerializationError
+        })?;
+        self.typist.push(keystroke)
+    }
+
+    /// Call every 1ms
+    pub fn tick(&mut self) {
+        if self.since_report < self.spacing {
+            self.since_report += 1.millis();
+        }
+    }
+
+    /// The next report to send, `None` if there is nothing to type or the previous report was
+    /// sent less than the spacing ago
+    #[must_use]
+    pub fn report(&self) -> Option<Keystroke> {
+        if self.since_report < self.spacing {
+            return None;
+        }
+        self.typist.report()
+    }
+
+    /// Move on to the next report, call once the report returned by [`KeyBridge::report`] has
+    /// been accepted by the device
+    pub fn advance(&mut self) {
+        if self.report().is_some() {
+            self.typist.advance();
+            self.since_report = 0.millis();
+        }
+    }
+
+    /// `true` if there are no reports left to send
+    #[must_use]
+    pub fn is_idle(&self) -> bool {
+        self.typist.is_idle()
+    }
+
+    /// Drop all queued characters, e.g. when passthrough is switched off. Any key currently
+    /// pressed will still be released
+    pub fn clear(&mut self) {
+        self.typist.clear();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+    use crate::device::keyboard::layout::{FrenchAzerty, UsQwerty};
+
+    #[test]
+    fn control_characters_translated() {
+        assert_eq!(
+            translate('A', &UsQwerty),
+            Some(Keystroke::new(Modifiers::LEFT_SHIFT, Keyboard::A))
+        );
+        assert_eq!(
+            translate('↑', &UsQwerty),
+            Some(Keystroke::key(Keyboard::UpArrow))
+        );
+        assert_eq!(
+            translate('\u{12}', &UsQwerty),
+            Some(Keystroke::key(Keyboard::F2))
+        );
+        assert_eq!(
+            translate('\t', &UsQwerty),
+            Some(Keystroke::key(Keyboard::Tab))
+        );
+        // Ctrl+A uses the key typing `a` on the host layout
+        assert_eq!(
+            translate('\u{1}', &FrenchAzerty),
+            Some(Keystroke::new(Modifiers::LEFT_CTRL, Keyboard::Q))
+        );
+        assert_eq!(translate('€', &UsQwerty), None);
+    }
+
+    #[test]
+    fn reports_are_paced() {
+        let mut bridge = KeyBridge::<_, 4>::new(UsQwerty, 2.millis());
+        bridge.receive('a').unwrap();
+        assert!(matches!(
+            bridge.receive('€'),
+            Err(UsbHidError::SerializationError)
+        ));
+
+        assert_eq!(bridge.report(), Some(Keystroke::key(Keyboard::A)));
+        bridge.advance();
+        assert_eq!(bridge.report(), None);
+        // Not sent yet, advancing has no effect
+        bridge.advance();
+        bridge.tick();
+        assert_eq!(bridge.report(), None);
+        bridge.tick();
+        assert_eq!(bridge.report(), Some(Keystroke::RELEASE));
+        bridge.advance();
+        bridge.tick();
+        bridge.tick();
+        assert_eq!(bridge.report(), None);
+        assert!(bridge.is_idle());
+    }
+}
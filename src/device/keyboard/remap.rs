@@ -0,0 +1,326 @@
+//! Per key remapping configured by the host
+//!
+//! A [`RemapTable`] replaces keyboard usages before they are sent, e.g. to swap Caps Lock and
+//! Left Control. Host tools read and write the table with a vendor defined feature report, see
+//! [`BOOT_KEYBOARD_REMAP_REPORT_DESCRIPTOR`], so keys can be remapped without reflashing. The
+//! table can be saved with [`RemapTable::write_to`], e.g. to flash, and restored at start up
+//! with [`RemapTable::from_bytes`].
+//!
+//! Requests and responses are [`REMAP_REPORT_LEN`] bytes. The host sends a request with
+//! `SET_REPORT`, the device replaces it with the response, which the host reads with
+//! `GET_REPORT`:
+//!
+//! | Request                        | Response                                                |
+//! |--------------------------------|---------------------------------------------------------|
+//! | [`REMAP_GET`], index           | `0x81`, status, from, to, entry count, capacity         |
+//! | [`REMAP_SET`], from, to        | `0x82`, status, from, to, entry count, capacity         |
+//! | [`REMAP_CLEAR`]                | `0x83`, status, 0, 0, entry count, capacity             |
+//!
+//! Setting a usage to itself removes its entry. Unused bytes are zero.
+//!
+//! ```
+//! # use xous_usb_hid::device::keyboard::remap::*;
+//! # use xous_usb_hid::page::Keyboard;
+//! let mut table = RemapTable::<16>::new();
+//!
+//! // after usb_dev.poll()
+//! // if table.service(keyboard.interface()) { save the table }
+//! table.handle_request(&[REMAP_SET, Keyboard::CapsLock.into(), Keyboard::LeftControl.into()]);
+//!
+//! let keys: heapless::Vec<_, 6> = table.remap_keys([Keyboard::CapsLock, Keyboard::A]).collect();
+//! assert_eq!(keys, [Keyboard::LeftControl, Keyboard::A]);
+//! // keyboard.write_report(keys)
+//! ```
+
+use crate::device::keyboard::KeyboardUsage;
+use crate::interface::InterfaceClass;
+use crate::UsbHidError;
+use heapless::Vec;
+use usb_device::UsbError;
+
+use crate::protocol::remap::REPORT_ID;
+pub use crate::protocol::remap::{
+    CLEAR as REMAP_CLEAR, ERR_COMMAND as REMAP_ERR_COMMAND, ERR_FULL as REMAP_ERR_FULL,
+    ERR_INDEX as REMAP_ERR_INDEX, GET as REMAP_GET, OK as REMAP_OK, REPORT_LEN as REMAP_REPORT_LEN,
+    RESPONSE as REMAP_RESPONSE, SET as REMAP_SET,
+};
+
+/// [`BOOT_KEYBOARD_REPORT_DESCRIPTOR`](crate::device::keyboard::BOOT_KEYBOARD_REPORT_DESCRIPTOR)
+/// with a vendor defined feature report of [`REMAP_REPORT_LEN`] bytes for the remap protocol
+#[rustfmt::skip]
+pub const BOOT_KEYBOARD_REMAP_REPORT_DESCRIPTOR: &[u8] = &[
+    0x05, 0x01, // Usage Page (Generic Desktop),
+    0x09, 0x06, // Usage (Keyboard),
+    0xA1, 0x01, // Collection (Application),
+    0x75, 0x01, //     Report Size (1),
+    0x95, 0x08, //     Report Count (8),
+    0x05, 0x07, //     Usage Page (Key Codes),
+    0x19, 0xE0, //     Usage Minimum (224),
+    0x29, 0xE7, //     Usage Maximum (231),
+    0x15, 0x00, //     Logical Minimum (0),
+    0x25, 0x01, //     Logical Maximum (1),
+    0x81, 0x02, //     Input (Data, Variable, Absolute), ;Modifier byte
+    0x95, 0x01, //     Report Count (1),
+    0x75, 0x08, //     Report Size (8),
+    0x81, 0x01, //     Input (Constant), ;Reserved byte
+    0x95, 0x05, //     Report Count (5),
+    0x75, 0x01, //     Report Size (1),
+    0x05, 0x08, //     Usage Page (LEDs),
+    0x19, 0x01, //     Usage Minimum (1),
+    0x29, 0x05, //     Usage Maximum (5),
+    0x91, 0x02, //     Output (Data, Variable, Absolute), ;LED report
+    0x95, 0x01, //     Report Count (1),
+    0x75, 0x03, //     Report Size (3),
+    0x91, 0x01, //     Output (Constant), ;LED report padding
+    0x95, 0x06, //     Report Count (6),
+    0x75, 0x08, //     Report Size (8),
+    0x15, 0x00, //     Logical Minimum (0),
+    0x26, 0xFF, 0x00, //     Logical Maximum(255),
+    0x05, 0x07, //     Usage Page (Key Codes),
+    0x19, 0x00, //     Usage Minimum (0),
+    0x2A, 0xFF, 0x00, //     Usage Maximum (255),
+    0x81, 0x00, //     Input (Data, Array),
+    0x06, 0x00, 0xFF, //     Usage Page (Vendor Defined 0xFF00),
+    0x09, 0x02, //     Usage (0x02),
+    0x95, 0x08, //     Report Count (8),
+    0x75, 0x08, //     Report Size (8),
+    0xB1, 0x02, //     Feature (Data, Variable, Absolute), ;Remap report
+    0xC0, // End Collection
+];
+
+/// Usage to usage remapping of up to `N` keys, see the [module docs](self)
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub struct RemapTable<const N: usize> {
+    entries: Vec<(KeyboardUsage, KeyboardUsage), N>,
+}
+
+impl<const N: usize> RemapTable<N> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Usage sent for `from`, `None` if it isn't remapped
+    #[must_use]
+    pub fn get(&self, from: impl Into<KeyboardUsage>) -> Option<KeyboardUsage> {
+        let from = from.into();
+        self.entries
+            .iter()
+            .find(|(f, _)| *f == from)
+            .map(|(_, to)| *to)
+    }
+
+    /// Send `to` in place of `from`, replacing any existing entry for `from`. Mapping a usage to
+    /// itself removes its entry
+    ///
+    /// Fails with [`UsbError::BufferOverflow`] if the table is full
+    pub fn set(
+        &mut self,
+        from: impl Into<KeyboardUsage>,
+        to: impl Into<KeyboardUsage>,
+    ) -> Result<(), UsbHidError> {
+        let (from, to) = (from.into(), to.into());
+        if from == to {
+            self.remove(from);
+            return Ok(());
+        }
+        if let Some(entry) = self.entries.iter_mut().find(|(f, _)| *f == from) {
+            entry.1 = to;
+            return Ok(());
+        }
+        self.entries.push((from, to)).map_err(|_| {
+            warn!("Remap table full");
+            UsbHidError::UsbError(UsbError::BufferOverflow)
+        })
+    }
+
+    /// Remove the entry for `from`, if any
+    pub fn remove(&mut self, from: impl Into<KeyboardUsage>) {
+        let from = from.into();
+        self.entries.retain(|(f, _)| *f != from);
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Entries as `(from, to)` pairs
+    #[must_use]
+    pub fn entries(&self) -> &[(KeyboardUsage, KeyboardUsage)] {
+        &self.entries
+    }
+
+    /// Usage to send for `usage`
+    #[must_use]
+    pub fn remap(&self, usage: impl Into<KeyboardUsage>) -> KeyboardUsage {
+        let usage = usage.into();
+        self.get(usage).unwrap_or(usage)
+    }
+
+    /// Usages to send for `keys`, e.g. to pass to a keyboard's `write_report`
+    pub fn remap_keys<'s, K>(&'s self, keys: K) -> impl Iterator<Item = KeyboardUsage> + 's
+    where
+        K: IntoIterator,
+        K::IntoIter: 's,
+        K::Item: Into<KeyboardUsage>,
+    {
+        keys.into_iter().map(|k| self.remap(k))
+    }
+
+    /// Handle a remap request, returns the response, `None` if `report` isn't a request, e.g.
+    /// because it is the response to the previous request
+    pub fn handle_request(&mut self, report: &[u8]) -> Option<[u8; REMAP_REPORT_LEN]> {
+        let mut request = [0; REMAP_REPORT_LEN];
+        let n = report.len().min(REMAP_REPORT_LEN);
+        request[..n].copy_from_slice(&report[..n]);
+        let [command, a, b, ..] = request;
+        if command == 0 || command & REMAP_RESPONSE != 0 {
+            return None;
+        }
+
+        let (status, from, to) = match command {
+            REMAP_GET => match self.entries.get(usize::from(a)) {
+                Some((from, to)) => (REMAP_OK, from.0, to.0),
+                None => (REMAP_ERR_INDEX, 0, 0),
+            },
+            REMAP_SET => match self.set(a, b) {
+                Ok(()) => (REMAP_OK, a, b),
+                Err(_) => (REMAP_ERR_FULL, a, b),
+            },
+            REMAP_CLEAR => {
+                self.clear();
+                (REMAP_OK, 0, 0)
+            }
+            _ => (REMAP_ERR_COMMAND, 0, 0),
+        };
+        let count = u8::try_from(self.entries.len()).unwrap_or(u8::MAX);
+        let capacity = u8::try_from(N).unwrap_or(u8::MAX);
+        Some([
+            command | REMAP_RESPONSE,
+            status,
+            from,
+            to,
+            count,
+            capacity,
+            0,
+            0,
+        ])
+    }
+
+    /// Handle a pending request in the feature report kept by `interface`, which must have been
+    /// built with [`InterfaceBuilder::feature_report`](crate::interface::InterfaceBuilder::feature_report)
+    /// for report ID 0. Call after every `usb_dev.poll()`
+    ///
+    /// Returns `true` if the table changed and should be saved
+    pub fn service<'a, I: InterfaceClass<'a> + ?Sized>(&mut self, interface: &mut I) -> bool {
+        let mut report = [0; REMAP_REPORT_LEN];
+        let Some(n) = interface.get_feature_report(REPORT_ID, &mut report) else {
+            return false;
+        };
+        let before = self.entries.clone();
+        let Some(response) = self.handle_request(&report[..n]) else {
+            return false;
+        };
+        interface.set_feature_report(REPORT_ID, &response);
+        self.entries != before
+    }
+
+    /// Write the table to `buf` for storage, returns the length written
+    ///
+    /// Fails with [`UsbError::BufferOverflow`] if `buf` is shorter than `1 + 2 * N` bytes
+    pub fn write_to(&self, buf: &mut [u8]) -> Result<usize, UsbHidError> {
+        let len = 1 + 2 * self.entries.len();
+        let buf = buf
+            .get_mut(..len)
+            .ok_or(UsbHidError::UsbError(UsbError::BufferOverflow))?;
+        buf[0] = u8::try_from(self.entries.len()).map_err(|_| UsbHidError::SerializationError)?;
+        for ((from, to), b) in self.entries.iter().zip(buf[1..].chunks_exact_mut(2)) {
+            b.copy_from_slice(&[from.0, to.0]);
+        }
+        Ok(len)
+    }
+
+    /// Read a table written by [`RemapTable::write_to`]
+    ///
+    /// Fails with [`UsbHidError::SerializationError`] if `data` is truncated or holds more than
+    /// `N` entries
+    pub fn from_bytes(data: &[u8]) -> Result<Self, UsbHidError> {
+        let (&count, pairs) = data.split_first().ok_or(UsbHidError::SerializationError)?;
+        let pairs = pairs
+            .get(..2 * usize::from(count))
+            .ok_or(UsbHidError::SerializationError)?;
+        let mut table = Self::new();
+        for pair in pairs.chunks_exact(2) {
+            table
+                .set(pair[0], pair[1])
+                .map_err(|_| UsbHidError::SerializationError)?;
+        }
+        Ok(table)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+    use crate::page::Keyboard;
+
+    #[test]
+    fn remap_requests() {
+        let mut table = RemapTable::<2>::new();
+        let caps = u8::from(Keyboard::CapsLock);
+        let ctrl = u8::from(Keyboard::LeftControl);
+
+        assert_eq!(
+            table.handle_request(&[REMAP_SET, caps, ctrl]),
+            Some([0x82, REMAP_OK, caps, ctrl, 1, 2, 0, 0])
+        );
+        assert_eq!(table.remap(Keyboard::CapsLock), Keyboard::LeftControl);
+        assert_eq!(table.remap(Keyboard::A), Keyboard::A);
+        // Responses aren't handled again
+        assert_eq!(table.handle_request(&[0x82, REMAP_OK, caps, ctrl]), None);
+
+        table.set(Keyboard::A, Keyboard::B).unwrap();
+        assert_eq!(
+            table.handle_request(&[REMAP_SET, 0x10, 0x11]),
+            Some([0x82, REMAP_ERR_FULL, 0x10, 0x11, 2, 2, 0, 0])
+        );
+        assert_eq!(
+            table.handle_request(&[REMAP_GET, 1]),
+            Some([0x81, REMAP_OK, 0x04, 0x05, 2, 2, 0, 0])
+        );
+        assert_eq!(
+            table.handle_request(&[REMAP_GET, 2]),
+            Some([0x81, REMAP_ERR_INDEX, 0, 0, 2, 2, 0, 0])
+        );
+
+        // Mapping a usage to itself removes the entry
+        table.set(Keyboard::A, Keyboard::A).unwrap();
+        assert_eq!(table.entries().len(), 1);
+        assert_eq!(
+            table.handle_request(&[REMAP_CLEAR]),
+            Some([0x83, REMAP_OK, 0, 0, 0, 2, 0, 0])
+        );
+        assert_eq!(
+            table.handle_request(&[0x7F]),
+            Some([0xFF, REMAP_ERR_COMMAND, 0, 0, 0, 2, 0, 0])
+        );
+    }
+
+    #[test]
+    fn stored_table_round_trip() {
+        let mut table = RemapTable::<4>::new();
+        table.set(Keyboard::CapsLock, Keyboard::Escape).unwrap();
+        table.set(Keyboard::Escape, Keyboard::CapsLock).unwrap();
+
+        let mut buf = [0; 9];
+        let len = table.write_to(&mut buf).unwrap();
+        assert_eq!(buf[..len], [2, 0x39, 0x29, 0x29, 0x39]);
+        assert_eq!(RemapTable::<4>::from_bytes(&buf[..len]).unwrap(), table);
+
+        assert!(RemapTable::<4>::from_bytes(&buf[..4]).is_err());
+        assert!(RemapTable::<1>::from_bytes(&buf[..len]).is_err());
+        assert!(table.write_to(&mut buf[..4]).is_err());
+    }
+}
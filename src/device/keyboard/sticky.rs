@@ -0,0 +1,246 @@
+//! Sticky keys, modifiers that stay down after being tapped
+//!
+//! [`StickyKeys`] sits between the key matrix and the keyboard report: it is given the keys held
+//! on every scan and adds the modifiers that have been tapped, so shortcuts can be typed one key
+//! at a time. A modifier is tapped when it is pressed and released without any other key in
+//! between; a modifier held while pressing another key works as usual. How taps behave depends on
+//! the [`StickyMode`]. A tapped modifier that isn't used within the timeout is dropped again.
+//!
+//! ```
+//! # use xous_usb_hid::device::keyboard::sticky::{StickyKeys, StickyMode};
+//! # use xous_usb_hid::page::Keyboard;
+//! # use fugit::ExtU32;
+//! let mut sticky = StickyKeys::new(StickyMode::OneShotOrLock, 3000.millis());
+//!
+//! // on every scan, keyboard.write_report(sticky.update(&keys))
+//! sticky.update(&[Keyboard::LeftShift]).for_each(drop);
+//! sticky.update(&[]).for_each(drop);
+//! let keys: Vec<_> = sticky.update(&[Keyboard::A]).collect();
+//! assert_eq!(keys, [Keyboard::A, Keyboard::LeftShift]);
+//!
+//! // every 1ms
+//! sticky.tick();
+//! ```
+
+use crate::device::keyboard::typing::Modifiers;
+use crate::page::Keyboard;
+use fugit::MillisDurationU32;
+
+/// What tapping a modifier does
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, Hash)]
+pub enum StickyMode {
+    /// The modifier applies to the next key only, tapping it again cancels it
+    OneShot,
+    /// The modifier stays down until it is tapped again
+    Toggle,
+    /// The modifier applies to the next key only, tapping it twice keeps it down until it is
+    /// tapped again
+    #[default]
+    OneShotOrLock,
+}
+
+/// Sticky keys engine, see the [module docs](self)
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct StickyKeys {
+    mode: StickyMode,
+    timeout_ms: u32,
+    idle_ms: u32,
+    held: Modifiers,
+    tapping: Modifiers,
+    latched: Modifiers,
+    locked: Modifiers,
+    key_down: bool,
+    used: bool,
+}
+
+impl StickyKeys {
+    /// Tapped modifiers in `mode`, one-shot modifiers are dropped after `timeout` without a key
+    /// press
+    #[must_use]
+    pub const fn new(mode: StickyMode, timeout: MillisDurationU32) -> Self {
+        Self {
+            mode,
+            timeout_ms: timeout.ticks(),
+            idle_ms: 0,
+            held: Modifiers::NONE,
+            tapping: Modifiers::NONE,
+            latched: Modifiers::NONE,
+            locked: Modifiers::NONE,
+            key_down: false,
+            used: false,
+        }
+    }
+
+    #[must_use]
+    pub fn mode(&self) -> StickyMode {
+        self.mode
+    }
+
+    /// Modifiers applying to the next key, e.g. for indicator LEDs
+    #[must_use]
+    pub fn latched(&self) -> Modifiers {
+        self.latched
+    }
+
+    /// Modifiers kept down until tapped again
+    #[must_use]
+    pub fn locked(&self) -> Modifiers {
+        self.locked
+    }
+
+    /// Update with the keys held, returns the keys to report: `keys` followed by the latched and
+    /// locked modifiers
+    pub fn update<'k>(&mut self, keys: &'k [Keyboard]) -> impl Iterator<Item = Keyboard> + 'k {
+        let pressed = keys
+            .iter()
+            .filter_map(|&k| Modifiers::from_key(k))
+            .fold(Modifiers::NONE, Modifiers::union);
+        let key_down = keys
+            .iter()
+            .any(|&k| k != Keyboard::NoEventIndicated && Modifiers::from_key(k).is_none());
+
+        self.tapping = self.tapping.union(without(pressed, self.held));
+        if key_down {
+            self.tapping = Modifiers::NONE;
+            if !self.key_down && !self.latched.is_empty() {
+                self.used = true;
+            }
+        } else if self.used {
+            // The key using the one-shot modifiers has been released
+            self.latched = Modifiers::NONE;
+            self.used = false;
+        }
+
+        let released = without(self.held, pressed);
+        let taps = Modifiers::from_bits(released.bits() & self.tapping.bits());
+        self.tapping = without(self.tapping, released);
+        for tap in taps.keys().filter_map(Modifiers::from_key) {
+            self.tapped(tap);
+        }
+
+        self.held = pressed;
+        self.key_down = key_down;
+        let sticky = self.latched.union(self.locked);
+        keys.iter().copied().chain(sticky.keys())
+    }
+
+    /// Must be called every 1ms, drops one-shot modifiers after the timeout
+    pub fn tick(&mut self) {
+        if self.latched.is_empty() || self.used {
+            self.idle_ms = 0;
+            return;
+        }
+        self.idle_ms += 1;
+        if self.idle_ms >= self.timeout_ms {
+            trace!("One-shot modifiers {:X} timed out", self.latched.bits());
+            self.latched = Modifiers::NONE;
+            self.idle_ms = 0;
+        }
+    }
+
+    /// Drop all latched and locked modifiers
+    pub fn clear(&mut self) {
+        *self = Self::new(self.mode, MillisDurationU32::from_ticks(self.timeout_ms));
+    }
+
+    fn tapped(&mut self, modifier: Modifiers) {
+        self.idle_ms = 0;
+        match self.mode {
+            StickyMode::OneShot if self.latched.contains(modifier) => {
+                self.latched = without(self.latched, modifier);
+            }
+            StickyMode::Toggle => {
+                self.locked = Modifiers::from_bits(self.locked.bits() ^ modifier.bits());
+            }
+            StickyMode::OneShotOrLock if self.locked.contains(modifier) => {
+                self.locked = without(self.locked, modifier);
+            }
+            StickyMode::OneShotOrLock if self.latched.contains(modifier) => {
+                self.latched = without(self.latched, modifier);
+                self.locked = self.locked.union(modifier);
+            }
+            StickyMode::OneShot | StickyMode::OneShotOrLock => {
+                self.latched = self.latched.union(modifier);
+            }
+        }
+    }
+}
+
+fn without(modifiers: Modifiers, other: Modifiers) -> Modifiers {
+    Modifiers::from_bits(modifiers.bits() & !other.bits())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use fugit::ExtU32;
+    use std::vec::Vec;
+
+    fn update(sticky: &mut StickyKeys, keys: &[Keyboard]) -> Vec<Keyboard> {
+        sticky.update(keys).collect()
+    }
+
+    #[test]
+    fn one_shot_applies_to_next_key() {
+        let mut sticky = StickyKeys::new(StickyMode::OneShot, 10.millis());
+
+        update(&mut sticky, &[Keyboard::LeftShift]);
+        assert_eq!(update(&mut sticky, &[]), [Keyboard::LeftShift]);
+        assert_eq!(sticky.latched(), Modifiers::LEFT_SHIFT);
+        assert_eq!(
+            update(&mut sticky, &[Keyboard::A]),
+            [Keyboard::A, Keyboard::LeftShift]
+        );
+        assert_eq!(update(&mut sticky, &[]), []);
+        assert_eq!(update(&mut sticky, &[Keyboard::B]), [Keyboard::B]);
+
+        // Held while pressing a key isn't a tap
+        update(&mut sticky, &[Keyboard::LeftControl]);
+        update(&mut sticky, &[Keyboard::LeftControl, Keyboard::C]);
+        update(&mut sticky, &[Keyboard::LeftControl]);
+        assert_eq!(update(&mut sticky, &[]), []);
+
+        // Tapping again cancels, and unused modifiers time out
+        update(&mut sticky, &[Keyboard::LeftAlt]);
+        update(&mut sticky, &[]);
+        update(&mut sticky, &[Keyboard::LeftAlt]);
+        assert_eq!(update(&mut sticky, &[]), []);
+        update(&mut sticky, &[Keyboard::LeftAlt]);
+        update(&mut sticky, &[]);
+        for _ in 0..10 {
+            sticky.tick();
+        }
+        assert_eq!(sticky.latched(), Modifiers::NONE);
+    }
+
+    #[test]
+    fn double_tap_locks() {
+        let mut sticky = StickyKeys::new(StickyMode::OneShotOrLock, 10.millis());
+        for _ in 0..2 {
+            update(&mut sticky, &[Keyboard::RightControl]);
+            update(&mut sticky, &[]);
+        }
+        assert_eq!(sticky.locked(), Modifiers::RIGHT_CTRL);
+        for _ in 0..20 {
+            sticky.tick();
+        }
+        for _ in 0..2 {
+            assert_eq!(
+                update(&mut sticky, &[Keyboard::X]),
+                [Keyboard::X, Keyboard::RightControl]
+            );
+            update(&mut sticky, &[]);
+        }
+        update(&mut sticky, &[Keyboard::RightControl]);
+        assert_eq!(update(&mut sticky, &[]), []);
+
+        let mut toggle = StickyKeys::new(StickyMode::Toggle, 10.millis());
+        update(&mut toggle, &[Keyboard::LeftGUI]);
+        update(&mut toggle, &[]);
+        assert_eq!(toggle.locked(), Modifiers::LEFT_GUI);
+        toggle.clear();
+        assert_eq!(update(&mut toggle, &[]), []);
+    }
+}
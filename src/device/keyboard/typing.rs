@@ -0,0 +1,510 @@
+//! Queued typing of keystrokes through a keyboard device
+//!
+//! A [`Typist`] holds a queue of [`Keystroke`]s and turns each of them into a press report
+//! followed by a release report. With [`ReleasePolicy::Conflicting`] the release is only sent
+//! where the next keystroke needs it:
+//!
+//! ```
+//! # use xous_usb_hid::device::keyboard::typing::{Keystroke, Typist};
+//! # use xous_usb_hid::device::keyboard::KeyboardLedsReport;
+//! # use xous_usb_hid::page::Keyboard;
+//! let mut typist = Typist::<16>::new();
+//! typist.type_number(42, KeyboardLedsReport::default()).ok();
+//!
+//! while let Some(keystroke) = typist.report() {
+//!     // keyboard.write_report(keystroke) - advance once the report has been accepted
+//!     typist.advance();
+//! }
+//! ```
+
+use crate::device::keyboard::layout::Layout;
+use crate::device::keyboard::KeyboardLedsReport;
+use crate::page::Keyboard;
+use crate::UsbHidError;
+use heapless::{Deque, Vec};
+
+/// Modifier keys, encoded as the modifier byte of a boot keyboard report
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, Hash)]
+pub struct Modifiers(u8);
+
+impl Modifiers {
+    pub const NONE: Self = Self(0);
+    pub const LEFT_CTRL: Self = Self(0x01);
+    pub const LEFT_SHIFT: Self = Self(0x02);
+    pub const LEFT_ALT: Self = Self(0x04);
+    pub const LEFT_GUI: Self = Self(0x08);
+    pub const RIGHT_CTRL: Self = Self(0x10);
+    pub const RIGHT_SHIFT: Self = Self(0x20);
+    pub const RIGHT_ALT: Self = Self(0x40);
+    pub const RIGHT_GUI: Self = Self(0x80);
+
+    #[must_use]
+    pub const fn from_bits(bits: u8) -> Self {
+        Self(bits)
+    }
+
+    #[must_use]
+    pub const fn bits(self) -> u8 {
+        self.0
+    }
+
+    #[must_use]
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    #[must_use]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    #[must_use]
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// The modifier corresponding to a modifier key usage, `None` for any other key
+    #[must_use]
+    pub fn from_key(key: Keyboard) -> Option<Self> {
+        let usage = u8::from(key);
+        if (u8::from(Keyboard::LeftControl)..=u8::from(Keyboard::RightGUI)).contains(&usage) {
+            Some(Self(1 << (usage - u8::from(Keyboard::LeftControl))))
+        } else {
+            None
+        }
+    }
+
+    /// The modifier key usages that make up this set of modifiers
+    pub fn keys(self) -> impl Iterator<Item = Keyboard> {
+        (0..8)
+            .filter(move |i| self.0 & (1 << i) != 0)
+            .map(|i| Keyboard::from(u8::from(Keyboard::LeftControl) + i))
+    }
+}
+
+/// A single key press, with modifiers held for the duration of the press
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, Hash)]
+pub struct Keystroke {
+    pub modifiers: Modifiers,
+    pub key: Keyboard,
+}
+
+impl Keystroke {
+    /// All keys up
+    pub const RELEASE: Self = Self::new(Modifiers::NONE, Keyboard::NoEventIndicated);
+
+    #[must_use]
+    pub const fn new(modifiers: Modifiers, key: Keyboard) -> Self {
+        Self { modifiers, key }
+    }
+
+    #[must_use]
+    pub const fn key(key: Keyboard) -> Self {
+        Self::new(Modifiers::NONE, key)
+    }
+
+    #[must_use]
+    pub fn is_release(&self) -> bool {
+        self.modifiers.is_empty() && self.key == Keyboard::NoEventIndicated
+    }
+}
+
+impl IntoIterator for Keystroke {
+    type Item = Keyboard;
+    type IntoIter = core::iter::Chain<
+        <Vec<Keyboard, 8> as IntoIterator>::IntoIter,
+        core::option::IntoIter<Keyboard>,
+    >;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let modifiers: Vec<Keyboard, 8> = self.modifiers.keys().collect();
+        let key = Some(self.key).filter(|&k| k != Keyboard::NoEventIndicated);
+        modifiers.into_iter().chain(key)
+    }
+}
+
+/// When a [`Typist`] sends an all-up report between keystrokes
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, Hash)]
+pub enum ReleasePolicy {
+    /// Release all keys after every keystroke
+    #[default]
+    Always,
+    /// Release all keys only when the next keystroke would otherwise be lost or mis-typed: when
+    /// it uses the same key as the held keystroke (e.g. `a` then `a` or `A`), or when it uses
+    /// different modifiers. Otherwise the next keystroke replaces the held one in a single
+    /// report, roughly halving the number of reports needed
+    Conflicting,
+}
+
+impl ReleasePolicy {
+    fn needs_release(self, held: Keystroke, next: Keystroke) -> bool {
+        match self {
+            Self::Always => true,
+            Self::Conflicting => held.key == next.key || held.modifiers != next.modifiers,
+        }
+    }
+}
+
+/// Queue of keystrokes to be typed, each one sent as a press report followed, as required by the
+/// [`ReleasePolicy`], by a release report
+#[derive(Clone, Debug, Default)]
+pub struct Typist<const N: usize> {
+    queue: Deque<Keystroke, N>,
+    held: Option<Keystroke>,
+    release_policy: ReleasePolicy,
+}
+
+impl<const N: usize> Typist<N> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            queue: Deque::new(),
+            held: None,
+            release_policy: ReleasePolicy::default(),
+        }
+    }
+
+    #[must_use]
+    pub fn with_release_policy(mut self, release_policy: ReleasePolicy) -> Self {
+        self.release_policy = release_policy;
+        self
+    }
+
+    pub fn set_release_policy(&mut self, release_policy: ReleasePolicy) {
+        self.release_policy = release_policy;
+    }
+
+    #[must_use]
+    pub fn release_policy(&self) -> ReleasePolicy {
+        self.release_policy
+    }
+
+    /// `true` if there are no reports left to send
+    #[must_use]
+    pub fn is_idle(&self) -> bool {
+        self.held.is_none() && self.queue.is_empty()
+    }
+
+    /// Number of keystrokes that can be queued before the queue is full
+    #[must_use]
+    pub fn free(&self) -> usize {
+        self.queue.capacity() - self.queue.len()
+    }
+
+    /// Drop all queued keystrokes. Any key currently pressed will still be released
+    pub fn clear(&mut self) {
+        self.queue.clear();
+    }
+
+    /// Queue a single keystroke
+    pub fn push(&mut self, keystroke: Keystroke) -> Result<(), UsbHidError> {
+        self.queue
+            .push_back(keystroke)
+            .map_err(|_| UsbHidError::WouldBlock)
+    }
+
+    /// Queue keystrokes, either all of them or, if there isn't space, none of them
+    pub fn push_all<K>(&mut self, keystrokes: K) -> Result<(), UsbHidError>
+    where
+        K: IntoIterator<Item = Keystroke>,
+        K::IntoIter: ExactSizeIterator,
+    {
+        let keystrokes = keystrokes.into_iter();
+        if keystrokes.len() > self.free() {
+            return Err(UsbHidError::WouldBlock);
+        }
+        for k in keystrokes {
+            self.push(k)?;
+        }
+        Ok(())
+    }
+
+    /// The next report to send, `None` if there is nothing left to type
+    #[must_use]
+    pub fn report(&self) -> Option<Keystroke> {
+        match (self.held, self.queue.front().copied()) {
+            (Some(held), Some(next)) if !self.release_policy.needs_release(held, next) => {
+                Some(next)
+            }
+            (Some(_), _) => Some(Keystroke::RELEASE),
+            (None, next) => next,
+        }
+    }
+
+    /// Move on to the next report, call once the report returned by [`Typist::report`] has been
+    /// accepted by the device
+    ///
+    /// Keystrokes should not be queued between sending a report and calling `advance`, as they
+    /// can change which report `advance` assumes was sent
+    pub fn advance(&mut self) {
+        let press = match (self.held, self.queue.front().copied()) {
+            (Some(held), Some(next)) => !self.release_policy.needs_release(held, next),
+            (Some(_), None) => false,
+            (None, _) => true,
+        };
+        self.held = if press { self.queue.pop_front() } else { None };
+    }
+
+    /// Type a decimal number using the keypad
+    ///
+    /// If the host reports Num Lock as off it is toggled on for the duration of the number and
+    /// toggled back off afterwards
+    pub fn type_number(&mut self, n: u64, leds: KeyboardLedsReport) -> Result<(), UsbHidError> {
+        let mut digits: Vec<Keystroke, 20> = Vec::new();
+        let mut n = n;
+        loop {
+            #[allow(clippy::cast_possible_truncation)]
+            let digit = (n % 10) as u8;
+            digits
+                .push(Keystroke::key(keypad_digit(digit)))
+                .map_err(|_| UsbHidError::SerializationError)?;
+            n /= 10;
+            if n == 0 {
+                break;
+            }
+        }
+        digits.reverse();
+        self.push_with_num_lock(&digits, leds)
+    }
+
+    /// Type a hexadecimal number using the keypad, including the Keypad A-F usages
+    ///
+    /// If the host reports Num Lock as off it is toggled on for the duration of the number and
+    /// toggled back off afterwards
+    pub fn type_hex(&mut self, n: u32, leds: KeyboardLedsReport) -> Result<(), UsbHidError> {
+        let mut digits: Vec<Keystroke, 8> = Vec::new();
+        let mut n = n;
+        loop {
+            #[allow(clippy::cast_possible_truncation)]
+            let digit = (n % 16) as u8;
+            digits
+                .push(Keystroke::key(keypad_hex_digit(digit)))
+                .map_err(|_| UsbHidError::SerializationError)?;
+            n /= 16;
+            if n == 0 {
+                break;
+            }
+        }
+        digits.reverse();
+        self.push_with_num_lock(&digits, leds)
+    }
+
+    /// Type a string using the host keyboard layout
+    ///
+    /// Either the whole string is queued or none of it. Fails with
+    /// [`UsbHidError::SerializationError`] if the layout can't type one of the characters
+    pub fn type_str<L: Layout>(&mut self, s: &str, layout: &L) -> Result<(), UsbHidError> {
+        if s.chars().any(|c| layout.keystroke(c).is_none()) {
+            return Err(UsbHidError::SerializationError);
+        }
+        if s.chars().count() > self.free() {
+            return Err(UsbHidError::WouldBlock);
+        }
+        s.chars()
+            .filter_map(|c| layout.keystroke(c))
+            .try_for_each(|k| self.push(k))
+    }
+
+    fn push_with_num_lock(
+        &mut self,
+        keystrokes: &[Keystroke],
+        leds: KeyboardLedsReport,
+    ) -> Result<(), UsbHidError> {
+        let toggle = (!leds.num_lock).then_some(Keystroke::key(Keyboard::KeypadNumLockAndClear));
+        let needed = keystrokes.len() + if toggle.is_some() { 2 } else { 0 };
+        if needed > self.free() {
+            return Err(UsbHidError::WouldBlock);
+        }
+        toggle
+            .into_iter()
+            .chain(keystrokes.iter().copied())
+            .chain(toggle)
+            .try_for_each(|k| self.push(k))
+    }
+}
+
+fn keypad_digit(digit: u8) -> Keyboard {
+    match digit {
+        0 => Keyboard::Keypad0,
+        d => Keyboard::from(u8::from(Keyboard::Keypad1) + d - 1),
+    }
+}
+
+fn keypad_hex_digit(digit: u8) -> Keyboard {
+    match digit {
+        0..=9 => keypad_digit(digit),
+        d => Keyboard::from(u8::from(Keyboard::KeypadA) + d - 10),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #![allow(clippy::unwrap_used)]
+    #![allow(clippy::expect_used)]
+
+    use super::*;
+    use std::vec::Vec;
+
+    fn typed<const N: usize>(typist: &mut Typist<N>) -> Vec<Keystroke> {
+        let mut reports = Vec::new();
+        while let Some(k) = typist.report() {
+            reports.push(k);
+            typist.advance();
+        }
+        reports
+    }
+
+    #[test]
+    fn type_number_with_num_lock_on() {
+        let mut typist = Typist::<32>::new();
+        let leds = KeyboardLedsReport {
+            num_lock: true,
+            ..Default::default()
+        };
+        typist.type_number(1907, leds).unwrap();
+
+        assert_eq!(
+            typed(&mut typist),
+            [
+                Keystroke::key(Keyboard::Keypad1),
+                Keystroke::RELEASE,
+                Keystroke::key(Keyboard::Keypad9),
+                Keystroke::RELEASE,
+                Keystroke::key(Keyboard::Keypad0),
+                Keystroke::RELEASE,
+                Keystroke::key(Keyboard::Keypad7),
+                Keystroke::RELEASE,
+            ]
+        );
+        assert!(typist.is_idle());
+    }
+
+    #[test]
+    fn type_hex_toggles_num_lock() {
+        let mut typist = Typist::<32>::new();
+        typist
+            .type_hex(0x1F, KeyboardLedsReport::default())
+            .unwrap();
+
+        assert_eq!(
+            typed(&mut typist),
+            [
+                Keystroke::key(Keyboard::KeypadNumLockAndClear),
+                Keystroke::RELEASE,
+                Keystroke::key(Keyboard::Keypad1),
+                Keystroke::RELEASE,
+                Keystroke::key(Keyboard::KeypadF),
+                Keystroke::RELEASE,
+                Keystroke::key(Keyboard::KeypadNumLockAndClear),
+                Keystroke::RELEASE,
+            ]
+        );
+    }
+
+    #[test]
+    fn type_hex_uses_keypad_usages() {
+        let mut typist = Typist::<32>::new();
+        let leds = KeyboardLedsReport {
+            num_lock: true,
+            ..Default::default()
+        };
+        typist.type_hex(0xABCD_EF09, leds).unwrap();
+
+        let keys: Vec<Keyboard> = typed(&mut typist)
+            .into_iter()
+            .filter(|&k| k != Keystroke::RELEASE)
+            .map(|k| k.key)
+            .collect();
+        assert_eq!(
+            keys,
+            [
+                Keyboard::KeypadA,
+                Keyboard::KeypadB,
+                Keyboard::KeypadC,
+                Keyboard::KeypadD,
+                Keyboard::KeypadE,
+                Keyboard::KeypadF,
+                Keyboard::Keypad0,
+                Keyboard::Keypad9,
+            ]
+        );
+    }
+
+    #[test]
+    fn type_number_all_or_nothing() {
+        let mut typist = Typist::<4>::new();
+        assert!(typist
+            .type_number(12345, KeyboardLedsReport::default())
+            .is_err());
+        assert!(typist.is_idle());
+    }
+
+    #[test]
+    fn conflicting_release_policy() {
+        let mut typist = Typist::<8>::new().with_release_policy(ReleasePolicy::Conflicting);
+        let shift_a = Keystroke::new(Modifiers::LEFT_SHIFT, Keyboard::A);
+        typist
+            .push_all([
+                Keystroke::key(Keyboard::A),
+                Keystroke::key(Keyboard::B),
+                Keystroke::key(Keyboard::B),
+                Keystroke::key(Keyboard::A),
+                shift_a,
+                Keystroke::key(Keyboard::C),
+            ])
+            .unwrap();
+
+        assert_eq!(
+            typed(&mut typist),
+            [
+                Keystroke::key(Keyboard::A),
+                Keystroke::key(Keyboard::B),
+                Keystroke::RELEASE,
+                Keystroke::key(Keyboard::B),
+                Keystroke::key(Keyboard::A),
+                Keystroke::RELEASE,
+                shift_a,
+                Keystroke::RELEASE,
+                Keystroke::key(Keyboard::C),
+                Keystroke::RELEASE,
+            ]
+        );
+        assert!(typist.is_idle());
+    }
+
+    #[test]
+    fn type_str_with_alt_gr() {
+        use crate::device::keyboard::layout::GermanQwertz;
+
+        let mut typist = Typist::<8>::new().with_release_policy(ReleasePolicy::Conflicting);
+        typist.type_str("a@Z", &GermanQwertz).unwrap();
+        assert!(typist.type_str("^", &GermanQwertz).is_err());
+
+        assert_eq!(
+            typed(&mut typist),
+            [
+                Keystroke::key(Keyboard::A),
+                Keystroke::RELEASE,
+                Keystroke::new(Modifiers::RIGHT_ALT, Keyboard::Q),
+                Keystroke::RELEASE,
+                Keystroke::new(Modifiers::LEFT_SHIFT, Keyboard::Y),
+                Keystroke::RELEASE,
+            ]
+        );
+    }
+
+    #[test]
+    fn keystroke_keys() {
+        let keys: Vec<Keyboard> = Keystroke::new(
+            Modifiers::LEFT_SHIFT.union(Modifiers::RIGHT_ALT),
+            Keyboard::Q,
+        )
+        .into_iter()
+        .collect();
+        assert_eq!(keys, [Keyboard::LeftShift, Keyboard::RightAlt, Keyboard::Q]);
+        assert_eq!(Keystroke::RELEASE.into_iter().count(), 0);
+    }
+}
@@ -0,0 +1,157 @@
+//! Resume tracking for keyboards
+//!
+//! A keyboard that woke the host with a key press usually wants to replay that key once the host
+//! is listening again, but keys pressed while the host resumed on its own are better dropped.
+//! [`WakeMonitor`] follows the device state across suspend to tell the two apart, and reports the
+//! reason for the most recent resume with the first LED report received afterwards, which is a
+//! good sign that the host is ready for input again.
+//!
+//! ```
+//! # use xous_usb_hid::device::keyboard::wake::{WakeMonitor, WakeReason};
+//! # use xous_usb_hid::device::keyboard::KeyboardLedsReport;
+//! # use usb_device::device::UsbDeviceState;
+//! let mut monitor = WakeMonitor::new();
+//!
+//! // after every usb_dev.poll()
+//! monitor.update(UsbDeviceState::Suspend);
+//! // key pressed while suspended, usb_dev.bus().remote_wakeup()
+//! monitor.remote_wakeup_signalled();
+//! monitor.update(UsbDeviceState::Configured);
+//!
+//! // if let Ok(leds) = keyboard.read_report()
+//! let activity = monitor.leds_received(KeyboardLedsReport::default());
+//! if activity.wake_reason == Some(WakeReason::RemoteWakeup) {
+//!     // replay the key that woke the host
+//! }
+//! ```
+
+use crate::device::keyboard::KeyboardLedsReport;
+use usb_device::device::UsbDeviceState;
+
+/// Why the device last left suspend
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WakeReason {
+    /// The host resumed the bus
+    Host,
+    /// The device signalled remote wakeup while suspended
+    RemoteWakeup,
+}
+
+/// LED report received from the host
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct HostActivity {
+    pub leds: KeyboardLedsReport,
+    /// Reason for the preceding resume, only set for the first LED report after a resume
+    pub wake_reason: Option<WakeReason>,
+}
+
+/// Tracks suspend and resume, see the [module docs](self)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub struct WakeMonitor {
+    suspended: bool,
+    remote_wakeup: bool,
+    last_wake: Option<WakeReason>,
+    unreported_wake: bool,
+}
+
+impl WakeMonitor {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Follow the device state, returns the reason for the resume when the device leaves suspend
+    pub fn update(&mut self, state: UsbDeviceState) -> Option<WakeReason> {
+        match (self.suspended, state) {
+            (false, UsbDeviceState::Suspend) => {
+                self.suspended = true;
+                self.remote_wakeup = false;
+                None
+            }
+            (true, UsbDeviceState::Suspend) | (false, _) => None,
+            (true, _) => {
+                let reason = if self.remote_wakeup {
+                    WakeReason::RemoteWakeup
+                } else {
+                    WakeReason::Host
+                };
+                info!("Resumed, {:?}", reason);
+                self.suspended = false;
+                self.remote_wakeup = false;
+                self.last_wake = Some(reason);
+                self.unreported_wake = true;
+                Some(reason)
+            }
+        }
+    }
+
+    /// Call when signalling remote wakeup to the host. Ignored unless suspended
+    pub fn remote_wakeup_signalled(&mut self) {
+        self.remote_wakeup |= self.suspended;
+    }
+
+    /// `true` while the device is suspended
+    #[must_use]
+    pub fn is_suspended(&self) -> bool {
+        self.suspended
+    }
+
+    /// Reason for the most recent resume, `None` if the device hasn't been suspended
+    #[must_use]
+    pub fn last_wake(&self) -> Option<WakeReason> {
+        self.last_wake
+    }
+
+    /// Pair an LED report received from the host with the reason for the preceding resume
+    pub fn leds_received(&mut self, leds: KeyboardLedsReport) -> HostActivity {
+        let wake_reason = if self.unreported_wake {
+            self.unreported_wake = false;
+            self.last_wake
+        } else {
+            None
+        };
+        HostActivity { leds, wake_reason }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn wake_reason_reported_with_first_leds() {
+        let mut monitor = WakeMonitor::new();
+        let leds = KeyboardLedsReport::new().num_lock(true);
+
+        assert_eq!(monitor.update(UsbDeviceState::Configured), None);
+        monitor.remote_wakeup_signalled();
+        assert_eq!(monitor.update(UsbDeviceState::Suspend), None);
+        assert!(monitor.is_suspended());
+        assert_eq!(
+            monitor.update(UsbDeviceState::Configured),
+            Some(WakeReason::Host)
+        );
+        assert_eq!(
+            monitor.leds_received(leds),
+            HostActivity {
+                leds,
+                wake_reason: Some(WakeReason::Host)
+            }
+        );
+        assert_eq!(monitor.leds_received(leds).wake_reason, None);
+
+        monitor.update(UsbDeviceState::Suspend);
+        monitor.remote_wakeup_signalled();
+        assert_eq!(
+            monitor.update(UsbDeviceState::Configured),
+            Some(WakeReason::RemoteWakeup)
+        );
+        assert_eq!(monitor.last_wake(), Some(WakeReason::RemoteWakeup));
+        assert_eq!(
+            monitor.leds_received(leds).wake_reason,
+            Some(WakeReason::RemoteWakeup)
+        );
+    }
+}
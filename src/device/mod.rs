@@ -1,32 +1,106 @@
 //! Concrete implementation of Human Interface Devices
 
+use crate::capability::DescriptorSummary;
 use crate::interface::InterfaceClass;
+use crate::tlv::TlvWriter;
 use crate::UsbHidError;
 use frunk::{HCons, HNil, ToMut};
+use fugit::MillisDurationU32;
+use heapless::Vec;
 #[allow(clippy::wildcard_imports)]
 use usb_device::class_prelude::*;
 
+pub mod activity;
+pub mod alphanumeric_display;
+pub mod chord;
 pub mod consumer;
 pub mod fido;
+pub mod gamepad;
+pub mod headset;
 pub mod joystick;
 pub mod keyboard;
 pub mod mouse;
+pub mod multitouch;
+pub mod pen;
+pub mod pointing_stick;
+pub mod radial_controller;
+pub mod raw;
+pub mod sensor;
+pub mod simulation;
+pub mod system_control;
+pub mod touchpad;
+pub mod touchscreen;
+pub mod ups;
 
 pub trait DeviceClass<'a> {
     type I: InterfaceClass<'a>;
     fn interface(&mut self) -> &mut Self::I;
     /// Called if the USB Device is reset
     fn reset(&mut self);
+    /// Called after the host clears a halt on one of the device's endpoints
+    fn endpoint_halt_cleared(&mut self) {}
+    /// Called when the host selects a configuration
+    fn configured(&mut self) {}
     /// Called every 1ms
     fn tick(&mut self) -> Result<(), UsbHidError>;
+    /// Time until [`DeviceClass::tick`] next has work to do, `None` if nothing is scheduled.
+    /// Used by [`UsbHidClass::tick_until`](crate::usb_class::UsbHidClass::tick_until) to skip
+    /// ticks, devices doing work in `tick` must override it
+    fn next_deadline(&mut self) -> Option<MillisDurationU32> {
+        None
+    }
+    /// Called by [`UsbHidClass::tick_until`](crate::usb_class::UsbHidClass::tick_until) in place
+    /// of the ticks it skips, timers counted by [`DeviceClass::tick`] must advance by `elapsed`
+    fn advance(&mut self, _elapsed: MillisDurationU32) {}
+}
+
+/// Object safe view of a [`DeviceClass`], implemented for every device
+///
+/// Lets generic code handle a runtime configured set of devices as trait objects, see
+/// [`UsbHidClass::devices_dyn`](crate::usb_class::UsbHidClass::devices_dyn)
+pub trait HidDeviceAny<'a> {
+    fn interface_dyn(&mut self) -> &mut dyn InterfaceClass<'a>;
+    /// Called every 1ms, advances the interface clock like
+    /// [`UsbHidClass::tick`](crate::usb_class::UsbHidClass::tick)
+    fn tick(&mut self) -> Result<(), UsbHidError>;
+}
+
+impl<'a, T: DeviceClass<'a>> HidDeviceAny<'a> for T
+where
+    T::I: 'a,
+{
+    fn interface_dyn(&mut self) -> &mut dyn InterfaceClass<'a> {
+        self.interface()
+    }
+
+    fn tick(&mut self) -> Result<(), UsbHidError> {
+        self.interface().clock_tick();
+        DeviceClass::tick(self)
+    }
 }
 
 pub trait DeviceHList<'a>: ToMut<'a> {
     fn get(&mut self, id: u8) -> Option<&mut dyn InterfaceClass<'a>>;
     fn reset(&mut self);
+    /// Returns `false` if no device owns the endpoint `address`
+    fn endpoint_halt_cleared(&mut self, address: EndpointAddress) -> bool;
+    /// Returns `false` if no device owns the endpoint `address`
+    fn endpoint_in_complete(&mut self, address: EndpointAddress) -> bool;
+    /// `true` if any IN endpoint holds a report the host hasn't read
+    fn in_endpoint_busy(&mut self) -> bool;
+    fn configured(&mut self);
     fn write_descriptors(&mut self, writer: &mut DescriptorWriter) -> usb_device::Result<()>;
     fn get_string(&mut self, index: StringIndex, lang_id: u16) -> Option<&'a str>;
     fn tick(&mut self) -> Result<(), UsbHidError>;
+    /// Earliest [`DeviceClass::next_deadline`] of all devices
+    fn next_deadline(&mut self) -> Option<MillisDurationU32>;
+    /// Advance the interface clocks and device timers by `elapsed` without ticking the devices
+    fn clock_advance(&mut self, elapsed: MillisDurationU32);
+    fn write_capabilities(&mut self, writer: &mut TlvWriter<'_>) -> Result<(), UsbHidError>;
+    fn collect_dyn<'s, const N: usize>(
+        &'s mut self,
+        devices: &mut Vec<&'s mut dyn HidDeviceAny<'a>, N>,
+    ) -> Result<(), UsbHidError>;
 }
 
 impl<'a> DeviceHList<'a> for HNil {
@@ -36,6 +110,20 @@ impl<'a> DeviceHList<'a> for HNil {
 
     fn reset(&mut self) {}
 
+    fn endpoint_halt_cleared(&mut self, _: EndpointAddress) -> bool {
+        false
+    }
+
+    fn endpoint_in_complete(&mut self, _: EndpointAddress) -> bool {
+        false
+    }
+
+    fn in_endpoint_busy(&mut self) -> bool {
+        false
+    }
+
+    fn configured(&mut self) {}
+
     fn write_descriptors(&mut self, _: &mut DescriptorWriter) -> usb_device::Result<()> {
         Ok(())
     }
@@ -47,6 +135,23 @@ impl<'a> DeviceHList<'a> for HNil {
     fn tick(&mut self) -> Result<(), UsbHidError> {
         Ok(())
     }
+
+    fn next_deadline(&mut self) -> Option<MillisDurationU32> {
+        None
+    }
+
+    fn clock_advance(&mut self, _: MillisDurationU32) {}
+
+    fn write_capabilities(&mut self, _: &mut TlvWriter<'_>) -> Result<(), UsbHidError> {
+        Ok(())
+    }
+
+    fn collect_dyn<'s, const N: usize>(
+        &'s mut self,
+        _: &mut Vec<&'s mut dyn HidDeviceAny<'a>, N>,
+    ) -> Result<(), UsbHidError> {
+        Ok(())
+    }
 }
 
 impl<'a, Head: DeviceClass<'a> + 'a, Tail: DeviceHList<'a>> DeviceHList<'a> for HCons<Head, Tail> {
@@ -64,6 +169,29 @@ impl<'a, Head: DeviceClass<'a> + 'a, Tail: DeviceHList<'a>> DeviceHList<'a> for
         self.tail.reset();
     }
 
+    fn endpoint_halt_cleared(&mut self, address: EndpointAddress) -> bool {
+        if self.head.interface().endpoint_halt_cleared(address) {
+            self.head.endpoint_halt_cleared();
+            true
+        } else {
+            self.tail.endpoint_halt_cleared(address)
+        }
+    }
+
+    fn endpoint_in_complete(&mut self, address: EndpointAddress) -> bool {
+        self.head.interface().endpoint_in_complete(address)
+            || self.tail.endpoint_in_complete(address)
+    }
+
+    fn in_endpoint_busy(&mut self) -> bool {
+        self.head.interface().in_endpoint_busy() || self.tail.in_endpoint_busy()
+    }
+
+    fn configured(&mut self) {
+        self.head.configured();
+        self.tail.configured();
+    }
+
     fn write_descriptors(&mut self, writer: &mut DescriptorWriter) -> usb_device::Result<()> {
         self.head.interface().write_descriptors(writer)?;
         self.tail.write_descriptors(writer)
@@ -79,7 +207,38 @@ impl<'a, Head: DeviceClass<'a> + 'a, Tail: DeviceHList<'a>> DeviceHList<'a> for
     }
 
     fn tick(&mut self) -> Result<(), UsbHidError> {
+        self.head.interface().clock_tick();
         self.head.tick()?;
         self.tail.tick()
     }
+
+    fn next_deadline(&mut self) -> Option<MillisDurationU32> {
+        match (self.head.next_deadline(), self.tail.next_deadline()) {
+            (Some(head), Some(tail)) => Some(head.min(tail)),
+            (head, tail) => head.or(tail),
+        }
+    }
+
+    fn clock_advance(&mut self, elapsed: MillisDurationU32) {
+        self.head.interface().clock_advance(elapsed);
+        self.head.advance(elapsed);
+        self.tail.clock_advance(elapsed);
+    }
+
+    fn write_capabilities(&mut self, writer: &mut TlvWriter<'_>) -> Result<(), UsbHidError> {
+        let interface = self.head.interface();
+        DescriptorSummary::new(interface.report_descriptor())?
+            .write(u8::from(interface.id()), writer)?;
+        self.tail.write_capabilities(writer)
+    }
+
+    fn collect_dyn<'s, const N: usize>(
+        &'s mut self,
+        devices: &mut Vec<&'s mut dyn HidDeviceAny<'a>, N>,
+    ) -> Result<(), UsbHidError> {
+        devices
+            .push(&mut self.head)
+            .map_err(|_| UsbHidError::UsbError(UsbError::BufferOverflow))?;
+        self.tail.collect_dyn(devices)
+    }
 }
@@ -1,11 +1,16 @@
 //!HID mice
 use crate::usb_class::prelude::*;
 use core::default::Default;
-use fugit::ExtU32;
+use fugit::{ExtU32, MillisDurationU32};
 use packed_struct::prelude::*;
 use usb_device::bus::UsbBus;
 use usb_device::class_prelude::UsbBusAllocator;
 
+pub mod click;
+pub mod gesture;
+#[cfg(feature = "mouse-surface")]
+pub mod surface;
+
 /// HID Mouse report descriptor conforming to the Boot specification
 ///
 /// This aims to be compatible with BIOS and other reduced functionality USB hosts
@@ -42,6 +47,7 @@ pub const BOOT_MOUSE_REPORT_DESCRIPTOR: &[u8] = &[
     0xC0, // End Collection
 ];
 
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Default, PackedStruct)]
 #[packed_struct(endian = "lsb", size_bytes = "3")]
 pub struct BootMouseReport {
@@ -53,6 +59,71 @@ pub struct BootMouseReport {
     pub y: i8,
 }
 
+impl BootMouseReport {
+    /// Report with no buttons pressed and no movement
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            buttons: 0,
+            x: 0,
+            y: 0,
+        }
+    }
+
+    /// Button `n` is bit `n - 1`
+    #[must_use]
+    pub const fn buttons(mut self, buttons: u8) -> Self {
+        self.buttons = buttons;
+        self
+    }
+
+    /// Set button `n`, 1 to 3. Other buttons aren't declared by the boot descriptor and are
+    /// ignored
+    #[must_use]
+    pub const fn button(mut self, n: u8, pressed: bool) -> Self {
+        if n == 0 || n > 3 {
+            return self;
+        }
+        let mask = 1 << (n - 1);
+        if pressed {
+            self.buttons |= mask;
+        } else {
+            self.buttons &= !mask;
+        }
+        self
+    }
+
+    /// Button 1
+    #[must_use]
+    pub const fn left(self, pressed: bool) -> Self {
+        self.button(1, pressed)
+    }
+
+    /// Button 2
+    #[must_use]
+    pub const fn right(self, pressed: bool) -> Self {
+        self.button(2, pressed)
+    }
+
+    /// Button 3
+    #[must_use]
+    pub const fn middle(self, pressed: bool) -> Self {
+        self.button(3, pressed)
+    }
+
+    #[must_use]
+    pub const fn x(mut self, x: i8) -> Self {
+        self.x = x;
+        self
+    }
+
+    #[must_use]
+    pub const fn y(mut self, y: i8) -> Self {
+        self.y = y;
+        self
+    }
+}
+
 /// Boot compatible mouse with wheel, pan and eight buttons
 ///
 /// Reference: <https://docs.microsoft.com/en-us/previous-versions/windows/hardware/design/dn613912(v=vs.85)>
@@ -95,6 +166,7 @@ pub const WHEEL_MOUSE_REPORT_DESCRIPTOR: &[u8] = &[
     0xC0,              // End Collection
 ];
 
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Default, PackedStruct)]
 #[packed_struct(endian = "lsb")]
 pub struct WheelMouseReport {
@@ -110,6 +182,63 @@ pub struct WheelMouseReport {
     pub horizontal_wheel: i8,
 }
 
+impl WheelMouseReport {
+    /// Report with no buttons pressed and no movement
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            buttons: 0,
+            x: 0,
+            y: 0,
+            vertical_wheel: 0,
+            horizontal_wheel: 0,
+        }
+    }
+
+    /// Button `n` is bit `n - 1`
+    #[must_use]
+    pub const fn buttons(mut self, buttons: u8) -> Self {
+        self.buttons = buttons;
+        self
+    }
+
+    #[must_use]
+    pub const fn x(mut self, x: i8) -> Self {
+        self.x = x;
+        self
+    }
+
+    #[must_use]
+    pub const fn y(mut self, y: i8) -> Self {
+        self.y = y;
+        self
+    }
+
+    #[must_use]
+    pub const fn vertical_wheel(mut self, vertical_wheel: i8) -> Self {
+        self.vertical_wheel = vertical_wheel;
+        self
+    }
+
+    #[must_use]
+    pub const fn horizontal_wheel(mut self, horizontal_wheel: i8) -> Self {
+        self.horizontal_wheel = horizontal_wheel;
+        self
+    }
+
+    /// Vertical scroll, positive scrolls up
+    #[must_use]
+    pub const fn scroll_v(self, scroll: i8) -> Self {
+        self.vertical_wheel(scroll)
+    }
+
+    /// Horizontal scroll, reported as Consumer AC Pan, positive scrolls right
+    #[must_use]
+    pub const fn scroll_h(self, scroll: i8) -> Self {
+        self.horizontal_wheel(scroll)
+    }
+}
+
 /// Absolute mouse with wheel and eight buttons
 ///
 /// Note - absolute pointer support is relatively uncommon. This has been tested on Windows 11
@@ -159,6 +288,7 @@ pub const ABSOLUTE_WHEEL_MOUSE_REPORT_DESCRIPTOR: &[u8] = &[
     0xC0,              // End Collection
 ];
 
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Default, PackedStruct)]
 #[packed_struct(endian = "lsb")]
 pub struct AbsoluteWheelMouseReport {
@@ -172,6 +302,93 @@ pub struct AbsoluteWheelMouseReport {
     pub wheel: i8,
 }
 
+impl AbsoluteWheelMouseReport {
+    /// Report with no buttons pressed, at the origin
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            buttons: 0,
+            x: 0,
+            y: 0,
+            wheel: 0,
+        }
+    }
+
+    /// Button `n` is bit `n - 1`
+    #[must_use]
+    pub const fn buttons(mut self, buttons: u8) -> Self {
+        self.buttons = buttons;
+        self
+    }
+
+    #[must_use]
+    pub const fn x(mut self, x: u16) -> Self {
+        self.x = x;
+        self
+    }
+
+    #[must_use]
+    pub const fn y(mut self, y: u16) -> Self {
+        self.y = y;
+        self
+    }
+
+    #[must_use]
+    pub const fn wheel(mut self, wheel: i8) -> Self {
+        self.wheel = wheel;
+        self
+    }
+}
+
+/// Largest X and Y of an [`AbsoluteWheelMouseReport`], at the right and bottom edge of the screen
+pub const ABSOLUTE_MAX: u16 = 0x7FFF;
+
+/// Maps pixel coordinates on a screen of a given size to the logical range of an
+/// [`AbsoluteWheelMouseReport`]
+///
+/// The host scales the logical range back to the size of its own screen, so the mapping only
+/// needs to match the host screen size for pixel exact positioning, e.g. for test automation
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct ScreenMapping {
+    width: u16,
+    height: u16,
+}
+
+impl ScreenMapping {
+    /// Screen `width` by `height` pixels
+    #[must_use]
+    pub const fn new(width: u16, height: u16) -> Self {
+        Self { width, height }
+    }
+
+    /// Logical X and Y of pixel (`x`, `y`), pixels past the edge of the screen are clamped to it
+    #[must_use]
+    pub fn map(&self, x: u16, y: u16) -> (u16, u16) {
+        (Self::scale(x, self.width), Self::scale(y, self.height))
+    }
+
+    /// Report with the pointer at pixel (`x`, `y`)
+    #[must_use]
+    pub fn report(&self, x: u16, y: u16) -> AbsoluteWheelMouseReport {
+        let (x, y) = self.map(x, y);
+        AbsoluteWheelMouseReport::new().x(x).y(y)
+    }
+
+    fn scale(pixel: u16, size: u16) -> u16 {
+        let last = u32::from(size.saturating_sub(1));
+        if last == 0 {
+            return 0;
+        }
+        let pixel = u32::from(pixel).min(last);
+        let max = u32::from(ABSOLUTE_MAX);
+        // Rounded to the nearest logical value
+        u16::try_from((pixel * max + last / 2) / last).unwrap_or(ABSOLUTE_MAX)
+    }
+}
+
+/// Mouse sending [`BootMouseReport`]s, usable by hosts that only support the boot protocol, e.g.
+/// firmware setup screens
 pub struct BootMouse<'a, B: UsbBus> {
     interface: Interface<'a, B, InBytes8, OutNone, ReportSingle>,
 }
@@ -187,6 +404,12 @@ impl<'a, B: UsbBus> BootMouse<'a, B> {
             .map(|_| ())
             .map_err(UsbHidError::from)
     }
+
+    /// Protocol selected by the host, the report is the same for both
+    #[must_use]
+    pub fn protocol(&self) -> HidProtocol {
+        self.interface.protocol()
+    }
 }
 
 pub struct BootMouseConfig<'a> {
@@ -204,10 +427,12 @@ impl<'a> Default for BootMouseConfig<'a> {
     #[must_use]
     fn default() -> Self {
         Self::new(
-            unwrap!(unwrap!(InterfaceBuilder::new(BOOT_MOUSE_REPORT_DESCRIPTOR))
-                .boot_device(InterfaceProtocol::Mouse)
-                .description("Mouse")
-                .in_endpoint(10.millis()))
+            unwrap!(
+                unwrap!(InterfaceBuilder::new_static(BOOT_MOUSE_REPORT_DESCRIPTOR))
+                    .boot_device(InterfaceProtocol::Mouse)
+                    .description("Mouse")
+                    .in_endpoint(10.millis())
+            )
             .without_out_endpoint()
             .build(),
         )
@@ -236,6 +461,10 @@ impl<'a, B: UsbBus> DeviceClass<'a> for BootMouse<'a, B> {
     fn tick(&mut self) -> Result<(), UsbHidError> {
         Ok(())
     }
+
+    fn next_deadline(&mut self) -> Option<MillisDurationU32> {
+        None
+    }
 }
 
 pub struct WheelMouse<'a, B: UsbBus> {
@@ -270,7 +499,7 @@ impl<'a> Default for WheelMouseConfig<'a> {
     fn default() -> Self {
         WheelMouseConfig::new(
             unwrap!(
-                unwrap!(InterfaceBuilder::new(WHEEL_MOUSE_REPORT_DESCRIPTOR))
+                unwrap!(InterfaceBuilder::new_static(WHEEL_MOUSE_REPORT_DESCRIPTOR))
                     .boot_device(InterfaceProtocol::Mouse)
                     .description("Wheel Mouse")
                     .in_endpoint(10.millis())
@@ -303,6 +532,10 @@ impl<'a, B: UsbBus> DeviceClass<'a> for WheelMouse<'a, B> {
     fn tick(&mut self) -> Result<(), UsbHidError> {
         Ok(())
     }
+
+    fn next_deadline(&mut self) -> Option<MillisDurationU32> {
+        None
+    }
 }
 
 pub struct AbsoluteWheelMouse<'a, B: UsbBus> {
@@ -337,7 +570,7 @@ impl<'a> Default for AbsoluteWheelMouseConfig<'a> {
     #[must_use]
     fn default() -> Self {
         AbsoluteWheelMouseConfig::new(
-            unwrap!(unwrap!(InterfaceBuilder::new(
+            unwrap!(unwrap!(InterfaceBuilder::new_static(
                 ABSOLUTE_WHEEL_MOUSE_REPORT_DESCRIPTOR
             ))
             .description("Absolute Wheel Mouse")
@@ -370,4 +603,508 @@ impl<'a, B: UsbBus> DeviceClass<'a> for AbsoluteWheelMouse<'a, B> {
     fn tick(&mut self) -> Result<(), UsbHidError> {
         Ok(())
     }
+
+    fn next_deadline(&mut self) -> Option<MillisDurationU32> {
+        None
+    }
+}
+
+/// Wheel delta, in [`HiResWheelMouse`] units, of one wheel detent
+pub const RESOLUTION_MULTIPLIER: i16 = 4;
+
+/// Report ID of the [`HiResWheelMouse`] input report
+const HI_RES_INPUT_REPORT_ID: u8 = 0x01;
+/// Report ID of the [`HiResWheelMouse`] Resolution Multiplier feature report
+const HI_RES_FEATURE_REPORT_ID: u8 = 0x02;
+
+/// Wheel mouse with eight buttons, wheel and pan declaring a Resolution Multiplier for each
+/// wheel, for smooth scrolling on hosts that support it
+///
+/// Input report 1 has the layout of [`WheelMouseReport`]. Feature report 2 holds the multiplier
+/// of the vertical wheel in bits 0-1 and of the horizontal wheel in bits 2-3, a value of 1 sets
+/// the multiplier to [`RESOLUTION_MULTIPLIER`]
+///
+/// Reference: <https://learn.microsoft.com/en-us/previous-versions/windows/hardware/design/dn613912(v=vs.85)>
+#[rustfmt::skip]
+pub const HI_RES_WHEEL_MOUSE_REPORT_DESCRIPTOR: &[u8] = &[
+    0x05, 0x01,        // Usage Page (Generic Desktop),
+    0x09, 0x02,        // Usage (Mouse),
+    0xA1, 0x01,        // Collection (Application),
+    0x85, 0x01,        //   Report ID (1),
+    0x09, 0x01,        //   Usage (Pointer),
+    0xA1, 0x00,        //   Collection (Physical),
+    0x05, 0x09,        //     Usage Page (Buttons),
+    0x19, 0x01,        //     Usage Minimum (1),
+    0x29, 0x08,        //     Usage Maximum (8),
+    0x15, 0x00,        //     Logical Minimum (0),
+    0x25, 0x01,        //     Logical Maximum (1),
+    0x95, 0x08,        //     Report Count (8),
+    0x75, 0x01,        //     Report Size (1),
+    0x81, 0x02,        //     Input (Data, Variable, Absolute),
+    0x05, 0x01,        //     Usage Page (Generic Desktop),
+    0x09, 0x30,        //     Usage (X),
+    0x09, 0x31,        //     Usage (Y),
+    0x15, 0x81,        //     Logical Minimum (-127),
+    0x25, 0x7F,        //     Logical Maximum (127),
+    0x75, 0x08,        //     Report Size (8),
+    0x95, 0x02,        //     Report Count (2),
+    0x81, 0x06,        //     Input (Data, Variable, Relative),
+
+    0xA1, 0x02,        //     Collection (Logical),
+    0x85, 0x02,        //       Report ID (2),
+    0x09, 0x48,        //       Usage (Resolution Multiplier),
+    0x15, 0x00,        //       Logical Minimum (0),
+    0x25, 0x01,        //       Logical Maximum (1),
+    0x35, 0x01,        //       Physical Minimum (1),
+    0x45, 0x04,        //       Physical Maximum (4),
+    0x75, 0x02,        //       Report Size (2),
+    0x95, 0x01,        //       Report Count (1),
+    0xB1, 0x02,        //       Feature (Data, Variable, Absolute),
+    0x85, 0x01,        //       Report ID (1),
+    0x09, 0x38,        //       Usage (Wheel),
+    0x15, 0x81,        //       Logical Minimum (-127),
+    0x25, 0x7F,        //       Logical Maximum (127),
+    0x35, 0x00,        //       Physical Minimum (0),
+    0x45, 0x00,        //       Physical Maximum (0),
+    0x75, 0x08,        //       Report Size (8),
+    0x95, 0x01,        //       Report Count (1),
+    0x81, 0x06,        //       Input (Data, Variable, Relative),
+    0xC0,              //     End Collection,
+
+    0xA1, 0x02,        //     Collection (Logical),
+    0x85, 0x02,        //       Report ID (2),
+    0x09, 0x48,        //       Usage (Resolution Multiplier),
+    0x15, 0x00,        //       Logical Minimum (0),
+    0x25, 0x01,        //       Logical Maximum (1),
+    0x35, 0x01,        //       Physical Minimum (1),
+    0x45, 0x04,        //       Physical Maximum (4),
+    0x75, 0x02,        //       Report Size (2),
+    0x95, 0x01,        //       Report Count (1),
+    0xB1, 0x02,        //       Feature (Data, Variable, Absolute),
+    0x35, 0x00,        //       Physical Minimum (0),
+    0x45, 0x00,        //       Physical Maximum (0),
+    0x75, 0x04,        //       Report Size (4),
+    0xB1, 0x03,        //       Feature (Constant, Variable, Absolute),
+    0x85, 0x01,        //       Report ID (1),
+    0x05, 0x0C,        //       Usage Page (Consumer),
+    0x0A, 0x38, 0x02,  //       Usage (AC Pan),
+    0x15, 0x81,        //       Logical Minimum (-127),
+    0x25, 0x7F,        //       Logical Maximum (127),
+    0x75, 0x08,        //       Report Size (8),
+    0x95, 0x01,        //       Report Count (1),
+    0x81, 0x06,        //       Input (Data, Variable, Relative),
+    0xC0,              //     End Collection,
+    0xC0,              //   End Collection,
+    0xC0,              // End Collection
+];
+
+/// Vertical and horizontal wheel movement not sent yet, while high resolution scrolling is off
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+struct HiResWheels {
+    remainder: [i16; 2],
+}
+
+impl HiResWheels {
+    /// Multipliers set by the host in the Resolution Multiplier feature report
+    fn multipliers(feature_report: Option<&[u8]>) -> u8 {
+        match feature_report {
+            Some(&[HI_RES_FEATURE_REPORT_ID, multipliers, ..]) => multipliers,
+            _ => 0,
+        }
+    }
+
+    fn vertical_hi_res(feature_report: Option<&[u8]>) -> bool {
+        Self::multipliers(feature_report) & 0b0011 != 0
+    }
+
+    fn horizontal_hi_res(feature_report: Option<&[u8]>) -> bool {
+        Self::multipliers(feature_report) & 0b1100 != 0
+    }
+
+    /// Vertical and horizontal deltas to send and the movement left over, to be kept with
+    /// [`HiResWheels::sent`] once the report has been accepted
+    fn scale(&self, feature_report: Option<&[u8]>, deltas: [i8; 2]) -> ([i8; 2], [i16; 2]) {
+        let hi_res = [
+            Self::vertical_hi_res(feature_report),
+            Self::horizontal_hi_res(feature_report),
+        ];
+        let mut scaled = [0; 2];
+        let mut remainder = [0; 2];
+        for axis in 0..2 {
+            if hi_res[axis] {
+                scaled[axis] = deltas[axis];
+                continue;
+            }
+            let total = self.remainder[axis] + i16::from(deltas[axis]);
+            let detents = (total / RESOLUTION_MULTIPLIER).clamp(-127, 127);
+            #[allow(clippy::cast_possible_truncation)]
+            {
+                scaled[axis] = detents as i8;
+            }
+            remainder[axis] = total - detents * RESOLUTION_MULTIPLIER;
+        }
+        (scaled, remainder)
+    }
+
+    fn sent(&mut self, remainder: [i16; 2]) {
+        self.remainder = remainder;
+    }
+}
+
+/// Wheel mouse supporting high resolution scrolling, see [`HI_RES_WHEEL_MOUSE_REPORT_DESCRIPTOR`]
+///
+/// Wheel deltas are always given in units of 1/[`RESOLUTION_MULTIPLIER`] of a detent. While the
+/// host hasn't enabled high resolution scrolling for a wheel its movement is accumulated and only
+/// whole detents are sent
+pub struct HiResWheelMouse<'a, B: UsbBus> {
+    interface: Interface<'a, B, InBytes8, OutNone, ReportSingle>,
+    wheels: HiResWheels,
+}
+
+impl<B: UsbBus> HiResWheelMouse<'_, B> {
+    /// Write a report, with wheel deltas in units of 1/[`RESOLUTION_MULTIPLIER`] of a detent
+    pub fn write_report(&mut self, report: &WheelMouseReport) -> Result<(), UsbHidError> {
+        let ([vertical_wheel, horizontal_wheel], remainder) = self.wheels.scale(
+            self.interface.feature_report(),
+            [report.vertical_wheel, report.horizontal_wheel],
+        );
+        let report = WheelMouseReport {
+            vertical_wheel,
+            horizontal_wheel,
+            ..*report
+        };
+        let data = report.pack().map_err(|_| {
+            error!("Error packing WheelMouseReport");
+            UsbHidError::SerializationError
+        })?;
+        let mut report = [HI_RES_INPUT_REPORT_ID; 6];
+        report[1..].copy_from_slice(&data);
+        self.interface
+            .write_report(&report)
+            .map_err(UsbHidError::from)?;
+        self.wheels.sent(remainder);
+        Ok(())
+    }
+
+    /// `true` if the host has enabled high resolution scrolling for the vertical wheel
+    #[must_use]
+    pub fn vertical_hi_res(&self) -> bool {
+        HiResWheels::vertical_hi_res(self.interface.feature_report())
+    }
+
+    /// `true` if the host has enabled high resolution scrolling for the horizontal wheel
+    #[must_use]
+    pub fn horizontal_hi_res(&self) -> bool {
+        HiResWheels::horizontal_hi_res(self.interface.feature_report())
+    }
+}
+
+pub struct HiResWheelMouseConfig<'a> {
+    interface: InterfaceConfig<'a, InBytes8, OutNone, ReportSingle>,
+}
+
+impl<'a> HiResWheelMouseConfig<'a> {
+    /// `interface` must keep the Resolution Multiplier feature report, see
+    /// [`InterfaceBuilder::feature_report`]
+    #[must_use]
+    pub fn new(interface: InterfaceConfig<'a, InBytes8, OutNone, ReportSingle>) -> Self {
+        Self { interface }
+    }
+}
+
+impl Default for HiResWheelMouseConfig<'_> {
+    fn default() -> Self {
+        Self::new(
+            unwrap!(unwrap!(unwrap!(InterfaceBuilder::new_static(
+                HI_RES_WHEEL_MOUSE_REPORT_DESCRIPTOR
+            ))
+            .description("Wheel Mouse")
+            .feature_report(HI_RES_FEATURE_REPORT_ID, &[HI_RES_FEATURE_REPORT_ID, 0]))
+            .in_endpoint(10.millis()))
+            .without_out_endpoint()
+            .build(),
+        )
+    }
+}
+
+impl<'a, B: UsbBus + 'a> UsbAllocatable<'a, B> for HiResWheelMouseConfig<'a> {
+    type Allocated = HiResWheelMouse<'a, B>;
+
+    fn allocate(self, usb_alloc: &'a UsbBusAllocator<B>) -> Self::Allocated {
+        HiResWheelMouse {
+            interface: self.interface.allocate(usb_alloc),
+            wheels: HiResWheels::default(),
+        }
+    }
+}
+
+impl<'a, B: UsbBus> DeviceClass<'a> for HiResWheelMouse<'a, B> {
+    type I = Interface<'a, B, InBytes8, OutNone, ReportSingle>;
+
+    fn interface(&mut self) -> &mut Self::I {
+        &mut self.interface
+    }
+
+    fn reset(&mut self) {
+        self.wheels = HiResWheels::default();
+    }
+
+    fn tick(&mut self) -> Result<(), UsbHidError> {
+        Ok(())
+    }
+
+    fn next_deadline(&mut self) -> Option<MillisDurationU32> {
+        None
+    }
+}
+
+/// Scroll only device with two buttons, wheel and pan, e.g. for presenter scroll rings and
+/// scroll pucks that shouldn't move the pointer
+///
+/// Declares a Resolution Multiplier for each wheel like [`HI_RES_WHEEL_MOUSE_REPORT_DESCRIPTOR`].
+/// Input report 1 has the layout of [`ScrollWheelReport`]
+#[rustfmt::skip]
+pub const SCROLL_WHEEL_REPORT_DESCRIPTOR: &[u8] = &[
+    0x05, 0x01,        // Usage Page (Generic Desktop),
+    0x09, 0x02,        // Usage (Mouse),
+    0xA1, 0x01,        // Collection (Application),
+    0x85, 0x01,        //   Report ID (1),
+    0x09, 0x01,        //   Usage (Pointer),
+    0xA1, 0x00,        //   Collection (Physical),
+    0x05, 0x09,        //     Usage Page (Buttons),
+    0x19, 0x01,        //     Usage Minimum (1),
+    0x29, 0x02,        //     Usage Maximum (2),
+    0x15, 0x00,        //     Logical Minimum (0),
+    0x25, 0x01,        //     Logical Maximum (1),
+    0x95, 0x02,        //     Report Count (2),
+    0x75, 0x01,        //     Report Size (1),
+    0x81, 0x02,        //     Input (Data, Variable, Absolute),
+    0x95, 0x01,        //     Report Count (1),
+    0x75, 0x06,        //     Report Size (6),
+    0x81, 0x03,        //     Input (Constant, Variable, Absolute),
+    0x05, 0x01,        //     Usage Page (Generic Desktop),
+
+    0xA1, 0x02,        //     Collection (Logical),
+    0x85, 0x02,        //       Report ID (2),
+    0x09, 0x48,        //       Usage (Resolution Multiplier),
+    0x15, 0x00,        //       Logical Minimum (0),
+    0x25, 0x01,        //       Logical Maximum (1),
+    0x35, 0x01,        //       Physical Minimum (1),
+    0x45, 0x04,        //       Physical Maximum (4),
+    0x75, 0x02,        //       Report Size (2),
+    0x95, 0x01,        //       Report Count (1),
+    0xB1, 0x02,        //       Feature (Data, Variable, Absolute),
+    0x85, 0x01,        //       Report ID (1),
+    0x09, 0x38,        //       Usage (Wheel),
+    0x15, 0x81,        //       Logical Minimum (-127),
+    0x25, 0x7F,        //       Logical Maximum (127),
+    0x35, 0x00,        //       Physical Minimum (0),
+    0x45, 0x00,        //       Physical Maximum (0),
+    0x75, 0x08,        //       Report Size (8),
+    0x95, 0x01,        //       Report Count (1),
+    0x81, 0x06,        //       Input (Data, Variable, Relative),
+    0xC0,              //     End Collection,
+
+    0xA1, 0x02,        //     Collection (Logical),
+    0x85, 0x02,        //       Report ID (2),
+    0x09, 0x48,        //       Usage (Resolution Multiplier),
+    0x15, 0x00,        //       Logical Minimum (0),
+    0x25, 0x01,        //       Logical Maximum (1),
+    0x35, 0x01,        //       Physical Minimum (1),
+    0x45, 0x04,        //       Physical Maximum (4),
+    0x75, 0x02,        //       Report Size (2),
+    0x95, 0x01,        //       Report Count (1),
+    0xB1, 0x02,        //       Feature (Data, Variable, Absolute),
+    0x35, 0x00,        //       Physical Minimum (0),
+    0x45, 0x00,        //       Physical Maximum (0),
+    0x75, 0x04,        //       Report Size (4),
+    0xB1, 0x03,        //       Feature (Constant, Variable, Absolute),
+    0x85, 0x01,        //       Report ID (1),
+    0x05, 0x0C,        //       Usage Page (Consumer),
+    0x0A, 0x38, 0x02,  //       Usage (AC Pan),
+    0x15, 0x81,        //       Logical Minimum (-127),
+    0x25, 0x7F,        //       Logical Maximum (127),
+    0x75, 0x08,        //       Report Size (8),
+    0x95, 0x01,        //       Report Count (1),
+    0x81, 0x06,        //       Input (Data, Variable, Relative),
+    0xC0,              //     End Collection,
+    0xC0,              //   End Collection,
+    0xC0,              // End Collection
+];
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Default, PackedStruct)]
+#[packed_struct(endian = "lsb")]
+pub struct ScrollWheelReport {
+    #[packed_field]
+    pub buttons: u8,
+    #[packed_field]
+    pub vertical_wheel: i8,
+    #[packed_field]
+    pub horizontal_wheel: i8,
+}
+
+impl ScrollWheelReport {
+    /// Report with no buttons pressed and no movement
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            buttons: 0,
+            vertical_wheel: 0,
+            horizontal_wheel: 0,
+        }
+    }
+
+    /// Button 1 is bit 0 and button 2 is bit 1, other bits are ignored
+    #[must_use]
+    pub const fn buttons(mut self, buttons: u8) -> Self {
+        self.buttons = buttons;
+        self
+    }
+
+    /// Vertical scroll, positive scrolls up
+    #[must_use]
+    pub const fn scroll_v(mut self, scroll: i8) -> Self {
+        self.vertical_wheel = scroll;
+        self
+    }
+
+    /// Horizontal scroll, reported as Consumer AC Pan, positive scrolls right
+    #[must_use]
+    pub const fn scroll_h(mut self, scroll: i8) -> Self {
+        self.horizontal_wheel = scroll;
+        self
+    }
+}
+
+/// Scroll only device, see [`SCROLL_WHEEL_REPORT_DESCRIPTOR`]
+///
+/// Wheel deltas are handled as for [`HiResWheelMouse`]
+pub struct ScrollWheel<'a, B: UsbBus> {
+    interface: Interface<'a, B, InBytes8, OutNone, ReportSingle>,
+    wheels: HiResWheels,
+}
+
+impl<B: UsbBus> ScrollWheel<'_, B> {
+    /// Write a report, with wheel deltas in units of 1/[`RESOLUTION_MULTIPLIER`] of a detent
+    pub fn write_report(&mut self, report: &ScrollWheelReport) -> Result<(), UsbHidError> {
+        let ([vertical_wheel, horizontal_wheel], remainder) = self.wheels.scale(
+            self.interface.feature_report(),
+            [report.vertical_wheel, report.horizontal_wheel],
+        );
+        let report = ScrollWheelReport {
+            buttons: report.buttons & 0b11,
+            vertical_wheel,
+            horizontal_wheel,
+        };
+        let data = report.pack().map_err(|_| {
+            error!("Error packing ScrollWheelReport");
+            UsbHidError::SerializationError
+        })?;
+        let mut report = [HI_RES_INPUT_REPORT_ID; 4];
+        report[1..].copy_from_slice(&data);
+        self.interface
+            .write_report(&report)
+            .map_err(UsbHidError::from)?;
+        self.wheels.sent(remainder);
+        Ok(())
+    }
+
+    /// `true` if the host has enabled high resolution scrolling for the vertical wheel
+    #[must_use]
+    pub fn vertical_hi_res(&self) -> bool {
+        HiResWheels::vertical_hi_res(self.interface.feature_report())
+    }
+
+    /// `true` if the host has enabled high resolution scrolling for the horizontal wheel
+    #[must_use]
+    pub fn horizontal_hi_res(&self) -> bool {
+        HiResWheels::horizontal_hi_res(self.interface.feature_report())
+    }
+}
+
+pub struct ScrollWheelConfig<'a> {
+    interface: InterfaceConfig<'a, InBytes8, OutNone, ReportSingle>,
+}
+
+impl<'a> ScrollWheelConfig<'a> {
+    /// `interface` must keep the Resolution Multiplier feature report, see
+    /// [`InterfaceBuilder::feature_report`]
+    #[must_use]
+    pub fn new(interface: InterfaceConfig<'a, InBytes8, OutNone, ReportSingle>) -> Self {
+        Self { interface }
+    }
+}
+
+impl Default for ScrollWheelConfig<'_> {
+    fn default() -> Self {
+        Self::new(
+            unwrap!(unwrap!(
+                unwrap!(InterfaceBuilder::new_static(SCROLL_WHEEL_REPORT_DESCRIPTOR))
+                    .description("Scroll Wheel")
+                    .feature_report(HI_RES_FEATURE_REPORT_ID, &[HI_RES_FEATURE_REPORT_ID, 0])
+            )
+            .in_endpoint(10.millis()))
+            .without_out_endpoint()
+            .build(),
+        )
+    }
+}
+
+impl<'a, B: UsbBus + 'a> UsbAllocatable<'a, B> for ScrollWheelConfig<'a> {
+    type Allocated = ScrollWheel<'a, B>;
+
+    fn allocate(self, usb_alloc: &'a UsbBusAllocator<B>) -> Self::Allocated {
+        ScrollWheel {
+            interface: self.interface.allocate(usb_alloc),
+            wheels: HiResWheels::default(),
+        }
+    }
+}
+
+impl<'a, B: UsbBus> DeviceClass<'a> for ScrollWheel<'a, B> {
+    type I = Interface<'a, B, InBytes8, OutNone, ReportSingle>;
+
+    fn interface(&mut self) -> &mut Self::I {
+        &mut self.interface
+    }
+
+    fn reset(&mut self) {
+        self.wheels = HiResWheels::default();
+    }
+
+    fn tick(&mut self) -> Result<(), UsbHidError> {
+        Ok(())
+    }
+
+    fn next_deadline(&mut self) -> Option<MillisDurationU32> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    #[test]
+    fn screen_mapping_covers_logical_range() {
+        let screen = ScreenMapping::new(1920, 1080);
+        assert_eq!(screen.map(0, 0), (0, 0));
+        assert_eq!(screen.map(1919, 1079), (ABSOLUTE_MAX, ABSOLUTE_MAX));
+        assert_eq!(screen.map(5000, 5000), (ABSOLUTE_MAX, ABSOLUTE_MAX));
+        assert_eq!(screen.map(960, 540), (16392, 16399));
+        assert_eq!(
+            screen.report(1919, 0).buttons(1),
+            AbsoluteWheelMouseReport::new().x(ABSOLUTE_MAX).buttons(1)
+        );
+        assert_eq!(ScreenMapping::new(0, 1).map(10, 10), (0, 0));
+    }
+
+    #[test]
+    fn wheel_mouse_scroll_setters() {
+        let report = WheelMouseReport::new().scroll_v(2).scroll_h(-3);
+        assert_eq!(report.pack().unwrap(), [0, 0, 0, 2, 0xFD]);
+    }
 }
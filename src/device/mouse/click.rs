@@ -0,0 +1,185 @@
+//! Button filtering for mice with worn or bouncy switches
+//!
+//! [`ButtonDebouncer`] debounces each of the eight buttons of a mouse report and [`DragLock`]
+//! latches a button after a short tap, so it can be held for a drag without keeping the switch
+//! pressed. Both are ticked once per ms with the raw button bits and return the filtered button
+//! bits, and can be chained:
+//!
+//! ```rust
+//! use xous_usb_hid::device::mouse::click::{ButtonDebouncer, DragLock};
+//!
+//! let mut debouncer = ButtonDebouncer::default();
+//! let mut drag_lock = DragLock::default();
+//!
+//! # let raw_buttons = 0;
+//! let buttons = drag_lock.tick(debouncer.tick(raw_buttons));
+//! ```
+
+use crate::debounce::Debouncer;
+use fugit::MillisDurationU32;
+
+/// Debounces each of the eight buttons of a mouse report
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Default)]
+pub struct ButtonDebouncer {
+    buttons: [Debouncer; 8],
+}
+
+impl ButtonDebouncer {
+    #[must_use]
+    pub const fn new(period: MillisDurationU32) -> Self {
+        Self {
+            buttons: [Debouncer::new(period); 8],
+        }
+    }
+
+    /// Update with the raw button bits, must be called every 1ms. Returns the debounced button
+    /// bits
+    pub fn tick(&mut self, raw: u8) -> u8 {
+        self.buttons
+            .iter_mut()
+            .enumerate()
+            .fold(0, |buttons, (i, debouncer)| {
+                if debouncer.tick(raw & (1 << i) != 0) {
+                    buttons | (1 << i)
+                } else {
+                    buttons
+                }
+            })
+    }
+
+    pub fn reset(&mut self) {
+        for debouncer in &mut self.buttons {
+            debouncer.reset();
+        }
+    }
+}
+
+/// Latches a button held after it is tapped, the next press of the button releases it
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct DragLock {
+    button_mask: u8,
+    tap_ms: u32,
+    enabled: bool,
+    pressed_ms: Option<u32>,
+    locked: bool,
+}
+
+impl Default for DragLock {
+    /// Primary button, tapped for less than 200ms
+    fn default() -> Self {
+        Self::new(0x01, MillisDurationU32::millis(200))
+    }
+}
+
+impl DragLock {
+    /// Drag lock for the buttons in `button_mask`, a press shorter than `tap` locks the buttons
+    #[must_use]
+    pub const fn new(button_mask: u8, tap: MillisDurationU32) -> Self {
+        Self {
+            button_mask,
+            tap_ms: tap.ticks(),
+            enabled: true,
+            pressed_ms: None,
+            locked: false,
+        }
+    }
+
+    /// Enable or disable drag lock, disabling releases a locked button
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+        if !enabled {
+            self.reset();
+        }
+    }
+
+    #[must_use]
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// `true` while the buttons are latched
+    #[must_use]
+    pub fn is_locked(&self) -> bool {
+        self.locked
+    }
+
+    pub fn reset(&mut self) {
+        self.pressed_ms = None;
+        self.locked = false;
+    }
+
+    /// Update with the button bits, must be called every 1ms. Returns the button bits with the
+    /// locked buttons held
+    pub fn tick(&mut self, buttons: u8) -> u8 {
+        if !self.enabled {
+            return buttons;
+        }
+
+        let pressed = buttons & self.button_mask != 0;
+        self.pressed_ms = match (self.pressed_ms, pressed) {
+            (None, true) => Some(0),
+            (Some(ms), true) => Some(ms.saturating_add(1)),
+            (Some(ms), false) => {
+                // Any press releases the lock, only a tap sets it
+                self.locked = !self.locked && ms < self.tap_ms;
+                None
+            }
+            (None, false) => None,
+        };
+
+        if self.locked {
+            buttons | self.button_mask
+        } else {
+            buttons
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use fugit::ExtU32;
+
+    fn run(drag_lock: &mut DragLock, buttons: u8, ms: u32) -> u8 {
+        let mut out = 0;
+        for _ in 0..ms {
+            out = drag_lock.tick(buttons);
+        }
+        out
+    }
+
+    #[test]
+    fn buttons_are_debounced_independently() {
+        let mut debouncer = ButtonDebouncer::new(2.millis());
+        assert_eq!(debouncer.tick(0b101), 0);
+        assert_eq!(debouncer.tick(0b001), 0b001);
+        assert_eq!(debouncer.tick(0b100), 0b001);
+        assert_eq!(debouncer.tick(0b100), 0b100);
+    }
+
+    #[test]
+    fn tap_locks_and_press_unlocks() {
+        let mut drag_lock = DragLock::new(0x01, 100.millis());
+
+        // A long press is passed through
+        assert_eq!(run(&mut drag_lock, 0x01, 200), 0x01);
+        assert_eq!(run(&mut drag_lock, 0x00, 10), 0x00);
+        assert!(!drag_lock.is_locked());
+
+        // A tap latches the button, without a release in between
+        assert_eq!(run(&mut drag_lock, 0x01, 50), 0x01);
+        assert_eq!(run(&mut drag_lock, 0x02, 10), 0x03);
+        assert!(drag_lock.is_locked());
+
+        // The next press releases it, however long
+        assert_eq!(run(&mut drag_lock, 0x01, 300), 0x01);
+        assert_eq!(run(&mut drag_lock, 0x00, 10), 0x00);
+        assert!(!drag_lock.is_locked());
+
+        drag_lock.set_enabled(false);
+        assert_eq!(run(&mut drag_lock, 0x01, 50), 0x01);
+        assert_eq!(run(&mut drag_lock, 0x00, 10), 0x00);
+    }
+}
@@ -0,0 +1,220 @@
+//! Gesture synthesis for hosts without precision touchpad support
+//!
+//! [`GestureFallback`] translates two finger touchpad contacts into wheel mouse reports: two
+//! finger motion scrolls, and pinching zooms by scrolling the vertical wheel with Ctrl held. The
+//! fallback can be switched on and off at runtime, e.g. once it is known whether the host has
+//! picked up a precision touchpad collection.
+
+use crate::device::mouse::WheelMouseReport;
+
+/// Touchpad contact position, in touchpad units
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Default, Hash)]
+pub struct Contact {
+    pub x: i16,
+    pub y: i16,
+}
+
+/// Report synthesized from a gesture
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum Gesture {
+    /// Two finger scroll, positive `vertical` scrolls up and positive `horizontal` scrolls right
+    Scroll { vertical: i8, horizontal: i8 },
+    /// Pinch zoom, positive zooms in. Sent as the vertical wheel with Ctrl held
+    Zoom(i8),
+}
+
+impl Gesture {
+    /// Wheel mouse report for this gesture, with the given buttons held
+    #[must_use]
+    pub fn mouse_report(&self, buttons: u8) -> WheelMouseReport {
+        match *self {
+            Self::Scroll {
+                vertical,
+                horizontal,
+            } => WheelMouseReport {
+                buttons,
+                vertical_wheel: vertical,
+                horizontal_wheel: horizontal,
+                ..Default::default()
+            },
+            Self::Zoom(zoom) => WheelMouseReport {
+                buttons,
+                vertical_wheel: zoom,
+                ..Default::default()
+            },
+        }
+    }
+
+    /// `true` if Ctrl must be held on the keyboard while sending [`Gesture::mouse_report`]
+    #[must_use]
+    pub fn needs_ctrl(&self) -> bool {
+        matches!(self, Self::Zoom(_))
+    }
+}
+
+/// Translates two finger contacts into [`Gesture`]s
+#[derive(Clone, Debug)]
+pub struct GestureFallback {
+    enabled: bool,
+    scroll_resolution: i32,
+    zoom_resolution: i32,
+    natural_scrolling: bool,
+    last: Option<[Contact; 2]>,
+    vertical: i32,
+    horizontal: i32,
+    zoom: i32,
+}
+
+impl Default for GestureFallback {
+    fn default() -> Self {
+        Self::new(32, 64)
+    }
+}
+
+impl GestureFallback {
+    /// `scroll_resolution` is the finger travel per wheel detent and `zoom_resolution` the change
+    /// in finger separation per zoom step, both in touchpad units
+    #[must_use]
+    pub fn new(scroll_resolution: u16, zoom_resolution: u16) -> Self {
+        Self {
+            enabled: true,
+            scroll_resolution: i32::from(scroll_resolution.max(1)),
+            zoom_resolution: i32::from(zoom_resolution.max(1)),
+            natural_scrolling: false,
+            last: None,
+            vertical: 0,
+            horizontal: 0,
+            zoom: 0,
+        }
+    }
+
+    /// Scroll content in the direction the fingers move, rather than the opposite
+    #[must_use]
+    pub fn natural_scrolling(mut self, natural_scrolling: bool) -> Self {
+        self.natural_scrolling = natural_scrolling;
+        self
+    }
+
+    #[must_use]
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Enable or disable gesture synthesis, disabling also discards any gesture in progress
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+        if !enabled {
+            self.reset();
+        }
+    }
+
+    /// Discard any gesture in progress
+    pub fn reset(&mut self) {
+        self.last = None;
+        self.vertical = 0;
+        self.horizontal = 0;
+        self.zoom = 0;
+    }
+
+    /// Process the current set of contacts, returning a gesture report if one is due
+    ///
+    /// Anything other than exactly two contacts ends the current gesture
+    pub fn update(&mut self, contacts: &[Contact]) -> Option<Gesture> {
+        if !self.enabled {
+            return None;
+        }
+        let &[a, b] = contacts else {
+            self.reset();
+            return None;
+        };
+        let [last_a, last_b] = self.last.replace([a, b])?;
+
+        // Centroid motion, doubled to avoid rounding
+        let dx = i32::from(a.x) + i32::from(b.x) - i32::from(last_a.x) - i32::from(last_b.x);
+        let dy = i32::from(a.y) + i32::from(b.y) - i32::from(last_a.y) - i32::from(last_b.y);
+        let spread = separation(a, b) - separation(last_a, last_b);
+
+        if spread.abs() * 2 > dx.abs().max(dy.abs()) {
+            self.vertical = 0;
+            self.horizontal = 0;
+            self.zoom += spread;
+            let steps = take_steps(&mut self.zoom, self.zoom_resolution);
+            (steps != 0).then_some(Gesture::Zoom(steps))
+        } else {
+            self.zoom = 0;
+            let sign = if self.natural_scrolling { -1 } else { 1 };
+            self.vertical += sign * -dy / 2;
+            self.horizontal += sign * dx / 2;
+            let vertical = take_steps(&mut self.vertical, self.scroll_resolution);
+            let horizontal = take_steps(&mut self.horizontal, self.scroll_resolution);
+            (vertical != 0 || horizontal != 0).then_some(Gesture::Scroll {
+                vertical,
+                horizontal,
+            })
+        }
+    }
+}
+
+/// Manhattan distance between two contacts
+fn separation(a: Contact, b: Contact) -> i32 {
+    (i32::from(a.x) - i32::from(b.x)).abs() + (i32::from(a.y) - i32::from(b.y)).abs()
+}
+
+/// Remove whole steps from an accumulator, leaving the remainder
+fn take_steps(accumulator: &mut i32, resolution: i32) -> i8 {
+    let steps = (*accumulator / resolution).clamp(i32::from(i8::MIN), i32::from(i8::MAX));
+    *accumulator -= steps * resolution;
+    #[allow(clippy::cast_possible_truncation)]
+    let steps = steps as i8;
+    steps
+}
+
+#[cfg(test)]
+mod test {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    fn contacts(a: (i16, i16), b: (i16, i16)) -> [Contact; 2] {
+        [Contact { x: a.0, y: a.1 }, Contact { x: b.0, y: b.1 }]
+    }
+
+    #[test]
+    fn two_finger_motion_scrolls() {
+        let mut gestures = GestureFallback::new(10, 10);
+        assert_eq!(gestures.update(&contacts((100, 100), (120, 100))), None);
+        assert_eq!(gestures.update(&contacts((100, 95), (120, 95))), None);
+        assert_eq!(
+            gestures.update(&contacts((100, 85), (120, 85))),
+            Some(Gesture::Scroll {
+                vertical: 1,
+                horizontal: 0
+            })
+        );
+    }
+
+    #[test]
+    fn pinch_zooms_with_ctrl() {
+        let mut gestures = GestureFallback::new(10, 10);
+        gestures.update(&contacts((100, 100), (120, 100)));
+        let zoom = gestures.update(&contacts((90, 100), (130, 100))).unwrap();
+        assert_eq!(zoom, Gesture::Zoom(2));
+        assert!(zoom.needs_ctrl());
+        assert_eq!(zoom.mouse_report(0).vertical_wheel, 2);
+    }
+
+    #[test]
+    fn disabled_and_single_contact() {
+        let mut gestures = GestureFallback::new(10, 10);
+        gestures.set_enabled(false);
+        gestures.update(&contacts((100, 100), (120, 100)));
+        assert_eq!(gestures.update(&contacts((100, 0), (120, 0))), None);
+
+        gestures.set_enabled(true);
+        gestures.update(&contacts((100, 100), (120, 100)));
+        gestures.update(&[Contact { x: 100, y: 100 }]);
+        assert_eq!(gestures.update(&contacts((100, 0), (120, 0))), None);
+    }
+}
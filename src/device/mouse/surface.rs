@@ -0,0 +1,267 @@
+//! Optical sensor surface status for diagnostics, enabled by the `mouse-surface` feature
+//!
+//! [`SurfaceMouse`] is a wheel mouse with a second, vendor defined top-level collection carrying
+//! the status of the optical sensor: whether the mouse is lifted off the surface, the surface
+//! quality and the frame rate. Host tools read it with the HID APIs of the host while the mouse
+//! collection keeps working as usual. Sensors report these values in their own units, e.g. the
+//! surface quality register of most optical mouse sensors, and they are passed on unchanged.
+//!
+//! The status is only sent when it changes. It is sent from `tick` when the endpoint isn't busy
+//! with mouse reports, and a newer status replaces one that hasn't been sent yet.
+//!
+//! Input report 2, after its report ID, is [`SURFACE_STATUS_REPORT_LEN`] bytes:
+//!
+//! | Byte | Content                                              |
+//! |------|------------------------------------------------------|
+//! | 0    | Bit 0 set while lifted off the surface               |
+//! | 1    | Surface quality, 0 to 255                            |
+//! | 2..4 | Frame rate in frames per second, little endian       |
+//!
+//! ```rust,no_run
+//! # use xous_usb_hid::device::mouse::surface::*;
+//! # use usb_device::bus::UsbBus;
+//! # fn sample<B: UsbBus>(mouse: &mut SurfaceMouse<'_, B>) {
+//! mouse.set_surface_status(SurfaceStatus {
+//!     lifted: false,
+//!     quality: 87,
+//!     frame_rate: 6000,
+//! });
+//! # }
+//! ```
+
+use crate::device::mouse::WheelMouseReport;
+use crate::usb_class::prelude::*;
+use fugit::{ExtU32, MillisDurationU32};
+use packed_struct::prelude::*;
+use usb_device::bus::UsbBus;
+use usb_device::class_prelude::UsbBusAllocator;
+
+use crate::protocol::surface::{
+    FLAG_LIFTED, MOUSE_REPORT_ID, OFFSET_FLAGS, OFFSET_FRAME_RATE, OFFSET_QUALITY,
+};
+pub use crate::protocol::surface::{
+    STATUS_REPORT_ID as SURFACE_STATUS_REPORT_ID, STATUS_REPORT_LEN as SURFACE_STATUS_REPORT_LEN,
+};
+
+/// Wheel mouse with a vendor defined surface status collection
+///
+/// Input report 1 has the layout of [`WheelMouseReport`], input report 2 the layout of
+/// [`SurfaceStatus::to_bytes`]. Not boot compliant, as the reports have report IDs
+#[rustfmt::skip]
+pub const SURFACE_MOUSE_REPORT_DESCRIPTOR: &[u8] = &[
+    0x05, 0x01,                   // Usage Page (Generic Desktop),
+    0x09, 0x02,                   // Usage (Mouse),
+    0xA1, 0x01,                   // Collection (Application),
+    0x85, 0x01,                   //   Report ID (1),
+    0x09, 0x01,                   //   Usage (Pointer),
+    0xA1, 0x00,                   //   Collection (Physical),
+    0x05, 0x09,                   //     Usage Page (Buttons),
+    0x19, 0x01,                   //     Usage Minimum (1),
+    0x29, 0x08,                   //     Usage Maximum (8),
+    0x15, 0x00,                   //     Logical Minimum (0),
+    0x25, 0x01,                   //     Logical Maximum (1),
+    0x75, 0x01,                   //     Report Size (1),
+    0x95, 0x08,                   //     Report Count (8),
+    0x81, 0x02,                   //     Input (Data, Variable, Absolute),
+    0x05, 0x01,                   //     Usage Page (Generic Desktop),
+    0x09, 0x30,                   //     Usage (X),
+    0x09, 0x31,                   //     Usage (Y),
+    0x09, 0x38,                   //     Usage (Wheel),
+    0x15, 0x81,                   //     Logical Minimum (-127),
+    0x25, 0x7F,                   //     Logical Maximum (127),
+    0x75, 0x08,                   //     Report Size (8),
+    0x95, 0x03,                   //     Report Count (3),
+    0x81, 0x06,                   //     Input (Data, Variable, Relative),
+    0x05, 0x0C,                   //     Usage Page (Consumer),
+    0x0A, 0x38, 0x02,             //     Usage (AC Pan),
+    0x95, 0x01,                   //     Report Count (1),
+    0x81, 0x06,                   //     Input (Data, Variable, Relative),
+    0xC0,                         //   End Collection,
+    0xC0,                         // End Collection,
+    0x06, 0x00, 0xFF,             // Usage Page (Vendor Defined 0xFF00),
+    0x09, 0x20,                   // Usage (0x20),
+    0xA1, 0x01,                   // Collection (Application),
+    0x85, 0x02,                   //   Report ID (2),
+    0x09, 0x21,                   //   Usage (0x21),
+    0x15, 0x00,                   //   Logical Minimum (0),
+    0x25, 0x01,                   //   Logical Maximum (1),
+    0x75, 0x01,                   //   Report Size (1),
+    0x95, 0x01,                   //   Report Count (1),
+    0x81, 0x02,                   //   Input (Data, Variable, Absolute), ;Lifted
+    0x75, 0x07,                   //   Report Size (7),
+    0x81, 0x03,                   //   Input (Constant, Variable, Absolute),
+    0x09, 0x22,                   //   Usage (0x22),
+    0x26, 0xFF, 0x00,             //   Logical Maximum (255),
+    0x75, 0x08,                   //   Report Size (8),
+    0x81, 0x02,                   //   Input (Data, Variable, Absolute), ;Surface quality
+    0x09, 0x23,                   //   Usage (0x23),
+    0x27, 0xFF, 0xFF, 0x00, 0x00, //   Logical Maximum (65535),
+    0x75, 0x10,                   //   Report Size (16),
+    0x81, 0x02,                   //   Input (Data, Variable, Absolute), ;Frame rate
+    0xC0,                         // End Collection
+];
+
+/// Status of the optical sensor
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Default, Hash)]
+pub struct SurfaceStatus {
+    /// Lifted off the surface, the sensor doesn't track
+    pub lifted: bool,
+    /// Surface quality as read from the sensor, higher is better
+    pub quality: u8,
+    /// Frames per second captured by the sensor
+    pub frame_rate: u16,
+}
+
+impl SurfaceStatus {
+    /// Surface status report, without its report ID
+    #[must_use]
+    pub fn to_bytes(&self) -> [u8; SURFACE_STATUS_REPORT_LEN] {
+        let mut data = [0; SURFACE_STATUS_REPORT_LEN];
+        if self.lifted {
+            data[OFFSET_FLAGS] |= FLAG_LIFTED;
+        }
+        data[OFFSET_QUALITY] = self.quality;
+        data[OFFSET_FRAME_RATE..OFFSET_FRAME_RATE + 2]
+            .copy_from_slice(&self.frame_rate.to_le_bytes());
+        data
+    }
+}
+
+/// Wheel mouse reporting the surface status, see the [module docs](self)
+///
+/// **Note:** The surface status is sent by [`UsbHidClass::tick()`], which must be called every
+/// 1ms.
+pub struct SurfaceMouse<'a, B: UsbBus> {
+    interface: Interface<'a, B, InBytes8, OutNone, ReportSingle>,
+    status: Option<SurfaceStatus>,
+    sent: Option<SurfaceStatus>,
+}
+
+impl<B: UsbBus> SurfaceMouse<'_, B> {
+    pub fn write_report(&mut self, report: &WheelMouseReport) -> Result<(), UsbHidError> {
+        let data = report.pack().map_err(|_| {
+            error!("Error packing WheelMouseReport");
+            UsbHidError::SerializationError
+        })?;
+        let mut report = [MOUSE_REPORT_ID; 6];
+        report[1..].copy_from_slice(&data);
+        self.interface
+            .write_report(&report)
+            .map(|_| ())
+            .map_err(UsbHidError::from)
+    }
+
+    /// Update the surface status, sent by `tick` if it has changed
+    pub fn set_surface_status(&mut self, status: SurfaceStatus) {
+        self.status = (self.sent != Some(status)).then_some(status);
+    }
+
+    /// Last surface status sent to the host
+    #[must_use]
+    pub fn surface_status(&self) -> Option<SurfaceStatus> {
+        self.sent
+    }
+}
+
+impl<'a, B: UsbBus> DeviceClass<'a> for SurfaceMouse<'a, B> {
+    type I = Interface<'a, B, InBytes8, OutNone, ReportSingle>;
+
+    fn interface(&mut self) -> &mut Self::I {
+        &mut self.interface
+    }
+
+    /// Sends the last surface status again once the host is back
+    fn reset(&mut self) {
+        self.status = self.status.or(self.sent);
+        self.sent = None;
+    }
+
+    fn tick(&mut self) -> Result<(), UsbHidError> {
+        let Some(status) = self.status else {
+            return Ok(());
+        };
+        let mut report = [SURFACE_STATUS_REPORT_ID; 1 + SURFACE_STATUS_REPORT_LEN];
+        report[1..].copy_from_slice(&status.to_bytes());
+        match self
+            .interface
+            .write_report(&report)
+            .map_err(UsbHidError::from)
+        {
+            Ok(_) => {
+                self.sent = Some(status);
+                self.status = None;
+                Ok(())
+            }
+            Err(UsbHidError::WouldBlock) => Ok(()),
+            Err(e) => Err(e),
+        }
+    }
+
+    fn next_deadline(&mut self) -> Option<MillisDurationU32> {
+        self.status.map(|_| 1.millis())
+    }
+}
+
+pub struct SurfaceMouseConfig<'a> {
+    interface: InterfaceConfig<'a, InBytes8, OutNone, ReportSingle>,
+}
+
+impl<'a> SurfaceMouseConfig<'a> {
+    #[must_use]
+    pub fn new(interface: InterfaceConfig<'a, InBytes8, OutNone, ReportSingle>) -> Self {
+        Self { interface }
+    }
+}
+
+impl Default for SurfaceMouseConfig<'_> {
+    fn default() -> Self {
+        Self::new(
+            unwrap!(unwrap!(InterfaceBuilder::new_static(
+                SURFACE_MOUSE_REPORT_DESCRIPTOR
+            ))
+            .description("Wheel Mouse")
+            .in_endpoint(10.millis()))
+            .without_out_endpoint()
+            .build(),
+        )
+    }
+}
+
+impl<'a, B: UsbBus + 'a> UsbAllocatable<'a, B> for SurfaceMouseConfig<'a> {
+    type Allocated = SurfaceMouse<'a, B>;
+
+    fn allocate(self, usb_alloc: &'a UsbBusAllocator<B>) -> Self::Allocated {
+        SurfaceMouse {
+            interface: self.interface.allocate(usb_alloc),
+            status: None,
+            sent: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn surface_status_report() {
+        let status = SurfaceStatus {
+            lifted: true,
+            quality: 0x42,
+            frame_rate: 0x1770,
+        };
+        assert_eq!(status.to_bytes(), [1, 0x42, 0x70, 0x17]);
+        assert_eq!(SurfaceStatus::default().to_bytes(), [0; 4]);
+
+        // Vendor collection with report ID 2, after the mouse collection
+        let vendor = SURFACE_MOUSE_REPORT_DESCRIPTOR
+            .windows(3)
+            .position(|item| item == [0x06, 0x00, 0xFF])
+            .unwrap_or_default();
+        assert!(vendor > 0);
+        assert!(SURFACE_MOUSE_REPORT_DESCRIPTOR[vendor..]
+            .windows(2)
+            .any(|item| item == [0x85, SURFACE_STATUS_REPORT_ID]));
+    }
+}
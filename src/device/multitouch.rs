@@ -0,0 +1,550 @@
+//! Multitouch touchscreens
+//!
+//! [`Multitouch`] reports up to `N` simultaneous contacts through a digitizer touch screen
+//! collection, in hybrid mode: each report carries up to [`CONTACTS_PER_REPORT`] contacts, and a
+//! frame with more contacts is sent as several reports. The first report of a frame carries the
+//! number of contacts in the whole frame and the following reports a contact count of 0. The
+//! host reads `N` from the Contact Count Maximum feature report.
+//!
+//! Contacts are kept in `N` slots, and the slot index is the contact identifier reported to the
+//! host, so a contact must stay in the same slot from touch down to lift off. A lifted contact is
+//! reported once more with its tip switch clear before the slot is freed.
+//!
+//! ```rust
+//! # use xous_usb_hid::device::multitouch::*;
+//! let mut slots = ContactSlots::<10>::new();
+//! slots.touch(0, 100, 200).unwrap();
+//! slots.touch(1, 300, 400).unwrap();
+//!
+//! // Two contacts fit in a single report, multitouch.write_frame() sends it
+//! let report = slots.report().unwrap();
+//! assert_eq!(report[MULTITOUCH_REPORT_LEN - 1], 2);
+//! slots.advance();
+//! assert!(!slots.is_sending());
+//! ```
+
+use crate::device::touchscreen::MAX_COORDINATE;
+use crate::usb_class::prelude::*;
+use fugit::{ExtU32, MillisDurationU32};
+use heapless::Vec;
+use usb_device::bus::UsbBus;
+use usb_device::class_prelude::UsbBusAllocator;
+use usb_device::UsbError;
+
+pub mod tracker;
+
+/// Report ID of the contact reports
+pub const MULTITOUCH_REPORT_ID: u8 = 0x01;
+/// Report ID of the Contact Count Maximum feature report
+pub const CONTACT_COUNT_MAXIMUM_REPORT_ID: u8 = 0x02;
+
+/// Contacts carried by each report
+pub const CONTACTS_PER_REPORT: usize = 5;
+
+/// Length of a contact report, including the report ID
+pub const MULTITOUCH_REPORT_LEN: usize = 2 + CONTACT_LEN * CONTACTS_PER_REPORT;
+
+/// Most contacts, limited by the logical range of the Contact Identifier and Contact Count usages
+pub const MAX_CONTACTS: usize = 127;
+
+const CONTACT_LEN: usize = 6;
+
+/// Hybrid mode multitouch descriptor
+///
+/// * Report ID 1, Touch Screen: [`CONTACTS_PER_REPORT`] fingers with a Tip Switch bit, a Contact
+///   Identifier and X and Y from 0 to [`MAX_COORDINATE`], 16 bits each, followed by the Contact
+///   Count (32 bytes)
+/// * Report ID 2, Contact Count Maximum feature (2 bytes)
+#[rustfmt::skip]
+pub const MULTITOUCH_REPORT_DESCRIPTOR: &[u8] = &[
+    0x05, 0x0D,       // Usage Page (Digitizer),
+    0x09, 0x04,       // Usage (Touch Screen),
+    0xA1, 0x01,       // Collection (Application),
+    0x85, 0x01,       //   Report ID (1),
+    0x05, 0x0D,       //   Usage Page (Digitizer),
+    0x09, 0x22,       //   Usage (Finger),
+    0xA1, 0x02,       //   Collection (Logical),
+    0x09, 0x42,       //     Usage (Tip Switch),
+    0x15, 0x00,       //     Logical Minimum (0),
+    0x25, 0x01,       //     Logical Maximum (1),
+    0x75, 0x01,       //     Report Size (1),
+    0x95, 0x01,       //     Report Count (1),
+    0x81, 0x02,       //     Input (Data, Variable, Absolute),
+    0x95, 0x07,       //     Report Count (7),
+    0x81, 0x03,       //     Input (Constant, Variable, Absolute),
+    0x09, 0x51,       //     Usage (Contact Identifier),
+    0x25, 0x7F,       //     Logical Maximum (127),
+    0x75, 0x08,       //     Report Size (8),
+    0x95, 0x01,       //     Report Count (1),
+    0x81, 0x02,       //     Input (Data, Variable, Absolute),
+    0x05, 0x01,       //     Usage Page (Generic Desktop),
+    0x09, 0x30,       //     Usage (X),
+    0x09, 0x31,       //     Usage (Y),
+    0x26, 0xFF, 0x7F, //     Logical Maximum (32767),
+    0x75, 0x10,       //     Report Size (16),
+    0x95, 0x02,       //     Report Count (2),
+    0x81, 0x02,       //     Input (Data, Variable, Absolute),
+    0xC0,             //   End Collection,
+    0x05, 0x0D,       //   Usage Page (Digitizer),
+    0x09, 0x22,       //   Usage (Finger),
+    0xA1, 0x02,       //   Collection (Logical),
+    0x09, 0x42,       //     Usage (Tip Switch),
+    0x15, 0x00,       //     Logical Minimum (0),
+    0x25, 0x01,       //     Logical Maximum (1),
+    0x75, 0x01,       //     Report Size (1),
+    0x95, 0x01,       //     Report Count (1),
+    0x81, 0x02,       //     Input (Data, Variable, Absolute),
+    0x95, 0x07,       //     Report Count (7),
+    0x81, 0x03,       //     Input (Constant, Variable, Absolute),
+    0x09, 0x51,       //     Usage (Contact Identifier),
+    0x25, 0x7F,       //     Logical Maximum (127),
+    0x75, 0x08,       //     Report Size (8),
+    0x95, 0x01,       //     Report Count (1),
+    0x81, 0x02,       //     Input (Data, Variable, Absolute),
+    0x05, 0x01,       //     Usage Page (Generic Desktop),
+    0x09, 0x30,       //     Usage (X),
+    0x09, 0x31,       //     Usage (Y),
+    0x26, 0xFF, 0x7F, //     Logical Maximum (32767),
+    0x75, 0x10,       //     Report Size (16),
+    0x95, 0x02,       //     Report Count (2),
+    0x81, 0x02,       //     Input (Data, Variable, Absolute),
+    0xC0,             //   End Collection,
+    0x05, 0x0D,       //   Usage Page (Digitizer),
+    0x09, 0x22,       //   Usage (Finger),
+    0xA1, 0x02,       //   Collection (Logical),
+    0x09, 0x42,       //     Usage (Tip Switch),
+    0x15, 0x00,       //     Logical Minimum (0),
+    0x25, 0x01,       //     Logical Maximum (1),
+    0x75, 0x01,       //     Report Size (1),
+    0x95, 0x01,       //     Report Count (1),
+    0x81, 0x02,       //     Input (Data, Variable, Absolute),
+    0x95, 0x07,       //     Report Count (7),
+    0x81, 0x03,       //     Input (Constant, Variable, Absolute),
+    0x09, 0x51,       //     Usage (Contact Identifier),
+    0x25, 0x7F,       //     Logical Maximum (127),
+    0x75, 0x08,       //     Report Size (8),
+    0x95, 0x01,       //     Report Count (1),
+    0x81, 0x02,       //     Input (Data, Variable, Absolute),
+    0x05, 0x01,       //     Usage Page (Generic Desktop),
+    0x09, 0x30,       //     Usage (X),
+    0x09, 0x31,       //     Usage (Y),
+    0x26, 0xFF, 0x7F, //     Logical Maximum (32767),
+    0x75, 0x10,       //     Report Size (16),
+    0x95, 0x02,       //     Report Count (2),
+    0x81, 0x02,       //     Input (Data, Variable, Absolute),
+    0xC0,             //   End Collection,
+    0x05, 0x0D,       //   Usage Page (Digitizer),
+    0x09, 0x22,       //   Usage (Finger),
+    0xA1, 0x02,       //   Collection (Logical),
+    0x09, 0x42,       //     Usage (Tip Switch),
+    0x15, 0x00,       //     Logical Minimum (0),
+    0x25, 0x01,       //     Logical Maximum (1),
+    0x75, 0x01,       //     Report Size (1),
+    0x95, 0x01,       //     Report Count (1),
+    0x81, 0x02,       //     Input (Data, Variable, Absolute),
+    0x95, 0x07,       //     Report Count (7),
+    0x81, 0x03,       //     Input (Constant, Variable, Absolute),
+    0x09, 0x51,       //     Usage (Contact Identifier),
+    0x25, 0x7F,       //     Logical Maximum (127),
+    0x75, 0x08,       //     Report Size (8),
+    0x95, 0x01,       //     Report Count (1),
+    0x81, 0x02,       //     Input (Data, Variable, Absolute),
+    0x05, 0x01,       //     Usage Page (Generic Desktop),
+    0x09, 0x30,       //     Usage (X),
+    0x09, 0x31,       //     Usage (Y),
+    0x26, 0xFF, 0x7F, //     Logical Maximum (32767),
+    0x75, 0x10,       //     Report Size (16),
+    0x95, 0x02,       //     Report Count (2),
+    0x81, 0x02,       //     Input (Data, Variable, Absolute),
+    0xC0,             //   End Collection,
+    0x05, 0x0D,       //   Usage Page (Digitizer),
+    0x09, 0x22,       //   Usage (Finger),
+    0xA1, 0x02,       //   Collection (Logical),
+    0x09, 0x42,       //     Usage (Tip Switch),
+    0x15, 0x00,       //     Logical Minimum (0),
+    0x25, 0x01,       //     Logical Maximum (1),
+    0x75, 0x01,       //     Report Size (1),
+    0x95, 0x01,       //     Report Count (1),
+    0x81, 0x02,       //     Input (Data, Variable, Absolute),
+    0x95, 0x07,       //     Report Count (7),
+    0x81, 0x03,       //     Input (Constant, Variable, Absolute),
+    0x09, 0x51,       //     Usage (Contact Identifier),
+    0x25, 0x7F,       //     Logical Maximum (127),
+    0x75, 0x08,       //     Report Size (8),
+    0x95, 0x01,       //     Report Count (1),
+    0x81, 0x02,       //     Input (Data, Variable, Absolute),
+    0x05, 0x01,       //     Usage Page (Generic Desktop),
+    0x09, 0x30,       //     Usage (X),
+    0x09, 0x31,       //     Usage (Y),
+    0x26, 0xFF, 0x7F, //     Logical Maximum (32767),
+    0x75, 0x10,       //     Report Size (16),
+    0x95, 0x02,       //     Report Count (2),
+    0x81, 0x02,       //     Input (Data, Variable, Absolute),
+    0xC0,             //   End Collection,
+    0x05, 0x0D,       //   Usage Page (Digitizer),
+    0x09, 0x54,       //   Usage (Contact Count),
+    0x25, 0x7F,       //   Logical Maximum (127),
+    0x75, 0x08,       //   Report Size (8),
+    0x95, 0x01,       //   Report Count (1),
+    0x81, 0x02,       //   Input (Data, Variable, Absolute),
+    0x85, 0x02,       //   Report ID (2),
+    0x09, 0x55,       //   Usage (Contact Count Maximum),
+    0xB1, 0x02,       //   Feature (Data, Variable, Absolute),
+    0xC0,             // End Collection
+];
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Default, Hash)]
+enum Slot {
+    #[default]
+    Empty,
+    Down {
+        x: u16,
+        y: u16,
+    },
+    Lifted {
+        x: u16,
+        y: u16,
+    },
+}
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+struct FrameContact {
+    id: u8,
+    tip_switch: bool,
+    x: u16,
+    y: u16,
+}
+
+/// Contact slots and the frame being sent, see the [module docs](self)
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContactSlots<const N: usize> {
+    slots: [Slot; N],
+    frame: Vec<FrameContact, N>,
+    sent: usize,
+}
+
+impl<const N: usize> Default for ContactSlots<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> ContactSlots<N> {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            slots: [Slot::Empty; N],
+            frame: Vec::new(),
+            sent: 0,
+        }
+    }
+
+    /// Put contact `slot` down at `x`, `y`, or move it there. Fails with
+    /// [`UsbHidError::SerializationError`] if `slot` isn't below `N` and [`MAX_CONTACTS`]
+    pub fn touch(&mut self, slot: usize, x: u16, y: u16) -> Result<(), UsbHidError> {
+        if slot >= N.min(MAX_CONTACTS) {
+            warn!("No contact slot {}", slot);
+            return Err(UsbHidError::SerializationError);
+        }
+        self.slots[slot] = Slot::Down {
+            x: x.min(MAX_COORDINATE),
+            y: y.min(MAX_COORDINATE),
+        };
+        Ok(())
+    }
+
+    /// Lift contact `slot`, it is reported once more with the tip switch clear
+    pub fn lift(&mut self, slot: usize) {
+        if let Some(Slot::Down { x, y }) = self.slots.get(slot).copied() {
+            self.slots[slot] = Slot::Lifted { x, y };
+        }
+    }
+
+    /// `true` while contact `slot` is down
+    #[must_use]
+    pub fn is_touching(&self, slot: usize) -> bool {
+        matches!(self.slots.get(slot), Some(Slot::Down { .. }))
+    }
+
+    /// `true` if contact `slot` can touch down: not down, and its lift off has been reported
+    #[must_use]
+    pub fn is_free(&self, slot: usize) -> bool {
+        matches!(self.slots.get(slot), Some(Slot::Empty))
+    }
+
+    /// Number of contacts down
+    #[must_use]
+    pub fn contact_count(&self) -> usize {
+        self.slots
+            .iter()
+            .filter(|s| matches!(s, Slot::Down { .. }))
+            .count()
+    }
+
+    /// Next report of the current frame, starting a new frame from the slots if none is being
+    /// sent. `None` if there are no contacts to report
+    pub fn report(&mut self) -> Option<[u8; MULTITOUCH_REPORT_LEN]> {
+        if self.frame.is_empty() {
+            self.start_frame();
+        }
+        let contacts = self.frame.chunks(CONTACTS_PER_REPORT).nth(self.sent)?;
+
+        let mut report = [0; MULTITOUCH_REPORT_LEN];
+        report[0] = MULTITOUCH_REPORT_ID;
+        for (data, contact) in report[1..].chunks_exact_mut(CONTACT_LEN).zip(contacts) {
+            let [x_low, x_high] = contact.x.to_le_bytes();
+            let [y_low, y_high] = contact.y.to_le_bytes();
+            data.copy_from_slice(&[
+                u8::from(contact.tip_switch),
+                contact.id,
+                x_low,
+                x_high,
+                y_low,
+                y_high,
+            ]);
+        }
+        if self.sent == 0 {
+            report[MULTITOUCH_REPORT_LEN - 1] = u8::try_from(self.frame.len()).unwrap_or(u8::MAX);
+        }
+        Some(report)
+    }
+
+    /// Move on to the next report, once the report returned by [`ContactSlots::report`] has been
+    /// accepted
+    pub fn advance(&mut self) {
+        if self.frame.is_empty() {
+            return;
+        }
+        self.sent += 1;
+        if self.sent * CONTACTS_PER_REPORT >= self.frame.len() {
+            self.frame.clear();
+            self.sent = 0;
+        }
+    }
+
+    /// `true` while part of a frame remains to be sent
+    #[must_use]
+    pub fn is_sending(&self) -> bool {
+        !self.frame.is_empty()
+    }
+
+    /// Lift all contacts without reporting them and drop the frame being sent, e.g. after a bus
+    /// reset
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+
+    fn start_frame(&mut self) {
+        for (id, slot) in self.slots.iter_mut().enumerate() {
+            let (tip_switch, x, y) = match *slot {
+                Slot::Empty => continue,
+                Slot::Down { x, y } => (true, x, y),
+                Slot::Lifted { x, y } => {
+                    *slot = Slot::Empty;
+                    (false, x, y)
+                }
+            };
+            let contact = FrameContact {
+                id: u8::try_from(id).unwrap_or(u8::MAX),
+                tip_switch,
+                x,
+                y,
+            };
+            // Can't fail, the frame holds N contacts
+            self.frame.push(contact).ok();
+        }
+    }
+}
+
+/// Multitouch touchscreen with `N` contact slots, see the [module docs](self)
+pub struct Multitouch<'a, B: UsbBus, const N: usize> {
+    interface: Interface<'a, B, InBytes32, OutNone, Reports8>,
+    slots: ContactSlots<N>,
+}
+
+impl<B: UsbBus, const N: usize> Multitouch<'_, B, N> {
+    /// See [`ContactSlots::touch`]
+    pub fn touch(&mut self, slot: usize, x: u16, y: u16) -> Result<(), UsbHidError> {
+        self.slots.touch(slot, x, y)
+    }
+
+    /// See [`ContactSlots::lift`]
+    pub fn lift(&mut self, slot: usize) {
+        self.slots.lift(slot);
+    }
+
+    #[must_use]
+    pub fn is_touching(&self, slot: usize) -> bool {
+        self.slots.is_touching(slot)
+    }
+
+    #[must_use]
+    pub fn slots(&self) -> &ContactSlots<N> {
+        &self.slots
+    }
+
+    /// See [`ContactTracker::update`](tracker::ContactTracker::update)
+    pub fn track(
+        &mut self,
+        tracker: &mut tracker::ContactTracker<N>,
+        points: &[(u16, u16)],
+    ) -> Result<(), UsbHidError> {
+        tracker.update(points, &mut self.slots)
+    }
+
+    /// Send a frame with the contacts in the slots, call once per scan of the panel
+    ///
+    /// Returns [`UsbHidError::WouldBlock`] if a report of the frame couldn't be sent yet. The next
+    /// call then carries on with the rest of that frame, so call again until it returns `Ok`
+    pub fn write_frame(&mut self) -> Result<(), UsbHidError> {
+        while let Some(report) = self.slots.report() {
+            match self.interface.write_report(&report) {
+                Ok(_) => self.slots.advance(),
+                Err(UsbError::WouldBlock) => return Err(UsbHidError::WouldBlock),
+                Err(e) => return Err(UsbHidError::UsbError(e)),
+            }
+            if !self.slots.is_sending() {
+                break;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a, B: UsbBus, const N: usize> DeviceClass<'a> for Multitouch<'a, B, N> {
+    type I = Interface<'a, B, InBytes32, OutNone, Reports8>;
+
+    fn interface(&mut self) -> &mut Self::I {
+        &mut self.interface
+    }
+
+    fn reset(&mut self) {
+        self.slots.reset();
+    }
+
+    fn tick(&mut self) -> Result<(), UsbHidError> {
+        Ok(())
+    }
+
+    fn next_deadline(&mut self) -> Option<MillisDurationU32> {
+        None
+    }
+}
+
+pub struct MultitouchConfig<'a, const N: usize> {
+    interface: InterfaceConfig<'a, InBytes32, OutNone, Reports8>,
+}
+
+impl<const N: usize> Default for MultitouchConfig<'_, N> {
+    fn default() -> Self {
+        let maximum = u8::try_from(N.min(MAX_CONTACTS)).unwrap_or(u8::MAX);
+        Self::new(
+            unwrap!(unwrap!(
+                unwrap!(InterfaceBuilder::new_static(MULTITOUCH_REPORT_DESCRIPTOR))
+                    .description("Touchscreen")
+                    .feature_report(
+                        CONTACT_COUNT_MAXIMUM_REPORT_ID,
+                        &[CONTACT_COUNT_MAXIMUM_REPORT_ID, maximum]
+                    )
+            )
+            .in_endpoint(10.millis()))
+            .without_out_endpoint()
+            .build(),
+        )
+    }
+}
+
+impl<'a, const N: usize> MultitouchConfig<'a, N> {
+    /// `interface` must use [`MULTITOUCH_REPORT_DESCRIPTOR`] and keep the Contact Count Maximum
+    /// feature report
+    #[must_use]
+    pub fn new(interface: InterfaceConfig<'a, InBytes32, OutNone, Reports8>) -> Self {
+        Self { interface }
+    }
+}
+
+impl<'a, B: UsbBus + 'a, const N: usize> UsbAllocatable<'a, B> for MultitouchConfig<'a, N> {
+    type Allocated = Multitouch<'a, B, N>;
+
+    fn allocate(self, usb_alloc: &'a UsbBusAllocator<B>) -> Self::Allocated {
+        Self::Allocated {
+            interface: Interface::new(usb_alloc, self.interface),
+            slots: ContactSlots::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+    use std::vec::Vec;
+
+    fn frame<const N: usize>(slots: &mut ContactSlots<N>) -> Vec<[u8; MULTITOUCH_REPORT_LEN]> {
+        let mut reports = Vec::new();
+        while let Some(report) = slots.report() {
+            reports.push(report);
+            slots.advance();
+            if !slots.is_sending() {
+                break;
+            }
+        }
+        reports
+    }
+
+    #[test]
+    fn frames_split_across_reports() {
+        let mut slots = ContactSlots::<8>::new();
+        assert!(frame(&mut slots).is_empty());
+        assert!(slots.touch(8, 0, 0).is_err());
+
+        for slot in 0..7 {
+            slots
+                .touch(slot, 0x100 + u16::try_from(slot).unwrap(), 0xFFFF)
+                .unwrap();
+        }
+        let reports = frame(&mut slots);
+        assert_eq!(reports.len(), 2);
+        assert_eq!(reports[0][0], MULTITOUCH_REPORT_ID);
+        assert_eq!(reports[0][1..7], [1, 0, 0x00, 0x01, 0xFF, 0x7F]);
+        assert_eq!(reports[0][MULTITOUCH_REPORT_LEN - 1], 7);
+        assert_eq!(
+            reports[1][1..13],
+            [1, 5, 0x05, 0x01, 0xFF, 0x7F, 1, 6, 0x06, 0x01, 0xFF, 0x7F]
+        );
+        assert_eq!(reports[1][13..], [0; 19]);
+
+        // A lifted contact is reported once with the tip switch clear
+        slots.lift(0);
+        assert!(!slots.is_touching(0));
+        assert_eq!(slots.contact_count(), 6);
+        let reports = frame(&mut slots);
+        assert_eq!(reports[0][1..3], [0, 0]);
+        assert_eq!(reports[0][MULTITOUCH_REPORT_LEN - 1], 7);
+        let reports = frame(&mut slots);
+        assert_eq!(reports[0][1..3], [1, 1]);
+        assert_eq!(reports[0][MULTITOUCH_REPORT_LEN - 1], 6);
+    }
+
+    #[test]
+    fn frame_resumes_after_partial_send() {
+        let mut slots = ContactSlots::<8>::new();
+        for slot in 0..6 {
+            slots.touch(slot, 1, 1).unwrap();
+        }
+        slots.report().unwrap();
+        slots.advance();
+        // Changes only apply from the next frame
+        slots.touch(6, 1, 1).unwrap();
+        let rest = slots.report().unwrap();
+        assert_eq!(rest[MULTITOUCH_REPORT_LEN - 1], 0);
+        assert_eq!(rest[2], 5);
+        assert_eq!(rest[7], 0);
+        slots.advance();
+        assert!(!slots.is_sending());
+        assert_eq!(slots.report().unwrap()[MULTITOUCH_REPORT_LEN - 1], 7);
+    }
+}
@@ -0,0 +1,190 @@
+//! Stable contact identifiers for raw touch points
+//!
+//! Touch controllers often report the points touched in every scan without telling which point
+//! of the previous scan each one continues. Hosts require a contact to keep its identifier from
+//! touch down to lift off, so [`ContactTracker`] matches the points of every scan to the contacts
+//! of the previous one, nearest first, and keeps each contact in its slot:
+//!
+//! * A point within the maximum distance of a contact moves that contact
+//! * A contact without a point lifts off
+//! * Any other point touches down in the lowest free slot, a slot only becomes free once the lift
+//!   off of its previous contact has been reported
+//!
+//! ```rust
+//! # use xous_usb_hid::device::multitouch::ContactSlots;
+//! # use xous_usb_hid::device::multitouch::tracker::ContactTracker;
+//! let mut tracker = ContactTracker::<10>::new(200);
+//! let mut slots = ContactSlots::<10>::new();
+//!
+//! // for every scan of the panel, then multitouch.write_frame()
+//! tracker.update(&[(1000, 1000), (3000, 3000)], &mut slots).unwrap();
+//! tracker.update(&[(3050, 2950), (1010, 1020)], &mut slots).unwrap();
+//! assert_eq!(tracker.position(0), Some((1010, 1020)));
+//! assert_eq!(tracker.position(1), Some((3050, 2950)));
+//! ```
+
+use crate::device::multitouch::{ContactSlots, MAX_CONTACTS};
+use crate::UsbHidError;
+
+/// Assigns the points of every scan to contact slots, see the [module docs](self)
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ContactTracker<const N: usize> {
+    positions: [Option<(u16, u16)>; N],
+    max_distance: u16,
+}
+
+impl<const N: usize> ContactTracker<N> {
+    /// Points further than `max_distance` from every contact of the previous scan are new
+    /// contacts, in the units of the coordinates
+    #[must_use]
+    pub const fn new(max_distance: u16) -> Self {
+        Self {
+            positions: [None; N],
+            max_distance,
+        }
+    }
+
+    /// Position of the contact in `slot`, `None` if it isn't down
+    #[must_use]
+    pub fn position(&self, slot: usize) -> Option<(u16, u16)> {
+        self.positions.get(slot).copied().flatten()
+    }
+
+    /// Match the points of a scan to the contacts and update `slots` with them
+    ///
+    /// Fails with [`UsbHidError::SerializationError`] if some points didn't get a slot, because
+    /// there were more points than `N` and [`MAX_CONTACTS`] or slots were still lifting off. The
+    /// other points are tracked as usual
+    pub fn update(
+        &mut self,
+        points: &[(u16, u16)],
+        slots: &mut ContactSlots<N>,
+    ) -> Result<(), UsbHidError> {
+        let capacity = N.min(MAX_CONTACTS);
+        let mut dropped = points.len().saturating_sub(capacity);
+        let points = &points[..points.len().min(capacity)];
+
+        // Slot of each point, matched nearest first
+        let mut point_slots = [None; N];
+        let mut matched = [false; N];
+        let max = u64::from(self.max_distance).pow(2);
+        loop {
+            let mut nearest: Option<(u64, usize, usize)> = None;
+            for (slot, position) in self.positions.iter().enumerate() {
+                let Some(position) = position.filter(|_| !matched[slot]) else {
+                    continue;
+                };
+                for (point, &p) in points.iter().enumerate() {
+                    let distance = distance_squared(position, p);
+                    if point_slots[point].is_none()
+                        && distance <= max
+                        && !matches!(nearest, Some((d, _, _)) if d <= distance)
+                    {
+                        nearest = Some((distance, slot, point));
+                    }
+                }
+            }
+            let Some((_, slot, point)) = nearest else {
+                break;
+            };
+            matched[slot] = true;
+            point_slots[point] = Some(slot);
+        }
+
+        for (slot, position) in self.positions.iter_mut().enumerate() {
+            if position.is_some() && !matched[slot] {
+                *position = None;
+                slots.lift(slot);
+            }
+        }
+
+        for (point, &(x, y)) in points.iter().enumerate() {
+            let slot = point_slots[point].or_else(|| {
+                (0..capacity).find(|&s| self.positions[s].is_none() && slots.is_free(s))
+            });
+            let Some(slot) = slot else {
+                dropped += 1;
+                continue;
+            };
+            slots.touch(slot, x, y)?;
+            self.positions[slot] = Some((x, y));
+        }
+
+        if dropped > 0 {
+            warn!("{} touch points without a contact slot", dropped);
+            return Err(UsbHidError::SerializationError);
+        }
+        Ok(())
+    }
+
+    /// Forget all contacts, e.g. after [`ContactSlots::reset`]
+    pub fn reset(&mut self) {
+        self.positions = [None; N];
+    }
+}
+
+fn distance_squared(a: (u16, u16), b: (u16, u16)) -> u64 {
+    let dx = u64::from(a.0.abs_diff(b.0));
+    let dy = u64::from(a.1.abs_diff(b.1));
+    dx * dx + dy * dy
+}
+
+#[cfg(test)]
+mod test {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    #[test]
+    fn contacts_keep_their_slots() {
+        let mut tracker = ContactTracker::<3>::new(100);
+        let mut slots = ContactSlots::<3>::new();
+
+        tracker
+            .update(&[(100, 100), (500, 500)], &mut slots)
+            .unwrap();
+        // Crossing paths, each point goes to the nearest contact
+        tracker
+            .update(&[(480, 520), (150, 90)], &mut slots)
+            .unwrap();
+        assert_eq!(tracker.position(0), Some((150, 90)));
+        assert_eq!(tracker.position(1), Some((480, 520)));
+
+        // Contact 0 lifts, a far point is a new contact and can't reuse slot 0 until the lift
+        // has been reported
+        tracker
+            .update(&[(490, 510), (2000, 2000)], &mut slots)
+            .unwrap();
+        assert!(!slots.is_touching(0));
+        assert!(!slots.is_free(0));
+        assert_eq!(tracker.position(2), Some((2000, 2000)));
+        assert!(tracker
+            .update(&[(490, 510), (2000, 2000), (9000, 9000)], &mut slots)
+            .is_err());
+
+        while slots.report().is_some() {
+            slots.advance();
+            if !slots.is_sending() {
+                break;
+            }
+        }
+        tracker
+            .update(&[(490, 510), (2000, 2000), (9000, 9000)], &mut slots)
+            .unwrap();
+        assert_eq!(tracker.position(0), Some((9000, 9000)));
+        assert_eq!(slots.contact_count(), 3);
+    }
+
+    #[test]
+    fn distance_across_the_whole_range() {
+        assert_eq!(distance_squared((0, 0), (65535, 65535)), 2 * 65535 * 65535);
+
+        let mut tracker = ContactTracker::<2>::new(u16::MAX);
+        let mut slots = ContactSlots::<2>::new();
+        tracker.update(&[(0, 0)], &mut slots).unwrap();
+        tracker.update(&[(65535, 65535)], &mut slots).unwrap();
+        assert_eq!(tracker.position(1), Some((65535, 65535)));
+        assert!(!slots.is_touching(0));
+    }
+}
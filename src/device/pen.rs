@@ -0,0 +1,342 @@
+//! HID pen digitizers
+//!
+//! A single stylus reporting through a digitizer pen collection with usages from the
+//! [`Digitizer`](crate::page::Digitizer) page: tip switch, barrel button, eraser and in range
+//! bits, position, tip pressure and tilt. A stylus is either out of range, hovering above the surface or in contact
+//! with it, see [`PenState`].
+//!
+//! ```rust
+//! # use xous_usb_hid::device::pen::*;
+//! let hover = PenReport::hover(1000, 2000);
+//! let stroke = PenReport::contact(1000, 2000, MAX_PRESSURE / 2).tilt(-20, 15);
+//! assert_eq!(stroke.state(), PenState::Contact);
+//! // pen.write_report(&stroke)
+//! ```
+
+use crate::usb_class::prelude::*;
+use fugit::{ExtU32, MillisDurationU32};
+use usb_device::bus::UsbBus;
+use usb_device::class_prelude::UsbBusAllocator;
+
+/// Largest X and Y coordinate
+pub const MAX_COORDINATE: u16 = 0x7FFF;
+
+/// Largest tip pressure
+pub const MAX_PRESSURE: u16 = 4095;
+
+/// Largest tilt from the vertical, in degrees
+pub const MAX_TILT: i8 = 90;
+
+/// Stylus descriptor
+///
+/// * Pen, Stylus: Tip Switch, Barrel Switch, Eraser and In Range bits, X and Y from 0 to
+///   [`MAX_COORDINATE`], Tip Pressure from 0 to [`MAX_PRESSURE`], 16 bits each, and X and Y
+///   Tilt from -[`MAX_TILT`] to [`MAX_TILT`] degrees, 8 bits each (9 bytes)
+#[rustfmt::skip]
+pub const PEN_REPORT_DESCRIPTOR: &[u8] = &[
+    0x05, 0x0D,       // Usage Page (Digitizer),
+    0x09, 0x02,       // Usage (Pen),
+    0xA1, 0x01,       // Collection (Application),
+    0x09, 0x20,       //   Usage (Stylus),
+    0xA1, 0x00,       //   Collection (Physical),
+    0x09, 0x42,       //     Usage (Tip Switch),
+    0x09, 0x44,       //     Usage (Barrel Switch),
+    0x09, 0x45,       //     Usage (Eraser),
+    0x09, 0x32,       //     Usage (In Range),
+    0x15, 0x00,       //     Logical Minimum (0),
+    0x25, 0x01,       //     Logical Maximum (1),
+    0x75, 0x01,       //     Report Size (1),
+    0x95, 0x04,       //     Report Count (4),
+    0x81, 0x02,       //     Input (Data, Variable, Absolute),
+    0x95, 0x04,       //     Report Count (4),
+    0x81, 0x03,       //     Input (Constant, Variable, Absolute),
+    0x05, 0x01,       //     Usage Page (Generic Desktop),
+    0x09, 0x30,       //     Usage (X),
+    0x09, 0x31,       //     Usage (Y),
+    0x26, 0xFF, 0x7F, //     Logical Maximum (32767),
+    0x75, 0x10,       //     Report Size (16),
+    0x95, 0x02,       //     Report Count (2),
+    0x81, 0x02,       //     Input (Data, Variable, Absolute),
+    0x05, 0x0D,       //     Usage Page (Digitizer),
+    0x09, 0x30,       //     Usage (Tip Pressure),
+    0x26, 0xFF, 0x0F, //     Logical Maximum (4095),
+    0x95, 0x01,       //     Report Count (1),
+    0x81, 0x02,       //     Input (Data, Variable, Absolute),
+    0x09, 0x3D,       //     Usage (X Tilt),
+    0x09, 0x3E,       //     Usage (Y Tilt),
+    0x15, 0xA6,       //     Logical Minimum (-90),
+    0x25, 0x5A,       //     Logical Maximum (90),
+    0x65, 0x14,       //     Unit (Degrees),
+    0x75, 0x08,       //     Report Size (8),
+    0x95, 0x02,       //     Report Count (2),
+    0x81, 0x02,       //     Input (Data, Variable, Absolute),
+    0x65, 0x00,       //     Unit (None),
+    0xC0,             //   End Collection,
+    0xC0,             // End Collection
+];
+
+/// Where the stylus is relative to the surface
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum PenState {
+    /// Not detected, the host hides the pen cursor
+    OutOfRange,
+    /// Detected above the surface, the host moves the pen cursor without inking
+    Hovering,
+    /// Touching the surface
+    Contact,
+}
+
+/// State of the stylus, [`PenReport::default`] is out of range
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Default, Hash)]
+pub struct PenReport {
+    /// Tip touching the surface
+    pub tip_switch: bool,
+    /// Barrel button pressed
+    pub barrel_switch: bool,
+    /// Eraser end in use, instead of the tip
+    pub eraser: bool,
+    /// Stylus detected, touching or hovering
+    pub in_range: bool,
+    /// 0 to [`MAX_COORDINATE`]
+    pub x: u16,
+    /// 0 to [`MAX_COORDINATE`]
+    pub y: u16,
+    /// 0 to [`MAX_PRESSURE`]
+    pub pressure: u16,
+    /// -[`MAX_TILT`] to [`MAX_TILT`] degrees, positive tilts right
+    pub tilt_x: i8,
+    /// -[`MAX_TILT`] to [`MAX_TILT`] degrees, positive tilts towards the user
+    pub tilt_y: i8,
+}
+
+impl PenReport {
+    /// Stylus out of range
+    #[must_use]
+    pub const fn out_of_range() -> Self {
+        Self {
+            tip_switch: false,
+            barrel_switch: false,
+            eraser: false,
+            in_range: false,
+            x: 0,
+            y: 0,
+            pressure: 0,
+            tilt_x: 0,
+            tilt_y: 0,
+        }
+    }
+
+    /// Stylus hovering above `x`, `y`
+    #[must_use]
+    pub const fn hover(x: u16, y: u16) -> Self {
+        Self {
+            in_range: true,
+            x,
+            y,
+            ..Self::out_of_range()
+        }
+    }
+
+    /// Stylus touching the surface at `x`, `y`
+    #[must_use]
+    pub const fn contact(x: u16, y: u16, pressure: u16) -> Self {
+        Self {
+            tip_switch: true,
+            pressure,
+            ..Self::hover(x, y)
+        }
+    }
+
+    #[must_use]
+    pub const fn barrel_switch(mut self, pressed: bool) -> Self {
+        self.barrel_switch = pressed;
+        self
+    }
+
+    #[must_use]
+    pub const fn eraser(mut self, eraser: bool) -> Self {
+        self.eraser = eraser;
+        self
+    }
+
+    #[must_use]
+    pub const fn tilt(mut self, tilt_x: i8, tilt_y: i8) -> Self {
+        self.tilt_x = tilt_x;
+        self.tilt_y = tilt_y;
+        self
+    }
+
+    /// The tip switch implies the stylus is in range
+    #[must_use]
+    pub const fn state(&self) -> PenState {
+        if self.tip_switch {
+            PenState::Contact
+        } else if self.in_range {
+            PenState::Hovering
+        } else {
+            PenState::OutOfRange
+        }
+    }
+
+    /// `true` while hovering above the surface
+    #[must_use]
+    pub const fn is_hovering(&self) -> bool {
+        matches!(self.state(), PenState::Hovering)
+    }
+
+    /// `true` while touching the surface
+    #[must_use]
+    pub const fn is_contact(&self) -> bool {
+        matches!(self.state(), PenState::Contact)
+    }
+
+    /// Report as sent to the host, with values clamped to their logical range. In range is set
+    /// with the tip switch, and pressure is only reported in contact
+    #[must_use]
+    pub fn to_bytes(&self) -> [u8; 9] {
+        let contact = self.is_contact();
+        let flags = u8::from(contact)
+            | u8::from(self.barrel_switch) << 1
+            | u8::from(self.eraser) << 2
+            | u8::from(self.state() != PenState::OutOfRange) << 3;
+        let pressure = if contact {
+            self.pressure.min(MAX_PRESSURE)
+        } else {
+            0
+        };
+        let [x_low, x_high] = self.x.min(MAX_COORDINATE).to_le_bytes();
+        let [y_low, y_high] = self.y.min(MAX_COORDINATE).to_le_bytes();
+        let [p_low, p_high] = pressure.to_le_bytes();
+        let [tilt_x, tilt_y] =
+            [self.tilt_x, self.tilt_y].map(|t| t.clamp(-MAX_TILT, MAX_TILT).to_le_bytes()[0]);
+        [
+            flags, x_low, x_high, y_low, y_high, p_low, p_high, tilt_x, tilt_y,
+        ]
+    }
+}
+
+/// Stylus, see [`PEN_REPORT_DESCRIPTOR`]
+pub struct Pen<'a, B: UsbBus> {
+    interface: Interface<'a, B, InBytes16, OutNone, ReportSingle>,
+}
+
+impl<B: UsbBus> Pen<'_, B> {
+    pub fn write_report(&mut self, report: &PenReport) -> Result<(), UsbHidError> {
+        self.interface
+            .write_report(&report.to_bytes())
+            .map(|_| ())
+            .map_err(UsbHidError::from)
+    }
+}
+
+impl<'a, B: UsbBus> DeviceClass<'a> for Pen<'a, B> {
+    type I = Interface<'a, B, InBytes16, OutNone, ReportSingle>;
+
+    fn interface(&mut self) -> &mut Self::I {
+        &mut self.interface
+    }
+
+    fn reset(&mut self) {}
+
+    fn tick(&mut self) -> Result<(), UsbHidError> {
+        Ok(())
+    }
+
+    fn next_deadline(&mut self) -> Option<MillisDurationU32> {
+        None
+    }
+}
+
+pub struct PenConfig<'a> {
+    interface: InterfaceConfig<'a, InBytes16, OutNone, ReportSingle>,
+}
+
+impl Default for PenConfig<'_> {
+    fn default() -> Self {
+        Self::new(
+            unwrap!(unwrap!(InterfaceBuilder::new_static(PEN_REPORT_DESCRIPTOR))
+                .boot_device(InterfaceProtocol::None)
+                .description("Pen")
+                .in_endpoint(5.millis()))
+            .without_out_endpoint()
+            .build(),
+        )
+    }
+}
+
+impl<'a> PenConfig<'a> {
+    #[must_use]
+    pub fn new(interface: InterfaceConfig<'a, InBytes16, OutNone, ReportSingle>) -> Self {
+        Self { interface }
+    }
+}
+
+impl<'a, B: UsbBus + 'a> UsbAllocatable<'a, B> for PenConfig<'a> {
+    type Allocated = Pen<'a, B>;
+
+    fn allocate(self, usb_alloc: &'a UsbBusAllocator<B>) -> Self::Allocated {
+        Self::Allocated {
+            interface: Interface::new(usb_alloc, self.interface),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::page::Digitizer;
+
+    #[test]
+    fn pen_states() {
+        assert_eq!(PenReport::default().state(), PenState::OutOfRange);
+        assert_eq!(PenReport::default().to_bytes(), [0; 9]);
+
+        let hover = PenReport::hover(0x1234, 0xFFFF).barrel_switch(true);
+        assert!(hover.is_hovering());
+        assert_eq!(
+            hover.to_bytes(),
+            [0b1010, 0x34, 0x12, 0xFF, 0x7F, 0, 0, 0, 0]
+        );
+
+        let contact = PenReport::contact(1, 2, 0xFFFF).eraser(true).tilt(-128, 30);
+        assert!(contact.is_contact());
+        assert_eq!(
+            contact.to_bytes(),
+            [0b1101, 1, 0, 2, 0, 0xFF, 0x0F, 0xA6, 30]
+        );
+
+        // Pressure is only reported in contact, and the tip switch implies in range
+        let lifted = PenReport {
+            tip_switch: false,
+            ..contact
+        };
+        assert_eq!(lifted.to_bytes()[5..7], [0, 0]);
+        let touching = PenReport {
+            in_range: false,
+            ..contact
+        };
+        assert_eq!(touching.to_bytes()[0] & 0b1000, 0b1000);
+    }
+
+    #[test]
+    fn descriptor_uses_digitizer_page() {
+        let usages: [u8; 9] = [
+            Digitizer::Pen.into(),
+            Digitizer::Stylus.into(),
+            Digitizer::TipSwitch.into(),
+            Digitizer::BarrelSwitch.into(),
+            Digitizer::Eraser.into(),
+            Digitizer::InRange.into(),
+            Digitizer::TipPressure.into(),
+            Digitizer::XTilt.into(),
+            Digitizer::YTilt.into(),
+        ];
+        for usage in usages {
+            assert!(PEN_REPORT_DESCRIPTOR
+                .windows(2)
+                .any(|item| item == [0x09, usage]));
+        }
+    }
+}
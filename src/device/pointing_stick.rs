@@ -0,0 +1,180 @@
+//! Keyboard with a pointing stick, such as a laptop keyboard converted to USB
+//!
+//! [`keyboard_pointing_stick`] is a preset pairing a boot keyboard with a wheel mouse for the
+//! pointing stick. [`MiddleButtonScroll`] implements the scrolling mode of these keyboards on the
+//! device: while the middle button is held, moving the stick scrolls instead of moving the
+//! pointer, and the middle button is only clicked if it is released without moving the stick.
+//!
+//! ```rust
+//! # use xous_usb_hid::device::pointing_stick::*;
+//! # use xous_usb_hid::device::mouse::WheelMouseReport;
+//! let mut scroll = MiddleButtonScroll::default();
+//!
+//! // for each sample read from the pointing stick
+//! # let (buttons, x, y) = (0, 3, -2);
+//! let report: WheelMouseReport = scroll.update(buttons, x, y);
+//! // pointing_stick.device::<WheelMouse<_>, _>().write_report(&report)
+//! ```
+
+use crate::device::keyboard::{BootKeyboard, BootKeyboardConfig};
+use crate::device::mouse::{
+    WheelMouse, WheelMouseConfig, WheelMouseReport, WHEEL_MOUSE_REPORT_DESCRIPTOR,
+};
+use crate::usb_class::prelude::*;
+use frunk::{HCons, HNil};
+use fugit::ExtU32;
+use usb_device::bus::UsbBus;
+
+/// Middle button bit of a mouse report
+pub const MIDDLE_BUTTON: u8 = 0b100;
+
+/// Stick motion per wheel detent used by [`MiddleButtonScroll::default`]
+pub const DEFAULT_SCROLL_DIVISOR: i16 = 8;
+
+/// Devices of a [`KeyboardPointingStick`], the keyboard is interface 0 and the pointing stick
+/// interface 1
+pub type KeyboardPointingStickDevices<'a, B> =
+    HCons<BootKeyboard<'a, B>, HCons<WheelMouse<'a, B>, HNil>>;
+
+/// Keyboard and pointing stick built by [`keyboard_pointing_stick`]
+pub type KeyboardPointingStick<'a, B> = UsbHidClass<'a, B, KeyboardPointingStickDevices<'a, B>>;
+
+/// Builder for a boot keyboard and a boot compliant wheel mouse for the pointing stick
+///
+/// Further devices and options can be added before calling `build`
+pub fn keyboard_pointing_stick<'a, B: UsbBus>(
+) -> UsbHidClassBuilder<'a, B, HCons<BootKeyboardConfig<'a>, HCons<WheelMouseConfig<'a>, HNil>>> {
+    UsbHidClassBuilder::new()
+        .add_device(WheelMouseConfig::new(
+            unwrap!(
+                unwrap!(InterfaceBuilder::new_static(WHEEL_MOUSE_REPORT_DESCRIPTOR))
+                    .boot_device(InterfaceProtocol::Mouse)
+                    .description("Pointing Stick")
+                    .in_endpoint(10.millis())
+            )
+            .without_out_endpoint()
+            .build(),
+        ))
+        .add_device(BootKeyboardConfig::default())
+}
+
+/// Middle button scrolling, see the [module docs](self)
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MiddleButtonScroll {
+    divisor: i16,
+    held: bool,
+    scrolled: bool,
+    remainder: [i16; 2],
+}
+
+impl Default for MiddleButtonScroll {
+    fn default() -> Self {
+        Self::new(DEFAULT_SCROLL_DIVISOR)
+    }
+}
+
+impl MiddleButtonScroll {
+    /// Scroll one wheel detent for every `divisor` units of stick motion, at least 1
+    #[must_use]
+    pub const fn new(divisor: i16) -> Self {
+        Self {
+            divisor: if divisor < 1 { 1 } else { divisor },
+            held: false,
+            scrolled: false,
+            remainder: [0; 2],
+        }
+    }
+
+    /// Mouse report for a sample of the buttons and stick motion
+    ///
+    /// A middle click is reported in a single report, so the next sample must be reported too,
+    /// even without any motion, for the host to see the button released
+    pub fn update(&mut self, buttons: u8, x: i8, y: i8) -> WheelMouseReport {
+        let other = buttons & !MIDDLE_BUTTON;
+
+        if buttons & MIDDLE_BUTTON == 0 {
+            let click = core::mem::take(&mut self.held) && !self.scrolled;
+            let buttons = if click { other | MIDDLE_BUTTON } else { other };
+            return WheelMouseReport {
+                buttons,
+                x,
+                y,
+                ..Default::default()
+            };
+        }
+
+        if !self.held {
+            self.held = true;
+            self.scrolled = false;
+            self.remainder = [0; 2];
+        }
+        self.scrolled |= x != 0 || y != 0;
+        WheelMouseReport {
+            buttons: other,
+            // Pushing the stick away from the user scrolls up
+            vertical_wheel: self.detents(0, -i16::from(y)),
+            horizontal_wheel: self.detents(1, i16::from(x)),
+            ..Default::default()
+        }
+    }
+
+    /// `true` while the middle button is held and the stick has been moved
+    #[must_use]
+    pub fn is_scrolling(&self) -> bool {
+        self.held && self.scrolled
+    }
+
+    /// Forget the button state and any partial detents, e.g. after a bus reset
+    pub fn reset(&mut self) {
+        *self = Self::new(self.divisor);
+    }
+
+    fn detents(&mut self, axis: usize, motion: i16) -> i8 {
+        let total = self.remainder[axis].saturating_add(motion);
+        let detents = total / self.divisor;
+        self.remainder[axis] = total - detents * self.divisor;
+        i8::try_from(detents).unwrap_or(if detents < 0 { i8::MIN } else { i8::MAX })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn middle_button_scrolls_or_clicks() {
+        let mut scroll = MiddleButtonScroll::new(4);
+
+        // Pointer motion is passed through
+        let report = scroll.update(0b001, 3, -2);
+        assert_eq!((report.buttons, report.x, report.y), (0b001, 3, -2));
+
+        // Held without motion, then released: a single middle click
+        assert_eq!(scroll.update(MIDDLE_BUTTON, 0, 0), WheelMouseReport::new());
+        assert_eq!(scroll.update(0, 0, 0).buttons, MIDDLE_BUTTON);
+        assert_eq!(scroll.update(0, 0, 0).buttons, 0);
+
+        // Held with motion: scrolls, without moving the pointer or clicking
+        let report = scroll.update(MIDDLE_BUTTON, 2, -3);
+        assert_eq!(report, WheelMouseReport::new());
+        assert!(scroll.is_scrolling());
+        let report = scroll.update(MIDDLE_BUTTON | 0b001, 2, -6);
+        assert_eq!(
+            report,
+            WheelMouseReport {
+                buttons: 0b001,
+                vertical_wheel: 2,
+                horizontal_wheel: 1,
+                ..Default::default()
+            }
+        );
+        assert_eq!(scroll.update(0, 0, 0).buttons, 0);
+        assert!(!scroll.is_scrolling());
+
+        // Partial detents don't carry over to the next scroll
+        scroll.update(MIDDLE_BUTTON, 0, -3);
+        scroll.update(0, 0, 0);
+        assert_eq!(scroll.update(MIDDLE_BUTTON, 0, -1).vertical_wheel, 0);
+    }
+}
@@ -0,0 +1,400 @@
+//! Radial controllers, such as the Surface Dial
+//!
+//! [`RadialController`] implements the system multi-axis controller collection that Windows
+//! shows its wheel menu for: a button and a dial reporting relative rotation in tenths of a
+//! degree, see [`RadialControllerReport`].
+//!
+//! The collection includes the simple haptic controller Windows expects next to the dial. The
+//! device lists a single click waveform, played automatically for every detent as set by the
+//! host with the auto trigger feature report, see [`RadialController::auto_trigger`]. The host
+//! plays waveforms itself with the manual trigger output report, read with
+//! [`RadialController::read_haptic_trigger`]. Driving the actuator is up to the application.
+//!
+//! ```rust
+//! # use xous_usb_hid::device::radial_controller::*;
+//! // 15 degrees clockwise, from a 24 detent encoder
+//! let report = RadialControllerReport::new(false, 150);
+//! assert_eq!(report.to_bytes(), [RADIAL_CONTROLLER_REPORT_ID, 0x2C, 0x01]);
+//! // dial.write_report(&report)
+//! ```
+
+use crate::usb_class::prelude::*;
+use fugit::{ExtU32, MillisDurationU32};
+use usb_device::bus::UsbBus;
+use usb_device::class_prelude::UsbBusAllocator;
+use usb_device::UsbError;
+
+/// Report ID of the button and dial input report
+pub const RADIAL_CONTROLLER_REPORT_ID: u8 = 0x01;
+/// Report ID of the haptic capabilities feature report, see [`HAPTIC_CAPABILITIES_REPORT`]
+pub const HAPTIC_CAPABILITIES_REPORT_ID: u8 = 0x02;
+/// Report ID of the auto trigger feature report, see [`HapticTrigger`]
+pub const AUTO_TRIGGER_REPORT_ID: u8 = 0x03;
+/// Report ID of the manual trigger output report, see [`HapticTrigger`]
+pub const MANUAL_TRIGGER_REPORT_ID: u8 = 0x04;
+
+/// Largest rotation in a single report, in tenths of a degree
+pub const MAX_ROTATION: i16 = 3600;
+
+/// Haptics page click waveform, the only waveform listed by the device
+pub const WAVEFORM_CLICK: u16 = 0x1003;
+
+/// Haptic capabilities feature report: the click waveform as ordinal 3, a duration of 0 as it
+/// isn't continuous, no cutoff time and auto triggering associated with the dial
+pub static HAPTIC_CAPABILITIES_REPORT: [u8; 11] = [
+    HAPTIC_CAPABILITIES_REPORT_ID,
+    0x03,
+    0x10,
+    0x00,
+    0x00,
+    0x00,
+    0x00,
+    0x37,
+    0x00,
+    0x01,
+    0x00,
+];
+
+/// Radial controller descriptor
+///
+/// * Report ID 1, System Multi-Axis Controller: Button 1 and Dial, relative from -[`MAX_ROTATION`]
+///   to [`MAX_ROTATION`] in tenths of a degree, 15 bits (3 bytes)
+/// * Report ID 2, Simple Haptic Controller: Waveform List, Duration List, Waveform Cutoff Time
+///   and Auto Trigger Associated Control feature (11 bytes)
+/// * Report ID 3, Auto Trigger, Intensity, Repeat Count and Retrigger Period feature (7 bytes)
+/// * Report ID 4, Manual Trigger, Intensity, Repeat Count and Retrigger Period output (7 bytes)
+#[rustfmt::skip]
+pub const RADIAL_CONTROLLER_REPORT_DESCRIPTOR: &[u8] = &[
+    0x05, 0x01,                   // Usage Page (Generic Desktop),
+    0x09, 0x0E,                   // Usage (System Multi-Axis Controller),
+    0xA1, 0x01,                   // Collection (Application),
+    0x85, 0x01,                   //   Report ID (1),
+    0x05, 0x0D,                   //   Usage Page (Digitizer),
+    0x09, 0x21,                   //   Usage (Puck),
+    0xA1, 0x00,                   //   Collection (Physical),
+    0x05, 0x09,                   //     Usage Page (Button),
+    0x09, 0x01,                   //     Usage (Button 1),
+    0x15, 0x00,                   //     Logical Minimum (0),
+    0x25, 0x01,                   //     Logical Maximum (1),
+    0x75, 0x01,                   //     Report Size (1),
+    0x95, 0x01,                   //     Report Count (1),
+    0x81, 0x02,                   //     Input (Data, Variable, Absolute),
+    0x05, 0x01,                   //     Usage Page (Generic Desktop),
+    0x09, 0x37,                   //     Usage (Dial),
+    0x16, 0xF0, 0xF1,             //     Logical Minimum (-3600),
+    0x26, 0x10, 0x0E,             //     Logical Maximum (3600),
+    0x36, 0xF0, 0xF1,             //     Physical Minimum (-3600),
+    0x46, 0x10, 0x0E,             //     Physical Maximum (3600),
+    0x55, 0x0F,                   //     Unit Exponent (-1),
+    0x65, 0x14,                   //     Unit (Degrees),
+    0x75, 0x0F,                   //     Report Size (15),
+    0x81, 0x06,                   //     Input (Data, Variable, Relative),
+    0x35, 0x00,                   //     Physical Minimum (0),
+    0x45, 0x00,                   //     Physical Maximum (0),
+    0x55, 0x00,                   //     Unit Exponent (0),
+    0x65, 0x00,                   //     Unit (None),
+    0xC0,                         //   End Collection,
+    0x05, 0x0E,                   //   Usage Page (Haptics),
+    0x09, 0x01,                   //   Usage (Simple Haptic Controller),
+    0xA1, 0x02,                   //   Collection (Logical),
+    0x85, 0x02,                   //     Report ID (2),
+    0x09, 0x10,                   //     Usage (Waveform List),
+    0xA1, 0x02,                   //     Collection (Logical),
+    0x05, 0x0A,                   //       Usage Page (Ordinal),
+    0x09, 0x03,                   //       Usage (Instance 3),
+    0x16, 0x01, 0x10,             //       Logical Minimum (0x1001),
+    0x26, 0xFF, 0x2F,             //       Logical Maximum (0x2FFF),
+    0x75, 0x10,                   //       Report Size (16),
+    0xB1, 0x02,                   //       Feature (Data, Variable, Absolute),
+    0xC0,                         //     End Collection,
+    0x05, 0x0E,                   //     Usage Page (Haptics),
+    0x09, 0x11,                   //     Usage (Duration List),
+    0xA1, 0x02,                   //     Collection (Logical),
+    0x05, 0x0A,                   //       Usage Page (Ordinal),
+    0x09, 0x03,                   //       Usage (Instance 3),
+    0x15, 0x00,                   //       Logical Minimum (0),
+    0x26, 0xFF, 0x7F,             //       Logical Maximum (32767),
+    0xB1, 0x02,                   //       Feature (Data, Variable, Absolute),
+    0xC0,                         //     End Collection,
+    0x05, 0x0E,                   //     Usage Page (Haptics),
+    0x09, 0x28,                   //     Usage (Waveform Cutoff Time),
+    0xB1, 0x02,                   //     Feature (Data, Variable, Absolute),
+    0x09, 0x22,                   //     Usage (Auto Trigger Associated Control),
+    0x17, 0x37, 0x00, 0x01, 0x00, //     Logical Minimum (0x00010037),
+    0x27, 0x37, 0x00, 0x01, 0x00, //     Logical Maximum (0x00010037),
+    0x75, 0x20,                   //     Report Size (32),
+    0xB1, 0x02,                   //     Feature (Data, Variable, Absolute),
+    0x85, 0x03,                   //     Report ID (3),
+    0x09, 0x20,                   //     Usage (Auto Trigger),
+    0x16, 0x01, 0x10,             //     Logical Minimum (0x1001),
+    0x26, 0xFF, 0x2F,             //     Logical Maximum (0x2FFF),
+    0x75, 0x10,                   //     Report Size (16),
+    0xB1, 0x02,                   //     Feature (Data, Variable, Absolute),
+    0x09, 0x23,                   //     Usage (Intensity),
+    0x15, 0x00,                   //     Logical Minimum (0),
+    0x25, 0x64,                   //     Logical Maximum (100),
+    0x75, 0x08,                   //     Report Size (8),
+    0xB1, 0x02,                   //     Feature (Data, Variable, Absolute),
+    0x09, 0x24,                   //     Usage (Repeat Count),
+    0x26, 0xFF, 0x00,             //     Logical Maximum (255),
+    0xB1, 0x02,                   //     Feature (Data, Variable, Absolute),
+    0x09, 0x25,                   //     Usage (Retrigger Period),
+    0x26, 0xFF, 0x7F,             //     Logical Maximum (32767),
+    0x75, 0x10,                   //     Report Size (16),
+    0xB1, 0x02,                   //     Feature (Data, Variable, Absolute),
+    0x85, 0x04,                   //     Report ID (4),
+    0x09, 0x21,                   //     Usage (Manual Trigger),
+    0x16, 0x01, 0x10,             //     Logical Minimum (0x1001),
+    0x26, 0xFF, 0x2F,             //     Logical Maximum (0x2FFF),
+    0x91, 0x02,                   //     Output (Data, Variable, Absolute),
+    0x09, 0x23,                   //     Usage (Intensity),
+    0x15, 0x00,                   //     Logical Minimum (0),
+    0x25, 0x64,                   //     Logical Maximum (100),
+    0x75, 0x08,                   //     Report Size (8),
+    0x91, 0x02,                   //     Output (Data, Variable, Absolute),
+    0x09, 0x24,                   //     Usage (Repeat Count),
+    0x26, 0xFF, 0x00,             //     Logical Maximum (255),
+    0x91, 0x02,                   //     Output (Data, Variable, Absolute),
+    0x09, 0x25,                   //     Usage (Retrigger Period),
+    0x26, 0xFF, 0x7F,             //     Logical Maximum (32767),
+    0x75, 0x10,                   //     Report Size (16),
+    0x91, 0x02,                   //     Output (Data, Variable, Absolute),
+    0xC0,                         //   End Collection,
+    0xC0,                         // End Collection
+];
+
+/// State of the dial
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Default, Hash)]
+pub struct RadialControllerReport {
+    /// Dial pressed
+    pub button: bool,
+    /// Rotation since the last report in tenths of a degree, positive clockwise
+    pub rotation: i16,
+}
+
+impl RadialControllerReport {
+    #[must_use]
+    pub const fn new(button: bool, rotation: i16) -> Self {
+        Self { button, rotation }
+    }
+
+    /// Report as sent to the host, with the rotation clamped to [`MAX_ROTATION`]
+    #[must_use]
+    pub fn to_bytes(&self) -> [u8; 3] {
+        let rotation = self.rotation.clamp(-MAX_ROTATION, MAX_ROTATION);
+        let field = u16::from_le_bytes(rotation.to_le_bytes()) << 1 | u16::from(self.button);
+        let [low, high] = field.to_le_bytes();
+        [RADIAL_CONTROLLER_REPORT_ID, low, high]
+    }
+}
+
+/// Waveform played by the haptic actuator, set by the host with the auto trigger feature report
+/// or sent with the manual trigger output report
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Default, Hash)]
+pub struct HapticTrigger {
+    /// Haptics page waveform usage, e.g. [`WAVEFORM_CLICK`], 0x1001 for none and 0x1002 to stop
+    pub waveform: u16,
+    /// 0 to 100 percent
+    pub intensity: u8,
+    /// Times to play the waveform again
+    pub repeat_count: u8,
+    /// Time between repeats, in ms
+    pub retrigger_period: u16,
+}
+
+impl HapticTrigger {
+    /// Decode a feature or output report, including the report ID
+    pub fn unpack(data: &[u8]) -> usb_device::Result<Self> {
+        let &[_, waveform_low, waveform_high, intensity, repeat_count, period_low, period_high] =
+            data
+        else {
+            return Err(UsbError::ParseError);
+        };
+        Ok(Self {
+            waveform: u16::from_le_bytes([waveform_low, waveform_high]),
+            intensity,
+            repeat_count,
+            retrigger_period: u16::from_le_bytes([period_low, period_high]),
+        })
+    }
+
+    /// Encode as the feature or output report `report_id`
+    #[must_use]
+    pub fn pack(&self, report_id: u8) -> [u8; 7] {
+        let [waveform_low, waveform_high] = self.waveform.to_le_bytes();
+        let [period_low, period_high] = self.retrigger_period.to_le_bytes();
+        [
+            report_id,
+            waveform_low,
+            waveform_high,
+            self.intensity,
+            self.repeat_count,
+            period_low,
+            period_high,
+        ]
+    }
+}
+
+/// Radial controller, see the [module docs](self)
+pub struct RadialController<'a, B: UsbBus> {
+    interface: Interface<'a, B, InBytes8, OutBytes8, Reports8>,
+}
+
+impl<B: UsbBus> RadialController<'_, B> {
+    pub fn write_report(&mut self, report: &RadialControllerReport) -> Result<(), UsbHidError> {
+        self.interface
+            .write_report(&report.to_bytes())
+            .map(|_| ())
+            .map_err(UsbHidError::from)
+    }
+
+    /// Read a manual trigger output report
+    pub fn read_haptic_trigger(&mut self) -> usb_device::Result<HapticTrigger> {
+        let mut data = [0; 8];
+        let n = self.interface.read_report(&mut data)?;
+        match data[..n] {
+            [MANUAL_TRIGGER_REPORT_ID, ..] => HapticTrigger::unpack(&data[..n]),
+            _ => Err(UsbError::ParseError),
+        }
+    }
+
+    /// Waveform to play for every detent, as set by the host
+    #[must_use]
+    pub fn auto_trigger(&self) -> HapticTrigger {
+        self.interface
+            .feature_report_with_id(AUTO_TRIGGER_REPORT_ID)
+            .and_then(|data| HapticTrigger::unpack(data).ok())
+            .unwrap_or_default()
+    }
+}
+
+impl<'a, B: UsbBus> DeviceClass<'a> for RadialController<'a, B> {
+    type I = Interface<'a, B, InBytes8, OutBytes8, Reports8>;
+
+    fn interface(&mut self) -> &mut Self::I {
+        &mut self.interface
+    }
+
+    fn reset(&mut self) {}
+
+    fn tick(&mut self) -> Result<(), UsbHidError> {
+        Ok(())
+    }
+
+    fn next_deadline(&mut self) -> Option<MillisDurationU32> {
+        None
+    }
+}
+
+pub struct RadialControllerConfig<'a> {
+    interface: InterfaceConfig<'a, InBytes8, OutBytes8, Reports8>,
+}
+
+impl Default for RadialControllerConfig<'_> {
+    fn default() -> Self {
+        let auto_trigger = HapticTrigger {
+            waveform: WAVEFORM_CLICK,
+            intensity: 100,
+            ..Default::default()
+        };
+        Self::new(
+            unwrap!(unwrap!(unwrap!(unwrap!(InterfaceBuilder::new_static(
+                RADIAL_CONTROLLER_REPORT_DESCRIPTOR
+            ))
+            .description("Radial Controller")
+            .static_feature_report(HAPTIC_CAPABILITIES_REPORT_ID, &HAPTIC_CAPABILITIES_REPORT)
+            .feature_report(
+                AUTO_TRIGGER_REPORT_ID,
+                &auto_trigger.pack(AUTO_TRIGGER_REPORT_ID)
+            ))
+            .in_endpoint(10.millis()))
+            .with_out_endpoint(10.millis()))
+            .build(),
+        )
+    }
+}
+
+impl<'a> RadialControllerConfig<'a> {
+    /// `interface` must use [`RADIAL_CONTROLLER_REPORT_DESCRIPTOR`] and answer its feature
+    /// reports
+    #[must_use]
+    pub fn new(interface: InterfaceConfig<'a, InBytes8, OutBytes8, Reports8>) -> Self {
+        Self { interface }
+    }
+}
+
+impl<'a, B: UsbBus + 'a> UsbAllocatable<'a, B> for RadialControllerConfig<'a> {
+    type Allocated = RadialController<'a, B>;
+
+    fn allocate(self, usb_alloc: &'a UsbBusAllocator<B>) -> Self::Allocated {
+        Self::Allocated {
+            interface: Interface::new(usb_alloc, self.interface),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+    use crate::page::{Desktop, Haptics};
+
+    #[test]
+    fn radial_controller_reports() {
+        assert_eq!(
+            RadialControllerReport::new(true, -1).to_bytes(),
+            [RADIAL_CONTROLLER_REPORT_ID, 0xFF, 0xFF]
+        );
+        assert_eq!(
+            RadialControllerReport::new(false, i16::MAX).to_bytes(),
+            [RADIAL_CONTROLLER_REPORT_ID, 0x20, 0x1C]
+        );
+
+        let trigger = HapticTrigger {
+            waveform: WAVEFORM_CLICK,
+            intensity: 50,
+            repeat_count: 2,
+            retrigger_period: 300,
+        };
+        let data = trigger.pack(MANUAL_TRIGGER_REPORT_ID);
+        assert_eq!(
+            data,
+            [MANUAL_TRIGGER_REPORT_ID, 0x03, 0x10, 50, 2, 0x2C, 0x01]
+        );
+        assert_eq!(HapticTrigger::unpack(&data).unwrap(), trigger);
+        assert!(HapticTrigger::unpack(&data[..6]).is_err());
+    }
+
+    #[test]
+    fn descriptor_uses_radial_controller_usages() {
+        let desktop: [u8; 2] = [
+            Desktop::SystemMultiAxisController.into(),
+            Desktop::Dial.into(),
+        ];
+        let haptics = [
+            Haptics::SimpleHapticController,
+            Haptics::WaveformList,
+            Haptics::DurationList,
+            Haptics::WaveformCutoffTime,
+            Haptics::AutoTriggerAssociatedControl,
+            Haptics::AutoTrigger,
+            Haptics::ManualTrigger,
+            Haptics::Intensity,
+            Haptics::RepeatCount,
+            Haptics::RetriggerPeriod,
+        ]
+        .map(|usage| u8::try_from(u16::from(usage)).unwrap());
+        for usage in desktop.into_iter().chain(haptics) {
+            assert!(RADIAL_CONTROLLER_REPORT_DESCRIPTOR
+                .windows(2)
+                .any(|item| item == [0x09, usage]));
+        }
+        assert_eq!(
+            u16::from_le_bytes([HAPTIC_CAPABILITIES_REPORT[1], HAPTIC_CAPABILITIES_REPORT[2]]),
+            u16::from(Haptics::WaveformClick)
+        );
+    }
+}
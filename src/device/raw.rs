@@ -0,0 +1,126 @@
+//! Raw vendor defined 64 byte HID reports
+use crate::usb_class::prelude::*;
+use fugit::{ExtU32, MillisDurationU32};
+use usb_device::bus::UsbBus;
+use usb_device::class_prelude::UsbBusAllocator;
+
+pub mod midi;
+pub mod rpc;
+pub mod transfer;
+
+pub use crate::protocol::raw::REPORT_LEN as RAW_REPORT_LEN;
+
+/// Raw vendor defined report descriptor - 64 byte input and output reports
+#[rustfmt::skip]
+pub const RAW_REPORT_DESCRIPTOR: &[u8] = &[
+    0x06, 0x00, 0xFF, // Usage Page (Vendor Defined 0xFF00),
+    0x09, 0x01,       // Usage (0x01),
+    0xA1, 0x01,       // Collection (Application),
+    0x09, 0x02,       //   Usage (0x02),
+    0x15, 0x00,       //   Logical Minimum (0),
+    0x26, 0xFF, 0x00, //   Logical Maximum (255),
+    0x75, 0x08,       //   Report Size (8),
+    0x95, 0x40,       //   Report Count (64),
+    0x81, 0x02,       //   Input (Data, Variable, Absolute),
+    0x09, 0x03,       //   Usage (0x03),
+    0x15, 0x00,       //   Logical Minimum (0),
+    0x26, 0xFF, 0x00, //   Logical Maximum (255),
+    0x75, 0x08,       //   Report Size (8),
+    0x95, 0x40,       //   Report Count (64),
+    0x91, 0x02,       //   Output (Data, Variable, Absolute),
+    0xC0,             // End Collection
+];
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct RawReport {
+    pub packet: [u8; RAW_REPORT_LEN],
+}
+
+impl RawReport {
+    #[must_use]
+    pub const fn new(packet: [u8; RAW_REPORT_LEN]) -> Self {
+        Self { packet }
+    }
+}
+
+impl Default for RawReport {
+    fn default() -> Self {
+        Self {
+            packet: [0u8; RAW_REPORT_LEN],
+        }
+    }
+}
+
+pub struct RawHid<'a, B: UsbBus> {
+    interface: Interface<'a, B, InBytes64, OutBytes64, ReportSingle>,
+}
+
+impl<B: UsbBus> RawHid<'_, B> {
+    pub fn write_report(&mut self, report: &RawReport) -> Result<(), UsbHidError> {
+        self.interface
+            .write_report(&report.packet)
+            .map(|_| ())
+            .map_err(UsbHidError::from)
+    }
+
+    pub fn read_report(&mut self) -> usb_device::Result<RawReport> {
+        let mut report = RawReport::default();
+        self.interface
+            .read_report(&mut report.packet)
+            .map(|_| report)
+    }
+}
+
+impl<'a, B: UsbBus> DeviceClass<'a> for RawHid<'a, B> {
+    type I = Interface<'a, B, InBytes64, OutBytes64, ReportSingle>;
+
+    fn interface(&mut self) -> &mut Self::I {
+        &mut self.interface
+    }
+
+    fn reset(&mut self) {}
+
+    fn tick(&mut self) -> Result<(), UsbHidError> {
+        Ok(())
+    }
+
+    fn next_deadline(&mut self) -> Option<MillisDurationU32> {
+        None
+    }
+}
+
+pub struct RawHidConfig<'a> {
+    interface: InterfaceConfig<'a, InBytes64, OutBytes64, ReportSingle>,
+}
+
+impl Default for RawHidConfig<'_> {
+    fn default() -> Self {
+        Self::new(
+            unwrap!(
+                unwrap!(unwrap!(InterfaceBuilder::new_static(RAW_REPORT_DESCRIPTOR))
+                    .description("Raw HID")
+                    .in_endpoint(10.millis()))
+                .with_out_endpoint(10.millis())
+            )
+            .build(),
+        )
+    }
+}
+
+impl<'a> RawHidConfig<'a> {
+    #[must_use]
+    pub fn new(interface: InterfaceConfig<'a, InBytes64, OutBytes64, ReportSingle>) -> Self {
+        Self { interface }
+    }
+}
+
+impl<'a, B: UsbBus + 'a> UsbAllocatable<'a, B> for RawHidConfig<'a> {
+    type Allocated = RawHid<'a, B>;
+
+    fn allocate(self, usb_alloc: &'a UsbBusAllocator<B>) -> Self::Allocated {
+        Self::Allocated {
+            interface: Interface::new(usb_alloc, self.interface),
+        }
+    }
+}
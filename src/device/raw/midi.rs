@@ -0,0 +1,277 @@
+//! MIDI note and control change events over [`RawHid`](super::RawHid) reports
+//!
+//! For controllers that can't enumerate as a class compliant USB MIDI device but can expose HID,
+//! a small host side bridge reads the raw input reports and forwards the events to a virtual MIDI
+//! port. Output reports from the host carry events the other way, e.g. to light pad LEDs.
+//!
+//! Every packet, in both directions, is [`RAW_REPORT_LEN`] bytes:
+//!
+//! | Byte   | Content                                                    |
+//! |--------|------------------------------------------------------------|
+//! | 0      | Number of events, 0 to [`MAX_EVENTS`]                      |
+//! | 1..61  | Events, 4 bytes each, zero padded                          |
+//! | 61..64 | Zero                                                       |
+//!
+//! Events use the USB MIDI 1.0 event packet layout, so that bridges can hand them to the USB MIDI
+//! APIs of the host unchanged or strip the first byte to get the MIDI message:
+//!
+//! | Byte | Content                                                        |
+//! |------|----------------------------------------------------------------|
+//! | 0    | Cable number (0) in bits 4..8, code index number in bits 0..4  |
+//! | 1    | MIDI status byte, message type and channel                     |
+//! | 2    | Note or controller number, 0 to 127                            |
+//! | 3    | Velocity or controller value, 0 to 127                         |
+//!
+//! The code index numbers used are 0x8 for Note Off, 0x9 for Note On and 0xB for Control Change.
+//! Decoders skip events with any other code index number, so that later versions can add event
+//! types without breaking older bridges, and drop packets with more than [`MAX_EVENTS`] events.
+//!
+//! ```rust
+//! # use xous_usb_hid::device::raw::midi::*;
+//! let mut packet = MidiPacket::new();
+//! packet.push(MidiEvent::NoteOn { channel: 0, note: 60, velocity: 100 }).unwrap();
+//! packet.push(MidiEvent::ControlChange { channel: 0, control: 7, value: 90 }).unwrap();
+//! // raw_hid.write_report(&RawReport::new(packet.pack()))
+//!
+//! // Host side
+//! let events = MidiPacket::unpack(&packet.pack()).unwrap();
+//! assert_eq!(events.len(), 2);
+//! ```
+
+use super::RAW_REPORT_LEN;
+use crate::protocol::midi::{
+    CIN_CONTROL_CHANGE, CIN_NOTE_OFF, CIN_NOTE_ON, EVENT_LEN, OFFSET_COUNT, OFFSET_EVENTS,
+};
+use heapless::Vec;
+
+pub use crate::protocol::midi::MAX_EVENTS;
+
+/// MIDI channel voice message, channels are 0 to 15 and other values 0 to 127
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MidiEvent {
+    NoteOff {
+        channel: u8,
+        note: u8,
+        velocity: u8,
+    },
+    /// A velocity of 0 is treated as Note Off by most hosts
+    NoteOn {
+        channel: u8,
+        note: u8,
+        velocity: u8,
+    },
+    ControlChange {
+        channel: u8,
+        control: u8,
+        value: u8,
+    },
+}
+
+impl MidiEvent {
+    /// USB MIDI event packet, with out of range values masked
+    #[must_use]
+    pub const fn to_bytes(&self) -> [u8; EVENT_LEN] {
+        let (cin, channel, data1, data2) = match *self {
+            Self::NoteOff {
+                channel,
+                note,
+                velocity,
+            } => (CIN_NOTE_OFF, channel, note, velocity),
+            Self::NoteOn {
+                channel,
+                note,
+                velocity,
+            } => (CIN_NOTE_ON, channel, note, velocity),
+            Self::ControlChange {
+                channel,
+                control,
+                value,
+            } => (CIN_CONTROL_CHANGE, channel, control, value),
+        };
+        [cin, cin << 4 | channel & 0x0F, data1 & 0x7F, data2 & 0x7F]
+    }
+
+    /// Decode a USB MIDI event packet, `None` for other event types
+    #[must_use]
+    pub const fn from_bytes(bytes: [u8; EVENT_LEN]) -> Option<Self> {
+        let [header, status, data1, data2] = bytes;
+        let cin = header & 0x0F;
+        if status >> 4 != cin {
+            return None;
+        }
+        let channel = status & 0x0F;
+        let (data1, data2) = (data1 & 0x7F, data2 & 0x7F);
+        match cin {
+            CIN_NOTE_OFF => Some(Self::NoteOff {
+                channel,
+                note: data1,
+                velocity: data2,
+            }),
+            CIN_NOTE_ON => Some(Self::NoteOn {
+                channel,
+                note: data1,
+                velocity: data2,
+            }),
+            CIN_CONTROL_CHANGE => Some(Self::ControlChange {
+                channel,
+                control: data1,
+                value: data2,
+            }),
+            _ => None,
+        }
+    }
+}
+
+/// Up to [`MAX_EVENTS`] events carried by a single report
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MidiPacket {
+    events: Vec<MidiEvent, MAX_EVENTS>,
+}
+
+impl MidiPacket {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { events: Vec::new() }
+    }
+
+    /// Add an event, giving it back if the packet is full
+    pub fn push(&mut self, event: MidiEvent) -> Result<(), MidiEvent> {
+        self.events.push(event)
+    }
+
+    #[must_use]
+    pub fn events(&self) -> &[MidiEvent] {
+        &self.events
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    #[must_use]
+    pub fn is_full(&self) -> bool {
+        self.events.is_full()
+    }
+
+    pub fn clear(&mut self) {
+        self.events.clear();
+    }
+
+    #[must_use]
+    pub fn pack(&self) -> [u8; RAW_REPORT_LEN] {
+        let mut packet = [0; RAW_REPORT_LEN];
+        packet[OFFSET_COUNT] = u8::try_from(self.events.len()).unwrap_or_default();
+        for (bytes, event) in packet[OFFSET_EVENTS..]
+            .chunks_exact_mut(EVENT_LEN)
+            .zip(&self.events)
+        {
+            bytes.copy_from_slice(&event.to_bytes());
+        }
+        packet
+    }
+
+    /// Decode a packet, skipping unknown event types
+    ///
+    /// Returns `None` if `packet` isn't [`RAW_REPORT_LEN`] bytes or has more than [`MAX_EVENTS`]
+    /// events
+    #[must_use]
+    pub fn unpack(packet: &[u8]) -> Option<Self> {
+        let count = usize::from(*packet.get(OFFSET_COUNT)?);
+        if packet.len() != RAW_REPORT_LEN || count > MAX_EVENTS {
+            return None;
+        }
+        let events = packet[OFFSET_EVENTS..]
+            .chunks_exact(EVENT_LEN)
+            .take(count)
+            .filter_map(|bytes| <[u8; EVENT_LEN]>::try_from(bytes).ok())
+            .filter_map(MidiEvent::from_bytes)
+            .collect();
+        Some(Self { events })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    #[test]
+    fn events_round_trip() {
+        let events = [
+            MidiEvent::NoteOn {
+                channel: 9,
+                note: 36,
+                velocity: 127,
+            },
+            MidiEvent::NoteOff {
+                channel: 9,
+                note: 36,
+                velocity: 0,
+            },
+            MidiEvent::ControlChange {
+                channel: 15,
+                control: 1,
+                value: 64,
+            },
+        ];
+        let mut packet = MidiPacket::new();
+        for event in events {
+            packet.push(event).unwrap();
+        }
+
+        let bytes = packet.pack();
+        assert_eq!(
+            bytes[..13],
+            [3, 0x09, 0x99, 36, 127, 0x08, 0x89, 36, 0, 0x0B, 0xBF, 1, 64]
+        );
+        assert_eq!(bytes[13..], [0; RAW_REPORT_LEN - 13]);
+        assert_eq!(MidiPacket::unpack(&bytes).unwrap().events(), events);
+
+        // Out of range values are masked
+        let event = MidiEvent::NoteOn {
+            channel: 0x12,
+            note: 0x80,
+            velocity: 0xFF,
+        };
+        assert_eq!(event.to_bytes(), [0x09, 0x92, 0x00, 0x7F]);
+    }
+
+    #[test]
+    fn unpack_skips_unknown_events() {
+        let mut packet = MidiPacket::new();
+        while !packet.is_full() {
+            packet
+                .push(MidiEvent::ControlChange {
+                    channel: 0,
+                    control: 7,
+                    value: 1,
+                })
+                .unwrap();
+        }
+        assert_eq!(packet.len(), MAX_EVENTS);
+        assert!(packet
+            .push(MidiEvent::ControlChange {
+                channel: 0,
+                control: 7,
+                value: 2
+            })
+            .is_err());
+
+        let mut bytes = packet.pack();
+        // Pitch bend, code index number 0xE
+        bytes[OFFSET_EVENTS..][..EVENT_LEN].copy_from_slice(&[0x0E, 0xE0, 0, 0x40]);
+        assert_eq!(MidiPacket::unpack(&bytes).unwrap().len(), MAX_EVENTS - 1);
+
+        bytes[OFFSET_COUNT] = 16;
+        assert_eq!(MidiPacket::unpack(&bytes), None);
+        assert_eq!(MidiPacket::unpack(&bytes[..8]), None);
+    }
+}
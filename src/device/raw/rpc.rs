@@ -0,0 +1,355 @@
+//! Request/response calls over [`RawHid`](super::RawHid) reports
+//!
+//! Host configuration tools send a request as an output report and the device answers with an
+//! input report carrying the same request ID, so that every protocol built on raw reports doesn't
+//! have to reinvent correlation and error signalling.
+//!
+//! Every packet, in both directions, is [`RAW_REPORT_LEN`] bytes:
+//!
+//! | Byte   | Content                                                    |
+//! |--------|------------------------------------------------------------|
+//! | 0      | Request ID, chosen by the host                             |
+//! | 1      | Method for requests, [`Status`] for responses              |
+//! | 2      | Payload length                                             |
+//! | 3..64  | Payload, zero padded                                       |
+//!
+//! Methods are defined by the application. The device handles one request at a time: a request
+//! received while another is pending is answered with [`Status::Busy`], and a request that isn't
+//! answered within the timeout of the [`RpcServer`] is answered with [`Status::Timeout`]. A
+//! request repeated by the host because the response was lost is answered again with the last
+//! response, without being handed to the application twice, so hosts should use a new request ID
+//! for every request.
+//!
+//! ```rust
+//! # use xous_usb_hid::device::raw::rpc::*;
+//! # use fugit::ExtU32;
+//! let mut server = RpcServer::new(100.millis());
+//!
+//! // if let Ok(report) = raw_hid.read_report()
+//! let packet = Request { id: 7, method: 0x01, payload: &[] }.pack().unwrap();
+//! match server.receive(&packet) {
+//!     Received::Call(request) => {
+//!         let response = server.respond(request.id, Status::Ok, b"v1.0");
+//!         // raw_hid.write_report(&RawReport::new(response.unwrap()))
+//!     }
+//!     Received::Reply(response) => { /* raw_hid.write_report(&RawReport::new(response)) */ }
+//! }
+//!
+//! // every 1ms
+//! if let Some(response) = server.tick() {
+//!     // raw_hid.write_report(&RawReport::new(response))
+//! }
+//! ```
+
+use super::RAW_REPORT_LEN;
+use crate::protocol::rpc::{OFFSET_CODE, OFFSET_ID, OFFSET_LEN, OFFSET_PAYLOAD};
+use fugit::{ExtU32, MillisDurationU32};
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+
+pub use crate::protocol::rpc::MAX_PAYLOAD_LEN;
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, TryFromPrimitive, IntoPrimitive)]
+#[repr(u8)]
+pub enum Status {
+    Ok = 0x00,
+    /// Another request is still pending
+    Busy = 0x01,
+    /// The request wasn't answered in time
+    Timeout = 0x02,
+    UnknownMethod = 0x03,
+    InvalidArgument = 0x04,
+    /// The request packet couldn't be decoded
+    Malformed = 0x05,
+    /// The response payload was longer than [`MAX_PAYLOAD_LEN`]
+    Overflow = 0x06,
+    /// Application defined failure, details may follow in the payload
+    Failed = 0x07,
+}
+
+/// Request sent by the host
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Request<'a> {
+    pub id: u8,
+    pub method: u8,
+    pub payload: &'a [u8],
+}
+
+impl<'a> Request<'a> {
+    /// Encode a request, for use by host tools
+    ///
+    /// Returns `None` if the payload is longer than [`MAX_PAYLOAD_LEN`]
+    #[must_use]
+    pub fn pack(&self) -> Option<[u8; RAW_REPORT_LEN]> {
+        encode(self.id, self.method, self.payload)
+    }
+
+    #[must_use]
+    pub fn unpack(packet: &'a [u8]) -> Option<Self> {
+        let (id, method, payload) = decode(packet)?;
+        Some(Self {
+            id,
+            method,
+            payload,
+        })
+    }
+}
+
+/// Response sent by the device
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Response<'a> {
+    pub id: u8,
+    pub status: Status,
+    pub payload: &'a [u8],
+}
+
+impl<'a> Response<'a> {
+    /// Returns `None` if the payload is longer than [`MAX_PAYLOAD_LEN`]
+    #[must_use]
+    pub fn pack(&self) -> Option<[u8; RAW_REPORT_LEN]> {
+        encode(self.id, self.status.into(), self.payload)
+    }
+
+    /// Decode a response, for use by host tools
+    #[must_use]
+    pub fn unpack(packet: &'a [u8]) -> Option<Self> {
+        let (id, status, payload) = decode(packet)?;
+        Some(Self {
+            id,
+            status: Status::try_from(status).ok()?,
+            payload,
+        })
+    }
+}
+
+fn encode(id: u8, code: u8, payload: &[u8]) -> Option<[u8; RAW_REPORT_LEN]> {
+    let len = u8::try_from(payload.len())
+        .ok()
+        .filter(|&l| usize::from(l) <= MAX_PAYLOAD_LEN)?;
+    let mut packet = [0; RAW_REPORT_LEN];
+    packet[OFFSET_ID] = id;
+    packet[OFFSET_CODE] = code;
+    packet[OFFSET_LEN] = len;
+    packet[OFFSET_PAYLOAD..OFFSET_PAYLOAD + payload.len()].copy_from_slice(payload);
+    Some(packet)
+}
+
+fn decode(packet: &[u8]) -> Option<(u8, u8, &[u8])> {
+    if packet.len() != RAW_REPORT_LEN {
+        return None;
+    }
+    let len = usize::from(packet[OFFSET_LEN]);
+    let payload = packet.get(OFFSET_PAYLOAD..OFFSET_PAYLOAD + len)?;
+    Some((packet[OFFSET_ID], packet[OFFSET_CODE], payload))
+}
+
+/// Outcome of [`RpcServer::receive`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Received<'a> {
+    /// A new request for the application, to be answered with [`RpcServer::respond`]
+    Call(Request<'a>),
+    /// A response to send straight back to the host
+    Reply([u8; RAW_REPORT_LEN]),
+}
+
+/// Device side request tracking, see the [module docs](self)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RpcServer {
+    timeout: MillisDurationU32,
+    pending: Option<(u8, MillisDurationU32)>,
+    last_response: Option<[u8; RAW_REPORT_LEN]>,
+}
+
+impl RpcServer {
+    /// Server answering requests with [`Status::Timeout`] if the application doesn't respond
+    /// within `timeout`
+    #[must_use]
+    pub fn new(timeout: MillisDurationU32) -> Self {
+        Self {
+            timeout,
+            pending: None,
+            last_response: None,
+        }
+    }
+
+    /// ID of the request awaiting a response, if any
+    #[must_use]
+    pub fn pending(&self) -> Option<u8> {
+        self.pending.map(|(id, _)| id)
+    }
+
+    /// Forget any pending request and the last response, e.g. after a bus reset
+    pub fn reset(&mut self) {
+        self.pending = None;
+        self.last_response = None;
+    }
+
+    /// Process a packet received from the host
+    pub fn receive<'a>(&mut self, packet: &'a [u8]) -> Received<'a> {
+        let Some(request) = Request::unpack(packet) else {
+            let id = packet.get(OFFSET_ID).copied().unwrap_or_default();
+            return Received::Reply(Self::status(id, Status::Malformed));
+        };
+
+        if self.pending.is_some() {
+            return Received::Reply(Self::status(request.id, Status::Busy));
+        }
+        if let Some(response) = self.last_response.filter(|r| r[OFFSET_ID] == request.id) {
+            trace!("RPC request {} repeated", request.id);
+            return Received::Reply(response);
+        }
+
+        self.pending = Some((request.id, 0.millis()));
+        Received::Call(request)
+    }
+
+    /// Response to the pending request `id`
+    ///
+    /// Returns `None` if `id` isn't pending, e.g. because it has already timed out. A payload
+    /// longer than [`MAX_PAYLOAD_LEN`] is replaced with [`Status::Overflow`]
+    pub fn respond(
+        &mut self,
+        id: u8,
+        status: Status,
+        payload: &[u8],
+    ) -> Option<[u8; RAW_REPORT_LEN]> {
+        if self.pending() != Some(id) {
+            warn!("RPC response to request {} which isn't pending", id);
+            return None;
+        }
+        let response = Response {
+            id,
+            status,
+            payload,
+        }
+        .pack()
+        .unwrap_or_else(|| Self::status(id, Status::Overflow));
+        Some(self.complete(response))
+    }
+
+    /// Call every 1ms, returns the [`Status::Timeout`] response to send when the pending request
+    /// times out
+    pub fn tick(&mut self) -> Option<[u8; RAW_REPORT_LEN]> {
+        let (id, elapsed) = self.pending.as_mut()?;
+        *elapsed += 1.millis();
+        if *elapsed < self.timeout {
+            return None;
+        }
+        let id = *id;
+        warn!("RPC request {} timed out", id);
+        Some(self.complete(Self::status(id, Status::Timeout)))
+    }
+
+    fn complete(&mut self, response: [u8; RAW_REPORT_LEN]) -> [u8; RAW_REPORT_LEN] {
+        self.pending = None;
+        self.last_response = Some(response);
+        response
+    }
+
+    fn status(id: u8, status: Status) -> [u8; RAW_REPORT_LEN] {
+        let mut packet = [0; RAW_REPORT_LEN];
+        packet[OFFSET_ID] = id;
+        packet[OFFSET_CODE] = status.into();
+        packet
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    fn reply_status(received: Received<'_>) -> Status {
+        match received {
+            Received::Reply(packet) => Response::unpack(&packet).unwrap().status,
+            Received::Call(_) => panic!("expected a reply"),
+        }
+    }
+
+    #[test]
+    fn request_response_correlation() {
+        let mut server = RpcServer::new(10.millis());
+
+        let packet = Request {
+            id: 3,
+            method: 0x10,
+            payload: &[1, 2],
+        }
+        .pack()
+        .unwrap();
+        assert_eq!(
+            server.receive(&packet),
+            Received::Call(Request {
+                id: 3,
+                method: 0x10,
+                payload: &[1, 2]
+            })
+        );
+
+        let other = Request {
+            id: 4,
+            method: 0x10,
+            payload: &[],
+        }
+        .pack()
+        .unwrap();
+        assert_eq!(reply_status(server.receive(&other)), Status::Busy);
+        assert_eq!(server.respond(4, Status::Ok, &[]), None);
+
+        let response = server.respond(3, Status::Ok, &[0xAA]).unwrap();
+        assert_eq!(
+            Response::unpack(&response),
+            Some(Response {
+                id: 3,
+                status: Status::Ok,
+                payload: &[0xAA]
+            })
+        );
+
+        // Repeated request gets the same response without a new call
+        assert_eq!(server.receive(&packet), Received::Reply(response));
+        assert_eq!(server.pending(), None);
+
+        let too_long = [0; MAX_PAYLOAD_LEN + 1];
+        assert!(matches!(server.receive(&other), Received::Call(_)));
+        let response = server.respond(4, Status::Ok, &too_long).unwrap();
+        assert_eq!(
+            Response::unpack(&response).unwrap().status,
+            Status::Overflow
+        );
+    }
+
+    #[test]
+    fn pending_request_times_out() {
+        let mut server = RpcServer::new(3.millis());
+        let packet = Request {
+            id: 9,
+            method: 0x01,
+            payload: &[],
+        }
+        .pack()
+        .unwrap();
+        assert!(matches!(server.receive(&packet), Received::Call(_)));
+
+        assert_eq!(server.tick(), None);
+        assert_eq!(server.tick(), None);
+        let response = server.tick().unwrap();
+        assert_eq!(
+            Response::unpack(&response),
+            Some(Response {
+                id: 9,
+                status: Status::Timeout,
+                payload: &[]
+            })
+        );
+        assert_eq!(server.respond(9, Status::Ok, &[]), None);
+        assert_eq!(server.tick(), None);
+
+        let mut malformed = packet;
+        malformed[OFFSET_LEN] = 0xFF;
+        assert_eq!(reply_status(server.receive(&malformed)), Status::Malformed);
+    }
+}
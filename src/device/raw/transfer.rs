@@ -0,0 +1,459 @@
+//! Chunked, acknowledged transfer of large blobs over [`RawHid`](super::RawHid) reports
+//!
+//! Intended for pushing configuration blobs or firmware images from host tools. The host
+//! sends one output report per chunk and waits for the device to acknowledge it with an
+//! input report before sending the next chunk.
+//!
+//! Every packet, in both directions, is [`RAW_REPORT_LEN`] bytes:
+//!
+//! | Byte   | Content                                               |
+//! |--------|-------------------------------------------------------|
+//! | 0      | [`Command`]                                           |
+//! | 1      | Sequence number                                       |
+//! | 2      | Payload length, or [`Status`] for [`Command::Ack`]    |
+//! | 3..62  | Payload, zero padded                                  |
+//! | 62..64 | CRC-16/CCITT-FALSE of bytes 0..62, little endian      |
+//!
+//! A transfer is a [`Command::Start`] packet carrying the total length as a little endian
+//! `u32`, followed by [`Command::Data`] packets with consecutive sequence numbers and finally
+//! a [`Command::End`] packet. A packet repeated by the host because an acknowledgement was lost
+//! is acknowledged again without being written to the [`TransferSink`] twice.
+
+use super::RAW_REPORT_LEN;
+use crate::protocol::transfer::{
+    OFFSET_COMMAND, OFFSET_CRC, OFFSET_LEN, OFFSET_PAYLOAD, OFFSET_SEQ,
+};
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+
+pub use crate::protocol::transfer::MAX_PAYLOAD_LEN;
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, TryFromPrimitive, IntoPrimitive)]
+#[repr(u8)]
+pub enum Command {
+    Start = 0x01,
+    Data = 0x02,
+    End = 0x03,
+    Abort = 0x04,
+    Ack = 0x80,
+}
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, TryFromPrimitive, IntoPrimitive)]
+#[repr(u8)]
+pub enum Status {
+    /// Packet accepted, send the next one
+    Ok = 0x00,
+    /// [`Command::End`] accepted, the whole blob has been received
+    Complete = 0x01,
+    BadCrc = 0x02,
+    OutOfSequence = 0x03,
+    /// More data was sent than announced by [`Command::Start`]
+    Overflow = 0x04,
+    /// [`Command::End`] was received before all data announced by [`Command::Start`]
+    Incomplete = 0x05,
+    NotStarted = 0x06,
+    SinkError = 0x07,
+    Aborted = 0x08,
+    Malformed = 0x09,
+}
+
+/// Destination for data received by a [`Receiver`]
+pub trait TransferSink {
+    type Error;
+    /// Called when a new transfer of `total_len` bytes starts
+    fn begin(&mut self, total_len: u32) -> Result<(), Self::Error>;
+    /// Called once for each chunk, in order
+    fn write(&mut self, offset: u32, data: &[u8]) -> Result<(), Self::Error>;
+    /// Called once all data has been received
+    fn finish(&mut self) -> Result<(), Self::Error>;
+    /// Called if a transfer in progress is abandoned
+    fn abort(&mut self);
+}
+
+/// Acknowledgement sent by the device for every received packet
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Response {
+    pub seq: u8,
+    pub status: Status,
+}
+
+impl Response {
+    #[must_use]
+    pub fn pack(&self) -> [u8; RAW_REPORT_LEN] {
+        let mut packet = [0; RAW_REPORT_LEN];
+        packet[OFFSET_COMMAND] = Command::Ack.into();
+        packet[OFFSET_SEQ] = self.seq;
+        packet[OFFSET_LEN] = self.status.into();
+        seal(&mut packet);
+        packet
+    }
+
+    /// Decode an acknowledgement, for use by host tools
+    #[must_use]
+    pub fn unpack(packet: &[u8]) -> Option<Self> {
+        if !verify(packet) || packet[OFFSET_COMMAND] != u8::from(Command::Ack) {
+            return None;
+        }
+        Some(Self {
+            seq: packet[OFFSET_SEQ],
+            status: Status::try_from(packet[OFFSET_LEN]).ok()?,
+        })
+    }
+}
+
+/// Encode a packet, for use by host tools
+///
+/// Returns `None` if `payload` is longer than [`MAX_PAYLOAD_LEN`]
+#[must_use]
+pub fn encode(command: Command, seq: u8, payload: &[u8]) -> Option<[u8; RAW_REPORT_LEN]> {
+    let len = u8::try_from(payload.len())
+        .ok()
+        .filter(|&l| usize::from(l) <= MAX_PAYLOAD_LEN)?;
+    let mut packet = [0; RAW_REPORT_LEN];
+    packet[OFFSET_COMMAND] = command.into();
+    packet[OFFSET_SEQ] = seq;
+    packet[OFFSET_LEN] = len;
+    packet[OFFSET_PAYLOAD..OFFSET_PAYLOAD + payload.len()].copy_from_slice(payload);
+    seal(&mut packet);
+    Some(packet)
+}
+
+/// CRC-16/CCITT-FALSE
+#[must_use]
+pub fn crc16(data: &[u8]) -> u16 {
+    let mut crc: u16 = 0xFFFF;
+    for &b in data {
+        crc ^= u16::from(b) << 8;
+        for _ in 0..8 {
+            crc = if crc & 0x8000 == 0 {
+                crc << 1
+            } else {
+                (crc << 1) ^ 0x1021
+            };
+        }
+    }
+    crc
+}
+
+fn seal(packet: &mut [u8; RAW_REPORT_LEN]) {
+    let crc = crc16(&packet[..OFFSET_CRC]);
+    packet[OFFSET_CRC..].copy_from_slice(&crc.to_le_bytes());
+}
+
+fn verify(packet: &[u8]) -> bool {
+    packet.len() == RAW_REPORT_LEN
+        && crc16(&packet[..OFFSET_CRC])
+            == u16::from_le_bytes([packet[OFFSET_CRC], packet[OFFSET_CRC + 1]])
+}
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum TransferState {
+    #[default]
+    Idle,
+    Receiving {
+        next_seq: u8,
+        received: u32,
+        total: u32,
+    },
+    Complete {
+        seq: u8,
+    },
+}
+
+/// Device side state machine for the chunked transfer protocol
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub struct Receiver {
+    state: TransferState,
+}
+
+impl Receiver {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn state(&self) -> TransferState {
+        self.state
+    }
+
+    /// Abandon any transfer in progress
+    pub fn reset<S: TransferSink>(&mut self, sink: &mut S) {
+        if matches!(self.state, TransferState::Receiving { .. }) {
+            sink.abort();
+        }
+        self.state = TransferState::Idle;
+    }
+
+    /// Process a packet received from the host, returning the acknowledgement to send back
+    pub fn process<S: TransferSink>(&mut self, packet: &[u8], sink: &mut S) -> Response {
+        let seq = packet.get(OFFSET_SEQ).copied().unwrap_or_default();
+        let status = self.process_inner(packet, sink);
+        trace!("Transfer packet {}, status {:?}", seq, status);
+        Response { seq, status }
+    }
+
+    fn start<S: TransferSink>(&mut self, seq: u8, payload: &[u8], sink: &mut S) -> Status {
+        let Ok(total) = <[u8; 4]>::try_from(payload).map(u32::from_le_bytes) else {
+            self.reset(sink);
+            return Status::Malformed;
+        };
+        if let TransferState::Receiving {
+            next_seq,
+            received: 0,
+            total: active,
+        } = self.state
+        {
+            if seq == next_seq.wrapping_sub(1) && total == active {
+                // retransmission of the packet that started the active transfer
+                return Status::Ok;
+            }
+        }
+        self.reset(sink);
+        if sink.begin(total).is_err() {
+            return Status::SinkError;
+        }
+        self.state = TransferState::Receiving {
+            next_seq: seq.wrapping_add(1),
+            received: 0,
+            total,
+        };
+        Status::Ok
+    }
+
+    fn process_inner<S: TransferSink>(&mut self, packet: &[u8], sink: &mut S) -> Status {
+        if packet.len() != RAW_REPORT_LEN {
+            return Status::Malformed;
+        }
+        if !verify(packet) {
+            return Status::BadCrc;
+        }
+        let seq = packet[OFFSET_SEQ];
+        let len = usize::from(packet[OFFSET_LEN]);
+        if len > MAX_PAYLOAD_LEN {
+            return Status::Malformed;
+        }
+        let payload = &packet[OFFSET_PAYLOAD..OFFSET_PAYLOAD + len];
+
+        match (Command::try_from(packet[OFFSET_COMMAND]), self.state) {
+            (Ok(Command::Start), _) => self.start(seq, payload, sink),
+            (
+                Ok(Command::Data),
+                TransferState::Receiving {
+                    next_seq,
+                    received,
+                    total,
+                },
+            ) => {
+                if seq == next_seq.wrapping_sub(1) {
+                    // retransmission of a packet that has already been written
+                    return Status::Ok;
+                }
+                if seq != next_seq {
+                    return Status::OutOfSequence;
+                }
+                #[allow(clippy::cast_possible_truncation)]
+                let len = len as u32;
+                if total - received < len {
+                    self.reset(sink);
+                    return Status::Overflow;
+                }
+                if sink.write(received, payload).is_err() {
+                    self.reset(sink);
+                    return Status::SinkError;
+                }
+                self.state = TransferState::Receiving {
+                    next_seq: seq.wrapping_add(1),
+                    received: received + len,
+                    total,
+                };
+                Status::Ok
+            }
+            (
+                Ok(Command::End),
+                TransferState::Receiving {
+                    next_seq,
+                    received,
+                    total,
+                },
+            ) => {
+                if seq != next_seq {
+                    return Status::OutOfSequence;
+                }
+                if received != total {
+                    self.reset(sink);
+                    return Status::Incomplete;
+                }
+                if sink.finish().is_err() {
+                    self.state = TransferState::Idle;
+                    return Status::SinkError;
+                }
+                self.state = TransferState::Complete { seq };
+                Status::Complete
+            }
+            (Ok(Command::End), TransferState::Complete { seq: last }) if last == seq => {
+                Status::Complete
+            }
+            (Ok(Command::Abort), _) => {
+                self.reset(sink);
+                Status::Aborted
+            }
+            (Ok(Command::Data | Command::End), _) => Status::NotStarted,
+            (Ok(Command::Ack) | Err(_), _) => Status::Malformed,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #![allow(clippy::unwrap_used)]
+    #![allow(clippy::expect_used)]
+
+    use super::*;
+    use std::vec::Vec;
+
+    #[derive(Default)]
+    struct VecSink {
+        data: Vec<u8>,
+        finished: bool,
+        aborted: bool,
+    }
+
+    impl TransferSink for VecSink {
+        type Error = ();
+
+        fn begin(&mut self, total_len: u32) -> Result<(), ()> {
+            self.data.reserve(total_len as usize);
+            Ok(())
+        }
+
+        fn write(&mut self, offset: u32, data: &[u8]) -> Result<(), ()> {
+            assert_eq!(offset as usize, self.data.len());
+            self.data.extend_from_slice(data);
+            Ok(())
+        }
+
+        fn finish(&mut self) -> Result<(), ()> {
+            self.finished = true;
+            Ok(())
+        }
+
+        fn abort(&mut self) {
+            self.aborted = true;
+        }
+    }
+
+    #[test]
+    fn transfer_in_order() {
+        let blob: Vec<u8> = (0..=200).collect();
+        let mut sink = VecSink::default();
+        let mut receiver = Receiver::new();
+
+        let start = encode(Command::Start, 0, &201_u32.to_le_bytes()).unwrap();
+        assert_eq!(receiver.process(&start, &mut sink).status, Status::Ok);
+
+        let mut seq = 1;
+        for chunk in blob.chunks(MAX_PAYLOAD_LEN) {
+            let packet = encode(Command::Data, seq, chunk).unwrap();
+            let response = Response::unpack(&receiver.process(&packet, &mut sink).pack()).unwrap();
+            assert_eq!(
+                response,
+                Response {
+                    seq,
+                    status: Status::Ok
+                }
+            );
+            seq += 1;
+        }
+
+        let end = encode(Command::End, seq, &[]).unwrap();
+        assert_eq!(receiver.process(&end, &mut sink).status, Status::Complete);
+        assert!(sink.finished);
+        assert_eq!(sink.data, blob);
+    }
+
+    #[test]
+    fn transfer_retransmitted_packet_written_once() {
+        let mut sink = VecSink::default();
+        let mut receiver = Receiver::new();
+
+        let start = encode(Command::Start, 0, &4_u32.to_le_bytes()).unwrap();
+        receiver.process(&start, &mut sink);
+
+        let data = encode(Command::Data, 1, &[1, 2]).unwrap();
+        assert_eq!(receiver.process(&data, &mut sink).status, Status::Ok);
+        assert_eq!(receiver.process(&data, &mut sink).status, Status::Ok);
+
+        let skipped = encode(Command::Data, 3, &[3, 4]).unwrap();
+        assert_eq!(
+            receiver.process(&skipped, &mut sink).status,
+            Status::OutOfSequence
+        );
+
+        assert_eq!(sink.data, [1, 2]);
+    }
+
+    #[test]
+    fn transfer_retransmitted_start_keeps_transfer() {
+        let mut sink = VecSink::default();
+        let mut receiver = Receiver::new();
+
+        let start = encode(Command::Start, 7, &2_u32.to_le_bytes()).unwrap();
+        assert_eq!(receiver.process(&start, &mut sink).status, Status::Ok);
+        assert_eq!(
+            receiver.process(&start, &mut sink),
+            Response {
+                seq: 7,
+                status: Status::Ok
+            }
+        );
+        assert!(!sink.aborted);
+
+        let data = encode(Command::Data, 8, &[1, 2]).unwrap();
+        assert_eq!(receiver.process(&data, &mut sink).status, Status::Ok);
+        let end = encode(Command::End, 9, &[]).unwrap();
+        assert_eq!(receiver.process(&end, &mut sink).status, Status::Complete);
+        assert_eq!(sink.data, [1, 2]);
+
+        // a Start with a different length is a new transfer
+        let start = encode(Command::Start, 0, &4_u32.to_le_bytes()).unwrap();
+        receiver.process(&start, &mut sink);
+        let restart = encode(Command::Start, 0, &5_u32.to_le_bytes()).unwrap();
+        receiver.process(&restart, &mut sink);
+        assert!(sink.aborted);
+        assert_eq!(
+            receiver.state(),
+            TransferState::Receiving {
+                next_seq: 1,
+                received: 0,
+                total: 5
+            }
+        );
+    }
+
+    #[test]
+    fn transfer_rejects_corruption_and_overflow() {
+        let mut sink = VecSink::default();
+        let mut receiver = Receiver::new();
+
+        let data = encode(Command::Data, 1, &[1, 2]).unwrap();
+        assert_eq!(
+            receiver.process(&data, &mut sink).status,
+            Status::NotStarted
+        );
+
+        let start = encode(Command::Start, 0, &1_u32.to_le_bytes()).unwrap();
+        receiver.process(&start, &mut sink);
+
+        let mut corrupt = data;
+        corrupt[OFFSET_PAYLOAD] ^= 0xFF;
+        assert_eq!(receiver.process(&corrupt, &mut sink).status, Status::BadCrc);
+
+        assert_eq!(receiver.process(&data, &mut sink).status, Status::Overflow);
+        assert!(sink.aborted);
+        assert_eq!(receiver.state(), TransferState::Idle);
+    }
+}
@@ -0,0 +1,594 @@
+//! HID sensors, for sensor boards showing up under the sensor frameworks of the host
+//!
+//! [`TemperatureSensor`] implements the ambient temperature sensor of the HID Sensors usage
+//! model, see [`TEMPERATURE_SENSOR_REPORT_DESCRIPTOR`]. The host configures the sensor with the
+//! properties feature report, [`SensorSettings`]: whether events are reported at all, the power
+//! state, the report interval and the change sensitivity. Readings are sent as
+//! [`TemperatureReport`]s from `tick`, at most once per report interval and only once the
+//! temperature has changed by at least the change sensitivity since the last report.
+//!
+//! Temperatures are in hundredths of a degree Celsius.
+//!
+//! ```rust,no_run
+//! # use xous_usb_hid::device::sensor::*;
+//! # use usb_device::bus::UsbBus;
+//! # fn sample<B: UsbBus>(sensor: &mut TemperatureSensor<'_, B>) {
+//! // 21.5 °C, sent by UsbHidClass::tick when due
+//! sensor.set_temperature(2150);
+//! # }
+//! ```
+
+use crate::interface::InterfaceClass;
+use crate::usb_class::prelude::*;
+use fugit::{ExtU32, MillisDurationU32};
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use usb_device::bus::UsbBus;
+use usb_device::class_prelude::UsbBusAllocator;
+
+/// Length of the properties feature report, see [`SensorSettings`]
+pub const SENSOR_FEATURE_REPORT_LEN: usize = 8;
+
+/// Length of the temperature input report, see [`TemperatureReport`]
+pub const TEMPERATURE_REPORT_LEN: usize = 4;
+
+const REPORT_ID: u8 = 0;
+
+/// Ambient temperature sensor descriptor, without report IDs
+///
+/// * Feature: Sensor Connection Type, Reporting State, Power State and Sensor State selectors, 8
+///   bits each, Report Interval in milliseconds and the Change Sensitivity Absolute of the
+///   temperature in hundredths of a degree, 16 bits each ([`SENSOR_FEATURE_REPORT_LEN`] bytes)
+/// * Input: Sensor State and Sensor Event selectors, 8 bits each, and the Temperature in
+///   hundredths of a degree Celsius, 16 bits ([`TEMPERATURE_REPORT_LEN`] bytes)
+#[rustfmt::skip]
+pub const TEMPERATURE_SENSOR_REPORT_DESCRIPTOR: &[u8] = &[
+    0x05, 0x20,                   // Usage Page (Sensor),
+    0x09, 0x33,                   // Usage (Environmental: Temperature),
+    0xA1, 0x01,                   // Collection (Application),
+    0x15, 0x00,                   //   Logical Minimum (0),
+    0x75, 0x08,                   //   Report Size (8),
+    0x95, 0x01,                   //   Report Count (1),
+    0x0A, 0x09, 0x03,             //   Usage (Property: Sensor Connection Type),
+    0x25, 0x02,                   //   Logical Maximum (2),
+    0xA1, 0x02,                   //   Collection (Logical),
+    0x0A, 0x30, 0x08,             //     Usage (Connection Type: PC Integrated),
+    0x0A, 0x31, 0x08,             //     Usage (Connection Type: PC Attached),
+    0x0A, 0x32, 0x08,             //     Usage (Connection Type: PC External),
+    0xB1, 0x00,                   //     Feature (Data, Array, Absolute),
+    0xC0,                         //   End Collection,
+    0x0A, 0x16, 0x03,             //   Usage (Property: Reporting State),
+    0x25, 0x05,                   //   Logical Maximum (5),
+    0xA1, 0x02,                   //   Collection (Logical),
+    0x0A, 0x40, 0x08,             //     Usage (Reporting State: Report No Events),
+    0x0A, 0x41, 0x08,             //     Usage (Reporting State: Report All Events),
+    0x0A, 0x42, 0x08,             //     Usage (Reporting State: Report Threshold Events),
+    0x0A, 0x43, 0x08,             //     Usage (Reporting State: Wake On No Events),
+    0x0A, 0x44, 0x08,             //     Usage (Reporting State: Wake On All Events),
+    0x0A, 0x45, 0x08,             //     Usage (Reporting State: Wake On Threshold Events),
+    0xB1, 0x00,                   //     Feature (Data, Array, Absolute),
+    0xC0,                         //   End Collection,
+    0x0A, 0x19, 0x03,             //   Usage (Property: Power State),
+    0x25, 0x05,                   //   Logical Maximum (5),
+    0xA1, 0x02,                   //   Collection (Logical),
+    0x0A, 0x50, 0x08,             //     Usage (Power State: Undefined),
+    0x0A, 0x51, 0x08,             //     Usage (Power State: D0 Full Power),
+    0x0A, 0x52, 0x08,             //     Usage (Power State: D1 Low Power),
+    0x0A, 0x53, 0x08,             //     Usage (Power State: D2 Standby With Wake),
+    0x0A, 0x54, 0x08,             //     Usage (Power State: D3 Sleep With Wake),
+    0x0A, 0x55, 0x08,             //     Usage (Power State: D4 Power Off),
+    0xB1, 0x00,                   //     Feature (Data, Array, Absolute),
+    0xC0,                         //   End Collection,
+    0x0A, 0x01, 0x02,             //   Usage (Event: Sensor State),
+    0x25, 0x06,                   //   Logical Maximum (6),
+    0xA1, 0x02,                   //   Collection (Logical),
+    0x0A, 0x00, 0x08,             //     Usage (Sensor State: Undefined),
+    0x0A, 0x01, 0x08,             //     Usage (Sensor State: Ready),
+    0x0A, 0x02, 0x08,             //     Usage (Sensor State: Not Available),
+    0x0A, 0x03, 0x08,             //     Usage (Sensor State: No Data),
+    0x0A, 0x04, 0x08,             //     Usage (Sensor State: Initializing),
+    0x0A, 0x05, 0x08,             //     Usage (Sensor State: Access Denied),
+    0x0A, 0x06, 0x08,             //     Usage (Sensor State: Error),
+    0xB1, 0x00,                   //     Feature (Data, Array, Absolute),
+    0xC0,                         //   End Collection,
+    0x0A, 0x0E, 0x03,             //   Usage (Property: Report Interval),
+    0x27, 0xFF, 0xFF, 0x00, 0x00, //   Logical Maximum (65535),
+    0x75, 0x10,                   //   Report Size (16),
+    0xB1, 0x02,                   //   Feature (Data, Variable, Absolute),
+    0x0A, 0x34, 0x14,             //   Usage (Data Field: Temperature, Change Sensitivity Absolute),
+    0x55, 0x0E,                   //   Unit Exponent (-2),
+    0x67, 0x01, 0x00, 0x01, 0x00, //   Unit (Degrees Celsius),
+    0xB1, 0x02,                   //   Feature (Data, Variable, Absolute),
+    0x65, 0x00,                   //   Unit (None),
+    0x55, 0x00,                   //   Unit Exponent (0),
+    0x75, 0x08,                   //   Report Size (8),
+    0x0A, 0x01, 0x02,             //   Usage (Event: Sensor State),
+    0x25, 0x06,                   //   Logical Maximum (6),
+    0xA1, 0x02,                   //   Collection (Logical),
+    0x0A, 0x00, 0x08,             //     Usage (Sensor State: Undefined),
+    0x0A, 0x01, 0x08,             //     Usage (Sensor State: Ready),
+    0x0A, 0x02, 0x08,             //     Usage (Sensor State: Not Available),
+    0x0A, 0x03, 0x08,             //     Usage (Sensor State: No Data),
+    0x0A, 0x04, 0x08,             //     Usage (Sensor State: Initializing),
+    0x0A, 0x05, 0x08,             //     Usage (Sensor State: Access Denied),
+    0x0A, 0x06, 0x08,             //     Usage (Sensor State: Error),
+    0x81, 0x00,                   //     Input (Data, Array, Absolute),
+    0xC0,                         //   End Collection,
+    0x0A, 0x02, 0x02,             //   Usage (Event: Sensor Event),
+    0x25, 0x05,                   //   Logical Maximum (5),
+    0xA1, 0x02,                   //   Collection (Logical),
+    0x0A, 0x10, 0x08,             //     Usage (Sensor Event: Unknown),
+    0x0A, 0x11, 0x08,             //     Usage (Sensor Event: State Changed),
+    0x0A, 0x12, 0x08,             //     Usage (Sensor Event: Property Changed),
+    0x0A, 0x13, 0x08,             //     Usage (Sensor Event: Data Updated),
+    0x0A, 0x14, 0x08,             //     Usage (Sensor Event: Poll Response),
+    0x0A, 0x15, 0x08,             //     Usage (Sensor Event: Change Sensitivity),
+    0x81, 0x00,                   //     Input (Data, Array, Absolute),
+    0xC0,                         //   End Collection,
+    0x0A, 0x34, 0x04,             //   Usage (Data Field: Temperature),
+    0x16, 0x00, 0x80,             //   Logical Minimum (-32768),
+    0x26, 0xFF, 0x7F,             //   Logical Maximum (32767),
+    0x75, 0x10,                   //   Report Size (16),
+    0x55, 0x0E,                   //   Unit Exponent (-2),
+    0x67, 0x01, 0x00, 0x01, 0x00, //   Unit (Degrees Celsius),
+    0x81, 0x02,                   //   Input (Data, Variable, Absolute),
+    0x65, 0x00,                   //   Unit (None),
+    0x55, 0x00,                   //   Unit Exponent (0),
+    0xC0,                         // End Collection
+];
+
+/// How the sensor is connected to the host, reported in the properties feature report
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, Hash, TryFromPrimitive, IntoPrimitive)]
+#[repr(u8)]
+pub enum ConnectionType {
+    /// Built into the host
+    Integrated = 0,
+    /// Attached to the host, e.g. a docking station
+    Attached = 1,
+    /// External to the host, such as a USB sensor board
+    #[default]
+    External = 2,
+}
+
+/// Events the host wants the sensor to report
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, Hash, TryFromPrimitive, IntoPrimitive)]
+#[repr(u8)]
+pub enum ReportingState {
+    NoEvents = 0,
+    #[default]
+    AllEvents = 1,
+    ThresholdEvents = 2,
+    WakeOnNoEvents = 3,
+    WakeOnAllEvents = 4,
+    WakeOnThresholdEvents = 5,
+}
+
+/// Power state selected by the host
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, Hash, TryFromPrimitive, IntoPrimitive)]
+#[repr(u8)]
+pub enum PowerState {
+    Undefined = 0,
+    #[default]
+    FullPower = 1,
+    LowPower = 2,
+    StandbyWithWakeup = 3,
+    SleepWithWakeup = 4,
+    PowerOff = 5,
+}
+
+/// State of the sensor, reported with every reading and in the properties feature report
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, Hash, TryFromPrimitive, IntoPrimitive)]
+#[repr(u8)]
+pub enum SensorState {
+    Undefined = 0,
+    Ready = 1,
+    NotAvailable = 2,
+    NoData = 3,
+    /// Before the first reading
+    #[default]
+    Initializing = 4,
+    AccessDenied = 5,
+    Error = 6,
+}
+
+/// Why an input report was sent
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, Hash, TryFromPrimitive, IntoPrimitive)]
+#[repr(u8)]
+pub enum SensorEvent {
+    #[default]
+    Unknown = 0,
+    StateChanged = 1,
+    PropertyChanged = 2,
+    DataUpdated = 3,
+    PollResponse = 4,
+    ChangeSensitivity = 5,
+}
+
+/// Sensor properties set by the host with the properties feature report
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SensorSettings {
+    pub reporting_state: ReportingState,
+    pub power_state: PowerState,
+    /// Least time between readings in milliseconds, 0 sends every change
+    pub report_interval: u16,
+    /// Least temperature change sent, in hundredths of a degree
+    pub change_sensitivity: u16,
+}
+
+impl Default for SensorSettings {
+    /// All events at full power, one reading per second at most, for changes of half a degree
+    fn default() -> Self {
+        Self {
+            reporting_state: ReportingState::AllEvents,
+            power_state: PowerState::FullPower,
+            report_interval: 1000,
+            change_sensitivity: 50,
+        }
+    }
+}
+
+impl SensorSettings {
+    /// `true` if readings should be sent to the host
+    #[must_use]
+    pub fn is_reporting(&self) -> bool {
+        !matches!(
+            self.reporting_state,
+            ReportingState::NoEvents | ReportingState::WakeOnNoEvents
+        ) && !matches!(self.power_state, PowerState::PowerOff)
+    }
+
+    /// Properties feature report, with the sensor owned connection type and state
+    #[must_use]
+    pub fn to_bytes(
+        &self,
+        connection: ConnectionType,
+        state: SensorState,
+    ) -> [u8; SENSOR_FEATURE_REPORT_LEN] {
+        let [interval_low, interval_high] = self.report_interval.to_le_bytes();
+        let [sensitivity_low, sensitivity_high] = self.change_sensitivity.to_le_bytes();
+        [
+            connection.into(),
+            self.reporting_state.into(),
+            self.power_state.into(),
+            state.into(),
+            interval_low,
+            interval_high,
+            sensitivity_low,
+            sensitivity_high,
+        ]
+    }
+
+    /// Decode the properties feature report, ignoring the connection type and sensor state
+    ///
+    /// Returns `None` if it has the wrong length or an unknown selector
+    #[must_use]
+    pub fn from_bytes(data: &[u8]) -> Option<Self> {
+        let data: [u8; SENSOR_FEATURE_REPORT_LEN] = data.try_into().ok()?;
+        Some(Self {
+            reporting_state: ReportingState::try_from(data[1]).ok()?,
+            power_state: PowerState::try_from(data[2]).ok()?,
+            report_interval: u16::from_le_bytes([data[4], data[5]]),
+            change_sensitivity: u16::from_le_bytes([data[6], data[7]]),
+        })
+    }
+}
+
+/// Temperature input report
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, Hash)]
+pub struct TemperatureReport {
+    pub state: SensorState,
+    pub event: SensorEvent,
+    /// Hundredths of a degree Celsius
+    pub temperature: i16,
+}
+
+impl TemperatureReport {
+    #[must_use]
+    pub fn to_bytes(&self) -> [u8; TEMPERATURE_REPORT_LEN] {
+        let [low, high] = self.temperature.to_le_bytes();
+        [self.state.into(), self.event.into(), low, high]
+    }
+}
+
+/// Ambient temperature sensor, see the [module docs](self)
+///
+/// **Note:** Readings are sent by [`UsbHidClass::tick()`], which must be called every 1ms, or by
+/// [`UsbHidClass::tick_until()`].
+pub struct TemperatureSensor<'a, B: UsbBus> {
+    interface: Interface<'a, B, InBytes8, OutNone, ReportSingle>,
+    connection: ConnectionType,
+    initial: SensorSettings,
+    settings: SensorSettings,
+    state: SensorState,
+    temperature: Option<i16>,
+    reported: Option<i16>,
+    event: Option<SensorEvent>,
+    /// Interface uptime of the last report
+    reported_at: Option<MillisDurationU32>,
+}
+
+impl<B: UsbBus> TemperatureSensor<'_, B> {
+    /// Record a reading, in hundredths of a degree Celsius
+    ///
+    /// The first reading makes an initializing sensor ready
+    pub fn set_temperature(&mut self, temperature: i16) {
+        self.temperature = Some(temperature);
+        if self.state == SensorState::Initializing {
+            self.set_state(SensorState::Ready);
+        }
+    }
+
+    /// Last reading recorded
+    #[must_use]
+    pub fn temperature(&self) -> Option<i16> {
+        self.temperature
+    }
+
+    /// Change the state of the sensor, e.g. to [`SensorState::Error`] when the sensor stops
+    /// answering, the host is sent a state changed event
+    pub fn set_state(&mut self, state: SensorState) {
+        if state != self.state {
+            debug!("Sensor state {:?}", state);
+            self.state = state;
+            self.event = Some(SensorEvent::StateChanged);
+            self.store_properties();
+        }
+    }
+
+    #[must_use]
+    pub fn state(&self) -> SensorState {
+        self.state
+    }
+
+    /// Properties last set by the host
+    #[must_use]
+    pub fn settings(&self) -> SensorSettings {
+        self.settings
+    }
+
+    fn store_properties(&mut self) {
+        let properties = self.settings.to_bytes(self.connection, self.state);
+        self.interface.set_feature_report(REPORT_ID, &properties);
+    }
+
+    /// Properties in the feature report, `None` if the host has written invalid ones
+    fn host_settings(&self) -> Option<SensorSettings> {
+        self.interface
+            .feature_report_with_id(REPORT_ID)
+            .and_then(SensorSettings::from_bytes)
+    }
+
+    fn sync_settings(&mut self) {
+        match self.host_settings() {
+            Some(settings) if settings == self.settings => {}
+            Some(settings) => {
+                debug!(
+                    "Sensor report interval set to {}ms by the host",
+                    settings.report_interval
+                );
+                self.settings = settings;
+                self.event = Some(SensorEvent::PropertyChanged);
+                self.store_properties();
+            }
+            None => self.store_properties(),
+        }
+    }
+
+    /// `true` if the reading has changed by at least the change sensitivity since the last report
+    fn changed(&self) -> bool {
+        let Some(temperature) = self.temperature else {
+            return false;
+        };
+        let sensitivity = self.settings.change_sensitivity.max(1);
+        !matches!(self.reported, Some(reported) if temperature.abs_diff(reported) < sensitivity)
+    }
+
+    /// Time left until the report interval allows the next reading
+    fn interval_left(&self) -> MillisDurationU32 {
+        let interval = u32::from(self.settings.report_interval).millis();
+        self.reported_at
+            .and_then(|at| self.interface.uptime().checked_sub(at))
+            .and_then(|elapsed| interval.checked_sub(elapsed))
+            .unwrap_or(0.millis())
+    }
+
+    /// Event of the next report, if one is due
+    fn due(&self) -> Option<SensorEvent> {
+        if !self.settings.is_reporting() {
+            return None;
+        }
+        if self.event.is_some() {
+            return self.event;
+        }
+        (self.changed() && self.interval_left().ticks() == 0).then_some(SensorEvent::DataUpdated)
+    }
+}
+
+impl<'a, B: UsbBus> DeviceClass<'a> for TemperatureSensor<'a, B> {
+    type I = Interface<'a, B, InBytes8, OutNone, ReportSingle>;
+
+    fn interface(&mut self) -> &mut Self::I {
+        &mut self.interface
+    }
+
+    /// Restores the initial properties, keeping the sensor state and last reading
+    fn reset(&mut self) {
+        self.settings = self.initial;
+        self.store_properties();
+        self.reported = None;
+        self.reported_at = None;
+        self.event = None;
+    }
+
+    fn tick(&mut self) -> Result<(), UsbHidError> {
+        self.sync_settings();
+        let Some(event) = self.due() else {
+            return Ok(());
+        };
+        let temperature = self.temperature.unwrap_or_default();
+        let report = TemperatureReport {
+            state: self.state,
+            event,
+            temperature,
+        };
+        match self
+            .interface
+            .write_report(&report.to_bytes())
+            .map_err(UsbHidError::from)
+        {
+            Ok(_) => {
+                self.reported = self.temperature;
+                self.event = None;
+                self.reported_at = Some(self.interface.uptime());
+                Ok(())
+            }
+            Err(UsbHidError::WouldBlock) => Ok(()),
+            Err(e) => Err(e),
+        }
+    }
+
+    fn next_deadline(&mut self) -> Option<MillisDurationU32> {
+        // Properties set by the host are only taken in by `tick`
+        if self.host_settings() != Some(self.settings) {
+            return Some(1.millis());
+        }
+        if !self.settings.is_reporting() {
+            return None;
+        }
+        if self.event.is_some() {
+            return Some(1.millis());
+        }
+        self.changed().then(|| self.interval_left().max(1.millis()))
+    }
+}
+
+pub struct TemperatureSensorConfig<'a> {
+    interface: InterfaceConfig<'a, InBytes8, OutNone, ReportSingle>,
+    connection: ConnectionType,
+    settings: SensorSettings,
+}
+
+impl Default for TemperatureSensorConfig<'_> {
+    fn default() -> Self {
+        let properties =
+            SensorSettings::default().to_bytes(ConnectionType::default(), SensorState::default());
+        Self::new(
+            unwrap!(unwrap!(unwrap!(InterfaceBuilder::new_static(
+                TEMPERATURE_SENSOR_REPORT_DESCRIPTOR
+            ))
+            .boot_device(InterfaceProtocol::None)
+            .description("Temperature Sensor")
+            .feature_report(REPORT_ID, &properties))
+            .in_endpoint(10.millis()))
+            .without_out_endpoint()
+            .build(),
+        )
+    }
+}
+
+impl<'a> TemperatureSensorConfig<'a> {
+    /// `interface` must keep feature report 0, see
+    /// [`InterfaceBuilder::feature_report`](crate::interface::InterfaceBuilder::feature_report)
+    #[must_use]
+    pub fn new(interface: InterfaceConfig<'a, InBytes8, OutNone, ReportSingle>) -> Self {
+        Self {
+            interface,
+            connection: ConnectionType::default(),
+            settings: SensorSettings::default(),
+        }
+    }
+
+    /// How the sensor is connected to the host, defaults to [`ConnectionType::External`]
+    #[must_use]
+    pub fn connection(mut self, connection: ConnectionType) -> Self {
+        self.connection = connection;
+        self
+    }
+
+    /// Properties until the host sets them, and after a bus reset
+    #[must_use]
+    pub fn settings(mut self, settings: SensorSettings) -> Self {
+        self.settings = settings;
+        self
+    }
+}
+
+impl<'a, B: UsbBus + 'a> UsbAllocatable<'a, B> for TemperatureSensorConfig<'a> {
+    type Allocated = TemperatureSensor<'a, B>;
+
+    fn allocate(self, usb_alloc: &'a UsbBusAllocator<B>) -> Self::Allocated {
+        let mut sensor = Self::Allocated {
+            interface: Interface::new(usb_alloc, self.interface),
+            connection: self.connection,
+            initial: self.settings,
+            settings: self.settings,
+            state: SensorState::default(),
+            temperature: None,
+            reported: None,
+            event: None,
+            reported_at: None,
+        };
+        sensor.store_properties();
+        sensor
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::page::Sensor;
+
+    #[test]
+    fn properties_feature_report() {
+        let settings = SensorSettings {
+            reporting_state: ReportingState::ThresholdEvents,
+            power_state: PowerState::LowPower,
+            report_interval: 0x1234,
+            change_sensitivity: 25,
+        };
+        let bytes = settings.to_bytes(ConnectionType::Integrated, SensorState::Ready);
+        assert_eq!(bytes, [0, 2, 2, 1, 0x34, 0x12, 25, 0]);
+        assert_eq!(SensorSettings::from_bytes(&bytes), Some(settings));
+        assert!(settings.is_reporting());
+
+        assert_eq!(SensorSettings::from_bytes(&[0, 6, 2, 1, 0, 0, 0, 0]), None);
+        assert_eq!(SensorSettings::from_bytes(&bytes[..7]), None);
+
+        let off = SensorSettings {
+            power_state: PowerState::PowerOff,
+            ..settings
+        };
+        assert!(!off.is_reporting());
+
+        let report = TemperatureReport {
+            state: SensorState::Ready,
+            event: SensorEvent::DataUpdated,
+            temperature: -1050,
+        };
+        assert_eq!(report.to_bytes(), [1, 3, 0xE6, 0xFB]);
+    }
+
+    #[test]
+    fn descriptor_uses_sensor_page() {
+        let usages: [u16; 6] = [
+            Sensor::PropertySensorConnectionType.into(),
+            Sensor::PropertyReportingState.into(),
+            Sensor::PropertyPowerState.into(),
+            Sensor::EventSensorState.into(),
+            Sensor::PropertyReportInterval.into(),
+            Sensor::DataFieldTemperature.into(),
+        ];
+        for usage in usages {
+            let [low, high] = usage.to_le_bytes();
+            assert!(TEMPERATURE_SENSOR_REPORT_DESCRIPTOR
+                .windows(3)
+                .any(|item| item == [0x0A, low, high]));
+        }
+    }
+}
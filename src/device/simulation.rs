@@ -0,0 +1,455 @@
+//! Simulation controls for home cockpit panels and racing rigs
+//!
+//! [`FlightControls`] reports rudder, trim, throttle, flaps and toe brakes, and [`RacingWheel`]
+//! reports steering, pedals and shifter buttons, as dedicated axes with their usages from the
+//! [`Simulation`](crate::page::Simulation) page, so simulators can tell them apart instead of
+//! seeing generic joystick axes.
+use crate::usb_class::prelude::*;
+use fugit::{ExtU32, MillisDurationU32};
+use usb_device::bus::UsbBus;
+use usb_device::class_prelude::UsbBusAllocator;
+
+/// Flight simulation controls reporting [`FlightControlsReport`]
+///
+/// The application collection is a joystick, as hosts such as Windows only expose joysticks and
+/// gamepads to games, with the axes using Simulation page usages
+#[rustfmt::skip]
+pub const FLIGHT_CONTROLS_REPORT_DESCRIPTOR: &[u8] = &[
+    0x05, 0x01,                   // Usage Page (Generic Desktop),
+    0x09, 0x04,                   // Usage (Joystick),
+    0xA1, 0x01,                   // Collection (Application),
+    0x05, 0x02,                   //   Usage Page (Simulation Controls),
+    0x09, 0xBA,                   //   Usage (Rudder),
+    0x09, 0xB9,                   //   Usage (Elevator Trim),
+    0x09, 0xB1,                   //   Usage (Aileron Trim),
+    0x16, 0x01, 0x80,             //   Logical Minimum (-32767),
+    0x26, 0xFF, 0x7F,             //   Logical Maximum (32767),
+    0x75, 0x10,                   //   Report Size (16),
+    0x95, 0x03,                   //   Report Count (3),
+    0x81, 0x02,                   //   Input (Data, Variable, Absolute),
+    0x09, 0xBB,                   //   Usage (Throttle),
+    0x09, 0xC3,                   //   Usage (Wing Flaps),
+    0x09, 0xBF,                   //   Usage (Toe Brake),
+    0x09, 0xBF,                   //   Usage (Toe Brake),
+    0x15, 0x00,                   //   Logical Minimum (0),
+    0x27, 0xFF, 0xFF, 0x00, 0x00, //   Logical Maximum (65535),
+    0x75, 0x10,                   //   Report Size (16),
+    0x95, 0x04,                   //   Report Count (4),
+    0x81, 0x02,                   //   Input (Data, Variable, Absolute),
+    0xC0,                         // End Collection
+];
+
+/// Report of [`FlightControls`], [`FlightControlsReport::new`] is centred with the throttle
+/// closed, flaps up and brakes released
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Default, Hash)]
+pub struct FlightControlsReport {
+    /// -32767 to 32767, positive yaws right
+    pub rudder: i16,
+    /// -32767 to 32767
+    pub elevator_trim: i16,
+    /// -32767 to 32767
+    pub aileron_trim: i16,
+    pub throttle: u16,
+    pub flaps: u16,
+    pub left_toe_brake: u16,
+    pub right_toe_brake: u16,
+}
+
+impl FlightControlsReport {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            rudder: 0,
+            elevator_trim: 0,
+            aileron_trim: 0,
+            throttle: 0,
+            flaps: 0,
+            left_toe_brake: 0,
+            right_toe_brake: 0,
+        }
+    }
+
+    #[must_use]
+    pub const fn rudder(mut self, rudder: i16) -> Self {
+        self.rudder = rudder;
+        self
+    }
+
+    #[must_use]
+    pub const fn elevator_trim(mut self, elevator_trim: i16) -> Self {
+        self.elevator_trim = elevator_trim;
+        self
+    }
+
+    #[must_use]
+    pub const fn aileron_trim(mut self, aileron_trim: i16) -> Self {
+        self.aileron_trim = aileron_trim;
+        self
+    }
+
+    #[must_use]
+    pub const fn throttle(mut self, throttle: u16) -> Self {
+        self.throttle = throttle;
+        self
+    }
+
+    #[must_use]
+    pub const fn flaps(mut self, flaps: u16) -> Self {
+        self.flaps = flaps;
+        self
+    }
+
+    #[must_use]
+    pub const fn toe_brakes(mut self, left: u16, right: u16) -> Self {
+        self.left_toe_brake = left;
+        self.right_toe_brake = right;
+        self
+    }
+
+    /// Report as sent to the host, signed axes of -32768 are sent as -32767
+    #[must_use]
+    pub fn to_bytes(&self) -> [u8; 14] {
+        let signed = [self.rudder, self.elevator_trim, self.aileron_trim]
+            .map(|axis| axis.max(-i16::MAX).to_le_bytes());
+        let unsigned = [
+            self.throttle,
+            self.flaps,
+            self.left_toe_brake,
+            self.right_toe_brake,
+        ]
+        .map(u16::to_le_bytes);
+
+        let mut data = [0; 14];
+        for (b, axis) in data
+            .chunks_exact_mut(2)
+            .zip(signed.into_iter().chain(unsigned))
+        {
+            b.copy_from_slice(&axis);
+        }
+        data
+    }
+}
+
+/// Flight simulation controls, see [`FLIGHT_CONTROLS_REPORT_DESCRIPTOR`]
+pub struct FlightControls<'a, B: UsbBus> {
+    interface: Interface<'a, B, InBytes16, OutNone, ReportSingle>,
+}
+
+impl<B: UsbBus> FlightControls<'_, B> {
+    pub fn write_report(&mut self, report: &FlightControlsReport) -> Result<(), UsbHidError> {
+        self.interface
+            .write_report(&report.to_bytes())
+            .map(|_| ())
+            .map_err(UsbHidError::from)
+    }
+}
+
+impl<'a, B: UsbBus> DeviceClass<'a> for FlightControls<'a, B> {
+    type I = Interface<'a, B, InBytes16, OutNone, ReportSingle>;
+
+    fn interface(&mut self) -> &mut Self::I {
+        &mut self.interface
+    }
+
+    fn reset(&mut self) {}
+
+    fn tick(&mut self) -> Result<(), UsbHidError> {
+        Ok(())
+    }
+
+    fn next_deadline(&mut self) -> Option<MillisDurationU32> {
+        None
+    }
+}
+
+pub struct FlightControlsConfig<'a> {
+    interface: InterfaceConfig<'a, InBytes16, OutNone, ReportSingle>,
+}
+
+impl Default for FlightControlsConfig<'_> {
+    fn default() -> Self {
+        Self::new(
+            unwrap!(unwrap!(InterfaceBuilder::new_static(
+                FLIGHT_CONTROLS_REPORT_DESCRIPTOR
+            ))
+            .boot_device(InterfaceProtocol::None)
+            .description("Flight Controls")
+            .in_endpoint(10.millis()))
+            .without_out_endpoint()
+            .build(),
+        )
+    }
+}
+
+impl<'a> FlightControlsConfig<'a> {
+    #[must_use]
+    pub fn new(interface: InterfaceConfig<'a, InBytes16, OutNone, ReportSingle>) -> Self {
+        Self { interface }
+    }
+}
+
+impl<'a, B: UsbBus + 'a> UsbAllocatable<'a, B> for FlightControlsConfig<'a> {
+    type Allocated = FlightControls<'a, B>;
+
+    fn allocate(self, usb_alloc: &'a UsbBusAllocator<B>) -> Self::Allocated {
+        Self::Allocated {
+            interface: Interface::new(usb_alloc, self.interface),
+        }
+    }
+}
+
+/// Racing wheel with pedals reporting [`RacingWheelReport`]
+///
+/// As for [`FLIGHT_CONTROLS_REPORT_DESCRIPTOR`] the application collection is a joystick, and
+/// games identify the wheel by the Steering, Accelerator, Brake and Clutch usages. The shifter
+/// buttons are in a Shifter logical collection
+#[rustfmt::skip]
+pub const RACING_WHEEL_REPORT_DESCRIPTOR: &[u8] = &[
+    0x05, 0x01,                   // Usage Page (Generic Desktop),
+    0x09, 0x04,                   // Usage (Joystick),
+    0xA1, 0x01,                   // Collection (Application),
+    0x05, 0x02,                   //   Usage Page (Simulation Controls),
+    0x09, 0xC8,                   //   Usage (Steering),
+    0x16, 0x01, 0x80,             //   Logical Minimum (-32767),
+    0x26, 0xFF, 0x7F,             //   Logical Maximum (32767),
+    0x75, 0x10,                   //   Report Size (16),
+    0x95, 0x01,                   //   Report Count (1),
+    0x81, 0x02,                   //   Input (Data, Variable, Absolute),
+    0x09, 0xC4,                   //   Usage (Accelerator),
+    0x09, 0xC5,                   //   Usage (Brake),
+    0x09, 0xC6,                   //   Usage (Clutch),
+    0x15, 0x00,                   //   Logical Minimum (0),
+    0x27, 0xFF, 0xFF, 0x00, 0x00, //   Logical Maximum (65535),
+    0x75, 0x10,                   //   Report Size (16),
+    0x95, 0x03,                   //   Report Count (3),
+    0x81, 0x02,                   //   Input (Data, Variable, Absolute),
+    0x09, 0xC7,                   //   Usage (Shifter),
+    0xA1, 0x02,                   //   Collection (Logical),
+    0x05, 0x09,                   //     Usage Page (Button),
+    0x19, 0x01,                   //     Usage Minimum (1),
+    0x29, 0x10,                   //     Usage Maximum (16),
+    0x15, 0x00,                   //     Logical Minimum (0),
+    0x25, 0x01,                   //     Logical Maximum (1),
+    0x75, 0x01,                   //     Report Size (1),
+    0x95, 0x10,                   //     Report Count (16),
+    0x81, 0x02,                   //     Input (Data, Variable, Absolute),
+    0xC0,                         //   End Collection
+    0xC0,                         // End Collection
+];
+
+/// Report of [`RacingWheel`], [`RacingWheelReport::new`] is centred with the pedals released
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Default, Hash)]
+pub struct RacingWheelReport {
+    /// -32767 to 32767, positive steers right
+    pub steering: i16,
+    pub accelerator: u16,
+    pub brake: u16,
+    pub clutch: u16,
+    /// Shifter button `n` is bit `n - 1`, e.g. gears of an H-pattern shifter and paddles
+    pub buttons: u16,
+}
+
+impl RacingWheelReport {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            steering: 0,
+            accelerator: 0,
+            brake: 0,
+            clutch: 0,
+            buttons: 0,
+        }
+    }
+
+    #[must_use]
+    pub const fn steering(mut self, steering: i16) -> Self {
+        self.steering = steering;
+        self
+    }
+
+    #[must_use]
+    pub const fn pedals(mut self, accelerator: u16, brake: u16, clutch: u16) -> Self {
+        self.accelerator = accelerator;
+        self.brake = brake;
+        self.clutch = clutch;
+        self
+    }
+
+    #[must_use]
+    pub const fn buttons(mut self, buttons: u16) -> Self {
+        self.buttons = buttons;
+        self
+    }
+
+    /// Press or release shifter button `n`, 1 to 16. Other values are ignored
+    #[must_use]
+    pub const fn button(mut self, n: u8, pressed: bool) -> Self {
+        if n >= 1 && n <= 16 {
+            let mask = 1 << (n - 1);
+            if pressed {
+                self.buttons |= mask;
+            } else {
+                self.buttons &= !mask;
+            }
+        }
+        self
+    }
+
+    /// Report as sent to the host, a steering value of -32768 is sent as -32767
+    #[must_use]
+    pub fn to_bytes(&self) -> [u8; 10] {
+        let axes = [
+            self.steering.max(-i16::MAX).to_le_bytes(),
+            self.accelerator.to_le_bytes(),
+            self.brake.to_le_bytes(),
+            self.clutch.to_le_bytes(),
+            self.buttons.to_le_bytes(),
+        ];
+
+        let mut data = [0; 10];
+        for (b, axis) in data.chunks_exact_mut(2).zip(axes) {
+            b.copy_from_slice(&axis);
+        }
+        data
+    }
+}
+
+/// Racing wheel with pedals, see [`RACING_WHEEL_REPORT_DESCRIPTOR`]
+pub struct RacingWheel<'a, B: UsbBus> {
+    interface: Interface<'a, B, InBytes16, OutNone, ReportSingle>,
+}
+
+impl<B: UsbBus> RacingWheel<'_, B> {
+    pub fn write_report(&mut self, report: &RacingWheelReport) -> Result<(), UsbHidError> {
+        self.interface
+            .write_report(&report.to_bytes())
+            .map(|_| ())
+            .map_err(UsbHidError::from)
+    }
+}
+
+impl<'a, B: UsbBus> DeviceClass<'a> for RacingWheel<'a, B> {
+    type I = Interface<'a, B, InBytes16, OutNone, ReportSingle>;
+
+    fn interface(&mut self) -> &mut Self::I {
+        &mut self.interface
+    }
+
+    fn reset(&mut self) {}
+
+    fn tick(&mut self) -> Result<(), UsbHidError> {
+        Ok(())
+    }
+
+    fn next_deadline(&mut self) -> Option<MillisDurationU32> {
+        None
+    }
+}
+
+pub struct RacingWheelConfig<'a> {
+    interface: InterfaceConfig<'a, InBytes16, OutNone, ReportSingle>,
+}
+
+impl Default for RacingWheelConfig<'_> {
+    fn default() -> Self {
+        Self::new(
+            unwrap!(
+                unwrap!(InterfaceBuilder::new_static(RACING_WHEEL_REPORT_DESCRIPTOR))
+                    .boot_device(InterfaceProtocol::None)
+                    .description("Racing Wheel")
+                    .in_endpoint(10.millis())
+            )
+            .without_out_endpoint()
+            .build(),
+        )
+    }
+}
+
+impl<'a> RacingWheelConfig<'a> {
+    #[must_use]
+    pub fn new(interface: InterfaceConfig<'a, InBytes16, OutNone, ReportSingle>) -> Self {
+        Self { interface }
+    }
+}
+
+impl<'a, B: UsbBus + 'a> UsbAllocatable<'a, B> for RacingWheelConfig<'a> {
+    type Allocated = RacingWheel<'a, B>;
+
+    fn allocate(self, usb_alloc: &'a UsbBusAllocator<B>) -> Self::Allocated {
+        Self::Allocated {
+            interface: Interface::new(usb_alloc, self.interface),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::page::Simulation;
+
+    #[test]
+    fn flight_controls_report_layout() {
+        let report = FlightControlsReport::new()
+            .rudder(i16::MIN)
+            .elevator_trim(-1)
+            .aileron_trim(0x1234)
+            .throttle(0xFFFF)
+            .flaps(0x8000)
+            .toe_brakes(1, 2);
+        assert_eq!(
+            report.to_bytes(),
+            [0x01, 0x80, 0xFF, 0xFF, 0x34, 0x12, 0xFF, 0xFF, 0x00, 0x80, 0x01, 0x00, 0x02, 0x00]
+        );
+        assert_eq!(FlightControlsReport::default().to_bytes(), [0; 14]);
+    }
+
+    #[test]
+    fn descriptor_uses_simulation_page() {
+        let usages: [u8; 7] = [
+            Simulation::Rudder.into(),
+            Simulation::ElevatorTrim.into(),
+            Simulation::AileronTrim.into(),
+            Simulation::Throttle.into(),
+            Simulation::WingFlaps.into(),
+            Simulation::ToeBrake.into(),
+            Simulation::ToeBrake.into(),
+        ];
+        let descriptor = FLIGHT_CONTROLS_REPORT_DESCRIPTOR;
+        let found = descriptor
+            .windows(2)
+            .filter(|item| item[0] == 0x09 && item[1] != 0x04)
+            .map(|item| item[1]);
+        assert!(found.eq(usages));
+    }
+
+    #[test]
+    fn racing_wheel_report_layout() {
+        let report = RacingWheelReport::new()
+            .steering(i16::MIN)
+            .pedals(0xFFFF, 0x1234, 1)
+            .button(1, true)
+            .button(16, true)
+            .button(17, true);
+        assert_eq!(
+            report.to_bytes(),
+            [0x01, 0x80, 0xFF, 0xFF, 0x34, 0x12, 0x01, 0x00, 0x01, 0x80]
+        );
+        assert_eq!(report.button(16, false).buttons, 1);
+        assert_eq!(RacingWheelReport::default().to_bytes(), [0; 10]);
+
+        let usages: [u8; 5] = [
+            Simulation::Steering.into(),
+            Simulation::Accelerator.into(),
+            Simulation::Brake.into(),
+            Simulation::Clutch.into(),
+            Simulation::Shifter.into(),
+        ];
+        for usage in usages {
+            assert!(RACING_WHEEL_REPORT_DESCRIPTOR
+                .windows(2)
+                .any(|item| item == [0x09, usage]));
+        }
+    }
+}
@@ -0,0 +1,199 @@
+//! System power controls: power down, sleep and wake up
+//!
+//! [`SystemControl`] sends the system control usages of the Generic Desktop page as one-shot
+//! controls: every call presses the usage in a single report, and the release follows from
+//! `tick`. This suits lid switch dongles and power buttons, which act on the host once per event
+//! rather than while held.
+//!
+//! A stray power down or sleep turns the host off, so buttons mapped to them are best confirmed
+//! first, see [`ConfirmationGuard`](crate::device::consumer::guard::ConfirmationGuard).
+//!
+//! ```rust,no_run
+//! # use xous_usb_hid::device::system_control::*;
+//! # use usb_device::bus::UsbBus;
+//! # fn lid_closed<B: UsbBus>(system: &mut SystemControl<'_, B>) {
+//! system.sleep_host().ok();
+//! # }
+//! ```
+
+use crate::page::Desktop;
+use crate::usb_class::prelude::*;
+use fugit::{ExtU32, MillisDurationU32};
+use usb_device::bus::UsbBus;
+use usb_device::class_prelude::UsbBusAllocator;
+
+/// System control descriptor
+///
+/// * System Control: System Power Down, System Sleep and System Wake Up bits (1 byte)
+#[rustfmt::skip]
+pub const SYSTEM_CONTROL_REPORT_DESCRIPTOR: &[u8] = &[
+    0x05, 0x01, // Usage Page (Generic Desktop),
+    0x09, 0x80, // Usage (System Control),
+    0xA1, 0x01, // Collection (Application),
+    0x19, 0x81, //   Usage Minimum (System Power Down),
+    0x29, 0x83, //   Usage Maximum (System Wake Up),
+    0x15, 0x00, //   Logical Minimum (0),
+    0x25, 0x01, //   Logical Maximum (1),
+    0x75, 0x01, //   Report Size (1),
+    0x95, 0x03, //   Report Count (3),
+    0x81, 0x06, //   Input (Data, Variable, Relative),
+    0x95, 0x05, //   Report Count (5),
+    0x81, 0x03, //   Input (Constant, Variable, Absolute),
+    0xC0,       // End Collection
+];
+
+/// Report bit of a system control usage, `None` for other usages
+fn usage_bit(usage: Desktop) -> Option<u8> {
+    match usage {
+        Desktop::SystemPowerDown => Some(0b001),
+        Desktop::SystemSleep => Some(0b010),
+        Desktop::SystemWakeUp => Some(0b100),
+        _ => None,
+    }
+}
+
+/// System control, see the [module docs](self)
+///
+/// **Note:** Releases are sent by [`UsbHidClass::tick()`], which must be called every 1ms.
+pub struct SystemControl<'a, B: UsbBus> {
+    interface: Interface<'a, B, InBytes8, OutNone, ReportSingle>,
+    pressed: u8,
+    release: bool,
+}
+
+impl<B: UsbBus> SystemControl<'_, B> {
+    /// Press `usage` once, one of [`Desktop::SystemPowerDown`], [`Desktop::SystemSleep`] and
+    /// [`Desktop::SystemWakeUp`], returns [`UsbHidError::SerializationError`] for other usages
+    ///
+    /// Returns [`UsbHidError::WouldBlock`] if the press could not be sent yet, it will be sent
+    /// by `tick`
+    pub fn send(&mut self, usage: Desktop) -> Result<(), UsbHidError> {
+        let bit = usage_bit(usage).ok_or(UsbHidError::SerializationError)?;
+        self.pressed |= bit;
+        self.flush()
+    }
+
+    pub fn power_down_host(&mut self) -> Result<(), UsbHidError> {
+        self.send(Desktop::SystemPowerDown)
+    }
+
+    pub fn sleep_host(&mut self) -> Result<(), UsbHidError> {
+        self.send(Desktop::SystemSleep)
+    }
+
+    /// Wake a host that is asleep with the bus still running. A suspended bus has to be resumed
+    /// with a remote wakeup first, for hosts that allowed it
+    pub fn wake_host(&mut self) -> Result<(), UsbHidError> {
+        self.send(Desktop::SystemWakeUp)
+    }
+
+    /// `true` until the last press has been sent and released
+    #[must_use]
+    pub fn is_busy(&self) -> bool {
+        self.pressed != 0 || self.release
+    }
+
+    /// Send a pending press, or the release following the last press on a later call, so that
+    /// the host reads the press before the release
+    fn flush(&mut self) -> Result<(), UsbHidError> {
+        if self.pressed != 0 {
+            self.write(self.pressed)?;
+            self.pressed = 0;
+            self.release = true;
+        } else if self.release {
+            self.write(0)?;
+            self.release = false;
+        }
+        Ok(())
+    }
+
+    fn write(&mut self, bits: u8) -> Result<(), UsbHidError> {
+        self.interface
+            .write_report(&[bits])
+            .map(|_| ())
+            .map_err(UsbHidError::from)
+    }
+}
+
+impl<'a, B: UsbBus> DeviceClass<'a> for SystemControl<'a, B> {
+    type I = Interface<'a, B, InBytes8, OutNone, ReportSingle>;
+
+    fn interface(&mut self) -> &mut Self::I {
+        &mut self.interface
+    }
+
+    fn reset(&mut self) {
+        self.pressed = 0;
+        self.release = false;
+    }
+
+    fn tick(&mut self) -> Result<(), UsbHidError> {
+        match self.flush() {
+            Err(UsbHidError::WouldBlock) => Ok(()),
+            r => r,
+        }
+    }
+
+    fn next_deadline(&mut self) -> Option<MillisDurationU32> {
+        self.is_busy().then_some(1.millis())
+    }
+}
+
+pub struct SystemControlConfig<'a> {
+    interface: InterfaceConfig<'a, InBytes8, OutNone, ReportSingle>,
+}
+
+impl Default for SystemControlConfig<'_> {
+    fn default() -> Self {
+        Self::new(
+            unwrap!(unwrap!(InterfaceBuilder::new_static(
+                SYSTEM_CONTROL_REPORT_DESCRIPTOR
+            ))
+            .description("System Control")
+            .in_endpoint(10.millis()))
+            .without_out_endpoint()
+            .build(),
+        )
+    }
+}
+
+impl<'a> SystemControlConfig<'a> {
+    #[must_use]
+    pub fn new(interface: InterfaceConfig<'a, InBytes8, OutNone, ReportSingle>) -> Self {
+        Self { interface }
+    }
+}
+
+impl<'a, B: UsbBus + 'a> UsbAllocatable<'a, B> for SystemControlConfig<'a> {
+    type Allocated = SystemControl<'a, B>;
+
+    fn allocate(self, usb_alloc: &'a UsbBusAllocator<B>) -> Self::Allocated {
+        Self::Allocated {
+            interface: Interface::new(usb_alloc, self.interface),
+            pressed: 0,
+            release: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn usage_bits_follow_descriptor() {
+        let first = u8::from(Desktop::SystemPowerDown);
+        assert!(SYSTEM_CONTROL_REPORT_DESCRIPTOR
+            .windows(2)
+            .any(|item| item == [0x19, first]));
+        for usage in [
+            Desktop::SystemPowerDown,
+            Desktop::SystemSleep,
+            Desktop::SystemWakeUp,
+        ] {
+            assert_eq!(usage_bit(usage), Some(1 << (u8::from(usage) - first)));
+        }
+        assert_eq!(usage_bit(Desktop::SystemControl), None);
+        assert_eq!(usage_bit(Desktop::SystemMenuExit), None);
+    }
+}
@@ -0,0 +1,715 @@
+//! Windows Precision Touchpads
+//!
+//! [`Touchpad`] implements the precision touchpad collections: up to [`CONTACTS_PER_REPORT`]
+//! fingers with confidence and tip switch bits, a scan time, the contact count and the pad
+//! button, along with the device capabilities, certification, input mode and function switch
+//! feature reports. A mouse collection is included for hosts that don't switch the touchpad to
+//! [`InputMode::Touchpad`], such as older Windows versions and boot environments.
+//!
+//! The input mode starts as [`InputMode::Mouse`], and after a bus reset, and
+//! [`Touchpad::write_report`] sends the report the host asked for: a multitouch report, or a
+//! relative mouse report following the first confident contact. While the host has turned the
+//! surface or button off with the function switch report, contacts or the button are left out.
+//!
+//! Windows only enables precision touchpad mode with a certification blob issued by Microsoft for
+//! the device. [`TouchpadConfig::default`] answers with an empty blob, use
+//! [`TouchpadConfig::new`] with
+//! [`InterfaceBuilder::static_feature_report`](crate::interface::InterfaceBuilder::static_feature_report)
+//! to answer with the blob of the device.
+//!
+//! ```rust
+//! # use xous_usb_hid::device::touchpad::*;
+//! let mut report = TouchpadReport::new(0x1234);
+//! report.push(TouchpadContact::new(0, 1000, 600)).unwrap();
+//! report.push(TouchpadContact::new(1, 1200, 700).palm()).unwrap();
+//! report.button = true;
+//! // touchpad.write_report(&report)
+//! assert_eq!(report.to_bytes()[TOUCHPAD_REPORT_LEN - 2], 2);
+//! ```
+
+use crate::usb_class::prelude::*;
+use crate::usb_class::BuilderResult;
+use fugit::{ExtU32, MillisDurationU32};
+use heapless::Vec;
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use usb_device::bus::UsbBus;
+use usb_device::class_prelude::UsbBusAllocator;
+
+/// Report ID of the touchpad input report
+pub const TOUCHPAD_REPORT_ID: u8 = 0x01;
+/// Report ID of the device capabilities feature report, with the Contact Count Maximum and the
+/// [`PadType`]
+pub const CAPABILITIES_REPORT_ID: u8 = 0x02;
+/// Report ID of the certification blob feature report
+pub const CERTIFICATION_REPORT_ID: u8 = 0x03;
+/// Report ID of the input mode feature report, see [`InputMode`]
+pub const INPUT_MODE_REPORT_ID: u8 = 0x04;
+/// Report ID of the function switch feature report, turning surface and button reporting on and
+/// off
+pub const FUNCTION_SWITCH_REPORT_ID: u8 = 0x05;
+/// Report ID of the mouse input report
+pub const MOUSE_REPORT_ID: u8 = 0x06;
+
+/// Contacts carried by a touchpad report, and the Contact Count Maximum
+pub const CONTACTS_PER_REPORT: usize = 5;
+
+/// Length of the touchpad report, including the report ID
+pub const TOUCHPAD_REPORT_LEN: usize = 1 + CONTACT_LEN * CONTACTS_PER_REPORT + 4;
+
+/// Largest X coordinate, 300 per centimetre over 10 cm
+pub const MAX_X: u16 = 3000;
+/// Largest Y coordinate, 300 per centimetre over 6 cm
+pub const MAX_Y: u16 = 1800;
+
+/// Touchpad motion per mouse count in [`InputMode::Mouse`]
+pub const MOUSE_DIVISOR: i32 = 4;
+
+/// Length of the certification blob feature report, including the report ID
+pub const CERTIFICATION_REPORT_LEN: usize = 257;
+
+/// Certification feature report answered by [`TouchpadConfig::default`], without a blob
+pub static EMPTY_CERTIFICATION_REPORT: [u8; CERTIFICATION_REPORT_LEN] = {
+    let mut report = [0; CERTIFICATION_REPORT_LEN];
+    report[0] = CERTIFICATION_REPORT_ID;
+    report
+};
+
+const CONTACT_LEN: usize = 6;
+
+/// Precision touchpad descriptor
+///
+/// * Report ID 1, Touch Pad: [`CONTACTS_PER_REPORT`] fingers with Touch Valid (confidence) and
+///   Tip Switch bits, a Contact Identifier and X and Y up to [`MAX_X`] and [`MAX_Y`], 16 bits
+///   each, the Scan Time in 100 µs units, the Contact Count and Button 1 (35 bytes)
+/// * Report ID 2, Contact Count Maximum and Pad Type feature (2 bytes)
+/// * Report ID 3, certification blob feature (257 bytes)
+/// * Report ID 4, Device Configuration: Device Mode (input mode) feature (2 bytes)
+/// * Report ID 5, Surface Switch and Button Switch feature (2 bytes)
+/// * Report ID 6, Mouse: 2 buttons and relative X and Y (4 bytes)
+#[rustfmt::skip]
+pub const TOUCHPAD_REPORT_DESCRIPTOR: &[u8] = &[
+    0x05, 0x0D,                   // Usage Page (Digitizer),
+    0x09, 0x05,                   // Usage (Touch Pad),
+    0xA1, 0x01,                   // Collection (Application),
+    0x85, 0x01,                   //   Report ID (1),
+    0x09, 0x22,                   //   Usage (Finger),
+    0xA1, 0x02,                   //   Collection (Logical),
+    0x09, 0x47,                   //     Usage (Touch Valid),
+    0x09, 0x42,                   //     Usage (Tip Switch),
+    0x15, 0x00,                   //     Logical Minimum (0),
+    0x25, 0x01,                   //     Logical Maximum (1),
+    0x75, 0x01,                   //     Report Size (1),
+    0x95, 0x02,                   //     Report Count (2),
+    0x81, 0x02,                   //     Input (Data, Variable, Absolute),
+    0x95, 0x06,                   //     Report Count (6),
+    0x81, 0x03,                   //     Input (Constant, Variable, Absolute),
+    0x09, 0x51,                   //     Usage (Contact Identifier),
+    0x25, 0x7F,                   //     Logical Maximum (127),
+    0x75, 0x08,                   //     Report Size (8),
+    0x95, 0x01,                   //     Report Count (1),
+    0x81, 0x02,                   //     Input (Data, Variable, Absolute),
+    0x05, 0x01,                   //     Usage Page (Generic Desktop),
+    0x09, 0x30,                   //     Usage (X),
+    0x26, 0xB8, 0x0B,             //     Logical Maximum (3000),
+    0x55, 0x0E,                   //     Unit Exponent (-2),
+    0x65, 0x11,                   //     Unit (Centimeter),
+    0x46, 0xE8, 0x03,             //     Physical Maximum (1000),
+    0x75, 0x10,                   //     Report Size (16),
+    0x81, 0x02,                   //     Input (Data, Variable, Absolute),
+    0x09, 0x31,                   //     Usage (Y),
+    0x26, 0x08, 0x07,             //     Logical Maximum (1800),
+    0x46, 0x58, 0x02,             //     Physical Maximum (600),
+    0x81, 0x02,                   //     Input (Data, Variable, Absolute),
+    0x45, 0x00,                   //     Physical Maximum (0),
+    0x55, 0x00,                   //     Unit Exponent (0),
+    0x65, 0x00,                   //     Unit (None),
+    0x05, 0x0D,                   //     Usage Page (Digitizer),
+    0xC0,                         //   End Collection,
+    0x09, 0x22,                   //   Usage (Finger),
+    0xA1, 0x02,                   //   Collection (Logical),
+    0x09, 0x47,                   //     Usage (Touch Valid),
+    0x09, 0x42,                   //     Usage (Tip Switch),
+    0x15, 0x00,                   //     Logical Minimum (0),
+    0x25, 0x01,                   //     Logical Maximum (1),
+    0x75, 0x01,                   //     Report Size (1),
+    0x95, 0x02,                   //     Report Count (2),
+    0x81, 0x02,                   //     Input (Data, Variable, Absolute),
+    0x95, 0x06,                   //     Report Count (6),
+    0x81, 0x03,                   //     Input (Constant, Variable, Absolute),
+    0x09, 0x51,                   //     Usage (Contact Identifier),
+    0x25, 0x7F,                   //     Logical Maximum (127),
+    0x75, 0x08,                   //     Report Size (8),
+    0x95, 0x01,                   //     Report Count (1),
+    0x81, 0x02,                   //     Input (Data, Variable, Absolute),
+    0x05, 0x01,                   //     Usage Page (Generic Desktop),
+    0x09, 0x30,                   //     Usage (X),
+    0x26, 0xB8, 0x0B,             //     Logical Maximum (3000),
+    0x55, 0x0E,                   //     Unit Exponent (-2),
+    0x65, 0x11,                   //     Unit (Centimeter),
+    0x46, 0xE8, 0x03,             //     Physical Maximum (1000),
+    0x75, 0x10,                   //     Report Size (16),
+    0x81, 0x02,                   //     Input (Data, Variable, Absolute),
+    0x09, 0x31,                   //     Usage (Y),
+    0x26, 0x08, 0x07,             //     Logical Maximum (1800),
+    0x46, 0x58, 0x02,             //     Physical Maximum (600),
+    0x81, 0x02,                   //     Input (Data, Variable, Absolute),
+    0x45, 0x00,                   //     Physical Maximum (0),
+    0x55, 0x00,                   //     Unit Exponent (0),
+    0x65, 0x00,                   //     Unit (None),
+    0x05, 0x0D,                   //     Usage Page (Digitizer),
+    0xC0,                         //   End Collection,
+    0x09, 0x22,                   //   Usage (Finger),
+    0xA1, 0x02,                   //   Collection (Logical),
+    0x09, 0x47,                   //     Usage (Touch Valid),
+    0x09, 0x42,                   //     Usage (Tip Switch),
+    0x15, 0x00,                   //     Logical Minimum (0),
+    0x25, 0x01,                   //     Logical Maximum (1),
+    0x75, 0x01,                   //     Report Size (1),
+    0x95, 0x02,                   //     Report Count (2),
+    0x81, 0x02,                   //     Input (Data, Variable, Absolute),
+    0x95, 0x06,                   //     Report Count (6),
+    0x81, 0x03,                   //     Input (Constant, Variable, Absolute),
+    0x09, 0x51,                   //     Usage (Contact Identifier),
+    0x25, 0x7F,                   //     Logical Maximum (127),
+    0x75, 0x08,                   //     Report Size (8),
+    0x95, 0x01,                   //     Report Count (1),
+    0x81, 0x02,                   //     Input (Data, Variable, Absolute),
+    0x05, 0x01,                   //     Usage Page (Generic Desktop),
+    0x09, 0x30,                   //     Usage (X),
+    0x26, 0xB8, 0x0B,             //     Logical Maximum (3000),
+    0x55, 0x0E,                   //     Unit Exponent (-2),
+    0x65, 0x11,                   //     Unit (Centimeter),
+    0x46, 0xE8, 0x03,             //     Physical Maximum (1000),
+    0x75, 0x10,                   //     Report Size (16),
+    0x81, 0x02,                   //     Input (Data, Variable, Absolute),
+    0x09, 0x31,                   //     Usage (Y),
+    0x26, 0x08, 0x07,             //     Logical Maximum (1800),
+    0x46, 0x58, 0x02,             //     Physical Maximum (600),
+    0x81, 0x02,                   //     Input (Data, Variable, Absolute),
+    0x45, 0x00,                   //     Physical Maximum (0),
+    0x55, 0x00,                   //     Unit Exponent (0),
+    0x65, 0x00,                   //     Unit (None),
+    0x05, 0x0D,                   //     Usage Page (Digitizer),
+    0xC0,                         //   End Collection,
+    0x09, 0x22,                   //   Usage (Finger),
+    0xA1, 0x02,                   //   Collection (Logical),
+    0x09, 0x47,                   //     Usage (Touch Valid),
+    0x09, 0x42,                   //     Usage (Tip Switch),
+    0x15, 0x00,                   //     Logical Minimum (0),
+    0x25, 0x01,                   //     Logical Maximum (1),
+    0x75, 0x01,                   //     Report Size (1),
+    0x95, 0x02,                   //     Report Count (2),
+    0x81, 0x02,                   //     Input (Data, Variable, Absolute),
+    0x95, 0x06,                   //     Report Count (6),
+    0x81, 0x03,                   //     Input (Constant, Variable, Absolute),
+    0x09, 0x51,                   //     Usage (Contact Identifier),
+    0x25, 0x7F,                   //     Logical Maximum (127),
+    0x75, 0x08,                   //     Report Size (8),
+    0x95, 0x01,                   //     Report Count (1),
+    0x81, 0x02,                   //     Input (Data, Variable, Absolute),
+    0x05, 0x01,                   //     Usage Page (Generic Desktop),
+    0x09, 0x30,                   //     Usage (X),
+    0x26, 0xB8, 0x0B,             //     Logical Maximum (3000),
+    0x55, 0x0E,                   //     Unit Exponent (-2),
+    0x65, 0x11,                   //     Unit (Centimeter),
+    0x46, 0xE8, 0x03,             //     Physical Maximum (1000),
+    0x75, 0x10,                   //     Report Size (16),
+    0x81, 0x02,                   //     Input (Data, Variable, Absolute),
+    0x09, 0x31,                   //     Usage (Y),
+    0x26, 0x08, 0x07,             //     Logical Maximum (1800),
+    0x46, 0x58, 0x02,             //     Physical Maximum (600),
+    0x81, 0x02,                   //     Input (Data, Variable, Absolute),
+    0x45, 0x00,                   //     Physical Maximum (0),
+    0x55, 0x00,                   //     Unit Exponent (0),
+    0x65, 0x00,                   //     Unit (None),
+    0x05, 0x0D,                   //     Usage Page (Digitizer),
+    0xC0,                         //   End Collection,
+    0x09, 0x22,                   //   Usage (Finger),
+    0xA1, 0x02,                   //   Collection (Logical),
+    0x09, 0x47,                   //     Usage (Touch Valid),
+    0x09, 0x42,                   //     Usage (Tip Switch),
+    0x15, 0x00,                   //     Logical Minimum (0),
+    0x25, 0x01,                   //     Logical Maximum (1),
+    0x75, 0x01,                   //     Report Size (1),
+    0x95, 0x02,                   //     Report Count (2),
+    0x81, 0x02,                   //     Input (Data, Variable, Absolute),
+    0x95, 0x06,                   //     Report Count (6),
+    0x81, 0x03,                   //     Input (Constant, Variable, Absolute),
+    0x09, 0x51,                   //     Usage (Contact Identifier),
+    0x25, 0x7F,                   //     Logical Maximum (127),
+    0x75, 0x08,                   //     Report Size (8),
+    0x95, 0x01,                   //     Report Count (1),
+    0x81, 0x02,                   //     Input (Data, Variable, Absolute),
+    0x05, 0x01,                   //     Usage Page (Generic Desktop),
+    0x09, 0x30,                   //     Usage (X),
+    0x26, 0xB8, 0x0B,             //     Logical Maximum (3000),
+    0x55, 0x0E,                   //     Unit Exponent (-2),
+    0x65, 0x11,                   //     Unit (Centimeter),
+    0x46, 0xE8, 0x03,             //     Physical Maximum (1000),
+    0x75, 0x10,                   //     Report Size (16),
+    0x81, 0x02,                   //     Input (Data, Variable, Absolute),
+    0x09, 0x31,                   //     Usage (Y),
+    0x26, 0x08, 0x07,             //     Logical Maximum (1800),
+    0x46, 0x58, 0x02,             //     Physical Maximum (600),
+    0x81, 0x02,                   //     Input (Data, Variable, Absolute),
+    0x45, 0x00,                   //     Physical Maximum (0),
+    0x55, 0x00,                   //     Unit Exponent (0),
+    0x65, 0x00,                   //     Unit (None),
+    0x05, 0x0D,                   //     Usage Page (Digitizer),
+    0xC0,                         //   End Collection,
+    0x09, 0x56,                   //   Usage (Scan Time),
+    0x27, 0xFF, 0xFF, 0x00, 0x00, //   Logical Maximum (65535),
+    0x55, 0x0C,                   //   Unit Exponent (-4),
+    0x66, 0x01, 0x10,             //   Unit (Seconds),
+    0x47, 0xFF, 0xFF, 0x00, 0x00, //   Physical Maximum (65535),
+    0x75, 0x10,                   //   Report Size (16),
+    0x81, 0x02,                   //   Input (Data, Variable, Absolute),
+    0x45, 0x00,                   //   Physical Maximum (0),
+    0x55, 0x00,                   //   Unit Exponent (0),
+    0x65, 0x00,                   //   Unit (None),
+    0x09, 0x54,                   //   Usage (Contact Count),
+    0x25, 0x7F,                   //   Logical Maximum (127),
+    0x75, 0x08,                   //   Report Size (8),
+    0x81, 0x02,                   //   Input (Data, Variable, Absolute),
+    0x05, 0x09,                   //   Usage Page (Button),
+    0x09, 0x01,                   //   Usage (Button 1),
+    0x25, 0x01,                   //   Logical Maximum (1),
+    0x75, 0x01,                   //   Report Size (1),
+    0x81, 0x02,                   //   Input (Data, Variable, Absolute),
+    0x95, 0x07,                   //   Report Count (7),
+    0x81, 0x03,                   //   Input (Constant, Variable, Absolute),
+    0x05, 0x0D,                   //   Usage Page (Digitizer),
+    0x85, 0x02,                   //   Report ID (2),
+    0x09, 0x55,                   //   Usage (Contact Count Maximum),
+    0x09, 0x59,                   //   Usage (Pad Type),
+    0x25, 0x0F,                   //   Logical Maximum (15),
+    0x75, 0x04,                   //   Report Size (4),
+    0x95, 0x02,                   //   Report Count (2),
+    0xB1, 0x02,                   //   Feature (Data, Variable, Absolute),
+    0x06, 0x00, 0xFF,             //   Usage Page (Vendor Defined 0xFF00),
+    0x85, 0x03,                   //   Report ID (3),
+    0x09, 0xC5,                   //   Usage (0xC5),
+    0x26, 0xFF, 0x00,             //   Logical Maximum (255),
+    0x75, 0x08,                   //   Report Size (8),
+    0x96, 0x00, 0x01,             //   Report Count (256),
+    0xB1, 0x02,                   //   Feature (Data, Variable, Absolute),
+    0xC0,                         // End Collection,
+    0x05, 0x0D,                   // Usage Page (Digitizer),
+    0x09, 0x0E,                   // Usage (Device Configuration),
+    0xA1, 0x01,                   // Collection (Application),
+    0x85, 0x04,                   //   Report ID (4),
+    0x09, 0x22,                   //   Usage (Finger),
+    0xA1, 0x02,                   //   Collection (Logical),
+    0x09, 0x52,                   //     Usage (Device Mode),
+    0x15, 0x00,                   //     Logical Minimum (0),
+    0x25, 0x0A,                   //     Logical Maximum (10),
+    0x75, 0x08,                   //     Report Size (8),
+    0x95, 0x01,                   //     Report Count (1),
+    0xB1, 0x02,                   //     Feature (Data, Variable, Absolute),
+    0xC0,                         //   End Collection,
+    0x09, 0x22,                   //   Usage (Finger),
+    0xA1, 0x00,                   //   Collection (Physical),
+    0x85, 0x05,                   //     Report ID (5),
+    0x09, 0x57,                   //     Usage (Surface Switch),
+    0x09, 0x58,                   //     Usage (Button Switch),
+    0x25, 0x01,                   //     Logical Maximum (1),
+    0x75, 0x01,                   //     Report Size (1),
+    0x95, 0x02,                   //     Report Count (2),
+    0xB1, 0x02,                   //     Feature (Data, Variable, Absolute),
+    0x95, 0x06,                   //     Report Count (6),
+    0xB1, 0x03,                   //     Feature (Constant, Variable, Absolute),
+    0xC0,                         //   End Collection,
+    0xC0,                         // End Collection,
+    0x05, 0x01,                   // Usage Page (Generic Desktop),
+    0x09, 0x02,                   // Usage (Mouse),
+    0xA1, 0x01,                   // Collection (Application),
+    0x85, 0x06,                   //   Report ID (6),
+    0x09, 0x01,                   //   Usage (Pointer),
+    0xA1, 0x00,                   //   Collection (Physical),
+    0x05, 0x09,                   //     Usage Page (Button),
+    0x19, 0x01,                   //     Usage Minimum (1),
+    0x29, 0x02,                   //     Usage Maximum (2),
+    0x15, 0x00,                   //     Logical Minimum (0),
+    0x25, 0x01,                   //     Logical Maximum (1),
+    0x75, 0x01,                   //     Report Size (1),
+    0x95, 0x02,                   //     Report Count (2),
+    0x81, 0x02,                   //     Input (Data, Variable, Absolute),
+    0x95, 0x06,                   //     Report Count (6),
+    0x81, 0x03,                   //     Input (Constant, Variable, Absolute),
+    0x05, 0x01,                   //     Usage Page (Generic Desktop),
+    0x09, 0x30,                   //     Usage (X),
+    0x09, 0x31,                   //     Usage (Y),
+    0x15, 0x81,                   //     Logical Minimum (-127),
+    0x25, 0x7F,                   //     Logical Maximum (127),
+    0x75, 0x08,                   //     Report Size (8),
+    0x95, 0x02,                   //     Report Count (2),
+    0x81, 0x06,                   //     Input (Data, Variable, Relative),
+    0xC0,                         //   End Collection,
+    0xC0,                         // End Collection
+];
+
+/// Input mode set by the host with the input mode feature report
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, TryFromPrimitive, IntoPrimitive)]
+#[repr(u8)]
+pub enum InputMode {
+    /// Send mouse reports, until the host selects touchpad reports
+    #[default]
+    Mouse = 0x00,
+    Touchpad = 0x03,
+}
+
+/// Kind of touchpad, reported to the host in the device capabilities feature report
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, TryFromPrimitive, IntoPrimitive)]
+#[repr(u8)]
+pub enum PadType {
+    /// The whole surface clicks, reported as Button 1
+    #[default]
+    Clickpad = 0x00,
+    /// Clicks are detected from the contact pressure
+    Pressurepad = 0x01,
+    /// Separate buttons, or none
+    NonClickable = 0x02,
+}
+
+/// A finger on the touchpad
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Default, Hash)]
+pub struct TouchpadContact {
+    /// Identifies the contact from touch down to lift off, 0 to 127
+    pub id: u8,
+    /// Touching the surface, cleared in the report after the contact lifts
+    pub tip_switch: bool,
+    /// The contact is a finger, cleared for palms and other unintended contacts
+    pub confidence: bool,
+    /// 0 to [`MAX_X`]
+    pub x: u16,
+    /// 0 to [`MAX_Y`]
+    pub y: u16,
+}
+
+impl TouchpadContact {
+    /// Confident finger touching at `x`, `y`
+    #[must_use]
+    pub const fn new(id: u8, x: u16, y: u16) -> Self {
+        Self {
+            id,
+            tip_switch: true,
+            confidence: true,
+            x,
+            y,
+        }
+    }
+
+    /// The contact lifted off the surface
+    #[must_use]
+    pub const fn lifted(mut self) -> Self {
+        self.tip_switch = false;
+        self
+    }
+
+    /// The contact isn't an intended touch, e.g. a palm
+    #[must_use]
+    pub const fn palm(mut self) -> Self {
+        self.confidence = false;
+        self
+    }
+}
+
+/// Contacts and button of a scan of the touchpad
+#[derive(Clone, Debug, Eq, PartialEq, Default)]
+pub struct TouchpadReport {
+    /// Contacts on the surface, and contacts lifted since the last scan
+    pub contacts: Vec<TouchpadContact, CONTACTS_PER_REPORT>,
+    /// Time of the scan in 100 µs units, wrapping, see [`Touchpad::scan_time`]
+    pub scan_time: u16,
+    /// Pad button pressed
+    pub button: bool,
+}
+
+impl TouchpadReport {
+    #[must_use]
+    pub const fn new(scan_time: u16) -> Self {
+        Self {
+            contacts: Vec::new(),
+            scan_time,
+            button: false,
+        }
+    }
+
+    /// Add a contact, giving it back if the report is full
+    pub fn push(&mut self, contact: TouchpadContact) -> Result<(), TouchpadContact> {
+        self.contacts.push(contact)
+    }
+
+    /// Report as sent to the host, with coordinates clamped to their logical range
+    #[must_use]
+    pub fn to_bytes(&self) -> [u8; TOUCHPAD_REPORT_LEN] {
+        let mut report = [0; TOUCHPAD_REPORT_LEN];
+        report[0] = TOUCHPAD_REPORT_ID;
+        for (data, contact) in report[1..]
+            .chunks_exact_mut(CONTACT_LEN)
+            .zip(&self.contacts)
+        {
+            let [x_low, x_high] = contact.x.min(MAX_X).to_le_bytes();
+            let [y_low, y_high] = contact.y.min(MAX_Y).to_le_bytes();
+            data.copy_from_slice(&[
+                u8::from(contact.confidence) | u8::from(contact.tip_switch) << 1,
+                contact.id & 0x7F,
+                x_low,
+                x_high,
+                y_low,
+                y_high,
+            ]);
+        }
+        let [.., scan_low, scan_high, count, button] = &mut report;
+        [*scan_low, *scan_high] = self.scan_time.to_le_bytes();
+        *count = u8::try_from(self.contacts.len()).unwrap_or_default();
+        *button = u8::from(self.button);
+        report
+    }
+}
+
+/// Precision touchpad, see the [module docs](self)
+pub struct Touchpad<'a, B: UsbBus> {
+    interface: Interface<'a, B, InBytes64, OutNone, Reports8>,
+    tracked: Option<(u8, [u16; 2])>,
+    remainder: [i32; 2],
+}
+
+impl<B: UsbBus> Touchpad<'_, B> {
+    /// Input mode selected by the host
+    #[must_use]
+    pub fn input_mode(&self) -> InputMode {
+        self.feature(INPUT_MODE_REPORT_ID)
+            .and_then(|mode| InputMode::try_from(mode).ok())
+            .unwrap_or_default()
+    }
+
+    /// `false` while the host has turned off reporting of contacts
+    #[must_use]
+    pub fn surface_switch(&self) -> bool {
+        self.feature(FUNCTION_SWITCH_REPORT_ID).unwrap_or(0b11) & 0b01 != 0
+    }
+
+    /// `false` while the host has turned off reporting of the button
+    #[must_use]
+    pub fn button_switch(&self) -> bool {
+        self.feature(FUNCTION_SWITCH_REPORT_ID).unwrap_or(0b11) & 0b10 != 0
+    }
+
+    /// Time since enumeration in 100 µs units, wrapping, for [`TouchpadReport::scan_time`].
+    /// Follows the interface clock, advanced by
+    /// [`UsbHidClass::tick`](crate::usb_class::UsbHidClass::tick) and
+    /// [`UsbHidClass::tick_until`](crate::usb_class::UsbHidClass::tick_until)
+    #[must_use]
+    pub fn scan_time(&self) -> u16 {
+        let [low, high, ..] = self
+            .interface
+            .uptime()
+            .ticks()
+            .wrapping_mul(10)
+            .to_le_bytes();
+        u16::from_le_bytes([low, high])
+    }
+
+    /// Send `report` in the current input mode
+    pub fn write_report(&mut self, report: &TouchpadReport) -> Result<(), UsbHidError> {
+        let mut report = report.clone();
+        if !self.surface_switch() {
+            report.contacts.clear();
+        }
+        report.button &= self.button_switch();
+
+        let data = match self.input_mode() {
+            InputMode::Touchpad => {
+                self.tracked = None;
+                self.interface.write_report(&report.to_bytes())
+            }
+            InputMode::Mouse => {
+                let [x, y] = self.mouse_motion(&report);
+                let [x, y] = [x, y].map(|d| d.to_le_bytes()[0]);
+                self.interface
+                    .write_report(&[MOUSE_REPORT_ID, u8::from(report.button), x, y])
+            }
+        };
+        data.map(|_| ()).map_err(UsbHidError::from)
+    }
+
+    fn feature(&self, report_id: u8) -> Option<u8> {
+        self.interface
+            .feature_report_with_id(report_id)
+            .and_then(|data| data.get(1).copied())
+    }
+
+    fn mouse_motion(&mut self, report: &TouchpadReport) -> [i8; 2] {
+        let contact = report
+            .contacts
+            .iter()
+            .find(|c| c.tip_switch && c.confidence);
+        let Some(contact) = contact else {
+            self.tracked = None;
+            return [0; 2];
+        };
+        let position = [contact.x.min(MAX_X), contact.y.min(MAX_Y)];
+        let previous = self
+            .tracked
+            .replace((contact.id, position))
+            .filter(|&(id, _)| id == contact.id);
+        let Some((_, previous)) = previous else {
+            self.remainder = [0; 2];
+            return [0; 2];
+        };
+
+        let mut motion = [0; 2];
+        for axis in 0..2 {
+            let total =
+                self.remainder[axis] + i32::from(position[axis]) - i32::from(previous[axis]);
+            let counts = total / MOUSE_DIVISOR;
+            self.remainder[axis] = total - counts * MOUSE_DIVISOR;
+            motion[axis] = i8::try_from(counts.clamp(-127, 127)).unwrap_or_default();
+        }
+        motion
+    }
+}
+
+impl<'a, B: UsbBus> DeviceClass<'a> for Touchpad<'a, B> {
+    type I = Interface<'a, B, InBytes64, OutNone, Reports8>;
+
+    fn interface(&mut self) -> &mut Self::I {
+        &mut self.interface
+    }
+
+    fn reset(&mut self) {
+        self.tracked = None;
+        self.remainder = [0; 2];
+    }
+
+    fn tick(&mut self) -> Result<(), UsbHidError> {
+        Ok(())
+    }
+
+    fn next_deadline(&mut self) -> Option<MillisDurationU32> {
+        None
+    }
+}
+
+pub struct TouchpadConfig<'a> {
+    interface: InterfaceConfig<'a, InBytes64, OutNone, Reports8>,
+}
+
+impl Default for TouchpadConfig<'_> {
+    fn default() -> Self {
+        Self::new(
+            unwrap!(unwrap!(Self::builder(PadType::Clickpad))
+                .static_feature_report(CERTIFICATION_REPORT_ID, &EMPTY_CERTIFICATION_REPORT)
+                .description("Touchpad")
+                .in_endpoint(5.millis()))
+            .without_out_endpoint()
+            .build(),
+        )
+    }
+}
+
+impl<'a> TouchpadConfig<'a> {
+    /// `interface` must use [`TOUCHPAD_REPORT_DESCRIPTOR`] and keep the feature reports set up by
+    /// [`TouchpadConfig::builder`]
+    #[must_use]
+    pub fn new(interface: InterfaceConfig<'a, InBytes64, OutNone, Reports8>) -> Self {
+        Self { interface }
+    }
+
+    /// Interface builder for [`TOUCHPAD_REPORT_DESCRIPTOR`] keeping the device capabilities,
+    /// input mode and function switch feature reports, to add the certification blob to
+    pub fn builder(
+        pad_type: PadType,
+    ) -> BuilderResult<InterfaceBuilder<'a, InBytes64, OutNone, Reports8>> {
+        let contact_count_maximum = u8::try_from(CONTACTS_PER_REPORT).unwrap_or_default();
+        let capabilities = u8::from(pad_type) << 4 | contact_count_maximum;
+        InterfaceBuilder::new_static(TOUCHPAD_REPORT_DESCRIPTOR)?
+            .feature_report(
+                CAPABILITIES_REPORT_ID,
+                &[CAPABILITIES_REPORT_ID, capabilities],
+            )?
+            .feature_report(
+                INPUT_MODE_REPORT_ID,
+                &[INPUT_MODE_REPORT_ID, InputMode::Mouse.into()],
+            )?
+            .feature_report(
+                FUNCTION_SWITCH_REPORT_ID,
+                &[FUNCTION_SWITCH_REPORT_ID, 0b11],
+            )
+    }
+}
+
+impl<'a, B: UsbBus + 'a> UsbAllocatable<'a, B> for TouchpadConfig<'a> {
+    type Allocated = Touchpad<'a, B>;
+
+    fn allocate(self, usb_alloc: &'a UsbBusAllocator<B>) -> Self::Allocated {
+        Self::Allocated {
+            interface: Interface::new(usb_alloc, self.interface),
+            tracked: None,
+            remainder: [0; 2],
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+    use crate::page::Digitizer;
+
+    #[test]
+    fn touchpad_report_layout() {
+        let mut report = TouchpadReport::new(0xABCD);
+        report
+            .push(TouchpadContact::new(3, 0x0123, 0xFFFF))
+            .unwrap();
+        report
+            .push(TouchpadContact::new(4, 10, 20).palm().lifted())
+            .unwrap();
+        report.button = true;
+
+        let bytes = report.to_bytes();
+        assert_eq!(bytes[0], TOUCHPAD_REPORT_ID);
+        assert_eq!(bytes[1..7], [0b11, 3, 0x23, 0x01, 0x08, 0x07]);
+        assert_eq!(bytes[7..13], [0b00, 4, 10, 0, 20, 0]);
+        assert_eq!(bytes[13..31], [0; 18]);
+        assert_eq!(bytes[31..], [0xCD, 0xAB, 2, 1]);
+
+        for _ in 0..CONTACTS_PER_REPORT - 2 {
+            report.push(TouchpadContact::default()).unwrap();
+        }
+        assert!(report.push(TouchpadContact::default()).is_err());
+    }
+
+    #[test]
+    fn descriptor_uses_digitizer_page() {
+        let usages: [u8; 11] = [
+            Digitizer::TouchPad.into(),
+            Digitizer::Finger.into(),
+            Digitizer::TouchValid.into(),
+            Digitizer::TipSwitch.into(),
+            Digitizer::ContactIdentifier.into(),
+            Digitizer::ScanTime.into(),
+            Digitizer::ContactCount.into(),
+            Digitizer::ContactCountMaximum.into(),
+            Digitizer::PadType.into(),
+            Digitizer::DeviceMode.into(),
+            Digitizer::SurfaceSwitch.into(),
+        ];
+        for usage in usages {
+            assert!(TOUCHPAD_REPORT_DESCRIPTOR
+                .windows(2)
+                .any(|item| item == [0x09, usage]));
+        }
+        let report_count = [0x96, 0x00, 0x01];
+        assert!(TOUCHPAD_REPORT_DESCRIPTOR
+            .windows(3)
+            .any(|item| item == report_count));
+        assert_eq!(EMPTY_CERTIFICATION_REPORT.len(), CERTIFICATION_REPORT_LEN);
+    }
+}
@@ -0,0 +1,320 @@
+//!HID touchscreens
+//!
+//! A single touch screen reporting through a digitizer touch screen collection. As many
+//! commercial panels do, the default configuration also includes a mouse compatible collection
+//! reporting the contact as an absolute pointer, so hosts without digitizer support still get
+//! basic pointer function. [`Touchscreen::set_mode`] selects which collection receives input.
+use crate::usb_class::prelude::*;
+use fugit::{ExtU32, MillisDurationU32};
+use usb_device::bus::UsbBus;
+use usb_device::class_prelude::UsbBusAllocator;
+use usb_device::UsbError;
+
+/// Report ID of the digitizer collection
+pub const DIGITIZER_REPORT_ID: u8 = 0x01;
+/// Report ID of the mouse compatible collection
+pub const MOUSE_COMPAT_REPORT_ID: u8 = 0x02;
+
+/// Largest X and Y coordinate
+pub const MAX_COORDINATE: u16 = 0x7FFF;
+
+/// Single touch digitizer descriptor
+///
+/// * Report ID 1, Touch Screen: Tip Switch and In Range bits, X and Y from 0 to
+///   [`MAX_COORDINATE`], 16 bits each (5 bytes)
+#[rustfmt::skip]
+pub const TOUCHSCREEN_REPORT_DESCRIPTOR: &[u8] = &[
+    0x05, 0x0D,       // Usage Page (Digitizer),
+    0x09, 0x04,       // Usage (Touch Screen),
+    0xA1, 0x01,       // Collection (Application),
+    0x85, 0x01,       //   Report ID (1),
+    0x09, 0x22,       //   Usage (Finger),
+    0xA1, 0x02,       //   Collection (Logical),
+    0x09, 0x42,       //     Usage (Tip Switch),
+    0x09, 0x32,       //     Usage (In Range),
+    0x15, 0x00,       //     Logical Minimum (0),
+    0x25, 0x01,       //     Logical Maximum (1),
+    0x75, 0x01,       //     Report Size (1),
+    0x95, 0x02,       //     Report Count (2),
+    0x81, 0x02,       //     Input (Data, Variable, Absolute),
+    0x95, 0x06,       //     Report Count (6),
+    0x81, 0x03,       //     Input (Constant, Variable, Absolute),
+    0x05, 0x01,       //     Usage Page (Generic Desktop),
+    0x09, 0x30,       //     Usage (X),
+    0x09, 0x31,       //     Usage (Y),
+    0x26, 0xFF, 0x7F, //     Logical Maximum (32767),
+    0x75, 0x10,       //     Report Size (16),
+    0x95, 0x02,       //     Report Count (2),
+    0x81, 0x02,       //     Input (Data, Variable, Absolute),
+    0xC0,             //   End Collection,
+    0xC0,             // End Collection
+];
+
+/// [`TOUCHSCREEN_REPORT_DESCRIPTOR`] followed by a mouse compatible collection
+///
+/// * Report ID 2, Mouse: 3 buttons, X and Y from 0 to [`MAX_COORDINATE`], 16 bits each
+///   (5 bytes)
+#[rustfmt::skip]
+pub const TOUCHSCREEN_MOUSE_COMPAT_REPORT_DESCRIPTOR: &[u8] = &[
+    0x05, 0x0D,       // Usage Page (Digitizer),
+    0x09, 0x04,       // Usage (Touch Screen),
+    0xA1, 0x01,       // Collection (Application),
+    0x85, 0x01,       //   Report ID (1),
+    0x09, 0x22,       //   Usage (Finger),
+    0xA1, 0x02,       //   Collection (Logical),
+    0x09, 0x42,       //     Usage (Tip Switch),
+    0x09, 0x32,       //     Usage (In Range),
+    0x15, 0x00,       //     Logical Minimum (0),
+    0x25, 0x01,       //     Logical Maximum (1),
+    0x75, 0x01,       //     Report Size (1),
+    0x95, 0x02,       //     Report Count (2),
+    0x81, 0x02,       //     Input (Data, Variable, Absolute),
+    0x95, 0x06,       //     Report Count (6),
+    0x81, 0x03,       //     Input (Constant, Variable, Absolute),
+    0x05, 0x01,       //     Usage Page (Generic Desktop),
+    0x09, 0x30,       //     Usage (X),
+    0x09, 0x31,       //     Usage (Y),
+    0x26, 0xFF, 0x7F, //     Logical Maximum (32767),
+    0x75, 0x10,       //     Report Size (16),
+    0x95, 0x02,       //     Report Count (2),
+    0x81, 0x02,       //     Input (Data, Variable, Absolute),
+    0xC0,             //   End Collection,
+    0xC0,             // End Collection,
+    0x05, 0x01,       // Usage Page (Generic Desktop),
+    0x09, 0x02,       // Usage (Mouse),
+    0xA1, 0x01,       // Collection (Application),
+    0x85, 0x02,       //   Report ID (2),
+    0x09, 0x01,       //   Usage (Pointer),
+    0xA1, 0x00,       //   Collection (Physical),
+    0x05, 0x09,       //     Usage Page (Button),
+    0x19, 0x01,       //     Usage Minimum (1),
+    0x29, 0x03,       //     Usage Maximum (3),
+    0x15, 0x00,       //     Logical Minimum (0),
+    0x25, 0x01,       //     Logical Maximum (1),
+    0x75, 0x01,       //     Report Size (1),
+    0x95, 0x03,       //     Report Count (3),
+    0x81, 0x02,       //     Input (Data, Variable, Absolute),
+    0x95, 0x05,       //     Report Count (5),
+    0x81, 0x03,       //     Input (Constant, Variable, Absolute),
+    0x05, 0x01,       //     Usage Page (Generic Desktop),
+    0x09, 0x30,       //     Usage (X),
+    0x09, 0x31,       //     Usage (Y),
+    0x26, 0xFF, 0x7F, //     Logical Maximum (32767),
+    0x75, 0x10,       //     Report Size (16),
+    0x95, 0x02,       //     Report Count (2),
+    0x81, 0x02,       //     Input (Data, Variable, Absolute),
+    0xC0,             //   End Collection,
+    0xC0,             // End Collection
+];
+
+/// State of the single contact
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Default, Hash)]
+pub struct TouchReport {
+    /// Finger touching the screen
+    pub tip_switch: bool,
+    /// Finger detected, touching or hovering
+    pub in_range: bool,
+    /// 0 to [`MAX_COORDINATE`]
+    pub x: u16,
+    /// 0 to [`MAX_COORDINATE`]
+    pub y: u16,
+}
+
+impl TouchReport {
+    /// Report for the collection selected by `mode`, including the report ID
+    #[must_use]
+    pub fn pack(&self, mode: TouchscreenMode) -> [u8; 6] {
+        let (id, flags) = match mode {
+            TouchscreenMode::Digitizer => (
+                DIGITIZER_REPORT_ID,
+                u8::from(self.tip_switch) | u8::from(self.in_range) << 1,
+            ),
+            TouchscreenMode::Mouse => (MOUSE_COMPAT_REPORT_ID, u8::from(self.tip_switch)),
+        };
+        let [x_low, x_high] = self.x.min(MAX_COORDINATE).to_le_bytes();
+        let [y_low, y_high] = self.y.min(MAX_COORDINATE).to_le_bytes();
+        [id, flags, x_low, x_high, y_low, y_high]
+    }
+}
+
+/// Collection receiving the contact
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Default, Hash)]
+pub enum TouchscreenMode {
+    /// Digitizer touch screen collection
+    #[default]
+    Digitizer,
+    /// Mouse compatible collection, the contact is reported as an absolute pointer with the tip
+    /// switch as the primary button
+    Mouse,
+}
+
+/// Single touch touchscreen, see the [module docs](self)
+pub struct Touchscreen<'a, B: UsbBus> {
+    interface: Interface<'a, B, InBytes8, OutNone, Reports8>,
+    mouse_compat: bool,
+    mode: TouchscreenMode,
+    last_report: TouchReport,
+}
+
+impl<B: UsbBus> Touchscreen<'_, B> {
+    pub fn write_report(&mut self, report: &TouchReport) -> Result<(), UsbHidError> {
+        Self::write(&mut self.interface, &report.pack(self.mode))?;
+        self.last_report = *report;
+        Ok(())
+    }
+
+    #[must_use]
+    pub fn mode(&self) -> TouchscreenMode {
+        self.mode
+    }
+
+    /// Route the contact to another collection. A contact held in the previous collection is
+    /// released first, so it isn't left stuck down on the host
+    ///
+    /// Returns [`UsbError::Unsupported`] when selecting [`TouchscreenMode::Mouse`] without the
+    /// mouse compatible collection, and [`UsbHidError::WouldBlock`] if the release could not be
+    /// sent yet, in which case the mode is unchanged
+    pub fn set_mode(&mut self, mode: TouchscreenMode) -> Result<(), UsbHidError> {
+        if mode == TouchscreenMode::Mouse && !self.mouse_compat {
+            return Err(UsbHidError::UsbError(UsbError::Unsupported));
+        }
+        if mode != self.mode && self.last_report != TouchReport::default() {
+            let release = TouchReport {
+                x: self.last_report.x,
+                y: self.last_report.y,
+                ..Default::default()
+            };
+            Self::write(&mut self.interface, &release.pack(self.mode))?;
+            self.last_report = TouchReport::default();
+        }
+        self.mode = mode;
+        Ok(())
+    }
+
+    fn write(
+        interface: &mut Interface<'_, B, InBytes8, OutNone, Reports8>,
+        data: &[u8],
+    ) -> Result<(), UsbHidError> {
+        match interface.write_report(data) {
+            Ok(_) => Ok(()),
+            Err(UsbError::WouldBlock) => Err(UsbHidError::WouldBlock),
+            Err(e) => Err(UsbHidError::UsbError(e)),
+        }
+    }
+}
+
+impl<'a, B: UsbBus> DeviceClass<'a> for Touchscreen<'a, B> {
+    type I = Interface<'a, B, InBytes8, OutNone, Reports8>;
+
+    fn interface(&mut self) -> &mut Self::I {
+        &mut self.interface
+    }
+
+    fn reset(&mut self) {
+        self.last_report = TouchReport::default();
+    }
+
+    fn tick(&mut self) -> Result<(), UsbHidError> {
+        Ok(())
+    }
+
+    fn next_deadline(&mut self) -> Option<MillisDurationU32> {
+        None
+    }
+}
+
+pub struct TouchscreenConfig<'a> {
+    interface: InterfaceConfig<'a, InBytes8, OutNone, Reports8>,
+    mouse_compat: bool,
+}
+
+impl Default for TouchscreenConfig<'_> {
+    /// Touchscreen with the mouse compatible collection
+    fn default() -> Self {
+        Self::new(
+            unwrap!(unwrap!(InterfaceBuilder::new_static(
+                TOUCHSCREEN_MOUSE_COMPAT_REPORT_DESCRIPTOR
+            ))
+            .description("Touchscreen")
+            .in_endpoint(10.millis()))
+            .without_out_endpoint()
+            .build(),
+            true,
+        )
+    }
+}
+
+impl<'a> TouchscreenConfig<'a> {
+    /// `mouse_compat` must be `true` if, and only if, the report descriptor includes the mouse
+    /// compatible collection
+    #[must_use]
+    pub fn new(
+        interface: InterfaceConfig<'a, InBytes8, OutNone, Reports8>,
+        mouse_compat: bool,
+    ) -> Self {
+        Self {
+            interface,
+            mouse_compat,
+        }
+    }
+
+    /// Touchscreen with only the digitizer collection
+    #[must_use]
+    pub fn digitizer_only() -> Self {
+        Self::new(
+            unwrap!(
+                unwrap!(InterfaceBuilder::new_static(TOUCHSCREEN_REPORT_DESCRIPTOR))
+                    .description("Touchscreen")
+                    .in_endpoint(10.millis())
+            )
+            .without_out_endpoint()
+            .build(),
+            false,
+        )
+    }
+}
+
+impl<'a, B: UsbBus + 'a> UsbAllocatable<'a, B> for TouchscreenConfig<'a> {
+    type Allocated = Touchscreen<'a, B>;
+
+    fn allocate(self, usb_alloc: &'a UsbBusAllocator<B>) -> Self::Allocated {
+        Self::Allocated {
+            interface: Interface::new(usb_alloc, self.interface),
+            mouse_compat: self.mouse_compat,
+            mode: TouchscreenMode::default(),
+            last_report: TouchReport::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn report_per_collection() {
+        let report = TouchReport {
+            tip_switch: true,
+            in_range: true,
+            x: 0x1234,
+            y: 0xFFFF,
+        };
+        assert_eq!(
+            report.pack(TouchscreenMode::Digitizer),
+            [0x01, 0x03, 0x34, 0x12, 0xFF, 0x7F]
+        );
+        assert_eq!(
+            report.pack(TouchscreenMode::Mouse),
+            [0x02, 0x01, 0x34, 0x12, 0xFF, 0x7F]
+        );
+    }
+
+    #[test]
+    fn digitizer_descriptor_is_prefix() {
+        assert!(
+            TOUCHSCREEN_MOUSE_COMPAT_REPORT_DESCRIPTOR.starts_with(TOUCHSCREEN_REPORT_DESCRIPTOR)
+        );
+    }
+}
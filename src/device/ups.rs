@@ -0,0 +1,448 @@
+//! Uninterruptible power supplies, for battery-backed appliances
+//!
+//! [`Ups`] implements the Power Summary of the HID Power Device class, with usages from the
+//! [`PowerDevice`](crate::page::PowerDevice) and [`BatterySystem`](crate::page::BatterySystem)
+//! pages. Hosts such as Windows, macOS and Linux with NUT treat it as a UPS without drivers: they
+//! show the battery level and shut down cleanly before the battery runs out.
+//!
+//! The application feeds in its measurements with [`Ups::update`]. Changes are sent as input
+//! reports from `tick`, and the same values answer the host's `GET_REPORT` requests for the
+//! feature reports. Capacities are in percent.
+//!
+//! ```rust,no_run
+//! # use xous_usb_hid::device::ups::*;
+//! # use usb_device::bus::UsbBus;
+//! # fn measure<B: UsbBus>(ups: &mut Ups<'_, B>) {
+//! ups.update(UpsStatus {
+//!     present: PresentStatus {
+//!         discharging: true,
+//!         battery_present: true,
+//!         ..Default::default()
+//!     },
+//!     remaining_capacity: 76,
+//!     run_time_to_empty: 45 * 60,
+//! });
+//! # }
+//! ```
+
+use crate::interface::InterfaceClass;
+use crate::usb_class::prelude::*;
+use fugit::{ExtU32, MillisDurationU32};
+use usb_device::bus::UsbBus;
+use usb_device::class_prelude::UsbBusAllocator;
+
+/// Report ID of the present status input and feature reports, see [`PresentStatus`]
+pub const PRESENT_STATUS_REPORT_ID: u8 = 0x01;
+/// Report ID of the remaining capacity and run time to empty input and feature reports
+pub const BATTERY_REPORT_ID: u8 = 0x02;
+/// Report ID of the capacity limits feature report, see [`CapacityLimits`]
+pub const CAPACITY_REPORT_ID: u8 = 0x03;
+
+/// Capacity mode of the capacity limits feature report: capacities are in percent
+const CAPACITY_MODE_PERCENT: u8 = 2;
+
+/// UPS descriptor
+///
+/// * Report 1, input and feature: Present Status bits, Charging, Discharging, AC Present,
+///   Battery Present, Below Remaining Capacity Limit, Need Replacement, Shutdown Imminent and
+///   Overload (1 byte)
+/// * Report 2, input and feature: Remaining Capacity from 0 to 100 percent, 8 bits, and Run Time
+///   To Empty in seconds, 16 bits (3 bytes)
+/// * Report 3, feature: Capacity Mode, Design Capacity, Full Charge Capacity, Warning Capacity
+///   Limit, Remaining Capacity Limit and Rechargeable, 8 bits each (6 bytes)
+#[rustfmt::skip]
+pub const UPS_REPORT_DESCRIPTOR: &[u8] = &[
+    0x05, 0x84,                   // Usage Page (Power Device),
+    0x09, 0x04,                   // Usage (UPS),
+    0xA1, 0x01,                   // Collection (Application),
+    0x09, 0x24,                   //   Usage (Power Summary),
+    0xA1, 0x00,                   //   Collection (Physical),
+    0x85, 0x01,                   //     Report ID (1),
+    0x09, 0x02,                   //     Usage (Present Status),
+    0xA1, 0x02,                   //     Collection (Logical),
+    0x15, 0x00,                   //       Logical Minimum (0),
+    0x25, 0x01,                   //       Logical Maximum (1),
+    0x75, 0x01,                   //       Report Size (1),
+    0x95, 0x06,                   //       Report Count (6),
+    0x05, 0x85,                   //       Usage Page (Battery System),
+    0x09, 0x44,                   //       Usage (Charging),
+    0x09, 0x45,                   //       Usage (Discharging),
+    0x09, 0xD0,                   //       Usage (AC Present),
+    0x09, 0xD1,                   //       Usage (Battery Present),
+    0x09, 0x42,                   //       Usage (Below Remaining Capacity Limit),
+    0x09, 0x4B,                   //       Usage (Need Replacement),
+    0x81, 0x02,                   //       Input (Data, Variable, Absolute),
+    0x05, 0x85,                   //       Usage Page (Battery System),
+    0x09, 0x44,                   //       Usage (Charging),
+    0x09, 0x45,                   //       Usage (Discharging),
+    0x09, 0xD0,                   //       Usage (AC Present),
+    0x09, 0xD1,                   //       Usage (Battery Present),
+    0x09, 0x42,                   //       Usage (Below Remaining Capacity Limit),
+    0x09, 0x4B,                   //       Usage (Need Replacement),
+    0xB1, 0x02,                   //       Feature (Data, Variable, Absolute),
+    0x95, 0x02,                   //       Report Count (2),
+    0x05, 0x84,                   //       Usage Page (Power Device),
+    0x09, 0x69,                   //       Usage (Shutdown Imminent),
+    0x09, 0x65,                   //       Usage (Overload),
+    0x81, 0x02,                   //       Input (Data, Variable, Absolute),
+    0x05, 0x84,                   //       Usage Page (Power Device),
+    0x09, 0x69,                   //       Usage (Shutdown Imminent),
+    0x09, 0x65,                   //       Usage (Overload),
+    0xB1, 0x02,                   //       Feature (Data, Variable, Absolute),
+    0xC0,                         //     End Collection,
+    0x85, 0x02,                   //     Report ID (2),
+    0x05, 0x85,                   //     Usage Page (Battery System),
+    0x25, 0x64,                   //     Logical Maximum (100),
+    0x75, 0x08,                   //     Report Size (8),
+    0x95, 0x01,                   //     Report Count (1),
+    0x09, 0x66,                   //     Usage (Remaining Capacity),
+    0x81, 0x02,                   //     Input (Data, Variable, Absolute),
+    0x09, 0x66,                   //     Usage (Remaining Capacity),
+    0xB1, 0x02,                   //     Feature (Data, Variable, Absolute),
+    0x27, 0xFF, 0xFF, 0x00, 0x00, //     Logical Maximum (65535),
+    0x66, 0x01, 0x10,             //     Unit (Seconds),
+    0x75, 0x10,                   //     Report Size (16),
+    0x09, 0x68,                   //     Usage (Run Time To Empty),
+    0x81, 0x02,                   //     Input (Data, Variable, Absolute),
+    0x09, 0x68,                   //     Usage (Run Time To Empty),
+    0xB1, 0x02,                   //     Feature (Data, Variable, Absolute),
+    0x65, 0x00,                   //     Unit (None),
+    0x85, 0x03,                   //     Report ID (3),
+    0x75, 0x08,                   //     Report Size (8),
+    0x09, 0x2C,                   //     Usage (Capacity Mode),
+    0x25, 0x02,                   //     Logical Maximum (2),
+    0xB1, 0x02,                   //     Feature (Data, Variable, Absolute),
+    0x25, 0x64,                   //     Logical Maximum (100),
+    0x09, 0x83,                   //     Usage (Design Capacity),
+    0x09, 0x67,                   //     Usage (Full Charge Capacity),
+    0x09, 0x8C,                   //     Usage (Warning Capacity Limit),
+    0x09, 0x29,                   //     Usage (Remaining Capacity Limit),
+    0x95, 0x04,                   //     Report Count (4),
+    0xB1, 0x02,                   //     Feature (Data, Variable, Absolute),
+    0x09, 0x8B,                   //     Usage (Rechargeable),
+    0x25, 0x01,                   //     Logical Maximum (1),
+    0x95, 0x01,                   //     Report Count (1),
+    0xB1, 0x02,                   //     Feature (Data, Variable, Absolute),
+    0xC0,                         //   End Collection,
+    0xC0,                         // End Collection
+];
+
+/// Present Status of the power summary
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Default, Hash)]
+pub struct PresentStatus {
+    pub charging: bool,
+    pub discharging: bool,
+    /// Running from mains power
+    pub ac_present: bool,
+    pub battery_present: bool,
+    /// Set by [`Ups::update`] from the remaining capacity and the remaining capacity limit
+    pub below_remaining_capacity_limit: bool,
+    pub need_replacement: bool,
+    /// The UPS is about to turn off, hosts shut down immediately
+    pub shutdown_imminent: bool,
+    pub overload: bool,
+}
+
+impl PresentStatus {
+    #[must_use]
+    pub fn to_byte(&self) -> u8 {
+        u8::from(self.charging)
+            | u8::from(self.discharging) << 1
+            | u8::from(self.ac_present) << 2
+            | u8::from(self.battery_present) << 3
+            | u8::from(self.below_remaining_capacity_limit) << 4
+            | u8::from(self.need_replacement) << 5
+            | u8::from(self.shutdown_imminent) << 6
+            | u8::from(self.overload) << 7
+    }
+}
+
+/// Measurements of the UPS, see [`Ups::update`]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Default, Hash)]
+pub struct UpsStatus {
+    pub present: PresentStatus,
+    /// Percent of the full charge, 0 to 100
+    pub remaining_capacity: u8,
+    /// Seconds until the battery is empty at the present load
+    pub run_time_to_empty: u16,
+}
+
+/// Capacity limits, in percent, reported in the capacity limits feature report
+///
+/// Hosts warn the user below the warning limit and shut down below the remaining capacity limit.
+/// Hosts may change the limits with `SET_REPORT`
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct CapacityLimits {
+    pub warning: u8,
+    pub remaining: u8,
+    pub rechargeable: bool,
+}
+
+impl Default for CapacityLimits {
+    /// Warning at 20%, shut down at 10%, rechargeable
+    fn default() -> Self {
+        Self {
+            warning: 20,
+            remaining: 10,
+            rechargeable: true,
+        }
+    }
+}
+
+impl CapacityLimits {
+    /// Capacity limits feature report, including the report ID, with limits clamped to 100%
+    #[must_use]
+    pub fn to_bytes(&self) -> [u8; 7] {
+        [
+            CAPACITY_REPORT_ID,
+            CAPACITY_MODE_PERCENT,
+            100,
+            100,
+            self.warning.min(100),
+            self.remaining.min(100),
+            u8::from(self.rechargeable),
+        ]
+    }
+}
+
+/// Power summary of a UPS, see the [module docs](self)
+///
+/// **Note:** Reports are sent by [`UsbHidClass::tick()`], which must be called every 1ms.
+pub struct Ups<'a, B: UsbBus> {
+    interface: Interface<'a, B, InBytes8, OutNone, ReportSingle>,
+    limits: CapacityLimits,
+    status: UpsStatus,
+    status_pending: bool,
+    battery_pending: bool,
+}
+
+impl<B: UsbBus> Ups<'_, B> {
+    /// Update the measurements, changes are sent to the host by `tick`
+    ///
+    /// The remaining capacity is clamped to 100%, and Below Remaining Capacity Limit is set
+    /// while it is below the remaining capacity limit
+    pub fn update(&mut self, status: UpsStatus) {
+        let remaining_capacity = status.remaining_capacity.min(100);
+        let status = UpsStatus {
+            present: PresentStatus {
+                below_remaining_capacity_limit: remaining_capacity
+                    < self.remaining_capacity_limit(),
+                ..status.present
+            },
+            remaining_capacity,
+            ..status
+        };
+        self.status_pending |= status.present != self.status.present;
+        self.battery_pending |= status.remaining_capacity != self.status.remaining_capacity
+            || status.run_time_to_empty != self.status.run_time_to_empty;
+        self.status = status;
+        self.store_reports();
+    }
+
+    /// Measurements last given to [`Ups::update`]
+    #[must_use]
+    pub fn status(&self) -> UpsStatus {
+        self.status
+    }
+
+    /// Remaining capacity limit in percent, as last set by the host
+    #[must_use]
+    pub fn remaining_capacity_limit(&self) -> u8 {
+        match self.interface.feature_report_with_id(CAPACITY_REPORT_ID) {
+            Some(&[CAPACITY_REPORT_ID, _, _, _, _, limit, ..]) => limit,
+            _ => self.limits.remaining,
+        }
+    }
+
+    fn present_status_report(&self) -> [u8; 2] {
+        [PRESENT_STATUS_REPORT_ID, self.status.present.to_byte()]
+    }
+
+    fn battery_report(&self) -> [u8; 4] {
+        let [run_time_low, run_time_high] = self.status.run_time_to_empty.to_le_bytes();
+        [
+            BATTERY_REPORT_ID,
+            self.status.remaining_capacity,
+            run_time_low,
+            run_time_high,
+        ]
+    }
+
+    fn store_reports(&mut self) {
+        let status = self.present_status_report();
+        self.interface
+            .set_feature_report(PRESENT_STATUS_REPORT_ID, &status);
+        let battery = self.battery_report();
+        self.interface
+            .set_feature_report(BATTERY_REPORT_ID, &battery);
+    }
+
+    fn flush(&mut self) -> Result<(), UsbHidError> {
+        if self.status_pending {
+            let report = self.present_status_report();
+            self.interface
+                .write_report(&report)
+                .map_err(UsbHidError::from)?;
+            self.status_pending = false;
+        }
+        if self.battery_pending {
+            let report = self.battery_report();
+            self.interface
+                .write_report(&report)
+                .map_err(UsbHidError::from)?;
+            self.battery_pending = false;
+        }
+        Ok(())
+    }
+}
+
+impl<'a, B: UsbBus> DeviceClass<'a> for Ups<'a, B> {
+    type I = Interface<'a, B, InBytes8, OutNone, ReportSingle>;
+
+    fn interface(&mut self) -> &mut Self::I {
+        &mut self.interface
+    }
+
+    /// Keeps the measurements, the interface has just reset the feature reports to their initial
+    /// values
+    fn reset(&mut self) {
+        self.interface
+            .set_feature_report(CAPACITY_REPORT_ID, &self.limits.to_bytes());
+        self.store_reports();
+        self.status_pending = true;
+        self.battery_pending = true;
+    }
+
+    fn tick(&mut self) -> Result<(), UsbHidError> {
+        match self.flush() {
+            Err(UsbHidError::WouldBlock) => Ok(()),
+            r => r,
+        }
+    }
+
+    fn next_deadline(&mut self) -> Option<MillisDurationU32> {
+        (self.status_pending || self.battery_pending).then_some(1.millis())
+    }
+}
+
+pub struct UpsConfig<'a> {
+    interface: InterfaceConfig<'a, InBytes8, OutNone, ReportSingle>,
+    limits: CapacityLimits,
+}
+
+impl Default for UpsConfig<'_> {
+    fn default() -> Self {
+        Self::new(
+            unwrap!(
+                unwrap!(unwrap!(unwrap!(unwrap!(InterfaceBuilder::new_static(
+                    UPS_REPORT_DESCRIPTOR
+                ))
+                .description("UPS")
+                .feature_report(PRESENT_STATUS_REPORT_ID, &[PRESENT_STATUS_REPORT_ID, 0]))
+                .feature_report(BATTERY_REPORT_ID, &[BATTERY_REPORT_ID, 0, 0, 0]))
+                .feature_report(CAPACITY_REPORT_ID, &CapacityLimits::default().to_bytes()))
+                .in_endpoint(10.millis())
+            )
+            .without_out_endpoint()
+            .build(),
+        )
+    }
+}
+
+impl<'a> UpsConfig<'a> {
+    /// `interface` must keep the feature reports [`PRESENT_STATUS_REPORT_ID`],
+    /// [`BATTERY_REPORT_ID`] and [`CAPACITY_REPORT_ID`], see
+    /// [`InterfaceBuilder::feature_report`](crate::interface::InterfaceBuilder::feature_report)
+    #[must_use]
+    pub fn new(interface: InterfaceConfig<'a, InBytes8, OutNone, ReportSingle>) -> Self {
+        Self {
+            interface,
+            limits: CapacityLimits::default(),
+        }
+    }
+
+    /// Initial capacity limits, restored after a bus reset
+    #[must_use]
+    pub fn limits(mut self, limits: CapacityLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+}
+
+impl<'a, B: UsbBus + 'a> UsbAllocatable<'a, B> for UpsConfig<'a> {
+    type Allocated = Ups<'a, B>;
+
+    fn allocate(self, usb_alloc: &'a UsbBusAllocator<B>) -> Self::Allocated {
+        let mut ups = Self::Allocated {
+            interface: Interface::new(usb_alloc, self.interface),
+            limits: self.limits,
+            status: UpsStatus::default(),
+            status_pending: false,
+            battery_pending: false,
+        };
+        ups.interface
+            .set_feature_report(CAPACITY_REPORT_ID, &self.limits.to_bytes());
+        ups.store_reports();
+        ups
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::page::{BatterySystem, PowerDevice};
+
+    #[test]
+    fn present_status_bits() {
+        assert_eq!(PresentStatus::default().to_byte(), 0);
+        let status = PresentStatus {
+            ac_present: true,
+            charging: true,
+            battery_present: true,
+            ..Default::default()
+        };
+        assert_eq!(status.to_byte(), 0b0000_1101);
+        let status = PresentStatus {
+            discharging: true,
+            shutdown_imminent: true,
+            overload: true,
+            ..Default::default()
+        };
+        assert_eq!(status.to_byte(), 0b1100_0010);
+
+        let limits = CapacityLimits {
+            warning: 150,
+            ..Default::default()
+        };
+        assert_eq!(
+            limits.to_bytes(),
+            [CAPACITY_REPORT_ID, 2, 100, 100, 100, 10, 1]
+        );
+    }
+
+    #[test]
+    fn descriptor_uses_power_device_pages() {
+        let power: [u8; 5] = [
+            PowerDevice::UPS.into(),
+            PowerDevice::PowerSummary.into(),
+            PowerDevice::PresentStatus.into(),
+            PowerDevice::ShutdownImminent.into(),
+            PowerDevice::Overload.into(),
+        ];
+        let battery: [u8; 5] = [
+            BatterySystem::ACPresent.into(),
+            BatterySystem::RemainingCapacity.into(),
+            BatterySystem::RunTimeToEmpty.into(),
+            BatterySystem::RemainingCapacityLimit.into(),
+            BatterySystem::Rechargeable.into(),
+        ];
+        for usage in power.into_iter().chain(battery) {
+            assert!(UPS_REPORT_DESCRIPTOR
+                .windows(2)
+                .any(|item| item == [0x09, usage]));
+        }
+    }
+}
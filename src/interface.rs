@@ -1,7 +1,8 @@
 //! Human Interface Device Interfaces
+use crate::capability::{DescriptorSummary, ReportKind};
 use crate::descriptor::{
-    DescriptorType, HidProtocol, InterfaceProtocol, InterfaceSubClass, COUNTRY_CODE_NOT_SUPPORTED,
-    SPEC_VERSION_1_11, USB_CLASS_HID,
+    report_descriptor_hash, DescriptorType, HidProtocol, InterfaceProtocol, InterfaceSubClass,
+    COUNTRY_CODE_NOT_SUPPORTED, SPEC_VERSION_1_11, USB_CLASS_HID,
 };
 use crate::device::DeviceClass;
 use crate::private::Sealed;
@@ -10,7 +11,7 @@ use crate::UsbHidError;
 use core::marker::PhantomData;
 use frunk::{HCons, HNil};
 use fugit::{ExtU32, MillisDurationU32};
-use heapless::Vec;
+use heapless::{Deque, Vec};
 use option_block::{Block128, Block16, Block32, Block64, Block8};
 use packed_struct::prelude::*;
 use packed_struct::PackedStruct;
@@ -63,17 +64,99 @@ where
 pub trait InterfaceClass<'a> {
     fn hid_descriptor_body(&self) -> [u8; 7];
     fn report_descriptor(&self) -> &'_ [u8];
+    /// The report descriptor, if it can be streamed straight from flash without a RAM copy
+    fn static_report_descriptor(&self) -> Option<&'static [u8]> {
+        None
+    }
+    /// [`report_descriptor_hash`](crate::descriptor::report_descriptor_hash) of the report
+    /// descriptor
+    fn report_descriptor_hash(&self) -> u32 {
+        report_descriptor_hash(self.report_descriptor())
+    }
+    /// Report ID of the feature report answered with the report descriptor hash, if enabled
+    fn descriptor_hash_report_id(&self) -> Option<u8> {
+        None
+    }
+    /// Data answering `GET_REPORT` for feature report `report_id`, if it is a static feature report
+    fn static_feature_report(&self, _report_id: u8) -> Option<&'static [u8]> {
+        None
+    }
     fn id(&self) -> InterfaceNumber;
     fn write_descriptors(&self, writer: &mut DescriptorWriter) -> usb_device::Result<()>;
     fn get_string(&self, index: StringIndex, _lang_id: u16) -> Option<&'a str>;
     fn reset(&mut self);
+    /// Called when the host clears a halt on the endpoint `address`, returns `false` if the
+    /// endpoint doesn't belong to this interface
+    fn endpoint_halt_cleared(&mut self, _address: EndpointAddress) -> bool {
+        false
+    }
+    /// Called when the host has read the report written to the IN endpoint `address`, returns
+    /// `false` if the endpoint doesn't belong to this interface
+    fn endpoint_in_complete(&mut self, _address: EndpointAddress) -> bool {
+        false
+    }
+    /// `true` while a report written to the IN endpoint hasn't been read by the host
+    fn in_endpoint_busy(&self) -> bool {
+        false
+    }
     fn set_report(&mut self, data: &[u8]) -> usb_device::Result<()>;
     fn get_report(&self, data: &mut [u8]) -> usb_device::Result<usize>;
+    /// Store feature report `report_id` sent with `SET_REPORT`, returns `false` if the interface
+    /// doesn't keep that feature report or `data` is longer than the report
+    fn set_feature_report(&mut self, _report_id: u8, _data: &[u8]) -> bool {
+        false
+    }
+    /// Copy feature report `report_id` to `data` for `GET_REPORT`, `None` if the interface doesn't
+    /// keep that feature report
+    fn get_feature_report(&self, _report_id: u8, _data: &mut [u8]) -> Option<usize> {
+        None
+    }
     fn get_report_ack(&mut self) -> usb_device::Result<()>;
     fn set_idle(&mut self, report_id: u8, value: u8);
     fn get_idle(&self, report_id: u8) -> u8;
     fn set_protocol(&mut self, protocol: HidProtocol);
     fn get_protocol(&self) -> HidProtocol;
+    /// Called every 1ms by [`UsbHidClass::tick`](crate::usb_class::UsbHidClass::tick), to
+    /// timestamp errors
+    fn clock_tick(&mut self) {
+        self.clock_advance(1.millis());
+    }
+    /// Called by [`UsbHidClass::tick_until`](crate::usb_class::UsbHidClass::tick_until) for time
+    /// skipped without ticks
+    fn clock_advance(&mut self, _elapsed: MillisDurationU32) {}
+    fn health(&self) -> InterfaceHealth {
+        InterfaceHealth::default()
+    }
+}
+
+/// Interface operation that failed, see [`InterfaceHealth`]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InterfaceOperation {
+    WriteReport,
+    ReadReport,
+    /// Output report from the host that couldn't be stored, e.g. because it was too long
+    SetReport,
+}
+
+/// An error of an interface, see [`InterfaceHealth`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InterfaceError {
+    pub operation: InterfaceOperation,
+    /// [`usb_error_code`](crate::usb_error_code) of the error
+    pub code: u8,
+    /// Time since the class was created, as counted by
+    /// [`UsbHidClass::tick`](crate::usb_class::UsbHidClass::tick)
+    pub at: MillisDurationU32,
+}
+
+/// Errors of an interface, for long running devices reporting their health to a supervisor
+///
+/// [`UsbError::WouldBlock`] isn't counted as an error. Errors are kept across bus resets
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub struct InterfaceHealth {
+    pub last_error: Option<InterfaceError>,
+    pub error_count: u32,
 }
 
 pub trait ReportBuffer: Default {
@@ -159,13 +242,54 @@ vec_in_bytes!(InBytes16, 16);
 vec_in_bytes!(InBytes32, 32);
 vec_in_bytes!(InBytes64, 64);
 
+/// Storage for output reports received via HID `SET_REPORT` requests
+pub trait ReportQueue: Default {
+    type Buffer: ReportBuffer;
+    fn is_empty(&self) -> bool;
+    fn is_full(&self) -> bool;
+    fn clear(&mut self);
+    fn front(&self) -> Option<&Self::Buffer>;
+    fn pop_front(&mut self) -> Option<Self::Buffer>;
+    fn push_back(&mut self, buffer: Self::Buffer) -> Result<(), Self::Buffer>;
+}
+
+impl<T: ReportBuffer, const N: usize> ReportQueue for Deque<T, N> {
+    type Buffer = T;
+
+    fn is_empty(&self) -> bool {
+        self.is_empty()
+    }
+
+    fn is_full(&self) -> bool {
+        self.is_full()
+    }
+
+    fn clear(&mut self) {
+        self.clear();
+    }
+
+    fn front(&self) -> Option<&T> {
+        self.front()
+    }
+
+    fn pop_front(&mut self) -> Option<T> {
+        self.pop_front()
+    }
+
+    fn push_back(&mut self, buffer: T) -> Result<(), T> {
+        self.push_back(buffer)
+    }
+}
+
 pub trait OutSize: Sealed {
     type Buffer: ReportBuffer;
+    type Queue: ReportQueue<Buffer = Self::Buffer>;
 }
 pub enum OutNone {}
 impl Sealed for OutNone {}
 impl OutSize for OutNone {
     type Buffer = ();
+    type Queue = Deque<(), 1>;
 }
 
 macro_rules! vec_out_bytes {
@@ -174,6 +298,7 @@ macro_rules! vec_out_bytes {
         impl Sealed for $name {}
         impl OutSize for $name {
             type Buffer = Vec<u8, $capacity>;
+            type Queue = Deque<Vec<u8, $capacity>, 1>;
         }
     };
 }
@@ -183,6 +308,31 @@ vec_out_bytes!(OutBytes16, 16);
 vec_out_bytes!(OutBytes32, 32);
 vec_out_bytes!(OutBytes64, 64);
 
+/// Output reports of size `O`, with storage for up to `N` reports received via `SET_REPORT`
+///
+/// Used with [`OutReportMode::Queue`] for command streams where no report may be lost
+pub struct OutQueue<O: OutSize, const N: usize>(PhantomData<O>);
+impl<O: OutSize, const N: usize> Sealed for OutQueue<O, N> {}
+impl<O: OutSize, const N: usize> OutSize for OutQueue<O, N> {
+    type Buffer = O::Buffer;
+    type Queue = Deque<O::Buffer, N>;
+}
+
+/// Handling of output reports received via `SET_REPORT` while earlier reports are still unread
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OutReportMode {
+    /// Keep the unread report, drop the new one
+    #[default]
+    Drop,
+    /// Replace any unread reports with the new one, e.g. for LED state where only the latest
+    /// value matters
+    Latch,
+    /// Append the new report to the queue, dropping it if the queue is full. The queue length is
+    /// set with [`OutQueue`]
+    Queue,
+}
+
 pub trait IdleStorage: Default {
     const CAPACITY: u32;
     fn insert(&mut self, index: usize, val: u8) -> Option<u8>;
@@ -239,6 +389,98 @@ option_block_idle_storage!(Reports32, Block32);
 option_block_idle_storage!(Reports64, Block64);
 option_block_idle_storage!(Reports128, Block128);
 
+/// Bytes shared by the feature reports kept by an interface, each taking its length in the
+/// report descriptor, see [`InterfaceBuilder::feature_report`]
+pub const FEATURE_REPORT_STORAGE: usize = 32;
+
+/// Most time between [`Interface::write_report`] accepting a report and the host reading it, for
+/// any interface: the longest polling interval, 255ms, an interrupt IN endpoint can declare
+///
+/// See [`Interface::max_report_latency`] for the bound of a given interface
+pub const MAX_WRITE_REPORT_LATENCY: MillisDurationU32 = MillisDurationU32::from_ticks(255);
+
+/// Most feature reports kept by an interface, see [`InterfaceBuilder::feature_report`]
+pub const MAX_FEATURE_REPORTS: usize = 4;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct FeatureReport {
+    id: u8,
+    /// Start of the report in [`FeatureReports::data`]
+    offset: usize,
+    /// Length of the report in the report descriptor
+    capacity: usize,
+    /// Length of the value last stored
+    len: usize,
+}
+
+/// Feature reports kept by an interface, sharing [`FEATURE_REPORT_STORAGE`] bytes
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct FeatureReports {
+    reports: [Option<FeatureReport>; MAX_FEATURE_REPORTS],
+    data: [u8; FEATURE_REPORT_STORAGE],
+}
+
+impl FeatureReports {
+    const fn new() -> Self {
+        Self {
+            reports: [None; MAX_FEATURE_REPORTS],
+            data: [0; FEATURE_REPORT_STORAGE],
+        }
+    }
+
+    /// Keep report `id` of `capacity` bytes, or replace its value if it is already kept
+    fn add(&mut self, id: u8, capacity: usize, initial: &[u8]) -> BuilderResult<()> {
+        if self.find(id).is_none() {
+            let offset: usize = self.reports.iter().flatten().map(|r| r.capacity).sum();
+            if offset + capacity > FEATURE_REPORT_STORAGE {
+                return Err(UsbHidBuilderError::SliceLengthOverflow);
+            }
+            let slot = self
+                .reports
+                .iter_mut()
+                .find(|r| r.is_none())
+                .ok_or(UsbHidBuilderError::SliceLengthOverflow)?;
+            *slot = Some(FeatureReport {
+                id,
+                offset,
+                capacity,
+                len: 0,
+            });
+        }
+        match self.set(id, initial) {
+            Some(true) => Ok(()),
+            _ => Err(UsbHidBuilderError::SliceLengthOverflow),
+        }
+    }
+
+    fn find(&self, id: u8) -> Option<&FeatureReport> {
+        self.reports.iter().flatten().find(|r| r.id == id)
+    }
+
+    fn get(&self, id: u8) -> Option<&[u8]> {
+        self.find(id).map(|r| self.value(r))
+    }
+
+    fn first(&self) -> Option<&[u8]> {
+        self.reports.iter().flatten().next().map(|r| self.value(r))
+    }
+
+    fn value(&self, report: &FeatureReport) -> &[u8] {
+        &self.data[report.offset..report.offset + report.len]
+    }
+
+    /// `None` if report `id` isn't kept, `Some(false)` if `data` is longer than the report
+    fn set(&mut self, id: u8, data: &[u8]) -> Option<bool> {
+        let report = self.reports.iter_mut().flatten().find(|r| r.id == id)?;
+        if data.len() > report.capacity {
+            return Some(false);
+        }
+        report.len = data.len();
+        self.data[report.offset..report.offset + data.len()].copy_from_slice(data);
+        Some(true)
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct InterfaceConfig<'a, I, O, R>
 where
@@ -248,10 +490,17 @@ where
 {
     marker: PhantomData<(I, O, R)>,
     report_descriptor: &'a [u8],
+    static_report_descriptor: Option<&'static [u8]>,
     report_descriptor_length: u16,
+    report_descriptor_hash: u32,
+    descriptor_hash_report_id: Option<u8>,
+    feature_reports: FeatureReports,
+    static_feature_report: Option<(u8, &'static [u8])>,
     description: Option<&'a str>,
+    localized_descriptions: &'a [(u16, &'a str)],
     protocol: InterfaceProtocol,
     idle_default: u8,
+    out_report_mode: OutReportMode,
     out_endpoint: Option<EndpointConfig>,
     in_endpoint: EndpointConfig,
 }
@@ -272,7 +521,11 @@ where
     report_idle: R::IdleStorage,
     global_idle: u8,
     control_in_report_buffer: I::Buffer,
-    control_out_report_buffer: O::Buffer,
+    control_out_report_buffer: O::Queue,
+    feature_reports: FeatureReports,
+    uptime: MillisDurationU32,
+    health: InterfaceHealth,
+    in_busy: bool,
 }
 
 impl<'a, B: UsbBus + 'a, I, O, R> UsbAllocatable<'a, B> for InterfaceConfig<'a, I, O, R>
@@ -309,6 +562,10 @@ where
     fn tick(&mut self) -> Result<(), crate::UsbHidError> {
         Ok(())
     }
+
+    fn next_deadline(&mut self) -> Option<MillisDurationU32> {
+        None
+    }
 }
 
 impl<'a, B: UsbBus, I, O, R> Interface<'a, B, I, O, R>
@@ -325,13 +582,19 @@ where
             out_endpoint: config
                 .out_endpoint
                 .map(|c| usb_alloc.interrupt(O::Buffer::CAPACITY, c.poll_interval)),
-            description_index: config.description.map(|_| usb_alloc.string()),
+            description_index: (config.description.is_some()
+                || !config.localized_descriptions.is_empty())
+            .then(|| usb_alloc.string()),
             //When initialized, all devices default to report protocol - Hid spec 7.2.6 Set_Protocol Request
             protocol: HidProtocol::Report,
             report_idle: R::IdleStorage::default(),
             global_idle: config.idle_default,
             control_in_report_buffer: I::Buffer::default(),
-            control_out_report_buffer: O::Buffer::default(),
+            control_out_report_buffer: O::Queue::default(),
+            feature_reports: config.feature_reports,
+            uptime: 0.millis(),
+            health: InterfaceHealth::default(),
+            in_busy: false,
             config,
         }
     }
@@ -339,6 +602,26 @@ where
     fn clear_report_idle(&mut self) {
         self.report_idle = R::IdleStorage::default();
     }
+
+    fn record<T>(
+        &mut self,
+        operation: InterfaceOperation,
+        result: usb_device::Result<T>,
+    ) -> usb_device::Result<T> {
+        match result {
+            Err(UsbError::WouldBlock) | Ok(_) => {}
+            Err(ref error) => {
+                warn!("Interface {:?} failed, {:?}", operation, error);
+                self.health.last_error = Some(InterfaceError {
+                    operation,
+                    code: crate::usb_error_code(error),
+                    at: self.uptime,
+                });
+                self.health.error_count = self.health.error_count.saturating_add(1);
+            }
+        }
+        result
+    }
     fn get_report_idle(&self, report_id: u8) -> Option<u8> {
         if u32::from(report_id) < R::IdleStorage::CAPACITY {
             self.report_idle.get(report_id.into())
@@ -350,20 +633,70 @@ where
     pub fn protocol(&self) -> HidProtocol {
         self.protocol
     }
+    /// Boot device type of the interface
+    #[must_use]
+    pub fn interface_protocol(&self) -> InterfaceProtocol {
+        self.config.protocol
+    }
+    /// Current value of the first feature report kept by the interface, see
+    /// [`InterfaceBuilder::feature_report`]
+    #[must_use]
+    pub fn feature_report(&self) -> Option<&[u8]> {
+        self.feature_reports.first()
+    }
+    /// Current value of feature report `report_id`, if the interface keeps it
+    #[must_use]
+    pub fn feature_report_with_id(&self, report_id: u8) -> Option<&[u8]> {
+        self.feature_reports.get(report_id)
+    }
+    /// Time ticked by [`UsbHidClass::tick`](crate::usb_class::UsbHidClass::tick) and
+    /// [`UsbHidClass::tick_until`](crate::usb_class::UsbHidClass::tick_until), including time
+    /// skipped without ticking the devices
+    #[must_use]
+    pub fn uptime(&self) -> MillisDurationU32 {
+        self.uptime
+    }
     #[must_use]
     pub fn global_idle(&self) -> MillisDurationU32 {
         (u32::from(self.global_idle) * 4).millis()
     }
+    /// Idle rate set by the host for a specific report ID, `None` if the report uses the global
+    /// idle rate
     #[must_use]
     pub fn report_idle(&self, report_id: u8) -> Option<MillisDurationU32> {
         if report_id == 0 {
             None
         } else {
-            self.get_report_idle(report_id)
+            self.get_report_idle(report_id - 1)
                 .map(|i| (u32::from(i) * 4).millis())
         }
     }
+    /// Most time between a call to [`Interface::write_report`] accepting a report and the host
+    /// reading it, the polling interval of the IN endpoint. Never more than
+    /// [`MAX_WRITE_REPORT_LATENCY`]
+    #[must_use]
+    pub fn max_report_latency(&self) -> MillisDurationU32 {
+        u32::from(self.config.in_endpoint.poll_interval).millis()
+    }
+    /// Write an input report
+    ///
+    /// While the host has selected the boot protocol, reports of boot devices are cut to the boot
+    /// report length, so report protocol layouts extending the boot report, such as NKRO
+    /// keyboards, also work with BIOSes. The protocol goes back to the report protocol on a bus
+    /// reset or when the host deconfigures the device.
+    ///
+    /// A report accepted by `write_report` has been written to the IN endpoint, or kept for a
+    /// `GET_REPORT` request, by the time the call returns. A report that can't be written is
+    /// rejected with [`UsbError::WouldBlock`] instead of being queued, so nothing is sent later
+    /// from a tick or poll, other than idle repeats requested by the host. The call makes a single
+    /// endpoint write and copies the report once, in time proportional to its length, without
+    /// waiting on the endpoint. See [`Interface::max_report_latency`] for when the host reads it
     pub fn write_report(&mut self, data: &[u8]) -> usb_device::Result<usize> {
+        let data = match (self.protocol, self.config.protocol.boot_report_len()) {
+            (HidProtocol::Boot, Some(len)) => &data[..len.min(data.len())],
+            _ => data,
+        };
+
         //Try to write report to the report buffer for the config endpoint
         let control_result = if self.control_in_report_buffer.is_empty() {
             match self.control_in_report_buffer.extend_from_slice(data) {
@@ -376,15 +709,21 @@ where
 
         //Also try to write report to the in endpoint
         let endpoint_result = self.in_endpoint.write(data);
+        self.in_busy |= endpoint_result.is_ok();
 
-        match (control_result, endpoint_result) {
+        let result = match (control_result, endpoint_result) {
             //OK if either succeeded
             (_, Ok(n)) | (Ok(n), _) => Ok(n),
             //non-WouldBlock errors take preference
             (Err(e), Err(UsbError::WouldBlock)) | (_, Err(e)) => Err(e),
-        }
+        };
+        self.record(InterfaceOperation::WriteReport, result)
     }
     pub fn read_report(&mut self, data: &mut [u8]) -> usb_device::Result<usize> {
+        let result = self.read_report_inner(data);
+        self.record(InterfaceOperation::ReadReport, result)
+    }
+    fn read_report_inner(&mut self, data: &mut [u8]) -> usb_device::Result<usize> {
         //If there is an out endpoint, try to read from it first
         let ep_result = if let Some(ep) = &self.out_endpoint {
             ep.read(data)
@@ -396,15 +735,20 @@ where
             Err(UsbError::WouldBlock) => {
                 //If there wasn't data available from the in endpoint
                 //try the config endpoint report buffer
-                let out_len = self.control_out_report_buffer.len();
-                if self.control_out_report_buffer.is_empty() {
-                    Err(UsbError::WouldBlock)
-                } else if data.len() < out_len {
+                let Some(out_len) = self
+                    .control_out_report_buffer
+                    .front()
+                    .map(ReportBuffer::len)
+                else {
+                    return Err(UsbError::WouldBlock);
+                };
+                if data.len() < out_len {
                     Err(UsbError::BufferOverflow)
-                } else {
-                    data[..out_len].copy_from_slice(self.control_out_report_buffer.as_ref());
-                    self.control_out_report_buffer.clear();
+                } else if let Some(report) = self.control_out_report_buffer.pop_front() {
+                    data[..out_len].copy_from_slice(report.as_ref());
                     Ok(out_len)
+                } else {
+                    Err(UsbError::WouldBlock)
                 }
             }
             _ => ep_result,
@@ -437,6 +781,24 @@ where
         self.config.report_descriptor
     }
 
+    fn static_report_descriptor(&self) -> Option<&'static [u8]> {
+        self.config.static_report_descriptor
+    }
+
+    fn report_descriptor_hash(&self) -> u32 {
+        self.config.report_descriptor_hash
+    }
+
+    fn descriptor_hash_report_id(&self) -> Option<u8> {
+        self.config.descriptor_hash_report_id
+    }
+    fn static_feature_report(&self, report_id: u8) -> Option<&'static [u8]> {
+        self.config
+            .static_feature_report
+            .filter(|&(id, _)| id == report_id)
+            .map(|(_, data)| data)
+    }
+
     fn id(&self) -> InterfaceNumber {
         self.id
     }
@@ -461,40 +823,76 @@ where
 
         Ok(())
     }
-    fn get_string(&self, index: StringIndex, _lang_id: u16) -> Option<&'a str> {
-        self.description_index
-            .filter(|&i| i == index)
-            .and(self.config.description)
+    fn get_string(&self, index: StringIndex, lang_id: u16) -> Option<&'a str> {
+        self.description_index.filter(|&i| i == index)?;
+        self.config
+            .localized_descriptions
+            .iter()
+            .find(|(id, _)| *id == lang_id)
+            .map(|&(_, s)| s)
+            .or(self.config.description)
     }
     fn reset(&mut self) {
         self.protocol = HidProtocol::Report;
         self.global_idle = self.config.idle_default;
         self.clear_report_idle();
         self.control_in_report_buffer = I::Buffer::default();
-        self.control_out_report_buffer = O::Buffer::default();
+        self.control_out_report_buffer = O::Queue::default();
+        self.feature_reports = self.config.feature_reports;
+        self.in_busy = false;
+    }
+    fn endpoint_halt_cleared(&mut self, address: EndpointAddress) -> bool {
+        //Reports queued before the halt may be stale or partial commands, drop them so the
+        //device and host start from a known state
+        if address == self.in_endpoint.address() {
+            info!("IN endpoint halt cleared");
+            self.control_in_report_buffer.clear();
+            self.in_busy = false;
+            true
+        } else if self.out_endpoint.as_ref().map(EndpointOut::address) == Some(address) {
+            info!("OUT endpoint halt cleared");
+            self.control_out_report_buffer = O::Queue::default();
+            true
+        } else {
+            false
+        }
+    }
+    fn endpoint_in_complete(&mut self, address: EndpointAddress) -> bool {
+        if address == self.in_endpoint.address() {
+            self.in_busy = false;
+            true
+        } else {
+            false
+        }
+    }
+    fn in_endpoint_busy(&self) -> bool {
+        self.in_busy
     }
     fn set_report(&mut self, data: &[u8]) -> usb_device::Result<()> {
-        if self.control_out_report_buffer.is_empty() {
-            if self
-                .control_out_report_buffer
-                .extend_from_slice(data)
-                .is_ok()
-            {
-                trace!(
-                    "Set report, {:X} bytes",
-                    &self.control_out_report_buffer.len()
-                );
-                Ok(())
-            } else {
-                error!(
-                    "Failed to set report, too large for buffer. Report size {:X}, expected <={:X}",
-                    data.len(),
-                    O::Buffer::CAPACITY
-                );
-                Err(UsbError::BufferOverflow)
+        let mut report = O::Buffer::default();
+        if report.extend_from_slice(data).is_err() {
+            error!(
+                "Failed to set report, too large for buffer. Report size {:X}, expected <={:X}",
+                data.len(),
+                O::Buffer::CAPACITY
+            );
+            return self.record(InterfaceOperation::SetReport, Err(UsbError::BufferOverflow));
+        }
+
+        match self.config.out_report_mode {
+            OutReportMode::Drop if !self.control_out_report_buffer.is_empty() => {
+                trace!("Failed to set report, buffer not empty");
+                return Err(UsbError::WouldBlock);
             }
+            OutReportMode::Latch => self.control_out_report_buffer.clear(),
+            _ => {}
+        }
+
+        if self.control_out_report_buffer.push_back(report).is_ok() {
+            trace!("Set report, {:X} bytes", data.len());
+            Ok(())
         } else {
-            trace!("Failed to set report, buffer not empty");
+            trace!("Failed to set report, queue full");
             Err(UsbError::WouldBlock)
         }
     }
@@ -513,6 +911,32 @@ where
         }
     }
 
+    fn set_feature_report(&mut self, report_id: u8, data: &[u8]) -> bool {
+        match self.feature_reports.set(report_id, data) {
+            Some(true) => {
+                trace!("Set feature report {}, {:X} bytes", report_id, data.len());
+                true
+            }
+            Some(false) => {
+                error!(
+                    "Failed to set feature report {}, {:X} bytes is too large",
+                    report_id,
+                    data.len()
+                );
+                self.record::<()>(InterfaceOperation::SetReport, Err(UsbError::BufferOverflow))
+                    .is_ok()
+            }
+            None => false,
+        }
+    }
+
+    fn get_feature_report(&self, report_id: u8, data: &mut [u8]) -> Option<usize> {
+        let report = self.feature_reports.get(report_id)?;
+        let n = report.len().min(data.len());
+        data[..n].copy_from_slice(&report[..n]);
+        Some(n)
+    }
+
     fn get_report_ack(&mut self) -> usb_device::Result<()> {
         if self.control_in_report_buffer.is_empty() {
             error!("GetReport ACK failed, empty buffer");
@@ -561,6 +985,14 @@ where
     fn get_protocol(&self) -> HidProtocol {
         self.protocol
     }
+
+    fn clock_advance(&mut self, elapsed: MillisDurationU32) {
+        self.uptime += elapsed;
+    }
+
+    fn health(&self) -> InterfaceHealth {
+        self.health
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -590,17 +1022,34 @@ where
             config: InterfaceConfig {
                 marker: PhantomData,
                 report_descriptor,
+                static_report_descriptor: None,
                 report_descriptor_length: u16::try_from(report_descriptor.len())
                     .map_err(|_| UsbHidBuilderError::SliceLengthOverflow)?,
+                report_descriptor_hash: report_descriptor_hash(report_descriptor),
+                descriptor_hash_report_id: None,
+                feature_reports: FeatureReports::new(),
+                static_feature_report: None,
                 description: None,
+                localized_descriptions: &[],
                 protocol: InterfaceProtocol::None,
                 idle_default: 0,
+                out_report_mode: OutReportMode::Drop,
                 out_endpoint: None,
                 in_endpoint: EndpointConfig { poll_interval: 20 },
             },
         })
     }
 
+    /// Builder for a report descriptor in flash, sent to the host in chunks straight from the
+    /// slice rather than through the control pipe's RAM buffer. Descriptors longer than the control
+    /// buffer (128 bytes, unless usb-device's `control-buffer-256` feature is enabled) must be
+    /// static
+    pub fn new_static(report_descriptor: &'static [u8]) -> BuilderResult<Self> {
+        let mut builder = Self::new(report_descriptor)?;
+        builder.config.static_report_descriptor = Some(report_descriptor);
+        Ok(builder)
+    }
+
     pub fn boot_device(mut self, protocol: InterfaceProtocol) -> Self {
         self.config.protocol = protocol;
         self
@@ -628,6 +1077,60 @@ where
         self
     }
 
+    /// Set the description in other languages, as `(LANGID, description)` pairs. Requests for
+    /// other languages fall back to [`InterfaceBuilder::description`]
+    ///
+    /// Hosts only request the languages listed in the device's LANGID table, see
+    /// [`UsbHidClassBuilder::lang_ids`](crate::usb_class::UsbHidClassBuilder::lang_ids)
+    pub fn localized_descriptions(mut self, descriptions: &'a [(u16, &'a str)]) -> Self {
+        self.config.localized_descriptions = descriptions;
+        self
+    }
+
+    /// Answer `GET_REPORT` requests for feature report `report_id` with the little endian
+    /// [`report_descriptor_hash`], 0 if the descriptor doesn't use report IDs. The report
+    /// descriptor must declare the report, see
+    /// [`DESCRIPTOR_HASH_FEATURE_ITEMS`](crate::descriptor::DESCRIPTOR_HASH_FEATURE_ITEMS)
+    pub fn descriptor_hash_report(mut self, report_id: u8) -> Self {
+        self.config.descriptor_hash_report_id = Some(report_id);
+        self
+    }
+
+    /// Keep feature report `report_id`, answering `GET_REPORT` with the value last stored by
+    /// `SET_REPORT`, or `initial` until the host sets it and after a bus reset. The data includes
+    /// the report ID byte if the descriptor uses report IDs
+    ///
+    /// The report takes its length in the report descriptor, or the length of `initial` if the
+    /// descriptor doesn't declare it, from the [`FEATURE_REPORT_STORAGE`] bytes shared by the
+    /// feature reports of the interface. `SET_REPORT` requests longer than that are rejected.
+    /// Up to [`MAX_FEATURE_REPORTS`] feature reports with different IDs can be kept, adding a
+    /// report ID again replaces its initial value
+    pub fn feature_report(mut self, report_id: u8, initial: &[u8]) -> BuilderResult<Self> {
+        let capacity = DescriptorSummary::new(self.config.report_descriptor)
+            .ok()
+            .and_then(|summary| {
+                summary
+                    .reports()
+                    .find(|r| r.kind == ReportKind::Feature && r.id == report_id)
+            })
+            .map_or(initial.len(), |r| {
+                usize::from(r.len) + usize::from(report_id != 0)
+            });
+        self.config
+            .feature_reports
+            .add(report_id, capacity, initial)?;
+        Ok(self)
+    }
+
+    /// Answer `GET_REPORT` requests for feature report `report_id` with `data`, including the
+    /// report ID byte if the descriptor uses report IDs. The data is sent straight from flash, so
+    /// it can be longer than the control buffer, e.g. a certificate. `SET_REPORT` requests for the
+    /// report are ignored
+    pub fn static_feature_report(mut self, report_id: u8, data: &'static [u8]) -> Self {
+        self.config.static_feature_report = Some((report_id, data));
+        self
+    }
+
     pub fn with_out_endpoint(mut self, poll_interval: MillisDurationU32) -> BuilderResult<Self> {
         self.config.out_endpoint = Some(EndpointConfig {
             poll_interval: u8::try_from(poll_interval.to_millis())
@@ -636,6 +1139,13 @@ where
         Ok(self)
     }
 
+    /// Set the handling of output reports received via `SET_REPORT` while earlier reports are
+    /// still unread, defaults to [`OutReportMode::Drop`]
+    pub fn out_report_mode(mut self, mode: OutReportMode) -> Self {
+        self.config.out_report_mode = mode;
+        self
+    }
+
     pub fn without_out_endpoint(mut self) -> Self {
         self.config.out_endpoint = None;
         self
@@ -698,9 +1208,32 @@ where
         }
     }
 
+    /// Count `elapsed` ticks that [`IdleManager::next_deadline`] reported as not due
+    pub fn advance(&mut self, elapsed: MillisDurationU32, timeout: MillisDurationU32) {
+        self.since_last_report = if timeout.ticks() == 0 {
+            0.millis()
+        } else {
+            (self.since_last_report + elapsed).min(timeout)
+        };
+    }
+
     pub fn last_report(&self) -> Option<R> {
         self.last_report
     }
+
+    /// Ticks until [`IdleManager::tick`] next returns `true`
+    pub fn next_deadline(&self, timeout: MillisDurationU32) -> Option<MillisDurationU32> {
+        if timeout.ticks() == 0 || self.last_report.is_none() {
+            None
+        } else {
+            Some(
+                timeout
+                    .checked_sub(self.since_last_report)
+                    .unwrap_or(0.millis())
+                    + 1.millis(),
+            )
+        }
+    }
 }
 
 pub struct ManagedIdleInterface<'a, B: UsbBus, Report, I, O>
@@ -711,6 +1244,11 @@ where
 {
     interface: Interface<'a, B, I, O, ReportSingle>,
     idle_manager: IdleManager<Report>,
+    min_report_spacing: MillisDurationU32,
+    since_last_write: MillisDurationU32,
+    settle_time: MillisDurationU32,
+    /// `None` until the host configures the device
+    since_configured: Option<MillisDurationU32>,
 }
 
 #[allow(clippy::inline_always)]
@@ -720,12 +1258,25 @@ where
     I: InSize,
     O: OutSize,
 {
-    fn new(interface: Interface<'a, B, I, O, ReportSingle>) -> Self {
+    fn new(
+        interface: Interface<'a, B, I, O, ReportSingle>,
+        min_report_spacing: MillisDurationU32,
+        settle_time: MillisDurationU32,
+    ) -> Self {
         Self {
             interface,
             idle_manager: IdleManager::default(),
+            min_report_spacing,
+            since_last_write: min_report_spacing,
+            settle_time,
+            since_configured: None,
         }
     }
+
+    /// `true` while reports are held back after the host configured the device
+    fn is_settling(&self) -> bool {
+        self.settle_time.ticks() > 0 && self.since_configured.is_none_or(|t| t < self.settle_time)
+    }
 }
 
 #[allow(clippy::inline_always)]
@@ -736,9 +1287,14 @@ where
     I: InSize,
     O: OutSize,
 {
+    /// Write a report, returns [`UsbHidError::WouldBlock`] until the minimum report spacing has
+    /// passed since the last report, and during the settle time after the host configured the
+    /// device. Like [`Interface::write_report`], reports are never held back to be sent later
     pub fn write_report(&mut self, report: &Report) -> Result<(), UsbHidError> {
         if self.idle_manager.is_duplicate(report) {
             Err(UsbHidError::Duplicate)
+        } else if self.since_last_write < self.min_report_spacing || self.is_settling() {
+            Err(UsbHidError::WouldBlock)
         } else {
             let data = report.pack().map_err(|_| {
                 error!("Error packing report");
@@ -750,6 +1306,7 @@ where
                 .map_err(UsbHidError::from)
                 .map(|_| {
                     self.idle_manager.report_written(*report);
+                    self.since_last_write = 0.millis();
                 })
         }
     }
@@ -775,9 +1332,31 @@ where
 
     fn reset(&mut self) {
         self.idle_manager = IdleManager::default();
+        self.since_last_write = self.min_report_spacing;
+        self.since_configured = None;
+    }
+
+    fn configured(&mut self) {
+        self.since_configured = Some(0.millis());
+    }
+
+    fn endpoint_halt_cleared(&mut self) {
+        //The last report may not have reached the host, allow it to be written again
+        self.idle_manager = IdleManager::default();
     }
 
     fn tick(&mut self) -> Result<(), UsbHidError> {
+        if self.since_last_write < self.min_report_spacing {
+            self.since_last_write += 1.millis();
+        }
+        if let Some(t) = self
+            .since_configured
+            .as_mut()
+            .filter(|t| **t < self.settle_time)
+        {
+            *t += 1.millis();
+        }
+
         if !(self.idle_manager.tick(self.interface.global_idle())) {
             Ok(())
         } else if let Some(r) = self.idle_manager.last_report() {
@@ -798,6 +1377,34 @@ where
             Ok(())
         }
     }
+
+    fn next_deadline(&mut self) -> Option<MillisDurationU32> {
+        let spacing = self.min_report_spacing.checked_sub(self.since_last_write);
+        let settle = self
+            .since_configured
+            .and_then(|t| self.settle_time.checked_sub(t));
+        let idle = self
+            .idle_manager
+            .next_deadline(self.interface.global_idle());
+        [spacing, settle, idle]
+            .into_iter()
+            .flatten()
+            .filter(|t| t.ticks() > 0)
+            .min()
+    }
+
+    fn advance(&mut self, elapsed: MillisDurationU32) {
+        self.since_last_write = (self.since_last_write + elapsed).min(self.min_report_spacing);
+        if let Some(t) = self
+            .since_configured
+            .as_mut()
+            .filter(|t| **t < self.settle_time)
+        {
+            *t = (*t + elapsed).min(self.settle_time);
+        }
+        self.idle_manager
+            .advance(elapsed, self.interface.global_idle());
+    }
 }
 
 pub struct ManagedIdleInterfaceConfig<'a, Report, I, O>
@@ -807,6 +1414,8 @@ where
 {
     report: PhantomData<Report>,
     interface_config: InterfaceConfig<'a, I, O, ReportSingle>,
+    min_report_spacing: MillisDurationU32,
+    settle_time: MillisDurationU32,
 }
 
 impl<'a, Report, I, O> ManagedIdleInterfaceConfig<'a, Report, I, O>
@@ -819,8 +1428,29 @@ where
         Self {
             interface_config,
             report: PhantomData,
+            min_report_spacing: 0.millis(),
+            settle_time: 0.millis(),
         }
     }
+
+    /// Minimum time between successive reports, for hosts and KVMs that drop reports arriving
+    /// back-to-back. Requires [`UsbHidClass::tick()`](crate::usb_class::UsbHidClass::tick) to be
+    /// called every 1ms
+    #[must_use]
+    pub fn min_report_spacing(mut self, spacing: MillisDurationU32) -> Self {
+        self.min_report_spacing = spacing;
+        self
+    }
+
+    /// Time to hold back reports after the host configures the device, for hosts that lose
+    /// reports sent while they are still setting up the device, e.g. the first key press after
+    /// plugging in. Reports are also held back until the device is configured. Requires
+    /// [`UsbHidClass::tick()`](crate::usb_class::UsbHidClass::tick) to be called every 1ms
+    #[must_use]
+    pub fn settle_time(mut self, settle_time: MillisDurationU32) -> Self {
+        self.settle_time = settle_time;
+        self
+    }
 }
 
 impl<'a, B, Report, I, O> UsbAllocatable<'a, B> for ManagedIdleInterfaceConfig<'a, Report, I, O>
@@ -832,6 +1462,10 @@ where
     type Allocated = ManagedIdleInterface<'a, B, Report, I, O>;
 
     fn allocate(self, usb_alloc: &'a UsbBusAllocator<B>) -> Self::Allocated {
-        ManagedIdleInterface::new(self.interface_config.allocate(usb_alloc))
+        ManagedIdleInterface::new(
+            self.interface_config.allocate(usb_alloc),
+            self.min_report_spacing,
+            self.settle_time,
+        )
     }
 }
@@ -146,11 +146,17 @@ extern crate std;
 
 use usb_device::UsbError;
 
+pub mod capability;
+pub mod debounce;
 pub mod descriptor;
 pub mod device;
 pub mod interface;
 pub mod page;
+pub mod power;
 pub mod prelude;
+pub mod protocol;
+pub mod recorder;
+pub mod tlv;
 pub mod usb_class;
 
 #[derive(Debug)]
@@ -170,6 +176,68 @@ impl From<UsbError> for UsbHidError {
     }
 }
 
+impl UsbHidError {
+    /// Compact numeric code for the error, unique across the error types of this crate
+    ///
+    /// | Code        | Error                                               |
+    /// |-------------|-----------------------------------------------------|
+    /// | `0x01`      | [`UsbHidError::WouldBlock`]                         |
+    /// | `0x02`      | [`UsbHidError::Duplicate`]                          |
+    /// | `0x03`      | [`UsbHidError::SerializationError`]                 |
+    /// | `0x10-0x17` | [`UsbHidError::UsbError`], see [`usb_error_code`]   |
+    /// | `0x20-0x2F` | [`UsbHidBuilderError`](usb_class::UsbHidBuilderError) |
+    #[must_use]
+    pub const fn code(&self) -> u8 {
+        match self {
+            Self::WouldBlock => 0x01,
+            Self::Duplicate => 0x02,
+            Self::SerializationError => 0x03,
+            Self::UsbError(e) => usb_error_code(e),
+        }
+    }
+}
+
+/// Compact numeric code for a [`UsbError`], see [`UsbHidError::code`]
+#[must_use]
+pub const fn usb_error_code(e: &UsbError) -> u8 {
+    match e {
+        UsbError::WouldBlock => 0x10,
+        UsbError::ParseError => 0x11,
+        UsbError::BufferOverflow => 0x12,
+        UsbError::EndpointOverflow => 0x13,
+        UsbError::EndpointMemoryOverflow => 0x14,
+        UsbError::InvalidEndpoint => 0x15,
+        UsbError::Unsupported => 0x16,
+        UsbError::InvalidState => 0x17,
+    }
+}
+
+#[cfg(feature = "ufmt")]
+impl ufmt::uDebug for UsbHidError {
+    fn fmt<W: ufmt::uWrite + ?Sized>(
+        &self,
+        f: &mut ufmt::Formatter<'_, W>,
+    ) -> Result<(), W::Error> {
+        match self {
+            Self::WouldBlock => f.write_str("WouldBlock"),
+            Self::Duplicate => f.write_str("Duplicate"),
+            Self::SerializationError => f.write_str("SerializationError"),
+            Self::UsbError(_) => ufmt::uwrite!(f, "UsbError(E{:#x})", self.code()),
+        }
+    }
+}
+
+/// Writes the error code, e.g. `E0x11`
+#[cfg(feature = "ufmt")]
+impl ufmt::uDisplay for UsbHidError {
+    fn fmt<W: ufmt::uWrite + ?Sized>(
+        &self,
+        f: &mut ufmt::Formatter<'_, W>,
+    ) -> Result<(), W::Error> {
+        ufmt::uwrite!(f, "E{:#x}", self.code())
+    }
+}
+
 mod private {
     /// Super trait used to mark traits with an exhaustive set of
     /// implementations
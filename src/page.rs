@@ -4,9 +4,14 @@
 //! <https://www.usb.org/sites/default/files/documents/hut1_12v2.pdf>
 
 use core::hash::Hash;
-use num_enum::{FromPrimitive, IntoPrimitive};
+use num_enum::{FromPrimitive, IntoPrimitive, TryFromPrimitive};
 use packed_struct::prelude::*;
 
+#[cfg(feature = "usage-names")]
+pub mod names;
+#[cfg(feature = "serde")]
+mod serialize;
+
 // Notes for converting .upg files to rust enum
 // * Trim header
 // * Flip and format: ([0-9A-F]*)\t(.*) - $2=0x$1,
@@ -120,9 +125,78 @@ impl Default for Leds {
     }
 }
 
-/// Consumer usage page
+/// Button usage page
+///
+/// Buttons are ordinal, usage `n` is button `n`, with usage 0 meaning no button pressed.
+/// [`Button::PRIMARY`], [`Button::SECONDARY`] and [`Button::TERTIARY`] are provided for the
+/// conventional pointer buttons.
 ///
 /// See [Universal Serial Bus (USB) HID Usage Tables Version 1.12](<https://www.usb.org/sites/default/files/documents/hut1_12v2.pdf>):
+/// Section 12 Button Page (0x09)
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
+pub struct Button(u16);
+
+impl Button {
+    pub const NO_BUTTON: Self = Self(0x00);
+    pub const PRIMARY: Self = Self(0x01);
+    pub const SECONDARY: Self = Self(0x02);
+    pub const TERTIARY: Self = Self(0x03);
+
+    /// Button `n`, button 0 is [`Button::NO_BUTTON`]
+    #[must_use]
+    pub const fn new(n: u16) -> Self {
+        Self(n)
+    }
+
+    /// Ordinal of the button, this is also its usage ID
+    #[must_use]
+    pub const fn number(self) -> u16 {
+        self.0
+    }
+}
+
+impl From<u16> for Button {
+    fn from(n: u16) -> Self {
+        Self(n)
+    }
+}
+
+impl From<Button> for u16 {
+    fn from(b: Button) -> Self {
+        b.0
+    }
+}
+
+impl PrimitiveEnum for Button {
+    type Primitive = u16;
+
+    fn from_primitive(val: u16) -> Option<Self> {
+        Some(Self(val))
+    }
+
+    fn to_primitive(&self) -> u16 {
+        self.0
+    }
+
+    fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "NoButton" => Some(Self::NO_BUTTON),
+            _ => s.strip_prefix("Button")?.parse().ok().map(Self),
+        }
+    }
+
+    fn from_str_lower(s: &str) -> Option<Self> {
+        match s {
+            "nobutton" => Some(Self::NO_BUTTON),
+            _ => s.strip_prefix("button")?.parse().ok().map(Self),
+        }
+    }
+}
+
+/// Consumer usage page
+///
+/// See [HID Usage Tables for Universal Serial Bus (USB) Version 1.4](<https://usb.org/sites/default/files/hut1_4.pdf>):
 /// Section 15 Consumer Page (0x0C)
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[derive(
@@ -152,7 +226,7 @@ pub enum Consumer {
     Plus10 = 0x20,
     Plus100 = 0x21,
     AmPm = 0x22,
-    //0x23-0x3F Reserved
+    //0x23-0x2F Reserved
     Power = 0x30,
     Reset = 0x31,
     Sleep = 0x32,
@@ -178,7 +252,31 @@ pub enum Consumer {
     BroadcastMode = 0x64,
     Snapshot = 0x65,
     Still = 0x66,
-    //0x67-0x7F Reserved
+    PictureByPictureToggle = 0x67,
+    PictureByPictureSwap = 0x68,
+    RedMenuButton = 0x69,
+    GreenMenuButton = 0x6A,
+    BlueMenuButton = 0x6B,
+    YellowMenuButton = 0x6C,
+    Aspect = 0x6D,
+    ThreeDModeSelect = 0x6E,
+    DisplayBrightnessIncrement = 0x6F,
+    DisplayBrightnessDecrement = 0x70,
+    DisplayBrightness = 0x71,
+    DisplayBacklightToggle = 0x72,
+    DisplaySetBrightnessToMinimum = 0x73,
+    DisplaySetBrightnessToMaximum = 0x74,
+    DisplaySetAutoBrightness = 0x75,
+    CameraAccessEnabled = 0x76,
+    CameraAccessDisabled = 0x77,
+    CameraAccessToggle = 0x78,
+    KeyboardBrightnessIncrement = 0x79,
+    KeyboardBrightnessDecrement = 0x7A,
+    KeyboardBacklightSetLevel = 0x7B,
+    KeyboardBacklightOOC = 0x7C,
+    KeyboardBacklightSetMinimum = 0x7D,
+    KeyboardBacklightSetMaximum = 0x7E,
+    KeyboardBacklightAuto = 0x7F,
     Selection = 0x80,
     AssignSelection = 0x81,
     ModeStep = 0x82,
@@ -248,7 +346,18 @@ pub enum Consumer {
     StopEject = 0xCC,
     PlayPause = 0xCD,
     PlaySkip = 0xCE,
-    //0xCF-0xDF Reserved
+    VoiceCommand = 0xCF,
+    InvokeCaptureInterface = 0xD0,
+    StartOrStopGameRecording = 0xD1,
+    HistoricalGameCapture = 0xD2,
+    CaptureGameScreenshot = 0xD3,
+    ShowOrHideRecordingIndicator = 0xD4,
+    StartOrStopMicrophoneCapture = 0xD5,
+    StartOrStopCameraCapture = 0xD6,
+    StartOrStopGameBroadcast = 0xD7,
+    StartOrStopVoiceDictationSession = 0xD8,
+    InvokeDismissEmojiPicker = 0xD9,
+    //0xDA-0xDF Reserved
     Volume = 0xE0,
     Balance = 0xE1,
     Mute = 0xE2,
@@ -367,7 +476,7 @@ pub enum Consumer {
     ALMovieBrowser = 0x1B8,
     ALDigitalRightsManager = 0x1B9,
     ALDigitalWallet = 0x1BA,
-    //0x-0x1BB Reserved
+    //0x1BB Reserved
     ALInstantMessaging = 0x1BC,
     ALOemFeaturesTipsTutorialBrowser = 0x1BD,
     ALOemHelp = 0x1BE,
@@ -380,7 +489,11 @@ pub enum Consumer {
     ALOnlineActivityBrowser = 0x1C5,
     ALResearchSearchBrowser = 0x1C6,
     ALAudioPlayer = 0x1C7,
-    //0x1C8-0x1FF Reserved
+    ALMessageStatus = 0x1C8,
+    ALContactSync = 0x1C9,
+    ALNavigation = 0x1CA,
+    ALContextAwareDesktopAssistant = 0x1CB,
+    //0x1CC-0x1FF Reserved
     GenericGUIApplicationControls = 0x200,
     ACNew = 0x201,
     ACOpen = 0x202,
@@ -391,6 +504,7 @@ pub enum Consumer {
     ACSave = 0x207,
     ACPrint = 0x208,
     ACProperties = 0x209,
+    //0x20A-0x219 Reserved
     ACUndo = 0x21A,
     ACCopy = 0x21B,
     ACCut = 0x21C,
@@ -522,7 +636,57 @@ pub enum Consumer {
     ACSplit = 0x29A,
     ACDistributeHorizontally = 0x29B,
     ACDistributeVertically = 0x29C,
-    //0x29D-0xFFFF Reserved
+    ACNextKeyboardLayoutSelect = 0x29D,
+    ACNavigationGuidance = 0x29E,
+    ACDesktopShowAllWindows = 0x29F,
+    ACSoftKeyLeft = 0x2A0,
+    ACSoftKeyRight = 0x2A1,
+    ACDesktopShowAllApplications = 0x2A2,
+    //0x2A3-0x2AF Reserved
+    ACIdleKeepAlive = 0x2B0,
+    //0x2B1-0x2BF Reserved
+    ExtendedKeyboardAttributesCollection = 0x2C0,
+    KeyboardFormFactor = 0x2C1,
+    KeyboardKeyType = 0x2C2,
+    KeyboardPhysicalLayout = 0x2C3,
+    VendorSpecificKeyboardPhysicalLayout = 0x2C4,
+    KeyboardIetfLanguageTagIndex = 0x2C5,
+    ImplementedKeyboardInputAssistControls = 0x2C6,
+    KeyboardInputAssistPrevious = 0x2C7,
+    KeyboardInputAssistNext = 0x2C8,
+    KeyboardInputAssistPreviousGroup = 0x2C9,
+    KeyboardInputAssistNextGroup = 0x2CA,
+    KeyboardInputAssistAccept = 0x2CB,
+    KeyboardInputAssistCancel = 0x2CC,
+    //0x2CD-0x2CF Reserved
+    PrivacyScreenToggle = 0x2D0,
+    PrivacyScreenLevelDecrement = 0x2D1,
+    PrivacyScreenLevelIncrement = 0x2D2,
+    PrivacyScreenLevelMinimum = 0x2D3,
+    PrivacyScreenLevelMaximum = 0x2D4,
+    //0x2D5-0x4FF Reserved
+    ContactEdited = 0x500,
+    ContactAdded = 0x501,
+    ContactRecordActive = 0x502,
+    ContactIndex = 0x503,
+    ContactNickname = 0x504,
+    ContactFirstName = 0x505,
+    ContactLastName = 0x506,
+    ContactFullName = 0x507,
+    ContactPhoneNumberPersonal = 0x508,
+    ContactPhoneNumberBusiness = 0x509,
+    ContactPhoneNumberMobile = 0x50A,
+    ContactPhoneNumberPager = 0x50B,
+    ContactPhoneNumberFax = 0x50C,
+    ContactPhoneNumberOther = 0x50D,
+    ContactEmailPersonal = 0x50E,
+    ContactEmailBusiness = 0x50F,
+    ContactEmailOther = 0x510,
+    ContactEmailMain = 0x511,
+    ContactSpeedDialNumber = 0x512,
+    ContactStatusFlag = 0x513,
+    ContactMisc = 0x514,
+    //0x515-0xFFFF Reserved
 }
 
 impl Default for Consumer {
@@ -562,7 +726,17 @@ pub enum Desktop {
     Keypad = 0x07,
     MultiAxisController = 0x08,
     TabletPcSystemControls = 0x09,
-    //0x0A-0x2F Reserved
+    WaterCoolingDevice = 0x0A,
+    ComputerChassisDevice = 0x0B,
+    WirelessRadioControls = 0x0C,
+    PortableDeviceControl = 0x0D,
+    SystemMultiAxisController = 0x0E,
+    SpatialController = 0x0F,
+    AssistiveControl = 0x10,
+    DeviceDock = 0x11,
+    DockableDevice = 0x12,
+    CallStateManagementControl = 0x13,
+    //0x14-0x2F Reserved
     X = 0x30,
     Y = 0x31,
     Z = 0x32,
@@ -680,11 +854,14 @@ impl Default for Game {
 
 /// Keyboard usage page
 ///
-/// See [Universal Serial Bus (USB) HID Usage Tables Version 1.12](<https://www.usb.org/sites/default/files/documents/hut1_12v2.pdf>):
-/// Section 10 Keyboard/Keypad Page (0x04)
+/// See [HID Usage Tables for Universal Serial Bus (USB) Version 1.4](<https://usb.org/sites/default/files/hut1_4.pdf>):
+/// Section 10 Keyboard/Keypad Page (0x07)
 ///
 /// Naming from the specification has been preserved where possible but some names
 /// have been shortened or transliterated to be valid rust identifiers
+///
+/// Usages `0xE8` to `0xFB` are reserved by the specification, but Linux maps them to media keys,
+/// see [`Keyboard::MediaPlayPause`]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[derive(
     Debug,
@@ -867,7 +1044,54 @@ pub enum Keyboard {
     ClearAgain = 0xA2,
     CrSelProps = 0xA3,
     ExSel = 0xA4,
-    //0xA5-0xDF Reserved
+    //0xA5-0xAF Reserved
+    Keypad00 = 0xB0,
+    Keypad000 = 0xB1,
+    ThousandsSeparator = 0xB2,
+    DecimalSeparator = 0xB3,
+    CurrencyUnit = 0xB4,
+    CurrencySubunit = 0xB5,
+    KeypadLeftParenthesis = 0xB6,
+    KeypadRightParenthesis = 0xB7,
+    KeypadLeftCurlyBrace = 0xB8,
+    KeypadRightCurlyBrace = 0xB9,
+    KeypadTab = 0xBA,
+    KeypadBackspace = 0xBB,
+    KeypadA = 0xBC,
+    KeypadB = 0xBD,
+    KeypadC = 0xBE,
+    KeypadD = 0xBF,
+    KeypadE = 0xC0,
+    KeypadF = 0xC1,
+    KeypadXor = 0xC2,
+    KeypadCaret = 0xC3,
+    KeypadPercentage = 0xC4,
+    KeypadLessThan = 0xC5,
+    KeypadGreaterThan = 0xC6,
+    KeypadAmpersand = 0xC7,
+    KeypadDoubleAmpersand = 0xC8,
+    KeypadBar = 0xC9,
+    KeypadDoubleBar = 0xCA,
+    KeypadColon = 0xCB,
+    KeypadHash = 0xCC,
+    KeypadSpace = 0xCD,
+    KeypadAt = 0xCE,
+    KeypadExclamation = 0xCF,
+    KeypadMemoryStore = 0xD0,
+    KeypadMemoryRecall = 0xD1,
+    KeypadMemoryClear = 0xD2,
+    KeypadMemoryAdd = 0xD3,
+    KeypadMemorySubtract = 0xD4,
+    KeypadMemoryMultiply = 0xD5,
+    KeypadMemoryDivide = 0xD6,
+    KeypadPlusMinus = 0xD7,
+    KeypadClear = 0xD8,
+    KeypadClearEntry = 0xD9,
+    KeypadBinary = 0xDA,
+    KeypadOctal = 0xDB,
+    KeypadDecimal = 0xDC,
+    KeypadHexadecimal = 0xDD,
+    //0xDE-0xDF Reserved
     LeftControl = 0xE0,
     LeftShift = 0xE1,
     LeftAlt = 0xE2,
@@ -876,7 +1100,29 @@ pub enum Keyboard {
     RightShift = 0xE5,
     RightAlt = 0xE6,
     RightGUI = 0xE7,
-    //0xE8-0xFFFF Reserved
+    /// Not defined by the HID Usage Tables, mapped to media keys by Linux. Hosts following the
+    /// specification ignore these usages, prefer the [`Consumer`] page where possible
+    MediaPlayPause = 0xE8,
+    MediaStopCD = 0xE9,
+    MediaPreviousSong = 0xEA,
+    MediaNextSong = 0xEB,
+    MediaEjectCD = 0xEC,
+    MediaVolumeUp = 0xED,
+    MediaVolumeDown = 0xEE,
+    MediaMute = 0xEF,
+    MediaWWW = 0xF0,
+    MediaBack = 0xF1,
+    MediaForward = 0xF2,
+    MediaStop = 0xF3,
+    MediaFind = 0xF4,
+    MediaScrollUp = 0xF5,
+    MediaScrollDown = 0xF6,
+    MediaEdit = 0xF7,
+    MediaSleep = 0xF8,
+    MediaCoffee = 0xF9,
+    MediaRefresh = 0xFA,
+    MediaCalc = 0xFB,
+    //0xFC-0xFFFF Reserved
 }
 
 impl Default for Keyboard {
@@ -1069,3 +1315,2434 @@ impl Default for Telephony {
         Self::Unassigned
     }
 }
+
+/// Sensors usage page
+///
+/// Data field usages may be combined with a data field modifier in the upper four bits of the
+/// usage, e.g. `0x1000` change sensitivity absolute, these combinations are not enumerated and
+/// convert to [`Sensor::Undefined`].
+///
+/// See [HID Usage Tables for Universal Serial Bus (USB) Version 1.4](<https://usb.org/sites/default/files/hut1_4.pdf>):
+/// Sensors Page (0x20)
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    PrimitiveEnum,
+    Hash,
+    IntoPrimitive,
+    FromPrimitive,
+    Default,
+)]
+#[repr(u16)]
+pub enum Sensor {
+    #[default]
+    Undefined = 0x00,
+    Sensor = 0x01,
+    //0x02-0x0F Reserved
+    Biometric = 0x10,
+    BiometricHumanPresence = 0x11,
+    BiometricHumanProximity = 0x12,
+    BiometricHumanTouch = 0x13,
+    BiometricBloodPressure = 0x14,
+    BiometricBodyTemperature = 0x15,
+    BiometricHeartRate = 0x16,
+    BiometricHeartRateVariability = 0x17,
+    BiometricPeripheralOxygenSaturation = 0x18,
+    BiometricRespiratoryRate = 0x19,
+    //0x1A-0x1F Reserved
+    Electrical = 0x20,
+    ElectricalCapacitance = 0x21,
+    ElectricalCurrent = 0x22,
+    ElectricalPower = 0x23,
+    ElectricalInductance = 0x24,
+    ElectricalResistance = 0x25,
+    ElectricalVoltage = 0x26,
+    ElectricalPotentiometer = 0x27,
+    ElectricalFrequency = 0x28,
+    ElectricalPeriod = 0x29,
+    //0x2A-0x2F Reserved
+    Environmental = 0x30,
+    EnvironmentalAtmosphericPressure = 0x31,
+    EnvironmentalHumidity = 0x32,
+    EnvironmentalTemperature = 0x33,
+    EnvironmentalWindDirection = 0x34,
+    EnvironmentalWindSpeed = 0x35,
+    EnvironmentalAirQuality = 0x36,
+    EnvironmentalHeatIndex = 0x37,
+    EnvironmentalSurfaceTemperature = 0x38,
+    EnvironmentalVolatileOrganicCompounds = 0x39,
+    EnvironmentalObjectPresence = 0x3A,
+    EnvironmentalObjectProximity = 0x3B,
+    //0x3C-0x3F Reserved
+    Light = 0x40,
+    LightAmbientLight = 0x41,
+    LightConsumerInfrared = 0x42,
+    LightInfraredLight = 0x43,
+    LightVisibleLight = 0x44,
+    LightUltravioletLight = 0x45,
+    //0x46-0x4F Reserved
+    Location = 0x50,
+    LocationBroadcast = 0x51,
+    LocationDeadReckoning = 0x52,
+    LocationGPS = 0x53,
+    LocationLookup = 0x54,
+    LocationOther = 0x55,
+    LocationStatic = 0x56,
+    LocationTriangulation = 0x57,
+    //0x58-0x5F Reserved
+    Mechanical = 0x60,
+    MechanicalBooleanSwitch = 0x61,
+    MechanicalBooleanSwitchArray = 0x62,
+    MechanicalMultivalueSwitch = 0x63,
+    MechanicalForce = 0x64,
+    MechanicalPressure = 0x65,
+    MechanicalStrain = 0x66,
+    MechanicalWeight = 0x67,
+    MechanicalHapticVibrator = 0x68,
+    MechanicalHallEffectSwitch = 0x69,
+    //0x6A-0x6F Reserved
+    Motion = 0x70,
+    MotionAccelerometer1D = 0x71,
+    MotionAccelerometer2D = 0x72,
+    MotionAccelerometer3D = 0x73,
+    MotionGyrometer1D = 0x74,
+    MotionGyrometer2D = 0x75,
+    MotionGyrometer3D = 0x76,
+    MotionMotionDetector = 0x77,
+    MotionSpeedometer = 0x78,
+    MotionAccelerometer = 0x79,
+    MotionGyrometer = 0x7A,
+    MotionGravityVector = 0x7B,
+    MotionLinearAccelerometer = 0x7C,
+    //0x7D-0x7F Reserved
+    Orientation = 0x80,
+    OrientationCompass1D = 0x81,
+    OrientationCompass2D = 0x82,
+    OrientationCompass3D = 0x83,
+    OrientationInclinometer1D = 0x84,
+    OrientationInclinometer2D = 0x85,
+    OrientationInclinometer3D = 0x86,
+    OrientationDistance1D = 0x87,
+    OrientationDistance2D = 0x88,
+    OrientationDistance3D = 0x89,
+    OrientationDeviceOrientation = 0x8A,
+    OrientationCompass = 0x8B,
+    OrientationInclinometer = 0x8C,
+    OrientationDistance = 0x8D,
+    OrientationRelativeOrientation = 0x8E,
+    OrientationSimpleOrientation = 0x8F,
+    Scanner = 0x90,
+    ScannerBarcode = 0x91,
+    ScannerRFID = 0x92,
+    ScannerNFC = 0x93,
+    //0x94-0x9F Reserved
+    Time = 0xA0,
+    TimeAlarmTimer = 0xA1,
+    TimeRealTimeClock = 0xA2,
+    //0xA3-0xAF Reserved
+    PersonalActivity = 0xB0,
+    PersonalActivityActivityDetection = 0xB1,
+    PersonalActivityDevicePosition = 0xB2,
+    PersonalActivityFloorTracker = 0xB3,
+    PersonalActivityPedometer = 0xB4,
+    PersonalActivityStepDetection = 0xB5,
+    //0xB6-0xBF Reserved
+    OrientationExtended = 0xC0,
+    OrientationExtendedGeomagneticOrientation = 0xC1,
+    OrientationExtendedMagnetometer = 0xC2,
+    //0xC3-0xCF Reserved
+    Gesture = 0xD0,
+    GestureChassisFlipGesture = 0xD1,
+    GestureHingeFoldGesture = 0xD2,
+    //0xD3-0xDF Reserved
+    Other = 0xE0,
+    OtherCustom = 0xE1,
+    OtherGeneric = 0xE2,
+    OtherGenericEnumerator = 0xE3,
+    OtherHingeAngle = 0xE4,
+    //0xE5-0xEF Reserved
+    VendorReserved1 = 0xF0,
+    VendorReserved2 = 0xF1,
+    VendorReserved3 = 0xF2,
+    VendorReserved4 = 0xF3,
+    VendorReserved5 = 0xF4,
+    VendorReserved6 = 0xF5,
+    VendorReserved7 = 0xF6,
+    VendorReserved8 = 0xF7,
+    VendorReserved9 = 0xF8,
+    VendorReserved10 = 0xF9,
+    VendorReserved11 = 0xFA,
+    VendorReserved12 = 0xFB,
+    VendorReserved13 = 0xFC,
+    VendorReserved14 = 0xFD,
+    VendorReserved15 = 0xFE,
+    VendorReserved16 = 0xFF,
+    //0x100-0x1FF Reserved
+    Event = 0x200,
+    EventSensorState = 0x201,
+    EventSensorEvent = 0x202,
+    //0x203-0x2FF Reserved
+    Property = 0x300,
+    PropertyFriendlyName = 0x301,
+    PropertyPersistentUniqueID = 0x302,
+    PropertySensorStatus = 0x303,
+    PropertyMinimumReportInterval = 0x304,
+    PropertySensorManufacturer = 0x305,
+    PropertySensorModel = 0x306,
+    PropertySensorSerialNumber = 0x307,
+    PropertySensorDescription = 0x308,
+    PropertySensorConnectionType = 0x309,
+    PropertySensorDevicePath = 0x30A,
+    PropertyHardwareRevision = 0x30B,
+    PropertyFirmwareVersion = 0x30C,
+    PropertyReleaseDate = 0x30D,
+    PropertyReportInterval = 0x30E,
+    PropertyChangeSensitivityAbsolute = 0x30F,
+    PropertyChangeSensitivityPercentOfRange = 0x310,
+    PropertyChangeSensitivityPercentRelative = 0x311,
+    PropertyAccuracy = 0x312,
+    PropertyResolution = 0x313,
+    PropertyMaximum = 0x314,
+    PropertyMinimum = 0x315,
+    PropertyReportingState = 0x316,
+    PropertySamplingRate = 0x317,
+    PropertyResponseCurve = 0x318,
+    PropertyPowerState = 0x319,
+    PropertyMaximumFIFOEvents = 0x31A,
+    PropertyReportLatency = 0x31B,
+    PropertyFlushFIFOEvents = 0x31C,
+    PropertyMaximumPowerConsumption = 0x31D,
+    PropertyIsPrimary = 0x31E,
+    PropertyHumanPresenceDetectionType = 0x31F,
+    //0x320-0x3FF Reserved
+    DataFieldLocation = 0x400,
+    //0x401 Reserved
+    DataFieldAltitudeAntennaSeaLevel = 0x402,
+    DataFieldDifferentialReferenceStationID = 0x403,
+    DataFieldAltitudeEllipsoidError = 0x404,
+    DataFieldAltitudeEllipsoid = 0x405,
+    DataFieldAltitudeSeaLevelError = 0x406,
+    DataFieldAltitudeSeaLevel = 0x407,
+    DataFieldDifferentialGPSDataAge = 0x408,
+    DataFieldErrorRadius = 0x409,
+    DataFieldFixQuality = 0x40A,
+    DataFieldFixType = 0x40B,
+    DataFieldGeoidalSeparation = 0x40C,
+    DataFieldGPSOperationMode = 0x40D,
+    DataFieldGPSSelectionMode = 0x40E,
+    DataFieldGPSStatus = 0x40F,
+    DataFieldPositionDilutionOfPrecision = 0x410,
+    DataFieldHorizontalDilutionOfPrecision = 0x411,
+    DataFieldVerticalDilutionOfPrecision = 0x412,
+    DataFieldLatitude = 0x413,
+    DataFieldLongitude = 0x414,
+    DataFieldTrueHeading = 0x415,
+    DataFieldMagneticHeading = 0x416,
+    DataFieldMagneticVariation = 0x417,
+    DataFieldSpeed = 0x418,
+    DataFieldSatellitesInView = 0x419,
+    DataFieldSatellitesInViewAzimuth = 0x41A,
+    DataFieldSatellitesInViewElevation = 0x41B,
+    DataFieldSatellitesInViewIDs = 0x41C,
+    DataFieldSatellitesInViewPRNs = 0x41D,
+    DataFieldSatellitesInViewSNRatios = 0x41E,
+    DataFieldSatellitesUsedCount = 0x41F,
+    DataFieldSatellitesUsedPRNs = 0x420,
+    DataFieldNMEASentence = 0x421,
+    DataFieldAddressLine1 = 0x422,
+    DataFieldAddressLine2 = 0x423,
+    DataFieldCity = 0x424,
+    DataFieldStateOrProvince = 0x425,
+    DataFieldCountryOrRegion = 0x426,
+    DataFieldPostalCode = 0x427,
+    //0x428-0x42F Reserved
+    DataFieldEnvironmental = 0x430,
+    DataFieldAtmosphericPressure = 0x431,
+    //0x432 Reserved
+    DataFieldRelativeHumidity = 0x433,
+    DataFieldTemperature = 0x434,
+    DataFieldWindDirection = 0x435,
+    DataFieldWindSpeed = 0x436,
+    DataFieldAirQualityIndex = 0x437,
+    DataFieldEquivalentCO2 = 0x438,
+    DataFieldVolatileOrganicCompoundConcentration = 0x439,
+    DataFieldObjectPresence = 0x43A,
+    DataFieldObjectProximityRange = 0x43B,
+    DataFieldObjectProximityOutOfRange = 0x43C,
+    //0x43D-0x43F Reserved
+    PropertyEnvironmental = 0x440,
+    PropertyReferencePressure = 0x441,
+    //0x442-0x44F Reserved
+    DataFieldMotion = 0x450,
+    DataFieldMotionState = 0x451,
+    DataFieldAcceleration = 0x452,
+    DataFieldAccelerationAxisX = 0x453,
+    DataFieldAccelerationAxisY = 0x454,
+    DataFieldAccelerationAxisZ = 0x455,
+    DataFieldAngularVelocity = 0x456,
+    DataFieldAngularVelocityAboutXAxis = 0x457,
+    DataFieldAngularVelocityAboutYAxis = 0x458,
+    DataFieldAngularVelocityAboutZAxis = 0x459,
+    DataFieldAngularPosition = 0x45A,
+    DataFieldAngularPositionAboutXAxis = 0x45B,
+    DataFieldAngularPositionAboutYAxis = 0x45C,
+    DataFieldAngularPositionAboutZAxis = 0x45D,
+    DataFieldMotionSpeed = 0x45E,
+    DataFieldMotionIntensity = 0x45F,
+    //0x460-0x46F Reserved
+    DataFieldOrientation = 0x470,
+    DataFieldHeading = 0x471,
+    DataFieldHeadingXAxis = 0x472,
+    DataFieldHeadingYAxis = 0x473,
+    DataFieldHeadingZAxis = 0x474,
+    DataFieldHeadingCompensatedMagneticNorth = 0x475,
+    DataFieldHeadingCompensatedTrueNorth = 0x476,
+    DataFieldHeadingMagneticNorth = 0x477,
+    DataFieldHeadingTrueNorth = 0x478,
+    DataFieldDistance = 0x479,
+    DataFieldDistanceXAxis = 0x47A,
+    DataFieldDistanceYAxis = 0x47B,
+    DataFieldDistanceZAxis = 0x47C,
+    DataFieldDistanceOutOfRange = 0x47D,
+    DataFieldTilt = 0x47E,
+    DataFieldTiltXAxis = 0x47F,
+    DataFieldTiltYAxis = 0x480,
+    DataFieldTiltZAxis = 0x481,
+    DataFieldRotationMatrix = 0x482,
+    DataFieldQuaternion = 0x483,
+    DataFieldMagneticFlux = 0x484,
+    DataFieldMagneticFluxXAxis = 0x485,
+    DataFieldMagneticFluxYAxis = 0x486,
+    DataFieldMagneticFluxZAxis = 0x487,
+    DataFieldMagnetometerAccuracy = 0x488,
+    DataFieldSimpleOrientationDirection = 0x489,
+    //0x48A-0x48F Reserved
+    DataFieldMechanical = 0x490,
+    DataFieldBooleanSwitchState = 0x491,
+    DataFieldBooleanSwitchArrayStates = 0x492,
+    DataFieldMultivalueSwitchValue = 0x493,
+    DataFieldForce = 0x494,
+    DataFieldAbsolutePressure = 0x495,
+    DataFieldGaugePressure = 0x496,
+    DataFieldStrain = 0x497,
+    DataFieldWeight = 0x498,
+    //0x499-0x49F Reserved
+    PropertyMechanical = 0x4A0,
+    PropertyVibrationState = 0x4A1,
+    PropertyForwardVibrationSpeed = 0x4A2,
+    PropertyBackwardVibrationSpeed = 0x4A3,
+    //0x4A4-0x4AF Reserved
+    DataFieldBiometric = 0x4B0,
+    DataFieldHumanPresence = 0x4B1,
+    DataFieldHumanProximityRange = 0x4B2,
+    DataFieldHumanProximityOutOfRange = 0x4B3,
+    DataFieldHumanTouchState = 0x4B4,
+    DataFieldBloodPressure = 0x4B5,
+    DataFieldBloodPressureDiastolic = 0x4B6,
+    DataFieldBloodPressureSystolic = 0x4B7,
+    DataFieldHeartRate = 0x4B8,
+    DataFieldRestingHeartRate = 0x4B9,
+    DataFieldHeartbeatInterval = 0x4BA,
+    DataFieldRespiratoryRate = 0x4BB,
+    DataFieldSpO2 = 0x4BC,
+    //0x4BD-0x4CF Reserved
+    DataFieldLight = 0x4D0,
+    DataFieldIlluminance = 0x4D1,
+    DataFieldColorTemperature = 0x4D2,
+    DataFieldChromaticity = 0x4D3,
+    DataFieldChromaticityX = 0x4D4,
+    DataFieldChromaticityY = 0x4D5,
+    DataFieldConsumerIRSentenceReceive = 0x4D6,
+    DataFieldInfraredLight = 0x4D7,
+    DataFieldRedLight = 0x4D8,
+    DataFieldGreenLight = 0x4D9,
+    DataFieldBlueLight = 0x4DA,
+    DataFieldUltravioletALight = 0x4DB,
+    DataFieldUltravioletBLight = 0x4DC,
+    DataFieldUltravioletIndex = 0x4DD,
+    DataFieldNearInfraredLight = 0x4DE,
+    //0x4DF Reserved
+    PropertyLight = 0x4E0,
+    PropertyConsumerIRSentenceSend = 0x4E1,
+    PropertyAutoBrightnessPreferred = 0x4E2,
+    PropertyAutoColorPreferred = 0x4E3,
+    //0x4E4-0x4EF Reserved
+    DataFieldScanner = 0x4F0,
+    DataFieldRFIDTag40Bit = 0x4F1,
+    DataFieldNFCSentenceReceive = 0x4F2,
+    //0x4F3-0x4F7 Reserved
+    PropertyScanner = 0x4F8,
+    PropertyNFCSentenceSend = 0x4F9,
+    //0x4FA-0x4FF Reserved
+    DataFieldElectrical = 0x500,
+    DataFieldCapacitance = 0x501,
+    DataFieldCurrent = 0x502,
+    DataFieldElectricalPower = 0x503,
+    DataFieldInductance = 0x504,
+    DataFieldResistance = 0x505,
+    DataFieldVoltage = 0x506,
+    DataFieldFrequency = 0x507,
+    DataFieldPeriod = 0x508,
+    DataFieldPercentOfRange = 0x509,
+    //0x50A-0x51F Reserved
+    DataFieldTime = 0x520,
+    DataFieldYear = 0x521,
+    DataFieldMonth = 0x522,
+    DataFieldDay = 0x523,
+    DataFieldDayOfWeek = 0x524,
+    DataFieldHour = 0x525,
+    DataFieldMinute = 0x526,
+    DataFieldSecond = 0x527,
+    DataFieldMillisecond = 0x528,
+    DataFieldTimestamp = 0x529,
+    DataFieldJulianDayOfYear = 0x52A,
+    DataFieldTimeSinceSystemBoot = 0x52B,
+    //0x52C-0x52F Reserved
+    PropertyTime = 0x530,
+    PropertyTimeZoneOffsetFromUTC = 0x531,
+    PropertyTimeZoneName = 0x532,
+    PropertyDaylightSavingsTimeObserved = 0x533,
+    PropertyTimeTrimAdjustment = 0x534,
+    PropertyArmAlarm = 0x535,
+    //0x536-0x53F Reserved
+    DataFieldCustom = 0x540,
+    DataFieldCustomUsage = 0x541,
+    DataFieldCustomBooleanArray = 0x542,
+    DataFieldCustomValue = 0x543,
+    DataFieldCustomValue1 = 0x544,
+    DataFieldCustomValue2 = 0x545,
+    DataFieldCustomValue3 = 0x546,
+    DataFieldCustomValue4 = 0x547,
+    DataFieldCustomValue5 = 0x548,
+    DataFieldCustomValue6 = 0x549,
+    DataFieldCustomValue7 = 0x54A,
+    DataFieldCustomValue8 = 0x54B,
+    DataFieldCustomValue9 = 0x54C,
+    DataFieldCustomValue10 = 0x54D,
+    DataFieldCustomValue11 = 0x54E,
+    DataFieldCustomValue12 = 0x54F,
+    DataFieldCustomValue13 = 0x550,
+    DataFieldCustomValue14 = 0x551,
+    DataFieldCustomValue15 = 0x552,
+    DataFieldCustomValue16 = 0x553,
+    DataFieldCustomValue17 = 0x554,
+    DataFieldCustomValue18 = 0x555,
+    DataFieldCustomValue19 = 0x556,
+    DataFieldCustomValue20 = 0x557,
+    DataFieldCustomValue21 = 0x558,
+    DataFieldCustomValue22 = 0x559,
+    DataFieldCustomValue23 = 0x55A,
+    DataFieldCustomValue24 = 0x55B,
+    DataFieldCustomValue25 = 0x55C,
+    DataFieldCustomValue26 = 0x55D,
+    DataFieldCustomValue27 = 0x55E,
+    DataFieldCustomValue28 = 0x55F,
+    //0x560-0x58F Generic data fields and properties, not enumerated
+    DataFieldPersonalActivity = 0x590,
+    DataFieldActivityType = 0x591,
+    DataFieldActivityState = 0x592,
+    DataFieldDevicePosition = 0x593,
+    DataFieldStepCount = 0x594,
+    DataFieldStepCountReset = 0x595,
+    DataFieldStepDuration = 0x596,
+    DataFieldStepType = 0x597,
+    //0x598-0x59F Reserved
+    PropertyPersonalActivity = 0x5A0,
+    PropertyMinimumActivityDetectionInterval = 0x5A1,
+    PropertySupportedActivityTypes = 0x5A2,
+    PropertySubscribedActivityTypes = 0x5A3,
+    PropertySupportedStepTypes = 0x5A4,
+    PropertySubscribedStepTypes = 0x5A5,
+    PropertyFloorHeight = 0x5A6,
+    //0x5A7-0x7FF Custom type, hinge and gesture data fields, not enumerated
+    SensorStateUndefined = 0x800,
+    SensorStateReady = 0x801,
+    SensorStateNotAvailable = 0x802,
+    SensorStateNoData = 0x803,
+    SensorStateInitializing = 0x804,
+    SensorStateAccessDenied = 0x805,
+    SensorStateError = 0x806,
+    //0x807-0x80F Reserved
+    SensorEventUnknown = 0x810,
+    SensorEventStateChanged = 0x811,
+    SensorEventPropertyChanged = 0x812,
+    SensorEventDataUpdated = 0x813,
+    SensorEventPollResponse = 0x814,
+    SensorEventChangeSensitivity = 0x815,
+    SensorEventRangeMaximumReached = 0x816,
+    SensorEventRangeMinimumReached = 0x817,
+    SensorEventHighThresholdCrossUpward = 0x818,
+    SensorEventHighThresholdCrossDownward = 0x819,
+    SensorEventLowThresholdCrossUpward = 0x81A,
+    SensorEventLowThresholdCrossDownward = 0x81B,
+    SensorEventZeroThresholdCrossUpward = 0x81C,
+    SensorEventZeroThresholdCrossDownward = 0x81D,
+    SensorEventPeriodExceeded = 0x81E,
+    SensorEventFrequencyExceeded = 0x81F,
+    SensorEventComplexTrigger = 0x820,
+    //0x821-0x82F Reserved
+    ConnectionTypePCIntegrated = 0x830,
+    ConnectionTypePCAttached = 0x831,
+    ConnectionTypePCExternal = 0x832,
+    //0x833-0x83F Reserved
+    ReportingStateReportNoEvents = 0x840,
+    ReportingStateReportAllEvents = 0x841,
+    ReportingStateReportThresholdEvents = 0x842,
+    ReportingStateWakeOnNoEvents = 0x843,
+    ReportingStateWakeOnAllEvents = 0x844,
+    ReportingStateWakeOnThresholdEvents = 0x845,
+    //0x846-0x84F Reserved
+    PowerStateUndefined = 0x850,
+    PowerStateD0FullPower = 0x851,
+    PowerStateD1LowPower = 0x852,
+    PowerStateD2StandbyPowerWithWakeup = 0x853,
+    PowerStateD3SleepWithWakeup = 0x854,
+    PowerStateD4PowerOff = 0x855,
+    //0x856-0xFFFF Further selectors and data field modifiers, not enumerated
+}
+
+/// Lighting And Illumination usage page, used by devices implementing the `LampArray` interface
+///
+/// See [HID Usage Tables for Universal Serial Bus (USB) Version 1.4](<https://usb.org/sites/default/files/hut1_4.pdf>):
+/// Lighting And Illumination Page (0x59)
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    PrimitiveEnum,
+    Hash,
+    IntoPrimitive,
+    FromPrimitive,
+    Default,
+)]
+#[repr(u8)]
+pub enum LightingAndIllumination {
+    #[default]
+    Undefined = 0x00,
+    LampArray = 0x01,
+    LampArrayAttributesReport = 0x02,
+    LampCount = 0x03,
+    BoundingBoxWidthInMicrometers = 0x04,
+    BoundingBoxHeightInMicrometers = 0x05,
+    BoundingBoxDepthInMicrometers = 0x06,
+    LampArrayKind = 0x07,
+    MinUpdateIntervalInMicroseconds = 0x08,
+    //0x09-0x1F Reserved
+    LampAttributesRequestReport = 0x20,
+    LampId = 0x21,
+    LampAttributesResponseReport = 0x22,
+    PositionXInMicrometers = 0x23,
+    PositionYInMicrometers = 0x24,
+    PositionZInMicrometers = 0x25,
+    LampPurposes = 0x26,
+    UpdateLatencyInMicroseconds = 0x27,
+    RedLevelCount = 0x28,
+    GreenLevelCount = 0x29,
+    BlueLevelCount = 0x2A,
+    IntensityLevelCount = 0x2B,
+    IsProgrammable = 0x2C,
+    InputBinding = 0x2D,
+    //0x2E-0x4F Reserved
+    LampMultiUpdateReport = 0x50,
+    RedUpdateChannel = 0x51,
+    GreenUpdateChannel = 0x52,
+    BlueUpdateChannel = 0x53,
+    IntensityUpdateChannel = 0x54,
+    LampUpdateFlags = 0x55,
+    //0x56-0x5F Reserved
+    LampRangeUpdateReport = 0x60,
+    LampIdStart = 0x61,
+    LampIdEnd = 0x62,
+    //0x63-0x6F Reserved
+    LampArrayControlReport = 0x70,
+    AutonomousMode = 0x71,
+    //0x72-0xFFFF Reserved
+}
+
+/// Haptics usage page
+///
+/// See [HID Usage Tables for Universal Serial Bus (USB) Version 1.4](<https://usb.org/sites/default/files/hut1_4.pdf>):
+/// Haptics Page (0x0E)
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    PrimitiveEnum,
+    Hash,
+    IntoPrimitive,
+    FromPrimitive,
+    Default,
+)]
+#[repr(u16)]
+pub enum Haptics {
+    #[default]
+    Undefined = 0x00,
+    SimpleHapticController = 0x01,
+    //0x02-0x0F Reserved
+    WaveformList = 0x10,
+    DurationList = 0x11,
+    //0x12-0x1F Reserved
+    AutoTrigger = 0x20,
+    ManualTrigger = 0x21,
+    AutoTriggerAssociatedControl = 0x22,
+    Intensity = 0x23,
+    RepeatCount = 0x24,
+    RetriggerPeriod = 0x25,
+    WaveformVendorPage = 0x26,
+    WaveformVendorID = 0x27,
+    WaveformCutoffTime = 0x28,
+    //0x29-0x1000 Reserved
+    WaveformNone = 0x1001,
+    WaveformStop = 0x1002,
+    WaveformClick = 0x1003,
+    WaveformBuzzContinuous = 0x1004,
+    WaveformRumbleContinuous = 0x1005,
+    WaveformPress = 0x1006,
+    WaveformRelease = 0x1007,
+    WaveformHover = 0x1008,
+    WaveformSuccess = 0x1009,
+    WaveformError = 0x100A,
+    WaveformInkContinuous = 0x100B,
+    WaveformPencilContinuous = 0x100C,
+    WaveformMarkerContinuous = 0x100D,
+    WaveformChiselMarkerContinuous = 0x100E,
+    WaveformBrushContinuous = 0x100F,
+    WaveformEraserContinuous = 0x1010,
+    WaveformSparkleContinuous = 0x1011,
+    //0x1012-0x2000 Reserved
+    //0x2001-0x2FFF Reserved for vendor waveforms
+    //0x3000-0xFFFF Reserved
+}
+
+/// Physical Interface Device usage page, used to describe force feedback devices
+///
+/// See [Device Class Definition for Physical Interface Devices (PID) Version 1.0](<https://www.usb.org/sites/default/files/documents/pid1_01.pdf>):
+/// PID Page (0x0F)
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    PrimitiveEnum,
+    Hash,
+    IntoPrimitive,
+    FromPrimitive,
+    Default,
+)]
+#[repr(u8)]
+pub enum PhysicalInterface {
+    #[default]
+    Undefined = 0x00,
+    PhysicalInterfaceDevice = 0x01,
+    //0x02-0x1F Reserved
+    Normal = 0x20,
+    SetEffectReport = 0x21,
+    EffectBlockIndex = 0x22,
+    ParameterBlockOffset = 0x23,
+    ROMFlag = 0x24,
+    EffectType = 0x25,
+    ETConstantForce = 0x26,
+    ETRamp = 0x27,
+    ETCustomForceData = 0x28,
+    //0x29-0x2F Reserved
+    ETSquare = 0x30,
+    ETSine = 0x31,
+    ETTriangle = 0x32,
+    ETSawtoothUp = 0x33,
+    ETSawtoothDown = 0x34,
+    //0x35-0x3F Reserved
+    ETSpring = 0x40,
+    ETDamper = 0x41,
+    ETInertia = 0x42,
+    ETFriction = 0x43,
+    //0x44-0x4F Reserved
+    Duration = 0x50,
+    SamplePeriod = 0x51,
+    Gain = 0x52,
+    TriggerButton = 0x53,
+    TriggerRepeatInterval = 0x54,
+    AxesEnable = 0x55,
+    DirectionEnable = 0x56,
+    Direction = 0x57,
+    TypeSpecificBlockOffset = 0x58,
+    BlockType = 0x59,
+    SetEnvelopeReport = 0x5A,
+    AttackLevel = 0x5B,
+    AttackTime = 0x5C,
+    FadeLevel = 0x5D,
+    FadeTime = 0x5E,
+    SetConditionReport = 0x5F,
+    CPOffset = 0x60,
+    PositiveCoefficient = 0x61,
+    NegativeCoefficient = 0x62,
+    PositiveSaturation = 0x63,
+    NegativeSaturation = 0x64,
+    DeadBand = 0x65,
+    DownloadForceSample = 0x66,
+    IsochCustomForceEnable = 0x67,
+    CustomForceDataReport = 0x68,
+    CustomForceData = 0x69,
+    CustomForceVendorDefinedData = 0x6A,
+    SetCustomForceReport = 0x6B,
+    CustomForceDataOffset = 0x6C,
+    SampleCount = 0x6D,
+    SetPeriodicReport = 0x6E,
+    Offset = 0x6F,
+    Magnitude = 0x70,
+    Phase = 0x71,
+    Period = 0x72,
+    SetConstantForceReport = 0x73,
+    SetRampForceReport = 0x74,
+    RampStart = 0x75,
+    RampEnd = 0x76,
+    EffectOperationReport = 0x77,
+    EffectOperation = 0x78,
+    OpEffectStart = 0x79,
+    OpEffectStartSolo = 0x7A,
+    OpEffectStop = 0x7B,
+    LoopCount = 0x7C,
+    DeviceGainReport = 0x7D,
+    DeviceGain = 0x7E,
+    PIDPoolReport = 0x7F,
+    RAMPoolSize = 0x80,
+    ROMPoolSize = 0x81,
+    ROMEffectBlockCount = 0x82,
+    SimultaneousEffectsMax = 0x83,
+    PoolAlignment = 0x84,
+    PIDPoolMoveReport = 0x85,
+    MoveSource = 0x86,
+    MoveDestination = 0x87,
+    MoveLength = 0x88,
+    PIDBlockLoadReport = 0x89,
+    //0x8A Reserved
+    BlockLoadStatus = 0x8B,
+    BlockLoadSuccess = 0x8C,
+    BlockLoadFull = 0x8D,
+    BlockLoadError = 0x8E,
+    BlockHandle = 0x8F,
+    PIDBlockFreeReport = 0x90,
+    TypeSpecificBlockHandle = 0x91,
+    PIDStateReport = 0x92,
+    //0x93 Reserved
+    EffectPlaying = 0x94,
+    PIDDeviceControlReport = 0x95,
+    PIDDeviceControl = 0x96,
+    DCEnableActuators = 0x97,
+    DCDisableActuators = 0x98,
+    DCStopAllEffects = 0x99,
+    DCDeviceReset = 0x9A,
+    DCDevicePause = 0x9B,
+    DCDeviceContinue = 0x9C,
+    //0x9D-0x9E Reserved
+    DevicePaused = 0x9F,
+    ActuatorsEnabled = 0xA0,
+    //0xA1-0xA3 Reserved
+    SafetySwitch = 0xA4,
+    ActuatorOverrideSwitch = 0xA5,
+    ActuatorPower = 0xA6,
+    StartDelay = 0xA7,
+    ParameterBlockSize = 0xA8,
+    DeviceManagedPool = 0xA9,
+    SharedParameterBlocks = 0xAA,
+    CreateNewEffectReport = 0xAB,
+    RAMPoolAvailable = 0xAC,
+    //0xAD-0xFFFF Reserved
+}
+
+/// Power Device usage page
+///
+/// See [Universal Serial Bus Usage Tables for HID Power Devices Release 1.1](<https://www.usb.org/sites/default/files/pdcv11.pdf>):
+/// Section 4.1 Power Device Page (0x84)
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    PrimitiveEnum,
+    Hash,
+    IntoPrimitive,
+    FromPrimitive,
+    Default,
+)]
+#[repr(u8)]
+pub enum PowerDevice {
+    #[default]
+    Undefined = 0x00,
+    IName = 0x01,
+    PresentStatus = 0x02,
+    ChangedStatus = 0x03,
+    UPS = 0x04,
+    PowerSupply = 0x05,
+    //0x06-0x0F Reserved
+    BatterySystem = 0x10,
+    BatterySystemID = 0x11,
+    Battery = 0x12,
+    BatteryID = 0x13,
+    Charger = 0x14,
+    ChargerID = 0x15,
+    PowerConverter = 0x16,
+    PowerConverterID = 0x17,
+    OutletSystem = 0x18,
+    OutletSystemID = 0x19,
+    Input = 0x1A,
+    InputID = 0x1B,
+    Output = 0x1C,
+    OutputID = 0x1D,
+    Flow = 0x1E,
+    FlowID = 0x1F,
+    Outlet = 0x20,
+    OutletID = 0x21,
+    Gang = 0x22,
+    GangID = 0x23,
+    PowerSummary = 0x24,
+    PowerSummaryID = 0x25,
+    //0x26-0x2F Reserved
+    Voltage = 0x30,
+    Current = 0x31,
+    Frequency = 0x32,
+    ApparentPower = 0x33,
+    ActivePower = 0x34,
+    PercentLoad = 0x35,
+    Temperature = 0x36,
+    Humidity = 0x37,
+    BadCount = 0x38,
+    //0x39-0x3F Reserved
+    ConfigVoltage = 0x40,
+    ConfigCurrent = 0x41,
+    ConfigFrequency = 0x42,
+    ConfigApparentPower = 0x43,
+    ConfigActivePower = 0x44,
+    ConfigPercentLoad = 0x45,
+    ConfigTemperature = 0x46,
+    ConfigHumidity = 0x47,
+    //0x48-0x4F Reserved
+    SwitchOnControl = 0x50,
+    SwitchOffControl = 0x51,
+    ToggleControl = 0x52,
+    LowVoltageTransfer = 0x53,
+    HighVoltageTransfer = 0x54,
+    DelayBeforeReboot = 0x55,
+    DelayBeforeStartup = 0x56,
+    DelayBeforeShutdown = 0x57,
+    Test = 0x58,
+    ModuleReset = 0x59,
+    AudibleAlarmControl = 0x5A,
+    //0x5B-0x5F Reserved
+    Present = 0x60,
+    Good = 0x61,
+    InternalFailure = 0x62,
+    VoltageOutOfRange = 0x63,
+    FrequencyOutOfRange = 0x64,
+    Overload = 0x65,
+    OverCharged = 0x66,
+    OverTemperature = 0x67,
+    ShutdownRequested = 0x68,
+    ShutdownImminent = 0x69,
+    //0x6A Reserved
+    SwitchOnOff = 0x6B,
+    Switchable = 0x6C,
+    Used = 0x6D,
+    Boost = 0x6E,
+    Buck = 0x6F,
+    Initialized = 0x70,
+    Tested = 0x71,
+    AwaitingPower = 0x72,
+    CommunicationLost = 0x73,
+    //0x74-0xFC Reserved
+    IManufacturer = 0xFD,
+    IProduct = 0xFE,
+    ISerialNumber = 0xFF,
+}
+
+/// Battery System usage page
+///
+/// See [Universal Serial Bus Usage Tables for HID Power Devices Release 1.1](<https://www.usb.org/sites/default/files/pdcv11.pdf>):
+/// Section 4.2 Battery System Page (0x85)
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    PrimitiveEnum,
+    Hash,
+    IntoPrimitive,
+    FromPrimitive,
+    Default,
+)]
+#[repr(u8)]
+pub enum BatterySystem {
+    #[default]
+    Undefined = 0x00,
+    SMBBatteryMode = 0x01,
+    SMBBatteryStatus = 0x02,
+    SMBAlarmWarning = 0x03,
+    SMBChargerMode = 0x04,
+    SMBChargerStatus = 0x05,
+    SMBChargerSpecInfo = 0x06,
+    SMBSelectorState = 0x07,
+    SMBSelectorPresets = 0x08,
+    SMBSelectorInfo = 0x09,
+    //0x0A-0x0F Reserved
+    OptionalMfgFunction1 = 0x10,
+    OptionalMfgFunction2 = 0x11,
+    OptionalMfgFunction3 = 0x12,
+    OptionalMfgFunction4 = 0x13,
+    OptionalMfgFunction5 = 0x14,
+    ConnectionToSMBus = 0x15,
+    OutputConnection = 0x16,
+    ChargerConnection = 0x17,
+    BatteryInsertion = 0x18,
+    UseNext = 0x19,
+    OKToUse = 0x1A,
+    BatterySupported = 0x1B,
+    SelectorRevision = 0x1C,
+    ChargingIndicator = 0x1D,
+    //0x1E-0x27 Reserved
+    ManufacturerAccess = 0x28,
+    RemainingCapacityLimit = 0x29,
+    RemainingTimeLimit = 0x2A,
+    AtRate = 0x2B,
+    CapacityMode = 0x2C,
+    BroadcastToCharger = 0x2D,
+    PrimaryBattery = 0x2E,
+    ChargeController = 0x2F,
+    //0x30-0x3F Reserved
+    TerminateCharge = 0x40,
+    TerminateDischarge = 0x41,
+    BelowRemainingCapacityLimit = 0x42,
+    RemainingTimeLimitExpired = 0x43,
+    Charging = 0x44,
+    Discharging = 0x45,
+    FullyCharged = 0x46,
+    FullyDischarged = 0x47,
+    ConditioningFlag = 0x48,
+    AtRateOK = 0x49,
+    SMBErrorCode = 0x4A,
+    NeedReplacement = 0x4B,
+    //0x4C-0x5F Reserved
+    AtRateTimeToFull = 0x60,
+    AtRateTimeToEmpty = 0x61,
+    AverageCurrent = 0x62,
+    MaxError = 0x63,
+    RelativeStateOfCharge = 0x64,
+    AbsoluteStateOfCharge = 0x65,
+    RemainingCapacity = 0x66,
+    FullChargeCapacity = 0x67,
+    RunTimeToEmpty = 0x68,
+    AverageTimeToEmpty = 0x69,
+    AverageTimeToFull = 0x6A,
+    CycleCount = 0x6B,
+    //0x6C-0x7F Reserved
+    BattPackModelLevel = 0x80,
+    InternalChargeController = 0x81,
+    PrimaryBatterySupport = 0x82,
+    DesignCapacity = 0x83,
+    SpecificationInfo = 0x84,
+    ManufactureDate = 0x85,
+    SerialNumber = 0x86,
+    IManufacturerName = 0x87,
+    IDeviceName = 0x88,
+    IDeviceChemistry = 0x89,
+    ManufacturerData = 0x8A,
+    Rechargeable = 0x8B,
+    WarningCapacityLimit = 0x8C,
+    CapacityGranularity1 = 0x8D,
+    CapacityGranularity2 = 0x8E,
+    IOEMInformation = 0x8F,
+    //0x90-0xBF Reserved
+    InhibitCharge = 0xC0,
+    EnablePolling = 0xC1,
+    ResetToZero = 0xC2,
+    //0xC3-0xCF Reserved
+    ACPresent = 0xD0,
+    BatteryPresent = 0xD1,
+    PowerFail = 0xD2,
+    AlarmInhibited = 0xD3,
+    ThermistorUnderRange = 0xD4,
+    ThermistorHot = 0xD5,
+    ThermistorCold = 0xD6,
+    ThermistorOverRange = 0xD7,
+    VoltageOutOfRange = 0xD8,
+    CurrentOutOfRange = 0xD9,
+    CurrentNotRegulated = 0xDA,
+    VoltageNotRegulated = 0xDB,
+    MasterMode = 0xDC,
+    //0xDD-0xEF Reserved
+    ChargerSelectorSupport = 0xF0,
+    ChargerSpec = 0xF1,
+    Level2 = 0xF2,
+    Level3 = 0xF3,
+    //0xF4-0xFF Reserved
+}
+
+/// Braille Display usage page
+///
+/// See [HID Usage Tables for Universal Serial Bus (USB) Version 1.4](<https://usb.org/sites/default/files/hut1_4.pdf>):
+/// Braille Display Page (0x41)
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    PrimitiveEnum,
+    Hash,
+    IntoPrimitive,
+    FromPrimitive,
+    Default,
+)]
+#[repr(u16)]
+pub enum Braille {
+    #[default]
+    Undefined = 0x0000,
+    BrailleDisplay = 0x0001,
+    BrailleRow = 0x0002,
+    EightDotBrailleCell = 0x0003,
+    SixDotBrailleCell = 0x0004,
+    NumberOfBrailleCells = 0x0005,
+    ScreenReaderControl = 0x0006,
+    ScreenReaderIdentifier = 0x0007,
+    //0x0008-0x00F9 Reserved
+    RouterSet1 = 0x00FA,
+    RouterSet2 = 0x00FB,
+    RouterSet3 = 0x00FC,
+    //0x00FD-0x00FF Reserved
+    RouterKey = 0x0100,
+    RowRouterKey = 0x0101,
+    //0x0102-0x01FF Reserved
+    BrailleButtons = 0x0200,
+    BrailleKeyboardDot1 = 0x0201,
+    BrailleKeyboardDot2 = 0x0202,
+    BrailleKeyboardDot3 = 0x0203,
+    BrailleKeyboardDot4 = 0x0204,
+    BrailleKeyboardDot5 = 0x0205,
+    BrailleKeyboardDot6 = 0x0206,
+    BrailleKeyboardDot7 = 0x0207,
+    BrailleKeyboardDot8 = 0x0208,
+    BrailleKeyboardSpace = 0x0209,
+    BrailleKeyboardLeftSpace = 0x020A,
+    BrailleKeyboardRightSpace = 0x020B,
+    BrailleFaceControls = 0x020C,
+    BrailleLeftControls = 0x020D,
+    BrailleRightControls = 0x020E,
+    BrailleTopControls = 0x020F,
+    BrailleJoystickCenter = 0x0210,
+    BrailleJoystickUp = 0x0211,
+    BrailleJoystickDown = 0x0212,
+    BrailleJoystickLeft = 0x0213,
+    BrailleJoystickRight = 0x0214,
+    BrailleDPadCenter = 0x0215,
+    BrailleDPadUp = 0x0216,
+    BrailleDPadDown = 0x0217,
+    BrailleDPadLeft = 0x0218,
+    BrailleDPadRight = 0x0219,
+    BraillePanLeft = 0x021A,
+    BraillePanRight = 0x021B,
+    BrailleRockerUp = 0x021C,
+    BrailleRockerDown = 0x021D,
+    BrailleRockerPress = 0x021E,
+    //0x021F-0xFFFF Reserved
+}
+
+/// Barcode Scanner usage page
+///
+/// See [HID Usage Tables for Universal Serial Bus (USB) Version 1.4](<https://usb.org/sites/default/files/hut1_4.pdf>):
+/// Barcode Scanner Page (0x8C)
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    PrimitiveEnum,
+    Hash,
+    IntoPrimitive,
+    FromPrimitive,
+    Default,
+)]
+#[repr(u16)]
+pub enum BarcodeScanner {
+    #[default]
+    Undefined = 0x0000,
+    BarcodeBadgeReader = 0x0001,
+    BarcodeScanner = 0x0002,
+    DumbBarCodeScanner = 0x0003,
+    CordlessScannerBase = 0x0004,
+    BarCodeScannerCradle = 0x0005,
+    //0x0006-0x000F Reserved
+    AttributeReport = 0x0010,
+    SettingsReport = 0x0011,
+    ScannedDataReport = 0x0012,
+    RawScannedDataReport = 0x0013,
+    TriggerReport = 0x0014,
+    StatusReport = 0x0015,
+    UPCEANControlReport = 0x0016,
+    EAN2And3LabelControlReport = 0x0017,
+    Code39ControlReport = 0x0018,
+    Interleaved2Of5ControlReport = 0x0019,
+    Standard2Of5ControlReport = 0x001A,
+    MSIPlesseyControlReport = 0x001B,
+    CodabarControlReport = 0x001C,
+    Code128ControlReport = 0x001D,
+    Misc1DControlReport = 0x001E,
+    TwoDControlReport = 0x001F,
+    //0x0020-0x002F Reserved
+    AimingPointerMode = 0x0030,
+    BarCodePresentSensor = 0x0031,
+    Class1ALaser = 0x0032,
+    Class2Laser = 0x0033,
+    HeaterPresent = 0x0034,
+    ContactScanner = 0x0035,
+    ElectronicArticleSurveillanceNotification = 0x0036,
+    ConstantElectronicArticleSurveillance = 0x0037,
+    ErrorIndication = 0x0038,
+    FixedBeeper = 0x0039,
+    GoodDecodeIndication = 0x003A,
+    HandsFreeScanning = 0x003B,
+    IntrinsicallySafe = 0x003C,
+    KlasseEinsLaser = 0x003D,
+    LongRangeScanner = 0x003E,
+    MirrorSpeedControl = 0x003F,
+    NotOnFileIndication = 0x0040,
+    ProgrammableBeeper = 0x0041,
+    Triggerless = 0x0042,
+    Wand = 0x0043,
+    WaterResistant = 0x0044,
+    MultiRangeScanner = 0x0045,
+    ProximitySensor = 0x0046,
+    //0x0047-0x004C Reserved
+    FragmentDecoding = 0x004D,
+    ScannerReadConfidence = 0x004E,
+    DataPrefix = 0x004F,
+    PrefixAIMI = 0x0050,
+    PrefixNone = 0x0051,
+    PrefixProprietary = 0x0052,
+    //0x0053-0x0054 Reserved
+    ActiveTime = 0x0055,
+    AimingLaserPattern = 0x0056,
+    BarCodePresent = 0x0057,
+    BeeperState = 0x0058,
+    LaserOnTime = 0x0059,
+    LaserState = 0x005A,
+    LockoutTime = 0x005B,
+    MotorState = 0x005C,
+    MotorTimeout = 0x005D,
+    PowerOnResetScanner = 0x005E,
+    PreventReadOfBarcodes = 0x005F,
+    InitiateBarcodeRead = 0x0060,
+    TriggerState = 0x0061,
+    TriggerMode = 0x0062,
+    TriggerModeBlinkingLaserOn = 0x0063,
+    TriggerModeContinuousLaserOn = 0x0064,
+    TriggerModeLaserOnWhilePulled = 0x0065,
+    TriggerModeLaserStaysOnAfterRelease = 0x0066,
+    //0x0067-0x006C Reserved
+    CommitParametersToNVM = 0x006D,
+    ParameterScanning = 0x006E,
+    ParametersChanged = 0x006F,
+    SetParameterDefaultValues = 0x0070,
+    //0x0071-0x0074 Reserved
+    ScannerInCradle = 0x0075,
+    ScannerInRange = 0x0076,
+    //0x0077-0x0079 Reserved
+    AimDuration = 0x007A,
+    GoodReadLampDuration = 0x007B,
+    GoodReadLampIntensity = 0x007C,
+    GoodReadLED = 0x007D,
+    GoodReadToneFrequency = 0x007E,
+    GoodReadToneLength = 0x007F,
+    GoodReadToneVolume = 0x0080,
+    //0x0081 Reserved
+    NoReadMessage = 0x0082,
+    NotOnFileVolume = 0x0083,
+    PowerupBeep = 0x0084,
+    SoundErrorBeep = 0x0085,
+    SoundGoodReadBeep = 0x0086,
+    SoundNotOnFileBeep = 0x0087,
+    GoodReadWhenToWrite = 0x0088,
+    GRWTIAfterDecode = 0x0089,
+    GRWTIBeepLampAfterTransmit = 0x008A,
+    GRWTINoBeepLampUseAtAll = 0x008B,
+    //0x008C-0x0090 Reserved
+    BooklandEAN = 0x0091,
+    ConvertEAN8To13Type = 0x0092,
+    ConvertUPCAToEAN13 = 0x0093,
+    ConvertUPCEToA = 0x0094,
+    EAN13 = 0x0095,
+    EAN8 = 0x0096,
+    EAN99128Mandatory = 0x0097,
+    EAN99P5128Optional = 0x0098,
+    EnableEANTwoLabel = 0x0099,
+    UPCEAN = 0x009A,
+    UPCEANCouponCode = 0x009B,
+    UPCEANPeriodicals = 0x009C,
+    UPCA = 0x009D,
+    UPCAWith128Mandatory = 0x009E,
+    UPCAWith128Optional = 0x009F,
+    UPCAWithP5Optional = 0x00A0,
+    UPCE = 0x00A1,
+    UPCE1 = 0x00A2,
+    //0x00A3-0x00A8 Reserved
+    Periodical = 0x00A9,
+    PeriodicalAutoDiscriminatePlus2 = 0x00AA,
+    PeriodicalOnlyDecodeWithPlus2 = 0x00AB,
+    PeriodicalIgnorePlus2 = 0x00AC,
+    PeriodicalAutoDiscriminatePlus5 = 0x00AD,
+    PeriodicalOnlyDecodeWithPlus5 = 0x00AE,
+    PeriodicalIgnorePlus5 = 0x00AF,
+    Check = 0x00B0,
+    CheckDisablePrice = 0x00B1,
+    CheckEnable4DigitPrice = 0x00B2,
+    CheckEnable5DigitPrice = 0x00B3,
+    CheckEnableEuropean4DigitPrice = 0x00B4,
+    CheckEnableEuropean5DigitPrice = 0x00B5,
+    //0x00B6 Reserved
+    EANTwoLabel = 0x00B7,
+    EANThreeLabel = 0x00B8,
+    EAN8FlagDigit1 = 0x00B9,
+    EAN8FlagDigit2 = 0x00BA,
+    EAN8FlagDigit3 = 0x00BB,
+    EAN13FlagDigit1 = 0x00BC,
+    EAN13FlagDigit2 = 0x00BD,
+    EAN13FlagDigit3 = 0x00BE,
+    AddEAN2And3LabelDefinition = 0x00BF,
+    ClearAllEAN2And3LabelDefinitions = 0x00C0,
+    //0x00C1-0x00C2 Reserved
+    Codabar = 0x00C3,
+    Code128 = 0x00C4,
+    //0x00C5-0x00C6 Reserved
+    Code39 = 0x00C7,
+    Code93 = 0x00C8,
+    FullASCIIConversion = 0x00C9,
+    Interleaved2Of5 = 0x00CA,
+    ItalianPharmacyCode = 0x00CB,
+    MSIPlessey = 0x00CC,
+    Standard2Of5IATA = 0x00CD,
+    Standard2Of5 = 0x00CE,
+    //0x00CF-0x00D2 Reserved
+    TransmitStartStop = 0x00D3,
+    TriOptic = 0x00D4,
+    UCCEAN128 = 0x00D5,
+    CheckDigit = 0x00D6,
+    CheckDigitDisable = 0x00D7,
+    CheckDigitEnableInterleaved2Of5OPCC = 0x00D8,
+    CheckDigitEnableInterleaved2Of5USS = 0x00D9,
+    CheckDigitEnableStandard2Of5OPCC = 0x00DA,
+    CheckDigitEnableStandard2Of5USS = 0x00DB,
+    CheckDigitEnableOneMSIPlessey = 0x00DC,
+    CheckDigitEnableTwoMSIPlessey = 0x00DD,
+    CheckDigitCodabarEnable = 0x00DE,
+    CheckDigitCode39Enable = 0x00DF,
+    //0x00E0-0x00EF Reserved
+    TransmitCheckDigit = 0x00F0,
+    DisableCheckDigitTransmit = 0x00F1,
+    EnableCheckDigitTransmit = 0x00F2,
+    //0x00F3-0x00FA Reserved
+    SymbologyIdentifier1 = 0x00FB,
+    SymbologyIdentifier2 = 0x00FC,
+    SymbologyIdentifier3 = 0x00FD,
+    DecodedData = 0x00FE,
+    DecodeDataContinued = 0x00FF,
+    BarSpaceData = 0x0100,
+    ScannerDataAccuracy = 0x0101,
+    RawDataPolarity = 0x0102,
+    PolarityInvertedBarCode = 0x0103,
+    PolarityNormalBarCode = 0x0104,
+    //0x0105 Reserved
+    MinimumLengthToDecode = 0x0106,
+    MaximumLengthToDecode = 0x0107,
+    DiscreteLengthToDecode1 = 0x0108,
+    DiscreteLengthToDecode2 = 0x0109,
+    DataLengthMethod = 0x010A,
+    DLMethodReadAny = 0x010B,
+    DLMethodCheckInRange = 0x010C,
+    DLMethodCheckForDiscrete = 0x010D,
+    //0x010E-0x010F Reserved
+    AztecCode = 0x0110,
+    BC412 = 0x0111,
+    ChannelCode = 0x0112,
+    Code16 = 0x0113,
+    Code32 = 0x0114,
+    Code49 = 0x0115,
+    CodeOne = 0x0116,
+    Colorcode = 0x0117,
+    DataMatrix = 0x0118,
+    MaxiCode = 0x0119,
+    MicroPDF = 0x011A,
+    PDF417 = 0x011B,
+    PosiCode = 0x011C,
+    QRCode = 0x011D,
+    SuperCode = 0x011E,
+    UltraCode = 0x011F,
+    USD5SlugCode = 0x0120,
+    VeriCode = 0x0121,
+    //0x0122-0xFFFF Reserved
+}
+
+/// Scale usage page
+///
+/// See [HID Usage Tables for Universal Serial Bus (USB) Version 1.4](<https://usb.org/sites/default/files/hut1_4.pdf>):
+/// Scale Page (0x8D)
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    PrimitiveEnum,
+    Hash,
+    IntoPrimitive,
+    FromPrimitive,
+    Default,
+)]
+#[repr(u8)]
+pub enum Scale {
+    #[default]
+    Undefined = 0x00,
+    Scales = 0x01,
+    //0x02-0x1F Reserved
+    ScaleDevice = 0x20,
+    ScaleClass = 0x21,
+    ScaleClassIMetric = 0x22,
+    ScaleClassIIMetric = 0x23,
+    ScaleClassIIIMetric = 0x24,
+    ScaleClassIIILMetric = 0x25,
+    ScaleClassIVMetric = 0x26,
+    ScaleClassIIIEnglish = 0x27,
+    ScaleClassIIILEnglish = 0x28,
+    ScaleClassIVEnglish = 0x29,
+    ScaleClassGeneric = 0x2A,
+    //0x2B-0x2F Reserved
+    ScaleAttributeReport = 0x30,
+    ScaleControlReport = 0x31,
+    ScaleDataReport = 0x32,
+    ScaleStatusReport = 0x33,
+    ScaleWeightLimitReport = 0x34,
+    ScaleStatisticsReport = 0x35,
+    //0x36-0x3F Reserved
+    DataWeight = 0x40,
+    DataScaling = 0x41,
+    //0x42-0x4F Reserved
+    WeightUnit = 0x50,
+    WeightUnitMilligram = 0x51,
+    WeightUnitGram = 0x52,
+    WeightUnitKilogram = 0x53,
+    WeightUnitCarats = 0x54,
+    WeightUnitTaels = 0x55,
+    WeightUnitGrains = 0x56,
+    WeightUnitPennyweights = 0x57,
+    WeightUnitMetricTon = 0x58,
+    WeightUnitAvoirTon = 0x59,
+    WeightUnitTroyOunce = 0x5A,
+    WeightUnitOunce = 0x5B,
+    WeightUnitPound = 0x5C,
+    //0x5D-0x5F Reserved
+    CalibrationCount = 0x60,
+    ReZeroCount = 0x61,
+    //0x62-0x6F Reserved
+    ScaleStatus = 0x70,
+    ScaleStatusFault = 0x71,
+    ScaleStatusStableAtCenterOfZero = 0x72,
+    ScaleStatusInMotion = 0x73,
+    ScaleStatusWeightStable = 0x74,
+    ScaleStatusUnderZero = 0x75,
+    ScaleStatusOverWeightLimit = 0x76,
+    ScaleStatusRequiresCalibration = 0x77,
+    ScaleStatusRequiresRezeroing = 0x78,
+    //0x79-0x7F Reserved
+    ZeroScale = 0x80,
+    EnforcedZeroReturn = 0x81,
+    //0x82-0xFF Reserved
+}
+
+/// Magnetic Stripe Reader usage page
+///
+/// See [HID Usage Tables for Universal Serial Bus (USB) Version 1.4](<https://usb.org/sites/default/files/hut1_4.pdf>):
+/// Magnetic Stripe Reader Page (0x8E)
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    PrimitiveEnum,
+    Hash,
+    IntoPrimitive,
+    FromPrimitive,
+    Default,
+)]
+#[repr(u16)]
+pub enum MagneticStripeReader {
+    #[default]
+    Undefined = 0x0000,
+    MSRDeviceReadOnly = 0x0001,
+    //0x0002-0x0010 Reserved
+    Track1Length = 0x0011,
+    Track2Length = 0x0012,
+    Track3Length = 0x0013,
+    TrackJISLength = 0x0014,
+    //0x0015-0x001F Reserved
+    TrackData = 0x0020,
+    Track1Data = 0x0021,
+    Track2Data = 0x0022,
+    Track3Data = 0x0023,
+    TrackJISData = 0x0024,
+    //0x0025-0xFFFF Reserved
+}
+
+/// Camera Control usage page
+///
+/// See [HID Usage Tables for Universal Serial Bus (USB) Version 1.4](<https://usb.org/sites/default/files/hut1_4.pdf>):
+/// Camera Control Page (0x90)
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    PrimitiveEnum,
+    Hash,
+    IntoPrimitive,
+    FromPrimitive,
+    Default,
+)]
+#[repr(u8)]
+pub enum Camera {
+    #[default]
+    Undefined = 0x00,
+    //0x01-0x1F Reserved
+    CameraAutoFocus = 0x20,
+    CameraShutter = 0x21,
+    //0x22-0xFF Reserved
+}
+
+/// Arcade usage page
+///
+/// See [HID Usage Tables for Universal Serial Bus (USB) Version 1.4](<https://usb.org/sites/default/files/hut1_4.pdf>):
+/// Arcade Page (0x91)
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    PrimitiveEnum,
+    Hash,
+    IntoPrimitive,
+    FromPrimitive,
+    Default,
+)]
+#[repr(u8)]
+pub enum Arcade {
+    #[default]
+    Undefined = 0x00,
+    GeneralPurposeIOCard = 0x01,
+    CoinDoor = 0x02,
+    WatchdogTimer = 0x03,
+    //0x04-0x2F Reserved
+    GeneralPurposeAnalogInputState = 0x30,
+    GeneralPurposeDigitalInputState = 0x31,
+    GeneralPurposeOpticalInputState = 0x32,
+    GeneralPurposeDigitalOutputState = 0x33,
+    NumberOfCoinDoors = 0x34,
+    CoinDrawerDropCount = 0x35,
+    CoinDrawerStart = 0x36,
+    CoinDrawerService = 0x37,
+    CoinDrawerTilt = 0x38,
+    CoinDoorTest = 0x39,
+    //0x3A-0x3F Reserved
+    CoinDoorLockout = 0x40,
+    WatchdogTimeout = 0x41,
+    WatchdogAction = 0x42,
+    WatchdogReboot = 0x43,
+    WatchdogRestart = 0x44,
+    AlarmInput = 0x45,
+    CoinDoorCounter = 0x46,
+    IODirectionMapping = 0x47,
+    SetIODirectionMapping = 0x48,
+    ExtendedOpticalInputState = 0x49,
+    PinPadInputState = 0x4A,
+    PinPadStatus = 0x4B,
+    PinPadOutput = 0x4C,
+    PinPadCommand = 0x4D,
+    //0x4E-0xFF Reserved
+}
+
+/// FIDO Alliance usage page
+///
+/// See [HID Usage Tables for Universal Serial Bus (USB) Version 1.4](<https://usb.org/sites/default/files/hut1_4.pdf>):
+/// FIDO Alliance Page (0xF1D0)
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    PrimitiveEnum,
+    Hash,
+    IntoPrimitive,
+    FromPrimitive,
+    Default,
+)]
+#[repr(u8)]
+pub enum Fido {
+    #[default]
+    Undefined = 0x00,
+    U2FAuthenticatorDevice = 0x01,
+    //0x02-0x1F Reserved
+    InputReportData = 0x20,
+    OutputReportData = 0x21,
+    //0x22-0xFF Reserved
+}
+
+/// Unicode usage page
+///
+/// The usage ID is the code point of the character. Usage IDs are 16 bits, so only characters in
+/// the Basic Multilingual Plane can be represented:
+///
+/// ```rust
+/// # use xous_usb_hid::page::Unicode;
+/// assert_eq!(Unicode::try_from('é').map(u16::from), Ok(0xE9));
+/// assert_eq!(Unicode::try_from('🦀'), Err('🦀'));
+/// assert_eq!(Unicode::from(0x20AC).to_char(), Some('€'));
+/// ```
+///
+/// See [Universal Serial Bus (USB) HID Usage Tables Version 1.12](<https://www.usb.org/sites/default/files/documents/hut1_12v2.pdf>):
+/// Section 17 Unicode Page (0x10)
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
+pub struct Unicode(u16);
+
+impl Unicode {
+    /// Usage for `c`, `None` if it is outside the Basic Multilingual Plane
+    #[must_use]
+    pub const fn from_char(c: char) -> Option<Self> {
+        let code_point = c as u32;
+        if code_point > u16::MAX as u32 {
+            None
+        } else {
+            #[allow(clippy::cast_possible_truncation)]
+            Some(Self(code_point as u16))
+        }
+    }
+
+    /// Code point of the character, this is also its usage ID
+    #[must_use]
+    pub const fn code_point(self) -> u16 {
+        self.0
+    }
+
+    /// Character for the usage, `None` for surrogate code points
+    #[must_use]
+    pub fn to_char(self) -> Option<char> {
+        char::from_u32(u32::from(self.0))
+    }
+}
+
+impl TryFrom<char> for Unicode {
+    /// The character outside the Basic Multilingual Plane
+    type Error = char;
+
+    fn try_from(c: char) -> Result<Self, char> {
+        Self::from_char(c).ok_or(c)
+    }
+}
+
+impl From<u16> for Unicode {
+    fn from(code_point: u16) -> Self {
+        Self(code_point)
+    }
+}
+
+impl From<Unicode> for u16 {
+    fn from(u: Unicode) -> Self {
+        u.0
+    }
+}
+
+impl PrimitiveEnum for Unicode {
+    type Primitive = u16;
+
+    fn from_primitive(val: u16) -> Option<Self> {
+        Some(Self(val))
+    }
+
+    fn to_primitive(&self) -> u16 {
+        self.0
+    }
+
+    fn from_str(s: &str) -> Option<Self> {
+        u16::from_str_radix(s.strip_prefix("U+")?, 16)
+            .ok()
+            .map(Self)
+    }
+
+    fn from_str_lower(s: &str) -> Option<Self> {
+        u16::from_str_radix(s.strip_prefix("u+")?, 16)
+            .ok()
+            .map(Self)
+    }
+}
+
+/// Eye and Head Trackers usage page
+///
+/// See [HID Usage Tables for Universal Serial Bus (USB) Version 1.4](<https://usb.org/sites/default/files/hut1_4.pdf>):
+/// Eye and Head Trackers Page (0x12)
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    PrimitiveEnum,
+    Hash,
+    IntoPrimitive,
+    FromPrimitive,
+    Default,
+)]
+#[repr(u16)]
+pub enum EyeAndHeadTracker {
+    #[default]
+    Undefined = 0x0000,
+    EyeTracker = 0x0001,
+    HeadTracker = 0x0002,
+    //0x0003-0x000F Reserved
+    TrackingData = 0x0010,
+    Capabilities = 0x0011,
+    Configuration = 0x0012,
+    Status = 0x0013,
+    Control = 0x0014,
+    //0x0015-0x001F Reserved
+    SensorTimestamp = 0x0020,
+    PositionX = 0x0021,
+    PositionY = 0x0022,
+    PositionZ = 0x0023,
+    GazePoint = 0x0024,
+    LeftEyePosition = 0x0025,
+    RightEyePosition = 0x0026,
+    HeadPosition = 0x0027,
+    HeadDirectionPoint = 0x0028,
+    RotationAboutXAxis = 0x0029,
+    RotationAboutYAxis = 0x002A,
+    RotationAboutZAxis = 0x002B,
+    //0x002C-0x00FF Reserved
+    TrackerQuality = 0x0100,
+    MinimumTrackingDistance = 0x0101,
+    OptimumTrackingDistance = 0x0102,
+    MaximumTrackingDistance = 0x0103,
+    MaximumScreenPlaneWidth = 0x0104,
+    MaximumScreenPlaneHeight = 0x0105,
+    //0x0106-0x01FF Reserved
+    DisplayManufacturerId = 0x0200,
+    DisplayProductId = 0x0201,
+    DisplaySerialNumber = 0x0202,
+    DisplayManufacturerDate = 0x0203,
+    CalibratedScreenWidth = 0x0204,
+    CalibratedScreenHeight = 0x0205,
+    //0x0206-0x02FF Reserved
+    SamplingFrequency = 0x0300,
+    ConfigurationStatus = 0x0301,
+    //0x0302-0x03FF Reserved
+    DeviceModeRequest = 0x0400,
+    //0x0401-0xFFFF Reserved
+}
+
+/// Auxiliary Display usage page
+///
+/// Formerly the Alphanumeric Display page
+///
+/// See [HID Usage Tables for Universal Serial Bus (USB) Version 1.4](<https://usb.org/sites/default/files/hut1_4.pdf>):
+/// Auxiliary Display Page (0x14)
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    PrimitiveEnum,
+    Hash,
+    IntoPrimitive,
+    FromPrimitive,
+    Default,
+)]
+#[repr(u8)]
+pub enum AuxiliaryDisplay {
+    #[default]
+    Undefined = 0x00,
+    AlphanumericDisplay = 0x01,
+    AuxiliaryDisplay = 0x02,
+    //0x03-0x1F Reserved
+    DisplayAttributesReport = 0x20,
+    AsciiCharacterSet = 0x21,
+    DataReadBack = 0x22,
+    FontReadBack = 0x23,
+    DisplayControlReport = 0x24,
+    ClearDisplay = 0x25,
+    DisplayEnable = 0x26,
+    ScreenSaverDelay = 0x27,
+    ScreenSaverEnable = 0x28,
+    VerticalScroll = 0x29,
+    HorizontalScroll = 0x2A,
+    CharacterReport = 0x2B,
+    DisplayData = 0x2C,
+    DisplayStatus = 0x2D,
+    StatNotReady = 0x2E,
+    StatReady = 0x2F,
+    ErrNotALoadableCharacter = 0x30,
+    ErrFontDataCannotBeRead = 0x31,
+    CursorPositionReport = 0x32,
+    Row = 0x33,
+    Column = 0x34,
+    Rows = 0x35,
+    Columns = 0x36,
+    CursorPixelPositioning = 0x37,
+    CursorMode = 0x38,
+    CursorEnable = 0x39,
+    CursorBlink = 0x3A,
+    FontReport = 0x3B,
+    FontData = 0x3C,
+    CharacterWidth = 0x3D,
+    CharacterHeight = 0x3E,
+    CharacterSpacingHorizontal = 0x3F,
+    CharacterSpacingVertical = 0x40,
+    UnicodeCharacterSet = 0x41,
+    Font7Segment = 0x42,
+    SevenSegmentDirectMap = 0x43,
+    Font14Segment = 0x44,
+    FourteenSegmentDirectMap = 0x45,
+    DisplayBrightness = 0x46,
+    DisplayContrast = 0x47,
+    CharacterAttribute = 0x48,
+    AttributeReadback = 0x49,
+    AttributeData = 0x4A,
+    CharAttrEnhance = 0x4B,
+    CharAttrUnderline = 0x4C,
+    CharAttrBlink = 0x4D,
+    //0x4E-0x7F Reserved
+    BitmapSizeX = 0x80,
+    BitmapSizeY = 0x81,
+    MaxBlitSize = 0x82,
+    BitDepthFormat = 0x83,
+    DisplayOrientation = 0x84,
+    PaletteReport = 0x85,
+    PaletteDataSize = 0x86,
+    PaletteDataOffset = 0x87,
+    PaletteData = 0x88,
+    //0x89 Reserved
+    BlitReport = 0x8A,
+    BlitRectangleX1 = 0x8B,
+    BlitRectangleY1 = 0x8C,
+    BlitRectangleX2 = 0x8D,
+    BlitRectangleY2 = 0x8E,
+    BlitData = 0x8F,
+    SoftButton = 0x90,
+    SoftButtonId = 0x91,
+    SoftButtonSide = 0x92,
+    SoftButtonOffset1 = 0x93,
+    SoftButtonOffset2 = 0x94,
+    SoftButtonReport = 0x95,
+    //0x96-0xC1 Reserved
+    SoftKeys = 0xC2,
+    //0xC3-0xCB Reserved
+    DisplayDataExtensions = 0xCC,
+    //0xCD-0xCE Reserved
+    CharacterMapping = 0xCF,
+    //0xD0-0xDC Reserved
+    UnicodeEquivalent = 0xDD,
+    //0xDE Reserved
+    CharacterPageMapping = 0xDF,
+    //0xE0-0xFE Reserved
+    RequestReport = 0xFF,
+}
+
+/// Alphanumeric Display usage page (0x14), the name of the [`AuxiliaryDisplay`] page up to HID
+/// Usage Tables 1.12, for descriptors and datasheets of display hardware using the older name
+///
+/// ```rust
+/// # use xous_usb_hid::page::{AlphanumericDisplay, UsagePage};
+/// assert_eq!(u8::from(AlphanumericDisplay::CursorPositionReport), 0x32);
+/// assert_eq!(u16::from(UsagePage::AuxiliaryDisplay), 0x14);
+/// ```
+pub type AlphanumericDisplay = AuxiliaryDisplay;
+
+/// VR Controls usage page
+///
+/// See [HID Usage Tables for Universal Serial Bus (USB) Version 1.4](<https://usb.org/sites/default/files/hut1_4.pdf>):
+/// VR Controls Page (0x03)
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    PrimitiveEnum,
+    Hash,
+    IntoPrimitive,
+    FromPrimitive,
+    Default,
+)]
+#[repr(u8)]
+pub enum Vr {
+    #[default]
+    Undefined = 0x00,
+    Belt = 0x01,
+    BodySuit = 0x02,
+    Flexor = 0x03,
+    Glove = 0x04,
+    HeadTracker = 0x05,
+    HeadMountedDisplay = 0x06,
+    HandTracker = 0x07,
+    Oculometer = 0x08,
+    Vest = 0x09,
+    AnimatronicDevice = 0x0A,
+    //0x0B-0x1F Reserved
+    StereoEnable = 0x20,
+    DisplayEnable = 0x21,
+    //0x22-0xFF Reserved
+}
+
+/// Sport Controls usage page
+///
+/// The HID Usage Tables have no page for exercise equipment beyond this one. Treadmills and
+/// rowing machines are [`Sport::Treadmill`] and [`Sport::RowingMachine`] application collections
+/// reporting [`Sport::Rate`] and [`Sport::Slope`]. Bike trainers are
+/// [`Simulation::Bicycle`] application collections reporting cadence as
+/// [`Simulation::BicycleCrank`], and resistance, which has no standard usage, on a vendor defined
+/// page:
+///
+/// ```rust
+/// # use xous_usb_hid::page::{KnownPage, Simulation, Sport, Usage, VendorPage};
+/// let cadence = Usage::from(Simulation::BicycleCrank);
+/// let incline = Usage::from(Sport::Slope);
+/// let resistance = Usage::from(VendorPage::<0xFF00>::new(0x01));
+/// assert_eq!(KnownPage::decode(0x04, 0x31), Some(KnownPage::Sport(Sport::Slope)));
+/// ```
+///
+/// See [HID Usage Tables for Universal Serial Bus (USB) Version 1.4](<https://usb.org/sites/default/files/hut1_4.pdf>):
+/// Sport Controls Page (0x04)
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    PrimitiveEnum,
+    Hash,
+    IntoPrimitive,
+    FromPrimitive,
+    Default,
+)]
+#[repr(u8)]
+pub enum Sport {
+    #[default]
+    Undefined = 0x00,
+    BaseballBat = 0x01,
+    GolfClub = 0x02,
+    RowingMachine = 0x03,
+    Treadmill = 0x04,
+    //0x05-0x2F Reserved
+    Oar = 0x30,
+    Slope = 0x31,
+    Rate = 0x32,
+    StickSpeed = 0x33,
+    StickFaceAngle = 0x34,
+    StickHeelToe = 0x35,
+    StickFollowThrough = 0x36,
+    StickTempo = 0x37,
+    StickType = 0x38,
+    StickHeight = 0x39,
+    //0x3A-0x4F Reserved
+    Putter = 0x50,
+    Iron1 = 0x51,
+    Iron2 = 0x52,
+    Iron3 = 0x53,
+    Iron4 = 0x54,
+    Iron5 = 0x55,
+    Iron6 = 0x56,
+    Iron7 = 0x57,
+    Iron8 = 0x58,
+    Iron9 = 0x59,
+    Iron10 = 0x5A,
+    Iron11 = 0x5B,
+    SandWedge = 0x5C,
+    LoftWedge = 0x5D,
+    PowerWedge = 0x5E,
+    Wood1 = 0x5F,
+    Wood3 = 0x60,
+    Wood5 = 0x61,
+    Wood7 = 0x62,
+    Wood9 = 0x63,
+    //0x64-0xFF Reserved
+}
+
+/// Generic Device Controls usage page
+///
+/// See [HID Usage Tables for Universal Serial Bus (USB) Version 1.4](<https://usb.org/sites/default/files/hut1_4.pdf>):
+/// Generic Device Controls Page (0x06)
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    PrimitiveEnum,
+    Hash,
+    IntoPrimitive,
+    FromPrimitive,
+    Default,
+)]
+#[repr(u8)]
+pub enum GenericDevice {
+    #[default]
+    Undefined = 0x00,
+    BackgroundNonuserControls = 0x01,
+    //0x02-0x1F Reserved
+    BatteryStrength = 0x20,
+    WirelessChannel = 0x21,
+    WirelessId = 0x22,
+    DiscoverWirelessControl = 0x23,
+    SecurityCodeCharacterEntered = 0x24,
+    SecurityCodeCharacterErased = 0x25,
+    SecurityCodeCleared = 0x26,
+    SequenceId = 0x27,
+    SequenceIdReset = 0x28,
+    RfSignalStrength = 0x29,
+    SoftwareVersion = 0x2A,
+    ProtocolVersion = 0x2B,
+    HardwareVersion = 0x2C,
+    Major = 0x2D,
+    Minor = 0x2E,
+    Revision = 0x2F,
+    Handedness = 0x30,
+    EitherHand = 0x31,
+    LeftHand = 0x32,
+    RightHand = 0x33,
+    BothHands = 0x34,
+    //0x35-0x3F Reserved
+    GripPoseOffset = 0x40,
+    PointerPoseOffset = 0x41,
+    //0x42-0xFF Reserved
+}
+
+/// Digitizers usage page
+///
+/// See [HID Usage Tables for Universal Serial Bus (USB) Version 1.4](<https://usb.org/sites/default/files/hut1_4.pdf>):
+/// Digitizers Page (0x0D)
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    PrimitiveEnum,
+    Hash,
+    IntoPrimitive,
+    FromPrimitive,
+    Default,
+)]
+#[repr(u8)]
+pub enum Digitizer {
+    #[default]
+    Undefined = 0x00,
+    Digitizer = 0x01,
+    Pen = 0x02,
+    LightPen = 0x03,
+    TouchScreen = 0x04,
+    TouchPad = 0x05,
+    Whiteboard = 0x06,
+    CoordinateMeasuringMachine = 0x07,
+    ThreeDDigitizer = 0x08,
+    StereoPlotter = 0x09,
+    ArticulatedArm = 0x0A,
+    Armature = 0x0B,
+    MultiplePointDigitizer = 0x0C,
+    FreeSpaceWand = 0x0D,
+    DeviceConfiguration = 0x0E,
+    CapacitiveHeatMapDigitizer = 0x0F,
+    //0x10-0x1F Reserved
+    Stylus = 0x20,
+    Puck = 0x21,
+    Finger = 0x22,
+    DeviceSettings = 0x23,
+    CharacterGesture = 0x24,
+    //0x25-0x2F Reserved
+    TipPressure = 0x30,
+    BarrelPressure = 0x31,
+    InRange = 0x32,
+    Touch = 0x33,
+    Untouch = 0x34,
+    Tap = 0x35,
+    Quality = 0x36,
+    DataValid = 0x37,
+    TransducerIndex = 0x38,
+    TabletFunctionKeys = 0x39,
+    ProgramChangeKeys = 0x3A,
+    BatteryStrength = 0x3B,
+    Invert = 0x3C,
+    XTilt = 0x3D,
+    YTilt = 0x3E,
+    Azimuth = 0x3F,
+    Altitude = 0x40,
+    Twist = 0x41,
+    TipSwitch = 0x42,
+    SecondaryTipSwitch = 0x43,
+    BarrelSwitch = 0x44,
+    Eraser = 0x45,
+    TabletPick = 0x46,
+    TouchValid = 0x47,
+    Width = 0x48,
+    Height = 0x49,
+    //0x4A-0x50 Reserved
+    ContactIdentifier = 0x51,
+    DeviceMode = 0x52,
+    DeviceIdentifier = 0x53,
+    ContactCount = 0x54,
+    ContactCountMaximum = 0x55,
+    ScanTime = 0x56,
+    SurfaceSwitch = 0x57,
+    ButtonSwitch = 0x58,
+    PadType = 0x59,
+    SecondaryBarrelSwitch = 0x5A,
+    TransducerSerialNumber = 0x5B,
+    PreferredColor = 0x5C,
+    PreferredColorIsLocked = 0x5D,
+    PreferredLineWidth = 0x5E,
+    PreferredLineWidthIsLocked = 0x5F,
+    LatencyMode = 0x60,
+    GestureCharacterQuality = 0x61,
+    CharacterGestureDataLength = 0x62,
+    CharacterGestureData = 0x63,
+    GestureCharacterEncoding = 0x64,
+    Utf8CharacterGestureEncoding = 0x65,
+    Utf16LittleEndianCharacterGestureEncoding = 0x66,
+    Utf16BigEndianCharacterGestureEncoding = 0x67,
+    Utf32LittleEndianCharacterGestureEncoding = 0x68,
+    Utf32BigEndianCharacterGestureEncoding = 0x69,
+    CapacitiveHeatMapProtocolVendorId = 0x6A,
+    CapacitiveHeatMapProtocolVersion = 0x6B,
+    CapacitiveHeatMapFrameData = 0x6C,
+    GestureCharacterEnable = 0x6D,
+    TransducerSerialNumberPart2 = 0x6E,
+    NoPreferredColor = 0x6F,
+    PreferredLineStyle = 0x70,
+    PreferredLineStyleIsLocked = 0x71,
+    Ink = 0x72,
+    Pencil = 0x73,
+    Highlighter = 0x74,
+    ChiselMarker = 0x75,
+    Brush = 0x76,
+    NoPreference = 0x77,
+    //0x78-0x7F Reserved
+    DigitizerDiagnostic = 0x80,
+    DigitizerError = 0x81,
+    ErrNormalStatus = 0x82,
+    ErrTransducersExceeded = 0x83,
+    ErrFullTransFeaturesUnavailable = 0x84,
+    ErrChargeLow = 0x85,
+    //0x86-0x8F Reserved
+    TransducerSoftwareInfo = 0x90,
+    TransducerVendorId = 0x91,
+    TransducerProductId = 0x92,
+    DeviceSupportedProtocols = 0x93,
+    TransducerSupportedProtocols = 0x94,
+    NoProtocol = 0x95,
+    WacomAesProtocol = 0x96,
+    HidProtocol = 0x97,
+    MicrosoftPenProtocol = 0x98,
+    //0x99-0x9F Reserved
+    SupportedReportRates = 0xA0,
+    ReportRate = 0xA1,
+    TransducerConnected = 0xA2,
+    SwitchDisabled = 0xA3,
+    SwitchUnimplemented = 0xA4,
+    TransducerSwitches = 0xA5,
+    TransducerIndexSelector = 0xA6,
+    //0xA7-0xAF Reserved
+    ButtonPressThreshold = 0xB0,
+    //0xB1-0xFF Reserved
+}
+
+/// Usage pages with an enum in this module
+///
+/// See [HID Usage Tables for Universal Serial Bus (USB) Version 1.4](<https://usb.org/sites/default/files/hut1_4.pdf>):
+/// Section 3 Usage Pages
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, IntoPrimitive, TryFromPrimitive,
+)]
+#[repr(u16)]
+pub enum UsagePage {
+    Desktop = 0x01,
+    Simulation = 0x02,
+    Vr = 0x03,
+    Sport = 0x04,
+    Game = 0x05,
+    GenericDevice = 0x06,
+    Keyboard = 0x07,
+    Leds = 0x08,
+    Button = 0x09,
+    Telephony = 0x0B,
+    Consumer = 0x0C,
+    Digitizer = 0x0D,
+    Haptics = 0x0E,
+    PhysicalInterface = 0x0F,
+    Unicode = 0x10,
+    EyeAndHeadTracker = 0x12,
+    AuxiliaryDisplay = 0x14,
+    Sensor = 0x20,
+    Braille = 0x41,
+    LightingAndIllumination = 0x59,
+    PowerDevice = 0x84,
+    BatterySystem = 0x85,
+    BarcodeScanner = 0x8C,
+    Scale = 0x8D,
+    MagneticStripeReader = 0x8E,
+    Camera = 0x90,
+    Arcade = 0x91,
+    Fido = 0xF1D0,
+}
+
+/// A usage on any page, e.g. `Keyboard::A` or `Consumer::PlayPause`
+///
+/// ```rust
+/// # use xous_usb_hid::page::{Consumer, Keyboard, Usage, UsagePage};
+/// let usage = Usage::from(Consumer::PlayPause);
+/// assert_eq!(usage.usage_page(), Some(UsagePage::Consumer));
+/// assert_eq!(usage.extended(), 0x000C_00CD);
+/// assert_eq!(Consumer::try_from(usage), Ok(Consumer::PlayPause));
+/// assert!(Keyboard::try_from(usage).is_err());
+/// ```
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
+pub struct Usage {
+    pub page: u16,
+    pub id: u16,
+}
+
+impl Usage {
+    #[must_use]
+    pub const fn new(page: u16, id: u16) -> Self {
+        Self { page, id }
+    }
+
+    /// The usage page, `None` if it has no enum in this module
+    #[must_use]
+    pub fn usage_page(&self) -> Option<UsagePage> {
+        UsagePage::try_from(self.page).ok()
+    }
+
+    /// `true` for usages on a page in [`VENDOR_DEFINED_PAGES`]
+    #[must_use]
+    pub const fn is_vendor_defined(&self) -> bool {
+        self.page >= *VENDOR_DEFINED_PAGES.start()
+    }
+
+    /// Extended usage, with the page in the high 16 bits, as used by report descriptors
+    #[must_use]
+    pub const fn extended(&self) -> u32 {
+        (self.page as u32) << 16 | self.id as u32
+    }
+
+    #[must_use]
+    pub const fn from_extended(usage: u32) -> Self {
+        let [id_low, id_high, page_low, page_high] = usage.to_le_bytes();
+        Self {
+            page: u16::from_le_bytes([page_low, page_high]),
+            id: u16::from_le_bytes([id_low, id_high]),
+        }
+    }
+}
+
+impl From<u32> for Usage {
+    fn from(usage: u32) -> Self {
+        Self::from_extended(usage)
+    }
+}
+
+impl From<Usage> for u32 {
+    fn from(usage: Usage) -> Self {
+        usage.extended()
+    }
+}
+
+macro_rules! usage_conversions {
+    ($($page:ident => $repr:ident),* $(,)?) => {
+        $(
+            impl From<$page> for Usage {
+                fn from(usage: $page) -> Self {
+                    Self::new(UsagePage::$page.into(), usage_conversions!(@id $repr, usage))
+                }
+            }
+
+            /// Fails with the usage if it is on another page or out of range for the page
+            impl TryFrom<Usage> for $page {
+                type Error = Usage;
+
+                fn try_from(usage: Usage) -> Result<Self, Usage> {
+                    if usage.page != u16::from(UsagePage::$page) {
+                        return Err(usage);
+                    }
+                    usage_conversions!(@from $repr, usage)
+                }
+            }
+        )*
+
+        /// A usage decoded to the enum of its page, the single dispatch point from page and usage
+        /// IDs to the enums of this module
+        ///
+        /// ```rust
+        /// # use xous_usb_hid::page::{Consumer, KnownPage, Usage, UsagePage};
+        /// let usage = KnownPage::decode(0x0C, 0xCD);
+        /// assert_eq!(usage, Some(KnownPage::Consumer(Consumer::PlayPause)));
+        /// assert_eq!(usage.map(KnownPage::page), Some(UsagePage::Consumer));
+        /// assert_eq!(usage.map(Usage::from), Some(Usage::new(0x0C, 0xCD)));
+        ///
+        /// // Vendor defined page, and a usage ID the Consumer page doesn't define
+        /// assert_eq!(KnownPage::decode(0xFF00, 0x01), None);
+        /// assert_eq!(KnownPage::decode(0x0C, 0x0FFF), None);
+        /// ```
+        #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+        #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+        pub enum KnownPage {
+            $($page($page),)*
+        }
+
+        impl KnownPage {
+            /// `None` if the page has no enum in this module, e.g. vendor defined pages, or if the
+            /// enum doesn't define the usage ID
+            #[must_use]
+            pub fn decode(page: u16, id: u16) -> Option<Self> {
+                Self::try_from(Usage::new(page, id)).ok()
+            }
+
+            #[must_use]
+            pub fn page(self) -> UsagePage {
+                match self {
+                    $(Self::$page(_) => UsagePage::$page,)*
+                }
+            }
+        }
+
+        impl From<KnownPage> for Usage {
+            fn from(usage: KnownPage) -> Self {
+                match usage {
+                    $(KnownPage::$page(u) => u.into(),)*
+                }
+            }
+        }
+
+        /// Fails with the usage if [`KnownPage::decode`] would return `None`
+        impl TryFrom<Usage> for KnownPage {
+            type Error = Usage;
+
+            fn try_from(usage: Usage) -> Result<Self, Usage> {
+                let known = match UsagePage::try_from(usage.page).map_err(|_| usage)? {
+                    $(UsagePage::$page => $page::try_from(usage).map(Self::$page)?,)*
+                };
+                // Enums map undefined IDs to a default usage
+                if Usage::from(known) == usage {
+                    Ok(known)
+                } else {
+                    Err(usage)
+                }
+            }
+        }
+    };
+    (@id u8, $usage:ident) => { u16::from(u8::from($usage)) };
+    (@id u16, $usage:ident) => { u16::from($usage) };
+    (@from u8, $usage:ident) => { u8::try_from($usage.id).map(Self::from).map_err(|_| $usage) };
+    (@from u16, $usage:ident) => { Ok(Self::from($usage.id)) };
+}
+
+usage_conversions! {
+    Desktop => u8,
+    Simulation => u8,
+    Vr => u8,
+    Sport => u8,
+    Game => u8,
+    GenericDevice => u8,
+    Keyboard => u8,
+    Leds => u8,
+    Button => u16,
+    Telephony => u8,
+    Consumer => u16,
+    Digitizer => u8,
+    Haptics => u16,
+    PhysicalInterface => u8,
+    Unicode => u16,
+    EyeAndHeadTracker => u16,
+    AuxiliaryDisplay => u8,
+    Sensor => u16,
+    Braille => u16,
+    LightingAndIllumination => u8,
+    PowerDevice => u8,
+    BatterySystem => u8,
+    BarcodeScanner => u16,
+    Scale => u8,
+    MagneticStripeReader => u16,
+    Camera => u8,
+    Arcade => u8,
+    Fido => u8,
+}
+
+/// Vendor defined usage pages
+pub const VENDOR_DEFINED_PAGES: core::ops::RangeInclusive<u16> = 0xFF00..=0xFFFF;
+
+/// Usage on the vendor defined page `PAGE`, 0xFF00 to 0xFFFF
+///
+/// Usage IDs have no meaning defined by the specification, they are passed through unchanged:
+///
+/// ```rust
+/// # use xous_usb_hid::page::{Usage, VendorPage};
+/// type Config = VendorPage<0xFF60>;
+///
+/// let usage = Usage::from(Config::new(0x61));
+/// assert_eq!(usage, Usage::new(0xFF60, 0x61));
+/// assert!(usage.is_vendor_defined());
+/// assert_eq!(Config::try_from(usage), Ok(Config::new(0x61)));
+/// ```
+///
+/// See [HID Usage Tables for Universal Serial Bus (USB) Version 1.4](<https://usb.org/sites/default/files/hut1_4.pdf>):
+/// Section 3 Usage Pages
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
+pub struct VendorPage<const PAGE: u16>(u16);
+
+impl<const PAGE: u16> VendorPage<PAGE> {
+    /// The usage page, checked to be vendor defined when the type is used
+    pub const PAGE: u16 = {
+        core::assert!(PAGE >= 0xFF00, "Not a vendor defined usage page");
+        PAGE
+    };
+
+    #[must_use]
+    pub const fn new(usage: u16) -> Self {
+        let _ = Self::PAGE;
+        Self(usage)
+    }
+
+    #[must_use]
+    pub const fn usage(self) -> u16 {
+        self.0
+    }
+}
+
+impl<const PAGE: u16> From<u16> for VendorPage<PAGE> {
+    fn from(usage: u16) -> Self {
+        Self::new(usage)
+    }
+}
+
+impl<const PAGE: u16> From<VendorPage<PAGE>> for u16 {
+    fn from(usage: VendorPage<PAGE>) -> Self {
+        usage.0
+    }
+}
+
+impl<const PAGE: u16> PrimitiveEnum for VendorPage<PAGE> {
+    type Primitive = u16;
+
+    fn from_primitive(val: u16) -> Option<Self> {
+        Some(Self::new(val))
+    }
+
+    fn to_primitive(&self) -> u16 {
+        self.0
+    }
+
+    fn from_str(s: &str) -> Option<Self> {
+        u16::from_str_radix(s.strip_prefix("0x")?, 16)
+            .ok()
+            .map(Self::new)
+    }
+
+    fn from_str_lower(s: &str) -> Option<Self> {
+        Self::from_str(s)
+    }
+}
+
+impl<const PAGE: u16> From<VendorPage<PAGE>> for Usage {
+    fn from(usage: VendorPage<PAGE>) -> Self {
+        Self::new(VendorPage::<PAGE>::PAGE, usage.0)
+    }
+}
+
+/// Fails with the usage if it is on another page
+impl<const PAGE: u16> TryFrom<Usage> for VendorPage<PAGE> {
+    type Error = Usage;
+
+    fn try_from(usage: Usage) -> Result<Self, Usage> {
+        if usage.page == Self::PAGE {
+            Ok(Self::new(usage.id))
+        } else {
+            Err(usage)
+        }
+    }
+}
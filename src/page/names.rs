@@ -0,0 +1,2842 @@
+//! Usage names from the HID Usage Tables, enabled by the `usage-names` feature
+//!
+//! Every usage enum gets an `as_str()` method and a [`Display`] implementation returning the name
+//! of the usage, e.g. `"AC Copy"` for [`Consumer::ACCopy`], for use by host tools and debug logs.
+//! Names are derived from the variant names, so abbreviations made to get valid identifiers carry
+//! over. Ordinal pages display their usage number, e.g. `"Button 1"` or `"U+0041"`.
+//!
+//! ```rust
+//! # use xous_usb_hid::page::{Button, Consumer, Keyboard};
+//! assert_eq!(Consumer::ACCopy.as_str(), "AC Copy");
+//! assert_eq!(format!("{}", Keyboard::KeypadNumLockAndClear), "Keypad Num Lock and Clear");
+//! assert_eq!(format!("{}", Button::PRIMARY), "Button 1");
+//! ```
+
+#![allow(clippy::too_many_lines)]
+
+#[allow(clippy::wildcard_imports)]
+use super::*;
+use core::fmt::{self, Display, Formatter};
+
+impl Display for Button {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if *self == Self::NO_BUTTON {
+            f.write_str("No Button")
+        } else {
+            write!(f, "Button {}", self.number())
+        }
+    }
+}
+
+impl Display for Unicode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "U+{:04X}", self.code_point())
+    }
+}
+
+impl<const PAGE: u16> Display for VendorPage<PAGE> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "Vendor {:#06X}:{:#06X}", Self::PAGE, self.usage())
+    }
+}
+
+impl Leds {
+    /// Name of the usage in the HID Usage Tables
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Undefined => "Undefined",
+            Self::NumLock => "Num Lock",
+            Self::CapsLock => "Caps Lock",
+            Self::ScrollLock => "Scroll Lock",
+            Self::Compose => "Compose",
+            Self::Kana => "Kana",
+            Self::Power => "Power",
+            Self::Shift => "Shift",
+            Self::DoNotDisturb => "Do Not Disturb",
+            Self::Mute => "Mute",
+            Self::ToneEnable => "Tone Enable",
+            Self::HighCutFilter => "High Cut Filter",
+            Self::LowCutFilter => "Low Cut Filter",
+            Self::EqualizerEnable => "Equalizer Enable",
+            Self::SoundFieldOn => "Sound Field On",
+            Self::SurroundFieldOn => "Surround Field On",
+            Self::Repeat => "Repeat",
+            Self::Stereo => "Stereo",
+            Self::SamplingRateDetect => "Sampling Rate Detect",
+            Self::Spinning => "Spinning",
+            Self::CAV => "CAV",
+            Self::CLV => "CLV",
+            Self::RecordingFormatDetect => "Recording Format Detect",
+            Self::OffHook => "Off Hook",
+            Self::Ring => "Ring",
+            Self::MessageWaiting => "Message Waiting",
+            Self::DataMode => "Data Mode",
+            Self::BatteryOperation => "Battery Operation",
+            Self::BatteryOK => "Battery OK",
+            Self::BatteryLow => "Battery Low",
+            Self::Speaker => "Speaker",
+            Self::HeadSet => "Head Set",
+            Self::Hold => "Hold",
+            Self::Microphone => "Microphone",
+            Self::Coverage => "Coverage",
+            Self::NightMode => "Night Mode",
+            Self::SendCalls => "Send Calls",
+            Self::CallPickup => "Call Pickup",
+            Self::Conference => "Conference",
+            Self::StandBy => "Stand By",
+            Self::CameraOn => "Camera On",
+            Self::CameraOff => "Camera Off",
+            Self::OnLine => "On Line",
+            Self::OffLine => "Off Line",
+            Self::Busy => "Busy",
+            Self::Ready => "Ready",
+            Self::PaperOut => "Paper Out",
+            Self::PaperJam => "Paper Jam",
+            Self::Remote => "Remote",
+            Self::Forward => "Forward",
+            Self::Reverse => "Reverse",
+            Self::Stop => "Stop",
+            Self::Rewind => "Rewind",
+            Self::FastForward => "Fast Forward",
+            Self::Play => "Play",
+            Self::Pause => "Pause",
+            Self::Record => "Record",
+            Self::Error => "Error",
+            Self::UsageSelectedIndicator => "Usage Selected Indicator",
+            Self::UsageInUseIndicator => "Usage In Use Indicator",
+            Self::UsageMultiModeIndicator => "Usage Multi Mode Indicator",
+            Self::IndicatorOn => "Indicator On",
+            Self::IndicatorFlash => "Indicator Flash",
+            Self::IndicatorSlowBlink => "Indicator Slow Blink",
+            Self::IndicatorFastBlink => "Indicator Fast Blink",
+            Self::IndicatorOff => "Indicator Off",
+            Self::FlashOnTime => "Flash On Time",
+            Self::SlowBlinkOnTime => "Slow Blink On Time",
+            Self::SlowBlinkOffTime => "Slow Blink Off Time",
+            Self::FastBlinkOnTime => "Fast Blink On Time",
+            Self::FastBlinkOffTime => "Fast Blink Off Time",
+            Self::UsageIndicatorColor => "Usage Indicator Color",
+            Self::Red => "Red",
+            Self::Green => "Green",
+            Self::Amber => "Amber",
+            Self::GenericIndicator => "Generic Indicator",
+        }
+    }
+}
+
+impl Display for Leds {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Consumer {
+    /// Name of the usage in the HID Usage Tables
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Unassigned => "Unassigned",
+            Self::ConsumerControl => "Consumer Control",
+            Self::NumericKeyPad => "Numeric Key Pad",
+            Self::ProgrammableButtons => "Programmable Buttons",
+            Self::Microphone => "Microphone",
+            Self::Headphone => "Headphone",
+            Self::GraphicEqualizer => "Graphic Equalizer",
+            Self::Plus10 => "Plus10",
+            Self::Plus100 => "Plus100",
+            Self::AmPm => "Am Pm",
+            Self::Power => "Power",
+            Self::Reset => "Reset",
+            Self::Sleep => "Sleep",
+            Self::SleepAfter => "Sleep After",
+            Self::SleepMode => "Sleep Mode",
+            Self::Illumination => "Illumination",
+            Self::FunctionButtons => "Function Buttons",
+            Self::Menu => "Menu",
+            Self::MenuPick => "Menu Pick",
+            Self::MenuUp => "Menu Up",
+            Self::MenuDown => "Menu Down",
+            Self::MenuLeft => "Menu Left",
+            Self::MenuRight => "Menu Right",
+            Self::MenuEscape => "Menu Escape",
+            Self::MenuValueIncrease => "Menu Value Increase",
+            Self::MenuValueDecrease => "Menu Value Decrease",
+            Self::DataOnScreen => "Data On Screen",
+            Self::ClosedCaption => "Closed Caption",
+            Self::ClosedCaptionSelect => "Closed Caption Select",
+            Self::VcrTv => "Vcr Tv",
+            Self::BroadcastMode => "Broadcast Mode",
+            Self::Snapshot => "Snapshot",
+            Self::Still => "Still",
+            Self::PictureByPictureToggle => "Picture By Picture Toggle",
+            Self::PictureByPictureSwap => "Picture By Picture Swap",
+            Self::RedMenuButton => "Red Menu Button",
+            Self::GreenMenuButton => "Green Menu Button",
+            Self::BlueMenuButton => "Blue Menu Button",
+            Self::YellowMenuButton => "Yellow Menu Button",
+            Self::Aspect => "Aspect",
+            Self::ThreeDModeSelect => "Three D Mode Select",
+            Self::DisplayBrightnessIncrement => "Display Brightness Increment",
+            Self::DisplayBrightnessDecrement => "Display Brightness Decrement",
+            Self::DisplayBrightness => "Display Brightness",
+            Self::DisplayBacklightToggle => "Display Backlight Toggle",
+            Self::DisplaySetBrightnessToMinimum => "Display Set Brightness to Minimum",
+            Self::DisplaySetBrightnessToMaximum => "Display Set Brightness to Maximum",
+            Self::DisplaySetAutoBrightness => "Display Set Auto Brightness",
+            Self::CameraAccessEnabled => "Camera Access Enabled",
+            Self::CameraAccessDisabled => "Camera Access Disabled",
+            Self::CameraAccessToggle => "Camera Access Toggle",
+            Self::KeyboardBrightnessIncrement => "Keyboard Brightness Increment",
+            Self::KeyboardBrightnessDecrement => "Keyboard Brightness Decrement",
+            Self::KeyboardBacklightSetLevel => "Keyboard Backlight Set Level",
+            Self::KeyboardBacklightOOC => "Keyboard Backlight OOC",
+            Self::KeyboardBacklightSetMinimum => "Keyboard Backlight Set Minimum",
+            Self::KeyboardBacklightSetMaximum => "Keyboard Backlight Set Maximum",
+            Self::KeyboardBacklightAuto => "Keyboard Backlight Auto",
+            Self::Selection => "Selection",
+            Self::AssignSelection => "Assign Selection",
+            Self::ModeStep => "Mode Step",
+            Self::RecallLast => "Recall Last",
+            Self::EnterChannel => "Enter Channel",
+            Self::OrderMovie => "Order Movie",
+            Self::Channel => "Channel",
+            Self::MediaSelection => "Media Selection",
+            Self::MediaSelectComputer => "Media Select Computer",
+            Self::MediaSelectTV => "Media Select TV",
+            Self::MediaSelectWWW => "Media Select WWW",
+            Self::MediaSelectDVD => "Media Select DVD",
+            Self::MediaSelectTelephone => "Media Select Telephone",
+            Self::MediaSelectProgramGuide => "Media Select Program Guide",
+            Self::MediaSelectVideoPhone => "Media Select Video Phone",
+            Self::MediaSelectGames => "Media Select Games",
+            Self::MediaSelectMessages => "Media Select Messages",
+            Self::MediaSelectCD => "Media Select CD",
+            Self::MediaSelectVCR => "Media Select VCR",
+            Self::MediaSelectTuner => "Media Select Tuner",
+            Self::Quit => "Quit",
+            Self::Help => "Help",
+            Self::MediaSelectTape => "Media Select Tape",
+            Self::MediaSelectCable => "Media Select Cable",
+            Self::MediaSelectSatellite => "Media Select Satellite",
+            Self::MediaSelectSecurity => "Media Select Security",
+            Self::MediaSelectHome => "Media Select Home",
+            Self::MediaSelectCall => "Media Select Call",
+            Self::ChannelIncrement => "Channel Increment",
+            Self::ChannelDecrement => "Channel Decrement",
+            Self::MediaSelectSAP => "Media Select SAP",
+            Self::VCRPlus => "VCR Plus",
+            Self::Once => "Once",
+            Self::Daily => "Daily",
+            Self::Weekly => "Weekly",
+            Self::Monthly => "Monthly",
+            Self::Play => "Play",
+            Self::Pause => "Pause",
+            Self::Record => "Record",
+            Self::FastForward => "Fast Forward",
+            Self::Rewind => "Rewind",
+            Self::ScanNextTrack => "Scan Next Track",
+            Self::ScanPreviousTrack => "Scan Previous Track",
+            Self::Stop => "Stop",
+            Self::Eject => "Eject",
+            Self::RandomPlay => "Random Play",
+            Self::SelectDisc => "Select Disc",
+            Self::EnterDisc => "Enter Disc",
+            Self::Repeat => "Repeat",
+            Self::Tracking => "Tracking",
+            Self::TrackNormal => "Track Normal",
+            Self::SlowTracking => "Slow Tracking",
+            Self::FrameForward => "Frame Forward",
+            Self::FrameBack => "Frame Back",
+            Self::Mark => "Mark",
+            Self::ClearMark => "Clear Mark",
+            Self::RepeatFromMark => "Repeat From Mark",
+            Self::ReturnToMark => "Return to Mark",
+            Self::SearchMarkForward => "Search Mark Forward",
+            Self::SearchMarkBackwards => "Search Mark Backwards",
+            Self::CounterReset => "Counter Reset",
+            Self::ShowCounter => "Show Counter",
+            Self::TrackingIncrement => "Tracking Increment",
+            Self::TrackingDecrement => "Tracking Decrement",
+            Self::StopEject => "Stop Eject",
+            Self::PlayPause => "Play Pause",
+            Self::PlaySkip => "Play Skip",
+            Self::VoiceCommand => "Voice Command",
+            Self::InvokeCaptureInterface => "Invoke Capture Interface",
+            Self::StartOrStopGameRecording => "Start or Stop Game Recording",
+            Self::HistoricalGameCapture => "Historical Game Capture",
+            Self::CaptureGameScreenshot => "Capture Game Screenshot",
+            Self::ShowOrHideRecordingIndicator => "Show or Hide Recording Indicator",
+            Self::StartOrStopMicrophoneCapture => "Start or Stop Microphone Capture",
+            Self::StartOrStopCameraCapture => "Start or Stop Camera Capture",
+            Self::StartOrStopGameBroadcast => "Start or Stop Game Broadcast",
+            Self::StartOrStopVoiceDictationSession => "Start or Stop Voice Dictation Session",
+            Self::InvokeDismissEmojiPicker => "Invoke Dismiss Emoji Picker",
+            Self::Volume => "Volume",
+            Self::Balance => "Balance",
+            Self::Mute => "Mute",
+            Self::Bass => "Bass",
+            Self::Treble => "Treble",
+            Self::BassBoost => "Bass Boost",
+            Self::SurroundMode => "Surround Mode",
+            Self::Loudness => "Loudness",
+            Self::MPX => "MPX",
+            Self::VolumeIncrement => "Volume Increment",
+            Self::VolumeDecrement => "Volume Decrement",
+            Self::SpeedSelect => "Speed Select",
+            Self::PlaybackSpeed => "Playback Speed",
+            Self::StandardPlay => "Standard Play",
+            Self::LongPlay => "Long Play",
+            Self::ExtendedPlay => "Extended Play",
+            Self::Slow => "Slow",
+            Self::FanEnable => "Fan Enable",
+            Self::FanSpeed => "Fan Speed",
+            Self::LightEnable => "Light Enable",
+            Self::LightIlluminationLevel => "Light Illumination Level",
+            Self::ClimateControlEnable => "Climate Control Enable",
+            Self::RoomTemperature => "Room Temperature",
+            Self::SecurityEnable => "Security Enable",
+            Self::FireAlarm => "Fire Alarm",
+            Self::PoliceAlarm => "Police Alarm",
+            Self::Proximity => "Proximity",
+            Self::Motion => "Motion",
+            Self::DuressAlarm => "Duress Alarm",
+            Self::HoldupAlarm => "Holdup Alarm",
+            Self::MedicalAlarm => "Medical Alarm",
+            Self::BalanceRight => "Balance Right",
+            Self::BalanceLeft => "Balance Left",
+            Self::BassIncrement => "Bass Increment",
+            Self::BassDecrement => "Bass Decrement",
+            Self::TrebleIncrement => "Treble Increment",
+            Self::TrebleDecrement => "Treble Decrement",
+            Self::SpeakerSystem => "Speaker System",
+            Self::ChannelLeft => "Channel Left",
+            Self::ChannelRight => "Channel Right",
+            Self::ChannelCenter => "Channel Center",
+            Self::ChannelFront => "Channel Front",
+            Self::ChannelCenterFront => "Channel Center Front",
+            Self::ChannelSide => "Channel Side",
+            Self::ChannelSurround => "Channel Surround",
+            Self::ChannelLowFrequencyEnhancement => "Channel Low Frequency Enhancement",
+            Self::ChannelTop => "Channel Top",
+            Self::ChannelUnknown => "Channel Unknown",
+            Self::SubChannel => "Sub Channel",
+            Self::SubChannelIncrement => "Sub Channel Increment",
+            Self::SubChannelDecrement => "Sub Channel Decrement",
+            Self::AlternateAudioIncrement => "Alternate Audio Increment",
+            Self::AlternateAudioDecrement => "Alternate Audio Decrement",
+            Self::ApplicationLaunchButtons => "Application Launch Buttons",
+            Self::ALLaunchButtonConfigurationTool => "AL Launch Button Configuration Tool",
+            Self::ALProgrammableButtonConfiguration => "AL Programmable Button Configuration",
+            Self::ALConsumerControlConfiguration => "AL Consumer Control Configuration",
+            Self::ALWordProcessor => "AL Word Processor",
+            Self::ALTextEditor => "AL Text Editor",
+            Self::ALSpreadsheet => "AL Spreadsheet",
+            Self::ALGraphicsEditor => "AL Graphics Editor",
+            Self::ALPresentationApp => "AL Presentation App",
+            Self::ALDatabaseApp => "AL Database App",
+            Self::ALEmailReader => "AL Email Reader",
+            Self::ALNewsreader => "AL Newsreader",
+            Self::ALVoicemail => "AL Voicemail",
+            Self::ALContactsAddressBook => "AL Contacts Address Book",
+            Self::ALCalendarSchedule => "AL Calendar Schedule",
+            Self::ALTaskProjectManager => "AL Task Project Manager",
+            Self::ALLogJournalTimecard => "AL Log Journal Timecard",
+            Self::ALCheckbookFinance => "AL Checkbook Finance",
+            Self::ALCalculator => "AL Calculator",
+            Self::ALAvCapturePlayback => "AL Av Capture Playback",
+            Self::ALLocalMachineBrowser => "AL Local Machine Browser",
+            Self::ALLanWanBrowser => "AL Lan Wan Browser",
+            Self::ALInternetBrowser => "AL Internet Browser",
+            Self::ALRemoteNetworkingISPConnect => "AL Remote Networking ISP Connect",
+            Self::ALNetworkConference => "AL Network Conference",
+            Self::ALNetworkChat => "AL Network Chat",
+            Self::ALTelephonyDialer => "AL Telephony Dialer",
+            Self::ALLogon => "AL Logon",
+            Self::ALLogoff => "AL Logoff",
+            Self::ALLogonLogoff => "AL Logon Logoff",
+            Self::ALTerminalLockScreensaver => "AL Terminal Lock Screensaver",
+            Self::ALControlPanel => "AL Control Panel",
+            Self::ALCommandLineProcessorRun => "AL Command Line Processor Run",
+            Self::ALProcessTaskManager => "AL Process Task Manager",
+            Self::ALSelectTaskApplication => "AL Select Task Application",
+            Self::ALNextTaskApplication => "AL Next Task Application",
+            Self::ALPreviousTaskApplication => "AL Previous Task Application",
+            Self::ALPreemptiveHaltTaskApplication => "AL Preemptive Halt Task Application",
+            Self::ALIntegratedHelpCenter => "AL Integrated Help Center",
+            Self::ALDocuments => "AL Documents",
+            Self::ALThesaurus => "AL Thesaurus",
+            Self::ALDictionary => "AL Dictionary",
+            Self::ALDesktop => "AL Desktop",
+            Self::ALSpellCheck => "AL Spell Check",
+            Self::ALGrammarCheck => "AL Grammar Check",
+            Self::ALWirelessStatus => "AL Wireless Status",
+            Self::ALKeyboardLayout => "AL Keyboard Layout",
+            Self::ALVirusProtection => "AL Virus Protection",
+            Self::ALEncryption => "AL Encryption",
+            Self::ALScreenSaver => "AL Screen Saver",
+            Self::ALAlarms => "AL Alarms",
+            Self::ALClock => "AL Clock",
+            Self::ALFileBrowser => "AL File Browser",
+            Self::ALPowerStatus => "AL Power Status",
+            Self::ALImageBrowser => "AL Image Browser",
+            Self::ALAudioBrowser => "AL Audio Browser",
+            Self::ALMovieBrowser => "AL Movie Browser",
+            Self::ALDigitalRightsManager => "AL Digital Rights Manager",
+            Self::ALDigitalWallet => "AL Digital Wallet",
+            Self::ALInstantMessaging => "AL Instant Messaging",
+            Self::ALOemFeaturesTipsTutorialBrowser => "AL Oem Features Tips Tutorial Browser",
+            Self::ALOemHelp => "AL Oem Help",
+            Self::ALOnlineCommunity => "AL Online Community",
+            Self::ALEntertainmentContentBrowser => "AL Entertainment Content Browser",
+            Self::ALOnlineShoppingBrowser => "AL Online Shopping Browser",
+            Self::ALSmartCardInformationHelp => "AL Smart Card Information Help",
+            Self::ALMarketMonitorFinanceBrowser => "AL Market Monitor Finance Browser",
+            Self::ALCustomizedCorporateNewsBrowser => "AL Customized Corporate News Browser",
+            Self::ALOnlineActivityBrowser => "AL Online Activity Browser",
+            Self::ALResearchSearchBrowser => "AL Research Search Browser",
+            Self::ALAudioPlayer => "AL Audio Player",
+            Self::ALMessageStatus => "AL Message Status",
+            Self::ALContactSync => "AL Contact Sync",
+            Self::ALNavigation => "AL Navigation",
+            Self::ALContextAwareDesktopAssistant => "AL Context Aware Desktop Assistant",
+            Self::GenericGUIApplicationControls => "Generic GUI Application Controls",
+            Self::ACNew => "AC New",
+            Self::ACOpen => "AC Open",
+            Self::ACClose => "AC Close",
+            Self::ACExit => "AC Exit",
+            Self::ACMaximize => "AC Maximize",
+            Self::ACMinimize => "AC Minimize",
+            Self::ACSave => "AC Save",
+            Self::ACPrint => "AC Print",
+            Self::ACProperties => "AC Properties",
+            Self::ACUndo => "AC Undo",
+            Self::ACCopy => "AC Copy",
+            Self::ACCut => "AC Cut",
+            Self::ACPaste => "AC Paste",
+            Self::ACSelectAll => "AC Select All",
+            Self::ACFind => "AC Find",
+            Self::ACFindAndReplace => "AC Find and Replace",
+            Self::ACSearch => "AC Search",
+            Self::ACGoTo => "AC Go to",
+            Self::ACHome => "AC Home",
+            Self::ACBack => "AC Back",
+            Self::ACForward => "AC Forward",
+            Self::ACStop => "AC Stop",
+            Self::ACRefresh => "AC Refresh",
+            Self::ACPreviousLink => "AC Previous Link",
+            Self::ACNextLink => "AC Next Link",
+            Self::ACBookmarks => "AC Bookmarks",
+            Self::ACHistory => "AC History",
+            Self::ACSubscriptions => "AC Subscriptions",
+            Self::ACZoomIn => "AC Zoom In",
+            Self::ACZoomOut => "AC Zoom Out",
+            Self::ACZoom => "AC Zoom",
+            Self::ACFullScreenView => "AC Full Screen View",
+            Self::ACNormalView => "AC Normal View",
+            Self::ACViewToggle => "AC View Toggle",
+            Self::ACScrollUp => "AC Scroll Up",
+            Self::ACScrollDown => "AC Scroll Down",
+            Self::ACScroll => "AC Scroll",
+            Self::ACPanLeft => "AC Pan Left",
+            Self::ACPanRight => "AC Pan Right",
+            Self::ACPan => "AC Pan",
+            Self::ACNewWindow => "AC New Window",
+            Self::ACTileHorizontally => "AC Tile Horizontally",
+            Self::ACTileVertically => "AC Tile Vertically",
+            Self::ACFormat => "AC Format",
+            Self::ACEdit => "AC Edit",
+            Self::ACBold => "AC Bold",
+            Self::ACItalics => "AC Italics",
+            Self::ACUnderline => "AC Underline",
+            Self::ACStrikethrough => "AC Strikethrough",
+            Self::ACSubscript => "AC Subscript",
+            Self::ACSuperscript => "AC Superscript",
+            Self::ACAllCaps => "AC All Caps",
+            Self::ACRotate => "AC Rotate",
+            Self::ACResize => "AC Resize",
+            Self::ACFlipHorizontal => "AC Flip Horizontal",
+            Self::ACFlipVertical => "AC Flip Vertical",
+            Self::ACMirrorHorizontal => "AC Mirror Horizontal",
+            Self::ACMirrorVertical => "AC Mirror Vertical",
+            Self::ACFontSelect => "AC Font Select",
+            Self::ACFontColor => "AC Font Color",
+            Self::ACFontSize => "AC Font Size",
+            Self::ACJustifyLeft => "AC Justify Left",
+            Self::ACJustifyCenterH => "AC Justify Center H",
+            Self::ACJustifyRight => "AC Justify Right",
+            Self::ACJustifyBlockH => "AC Justify Block H",
+            Self::ACJustifyTop => "AC Justify Top",
+            Self::ACJustifyCenterV => "AC Justify Center V",
+            Self::ACJustifyBottom => "AC Justify Bottom",
+            Self::ACJustifyBlockV => "AC Justify Block V",
+            Self::ACIndentDecrease => "AC Indent Decrease",
+            Self::ACIndentIncrease => "AC Indent Increase",
+            Self::ACNumberedList => "AC Numbered List",
+            Self::ACRestartNumbering => "AC Restart Numbering",
+            Self::ACBulletedList => "AC Bulleted List",
+            Self::ACPromote => "AC Promote",
+            Self::ACDemote => "AC Demote",
+            Self::ACYes => "AC Yes",
+            Self::ACNo => "AC No",
+            Self::ACCancel => "AC Cancel",
+            Self::ACCatalog => "AC Catalog",
+            Self::ACBuyCheckout => "AC Buy Checkout",
+            Self::ACAddToCart => "AC Add to Cart",
+            Self::ACExpand => "AC Expand",
+            Self::ACExpandAll => "AC Expand All",
+            Self::ACCollapse => "AC Collapse",
+            Self::ACCollapseAll => "AC Collapse All",
+            Self::ACPrintPreview => "AC Print Preview",
+            Self::ACPasteSpecial => "AC Paste Special",
+            Self::ACInsertMode => "AC Insert Mode",
+            Self::ACDelete => "AC Delete",
+            Self::ACLock => "AC Lock",
+            Self::ACUnlock => "AC Unlock",
+            Self::ACProtect => "AC Protect",
+            Self::ACUnprotect => "AC Unprotect",
+            Self::ACAttachComment => "AC Attach Comment",
+            Self::ACDeleteComment => "AC Delete Comment",
+            Self::ACViewComment => "AC View Comment",
+            Self::ACSelectWord => "AC Select Word",
+            Self::ACSelectSentence => "AC Select Sentence",
+            Self::ACSelectParagraph => "AC Select Paragraph",
+            Self::ACSelectColumn => "AC Select Column",
+            Self::ACSelectRow => "AC Select Row",
+            Self::ACSelectTable => "AC Select Table",
+            Self::ACSelectObject => "AC Select Object",
+            Self::ACRedoRepeat => "AC Redo Repeat",
+            Self::ACSort => "AC Sort",
+            Self::ACSortAscending => "AC Sort Ascending",
+            Self::ACSortDescending => "AC Sort Descending",
+            Self::ACFilter => "AC Filter",
+            Self::ACSetClock => "AC Set Clock",
+            Self::ACViewClock => "AC View Clock",
+            Self::ACSelectTimeZone => "AC Select Time Zone",
+            Self::ACEditTimeZones => "AC Edit Time Zones",
+            Self::ACSetAlarm => "AC Set Alarm",
+            Self::ACClearAlarm => "AC Clear Alarm",
+            Self::ACSnoozeAlarm => "AC Snooze Alarm",
+            Self::ACResetAlarm => "AC Reset Alarm",
+            Self::ACSynchronize => "AC Synchronize",
+            Self::ACSendReceive => "AC Send Receive",
+            Self::ACSendTo => "AC Send to",
+            Self::ACReply => "AC Reply",
+            Self::ACReplyAll => "AC Reply All",
+            Self::ACForwardMsg => "AC Forward Msg",
+            Self::ACSend => "AC Send",
+            Self::ACAttachFile => "AC Attach File",
+            Self::ACUpload => "AC Upload",
+            Self::ACDownloadSaveTargetAs => "AC Download Save Target As",
+            Self::ACSetBorders => "AC Set Borders",
+            Self::ACInsertRow => "AC Insert Row",
+            Self::ACInsertColumn => "AC Insert Column",
+            Self::ACInsertFile => "AC Insert File",
+            Self::ACInsertPicture => "AC Insert Picture",
+            Self::ACInsertObject => "AC Insert Object",
+            Self::ACInsertSymbol => "AC Insert Symbol",
+            Self::ACSaveAndClose => "AC Save and Close",
+            Self::ACRename => "AC Rename",
+            Self::ACMerge => "AC Merge",
+            Self::ACSplit => "AC Split",
+            Self::ACDistributeHorizontally => "AC Distribute Horizontally",
+            Self::ACDistributeVertically => "AC Distribute Vertically",
+            Self::ACNextKeyboardLayoutSelect => "AC Next Keyboard Layout Select",
+            Self::ACNavigationGuidance => "AC Navigation Guidance",
+            Self::ACDesktopShowAllWindows => "AC Desktop Show All Windows",
+            Self::ACSoftKeyLeft => "AC Soft Key Left",
+            Self::ACSoftKeyRight => "AC Soft Key Right",
+            Self::ACDesktopShowAllApplications => "AC Desktop Show All Applications",
+            Self::ACIdleKeepAlive => "AC Idle Keep Alive",
+            Self::ExtendedKeyboardAttributesCollection => "Extended Keyboard Attributes Collection",
+            Self::KeyboardFormFactor => "Keyboard Form Factor",
+            Self::KeyboardKeyType => "Keyboard Key Type",
+            Self::KeyboardPhysicalLayout => "Keyboard Physical Layout",
+            Self::VendorSpecificKeyboardPhysicalLayout => {
+                "Vendor Specific Keyboard Physical Layout"
+            }
+            Self::KeyboardIetfLanguageTagIndex => "Keyboard Ietf Language Tag Index",
+            Self::ImplementedKeyboardInputAssistControls => {
+                "Implemented Keyboard Input Assist Controls"
+            }
+            Self::KeyboardInputAssistPrevious => "Keyboard Input Assist Previous",
+            Self::KeyboardInputAssistNext => "Keyboard Input Assist Next",
+            Self::KeyboardInputAssistPreviousGroup => "Keyboard Input Assist Previous Group",
+            Self::KeyboardInputAssistNextGroup => "Keyboard Input Assist Next Group",
+            Self::KeyboardInputAssistAccept => "Keyboard Input Assist Accept",
+            Self::KeyboardInputAssistCancel => "Keyboard Input Assist Cancel",
+            Self::PrivacyScreenToggle => "Privacy Screen Toggle",
+            Self::PrivacyScreenLevelDecrement => "Privacy Screen Level Decrement",
+            Self::PrivacyScreenLevelIncrement => "Privacy Screen Level Increment",
+            Self::PrivacyScreenLevelMinimum => "Privacy Screen Level Minimum",
+            Self::PrivacyScreenLevelMaximum => "Privacy Screen Level Maximum",
+            Self::ContactEdited => "Contact Edited",
+            Self::ContactAdded => "Contact Added",
+            Self::ContactRecordActive => "Contact Record Active",
+            Self::ContactIndex => "Contact Index",
+            Self::ContactNickname => "Contact Nickname",
+            Self::ContactFirstName => "Contact First Name",
+            Self::ContactLastName => "Contact Last Name",
+            Self::ContactFullName => "Contact Full Name",
+            Self::ContactPhoneNumberPersonal => "Contact Phone Number Personal",
+            Self::ContactPhoneNumberBusiness => "Contact Phone Number Business",
+            Self::ContactPhoneNumberMobile => "Contact Phone Number Mobile",
+            Self::ContactPhoneNumberPager => "Contact Phone Number Pager",
+            Self::ContactPhoneNumberFax => "Contact Phone Number Fax",
+            Self::ContactPhoneNumberOther => "Contact Phone Number Other",
+            Self::ContactEmailPersonal => "Contact Email Personal",
+            Self::ContactEmailBusiness => "Contact Email Business",
+            Self::ContactEmailOther => "Contact Email Other",
+            Self::ContactEmailMain => "Contact Email Main",
+            Self::ContactSpeedDialNumber => "Contact Speed Dial Number",
+            Self::ContactStatusFlag => "Contact Status Flag",
+            Self::ContactMisc => "Contact Misc",
+        }
+    }
+}
+
+impl Display for Consumer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Desktop {
+    /// Name of the usage in the HID Usage Tables
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Undefined => "Undefined",
+            Self::Pointer => "Pointer",
+            Self::Mouse => "Mouse",
+            Self::Joystick => "Joystick",
+            Self::GamePad => "Game Pad",
+            Self::Keyboard => "Keyboard",
+            Self::Keypad => "Keypad",
+            Self::MultiAxisController => "Multi Axis Controller",
+            Self::TabletPcSystemControls => "Tablet Pc System Controls",
+            Self::WaterCoolingDevice => "Water Cooling Device",
+            Self::ComputerChassisDevice => "Computer Chassis Device",
+            Self::WirelessRadioControls => "Wireless Radio Controls",
+            Self::PortableDeviceControl => "Portable Device Control",
+            Self::SystemMultiAxisController => "System Multi-Axis Controller",
+            Self::SpatialController => "Spatial Controller",
+            Self::AssistiveControl => "Assistive Control",
+            Self::DeviceDock => "Device Dock",
+            Self::DockableDevice => "Dockable Device",
+            Self::CallStateManagementControl => "Call State Management Control",
+            Self::X => "X",
+            Self::Y => "Y",
+            Self::Z => "Z",
+            Self::Rx => "Rx",
+            Self::Ry => "Ry",
+            Self::Rz => "Rz",
+            Self::Slider => "Slider",
+            Self::Dial => "Dial",
+            Self::Wheel => "Wheel",
+            Self::HatSwitch => "Hat Switch",
+            Self::CountedBuffer => "Counted Buffer",
+            Self::ByteCount => "Byte Count",
+            Self::MotionWakeup => "Motion Wakeup",
+            Self::Start => "Start",
+            Self::Select => "Select",
+            Self::Vx => "Vx",
+            Self::Vy => "Vy",
+            Self::Vz => "Vz",
+            Self::Vbrx => "Vbrx",
+            Self::Vbry => "Vbry",
+            Self::Vbrz => "Vbrz",
+            Self::Vno => "Vno",
+            Self::FeatureNotification => "Feature Notification",
+            Self::ResolutionMultiplier => "Resolution Multiplier",
+            Self::SystemControl => "System Control",
+            Self::SystemPowerDown => "System Power Down",
+            Self::SystemSleep => "System Sleep",
+            Self::SystemWakeUp => "System Wake Up",
+            Self::SystemContextMenu => "System Context Menu",
+            Self::SystemMainMenu => "System Main Menu",
+            Self::SystemAppMenu => "System App Menu",
+            Self::SystemHelpMenu => "System Help Menu",
+            Self::SystemMenuExit => "System Menu Exit",
+            Self::SystemMenuSelect => "System Menu Select",
+            Self::SystemMenuRight => "System Menu Right",
+            Self::SystemMenuLeft => "System Menu Left",
+            Self::SystemMenuUp => "System Menu Up",
+            Self::SystemMenuDown => "System Menu Down",
+            Self::SystemColdRestart => "System Cold Restart",
+            Self::SystemWarmRestart => "System Warm Restart",
+            Self::DPadUp => "D Pad Up",
+            Self::DPadDown => "D Pad Down",
+            Self::DPadRight => "D Pad Right",
+            Self::DPadLeft => "D Pad Left",
+        }
+    }
+}
+
+impl Display for Desktop {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Game {
+    /// Name of the usage in the HID Usage Tables
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Undefined => "Undefined",
+            Self::Game3DController => "Game3 D Controller",
+            Self::PinballDevice => "Pinball Device",
+            Self::GunDevice => "Gun Device",
+            Self::PointOfView => "Point of View",
+            Self::TurnRightLeft => "Turn Right Left",
+            Self::PitchRightLeft => "Pitch Right Left",
+            Self::RollForwardBackward => "Roll Forward Backward",
+            Self::MoveRightLeft => "Move Right Left",
+            Self::MoveForwardBackward => "Move Forward Backward",
+            Self::MoveUpDown => "Move Up Down",
+            Self::LeanRightLeft => "Lean Right Left",
+            Self::LeanForwardBackward => "Lean Forward Backward",
+            Self::HeightOfPOV => "Height of POV",
+            Self::Flipper => "Flipper",
+            Self::SecondaryFlipper => "Secondary Flipper",
+            Self::Bump => "Bump",
+            Self::NewGame => "New Game",
+            Self::ShootBall => "Shoot Ball",
+            Self::Player => "Player",
+            Self::GunBolt => "Gun Bolt",
+            Self::GunClip => "Gun Clip",
+            Self::GunSelector => "Gun Selector",
+            Self::GunSingleShot => "Gun Single Shot",
+            Self::GunBurst => "Gun Burst",
+            Self::GunAutomatic => "Gun Automatic",
+            Self::GunSafety => "Gun Safety",
+            Self::GamePadFireJump => "Game Pad Fire Jump",
+            Self::GamePadTrigger => "Game Pad Trigger",
+        }
+    }
+}
+
+impl Display for Game {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Keyboard {
+    /// Name of the usage in the HID Usage Tables
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::NoEventIndicated => "No Event Indicated",
+            Self::ErrorRollOver => "Error Roll Over",
+            Self::POSTFail => "POST Fail",
+            Self::ErrorUndefine => "Error Undefine",
+            Self::A => "A",
+            Self::B => "B",
+            Self::C => "C",
+            Self::D => "D",
+            Self::E => "E",
+            Self::F => "F",
+            Self::G => "G",
+            Self::H => "H",
+            Self::I => "I",
+            Self::J => "J",
+            Self::K => "K",
+            Self::L => "L",
+            Self::M => "M",
+            Self::N => "N",
+            Self::O => "O",
+            Self::P => "P",
+            Self::Q => "Q",
+            Self::R => "R",
+            Self::S => "S",
+            Self::T => "T",
+            Self::U => "U",
+            Self::V => "V",
+            Self::W => "W",
+            Self::X => "X",
+            Self::Y => "Y",
+            Self::Z => "Z",
+            Self::Keyboard1 => "Keyboard1",
+            Self::Keyboard2 => "Keyboard2",
+            Self::Keyboard3 => "Keyboard3",
+            Self::Keyboard4 => "Keyboard4",
+            Self::Keyboard5 => "Keyboard5",
+            Self::Keyboard6 => "Keyboard6",
+            Self::Keyboard7 => "Keyboard7",
+            Self::Keyboard8 => "Keyboard8",
+            Self::Keyboard9 => "Keyboard9",
+            Self::Keyboard0 => "Keyboard0",
+            Self::ReturnEnter => "Return Enter",
+            Self::Escape => "Escape",
+            Self::DeleteBackspace => "Delete Backspace",
+            Self::Tab => "Tab",
+            Self::Space => "Space",
+            Self::Minus => "Minus",
+            Self::Equal => "Equal",
+            Self::LeftBrace => "Left Brace",
+            Self::RightBrace => "Right Brace",
+            Self::Backslash => "Backslash",
+            Self::NonUSHash => "Non US Hash",
+            Self::Semicolon => "Semicolon",
+            Self::Apostrophe => "Apostrophe",
+            Self::Grave => "Grave",
+            Self::Comma => "Comma",
+            Self::Dot => "Dot",
+            Self::ForwardSlash => "Forward Slash",
+            Self::CapsLock => "Caps Lock",
+            Self::F1 => "F1",
+            Self::F2 => "F2",
+            Self::F3 => "F3",
+            Self::F4 => "F4",
+            Self::F5 => "F5",
+            Self::F6 => "F6",
+            Self::F7 => "F7",
+            Self::F8 => "F8",
+            Self::F9 => "F9",
+            Self::F10 => "F10",
+            Self::F11 => "F11",
+            Self::F12 => "F12",
+            Self::PrintScreen => "Print Screen",
+            Self::ScrollLock => "Scroll Lock",
+            Self::Pause => "Pause",
+            Self::Insert => "Insert",
+            Self::Home => "Home",
+            Self::PageUp => "Page Up",
+            Self::DeleteForward => "Delete Forward",
+            Self::End => "End",
+            Self::PageDown => "Page Down",
+            Self::RightArrow => "Right Arrow",
+            Self::LeftArrow => "Left Arrow",
+            Self::DownArrow => "Down Arrow",
+            Self::UpArrow => "Up Arrow",
+            Self::KeypadNumLockAndClear => "Keypad Num Lock and Clear",
+            Self::KeypadDivide => "Keypad Divide",
+            Self::KeypadMultiply => "Keypad Multiply",
+            Self::KeypadSubtract => "Keypad Subtract",
+            Self::KeypadAdd => "Keypad Add",
+            Self::KeypadEnter => "Keypad Enter",
+            Self::Keypad1 => "Keypad1",
+            Self::Keypad2 => "Keypad2",
+            Self::Keypad3 => "Keypad3",
+            Self::Keypad4 => "Keypad4",
+            Self::Keypad5 => "Keypad5",
+            Self::Keypad6 => "Keypad6",
+            Self::Keypad7 => "Keypad7",
+            Self::Keypad8 => "Keypad8",
+            Self::Keypad9 => "Keypad9",
+            Self::Keypad0 => "Keypad0",
+            Self::KeypadDot => "Keypad Dot",
+            Self::NonUSBackslash => "Non US Backslash",
+            Self::Application => "Application",
+            Self::Power => "Power",
+            Self::KeypadEqual => "Keypad Equal",
+            Self::F13 => "F13",
+            Self::F14 => "F14",
+            Self::F15 => "F15",
+            Self::F16 => "F16",
+            Self::F17 => "F17",
+            Self::F18 => "F18",
+            Self::F19 => "F19",
+            Self::F20 => "F20",
+            Self::F21 => "F21",
+            Self::F22 => "F22",
+            Self::F23 => "F23",
+            Self::F24 => "F24",
+            Self::Execute => "Execute",
+            Self::Help => "Help",
+            Self::Menu => "Menu",
+            Self::Select => "Select",
+            Self::Stop => "Stop",
+            Self::Again => "Again",
+            Self::Undo => "Undo",
+            Self::Cut => "Cut",
+            Self::Copy => "Copy",
+            Self::Paste => "Paste",
+            Self::Find => "Find",
+            Self::Mute => "Mute",
+            Self::VolumeUp => "Volume Up",
+            Self::VolumeDown => "Volume Down",
+            Self::LockingCapsLock => "Locking Caps Lock",
+            Self::LockingNumLock => "Locking Num Lock",
+            Self::LockingScrollLock => "Locking Scroll Lock",
+            Self::KeypadComma => "Keypad Comma",
+            Self::KeypadEqualSign => "Keypad Equal Sign",
+            Self::Kanji1 => "Kanji1",
+            Self::Kanji2 => "Kanji2",
+            Self::Kanji3 => "Kanji3",
+            Self::Kanji4 => "Kanji4",
+            Self::Kanji5 => "Kanji5",
+            Self::Kanji6 => "Kanji6",
+            Self::Kanji7 => "Kanji7",
+            Self::Kanji8 => "Kanji8",
+            Self::Kanji9 => "Kanji9",
+            Self::LANG1 => "LANG1",
+            Self::LANG2 => "LANG2",
+            Self::LANG3 => "LANG3",
+            Self::LANG4 => "LANG4",
+            Self::LANG5 => "LANG5",
+            Self::LANG6 => "LANG6",
+            Self::LANG7 => "LANG7",
+            Self::LANG8 => "LANG8",
+            Self::LANG9 => "LANG9",
+            Self::AlternateErase => "Alternate Erase",
+            Self::SysReqAttention => "Sys Req Attention",
+            Self::Cancel => "Cancel",
+            Self::Clear => "Clear",
+            Self::Prior => "Prior",
+            Self::Return => "Return",
+            Self::Separator => "Separator",
+            Self::Out => "Out",
+            Self::Oper => "Oper",
+            Self::ClearAgain => "Clear Again",
+            Self::CrSelProps => "Cr Sel Props",
+            Self::ExSel => "Ex Sel",
+            Self::Keypad00 => "Keypad00",
+            Self::Keypad000 => "Keypad000",
+            Self::ThousandsSeparator => "Thousands Separator",
+            Self::DecimalSeparator => "Decimal Separator",
+            Self::CurrencyUnit => "Currency Unit",
+            Self::CurrencySubunit => "Currency Subunit",
+            Self::KeypadLeftParenthesis => "Keypad Left Parenthesis",
+            Self::KeypadRightParenthesis => "Keypad Right Parenthesis",
+            Self::KeypadLeftCurlyBrace => "Keypad Left Curly Brace",
+            Self::KeypadRightCurlyBrace => "Keypad Right Curly Brace",
+            Self::KeypadTab => "Keypad Tab",
+            Self::KeypadBackspace => "Keypad Backspace",
+            Self::KeypadA => "Keypad A",
+            Self::KeypadB => "Keypad B",
+            Self::KeypadC => "Keypad C",
+            Self::KeypadD => "Keypad D",
+            Self::KeypadE => "Keypad E",
+            Self::KeypadF => "Keypad F",
+            Self::KeypadXor => "Keypad Xor",
+            Self::KeypadCaret => "Keypad Caret",
+            Self::KeypadPercentage => "Keypad Percentage",
+            Self::KeypadLessThan => "Keypad Less Than",
+            Self::KeypadGreaterThan => "Keypad Greater Than",
+            Self::KeypadAmpersand => "Keypad Ampersand",
+            Self::KeypadDoubleAmpersand => "Keypad Double Ampersand",
+            Self::KeypadBar => "Keypad Bar",
+            Self::KeypadDoubleBar => "Keypad Double Bar",
+            Self::KeypadColon => "Keypad Colon",
+            Self::KeypadHash => "Keypad Hash",
+            Self::KeypadSpace => "Keypad Space",
+            Self::KeypadAt => "Keypad At",
+            Self::KeypadExclamation => "Keypad Exclamation",
+            Self::KeypadMemoryStore => "Keypad Memory Store",
+            Self::KeypadMemoryRecall => "Keypad Memory Recall",
+            Self::KeypadMemoryClear => "Keypad Memory Clear",
+            Self::KeypadMemoryAdd => "Keypad Memory Add",
+            Self::KeypadMemorySubtract => "Keypad Memory Subtract",
+            Self::KeypadMemoryMultiply => "Keypad Memory Multiply",
+            Self::KeypadMemoryDivide => "Keypad Memory Divide",
+            Self::KeypadPlusMinus => "Keypad Plus Minus",
+            Self::KeypadClear => "Keypad Clear",
+            Self::KeypadClearEntry => "Keypad Clear Entry",
+            Self::KeypadBinary => "Keypad Binary",
+            Self::KeypadOctal => "Keypad Octal",
+            Self::KeypadDecimal => "Keypad Decimal",
+            Self::KeypadHexadecimal => "Keypad Hexadecimal",
+            Self::LeftControl => "Left Control",
+            Self::LeftShift => "Left Shift",
+            Self::LeftAlt => "Left Alt",
+            Self::LeftGUI => "Left GUI",
+            Self::RightControl => "Right Control",
+            Self::RightShift => "Right Shift",
+            Self::RightAlt => "Right Alt",
+            Self::RightGUI => "Right GUI",
+            Self::MediaPlayPause => "Media Play Pause",
+            Self::MediaStopCD => "Media Stop CD",
+            Self::MediaPreviousSong => "Media Previous Song",
+            Self::MediaNextSong => "Media Next Song",
+            Self::MediaEjectCD => "Media Eject CD",
+            Self::MediaVolumeUp => "Media Volume Up",
+            Self::MediaVolumeDown => "Media Volume Down",
+            Self::MediaMute => "Media Mute",
+            Self::MediaWWW => "Media WWW",
+            Self::MediaBack => "Media Back",
+            Self::MediaForward => "Media Forward",
+            Self::MediaStop => "Media Stop",
+            Self::MediaFind => "Media Find",
+            Self::MediaScrollUp => "Media Scroll Up",
+            Self::MediaScrollDown => "Media Scroll Down",
+            Self::MediaEdit => "Media Edit",
+            Self::MediaSleep => "Media Sleep",
+            Self::MediaCoffee => "Media Coffee",
+            Self::MediaRefresh => "Media Refresh",
+            Self::MediaCalc => "Media Calc",
+        }
+    }
+}
+
+impl Display for Keyboard {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Simulation {
+    /// Name of the usage in the HID Usage Tables
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Undefined => "Undefined",
+            Self::FlightSimulationDevice => "Flight Simulation Device",
+            Self::AutomobileSimulationDevice => "Automobile Simulation Device",
+            Self::TankSimulationDevice => "Tank Simulation Device",
+            Self::SpaceshipSimulationDevice => "Spaceship Simulation Device",
+            Self::SubmarineSimulationDevice => "Submarine Simulation Device",
+            Self::SailingSimulationDevice => "Sailing Simulation Device",
+            Self::MotorcycleSimulationDevice => "Motorcycle Simulation Device",
+            Self::SportsSimulationDevice => "Sports Simulation Device",
+            Self::AirplaneSimulationDevice => "Airplane Simulation Device",
+            Self::HelicopterSimulationDevice => "Helicopter Simulation Device",
+            Self::MagicCarpetSimulationDevice => "Magic Carpet Simulation Device",
+            Self::Bicycle => "Bicycle",
+            Self::FlightControlStick => "Flight Control Stick",
+            Self::FlightStick => "Flight Stick",
+            Self::CyclicControl => "Cyclic Control",
+            Self::CyclicTrim => "Cyclic Trim",
+            Self::FlightYoke => "Flight Yoke",
+            Self::TrackControl => "Track Control",
+            Self::DrivingControl => "Driving Control",
+            Self::Aileron => "Aileron",
+            Self::AileronTrim => "Aileron Trim",
+            Self::AntiTorqueControl => "Anti Torque Control",
+            Self::AutoPilotEnable => "Auto Pilot Enable",
+            Self::ChaffRelease => "Chaff Release",
+            Self::CollectiveControl => "Collective Control",
+            Self::DiveBrake => "Dive Brake",
+            Self::ElectronicCounterMeasures => "Electronic Counter Measures",
+            Self::Elevator => "Elevator",
+            Self::ElevatorTrim => "Elevator Trim",
+            Self::Rudder => "Rudder",
+            Self::Throttle => "Throttle",
+            Self::FlightCommunication => "Flight Communication",
+            Self::FlareRelease => "Flare Release",
+            Self::LandingGear => "Landing Gear",
+            Self::ToeBrake => "Toe Brake",
+            Self::Trigger => "Trigger",
+            Self::WeaponsArm => "Weapons Arm",
+            Self::WeaponsSelect => "Weapons Select",
+            Self::WingFlaps => "Wing Flaps",
+            Self::Accelerator => "Accelerator",
+            Self::Brake => "Brake",
+            Self::Clutch => "Clutch",
+            Self::Shifter => "Shifter",
+            Self::Steering => "Steering",
+            Self::TurretDirection => "Turret Direction",
+            Self::BarrelElevation => "Barrel Elevation",
+            Self::DivePlane => "Dive Plane",
+            Self::Ballast => "Ballast",
+            Self::BicycleCrank => "Bicycle Crank",
+            Self::HandleBars => "Handle Bars",
+            Self::FrontBrake => "Front Brake",
+            Self::RearBrake => "Rear Brake",
+        }
+    }
+}
+
+impl Display for Simulation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Telephony {
+    /// Name of the usage in the HID Usage Tables
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Unassigned => "Unassigned",
+            Self::Phone => "Phone",
+            Self::AnsweringMachine => "Answering Machine",
+            Self::MessageControls => "Message Controls",
+            Self::Handset => "Handset",
+            Self::Headset => "Headset",
+            Self::TelephonyKeyPad => "Telephony Key Pad",
+            Self::ProgrammableButton => "Programmable Button",
+            Self::HookSwitch => "Hook Switch",
+            Self::Flash => "Flash",
+            Self::Feature => "Feature",
+            Self::Hold => "Hold",
+            Self::Redial => "Redial",
+            Self::Transfer => "Transfer",
+            Self::Drop => "Drop",
+            Self::Park => "Park",
+            Self::ForwardCalls => "Forward Calls",
+            Self::AlternateFunction => "Alternate Function",
+            Self::Line => "Line",
+            Self::SpeakerPhone => "Speaker Phone",
+            Self::Conference => "Conference",
+            Self::RingEnable => "Ring Enable",
+            Self::RingSelect => "Ring Select",
+            Self::PhoneMute => "Phone Mute",
+            Self::CallerID => "Caller ID",
+            Self::Send => "Send",
+            Self::SpeedDial => "Speed Dial",
+            Self::StoreNumber => "Store Number",
+            Self::RecallNumber => "Recall Number",
+            Self::PhoneDirectory => "Phone Directory",
+            Self::VoiceMail => "Voice Mail",
+            Self::ScreenCalls => "Screen Calls",
+            Self::DoNotDisturb => "Do Not Disturb",
+            Self::Message => "Message",
+            Self::AnswerOnOff => "Answer On Off",
+            Self::InsideDialTone => "Inside Dial Tone",
+            Self::OutsideDialTone => "Outside Dial Tone",
+            Self::InsideRingTone => "Inside Ring Tone",
+            Self::OutsideRingTone => "Outside Ring Tone",
+            Self::PriorityRingTone => "Priority Ring Tone",
+            Self::InsideRingback => "Inside Ringback",
+            Self::PriorityRingback => "Priority Ringback",
+            Self::LineBusyTone => "Line Busy Tone",
+            Self::ReorderTone => "Reorder Tone",
+            Self::CallWaitingTone => "Call Waiting Tone",
+            Self::ConfirmationTone1 => "Confirmation Tone1",
+            Self::ConfirmationTone2 => "Confirmation Tone2",
+            Self::TonesOff => "Tones Off",
+            Self::OutsideRingback => "Outside Ringback",
+            Self::Ringer => "Ringer",
+            Self::PhoneKey0 => "Phone Key0",
+            Self::PhoneKey1 => "Phone Key1",
+            Self::PhoneKey2 => "Phone Key2",
+            Self::PhoneKey3 => "Phone Key3",
+            Self::PhoneKey4 => "Phone Key4",
+            Self::PhoneKey5 => "Phone Key5",
+            Self::PhoneKey6 => "Phone Key6",
+            Self::PhoneKey7 => "Phone Key7",
+            Self::PhoneKey8 => "Phone Key8",
+            Self::PhoneKey9 => "Phone Key9",
+            Self::PhoneKeyStar => "Phone Key Star",
+            Self::PhoneKeyPound => "Phone Key Pound",
+            Self::PhoneKeyA => "Phone Key A",
+            Self::PhoneKeyB => "Phone Key B",
+            Self::PhoneKeyC => "Phone Key C",
+            Self::PhoneKeyD => "Phone Key D",
+        }
+    }
+}
+
+impl Display for Telephony {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Sensor {
+    /// Name of the usage in the HID Usage Tables
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Undefined => "Undefined",
+            Self::Sensor => "Sensor",
+            Self::Biometric => "Biometric",
+            Self::BiometricHumanPresence => "Biometric Human Presence",
+            Self::BiometricHumanProximity => "Biometric Human Proximity",
+            Self::BiometricHumanTouch => "Biometric Human Touch",
+            Self::BiometricBloodPressure => "Biometric Blood Pressure",
+            Self::BiometricBodyTemperature => "Biometric Body Temperature",
+            Self::BiometricHeartRate => "Biometric Heart Rate",
+            Self::BiometricHeartRateVariability => "Biometric Heart Rate Variability",
+            Self::BiometricPeripheralOxygenSaturation => "Biometric Peripheral Oxygen Saturation",
+            Self::BiometricRespiratoryRate => "Biometric Respiratory Rate",
+            Self::Electrical => "Electrical",
+            Self::ElectricalCapacitance => "Electrical Capacitance",
+            Self::ElectricalCurrent => "Electrical Current",
+            Self::ElectricalPower => "Electrical Power",
+            Self::ElectricalInductance => "Electrical Inductance",
+            Self::ElectricalResistance => "Electrical Resistance",
+            Self::ElectricalVoltage => "Electrical Voltage",
+            Self::ElectricalPotentiometer => "Electrical Potentiometer",
+            Self::ElectricalFrequency => "Electrical Frequency",
+            Self::ElectricalPeriod => "Electrical Period",
+            Self::Environmental => "Environmental",
+            Self::EnvironmentalAtmosphericPressure => "Environmental Atmospheric Pressure",
+            Self::EnvironmentalHumidity => "Environmental Humidity",
+            Self::EnvironmentalTemperature => "Environmental Temperature",
+            Self::EnvironmentalWindDirection => "Environmental Wind Direction",
+            Self::EnvironmentalWindSpeed => "Environmental Wind Speed",
+            Self::EnvironmentalAirQuality => "Environmental Air Quality",
+            Self::EnvironmentalHeatIndex => "Environmental Heat Index",
+            Self::EnvironmentalSurfaceTemperature => "Environmental Surface Temperature",
+            Self::EnvironmentalVolatileOrganicCompounds => {
+                "Environmental Volatile Organic Compounds"
+            }
+            Self::EnvironmentalObjectPresence => "Environmental Object Presence",
+            Self::EnvironmentalObjectProximity => "Environmental Object Proximity",
+            Self::Light => "Light",
+            Self::LightAmbientLight => "Light Ambient Light",
+            Self::LightConsumerInfrared => "Light Consumer Infrared",
+            Self::LightInfraredLight => "Light Infrared Light",
+            Self::LightVisibleLight => "Light Visible Light",
+            Self::LightUltravioletLight => "Light Ultraviolet Light",
+            Self::Location => "Location",
+            Self::LocationBroadcast => "Location Broadcast",
+            Self::LocationDeadReckoning => "Location Dead Reckoning",
+            Self::LocationGPS => "Location GPS",
+            Self::LocationLookup => "Location Lookup",
+            Self::LocationOther => "Location Other",
+            Self::LocationStatic => "Location Static",
+            Self::LocationTriangulation => "Location Triangulation",
+            Self::Mechanical => "Mechanical",
+            Self::MechanicalBooleanSwitch => "Mechanical Boolean Switch",
+            Self::MechanicalBooleanSwitchArray => "Mechanical Boolean Switch Array",
+            Self::MechanicalMultivalueSwitch => "Mechanical Multivalue Switch",
+            Self::MechanicalForce => "Mechanical Force",
+            Self::MechanicalPressure => "Mechanical Pressure",
+            Self::MechanicalStrain => "Mechanical Strain",
+            Self::MechanicalWeight => "Mechanical Weight",
+            Self::MechanicalHapticVibrator => "Mechanical Haptic Vibrator",
+            Self::MechanicalHallEffectSwitch => "Mechanical Hall Effect Switch",
+            Self::Motion => "Motion",
+            Self::MotionAccelerometer1D => "Motion Accelerometer1 D",
+            Self::MotionAccelerometer2D => "Motion Accelerometer2 D",
+            Self::MotionAccelerometer3D => "Motion Accelerometer3 D",
+            Self::MotionGyrometer1D => "Motion Gyrometer1 D",
+            Self::MotionGyrometer2D => "Motion Gyrometer2 D",
+            Self::MotionGyrometer3D => "Motion Gyrometer3 D",
+            Self::MotionMotionDetector => "Motion Motion Detector",
+            Self::MotionSpeedometer => "Motion Speedometer",
+            Self::MotionAccelerometer => "Motion Accelerometer",
+            Self::MotionGyrometer => "Motion Gyrometer",
+            Self::MotionGravityVector => "Motion Gravity Vector",
+            Self::MotionLinearAccelerometer => "Motion Linear Accelerometer",
+            Self::Orientation => "Orientation",
+            Self::OrientationCompass1D => "Orientation Compass1 D",
+            Self::OrientationCompass2D => "Orientation Compass2 D",
+            Self::OrientationCompass3D => "Orientation Compass3 D",
+            Self::OrientationInclinometer1D => "Orientation Inclinometer1 D",
+            Self::OrientationInclinometer2D => "Orientation Inclinometer2 D",
+            Self::OrientationInclinometer3D => "Orientation Inclinometer3 D",
+            Self::OrientationDistance1D => "Orientation Distance1 D",
+            Self::OrientationDistance2D => "Orientation Distance2 D",
+            Self::OrientationDistance3D => "Orientation Distance3 D",
+            Self::OrientationDeviceOrientation => "Orientation Device Orientation",
+            Self::OrientationCompass => "Orientation Compass",
+            Self::OrientationInclinometer => "Orientation Inclinometer",
+            Self::OrientationDistance => "Orientation Distance",
+            Self::OrientationRelativeOrientation => "Orientation Relative Orientation",
+            Self::OrientationSimpleOrientation => "Orientation Simple Orientation",
+            Self::Scanner => "Scanner",
+            Self::ScannerBarcode => "Scanner Barcode",
+            Self::ScannerRFID => "Scanner RFID",
+            Self::ScannerNFC => "Scanner NFC",
+            Self::Time => "Time",
+            Self::TimeAlarmTimer => "Time Alarm Timer",
+            Self::TimeRealTimeClock => "Time Real Time Clock",
+            Self::PersonalActivity => "Personal Activity",
+            Self::PersonalActivityActivityDetection => "Personal Activity Activity Detection",
+            Self::PersonalActivityDevicePosition => "Personal Activity Device Position",
+            Self::PersonalActivityFloorTracker => "Personal Activity Floor Tracker",
+            Self::PersonalActivityPedometer => "Personal Activity Pedometer",
+            Self::PersonalActivityStepDetection => "Personal Activity Step Detection",
+            Self::OrientationExtended => "Orientation Extended",
+            Self::OrientationExtendedGeomagneticOrientation => {
+                "Orientation Extended Geomagnetic Orientation"
+            }
+            Self::OrientationExtendedMagnetometer => "Orientation Extended Magnetometer",
+            Self::Gesture => "Gesture",
+            Self::GestureChassisFlipGesture => "Gesture Chassis Flip Gesture",
+            Self::GestureHingeFoldGesture => "Gesture Hinge Fold Gesture",
+            Self::Other => "Other",
+            Self::OtherCustom => "Other Custom",
+            Self::OtherGeneric => "Other Generic",
+            Self::OtherGenericEnumerator => "Other Generic Enumerator",
+            Self::OtherHingeAngle => "Other Hinge Angle",
+            Self::VendorReserved1 => "Vendor Reserved1",
+            Self::VendorReserved2 => "Vendor Reserved2",
+            Self::VendorReserved3 => "Vendor Reserved3",
+            Self::VendorReserved4 => "Vendor Reserved4",
+            Self::VendorReserved5 => "Vendor Reserved5",
+            Self::VendorReserved6 => "Vendor Reserved6",
+            Self::VendorReserved7 => "Vendor Reserved7",
+            Self::VendorReserved8 => "Vendor Reserved8",
+            Self::VendorReserved9 => "Vendor Reserved9",
+            Self::VendorReserved10 => "Vendor Reserved10",
+            Self::VendorReserved11 => "Vendor Reserved11",
+            Self::VendorReserved12 => "Vendor Reserved12",
+            Self::VendorReserved13 => "Vendor Reserved13",
+            Self::VendorReserved14 => "Vendor Reserved14",
+            Self::VendorReserved15 => "Vendor Reserved15",
+            Self::VendorReserved16 => "Vendor Reserved16",
+            Self::Event => "Event",
+            Self::EventSensorState => "Event Sensor State",
+            Self::EventSensorEvent => "Event Sensor Event",
+            Self::Property => "Property",
+            Self::PropertyFriendlyName => "Property Friendly Name",
+            Self::PropertyPersistentUniqueID => "Property Persistent Unique ID",
+            Self::PropertySensorStatus => "Property Sensor Status",
+            Self::PropertyMinimumReportInterval => "Property Minimum Report Interval",
+            Self::PropertySensorManufacturer => "Property Sensor Manufacturer",
+            Self::PropertySensorModel => "Property Sensor Model",
+            Self::PropertySensorSerialNumber => "Property Sensor Serial Number",
+            Self::PropertySensorDescription => "Property Sensor Description",
+            Self::PropertySensorConnectionType => "Property Sensor Connection Type",
+            Self::PropertySensorDevicePath => "Property Sensor Device Path",
+            Self::PropertyHardwareRevision => "Property Hardware Revision",
+            Self::PropertyFirmwareVersion => "Property Firmware Version",
+            Self::PropertyReleaseDate => "Property Release Date",
+            Self::PropertyReportInterval => "Property Report Interval",
+            Self::PropertyChangeSensitivityAbsolute => "Property Change Sensitivity Absolute",
+            Self::PropertyChangeSensitivityPercentOfRange => {
+                "Property Change Sensitivity Percent of Range"
+            }
+            Self::PropertyChangeSensitivityPercentRelative => {
+                "Property Change Sensitivity Percent Relative"
+            }
+            Self::PropertyAccuracy => "Property Accuracy",
+            Self::PropertyResolution => "Property Resolution",
+            Self::PropertyMaximum => "Property Maximum",
+            Self::PropertyMinimum => "Property Minimum",
+            Self::PropertyReportingState => "Property Reporting State",
+            Self::PropertySamplingRate => "Property Sampling Rate",
+            Self::PropertyResponseCurve => "Property Response Curve",
+            Self::PropertyPowerState => "Property Power State",
+            Self::PropertyMaximumFIFOEvents => "Property Maximum FIFO Events",
+            Self::PropertyReportLatency => "Property Report Latency",
+            Self::PropertyFlushFIFOEvents => "Property Flush FIFO Events",
+            Self::PropertyMaximumPowerConsumption => "Property Maximum Power Consumption",
+            Self::PropertyIsPrimary => "Property Is Primary",
+            Self::PropertyHumanPresenceDetectionType => "Property Human Presence Detection Type",
+            Self::DataFieldLocation => "Data Field Location",
+            Self::DataFieldAltitudeAntennaSeaLevel => "Data Field Altitude Antenna Sea Level",
+            Self::DataFieldDifferentialReferenceStationID => {
+                "Data Field Differential Reference Station ID"
+            }
+            Self::DataFieldAltitudeEllipsoidError => "Data Field Altitude Ellipsoid Error",
+            Self::DataFieldAltitudeEllipsoid => "Data Field Altitude Ellipsoid",
+            Self::DataFieldAltitudeSeaLevelError => "Data Field Altitude Sea Level Error",
+            Self::DataFieldAltitudeSeaLevel => "Data Field Altitude Sea Level",
+            Self::DataFieldDifferentialGPSDataAge => "Data Field Differential GPS Data Age",
+            Self::DataFieldErrorRadius => "Data Field Error Radius",
+            Self::DataFieldFixQuality => "Data Field Fix Quality",
+            Self::DataFieldFixType => "Data Field Fix Type",
+            Self::DataFieldGeoidalSeparation => "Data Field Geoidal Separation",
+            Self::DataFieldGPSOperationMode => "Data Field GPS Operation Mode",
+            Self::DataFieldGPSSelectionMode => "Data Field GPS Selection Mode",
+            Self::DataFieldGPSStatus => "Data Field GPS Status",
+            Self::DataFieldPositionDilutionOfPrecision => {
+                "Data Field Position Dilution of Precision"
+            }
+            Self::DataFieldHorizontalDilutionOfPrecision => {
+                "Data Field Horizontal Dilution of Precision"
+            }
+            Self::DataFieldVerticalDilutionOfPrecision => {
+                "Data Field Vertical Dilution of Precision"
+            }
+            Self::DataFieldLatitude => "Data Field Latitude",
+            Self::DataFieldLongitude => "Data Field Longitude",
+            Self::DataFieldTrueHeading => "Data Field True Heading",
+            Self::DataFieldMagneticHeading => "Data Field Magnetic Heading",
+            Self::DataFieldMagneticVariation => "Data Field Magnetic Variation",
+            Self::DataFieldSpeed => "Data Field Speed",
+            Self::DataFieldSatellitesInView => "Data Field Satellites In View",
+            Self::DataFieldSatellitesInViewAzimuth => "Data Field Satellites In View Azimuth",
+            Self::DataFieldSatellitesInViewElevation => "Data Field Satellites In View Elevation",
+            Self::DataFieldSatellitesInViewIDs => "Data Field Satellites In View I Ds",
+            Self::DataFieldSatellitesInViewPRNs => "Data Field Satellites In View PR Ns",
+            Self::DataFieldSatellitesInViewSNRatios => "Data Field Satellites In View SN Ratios",
+            Self::DataFieldSatellitesUsedCount => "Data Field Satellites Used Count",
+            Self::DataFieldSatellitesUsedPRNs => "Data Field Satellites Used PR Ns",
+            Self::DataFieldNMEASentence => "Data Field NMEA Sentence",
+            Self::DataFieldAddressLine1 => "Data Field Address Line1",
+            Self::DataFieldAddressLine2 => "Data Field Address Line2",
+            Self::DataFieldCity => "Data Field City",
+            Self::DataFieldStateOrProvince => "Data Field State or Province",
+            Self::DataFieldCountryOrRegion => "Data Field Country or Region",
+            Self::DataFieldPostalCode => "Data Field Postal Code",
+            Self::DataFieldEnvironmental => "Data Field Environmental",
+            Self::DataFieldAtmosphericPressure => "Data Field Atmospheric Pressure",
+            Self::DataFieldRelativeHumidity => "Data Field Relative Humidity",
+            Self::DataFieldTemperature => "Data Field Temperature",
+            Self::DataFieldWindDirection => "Data Field Wind Direction",
+            Self::DataFieldWindSpeed => "Data Field Wind Speed",
+            Self::DataFieldAirQualityIndex => "Data Field Air Quality Index",
+            Self::DataFieldEquivalentCO2 => "Data Field Equivalent CO2",
+            Self::DataFieldVolatileOrganicCompoundConcentration => {
+                "Data Field Volatile Organic Compound Concentration"
+            }
+            Self::DataFieldObjectPresence => "Data Field Object Presence",
+            Self::DataFieldObjectProximityRange => "Data Field Object Proximity Range",
+            Self::DataFieldObjectProximityOutOfRange => "Data Field Object Proximity Out of Range",
+            Self::PropertyEnvironmental => "Property Environmental",
+            Self::PropertyReferencePressure => "Property Reference Pressure",
+            Self::DataFieldMotion => "Data Field Motion",
+            Self::DataFieldMotionState => "Data Field Motion State",
+            Self::DataFieldAcceleration => "Data Field Acceleration",
+            Self::DataFieldAccelerationAxisX => "Data Field Acceleration Axis X",
+            Self::DataFieldAccelerationAxisY => "Data Field Acceleration Axis Y",
+            Self::DataFieldAccelerationAxisZ => "Data Field Acceleration Axis Z",
+            Self::DataFieldAngularVelocity => "Data Field Angular Velocity",
+            Self::DataFieldAngularVelocityAboutXAxis => "Data Field Angular Velocity About X Axis",
+            Self::DataFieldAngularVelocityAboutYAxis => "Data Field Angular Velocity About Y Axis",
+            Self::DataFieldAngularVelocityAboutZAxis => "Data Field Angular Velocity About Z Axis",
+            Self::DataFieldAngularPosition => "Data Field Angular Position",
+            Self::DataFieldAngularPositionAboutXAxis => "Data Field Angular Position About X Axis",
+            Self::DataFieldAngularPositionAboutYAxis => "Data Field Angular Position About Y Axis",
+            Self::DataFieldAngularPositionAboutZAxis => "Data Field Angular Position About Z Axis",
+            Self::DataFieldMotionSpeed => "Data Field Motion Speed",
+            Self::DataFieldMotionIntensity => "Data Field Motion Intensity",
+            Self::DataFieldOrientation => "Data Field Orientation",
+            Self::DataFieldHeading => "Data Field Heading",
+            Self::DataFieldHeadingXAxis => "Data Field Heading X Axis",
+            Self::DataFieldHeadingYAxis => "Data Field Heading Y Axis",
+            Self::DataFieldHeadingZAxis => "Data Field Heading Z Axis",
+            Self::DataFieldHeadingCompensatedMagneticNorth => {
+                "Data Field Heading Compensated Magnetic North"
+            }
+            Self::DataFieldHeadingCompensatedTrueNorth => {
+                "Data Field Heading Compensated True North"
+            }
+            Self::DataFieldHeadingMagneticNorth => "Data Field Heading Magnetic North",
+            Self::DataFieldHeadingTrueNorth => "Data Field Heading True North",
+            Self::DataFieldDistance => "Data Field Distance",
+            Self::DataFieldDistanceXAxis => "Data Field Distance X Axis",
+            Self::DataFieldDistanceYAxis => "Data Field Distance Y Axis",
+            Self::DataFieldDistanceZAxis => "Data Field Distance Z Axis",
+            Self::DataFieldDistanceOutOfRange => "Data Field Distance Out of Range",
+            Self::DataFieldTilt => "Data Field Tilt",
+            Self::DataFieldTiltXAxis => "Data Field Tilt X Axis",
+            Self::DataFieldTiltYAxis => "Data Field Tilt Y Axis",
+            Self::DataFieldTiltZAxis => "Data Field Tilt Z Axis",
+            Self::DataFieldRotationMatrix => "Data Field Rotation Matrix",
+            Self::DataFieldQuaternion => "Data Field Quaternion",
+            Self::DataFieldMagneticFlux => "Data Field Magnetic Flux",
+            Self::DataFieldMagneticFluxXAxis => "Data Field Magnetic Flux X Axis",
+            Self::DataFieldMagneticFluxYAxis => "Data Field Magnetic Flux Y Axis",
+            Self::DataFieldMagneticFluxZAxis => "Data Field Magnetic Flux Z Axis",
+            Self::DataFieldMagnetometerAccuracy => "Data Field Magnetometer Accuracy",
+            Self::DataFieldSimpleOrientationDirection => "Data Field Simple Orientation Direction",
+            Self::DataFieldMechanical => "Data Field Mechanical",
+            Self::DataFieldBooleanSwitchState => "Data Field Boolean Switch State",
+            Self::DataFieldBooleanSwitchArrayStates => "Data Field Boolean Switch Array States",
+            Self::DataFieldMultivalueSwitchValue => "Data Field Multivalue Switch Value",
+            Self::DataFieldForce => "Data Field Force",
+            Self::DataFieldAbsolutePressure => "Data Field Absolute Pressure",
+            Self::DataFieldGaugePressure => "Data Field Gauge Pressure",
+            Self::DataFieldStrain => "Data Field Strain",
+            Self::DataFieldWeight => "Data Field Weight",
+            Self::PropertyMechanical => "Property Mechanical",
+            Self::PropertyVibrationState => "Property Vibration State",
+            Self::PropertyForwardVibrationSpeed => "Property Forward Vibration Speed",
+            Self::PropertyBackwardVibrationSpeed => "Property Backward Vibration Speed",
+            Self::DataFieldBiometric => "Data Field Biometric",
+            Self::DataFieldHumanPresence => "Data Field Human Presence",
+            Self::DataFieldHumanProximityRange => "Data Field Human Proximity Range",
+            Self::DataFieldHumanProximityOutOfRange => "Data Field Human Proximity Out of Range",
+            Self::DataFieldHumanTouchState => "Data Field Human Touch State",
+            Self::DataFieldBloodPressure => "Data Field Blood Pressure",
+            Self::DataFieldBloodPressureDiastolic => "Data Field Blood Pressure Diastolic",
+            Self::DataFieldBloodPressureSystolic => "Data Field Blood Pressure Systolic",
+            Self::DataFieldHeartRate => "Data Field Heart Rate",
+            Self::DataFieldRestingHeartRate => "Data Field Resting Heart Rate",
+            Self::DataFieldHeartbeatInterval => "Data Field Heartbeat Interval",
+            Self::DataFieldRespiratoryRate => "Data Field Respiratory Rate",
+            Self::DataFieldSpO2 => "Data Field Sp O2",
+            Self::DataFieldLight => "Data Field Light",
+            Self::DataFieldIlluminance => "Data Field Illuminance",
+            Self::DataFieldColorTemperature => "Data Field Color Temperature",
+            Self::DataFieldChromaticity => "Data Field Chromaticity",
+            Self::DataFieldChromaticityX => "Data Field Chromaticity X",
+            Self::DataFieldChromaticityY => "Data Field Chromaticity Y",
+            Self::DataFieldConsumerIRSentenceReceive => "Data Field Consumer IR Sentence Receive",
+            Self::DataFieldInfraredLight => "Data Field Infrared Light",
+            Self::DataFieldRedLight => "Data Field Red Light",
+            Self::DataFieldGreenLight => "Data Field Green Light",
+            Self::DataFieldBlueLight => "Data Field Blue Light",
+            Self::DataFieldUltravioletALight => "Data Field Ultraviolet A Light",
+            Self::DataFieldUltravioletBLight => "Data Field Ultraviolet B Light",
+            Self::DataFieldUltravioletIndex => "Data Field Ultraviolet Index",
+            Self::DataFieldNearInfraredLight => "Data Field Near Infrared Light",
+            Self::PropertyLight => "Property Light",
+            Self::PropertyConsumerIRSentenceSend => "Property Consumer IR Sentence Send",
+            Self::PropertyAutoBrightnessPreferred => "Property Auto Brightness Preferred",
+            Self::PropertyAutoColorPreferred => "Property Auto Color Preferred",
+            Self::DataFieldScanner => "Data Field Scanner",
+            Self::DataFieldRFIDTag40Bit => "Data Field RFID Tag40 Bit",
+            Self::DataFieldNFCSentenceReceive => "Data Field NFC Sentence Receive",
+            Self::PropertyScanner => "Property Scanner",
+            Self::PropertyNFCSentenceSend => "Property NFC Sentence Send",
+            Self::DataFieldElectrical => "Data Field Electrical",
+            Self::DataFieldCapacitance => "Data Field Capacitance",
+            Self::DataFieldCurrent => "Data Field Current",
+            Self::DataFieldElectricalPower => "Data Field Electrical Power",
+            Self::DataFieldInductance => "Data Field Inductance",
+            Self::DataFieldResistance => "Data Field Resistance",
+            Self::DataFieldVoltage => "Data Field Voltage",
+            Self::DataFieldFrequency => "Data Field Frequency",
+            Self::DataFieldPeriod => "Data Field Period",
+            Self::DataFieldPercentOfRange => "Data Field Percent of Range",
+            Self::DataFieldTime => "Data Field Time",
+            Self::DataFieldYear => "Data Field Year",
+            Self::DataFieldMonth => "Data Field Month",
+            Self::DataFieldDay => "Data Field Day",
+            Self::DataFieldDayOfWeek => "Data Field Day of Week",
+            Self::DataFieldHour => "Data Field Hour",
+            Self::DataFieldMinute => "Data Field Minute",
+            Self::DataFieldSecond => "Data Field Second",
+            Self::DataFieldMillisecond => "Data Field Millisecond",
+            Self::DataFieldTimestamp => "Data Field Timestamp",
+            Self::DataFieldJulianDayOfYear => "Data Field Julian Day of Year",
+            Self::DataFieldTimeSinceSystemBoot => "Data Field Time Since System Boot",
+            Self::PropertyTime => "Property Time",
+            Self::PropertyTimeZoneOffsetFromUTC => "Property Time Zone Offset From UTC",
+            Self::PropertyTimeZoneName => "Property Time Zone Name",
+            Self::PropertyDaylightSavingsTimeObserved => "Property Daylight Savings Time Observed",
+            Self::PropertyTimeTrimAdjustment => "Property Time Trim Adjustment",
+            Self::PropertyArmAlarm => "Property Arm Alarm",
+            Self::DataFieldCustom => "Data Field Custom",
+            Self::DataFieldCustomUsage => "Data Field Custom Usage",
+            Self::DataFieldCustomBooleanArray => "Data Field Custom Boolean Array",
+            Self::DataFieldCustomValue => "Data Field Custom Value",
+            Self::DataFieldCustomValue1 => "Data Field Custom Value1",
+            Self::DataFieldCustomValue2 => "Data Field Custom Value2",
+            Self::DataFieldCustomValue3 => "Data Field Custom Value3",
+            Self::DataFieldCustomValue4 => "Data Field Custom Value4",
+            Self::DataFieldCustomValue5 => "Data Field Custom Value5",
+            Self::DataFieldCustomValue6 => "Data Field Custom Value6",
+            Self::DataFieldCustomValue7 => "Data Field Custom Value7",
+            Self::DataFieldCustomValue8 => "Data Field Custom Value8",
+            Self::DataFieldCustomValue9 => "Data Field Custom Value9",
+            Self::DataFieldCustomValue10 => "Data Field Custom Value10",
+            Self::DataFieldCustomValue11 => "Data Field Custom Value11",
+            Self::DataFieldCustomValue12 => "Data Field Custom Value12",
+            Self::DataFieldCustomValue13 => "Data Field Custom Value13",
+            Self::DataFieldCustomValue14 => "Data Field Custom Value14",
+            Self::DataFieldCustomValue15 => "Data Field Custom Value15",
+            Self::DataFieldCustomValue16 => "Data Field Custom Value16",
+            Self::DataFieldCustomValue17 => "Data Field Custom Value17",
+            Self::DataFieldCustomValue18 => "Data Field Custom Value18",
+            Self::DataFieldCustomValue19 => "Data Field Custom Value19",
+            Self::DataFieldCustomValue20 => "Data Field Custom Value20",
+            Self::DataFieldCustomValue21 => "Data Field Custom Value21",
+            Self::DataFieldCustomValue22 => "Data Field Custom Value22",
+            Self::DataFieldCustomValue23 => "Data Field Custom Value23",
+            Self::DataFieldCustomValue24 => "Data Field Custom Value24",
+            Self::DataFieldCustomValue25 => "Data Field Custom Value25",
+            Self::DataFieldCustomValue26 => "Data Field Custom Value26",
+            Self::DataFieldCustomValue27 => "Data Field Custom Value27",
+            Self::DataFieldCustomValue28 => "Data Field Custom Value28",
+            Self::DataFieldPersonalActivity => "Data Field Personal Activity",
+            Self::DataFieldActivityType => "Data Field Activity Type",
+            Self::DataFieldActivityState => "Data Field Activity State",
+            Self::DataFieldDevicePosition => "Data Field Device Position",
+            Self::DataFieldStepCount => "Data Field Step Count",
+            Self::DataFieldStepCountReset => "Data Field Step Count Reset",
+            Self::DataFieldStepDuration => "Data Field Step Duration",
+            Self::DataFieldStepType => "Data Field Step Type",
+            Self::PropertyPersonalActivity => "Property Personal Activity",
+            Self::PropertyMinimumActivityDetectionInterval => {
+                "Property Minimum Activity Detection Interval"
+            }
+            Self::PropertySupportedActivityTypes => "Property Supported Activity Types",
+            Self::PropertySubscribedActivityTypes => "Property Subscribed Activity Types",
+            Self::PropertySupportedStepTypes => "Property Supported Step Types",
+            Self::PropertySubscribedStepTypes => "Property Subscribed Step Types",
+            Self::PropertyFloorHeight => "Property Floor Height",
+            Self::SensorStateUndefined => "Sensor State Undefined",
+            Self::SensorStateReady => "Sensor State Ready",
+            Self::SensorStateNotAvailable => "Sensor State Not Available",
+            Self::SensorStateNoData => "Sensor State No Data",
+            Self::SensorStateInitializing => "Sensor State Initializing",
+            Self::SensorStateAccessDenied => "Sensor State Access Denied",
+            Self::SensorStateError => "Sensor State Error",
+            Self::SensorEventUnknown => "Sensor Event Unknown",
+            Self::SensorEventStateChanged => "Sensor Event State Changed",
+            Self::SensorEventPropertyChanged => "Sensor Event Property Changed",
+            Self::SensorEventDataUpdated => "Sensor Event Data Updated",
+            Self::SensorEventPollResponse => "Sensor Event Poll Response",
+            Self::SensorEventChangeSensitivity => "Sensor Event Change Sensitivity",
+            Self::SensorEventRangeMaximumReached => "Sensor Event Range Maximum Reached",
+            Self::SensorEventRangeMinimumReached => "Sensor Event Range Minimum Reached",
+            Self::SensorEventHighThresholdCrossUpward => "Sensor Event High Threshold Cross Upward",
+            Self::SensorEventHighThresholdCrossDownward => {
+                "Sensor Event High Threshold Cross Downward"
+            }
+            Self::SensorEventLowThresholdCrossUpward => "Sensor Event Low Threshold Cross Upward",
+            Self::SensorEventLowThresholdCrossDownward => {
+                "Sensor Event Low Threshold Cross Downward"
+            }
+            Self::SensorEventZeroThresholdCrossUpward => "Sensor Event Zero Threshold Cross Upward",
+            Self::SensorEventZeroThresholdCrossDownward => {
+                "Sensor Event Zero Threshold Cross Downward"
+            }
+            Self::SensorEventPeriodExceeded => "Sensor Event Period Exceeded",
+            Self::SensorEventFrequencyExceeded => "Sensor Event Frequency Exceeded",
+            Self::SensorEventComplexTrigger => "Sensor Event Complex Trigger",
+            Self::ConnectionTypePCIntegrated => "Connection Type PC Integrated",
+            Self::ConnectionTypePCAttached => "Connection Type PC Attached",
+            Self::ConnectionTypePCExternal => "Connection Type PC External",
+            Self::ReportingStateReportNoEvents => "Reporting State Report No Events",
+            Self::ReportingStateReportAllEvents => "Reporting State Report All Events",
+            Self::ReportingStateReportThresholdEvents => "Reporting State Report Threshold Events",
+            Self::ReportingStateWakeOnNoEvents => "Reporting State Wake On No Events",
+            Self::ReportingStateWakeOnAllEvents => "Reporting State Wake On All Events",
+            Self::ReportingStateWakeOnThresholdEvents => "Reporting State Wake On Threshold Events",
+            Self::PowerStateUndefined => "Power State Undefined",
+            Self::PowerStateD0FullPower => "Power State D0 Full Power",
+            Self::PowerStateD1LowPower => "Power State D1 Low Power",
+            Self::PowerStateD2StandbyPowerWithWakeup => "Power State D2 Standby Power With Wakeup",
+            Self::PowerStateD3SleepWithWakeup => "Power State D3 Sleep With Wakeup",
+            Self::PowerStateD4PowerOff => "Power State D4 Power Off",
+        }
+    }
+}
+
+impl Display for Sensor {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl LightingAndIllumination {
+    /// Name of the usage in the HID Usage Tables
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Undefined => "Undefined",
+            Self::LampArray => "Lamp Array",
+            Self::LampArrayAttributesReport => "Lamp Array Attributes Report",
+            Self::LampCount => "Lamp Count",
+            Self::BoundingBoxWidthInMicrometers => "Bounding Box Width In Micrometers",
+            Self::BoundingBoxHeightInMicrometers => "Bounding Box Height In Micrometers",
+            Self::BoundingBoxDepthInMicrometers => "Bounding Box Depth In Micrometers",
+            Self::LampArrayKind => "Lamp Array Kind",
+            Self::MinUpdateIntervalInMicroseconds => "Min Update Interval In Microseconds",
+            Self::LampAttributesRequestReport => "Lamp Attributes Request Report",
+            Self::LampId => "Lamp Id",
+            Self::LampAttributesResponseReport => "Lamp Attributes Response Report",
+            Self::PositionXInMicrometers => "Position X In Micrometers",
+            Self::PositionYInMicrometers => "Position Y In Micrometers",
+            Self::PositionZInMicrometers => "Position Z In Micrometers",
+            Self::LampPurposes => "Lamp Purposes",
+            Self::UpdateLatencyInMicroseconds => "Update Latency In Microseconds",
+            Self::RedLevelCount => "Red Level Count",
+            Self::GreenLevelCount => "Green Level Count",
+            Self::BlueLevelCount => "Blue Level Count",
+            Self::IntensityLevelCount => "Intensity Level Count",
+            Self::IsProgrammable => "Is Programmable",
+            Self::InputBinding => "Input Binding",
+            Self::LampMultiUpdateReport => "Lamp Multi Update Report",
+            Self::RedUpdateChannel => "Red Update Channel",
+            Self::GreenUpdateChannel => "Green Update Channel",
+            Self::BlueUpdateChannel => "Blue Update Channel",
+            Self::IntensityUpdateChannel => "Intensity Update Channel",
+            Self::LampUpdateFlags => "Lamp Update Flags",
+            Self::LampRangeUpdateReport => "Lamp Range Update Report",
+            Self::LampIdStart => "Lamp Id Start",
+            Self::LampIdEnd => "Lamp Id End",
+            Self::LampArrayControlReport => "Lamp Array Control Report",
+            Self::AutonomousMode => "Autonomous Mode",
+        }
+    }
+}
+
+impl Display for LightingAndIllumination {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Haptics {
+    /// Name of the usage in the HID Usage Tables
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Undefined => "Undefined",
+            Self::SimpleHapticController => "Simple Haptic Controller",
+            Self::WaveformList => "Waveform List",
+            Self::DurationList => "Duration List",
+            Self::AutoTrigger => "Auto Trigger",
+            Self::ManualTrigger => "Manual Trigger",
+            Self::AutoTriggerAssociatedControl => "Auto Trigger Associated Control",
+            Self::Intensity => "Intensity",
+            Self::RepeatCount => "Repeat Count",
+            Self::RetriggerPeriod => "Retrigger Period",
+            Self::WaveformVendorPage => "Waveform Vendor Page",
+            Self::WaveformVendorID => "Waveform Vendor ID",
+            Self::WaveformCutoffTime => "Waveform Cutoff Time",
+            Self::WaveformNone => "Waveform None",
+            Self::WaveformStop => "Waveform Stop",
+            Self::WaveformClick => "Waveform Click",
+            Self::WaveformBuzzContinuous => "Waveform Buzz Continuous",
+            Self::WaveformRumbleContinuous => "Waveform Rumble Continuous",
+            Self::WaveformPress => "Waveform Press",
+            Self::WaveformRelease => "Waveform Release",
+            Self::WaveformHover => "Waveform Hover",
+            Self::WaveformSuccess => "Waveform Success",
+            Self::WaveformError => "Waveform Error",
+            Self::WaveformInkContinuous => "Waveform Ink Continuous",
+            Self::WaveformPencilContinuous => "Waveform Pencil Continuous",
+            Self::WaveformMarkerContinuous => "Waveform Marker Continuous",
+            Self::WaveformChiselMarkerContinuous => "Waveform Chisel Marker Continuous",
+            Self::WaveformBrushContinuous => "Waveform Brush Continuous",
+            Self::WaveformEraserContinuous => "Waveform Eraser Continuous",
+            Self::WaveformSparkleContinuous => "Waveform Sparkle Continuous",
+        }
+    }
+}
+
+impl Display for Haptics {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl PhysicalInterface {
+    /// Name of the usage in the HID Usage Tables
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Undefined => "Undefined",
+            Self::PhysicalInterfaceDevice => "Physical Interface Device",
+            Self::Normal => "Normal",
+            Self::SetEffectReport => "Set Effect Report",
+            Self::EffectBlockIndex => "Effect Block Index",
+            Self::ParameterBlockOffset => "Parameter Block Offset",
+            Self::ROMFlag => "ROM Flag",
+            Self::EffectType => "Effect Type",
+            Self::ETConstantForce => "ET Constant Force",
+            Self::ETRamp => "ET Ramp",
+            Self::ETCustomForceData => "ET Custom Force Data",
+            Self::ETSquare => "ET Square",
+            Self::ETSine => "ET Sine",
+            Self::ETTriangle => "ET Triangle",
+            Self::ETSawtoothUp => "ET Sawtooth Up",
+            Self::ETSawtoothDown => "ET Sawtooth Down",
+            Self::ETSpring => "ET Spring",
+            Self::ETDamper => "ET Damper",
+            Self::ETInertia => "ET Inertia",
+            Self::ETFriction => "ET Friction",
+            Self::Duration => "Duration",
+            Self::SamplePeriod => "Sample Period",
+            Self::Gain => "Gain",
+            Self::TriggerButton => "Trigger Button",
+            Self::TriggerRepeatInterval => "Trigger Repeat Interval",
+            Self::AxesEnable => "Axes Enable",
+            Self::DirectionEnable => "Direction Enable",
+            Self::Direction => "Direction",
+            Self::TypeSpecificBlockOffset => "Type Specific Block Offset",
+            Self::BlockType => "Block Type",
+            Self::SetEnvelopeReport => "Set Envelope Report",
+            Self::AttackLevel => "Attack Level",
+            Self::AttackTime => "Attack Time",
+            Self::FadeLevel => "Fade Level",
+            Self::FadeTime => "Fade Time",
+            Self::SetConditionReport => "Set Condition Report",
+            Self::CPOffset => "CP Offset",
+            Self::PositiveCoefficient => "Positive Coefficient",
+            Self::NegativeCoefficient => "Negative Coefficient",
+            Self::PositiveSaturation => "Positive Saturation",
+            Self::NegativeSaturation => "Negative Saturation",
+            Self::DeadBand => "Dead Band",
+            Self::DownloadForceSample => "Download Force Sample",
+            Self::IsochCustomForceEnable => "Isoch Custom Force Enable",
+            Self::CustomForceDataReport => "Custom Force Data Report",
+            Self::CustomForceData => "Custom Force Data",
+            Self::CustomForceVendorDefinedData => "Custom Force Vendor Defined Data",
+            Self::SetCustomForceReport => "Set Custom Force Report",
+            Self::CustomForceDataOffset => "Custom Force Data Offset",
+            Self::SampleCount => "Sample Count",
+            Self::SetPeriodicReport => "Set Periodic Report",
+            Self::Offset => "Offset",
+            Self::Magnitude => "Magnitude",
+            Self::Phase => "Phase",
+            Self::Period => "Period",
+            Self::SetConstantForceReport => "Set Constant Force Report",
+            Self::SetRampForceReport => "Set Ramp Force Report",
+            Self::RampStart => "Ramp Start",
+            Self::RampEnd => "Ramp End",
+            Self::EffectOperationReport => "Effect Operation Report",
+            Self::EffectOperation => "Effect Operation",
+            Self::OpEffectStart => "Op Effect Start",
+            Self::OpEffectStartSolo => "Op Effect Start Solo",
+            Self::OpEffectStop => "Op Effect Stop",
+            Self::LoopCount => "Loop Count",
+            Self::DeviceGainReport => "Device Gain Report",
+            Self::DeviceGain => "Device Gain",
+            Self::PIDPoolReport => "PID Pool Report",
+            Self::RAMPoolSize => "RAM Pool Size",
+            Self::ROMPoolSize => "ROM Pool Size",
+            Self::ROMEffectBlockCount => "ROM Effect Block Count",
+            Self::SimultaneousEffectsMax => "Simultaneous Effects Max",
+            Self::PoolAlignment => "Pool Alignment",
+            Self::PIDPoolMoveReport => "PID Pool Move Report",
+            Self::MoveSource => "Move Source",
+            Self::MoveDestination => "Move Destination",
+            Self::MoveLength => "Move Length",
+            Self::PIDBlockLoadReport => "PID Block Load Report",
+            Self::BlockLoadStatus => "Block Load Status",
+            Self::BlockLoadSuccess => "Block Load Success",
+            Self::BlockLoadFull => "Block Load Full",
+            Self::BlockLoadError => "Block Load Error",
+            Self::BlockHandle => "Block Handle",
+            Self::PIDBlockFreeReport => "PID Block Free Report",
+            Self::TypeSpecificBlockHandle => "Type Specific Block Handle",
+            Self::PIDStateReport => "PID State Report",
+            Self::EffectPlaying => "Effect Playing",
+            Self::PIDDeviceControlReport => "PID Device Control Report",
+            Self::PIDDeviceControl => "PID Device Control",
+            Self::DCEnableActuators => "DC Enable Actuators",
+            Self::DCDisableActuators => "DC Disable Actuators",
+            Self::DCStopAllEffects => "DC Stop All Effects",
+            Self::DCDeviceReset => "DC Device Reset",
+            Self::DCDevicePause => "DC Device Pause",
+            Self::DCDeviceContinue => "DC Device Continue",
+            Self::DevicePaused => "Device Paused",
+            Self::ActuatorsEnabled => "Actuators Enabled",
+            Self::SafetySwitch => "Safety Switch",
+            Self::ActuatorOverrideSwitch => "Actuator Override Switch",
+            Self::ActuatorPower => "Actuator Power",
+            Self::StartDelay => "Start Delay",
+            Self::ParameterBlockSize => "Parameter Block Size",
+            Self::DeviceManagedPool => "Device Managed Pool",
+            Self::SharedParameterBlocks => "Shared Parameter Blocks",
+            Self::CreateNewEffectReport => "Create New Effect Report",
+            Self::RAMPoolAvailable => "RAM Pool Available",
+        }
+    }
+}
+
+impl Display for PhysicalInterface {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl PowerDevice {
+    /// Name of the usage in the HID Usage Tables
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Undefined => "Undefined",
+            Self::IName => "I Name",
+            Self::PresentStatus => "Present Status",
+            Self::ChangedStatus => "Changed Status",
+            Self::UPS => "UPS",
+            Self::PowerSupply => "Power Supply",
+            Self::BatterySystem => "Battery System",
+            Self::BatterySystemID => "Battery System ID",
+            Self::Battery => "Battery",
+            Self::BatteryID => "Battery ID",
+            Self::Charger => "Charger",
+            Self::ChargerID => "Charger ID",
+            Self::PowerConverter => "Power Converter",
+            Self::PowerConverterID => "Power Converter ID",
+            Self::OutletSystem => "Outlet System",
+            Self::OutletSystemID => "Outlet System ID",
+            Self::Input => "Input",
+            Self::InputID => "Input ID",
+            Self::Output => "Output",
+            Self::OutputID => "Output ID",
+            Self::Flow => "Flow",
+            Self::FlowID => "Flow ID",
+            Self::Outlet => "Outlet",
+            Self::OutletID => "Outlet ID",
+            Self::Gang => "Gang",
+            Self::GangID => "Gang ID",
+            Self::PowerSummary => "Power Summary",
+            Self::PowerSummaryID => "Power Summary ID",
+            Self::Voltage => "Voltage",
+            Self::Current => "Current",
+            Self::Frequency => "Frequency",
+            Self::ApparentPower => "Apparent Power",
+            Self::ActivePower => "Active Power",
+            Self::PercentLoad => "Percent Load",
+            Self::Temperature => "Temperature",
+            Self::Humidity => "Humidity",
+            Self::BadCount => "Bad Count",
+            Self::ConfigVoltage => "Config Voltage",
+            Self::ConfigCurrent => "Config Current",
+            Self::ConfigFrequency => "Config Frequency",
+            Self::ConfigApparentPower => "Config Apparent Power",
+            Self::ConfigActivePower => "Config Active Power",
+            Self::ConfigPercentLoad => "Config Percent Load",
+            Self::ConfigTemperature => "Config Temperature",
+            Self::ConfigHumidity => "Config Humidity",
+            Self::SwitchOnControl => "Switch On Control",
+            Self::SwitchOffControl => "Switch Off Control",
+            Self::ToggleControl => "Toggle Control",
+            Self::LowVoltageTransfer => "Low Voltage Transfer",
+            Self::HighVoltageTransfer => "High Voltage Transfer",
+            Self::DelayBeforeReboot => "Delay Before Reboot",
+            Self::DelayBeforeStartup => "Delay Before Startup",
+            Self::DelayBeforeShutdown => "Delay Before Shutdown",
+            Self::Test => "Test",
+            Self::ModuleReset => "Module Reset",
+            Self::AudibleAlarmControl => "Audible Alarm Control",
+            Self::Present => "Present",
+            Self::Good => "Good",
+            Self::InternalFailure => "Internal Failure",
+            Self::VoltageOutOfRange => "Voltage Out of Range",
+            Self::FrequencyOutOfRange => "Frequency Out of Range",
+            Self::Overload => "Overload",
+            Self::OverCharged => "Over Charged",
+            Self::OverTemperature => "Over Temperature",
+            Self::ShutdownRequested => "Shutdown Requested",
+            Self::ShutdownImminent => "Shutdown Imminent",
+            Self::SwitchOnOff => "Switch On Off",
+            Self::Switchable => "Switchable",
+            Self::Used => "Used",
+            Self::Boost => "Boost",
+            Self::Buck => "Buck",
+            Self::Initialized => "Initialized",
+            Self::Tested => "Tested",
+            Self::AwaitingPower => "Awaiting Power",
+            Self::CommunicationLost => "Communication Lost",
+            Self::IManufacturer => "I Manufacturer",
+            Self::IProduct => "I Product",
+            Self::ISerialNumber => "I Serial Number",
+        }
+    }
+}
+
+impl Display for PowerDevice {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl BatterySystem {
+    /// Name of the usage in the HID Usage Tables
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Undefined => "Undefined",
+            Self::SMBBatteryMode => "SMB Battery Mode",
+            Self::SMBBatteryStatus => "SMB Battery Status",
+            Self::SMBAlarmWarning => "SMB Alarm Warning",
+            Self::SMBChargerMode => "SMB Charger Mode",
+            Self::SMBChargerStatus => "SMB Charger Status",
+            Self::SMBChargerSpecInfo => "SMB Charger Spec Info",
+            Self::SMBSelectorState => "SMB Selector State",
+            Self::SMBSelectorPresets => "SMB Selector Presets",
+            Self::SMBSelectorInfo => "SMB Selector Info",
+            Self::OptionalMfgFunction1 => "Optional Mfg Function1",
+            Self::OptionalMfgFunction2 => "Optional Mfg Function2",
+            Self::OptionalMfgFunction3 => "Optional Mfg Function3",
+            Self::OptionalMfgFunction4 => "Optional Mfg Function4",
+            Self::OptionalMfgFunction5 => "Optional Mfg Function5",
+            Self::ConnectionToSMBus => "Connection to SM Bus",
+            Self::OutputConnection => "Output Connection",
+            Self::ChargerConnection => "Charger Connection",
+            Self::BatteryInsertion => "Battery Insertion",
+            Self::UseNext => "Use Next",
+            Self::OKToUse => "OK to Use",
+            Self::BatterySupported => "Battery Supported",
+            Self::SelectorRevision => "Selector Revision",
+            Self::ChargingIndicator => "Charging Indicator",
+            Self::ManufacturerAccess => "Manufacturer Access",
+            Self::RemainingCapacityLimit => "Remaining Capacity Limit",
+            Self::RemainingTimeLimit => "Remaining Time Limit",
+            Self::AtRate => "At Rate",
+            Self::CapacityMode => "Capacity Mode",
+            Self::BroadcastToCharger => "Broadcast to Charger",
+            Self::PrimaryBattery => "Primary Battery",
+            Self::ChargeController => "Charge Controller",
+            Self::TerminateCharge => "Terminate Charge",
+            Self::TerminateDischarge => "Terminate Discharge",
+            Self::BelowRemainingCapacityLimit => "Below Remaining Capacity Limit",
+            Self::RemainingTimeLimitExpired => "Remaining Time Limit Expired",
+            Self::Charging => "Charging",
+            Self::Discharging => "Discharging",
+            Self::FullyCharged => "Fully Charged",
+            Self::FullyDischarged => "Fully Discharged",
+            Self::ConditioningFlag => "Conditioning Flag",
+            Self::AtRateOK => "At Rate OK",
+            Self::SMBErrorCode => "SMB Error Code",
+            Self::NeedReplacement => "Need Replacement",
+            Self::AtRateTimeToFull => "At Rate Time to Full",
+            Self::AtRateTimeToEmpty => "At Rate Time to Empty",
+            Self::AverageCurrent => "Average Current",
+            Self::MaxError => "Max Error",
+            Self::RelativeStateOfCharge => "Relative State of Charge",
+            Self::AbsoluteStateOfCharge => "Absolute State of Charge",
+            Self::RemainingCapacity => "Remaining Capacity",
+            Self::FullChargeCapacity => "Full Charge Capacity",
+            Self::RunTimeToEmpty => "Run Time to Empty",
+            Self::AverageTimeToEmpty => "Average Time to Empty",
+            Self::AverageTimeToFull => "Average Time to Full",
+            Self::CycleCount => "Cycle Count",
+            Self::BattPackModelLevel => "Batt Pack Model Level",
+            Self::InternalChargeController => "Internal Charge Controller",
+            Self::PrimaryBatterySupport => "Primary Battery Support",
+            Self::DesignCapacity => "Design Capacity",
+            Self::SpecificationInfo => "Specification Info",
+            Self::ManufactureDate => "Manufacture Date",
+            Self::SerialNumber => "Serial Number",
+            Self::IManufacturerName => "I Manufacturer Name",
+            Self::IDeviceName => "I Device Name",
+            Self::IDeviceChemistry => "I Device Chemistry",
+            Self::ManufacturerData => "Manufacturer Data",
+            Self::Rechargeable => "Rechargeable",
+            Self::WarningCapacityLimit => "Warning Capacity Limit",
+            Self::CapacityGranularity1 => "Capacity Granularity1",
+            Self::CapacityGranularity2 => "Capacity Granularity2",
+            Self::IOEMInformation => "IOEM Information",
+            Self::InhibitCharge => "Inhibit Charge",
+            Self::EnablePolling => "Enable Polling",
+            Self::ResetToZero => "Reset to Zero",
+            Self::ACPresent => "AC Present",
+            Self::BatteryPresent => "Battery Present",
+            Self::PowerFail => "Power Fail",
+            Self::AlarmInhibited => "Alarm Inhibited",
+            Self::ThermistorUnderRange => "Thermistor Under Range",
+            Self::ThermistorHot => "Thermistor Hot",
+            Self::ThermistorCold => "Thermistor Cold",
+            Self::ThermistorOverRange => "Thermistor Over Range",
+            Self::VoltageOutOfRange => "Voltage Out of Range",
+            Self::CurrentOutOfRange => "Current Out of Range",
+            Self::CurrentNotRegulated => "Current Not Regulated",
+            Self::VoltageNotRegulated => "Voltage Not Regulated",
+            Self::MasterMode => "Master Mode",
+            Self::ChargerSelectorSupport => "Charger Selector Support",
+            Self::ChargerSpec => "Charger Spec",
+            Self::Level2 => "Level2",
+            Self::Level3 => "Level3",
+        }
+    }
+}
+
+impl Display for BatterySystem {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Braille {
+    /// Name of the usage in the HID Usage Tables
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Undefined => "Undefined",
+            Self::BrailleDisplay => "Braille Display",
+            Self::BrailleRow => "Braille Row",
+            Self::EightDotBrailleCell => "Eight Dot Braille Cell",
+            Self::SixDotBrailleCell => "Six Dot Braille Cell",
+            Self::NumberOfBrailleCells => "Number of Braille Cells",
+            Self::ScreenReaderControl => "Screen Reader Control",
+            Self::ScreenReaderIdentifier => "Screen Reader Identifier",
+            Self::RouterSet1 => "Router Set1",
+            Self::RouterSet2 => "Router Set2",
+            Self::RouterSet3 => "Router Set3",
+            Self::RouterKey => "Router Key",
+            Self::RowRouterKey => "Row Router Key",
+            Self::BrailleButtons => "Braille Buttons",
+            Self::BrailleKeyboardDot1 => "Braille Keyboard Dot1",
+            Self::BrailleKeyboardDot2 => "Braille Keyboard Dot2",
+            Self::BrailleKeyboardDot3 => "Braille Keyboard Dot3",
+            Self::BrailleKeyboardDot4 => "Braille Keyboard Dot4",
+            Self::BrailleKeyboardDot5 => "Braille Keyboard Dot5",
+            Self::BrailleKeyboardDot6 => "Braille Keyboard Dot6",
+            Self::BrailleKeyboardDot7 => "Braille Keyboard Dot7",
+            Self::BrailleKeyboardDot8 => "Braille Keyboard Dot8",
+            Self::BrailleKeyboardSpace => "Braille Keyboard Space",
+            Self::BrailleKeyboardLeftSpace => "Braille Keyboard Left Space",
+            Self::BrailleKeyboardRightSpace => "Braille Keyboard Right Space",
+            Self::BrailleFaceControls => "Braille Face Controls",
+            Self::BrailleLeftControls => "Braille Left Controls",
+            Self::BrailleRightControls => "Braille Right Controls",
+            Self::BrailleTopControls => "Braille Top Controls",
+            Self::BrailleJoystickCenter => "Braille Joystick Center",
+            Self::BrailleJoystickUp => "Braille Joystick Up",
+            Self::BrailleJoystickDown => "Braille Joystick Down",
+            Self::BrailleJoystickLeft => "Braille Joystick Left",
+            Self::BrailleJoystickRight => "Braille Joystick Right",
+            Self::BrailleDPadCenter => "Braille D Pad Center",
+            Self::BrailleDPadUp => "Braille D Pad Up",
+            Self::BrailleDPadDown => "Braille D Pad Down",
+            Self::BrailleDPadLeft => "Braille D Pad Left",
+            Self::BrailleDPadRight => "Braille D Pad Right",
+            Self::BraillePanLeft => "Braille Pan Left",
+            Self::BraillePanRight => "Braille Pan Right",
+            Self::BrailleRockerUp => "Braille Rocker Up",
+            Self::BrailleRockerDown => "Braille Rocker Down",
+            Self::BrailleRockerPress => "Braille Rocker Press",
+        }
+    }
+}
+
+impl Display for Braille {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl BarcodeScanner {
+    /// Name of the usage in the HID Usage Tables
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Undefined => "Undefined",
+            Self::BarcodeBadgeReader => "Barcode Badge Reader",
+            Self::BarcodeScanner => "Barcode Scanner",
+            Self::DumbBarCodeScanner => "Dumb Bar Code Scanner",
+            Self::CordlessScannerBase => "Cordless Scanner Base",
+            Self::BarCodeScannerCradle => "Bar Code Scanner Cradle",
+            Self::AttributeReport => "Attribute Report",
+            Self::SettingsReport => "Settings Report",
+            Self::ScannedDataReport => "Scanned Data Report",
+            Self::RawScannedDataReport => "Raw Scanned Data Report",
+            Self::TriggerReport => "Trigger Report",
+            Self::StatusReport => "Status Report",
+            Self::UPCEANControlReport => "UPCEAN Control Report",
+            Self::EAN2And3LabelControlReport => "EAN2 And3 Label Control Report",
+            Self::Code39ControlReport => "Code39 Control Report",
+            Self::Interleaved2Of5ControlReport => "Interleaved2 Of5 Control Report",
+            Self::Standard2Of5ControlReport => "Standard2 Of5 Control Report",
+            Self::MSIPlesseyControlReport => "MSI Plessey Control Report",
+            Self::CodabarControlReport => "Codabar Control Report",
+            Self::Code128ControlReport => "Code128 Control Report",
+            Self::Misc1DControlReport => "Misc1 D Control Report",
+            Self::TwoDControlReport => "Two D Control Report",
+            Self::AimingPointerMode => "Aiming Pointer Mode",
+            Self::BarCodePresentSensor => "Bar Code Present Sensor",
+            Self::Class1ALaser => "Class1 A Laser",
+            Self::Class2Laser => "Class2 Laser",
+            Self::HeaterPresent => "Heater Present",
+            Self::ContactScanner => "Contact Scanner",
+            Self::ElectronicArticleSurveillanceNotification => {
+                "Electronic Article Surveillance Notification"
+            }
+            Self::ConstantElectronicArticleSurveillance => {
+                "Constant Electronic Article Surveillance"
+            }
+            Self::ErrorIndication => "Error Indication",
+            Self::FixedBeeper => "Fixed Beeper",
+            Self::GoodDecodeIndication => "Good Decode Indication",
+            Self::HandsFreeScanning => "Hands Free Scanning",
+            Self::IntrinsicallySafe => "Intrinsically Safe",
+            Self::KlasseEinsLaser => "Klasse Eins Laser",
+            Self::LongRangeScanner => "Long Range Scanner",
+            Self::MirrorSpeedControl => "Mirror Speed Control",
+            Self::NotOnFileIndication => "Not On File Indication",
+            Self::ProgrammableBeeper => "Programmable Beeper",
+            Self::Triggerless => "Triggerless",
+            Self::Wand => "Wand",
+            Self::WaterResistant => "Water Resistant",
+            Self::MultiRangeScanner => "Multi Range Scanner",
+            Self::ProximitySensor => "Proximity Sensor",
+            Self::FragmentDecoding => "Fragment Decoding",
+            Self::ScannerReadConfidence => "Scanner Read Confidence",
+            Self::DataPrefix => "Data Prefix",
+            Self::PrefixAIMI => "Prefix AIMI",
+            Self::PrefixNone => "Prefix None",
+            Self::PrefixProprietary => "Prefix Proprietary",
+            Self::ActiveTime => "Active Time",
+            Self::AimingLaserPattern => "Aiming Laser Pattern",
+            Self::BarCodePresent => "Bar Code Present",
+            Self::BeeperState => "Beeper State",
+            Self::LaserOnTime => "Laser On Time",
+            Self::LaserState => "Laser State",
+            Self::LockoutTime => "Lockout Time",
+            Self::MotorState => "Motor State",
+            Self::MotorTimeout => "Motor Timeout",
+            Self::PowerOnResetScanner => "Power On Reset Scanner",
+            Self::PreventReadOfBarcodes => "Prevent Read of Barcodes",
+            Self::InitiateBarcodeRead => "Initiate Barcode Read",
+            Self::TriggerState => "Trigger State",
+            Self::TriggerMode => "Trigger Mode",
+            Self::TriggerModeBlinkingLaserOn => "Trigger Mode Blinking Laser On",
+            Self::TriggerModeContinuousLaserOn => "Trigger Mode Continuous Laser On",
+            Self::TriggerModeLaserOnWhilePulled => "Trigger Mode Laser On While Pulled",
+            Self::TriggerModeLaserStaysOnAfterRelease => {
+                "Trigger Mode Laser Stays On After Release"
+            }
+            Self::CommitParametersToNVM => "Commit Parameters to NVM",
+            Self::ParameterScanning => "Parameter Scanning",
+            Self::ParametersChanged => "Parameters Changed",
+            Self::SetParameterDefaultValues => "Set Parameter Default Values",
+            Self::ScannerInCradle => "Scanner In Cradle",
+            Self::ScannerInRange => "Scanner In Range",
+            Self::AimDuration => "Aim Duration",
+            Self::GoodReadLampDuration => "Good Read Lamp Duration",
+            Self::GoodReadLampIntensity => "Good Read Lamp Intensity",
+            Self::GoodReadLED => "Good Read LED",
+            Self::GoodReadToneFrequency => "Good Read Tone Frequency",
+            Self::GoodReadToneLength => "Good Read Tone Length",
+            Self::GoodReadToneVolume => "Good Read Tone Volume",
+            Self::NoReadMessage => "No Read Message",
+            Self::NotOnFileVolume => "Not On File Volume",
+            Self::PowerupBeep => "Powerup Beep",
+            Self::SoundErrorBeep => "Sound Error Beep",
+            Self::SoundGoodReadBeep => "Sound Good Read Beep",
+            Self::SoundNotOnFileBeep => "Sound Not On File Beep",
+            Self::GoodReadWhenToWrite => "Good Read When to Write",
+            Self::GRWTIAfterDecode => "GRWTI After Decode",
+            Self::GRWTIBeepLampAfterTransmit => "GRWTI Beep Lamp After Transmit",
+            Self::GRWTINoBeepLampUseAtAll => "GRWTI No Beep Lamp Use At All",
+            Self::BooklandEAN => "Bookland EAN",
+            Self::ConvertEAN8To13Type => "Convert EAN8 To13 Type",
+            Self::ConvertUPCAToEAN13 => "Convert UPCA to EAN13",
+            Self::ConvertUPCEToA => "Convert UPCE to A",
+            Self::EAN13 => "EAN13",
+            Self::EAN8 => "EAN8",
+            Self::EAN99128Mandatory => "EAN99128 Mandatory",
+            Self::EAN99P5128Optional => "EAN99 P5128 Optional",
+            Self::EnableEANTwoLabel => "Enable EAN Two Label",
+            Self::UPCEAN => "UPCEAN",
+            Self::UPCEANCouponCode => "UPCEAN Coupon Code",
+            Self::UPCEANPeriodicals => "UPCEAN Periodicals",
+            Self::UPCA => "UPCA",
+            Self::UPCAWith128Mandatory => "UPCA With128 Mandatory",
+            Self::UPCAWith128Optional => "UPCA With128 Optional",
+            Self::UPCAWithP5Optional => "UPCA With P5 Optional",
+            Self::UPCE => "UPCE",
+            Self::UPCE1 => "UPCE1",
+            Self::Periodical => "Periodical",
+            Self::PeriodicalAutoDiscriminatePlus2 => "Periodical Auto Discriminate Plus2",
+            Self::PeriodicalOnlyDecodeWithPlus2 => "Periodical Only Decode With Plus2",
+            Self::PeriodicalIgnorePlus2 => "Periodical Ignore Plus2",
+            Self::PeriodicalAutoDiscriminatePlus5 => "Periodical Auto Discriminate Plus5",
+            Self::PeriodicalOnlyDecodeWithPlus5 => "Periodical Only Decode With Plus5",
+            Self::PeriodicalIgnorePlus5 => "Periodical Ignore Plus5",
+            Self::Check => "Check",
+            Self::CheckDisablePrice => "Check Disable Price",
+            Self::CheckEnable4DigitPrice => "Check Enable4 Digit Price",
+            Self::CheckEnable5DigitPrice => "Check Enable5 Digit Price",
+            Self::CheckEnableEuropean4DigitPrice => "Check Enable European4 Digit Price",
+            Self::CheckEnableEuropean5DigitPrice => "Check Enable European5 Digit Price",
+            Self::EANTwoLabel => "EAN Two Label",
+            Self::EANThreeLabel => "EAN Three Label",
+            Self::EAN8FlagDigit1 => "EAN8 Flag Digit1",
+            Self::EAN8FlagDigit2 => "EAN8 Flag Digit2",
+            Self::EAN8FlagDigit3 => "EAN8 Flag Digit3",
+            Self::EAN13FlagDigit1 => "EAN13 Flag Digit1",
+            Self::EAN13FlagDigit2 => "EAN13 Flag Digit2",
+            Self::EAN13FlagDigit3 => "EAN13 Flag Digit3",
+            Self::AddEAN2And3LabelDefinition => "Add EAN2 And3 Label Definition",
+            Self::ClearAllEAN2And3LabelDefinitions => "Clear All EAN2 And3 Label Definitions",
+            Self::Codabar => "Codabar",
+            Self::Code128 => "Code128",
+            Self::Code39 => "Code39",
+            Self::Code93 => "Code93",
+            Self::FullASCIIConversion => "Full ASCII Conversion",
+            Self::Interleaved2Of5 => "Interleaved2 Of5",
+            Self::ItalianPharmacyCode => "Italian Pharmacy Code",
+            Self::MSIPlessey => "MSI Plessey",
+            Self::Standard2Of5IATA => "Standard2 Of5 IATA",
+            Self::Standard2Of5 => "Standard2 Of5",
+            Self::TransmitStartStop => "Transmit Start Stop",
+            Self::TriOptic => "Tri Optic",
+            Self::UCCEAN128 => "UCCEAN128",
+            Self::CheckDigit => "Check Digit",
+            Self::CheckDigitDisable => "Check Digit Disable",
+            Self::CheckDigitEnableInterleaved2Of5OPCC => "Check Digit Enable Interleaved2 Of5 OPCC",
+            Self::CheckDigitEnableInterleaved2Of5USS => "Check Digit Enable Interleaved2 Of5 USS",
+            Self::CheckDigitEnableStandard2Of5OPCC => "Check Digit Enable Standard2 Of5 OPCC",
+            Self::CheckDigitEnableStandard2Of5USS => "Check Digit Enable Standard2 Of5 USS",
+            Self::CheckDigitEnableOneMSIPlessey => "Check Digit Enable One MSI Plessey",
+            Self::CheckDigitEnableTwoMSIPlessey => "Check Digit Enable Two MSI Plessey",
+            Self::CheckDigitCodabarEnable => "Check Digit Codabar Enable",
+            Self::CheckDigitCode39Enable => "Check Digit Code39 Enable",
+            Self::TransmitCheckDigit => "Transmit Check Digit",
+            Self::DisableCheckDigitTransmit => "Disable Check Digit Transmit",
+            Self::EnableCheckDigitTransmit => "Enable Check Digit Transmit",
+            Self::SymbologyIdentifier1 => "Symbology Identifier1",
+            Self::SymbologyIdentifier2 => "Symbology Identifier2",
+            Self::SymbologyIdentifier3 => "Symbology Identifier3",
+            Self::DecodedData => "Decoded Data",
+            Self::DecodeDataContinued => "Decode Data Continued",
+            Self::BarSpaceData => "Bar Space Data",
+            Self::ScannerDataAccuracy => "Scanner Data Accuracy",
+            Self::RawDataPolarity => "Raw Data Polarity",
+            Self::PolarityInvertedBarCode => "Polarity Inverted Bar Code",
+            Self::PolarityNormalBarCode => "Polarity Normal Bar Code",
+            Self::MinimumLengthToDecode => "Minimum Length to Decode",
+            Self::MaximumLengthToDecode => "Maximum Length to Decode",
+            Self::DiscreteLengthToDecode1 => "Discrete Length to Decode1",
+            Self::DiscreteLengthToDecode2 => "Discrete Length to Decode2",
+            Self::DataLengthMethod => "Data Length Method",
+            Self::DLMethodReadAny => "DL Method Read Any",
+            Self::DLMethodCheckInRange => "DL Method Check In Range",
+            Self::DLMethodCheckForDiscrete => "DL Method Check For Discrete",
+            Self::AztecCode => "Aztec Code",
+            Self::BC412 => "BC412",
+            Self::ChannelCode => "Channel Code",
+            Self::Code16 => "Code16",
+            Self::Code32 => "Code32",
+            Self::Code49 => "Code49",
+            Self::CodeOne => "Code One",
+            Self::Colorcode => "Colorcode",
+            Self::DataMatrix => "Data Matrix",
+            Self::MaxiCode => "Maxi Code",
+            Self::MicroPDF => "Micro PDF",
+            Self::PDF417 => "PDF417",
+            Self::PosiCode => "Posi Code",
+            Self::QRCode => "QR Code",
+            Self::SuperCode => "Super Code",
+            Self::UltraCode => "Ultra Code",
+            Self::USD5SlugCode => "USD5 Slug Code",
+            Self::VeriCode => "Veri Code",
+        }
+    }
+}
+
+impl Display for BarcodeScanner {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Scale {
+    /// Name of the usage in the HID Usage Tables
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Undefined => "Undefined",
+            Self::Scales => "Scales",
+            Self::ScaleDevice => "Scale Device",
+            Self::ScaleClass => "Scale Class",
+            Self::ScaleClassIMetric => "Scale Class I Metric",
+            Self::ScaleClassIIMetric => "Scale Class II Metric",
+            Self::ScaleClassIIIMetric => "Scale Class III Metric",
+            Self::ScaleClassIIILMetric => "Scale Class IIIL Metric",
+            Self::ScaleClassIVMetric => "Scale Class IV Metric",
+            Self::ScaleClassIIIEnglish => "Scale Class III English",
+            Self::ScaleClassIIILEnglish => "Scale Class IIIL English",
+            Self::ScaleClassIVEnglish => "Scale Class IV English",
+            Self::ScaleClassGeneric => "Scale Class Generic",
+            Self::ScaleAttributeReport => "Scale Attribute Report",
+            Self::ScaleControlReport => "Scale Control Report",
+            Self::ScaleDataReport => "Scale Data Report",
+            Self::ScaleStatusReport => "Scale Status Report",
+            Self::ScaleWeightLimitReport => "Scale Weight Limit Report",
+            Self::ScaleStatisticsReport => "Scale Statistics Report",
+            Self::DataWeight => "Data Weight",
+            Self::DataScaling => "Data Scaling",
+            Self::WeightUnit => "Weight Unit",
+            Self::WeightUnitMilligram => "Weight Unit Milligram",
+            Self::WeightUnitGram => "Weight Unit Gram",
+            Self::WeightUnitKilogram => "Weight Unit Kilogram",
+            Self::WeightUnitCarats => "Weight Unit Carats",
+            Self::WeightUnitTaels => "Weight Unit Taels",
+            Self::WeightUnitGrains => "Weight Unit Grains",
+            Self::WeightUnitPennyweights => "Weight Unit Pennyweights",
+            Self::WeightUnitMetricTon => "Weight Unit Metric Ton",
+            Self::WeightUnitAvoirTon => "Weight Unit Avoir Ton",
+            Self::WeightUnitTroyOunce => "Weight Unit Troy Ounce",
+            Self::WeightUnitOunce => "Weight Unit Ounce",
+            Self::WeightUnitPound => "Weight Unit Pound",
+            Self::CalibrationCount => "Calibration Count",
+            Self::ReZeroCount => "Re Zero Count",
+            Self::ScaleStatus => "Scale Status",
+            Self::ScaleStatusFault => "Scale Status Fault",
+            Self::ScaleStatusStableAtCenterOfZero => "Scale Status Stable At Center of Zero",
+            Self::ScaleStatusInMotion => "Scale Status In Motion",
+            Self::ScaleStatusWeightStable => "Scale Status Weight Stable",
+            Self::ScaleStatusUnderZero => "Scale Status Under Zero",
+            Self::ScaleStatusOverWeightLimit => "Scale Status Over Weight Limit",
+            Self::ScaleStatusRequiresCalibration => "Scale Status Requires Calibration",
+            Self::ScaleStatusRequiresRezeroing => "Scale Status Requires Rezeroing",
+            Self::ZeroScale => "Zero Scale",
+            Self::EnforcedZeroReturn => "Enforced Zero Return",
+        }
+    }
+}
+
+impl Display for Scale {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl MagneticStripeReader {
+    /// Name of the usage in the HID Usage Tables
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Undefined => "Undefined",
+            Self::MSRDeviceReadOnly => "MSR Device Read Only",
+            Self::Track1Length => "Track1 Length",
+            Self::Track2Length => "Track2 Length",
+            Self::Track3Length => "Track3 Length",
+            Self::TrackJISLength => "Track JIS Length",
+            Self::TrackData => "Track Data",
+            Self::Track1Data => "Track1 Data",
+            Self::Track2Data => "Track2 Data",
+            Self::Track3Data => "Track3 Data",
+            Self::TrackJISData => "Track JIS Data",
+        }
+    }
+}
+
+impl Display for MagneticStripeReader {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Camera {
+    /// Name of the usage in the HID Usage Tables
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Undefined => "Undefined",
+            Self::CameraAutoFocus => "Camera Auto Focus",
+            Self::CameraShutter => "Camera Shutter",
+        }
+    }
+}
+
+impl Display for Camera {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Arcade {
+    /// Name of the usage in the HID Usage Tables
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Undefined => "Undefined",
+            Self::GeneralPurposeIOCard => "General Purpose IO Card",
+            Self::CoinDoor => "Coin Door",
+            Self::WatchdogTimer => "Watchdog Timer",
+            Self::GeneralPurposeAnalogInputState => "General Purpose Analog Input State",
+            Self::GeneralPurposeDigitalInputState => "General Purpose Digital Input State",
+            Self::GeneralPurposeOpticalInputState => "General Purpose Optical Input State",
+            Self::GeneralPurposeDigitalOutputState => "General Purpose Digital Output State",
+            Self::NumberOfCoinDoors => "Number of Coin Doors",
+            Self::CoinDrawerDropCount => "Coin Drawer Drop Count",
+            Self::CoinDrawerStart => "Coin Drawer Start",
+            Self::CoinDrawerService => "Coin Drawer Service",
+            Self::CoinDrawerTilt => "Coin Drawer Tilt",
+            Self::CoinDoorTest => "Coin Door Test",
+            Self::CoinDoorLockout => "Coin Door Lockout",
+            Self::WatchdogTimeout => "Watchdog Timeout",
+            Self::WatchdogAction => "Watchdog Action",
+            Self::WatchdogReboot => "Watchdog Reboot",
+            Self::WatchdogRestart => "Watchdog Restart",
+            Self::AlarmInput => "Alarm Input",
+            Self::CoinDoorCounter => "Coin Door Counter",
+            Self::IODirectionMapping => "IO Direction Mapping",
+            Self::SetIODirectionMapping => "Set IO Direction Mapping",
+            Self::ExtendedOpticalInputState => "Extended Optical Input State",
+            Self::PinPadInputState => "Pin Pad Input State",
+            Self::PinPadStatus => "Pin Pad Status",
+            Self::PinPadOutput => "Pin Pad Output",
+            Self::PinPadCommand => "Pin Pad Command",
+        }
+    }
+}
+
+impl Display for Arcade {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Fido {
+    /// Name of the usage in the HID Usage Tables
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Undefined => "Undefined",
+            Self::U2FAuthenticatorDevice => "U2 F Authenticator Device",
+            Self::InputReportData => "Input Report Data",
+            Self::OutputReportData => "Output Report Data",
+        }
+    }
+}
+
+impl Display for Fido {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl EyeAndHeadTracker {
+    /// Name of the usage in the HID Usage Tables
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Undefined => "Undefined",
+            Self::EyeTracker => "Eye Tracker",
+            Self::HeadTracker => "Head Tracker",
+            Self::TrackingData => "Tracking Data",
+            Self::Capabilities => "Capabilities",
+            Self::Configuration => "Configuration",
+            Self::Status => "Status",
+            Self::Control => "Control",
+            Self::SensorTimestamp => "Sensor Timestamp",
+            Self::PositionX => "Position X",
+            Self::PositionY => "Position Y",
+            Self::PositionZ => "Position Z",
+            Self::GazePoint => "Gaze Point",
+            Self::LeftEyePosition => "Left Eye Position",
+            Self::RightEyePosition => "Right Eye Position",
+            Self::HeadPosition => "Head Position",
+            Self::HeadDirectionPoint => "Head Direction Point",
+            Self::RotationAboutXAxis => "Rotation About X Axis",
+            Self::RotationAboutYAxis => "Rotation About Y Axis",
+            Self::RotationAboutZAxis => "Rotation About Z Axis",
+            Self::TrackerQuality => "Tracker Quality",
+            Self::MinimumTrackingDistance => "Minimum Tracking Distance",
+            Self::OptimumTrackingDistance => "Optimum Tracking Distance",
+            Self::MaximumTrackingDistance => "Maximum Tracking Distance",
+            Self::MaximumScreenPlaneWidth => "Maximum Screen Plane Width",
+            Self::MaximumScreenPlaneHeight => "Maximum Screen Plane Height",
+            Self::DisplayManufacturerId => "Display Manufacturer Id",
+            Self::DisplayProductId => "Display Product Id",
+            Self::DisplaySerialNumber => "Display Serial Number",
+            Self::DisplayManufacturerDate => "Display Manufacturer Date",
+            Self::CalibratedScreenWidth => "Calibrated Screen Width",
+            Self::CalibratedScreenHeight => "Calibrated Screen Height",
+            Self::SamplingFrequency => "Sampling Frequency",
+            Self::ConfigurationStatus => "Configuration Status",
+            Self::DeviceModeRequest => "Device Mode Request",
+        }
+    }
+}
+
+impl Display for EyeAndHeadTracker {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl AuxiliaryDisplay {
+    /// Name of the usage in the HID Usage Tables
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Undefined => "Undefined",
+            Self::AlphanumericDisplay => "Alphanumeric Display",
+            Self::AuxiliaryDisplay => "Auxiliary Display",
+            Self::DisplayAttributesReport => "Display Attributes Report",
+            Self::AsciiCharacterSet => "Ascii Character Set",
+            Self::DataReadBack => "Data Read Back",
+            Self::FontReadBack => "Font Read Back",
+            Self::DisplayControlReport => "Display Control Report",
+            Self::ClearDisplay => "Clear Display",
+            Self::DisplayEnable => "Display Enable",
+            Self::ScreenSaverDelay => "Screen Saver Delay",
+            Self::ScreenSaverEnable => "Screen Saver Enable",
+            Self::VerticalScroll => "Vertical Scroll",
+            Self::HorizontalScroll => "Horizontal Scroll",
+            Self::CharacterReport => "Character Report",
+            Self::DisplayData => "Display Data",
+            Self::DisplayStatus => "Display Status",
+            Self::StatNotReady => "Stat Not Ready",
+            Self::StatReady => "Stat Ready",
+            Self::ErrNotALoadableCharacter => "Err Not A Loadable Character",
+            Self::ErrFontDataCannotBeRead => "Err Font Data Cannot Be Read",
+            Self::CursorPositionReport => "Cursor Position Report",
+            Self::Row => "Row",
+            Self::Column => "Column",
+            Self::Rows => "Rows",
+            Self::Columns => "Columns",
+            Self::CursorPixelPositioning => "Cursor Pixel Positioning",
+            Self::CursorMode => "Cursor Mode",
+            Self::CursorEnable => "Cursor Enable",
+            Self::CursorBlink => "Cursor Blink",
+            Self::FontReport => "Font Report",
+            Self::FontData => "Font Data",
+            Self::CharacterWidth => "Character Width",
+            Self::CharacterHeight => "Character Height",
+            Self::CharacterSpacingHorizontal => "Character Spacing Horizontal",
+            Self::CharacterSpacingVertical => "Character Spacing Vertical",
+            Self::UnicodeCharacterSet => "Unicode Character Set",
+            Self::Font7Segment => "Font7 Segment",
+            Self::SevenSegmentDirectMap => "Seven Segment Direct Map",
+            Self::Font14Segment => "Font14 Segment",
+            Self::FourteenSegmentDirectMap => "Fourteen Segment Direct Map",
+            Self::DisplayBrightness => "Display Brightness",
+            Self::DisplayContrast => "Display Contrast",
+            Self::CharacterAttribute => "Character Attribute",
+            Self::AttributeReadback => "Attribute Readback",
+            Self::AttributeData => "Attribute Data",
+            Self::CharAttrEnhance => "Char Attr Enhance",
+            Self::CharAttrUnderline => "Char Attr Underline",
+            Self::CharAttrBlink => "Char Attr Blink",
+            Self::BitmapSizeX => "Bitmap Size X",
+            Self::BitmapSizeY => "Bitmap Size Y",
+            Self::MaxBlitSize => "Max Blit Size",
+            Self::BitDepthFormat => "Bit Depth Format",
+            Self::DisplayOrientation => "Display Orientation",
+            Self::PaletteReport => "Palette Report",
+            Self::PaletteDataSize => "Palette Data Size",
+            Self::PaletteDataOffset => "Palette Data Offset",
+            Self::PaletteData => "Palette Data",
+            Self::BlitReport => "Blit Report",
+            Self::BlitRectangleX1 => "Blit Rectangle X1",
+            Self::BlitRectangleY1 => "Blit Rectangle Y1",
+            Self::BlitRectangleX2 => "Blit Rectangle X2",
+            Self::BlitRectangleY2 => "Blit Rectangle Y2",
+            Self::BlitData => "Blit Data",
+            Self::SoftButton => "Soft Button",
+            Self::SoftButtonId => "Soft Button Id",
+            Self::SoftButtonSide => "Soft Button Side",
+            Self::SoftButtonOffset1 => "Soft Button Offset1",
+            Self::SoftButtonOffset2 => "Soft Button Offset2",
+            Self::SoftButtonReport => "Soft Button Report",
+            Self::SoftKeys => "Soft Keys",
+            Self::DisplayDataExtensions => "Display Data Extensions",
+            Self::CharacterMapping => "Character Mapping",
+            Self::UnicodeEquivalent => "Unicode Equivalent",
+            Self::CharacterPageMapping => "Character Page Mapping",
+            Self::RequestReport => "Request Report",
+        }
+    }
+}
+
+impl Display for AuxiliaryDisplay {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Vr {
+    /// Name of the usage in the HID Usage Tables
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Undefined => "Undefined",
+            Self::Belt => "Belt",
+            Self::BodySuit => "Body Suit",
+            Self::Flexor => "Flexor",
+            Self::Glove => "Glove",
+            Self::HeadTracker => "Head Tracker",
+            Self::HeadMountedDisplay => "Head Mounted Display",
+            Self::HandTracker => "Hand Tracker",
+            Self::Oculometer => "Oculometer",
+            Self::Vest => "Vest",
+            Self::AnimatronicDevice => "Animatronic Device",
+            Self::StereoEnable => "Stereo Enable",
+            Self::DisplayEnable => "Display Enable",
+        }
+    }
+}
+
+impl Display for Vr {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Sport {
+    /// Name of the usage in the HID Usage Tables
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Undefined => "Undefined",
+            Self::BaseballBat => "Baseball Bat",
+            Self::GolfClub => "Golf Club",
+            Self::RowingMachine => "Rowing Machine",
+            Self::Treadmill => "Treadmill",
+            Self::Oar => "Oar",
+            Self::Slope => "Slope",
+            Self::Rate => "Rate",
+            Self::StickSpeed => "Stick Speed",
+            Self::StickFaceAngle => "Stick Face Angle",
+            Self::StickHeelToe => "Stick Heel Toe",
+            Self::StickFollowThrough => "Stick Follow Through",
+            Self::StickTempo => "Stick Tempo",
+            Self::StickType => "Stick Type",
+            Self::StickHeight => "Stick Height",
+            Self::Putter => "Putter",
+            Self::Iron1 => "Iron1",
+            Self::Iron2 => "Iron2",
+            Self::Iron3 => "Iron3",
+            Self::Iron4 => "Iron4",
+            Self::Iron5 => "Iron5",
+            Self::Iron6 => "Iron6",
+            Self::Iron7 => "Iron7",
+            Self::Iron8 => "Iron8",
+            Self::Iron9 => "Iron9",
+            Self::Iron10 => "Iron10",
+            Self::Iron11 => "Iron11",
+            Self::SandWedge => "Sand Wedge",
+            Self::LoftWedge => "Loft Wedge",
+            Self::PowerWedge => "Power Wedge",
+            Self::Wood1 => "Wood1",
+            Self::Wood3 => "Wood3",
+            Self::Wood5 => "Wood5",
+            Self::Wood7 => "Wood7",
+            Self::Wood9 => "Wood9",
+        }
+    }
+}
+
+impl Display for Sport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl GenericDevice {
+    /// Name of the usage in the HID Usage Tables
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Undefined => "Undefined",
+            Self::BackgroundNonuserControls => "Background Nonuser Controls",
+            Self::BatteryStrength => "Battery Strength",
+            Self::WirelessChannel => "Wireless Channel",
+            Self::WirelessId => "Wireless Id",
+            Self::DiscoverWirelessControl => "Discover Wireless Control",
+            Self::SecurityCodeCharacterEntered => "Security Code Character Entered",
+            Self::SecurityCodeCharacterErased => "Security Code Character Erased",
+            Self::SecurityCodeCleared => "Security Code Cleared",
+            Self::SequenceId => "Sequence Id",
+            Self::SequenceIdReset => "Sequence Id Reset",
+            Self::RfSignalStrength => "Rf Signal Strength",
+            Self::SoftwareVersion => "Software Version",
+            Self::ProtocolVersion => "Protocol Version",
+            Self::HardwareVersion => "Hardware Version",
+            Self::Major => "Major",
+            Self::Minor => "Minor",
+            Self::Revision => "Revision",
+            Self::Handedness => "Handedness",
+            Self::EitherHand => "Either Hand",
+            Self::LeftHand => "Left Hand",
+            Self::RightHand => "Right Hand",
+            Self::BothHands => "Both Hands",
+            Self::GripPoseOffset => "Grip Pose Offset",
+            Self::PointerPoseOffset => "Pointer Pose Offset",
+        }
+    }
+}
+
+impl Display for GenericDevice {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Digitizer {
+    /// Name of the usage in the HID Usage Tables
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Undefined => "Undefined",
+            Self::Digitizer => "Digitizer",
+            Self::Pen => "Pen",
+            Self::LightPen => "Light Pen",
+            Self::TouchScreen => "Touch Screen",
+            Self::TouchPad => "Touch Pad",
+            Self::Whiteboard => "Whiteboard",
+            Self::CoordinateMeasuringMachine => "Coordinate Measuring Machine",
+            Self::ThreeDDigitizer => "3D Digitizer",
+            Self::StereoPlotter => "Stereo Plotter",
+            Self::ArticulatedArm => "Articulated Arm",
+            Self::Armature => "Armature",
+            Self::MultiplePointDigitizer => "Multiple Point Digitizer",
+            Self::FreeSpaceWand => "Free Space Wand",
+            Self::DeviceConfiguration => "Device Configuration",
+            Self::CapacitiveHeatMapDigitizer => "Capacitive Heat Map Digitizer",
+            Self::Stylus => "Stylus",
+            Self::Puck => "Puck",
+            Self::Finger => "Finger",
+            Self::DeviceSettings => "Device Settings",
+            Self::CharacterGesture => "Character Gesture",
+            Self::TipPressure => "Tip Pressure",
+            Self::BarrelPressure => "Barrel Pressure",
+            Self::InRange => "In Range",
+            Self::Touch => "Touch",
+            Self::Untouch => "Untouch",
+            Self::Tap => "Tap",
+            Self::Quality => "Quality",
+            Self::DataValid => "Data Valid",
+            Self::TransducerIndex => "Transducer Index",
+            Self::TabletFunctionKeys => "Tablet Function Keys",
+            Self::ProgramChangeKeys => "Program Change Keys",
+            Self::BatteryStrength => "Battery Strength",
+            Self::Invert => "Invert",
+            Self::XTilt => "X Tilt",
+            Self::YTilt => "Y Tilt",
+            Self::Azimuth => "Azimuth",
+            Self::Altitude => "Altitude",
+            Self::Twist => "Twist",
+            Self::TipSwitch => "Tip Switch",
+            Self::SecondaryTipSwitch => "Secondary Tip Switch",
+            Self::BarrelSwitch => "Barrel Switch",
+            Self::Eraser => "Eraser",
+            Self::TabletPick => "Tablet Pick",
+            Self::TouchValid => "Touch Valid",
+            Self::Width => "Width",
+            Self::Height => "Height",
+            Self::ContactIdentifier => "Contact Identifier",
+            Self::DeviceMode => "Device Mode",
+            Self::DeviceIdentifier => "Device Identifier",
+            Self::ContactCount => "Contact Count",
+            Self::ContactCountMaximum => "Contact Count Maximum",
+            Self::ScanTime => "Scan Time",
+            Self::SurfaceSwitch => "Surface Switch",
+            Self::ButtonSwitch => "Button Switch",
+            Self::PadType => "Pad Type",
+            Self::SecondaryBarrelSwitch => "Secondary Barrel Switch",
+            Self::TransducerSerialNumber => "Transducer Serial Number",
+            Self::PreferredColor => "Preferred Color",
+            Self::PreferredColorIsLocked => "Preferred Color Is Locked",
+            Self::PreferredLineWidth => "Preferred Line Width",
+            Self::PreferredLineWidthIsLocked => "Preferred Line Width Is Locked",
+            Self::LatencyMode => "Latency Mode",
+            Self::GestureCharacterQuality => "Gesture Character Quality",
+            Self::CharacterGestureDataLength => "Character Gesture Data Length",
+            Self::CharacterGestureData => "Character Gesture Data",
+            Self::GestureCharacterEncoding => "Gesture Character Encoding",
+            Self::Utf8CharacterGestureEncoding => "Utf8 Character Gesture Encoding",
+            Self::Utf16LittleEndianCharacterGestureEncoding => {
+                "Utf16 Little Endian Character Gesture Encoding"
+            }
+            Self::Utf16BigEndianCharacterGestureEncoding => {
+                "Utf16 Big Endian Character Gesture Encoding"
+            }
+            Self::Utf32LittleEndianCharacterGestureEncoding => {
+                "Utf32 Little Endian Character Gesture Encoding"
+            }
+            Self::Utf32BigEndianCharacterGestureEncoding => {
+                "Utf32 Big Endian Character Gesture Encoding"
+            }
+            Self::CapacitiveHeatMapProtocolVendorId => "Capacitive Heat Map Protocol Vendor Id",
+            Self::CapacitiveHeatMapProtocolVersion => "Capacitive Heat Map Protocol Version",
+            Self::CapacitiveHeatMapFrameData => "Capacitive Heat Map Frame Data",
+            Self::GestureCharacterEnable => "Gesture Character Enable",
+            Self::TransducerSerialNumberPart2 => "Transducer Serial Number Part2",
+            Self::NoPreferredColor => "No Preferred Color",
+            Self::PreferredLineStyle => "Preferred Line Style",
+            Self::PreferredLineStyleIsLocked => "Preferred Line Style Is Locked",
+            Self::Ink => "Ink",
+            Self::Pencil => "Pencil",
+            Self::Highlighter => "Highlighter",
+            Self::ChiselMarker => "Chisel Marker",
+            Self::Brush => "Brush",
+            Self::NoPreference => "No Preference",
+            Self::DigitizerDiagnostic => "Digitizer Diagnostic",
+            Self::DigitizerError => "Digitizer Error",
+            Self::ErrNormalStatus => "Err Normal Status",
+            Self::ErrTransducersExceeded => "Err Transducers Exceeded",
+            Self::ErrFullTransFeaturesUnavailable => "Err Full Trans Features Unavailable",
+            Self::ErrChargeLow => "Err Charge Low",
+            Self::TransducerSoftwareInfo => "Transducer Software Info",
+            Self::TransducerVendorId => "Transducer Vendor Id",
+            Self::TransducerProductId => "Transducer Product Id",
+            Self::DeviceSupportedProtocols => "Device Supported Protocols",
+            Self::TransducerSupportedProtocols => "Transducer Supported Protocols",
+            Self::NoProtocol => "No Protocol",
+            Self::WacomAesProtocol => "Wacom Aes Protocol",
+            Self::HidProtocol => "Hid Protocol",
+            Self::MicrosoftPenProtocol => "Microsoft Pen Protocol",
+            Self::SupportedReportRates => "Supported Report Rates",
+            Self::ReportRate => "Report Rate",
+            Self::TransducerConnected => "Transducer Connected",
+            Self::SwitchDisabled => "Switch Disabled",
+            Self::SwitchUnimplemented => "Switch Unimplemented",
+            Self::TransducerSwitches => "Transducer Switches",
+            Self::TransducerIndexSelector => "Transducer Index Selector",
+            Self::ButtonPressThreshold => "Button Press Threshold",
+        }
+    }
+}
+
+impl Display for Digitizer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
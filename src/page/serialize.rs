@@ -0,0 +1,113 @@
+//! Numeric `serde` representation of the usage page types
+//!
+//! Usages serialize as their usage ID, so key maps and macro sequences described on the host,
+//! e.g. in TOML or JSON, can be loaded into the same types by firmware build scripts.
+//! Deserializing an ID that the type doesn't define is an error.
+
+#[allow(clippy::wildcard_imports)]
+use super::*;
+use serde::de::{Deserialize, Deserializer, Error, Unexpected};
+use serde::ser::{Serialize, Serializer};
+
+macro_rules! numeric_serde {
+    ($primitive:ty, $serialize:ident, $($usage:ty,)*) => {
+        $(
+            impl Serialize for $usage {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    serializer.$serialize(<$primitive>::from(*self))
+                }
+            }
+
+            impl<'de> Deserialize<'de> for $usage {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    let id = <$primitive>::deserialize(deserializer)?;
+                    Self::try_from(id)
+                        .ok()
+                        .filter(|usage| <$primitive>::from(*usage) == id)
+                        .ok_or_else(|| {
+                            D::Error::invalid_value(
+                                Unexpected::Unsigned(id.into()),
+                                &stringify!($usage),
+                            )
+                        })
+                }
+            }
+        )*
+    };
+}
+
+numeric_serde!(
+    u8,
+    serialize_u8,
+    Leds,
+    Desktop,
+    Game,
+    Keyboard,
+    Simulation,
+    Telephony,
+    LightingAndIllumination,
+    PhysicalInterface,
+    PowerDevice,
+    BatterySystem,
+    Scale,
+    Camera,
+    Arcade,
+    Fido,
+    AuxiliaryDisplay,
+    Vr,
+    Sport,
+    GenericDevice,
+    Digitizer,
+);
+
+numeric_serde!(
+    u16,
+    serialize_u16,
+    Button,
+    Consumer,
+    Sensor,
+    Haptics,
+    Braille,
+    BarcodeScanner,
+    MagneticStripeReader,
+    Unicode,
+    EyeAndHeadTracker,
+    UsagePage,
+);
+
+impl<const PAGE: u16> Serialize for VendorPage<PAGE> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u16(self.usage())
+    }
+}
+
+impl<'de, const PAGE: u16> Deserialize<'de> for VendorPage<PAGE> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        u16::deserialize(deserializer).map(Self::new)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+    use serde::de::value::{Error as ValueError, U16Deserializer, U8Deserializer};
+    use serde::de::IntoDeserializer;
+
+    #[test]
+    fn usages_deserialize_from_ids() {
+        let a: U8Deserializer<ValueError> = 0x04u8.into_deserializer();
+        assert_eq!(Keyboard::deserialize(a).unwrap(), Keyboard::A);
+
+        let play: U16Deserializer<ValueError> = 0xCDu16.into_deserializer();
+        assert_eq!(Consumer::deserialize(play).unwrap(), Consumer::PlayPause);
+
+        // Unassigned IDs are rejected rather than mapped to a default variant
+        let unknown: U16Deserializer<ValueError> = 0x0FFFu16.into_deserializer();
+        assert!(Consumer::deserialize(unknown).is_err());
+
+        let button: U16Deserializer<ValueError> = 5u16.into_deserializer();
+        assert_eq!(Button::deserialize(button).unwrap(), Button::new(5));
+    }
+}
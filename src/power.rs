@@ -0,0 +1,153 @@
+//! Device power budgeting
+//!
+//! The configuration descriptor carries a single `bMaxPower` value and self-powered flag for the
+//! whole device. [`PowerHint`]s give a typical current draw for each device preset in this crate,
+//! and a [`PowerBudget`] sums the hints of the interfaces making up a composite device together
+//! with the draw of the rest of the board:
+//!
+//! ```rust
+//! use xous_usb_hid::power::{self, PowerBudget};
+//!
+//! let budget = PowerBudget::new(50)
+//!     .add(power::KEYBOARD)
+//!     .add(power::MOUSE)
+//!     .add(power::CONSUMER_CONTROL);
+//!
+//! assert_eq!(budget.max_power_ma(), 250);
+//! assert!(!budget.is_over_budget());
+//! ```
+//!
+//! The hints are guidance for the HID function alone (MCU USB peripheral, switches and
+//! indicators), firmware should add its own measured draw as the base of the budget.
+
+use usb_device::bus::UsbBus;
+use usb_device::device::UsbDeviceBuilder;
+
+/// Most current a bus powered device may draw once configured, in mA
+pub const MAX_BUS_POWER_MA: u16 = 500;
+
+/// Typical current draw of an interface
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Default, Hash)]
+pub struct PowerHint {
+    /// Current drawn from VBUS, in mA
+    pub max_power_ma: u16,
+    /// The interface is expected to have its own supply
+    pub self_powered: bool,
+}
+
+impl PowerHint {
+    #[must_use]
+    pub const fn bus_powered(max_power_ma: u16) -> Self {
+        Self {
+            max_power_ma,
+            self_powered: false,
+        }
+    }
+
+    #[must_use]
+    pub const fn self_powered(max_power_ma: u16) -> Self {
+        Self {
+            max_power_ma,
+            self_powered: true,
+        }
+    }
+}
+
+/// Keyboard with lock key LEDs
+pub const KEYBOARD: PowerHint = PowerHint::bus_powered(100);
+/// Optical mouse sensor and switches
+pub const MOUSE: PowerHint = PowerHint::bus_powered(50);
+/// Consumer and system control keys
+pub const CONSUMER_CONTROL: PowerHint = PowerHint::bus_powered(50);
+/// Joystick or gamepad without force feedback
+pub const JOYSTICK: PowerHint = PowerHint::bus_powered(100);
+/// FIDO authenticator with user presence button and indicator
+pub const FIDO: PowerHint = PowerHint::bus_powered(100);
+/// Raw HID interface, no peripherals of its own
+pub const RAW: PowerHint = PowerHint::bus_powered(0);
+
+/// Sum of the power hints of a composite device
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Default, Hash)]
+pub struct PowerBudget {
+    total_ma: u16,
+    self_powered: bool,
+}
+
+impl PowerBudget {
+    /// New budget with the draw of the rest of the device, in mA
+    #[must_use]
+    pub const fn new(base_ma: u16) -> Self {
+        Self {
+            total_ma: base_ma,
+            self_powered: false,
+        }
+    }
+
+    /// Add an interface to the budget, the device is self powered if any interface is
+    #[must_use]
+    pub const fn add(self, hint: PowerHint) -> Self {
+        Self {
+            total_ma: self.total_ma.saturating_add(hint.max_power_ma),
+            self_powered: self.self_powered || hint.self_powered,
+        }
+    }
+
+    /// Summed current draw, in mA
+    #[must_use]
+    pub const fn total_ma(&self) -> u16 {
+        self.total_ma
+    }
+
+    /// Value for `bMaxPower`, in mA, limited to [`MAX_BUS_POWER_MA`]
+    #[must_use]
+    pub const fn max_power_ma(&self) -> u16 {
+        if self.total_ma > MAX_BUS_POWER_MA {
+            MAX_BUS_POWER_MA
+        } else {
+            self.total_ma
+        }
+    }
+
+    /// `true` if the summed draw exceeds what a bus powered device may draw
+    #[must_use]
+    pub const fn is_over_budget(&self) -> bool {
+        self.total_ma > MAX_BUS_POWER_MA
+    }
+
+    #[must_use]
+    pub const fn self_powered(&self) -> bool {
+        self.self_powered
+    }
+
+    /// Set `bMaxPower` and the self-powered flag on a device builder
+    #[must_use]
+    pub fn apply<'a, B: UsbBus>(
+        &self,
+        builder: UsbDeviceBuilder<'a, B>,
+    ) -> UsbDeviceBuilder<'a, B> {
+        builder
+            .max_power(usize::from(self.max_power_ma()))
+            .self_powered(self.self_powered)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn budget_is_summed_and_limited() {
+        let budget = PowerBudget::new(100).add(KEYBOARD).add(RAW);
+        assert_eq!(budget.total_ma(), 200);
+        assert_eq!(budget.max_power_ma(), 200);
+        assert!(!budget.self_powered());
+
+        let budget = budget.add(PowerHint::self_powered(400));
+        assert_eq!(budget.total_ma(), 600);
+        assert_eq!(budget.max_power_ma(), MAX_BUS_POWER_MA);
+        assert!(budget.is_over_budget());
+        assert!(budget.self_powered());
+    }
+}
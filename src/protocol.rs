@@ -0,0 +1,252 @@
+//! Wire constants of the vendor protocols implemented by the crate
+//!
+//! Every report length, command byte, status code and field offset used by the optional vendor
+//! protocols is defined here and only here, and the device modules implementing the protocols
+//! use these definitions. The crate is `no_std` and builds for host targets, so host tools can
+//! depend on it for this module and always agree with the firmware on the wire format.
+//!
+//! ```rust
+//! # use xous_usb_hid::protocol::remap;
+//! // Host tool side, send as feature report `remap::REPORT_ID`
+//! let mut request = [0u8; remap::REPORT_LEN];
+//! request[..3].copy_from_slice(&[remap::SET, 0x39, 0xE0]);
+//! ```
+
+/// Raw vendor defined reports, see [`RawHid`](crate::device::raw::RawHid)
+pub mod raw {
+    /// Length of the raw input and output reports
+    pub const REPORT_LEN: usize = 64;
+    /// Usage page of the application collection
+    pub const USAGE_PAGE: u16 = 0xFF00;
+    /// Usage of the application collection
+    pub const USAGE: u16 = 0x01;
+}
+
+/// Request/response calls over raw reports, see [`rpc`](crate::device::raw::rpc)
+pub mod rpc {
+    pub use crate::device::raw::rpc::Status;
+
+    /// Request ID, chosen by the host
+    pub const OFFSET_ID: usize = 0;
+    /// Method for requests, [`Status`] for responses
+    pub const OFFSET_CODE: usize = 1;
+    /// Payload length
+    pub const OFFSET_LEN: usize = 2;
+    /// Payload, zero padded to the end of the packet
+    pub const OFFSET_PAYLOAD: usize = 3;
+    /// Maximum number of payload bytes carried by a single packet
+    pub const MAX_PAYLOAD_LEN: usize = super::raw::REPORT_LEN - OFFSET_PAYLOAD;
+}
+
+/// Chunked blob transfer over raw reports, see [`transfer`](crate::device::raw::transfer)
+pub mod transfer {
+    pub use crate::device::raw::transfer::{Command, Status};
+
+    /// [`Command`]
+    pub const OFFSET_COMMAND: usize = 0;
+    /// Sequence number
+    pub const OFFSET_SEQ: usize = 1;
+    /// Payload length, or [`Status`] for [`Command::Ack`]
+    pub const OFFSET_LEN: usize = 2;
+    /// Payload, zero padded up to the CRC
+    pub const OFFSET_PAYLOAD: usize = 3;
+    /// CRC-16/CCITT-FALSE of the preceding bytes, little endian
+    pub const OFFSET_CRC: usize = super::raw::REPORT_LEN - 2;
+    /// Maximum number of payload bytes carried by a single packet
+    pub const MAX_PAYLOAD_LEN: usize = OFFSET_CRC - OFFSET_PAYLOAD;
+}
+
+/// MIDI events over raw reports, see [`midi`](crate::device::raw::midi)
+pub mod midi {
+    /// Number of events in the packet
+    pub const OFFSET_COUNT: usize = 0;
+    /// First event
+    pub const OFFSET_EVENTS: usize = 1;
+    /// Length of a USB MIDI event packet
+    pub const EVENT_LEN: usize = 4;
+    /// Maximum number of events carried by a single packet
+    pub const MAX_EVENTS: usize = (super::raw::REPORT_LEN - OFFSET_EVENTS) / EVENT_LEN;
+    /// Code index number of Note Off events
+    pub const CIN_NOTE_OFF: u8 = 0x08;
+    /// Code index number of Note On events
+    pub const CIN_NOTE_ON: u8 = 0x09;
+    /// Code index number of Control Change events
+    pub const CIN_CONTROL_CHANGE: u8 = 0x0B;
+}
+
+/// Keyboard remap table feature report, see [`remap`](crate::device::keyboard::remap)
+pub mod remap {
+    /// Feature report carrying requests and responses, the descriptor has no report IDs
+    pub const REPORT_ID: u8 = 0;
+    /// Length of requests and responses
+    pub const REPORT_LEN: usize = 8;
+    /// Read the entry at an index
+    pub const GET: u8 = 0x01;
+    /// Map a usage to another
+    pub const SET: u8 = 0x02;
+    /// Remove all entries
+    pub const CLEAR: u8 = 0x03;
+    /// Set in the first byte of responses
+    pub const RESPONSE: u8 = 0x80;
+    /// Request handled
+    pub const OK: u8 = 0x00;
+    /// No entry at the index
+    pub const ERR_INDEX: u8 = 0x01;
+    /// The table is full
+    pub const ERR_FULL: u8 = 0x02;
+    /// Unknown command
+    pub const ERR_COMMAND: u8 = 0x03;
+}
+
+/// Keyboard lock state feature report, see [`lock_state`](crate::device::keyboard::lock_state)
+pub mod lock_sync {
+    /// Feature report forcing the lock state, the descriptor has no report IDs
+    pub const REPORT_ID: u8 = 0;
+    /// Length of the feature report
+    pub const REPORT_LEN: usize = 2;
+    /// First byte of the feature report
+    pub const COMMAND: u8 = 0x4C;
+}
+
+/// Gamepad autofire settings feature report, see
+/// [`autofire`](crate::device::gamepad::autofire)
+pub mod autofire {
+    /// Feature report carrying the settings, the descriptor has no report IDs
+    pub const REPORT_ID: u8 = 0;
+    /// Length of the feature report
+    pub const REPORT_LEN: usize = 4;
+    /// Autofire buttons, button `n` is bit `n - 1`, little endian
+    pub const OFFSET_BUTTONS: usize = 0;
+    /// Presses per second, 1 to [`MAX_RATE_HZ`]
+    pub const OFFSET_RATE: usize = 2;
+    /// Percent of the period the button is pressed, 1 to [`MAX_DUTY_PERCENT`]
+    pub const OFFSET_DUTY: usize = 3;
+    /// Highest rate, in presses per second
+    pub const MAX_RATE_HZ: u8 = 50;
+    /// Highest duty cycle, in percent
+    pub const MAX_DUTY_PERCENT: u8 = 99;
+}
+
+/// Surface status report of [`SurfaceMouse`](crate::device::mouse::surface::SurfaceMouse)
+#[cfg(feature = "mouse-surface")]
+pub mod surface {
+    /// Report ID of the mouse input report
+    pub const MOUSE_REPORT_ID: u8 = 0x01;
+    /// Report ID of the surface status input report
+    pub const STATUS_REPORT_ID: u8 = 0x02;
+    /// Length of the surface status report, without its report ID
+    pub const STATUS_REPORT_LEN: usize = 4;
+    /// Usage page of the surface status collection
+    pub const USAGE_PAGE: u16 = 0xFF00;
+    /// Usage of the surface status collection
+    pub const USAGE: u16 = 0x20;
+    /// Flags, see [`FLAG_LIFTED`]
+    pub const OFFSET_FLAGS: usize = 0;
+    /// Surface quality as read from the sensor
+    pub const OFFSET_QUALITY: usize = 1;
+    /// Frame rate in frames per second, little endian
+    pub const OFFSET_FRAME_RATE: usize = 2;
+    /// Set while the mouse is lifted off the surface
+    pub const FLAG_LIFTED: u8 = 0x01;
+}
+
+/// Versioned TLV encoding of feature report payloads, see [`tlv`](crate::tlv)
+pub mod tlv {
+    /// Record type reserved for padding, marks the end of a payload
+    pub const END: u8 = 0x00;
+}
+
+/// Capability payload, see [`capability`](crate::capability)
+pub mod capability {
+    pub use crate::capability::ReportKind;
+
+    /// Schema version of the payload
+    pub const VERSION: u8 = 1;
+    /// Interface number record
+    pub const TAG_INTERFACE: u8 = 0x01;
+    /// Top level collection usage record
+    pub const TAG_APPLICATION: u8 = 0x02;
+    /// Report ID, [`ReportKind`] and length record
+    pub const TAG_REPORT: u8 = 0x03;
+}
+
+/// Report descriptor hash feature report, see
+/// [`InterfaceBuilder::descriptor_hash_report`](crate::interface::InterfaceBuilder::descriptor_hash_report)
+pub mod descriptor_hash {
+    /// Usage page of the feature report
+    pub const USAGE_PAGE: u16 = 0xFF00;
+    /// Usage of the feature report
+    pub const USAGE: u16 = 0x48;
+    /// Length of the hash, little endian
+    pub const LEN: usize = 4;
+}
+
+/// CTAPHID framing, see [`transport`](crate::device::fido::transport)
+pub mod fido {
+    /// Channel ID, big endian
+    pub const OFFSET_CID: usize = 0;
+    /// Command with bit 7 set, or sequence number
+    pub const OFFSET_COMMAND: usize = 4;
+    /// Message length of initialization packets, big endian
+    pub const OFFSET_LEN: usize = 5;
+    /// Data of initialization packets
+    pub const OFFSET_INIT_DATA: usize = 7;
+    /// Data of continuation packets
+    pub const OFFSET_CONT_DATA: usize = 5;
+    /// Channel ID used by the host to allocate a channel
+    pub const BROADCAST_CID: u32 = 0xFFFF_FFFF;
+    /// `CTAPHID_ERROR` command, without bit 7
+    pub const CMD_ERROR: u8 = 0x3F;
+    /// Error code for a message not completed in time
+    pub const ERR_MSG_TIMEOUT: u8 = 0x05;
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::descriptor::DESCRIPTOR_HASH_FEATURE_ITEMS;
+    use crate::device::raw::RAW_REPORT_DESCRIPTOR;
+
+    fn usage_items(usage_page: u16, usage: u16) -> [u8; 5] {
+        let [page_low, page_high] = usage_page.to_le_bytes();
+        [0x06, page_low, page_high, 0x09, usage.to_le_bytes()[0]]
+    }
+
+    #[test]
+    fn descriptors_match_protocol() {
+        assert_eq!(
+            RAW_REPORT_DESCRIPTOR[..5],
+            usage_items(raw::USAGE_PAGE, raw::USAGE)
+        );
+        let report_count = [0x95, u8::try_from(raw::REPORT_LEN).unwrap_or(0)];
+        assert_eq!(
+            RAW_REPORT_DESCRIPTOR
+                .windows(2)
+                .filter(|item| *item == report_count)
+                .count(),
+            2
+        );
+
+        assert_eq!(
+            DESCRIPTOR_HASH_FEATURE_ITEMS[..5],
+            usage_items(descriptor_hash::USAGE_PAGE, descriptor_hash::USAGE)
+        );
+        let report_count = [0x95, u8::try_from(descriptor_hash::LEN).unwrap_or(0)];
+        assert!(DESCRIPTOR_HASH_FEATURE_ITEMS
+            .windows(2)
+            .any(|item| item == report_count));
+    }
+
+    #[cfg(feature = "mouse-surface")]
+    #[test]
+    fn surface_descriptor_matches_protocol() {
+        use crate::device::mouse::surface::SURFACE_MOUSE_REPORT_DESCRIPTOR;
+
+        // Vendor collection after the mouse collection
+        let usage = usage_items(surface::USAGE_PAGE, surface::USAGE);
+        let vendor = SURFACE_MOUSE_REPORT_DESCRIPTOR
+            .windows(usage.len())
+            .position(|item| item == usage);
+        assert!(matches!(vendor, Some(vendor) if vendor > 0));
+    }
+}
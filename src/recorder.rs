@@ -0,0 +1,232 @@
+//! Black-box recording of outgoing reports
+//!
+//! A [`Recorder`] serializes reports, together with a timestamp and the interface they were sent
+//! on, into a user provided [`RecordStorage`], such as a ring buffer in external flash. Records
+//! can later be read back out with [`RecordReader`] to debug intermittent host issues on
+//! deployed devices.
+//!
+//! Each record is laid out as:
+//!
+//! | Offset | Size | Field                       |
+//! |--------|------|-----------------------------|
+//! | 0      | 4    | Timestamp, ms, little endian |
+//! | 4      | 1    | Interface number            |
+//! | 5      | 1    | Report length `n`           |
+//! | 6      | n    | Report data                 |
+
+use crate::UsbHidError;
+
+/// Bytes preceding the report data in each record
+pub const RECORD_HEADER_LEN: usize = 6;
+
+/// Largest record, header plus a 64 byte report
+pub const MAX_RECORD_LEN: usize = RECORD_HEADER_LEN + 64;
+
+/// Storage that records are appended to
+pub trait RecordStorage {
+    type Error;
+
+    /// Append a complete record. Implementations should either store the whole record or none
+    /// of it
+    fn append(&mut self, record: &[u8]) -> Result<(), Self::Error>;
+}
+
+/// A single recorded report
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Record<'a> {
+    pub timestamp_ms: u32,
+    pub interface: u8,
+    pub report: &'a [u8],
+}
+
+impl Record<'_> {
+    /// Serialize the record into `buf`, returning the number of bytes used
+    pub fn encode(&self, buf: &mut [u8]) -> Result<usize, UsbHidError> {
+        let len = u8::try_from(self.report.len()).map_err(|_| UsbHidError::SerializationError)?;
+        let end = RECORD_HEADER_LEN + self.report.len();
+        let record = buf.get_mut(..end).ok_or(UsbHidError::SerializationError)?;
+        record[..4].copy_from_slice(&self.timestamp_ms.to_le_bytes());
+        record[4] = self.interface;
+        record[5] = len;
+        record[RECORD_HEADER_LEN..].copy_from_slice(self.report);
+        Ok(end)
+    }
+}
+
+/// Records outgoing reports into a [`RecordStorage`]
+pub struct Recorder<S: RecordStorage> {
+    storage: S,
+    enabled: bool,
+    dropped: u32,
+}
+
+impl<S: RecordStorage> Recorder<S> {
+    #[must_use]
+    pub fn new(storage: S) -> Self {
+        Self {
+            storage,
+            enabled: true,
+            dropped: 0,
+        }
+    }
+
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    #[must_use]
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Number of records that could not be stored
+    #[must_use]
+    pub fn dropped(&self) -> u32 {
+        self.dropped
+    }
+
+    #[must_use]
+    pub fn storage(&self) -> &S {
+        &self.storage
+    }
+
+    pub fn storage_mut(&mut self) -> &mut S {
+        &mut self.storage
+    }
+
+    #[must_use]
+    pub fn into_storage(self) -> S {
+        self.storage
+    }
+
+    /// Record a report sent on `interface` at `timestamp_ms`
+    ///
+    /// Recording is best effort, failures are counted in [`Recorder::dropped`] rather than
+    /// returned so that recording never interferes with sending reports
+    pub fn record(&mut self, timestamp_ms: u32, interface: u8, report: &[u8]) {
+        if !self.enabled {
+            return;
+        }
+        let mut buf = [0u8; MAX_RECORD_LEN];
+        let record = Record {
+            timestamp_ms,
+            interface,
+            report,
+        };
+        let stored = match record.encode(&mut buf) {
+            Ok(len) => self.storage.append(&buf[..len]).is_ok(),
+            Err(_) => false,
+        };
+        if !stored {
+            warn!("Failed to record report for interface {:X}", interface);
+            self.dropped = self.dropped.saturating_add(1);
+        }
+    }
+
+    /// Record a report if `result`, the result of writing it, indicates it was sent
+    pub fn record_result<T>(
+        &mut self,
+        timestamp_ms: u32,
+        interface: u8,
+        report: &[u8],
+        result: &Result<T, UsbHidError>,
+    ) {
+        if result.is_ok() {
+            self.record(timestamp_ms, interface, report);
+        }
+    }
+}
+
+/// Iterates over records previously written by a [`Recorder`]
+#[derive(Clone, Debug)]
+pub struct RecordReader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> RecordReader<'a> {
+    #[must_use]
+    pub fn new(data: &'a [u8]) -> Self {
+        Self { data }
+    }
+
+    /// Bytes remaining that did not form a complete record
+    #[must_use]
+    pub fn remainder(&self) -> &'a [u8] {
+        self.data
+    }
+}
+
+impl<'a> Iterator for RecordReader<'a> {
+    type Item = Record<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let header = self.data.get(..RECORD_HEADER_LEN)?;
+        let end = RECORD_HEADER_LEN + usize::from(header[5]);
+        let report = self.data.get(RECORD_HEADER_LEN..end)?;
+        let record = Record {
+            timestamp_ms: u32::from_le_bytes([header[0], header[1], header[2], header[3]]),
+            interface: header[4],
+            report,
+        };
+        self.data = &self.data[end..];
+        Some(record)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+    use heapless::Vec;
+
+    struct VecStorage(Vec<u8, 32>);
+
+    impl RecordStorage for VecStorage {
+        type Error = ();
+
+        fn append(&mut self, record: &[u8]) -> Result<(), Self::Error> {
+            self.0.extend_from_slice(record)
+        }
+    }
+
+    #[test]
+    fn record_and_read_back() {
+        let mut recorder = Recorder::new(VecStorage(Vec::new()));
+        recorder.record(0x0102_0304, 1, &[0xAA, 0xBB]);
+        recorder.record_result(7, 2, &[0xCC], &Ok::<_, UsbHidError>(()));
+        recorder.record_result(8, 2, &[0xDD], &Err::<(), _>(UsbHidError::WouldBlock));
+
+        let storage = recorder.into_storage();
+        let mut reader = RecordReader::new(&storage.0);
+        assert_eq!(
+            reader.next(),
+            Some(Record {
+                timestamp_ms: 0x0102_0304,
+                interface: 1,
+                report: &[0xAA, 0xBB]
+            })
+        );
+        assert_eq!(
+            reader.next(),
+            Some(Record {
+                timestamp_ms: 7,
+                interface: 2,
+                report: &[0xCC]
+            })
+        );
+        assert_eq!(reader.next(), None);
+        assert!(reader.remainder().is_empty());
+    }
+
+    #[test]
+    fn full_storage_counts_dropped() {
+        let mut recorder = Recorder::new(VecStorage(Vec::new()));
+        for i in 0..5 {
+            recorder.record(i, 0, &[0; 4]);
+        }
+        assert_eq!(recorder.dropped(), 2);
+        assert_eq!(RecordReader::new(&recorder.storage().0).count(), 3);
+    }
+}
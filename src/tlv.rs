@@ -0,0 +1,228 @@
+//! Versioned TLV (type, length, value) encoding for vendor feature report payloads
+//!
+//! A payload starts with a one byte schema version followed by records of a one byte type, a one
+//! byte length and `length` bytes of value. Type 0 is reserved as padding and ends the payload,
+//! so zero filled fixed length feature reports decode cleanly.
+//!
+//! Readers skip record types they don't know about and integer values are zero extended, so
+//! fields can be added or widened in later schema versions without breaking existing host tools.
+
+use crate::UsbHidError;
+
+pub use crate::protocol::tlv::END as TLV_END;
+
+/// A single decoded record
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Tlv<'a> {
+    pub tag: u8,
+    pub value: &'a [u8],
+}
+
+impl Tlv<'_> {
+    /// Value as a little endian integer, `None` if the value is too long to fit
+    #[must_use]
+    pub fn as_u32(&self) -> Option<u32> {
+        if self.value.len() > 4 {
+            return None;
+        }
+        let mut bytes = [0u8; 4];
+        bytes[..self.value.len()].copy_from_slice(self.value);
+        Some(u32::from_le_bytes(bytes))
+    }
+
+    #[must_use]
+    pub fn as_u16(&self) -> Option<u16> {
+        self.as_u32().and_then(|v| u16::try_from(v).ok())
+    }
+
+    #[must_use]
+    pub fn as_u8(&self) -> Option<u8> {
+        self.as_u32().and_then(|v| u8::try_from(v).ok())
+    }
+
+    #[must_use]
+    pub fn as_bool(&self) -> Option<bool> {
+        self.as_u32().map(|v| v != 0)
+    }
+}
+
+/// Builds a TLV payload into a caller provided buffer
+pub struct TlvWriter<'a> {
+    buf: &'a mut [u8],
+    len: usize,
+}
+
+impl<'a> TlvWriter<'a> {
+    /// Start a payload with the given schema version
+    pub fn new(buf: &'a mut [u8], version: u8) -> Result<Self, UsbHidError> {
+        let first = buf.first_mut().ok_or(UsbHidError::SerializationError)?;
+        *first = version;
+        Ok(Self { buf, len: 1 })
+    }
+
+    /// Append a record with an arbitrary value
+    pub fn field(&mut self, tag: u8, value: &[u8]) -> Result<&mut Self, UsbHidError> {
+        if tag == TLV_END {
+            return Err(UsbHidError::SerializationError);
+        }
+        let value_len = u8::try_from(value.len()).map_err(|_| UsbHidError::SerializationError)?;
+        let end = self.len + 2 + value.len();
+        let record = self
+            .buf
+            .get_mut(self.len..end)
+            .ok_or(UsbHidError::SerializationError)?;
+        record[0] = tag;
+        record[1] = value_len;
+        record[2..].copy_from_slice(value);
+        self.len = end;
+        Ok(self)
+    }
+
+    pub fn u8(&mut self, tag: u8, value: u8) -> Result<&mut Self, UsbHidError> {
+        self.field(tag, &[value])
+    }
+
+    pub fn u16(&mut self, tag: u8, value: u16) -> Result<&mut Self, UsbHidError> {
+        self.field(tag, &value.to_le_bytes())
+    }
+
+    pub fn u32(&mut self, tag: u8, value: u32) -> Result<&mut Self, UsbHidError> {
+        self.field(tag, &value.to_le_bytes())
+    }
+
+    pub fn bool(&mut self, tag: u8, value: bool) -> Result<&mut Self, UsbHidError> {
+        self.u8(tag, u8::from(value))
+    }
+
+    /// Number of bytes written so far, including the version byte
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len == 1
+    }
+
+    /// Zero the remainder of the buffer and return the length of the encoded payload
+    #[must_use]
+    pub fn finish(self) -> usize {
+        self.buf[self.len..].fill(TLV_END);
+        self.len
+    }
+}
+
+/// Decodes a TLV payload, iterating over its records
+#[derive(Clone, Debug)]
+pub struct TlvReader<'a> {
+    version: u8,
+    data: &'a [u8],
+    malformed: bool,
+}
+
+impl<'a> TlvReader<'a> {
+    pub fn new(data: &'a [u8]) -> Result<Self, UsbHidError> {
+        let (&version, data) = data.split_first().ok_or(UsbHidError::SerializationError)?;
+        Ok(Self {
+            version,
+            data,
+            malformed: false,
+        })
+    }
+
+    /// Schema version the payload was written with
+    #[must_use]
+    pub fn version(&self) -> u8 {
+        self.version
+    }
+
+    /// `true` if iteration stopped on a record that overran the end of the payload
+    #[must_use]
+    pub fn is_malformed(&self) -> bool {
+        self.malformed
+    }
+
+    /// The first record with the given type
+    #[must_use]
+    pub fn get(&self, tag: u8) -> Option<Tlv<'a>> {
+        self.clone().find(|t| t.tag == tag)
+    }
+}
+
+impl<'a> Iterator for TlvReader<'a> {
+    type Item = Tlv<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (&tag, rest) = self.data.split_first()?;
+        if tag == TLV_END {
+            self.data = &[];
+            return None;
+        }
+        let Some((&len, rest)) = rest.split_first() else {
+            self.malformed = true;
+            self.data = &[];
+            return None;
+        };
+        if rest.len() < usize::from(len) {
+            self.malformed = true;
+            self.data = &[];
+            return None;
+        }
+        let (value, rest) = rest.split_at(usize::from(len));
+        self.data = rest;
+        Some(Tlv { tag, value })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let mut buf = [0xAAu8; 16];
+        let mut writer = TlvWriter::new(&mut buf, 2).unwrap();
+        writer.u8(1, 7).unwrap().u16(2, 0x1234).unwrap();
+        writer.field(9, b"ab").unwrap();
+        assert_eq!(writer.finish(), 12);
+        assert_eq!(buf[12..], [0; 4]);
+
+        let reader = TlvReader::new(&buf).unwrap();
+        assert_eq!(reader.version(), 2);
+        assert_eq!(reader.get(1).unwrap().as_u8(), Some(7));
+        assert_eq!(reader.get(2).unwrap().as_u16(), Some(0x1234));
+        assert_eq!(reader.get(9).unwrap().value, b"ab");
+        assert_eq!(reader.get(3), None);
+        assert_eq!(reader.count(), 3);
+    }
+
+    #[test]
+    fn values_are_zero_extended() {
+        let tlv = Tlv {
+            tag: 1,
+            value: &[0x34, 0x12],
+        };
+        assert_eq!(tlv.as_u32(), Some(0x1234));
+        assert_eq!(tlv.as_u8(), None);
+    }
+
+    #[test]
+    fn overrun_is_malformed() {
+        let mut reader = TlvReader::new(&[1, 1, 1, 5, 2, 4, 0]).unwrap();
+        assert_eq!(reader.next().map(|t| t.value), Some(&[5u8][..]));
+        assert_eq!(reader.next(), None);
+        assert!(reader.is_malformed());
+    }
+
+    #[test]
+    fn writer_overflow() {
+        let mut buf = [0u8; 4];
+        let mut writer = TlvWriter::new(&mut buf, 1).unwrap();
+        assert!(writer.u16(1, 1).is_err());
+        assert!(writer.u8(1, 1).is_ok());
+    }
+}
@@ -1,17 +1,21 @@
 //! USB Class for implementing Human Interface Devices
 
+use crate::capability::CAPABILITY_VERSION;
 use crate::descriptor::{DescriptorType, HidProtocol, HidRequest};
-use crate::device::{DeviceClass, DeviceHList};
-use crate::interface::{InterfaceClass, UsbAllocatable};
+use crate::device::{DeviceClass, DeviceHList, HidDeviceAny};
+use crate::interface::{InterfaceClass, InterfaceHealth, UsbAllocatable};
+use crate::tlv::TlvWriter;
 use crate::UsbHidError;
 use core::cell::RefCell;
 use core::default::Default;
 use core::marker::PhantomData;
 use frunk::hlist::{HList, Selector};
 use frunk::{HCons, HNil, ToMut};
+use fugit::{ExtU32, MillisDurationU32};
 #[allow(clippy::wildcard_imports)]
 use usb_device::class_prelude::*;
 use usb_device::control::{Recipient, Request};
+use usb_device::descriptor::descriptor_type;
 use usb_device::{control::RequestType, Result};
 
 pub mod prelude {
@@ -29,11 +33,12 @@ pub mod prelude {
     pub use crate::device::DeviceClass;
     pub use crate::interface::{
         InBytes16, InBytes32, InBytes64, InBytes8, InNone, Interface, InterfaceBuilder,
-        InterfaceConfig, OutBytes16, OutBytes32, OutBytes64, OutBytes8, OutNone, ReportSingle,
-        Reports128, Reports16, Reports32, Reports64, Reports8, UsbAllocatable,
+        InterfaceConfig, OutBytes16, OutBytes32, OutBytes64, OutBytes8, OutNone, OutQueue,
+        OutReportMode, ReportSingle, Reports128, Reports16, Reports32, Reports64, Reports8,
+        UsbAllocatable,
     };
     pub use crate::interface::{ManagedIdleInterface, ManagedIdleInterfaceConfig};
-    pub use crate::usb_class::{UsbHidClass, UsbHidClassBuilder};
+    pub use crate::usb_class::{RequestStrictness, UsbHidClass, UsbHidClassBuilder};
     pub use crate::UsbHidError;
 }
 
@@ -47,11 +52,72 @@ pub enum UsbHidBuilderError {
     SliceLengthOverflow,
 }
 
+impl UsbHidBuilderError {
+    /// Compact numeric code for the error, see [`UsbHidError::code`]
+    #[must_use]
+    pub const fn code(&self) -> u8 {
+        match self {
+            Self::ValueOverflow => 0x20,
+            Self::SliceLengthOverflow => 0x21,
+        }
+    }
+}
+
+#[cfg(feature = "ufmt")]
+impl ufmt::uDebug for UsbHidBuilderError {
+    fn fmt<W: ufmt::uWrite + ?Sized>(
+        &self,
+        f: &mut ufmt::Formatter<'_, W>,
+    ) -> core::result::Result<(), W::Error> {
+        f.write_str(match self {
+            Self::ValueOverflow => "ValueOverflow",
+            Self::SliceLengthOverflow => "SliceLengthOverflow",
+        })
+    }
+}
+
+/// Writes the error code, e.g. `E0x21`
+#[cfg(feature = "ufmt")]
+impl ufmt::uDisplay for UsbHidBuilderError {
+    fn fmt<W: ufmt::uWrite + ?Sized>(
+        &self,
+        f: &mut ufmt::Formatter<'_, W>,
+    ) -> core::result::Result<(), W::Error> {
+        ufmt::uwrite!(f, "E{:#x}", self.code())
+    }
+}
+
+/// How strictly malformed HID class requests are handled
+///
+/// Some hosts, notably older BIOSes, send class requests with the wrong `wLength` or addressed to
+/// the device rather than the interface
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RequestStrictness {
+    /// STALL class requests with an unexpected `wLength`
+    Strict,
+    /// Log and process class requests with an unexpected `wLength`
+    #[default]
+    Tolerant,
+    /// As [`RequestStrictness::Tolerant`], and also process class requests with a device
+    /// recipient, treating `wIndex` as the interface number
+    Lenient,
+}
+
+/// `wValue` high byte of a `GET_REPORT` request for a feature report
+const REPORT_TYPE_FEATURE: u8 = 0x03;
+
+/// Most LANGIDs that fit in string descriptor 0
+pub const MAX_LANG_IDS: usize = 126;
+
 /// Builder for [`UsbHidClass`]
 #[must_use = "this `UsbHidClassBuilder` must be assigned or consumed by `::build()`"]
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub struct UsbHidClassBuilder<'a, B, Devices> {
     devices: Devices,
+    strictness: RequestStrictness,
+    lang_ids: &'a [u16],
+    in_endpoint_watchdog: Option<MillisDurationU32>,
     marker: PhantomData<&'a B>,
 }
 
@@ -59,6 +125,9 @@ impl<'a, B> UsbHidClassBuilder<'a, B, HNil> {
     pub fn new() -> Self {
         Self {
             devices: HNil,
+            strictness: RequestStrictness::default(),
+            lang_ids: &[],
+            in_endpoint_watchdog: None,
             marker: PhantomData,
         }
     }
@@ -81,9 +150,44 @@ impl<'a, B: UsbBus, Tail: HList> UsbHidClassBuilder<'a, B, Tail> {
     {
         UsbHidClassBuilder {
             devices: self.devices.prepend(config),
+            strictness: self.strictness,
+            lang_ids: self.lang_ids,
+            in_endpoint_watchdog: self.in_endpoint_watchdog,
             marker: PhantomData,
         }
     }
+
+    /// Set how strictly malformed class requests are handled, defaults to
+    /// [`RequestStrictness::Tolerant`]
+    pub fn strictness(mut self, strictness: RequestStrictness) -> Self {
+        self.strictness = strictness;
+        self
+    }
+
+    /// Set the LANGID table sent as string descriptor 0, listing the languages interface
+    /// descriptions are available in, see [`InterfaceBuilder::localized_descriptions`]. Defaults
+    /// to the US English only table sent by `usb-device`
+    ///
+    /// US English (`0x0409`) should be listed first, as some hosts only request the first language
+    ///
+    /// [`InterfaceBuilder::localized_descriptions`]: crate::interface::InterfaceBuilder::localized_descriptions
+    pub fn lang_ids(mut self, lang_ids: &'a [u16]) -> BuilderResult<Self> {
+        if lang_ids.len() > MAX_LANG_IDS {
+            return Err(UsbHidBuilderError::SliceLengthOverflow);
+        }
+        self.lang_ids = lang_ids;
+        Ok(self)
+    }
+
+    /// Flag the IN endpoints as stalled once the host hasn't read a report for `timeout` while
+    /// the device is configured and not suspended, see [`UsbHidClass::in_endpoint_stalled`]
+    ///
+    /// Some host controllers silently stop polling an endpoint, leaving the device unable to send
+    /// reports until it is re-enumerated. `timeout` should be well above the polling intervals
+    pub fn in_endpoint_watchdog(mut self, timeout: MillisDurationU32) -> Self {
+        self.in_endpoint_watchdog = Some(timeout);
+        self
+    }
 }
 
 impl<'a, B, Config, Tail> UsbHidClassBuilder<'a, B, HCons<Config, Tail>>
@@ -98,6 +202,14 @@ where
     ) -> UsbHidClass<B, HCons<Config::Allocated, Tail::Allocated>> {
         UsbHidClass {
             devices: RefCell::new(self.devices.allocate(usb_alloc)),
+            strictness: self.strictness,
+            lang_ids: self.lang_ids,
+            clock: None,
+            in_endpoint_watchdog: self.in_endpoint_watchdog,
+            in_endpoint_wait: 0.millis(),
+            in_endpoint_stalled: false,
+            configured: false,
+            suspended: false,
             _marker: PhantomData,
         }
     }
@@ -111,9 +223,22 @@ pub struct UsbHidClass<'a, B, Devices> {
     // this could be removed, but then each usb device would need to implement a non mut borrow
     // of its `RawInterface`.
     devices: RefCell<Devices>,
+    strictness: RequestStrictness,
+    lang_ids: &'a [u16],
+    /// Time up to which [`UsbHidClass::tick_until`] has ticked the devices
+    clock: Option<Instant>,
+    in_endpoint_watchdog: Option<MillisDurationU32>,
+    /// Time the IN endpoints have been waiting for the host while watched
+    in_endpoint_wait: MillisDurationU32,
+    in_endpoint_stalled: bool,
+    configured: bool,
+    suspended: bool,
     _marker: PhantomData<&'a B>,
 }
 
+/// Millisecond timestamp from the application's clock, see [`UsbHidClass::tick_until`]
+pub type Instant = fugit::TimerInstantU64<1000>;
+
 impl<'a, B, Devices: DeviceHList<'a>> UsbHidClass<'a, B, Devices> {
     /// Borrow a single device selected by `T`
     pub fn device<T, Index>(&mut self) -> &mut T
@@ -128,20 +253,256 @@ impl<'a, B, Devices: DeviceHList<'a>> UsbHidClass<'a, B, Devices> {
         self.devices.get_mut().to_mut()
     }
 
+    /// Borrow all devices as trait objects, in the order they were added, for generic code
+    /// handling a runtime configured set of devices
+    ///
+    /// Returns [`UsbError::BufferOverflow`] if there are more than `N` devices
+    pub fn devices_dyn<const N: usize>(
+        &mut self,
+    ) -> core::result::Result<heapless::Vec<&mut dyn HidDeviceAny<'a>, N>, UsbHidError> {
+        let mut devices = heapless::Vec::new();
+        self.devices.get_mut().collect_dyn(&mut devices)?;
+        Ok(devices)
+    }
+
     /// Provide a clock tick to allow the tracking of time. Call this every 1ms / at 1KHz
     pub fn tick(&mut self) -> core::result::Result<(), UsbHidError> {
+        self.watch_in_endpoints(1.millis());
         self.devices.get_mut().tick()
     }
+
+    /// Tickless alternative to [`UsbHidClass::tick`], for applications sleeping in low power
+    /// modes between events
+    ///
+    /// Runs the ticks due between the previous call and `now`, and returns when the class must be
+    /// called again, `None` if no device has anything scheduled, e.g. an idle retransmission.
+    /// Only the ticks at the devices' [`DeviceClass::next_deadline`] are run, the time in between
+    /// is skipped with [`DeviceClass::advance`], so a long sleep costs one step per deadline
+    /// rather than one tick per millisecond. On error the remaining time is run by the next call.
+    /// Don't mix with [`UsbHidClass::tick`]
+    pub fn tick_until(
+        &mut self,
+        now: Instant,
+    ) -> core::result::Result<Option<Instant>, UsbHidError> {
+        let mut clock = *self.clock.get_or_insert(now);
+        while clock < now {
+            let remaining = u32::try_from((now - clock).ticks())
+                .unwrap_or(u32::MAX)
+                .millis();
+            match self.devices.get_mut().next_deadline() {
+                Some(deadline) if deadline <= remaining => {
+                    //skip the ticks without work, then run the tick that is due
+                    let skipped = deadline.ticks().saturating_sub(1).millis();
+                    self.devices.get_mut().clock_advance(skipped);
+                    self.watch_in_endpoints(skipped);
+                    clock += skipped + 1.millis();
+                    self.clock = Some(clock);
+                    self.watch_in_endpoints(1.millis());
+                    self.devices.get_mut().tick()?;
+                }
+                _ => {
+                    self.devices.get_mut().clock_advance(remaining);
+                    self.watch_in_endpoints(remaining);
+                    clock += remaining;
+                    self.clock = Some(clock);
+                }
+            }
+        }
+        let deadline = self.devices.get_mut().next_deadline();
+        let watchdog = self.watchdog_deadline();
+        let deadline = match (deadline, watchdog) {
+            (Some(deadline), Some(watchdog)) => Some(deadline.min(watchdog)),
+            (deadline, watchdog) => deadline.or(watchdog),
+        };
+        Ok(deadline.map(|deadline| now + deadline))
+    }
+
+    /// Tell the class whether the bus is suspended, the host doesn't read the IN endpoints while
+    /// suspended so [`UsbHidClassBuilder::in_endpoint_watchdog`] is paused
+    ///
+    /// `usb-device` doesn't notify classes of suspend, call this after each poll with
+    /// `usb_dev.state() == UsbDeviceState::Suspend`
+    pub fn set_suspended(&mut self, suspended: bool) {
+        self.suspended = suspended;
+    }
+
+    /// `true` once the host hasn't read an IN endpoint for the
+    /// [`UsbHidClassBuilder::in_endpoint_watchdog`] timeout, until the next bus reset
+    ///
+    /// The endpoint is likely wedged by a host controller quirk, the firmware should reset the USB
+    /// stack, e.g. by disconnecting from the bus and building a new `UsbDevice`
+    #[must_use]
+    pub fn in_endpoint_stalled(&self) -> bool {
+        self.in_endpoint_stalled
+    }
+
+    fn watch_in_endpoints(&mut self, elapsed: MillisDurationU32) {
+        let Some(timeout) = self.in_endpoint_watchdog else {
+            return;
+        };
+        if !self.configured || self.suspended || !self.devices.get_mut().in_endpoint_busy() {
+            self.in_endpoint_wait = 0.millis();
+            return;
+        }
+        self.in_endpoint_wait += elapsed;
+        if self.in_endpoint_wait >= timeout && !self.in_endpoint_stalled {
+            warn!(
+                "IN endpoint not read for {}ms",
+                self.in_endpoint_wait.ticks()
+            );
+            self.in_endpoint_stalled = true;
+        }
+    }
+
+    /// Time until the watchdog expires, if it is counting
+    fn watchdog_deadline(&mut self) -> Option<MillisDurationU32> {
+        let timeout = self.in_endpoint_watchdog?;
+        (self.configured
+            && !self.suspended
+            && !self.in_endpoint_stalled
+            && self.devices.get_mut().in_endpoint_busy())
+        .then(|| {
+            timeout
+                .checked_sub(self.in_endpoint_wait)
+                .unwrap_or(1.millis())
+        })
+    }
+
+    /// Errors of the interface `interface`, `None` if there is no such interface
+    pub fn health(&mut self, interface: u8) -> Option<InterfaceHealth> {
+        self.devices.get_mut().get(interface).map(|i| i.health())
+    }
+
+    #[must_use]
+    pub fn strictness(&self) -> RequestStrictness {
+        self.strictness
+    }
+
+    /// Write a summary of the interfaces and reports of all devices to `buf`, see
+    /// [`capability`](crate::capability). Returns the length of the payload
+    pub fn write_capabilities(
+        &mut self,
+        buf: &mut [u8],
+    ) -> core::result::Result<usize, UsbHidError> {
+        let mut writer = TlvWriter::new(buf, CAPABILITY_VERSION)?;
+        self.devices.get_mut().write_capabilities(&mut writer)?;
+        Ok(writer.finish())
+    }
+}
+
+impl RequestStrictness {
+    /// `true` if a class request with this recipient should be handled
+    fn accepts_recipient(self, recipient: Recipient) -> bool {
+        recipient == Recipient::Interface
+            || (self == Self::Lenient && recipient == Recipient::Device)
+    }
+
+    /// `true` if a request with an unexpected `wLength` should be handled
+    fn accepts_length(self, request: &Request, expected: u16) -> bool {
+        if request.length == expected {
+            return true;
+        }
+        warn!(
+            "Expected request {} to have length {}, received {}",
+            request.request, expected, request.length
+        );
+        self != Self::Strict
+    }
 }
 
 impl<'a, B: UsbBus + 'a, Devices> UsbHidClass<'a, B, Devices> {
+    fn get_lang_ids(lang_ids: &[u16], transfer: ControlIn<B>) {
+        let mut buffer = [0; 2 + 2 * MAX_LANG_IDS];
+        let len = 2 + 2 * lang_ids.len();
+        buffer[0] = unwrap!(u8::try_from(len));
+        buffer[1] = descriptor_type::STRING;
+        for (b, id) in buffer[2..len].chunks_exact_mut(2).zip(lang_ids) {
+            b.copy_from_slice(&id.to_le_bytes());
+        }
+        match transfer.accept_with(&buffer[..len]) {
+            Err(e) => error!("Failed to send LANGID table - {:?}", e),
+            Ok(()) => {
+                trace!("Sent LANGID table");
+            }
+        }
+    }
+
+    fn get_descriptor_hash(
+        transfer: ControlIn<B>,
+        report_id: u8,
+        interface: &mut dyn InterfaceClass<'a>,
+    ) {
+        let mut buffer = [report_id, 0, 0, 0, 0];
+        buffer[1..].copy_from_slice(&interface.report_descriptor_hash().to_le_bytes());
+        let data = if report_id == 0 {
+            &buffer[1..]
+        } else {
+            &buffer[..]
+        };
+        match transfer.accept_with(data) {
+            Err(e) => error!("Failed to send descriptor hash - {:?}", e),
+            Ok(()) => {
+                trace!("Sent descriptor hash");
+            }
+        }
+    }
+
+    fn get_report(transfer: ControlIn<B>, interface: &mut dyn InterfaceClass<'a>) {
+        let [report_id, report_type] = transfer.request().value.to_le_bytes();
+        if report_type == REPORT_TYPE_FEATURE
+            && interface.descriptor_hash_report_id() == Some(report_id)
+        {
+            Self::get_descriptor_hash(transfer, report_id, interface);
+            return;
+        }
+        if let Some(data) = (report_type == REPORT_TYPE_FEATURE)
+            .then(|| interface.static_feature_report(report_id))
+            .flatten()
+        {
+            if let Err(e) = transfer.accept_with_static(data) {
+                error!("Failed to send static feature report - {:?}", e);
+            }
+            return;
+        }
+
+        let mut data = [0_u8; 64];
+        if report_type == REPORT_TYPE_FEATURE {
+            if let Some(n) = interface.get_feature_report(report_id, &mut data) {
+                if let Err(e) = transfer.accept_with(&data[..n]) {
+                    error!("Failed to send feature report - {:?}", e);
+                }
+                return;
+            }
+        }
+
+        if let Ok(n) = interface.get_report(&mut data) {
+            if n != transfer.request().length.into() {
+                warn!(
+                    "GetReport expected {} bytes, got {} bytes",
+                    transfer.request().length,
+                    data.len()
+                );
+            }
+            if let Err(e) = transfer.accept_with(&data[..n]) {
+                error!("Failed to send report - {:?}", e);
+            } else {
+                trace!("Sent report, {} bytes", n);
+                unwrap!(interface.get_report_ack());
+            }
+        }
+    }
+
     fn get_descriptor(transfer: ControlIn<B>, interface: &mut dyn InterfaceClass<'a>) {
         let request: &Request = transfer.request();
         match DescriptorType::try_from((request.value >> 8) as u8) {
             Ok(DescriptorType::Report) => {
-                match transfer.accept_with(interface.report_descriptor()) {
+                let result = match interface.static_report_descriptor() {
+                    Some(descriptor) => transfer.accept_with_static(descriptor),
+                    None => transfer.accept_with(interface.report_descriptor()),
+                };
+                match result {
                     Err(e) => error!("Failed to send report descriptor - {:?}", e),
-                    Ok(_) => {
+                    Ok(()) => {
                         trace!("Sent report descriptor");
                     }
                 }
@@ -189,14 +550,52 @@ where
     fn reset(&mut self) {
         info!("Reset");
         self.devices.get_mut().reset();
+        self.configured = false;
+        self.suspended = false;
+        self.in_endpoint_wait = 0.millis();
+        self.in_endpoint_stalled = false;
+    }
+
+    fn endpoint_in_complete(&mut self, addr: EndpointAddress) {
+        self.devices.get_mut().endpoint_in_complete(addr);
     }
 
     fn control_out(&mut self, transfer: ControlOut<B>) {
         let request: &Request = transfer.request();
 
+        //observe endpoint halts being cleared, usb-device clears the stall itself
+        if request.request_type == RequestType::Standard
+            && request.recipient == Recipient::Endpoint
+            && request.request == Request::CLEAR_FEATURE
+            && request.value == Request::FEATURE_ENDPOINT_HALT
+        {
+            let address = EndpointAddress::from((request.index & 0x8F) as u8);
+            if !self.devices.get_mut().endpoint_halt_cleared(address) {
+                trace!("Endpoint halt cleared on a non HID endpoint");
+            }
+            return;
+        }
+
+        //observe the host selecting a configuration, usb-device answers the request itself
+        if request.request_type == RequestType::Standard
+            && request.recipient == Recipient::Device
+            && request.request == Request::SET_CONFIGURATION
+        {
+            if request.value == 0 {
+                //deconfigured, start over as after a bus reset
+                info!("Deconfigured");
+                UsbClass::reset(self);
+            } else {
+                info!("Configured");
+                self.configured = true;
+                self.devices.get_mut().configured();
+            }
+            return;
+        }
+
         //only respond to Class requests for this interface
         if !(request.request_type == RequestType::Class
-            && request.recipient == Recipient::Interface)
+            && self.strictness.accepts_recipient(request.recipient))
         {
             return;
         }
@@ -214,26 +613,35 @@ where
 
         match HidRequest::try_from(request.request) {
             Ok(HidRequest::SetReport) => {
-                interface.set_report(transfer.data()).ok();
-                transfer.accept().ok();
+                let [report_id, report_type] = request.value.to_le_bytes();
+                let feature = report_type == REPORT_TYPE_FEATURE;
+                if feature && interface.static_feature_report(report_id).is_some() {
+                    transfer.accept().ok();
+                } else if feature && interface.get_feature_report(report_id, &mut []).is_some() {
+                    //rejected rather than dropped if longer than the report
+                    if interface.set_feature_report(report_id, transfer.data()) {
+                        transfer.accept().ok();
+                    } else {
+                        transfer.reject().ok();
+                    }
+                } else {
+                    interface.set_report(transfer.data()).ok();
+                    transfer.accept().ok();
+                }
             }
             Ok(HidRequest::SetIdle) => {
-                if request.length != 0 {
-                    warn!(
-                        "Expected SetIdle to have length 0, received {}",
-                        request.length
-                    );
+                if !self.strictness.accepts_length(request, 0) {
+                    transfer.reject().ok();
+                    return;
                 }
 
                 interface.set_idle((request.value & 0xFF) as u8, (request.value >> 8) as u8);
                 transfer.accept().ok();
             }
             Ok(HidRequest::SetProtocol) => {
-                if request.length != 0 {
-                    warn!(
-                        "Expected SetProtocol to have length 0, received {}",
-                        request.length
-                    );
+                if !self.strictness.accepts_length(request, 0) {
+                    transfer.reject().ok();
+                    return;
                 }
                 if let Ok(protocol) = HidProtocol::try_from((request.value & 0xFF) as u8) {
                     interface.set_protocol(protocol);
@@ -256,8 +664,23 @@ where
 
     fn control_in(&mut self, transfer: ControlIn<B>) {
         let request: &Request = transfer.request();
+
+        //answer for string descriptor 0 before usb-device does, if a LANGID table is configured
+        if request.request_type == RequestType::Standard
+            && request.recipient == Recipient::Device
+            && request.request == Request::GET_DESCRIPTOR
+            && request.value == u16::from(descriptor_type::STRING) << 8
+            && !self.lang_ids.is_empty()
+        {
+            Self::get_lang_ids(self.lang_ids, transfer);
+            return;
+        }
+
         //only respond to requests for this interface
-        if !(request.recipient == Recipient::Interface) {
+        if !(request.recipient == Recipient::Interface
+            || (request.request_type == RequestType::Class
+                && self.strictness.accepts_recipient(request.recipient)))
+        {
             return;
         }
 
@@ -288,30 +711,11 @@ where
                 };
 
                 match HidRequest::try_from(request.request) {
-                    Ok(HidRequest::GetReport) => {
-                        let mut data = [0_u8; 64];
-                        if let Ok(n) = interface.get_report(&mut data) {
-                            if n != transfer.request().length.into() {
-                                warn!(
-                                    "GetReport expected {} bytes, got {} bytes",
-                                    transfer.request().length,
-                                    data.len()
-                                );
-                            }
-                            if let Err(e) = transfer.accept_with(&data[..n]) {
-                                error!("Failed to send report - {:?}", e);
-                            } else {
-                                trace!("Sent report, {} bytes", n);
-                                unwrap!(interface.get_report_ack());
-                            }
-                        }
-                    }
+                    Ok(HidRequest::GetReport) => Self::get_report(transfer, interface),
                     Ok(HidRequest::GetIdle) => {
-                        if request.length != 1 {
-                            warn!(
-                                "Expected GetIdle to have length 1, received {}",
-                                request.length
-                            );
+                        if !self.strictness.accepts_length(request, 1) {
+                            transfer.reject().ok();
+                            return;
                         }
 
                         let report_id = (request.value & 0xFF) as u8;
@@ -323,11 +727,9 @@ where
                         }
                     }
                     Ok(HidRequest::GetProtocol) => {
-                        if request.length != 1 {
-                            warn!(
-                                "Expected GetProtocol to have length 1, received {}",
-                                request.length
-                            );
+                        if !self.strictness.accepts_length(request, 1) {
+                            transfer.reject().ok();
+                            return;
                         }
 
                         let protocol = interface.get_protocol();
@@ -359,8 +761,43 @@ mod test {
     use std::sync::Mutex;
     use std::vec::Vec;
 
-    use crate::descriptor::USB_CLASS_HID;
-    use crate::interface::{InBytes64, InterfaceBuilder, OutBytes64, ReportSingle, Reports8};
+    use crate::descriptor::{report_descriptor_hash, DESCRIPTOR_HASH_FEATURE_ITEMS, USB_CLASS_HID};
+    use crate::device::consumer::usage_list::{
+        ConsumerUsageList, ConsumerUsageListConfig, ConsumerUsageListDescriptor,
+    };
+    use crate::device::consumer::ConsumerCode;
+    use crate::device::gamepad::autofire::{
+        AutofireGamepad, AutofireGamepadConfig, AutofireSettings,
+    };
+    use crate::device::gamepad::GamepadReport;
+    use crate::device::keyboard::remap::{RemapTable, REMAP_SET};
+    use crate::device::keyboard::{
+        BootKeyboard, BootKeyboardConfig, NKROBootKeyboard, NKROBootKeyboardConfig,
+    };
+    use crate::device::mouse::{
+        BootMouse, BootMouseConfig, BootMouseReport, HiResWheelMouse, HiResWheelMouseConfig,
+        ScrollWheel, ScrollWheelConfig, ScrollWheelReport, WheelMouse, WheelMouseConfig,
+        WheelMouseReport,
+    };
+    use crate::device::pointing_stick::{
+        keyboard_pointing_stick, KeyboardPointingStick, MiddleButtonScroll, MIDDLE_BUTTON,
+    };
+    use crate::device::sensor::{
+        ConnectionType, ReportingState, SensorSettings, SensorState, TemperatureSensor,
+        TemperatureSensorConfig, TEMPERATURE_REPORT_LEN,
+    };
+    use crate::device::touchpad::{
+        InputMode, Touchpad, TouchpadConfig, TouchpadContact, TouchpadReport,
+        CAPABILITIES_REPORT_ID, CERTIFICATION_REPORT_ID, EMPTY_CERTIFICATION_REPORT,
+        FUNCTION_SWITCH_REPORT_ID, INPUT_MODE_REPORT_ID, TOUCHPAD_REPORT_ID,
+    };
+    use crate::device::ups::{PresentStatus, Ups, UpsConfig, UpsStatus};
+    use crate::interface::{
+        InBytes64, InBytes8, Interface, InterfaceBuilder, InterfaceError, InterfaceOperation,
+        OutBytes64, OutBytes8, OutNone, OutQueue, OutReportMode, ReportSingle, Reports8,
+        MAX_WRITE_REPORT_LATENCY,
+    };
+    use crate::page::{Consumer, Keyboard};
     use env_logger::Env;
     use fugit::MillisDurationU32;
     use log::SetLoggerError;
@@ -382,6 +819,8 @@ mod test {
     struct UsbTestManager {
         in_buf: Mutex<RefCell<Vec<u8>>>,
         setup_buf: Mutex<RefCell<Vec<u8>>>,
+        /// Device writes to IN endpoints, including those failing with `WouldBlock`
+        in_writes: Mutex<RefCell<usize>>,
     }
 
     impl UsbTestManager {
@@ -414,7 +853,12 @@ mod test {
             }
         }
 
+        fn in_writes(&self) -> usize {
+            *self.in_writes.lock().unwrap().borrow()
+        }
+
         fn device_write(&self, data: &[u8]) -> Result<usize> {
+            *self.in_writes.lock().unwrap().borrow_mut() += 1;
             let buf = self.in_buf.lock().unwrap();
             if buf.borrow().is_empty() {
                 buf.borrow_mut().extend_from_slice(data);
@@ -1309,4 +1753,1729 @@ mod test {
             "Unexpected report idle value"
         );
     }
+
+    fn get_idle_request(report_id: u8) -> [u8; 8] {
+        UsbRequest {
+            direction: UsbDirection::In != UsbDirection::Out,
+            request_type: RequestType::Class as u8,
+            recipient: Recipient::Interface as u8,
+            request: HidRequest::GetIdle.into(),
+            value: u16::from(report_id),
+            index: 0x0,
+            length: 0x1,
+        }
+        .pack()
+        .unwrap()
+    }
+
+    fn set_idle_request(report_id: u8, idle: MillisDurationU32) -> [u8; 8] {
+        UsbRequest {
+            // host to device
+            direction: false,
+            request_type: RequestType::Class as u8,
+            recipient: Recipient::Interface as u8,
+            request: HidRequest::SetIdle.into(),
+            value: (u16::try_from(idle.to_millis()).unwrap() / 4) << 8 | u16::from(report_id),
+            index: 0x0,
+            length: 0x0,
+        }
+        .pack()
+        .unwrap()
+    }
+
+    #[test]
+    fn get_report_idle_per_report_id() {
+        const IDLE_DEFAULT: MillisDurationU32 = MillisDurationU32::millis(40);
+        const IDLE_GLOBAL: MillisDurationU32 = MillisDurationU32::millis(100);
+
+        init_logging();
+
+        let manager = UsbTestManager::default();
+
+        let usb_alloc = UsbBusAllocator::new(TestUsbBus::new(&manager));
+
+        let mut hid = UsbHidClassBuilder::new()
+            .add_device(
+                InterfaceBuilder::<InBytes64, OutBytes64, Reports8>::new(&[])
+                    .unwrap()
+                    .idle_default(IDLE_DEFAULT)
+                    .unwrap()
+                    .build(),
+            )
+            .build(&usb_alloc);
+
+        let mut usb_dev = UsbDeviceBuilder::new(&usb_alloc, UsbVidPid(0x1209, 0x0001))
+            .device_class(USB_CLASS_HID)
+            .build();
+
+        for (report_id, idle) in [(1, 8), (3, 1020), (8, 0)] {
+            manager
+                .host_write_setup(&set_idle_request(
+                    report_id,
+                    MillisDurationU32::millis(idle),
+                ))
+                .unwrap();
+            assert!(usb_dev.poll(&mut [&mut hid]));
+        }
+
+        // Report IDs without their own idle rate fall back to the global idle rate
+        for (report_id, expected) in [(0, 10), (1, 2), (2, 10), (3, 255), (8, 0), (9, 10)] {
+            manager
+                .host_write_setup(&get_idle_request(report_id))
+                .unwrap();
+            assert!(usb_dev.poll(&mut [&mut hid]));
+            assert_eq!(
+                manager.host_read_in(),
+                [expected],
+                "Unexpected idle value for report ID {report_id}"
+            );
+        }
+
+        let interface = hid.device::<Interface<_, InBytes64, OutBytes64, Reports8>, _>();
+        assert_eq!(interface.report_idle(1), Some(MillisDurationU32::millis(8)));
+        assert_eq!(interface.report_idle(2), None);
+        assert_eq!(
+            interface.report_idle(3),
+            Some(MillisDurationU32::millis(1020))
+        );
+
+        // Setting the global idle rate applies to all reports
+        manager
+            .host_write_setup(&set_idle_request(0, IDLE_GLOBAL))
+            .unwrap();
+        assert!(usb_dev.poll(&mut [&mut hid]));
+
+        for report_id in [0, 1, 3] {
+            manager
+                .host_write_setup(&get_idle_request(report_id))
+                .unwrap();
+            assert!(usb_dev.poll(&mut [&mut hid]));
+            assert_eq!(
+                manager.host_read_in(),
+                [u8::try_from(IDLE_GLOBAL.ticks()).unwrap() / 4],
+                "Unexpected idle value for report ID {report_id}"
+            );
+        }
+    }
+
+    #[test]
+    fn idle_default_rounds_to_4ms_units() {
+        init_logging();
+
+        for (idle, expected) in [(0, 0), (1, 1), (3, 1), (4, 1), (7, 1), (8, 2), (1023, 255)] {
+            let manager = UsbTestManager::default();
+
+            let usb_alloc = UsbBusAllocator::new(TestUsbBus::new(&manager));
+
+            let mut hid = UsbHidClassBuilder::new()
+                .add_device(
+                    InterfaceBuilder::<InBytes64, OutBytes64, ReportSingle>::new(&[])
+                        .unwrap()
+                        .idle_default(MillisDurationU32::millis(idle))
+                        .unwrap()
+                        .build(),
+                )
+                .build(&usb_alloc);
+
+            let mut usb_dev = UsbDeviceBuilder::new(&usb_alloc, UsbVidPid(0x1209, 0x0001))
+                .device_class(USB_CLASS_HID)
+                .build();
+
+            manager.host_write_setup(&get_idle_request(0)).unwrap();
+            assert!(usb_dev.poll(&mut [&mut hid]));
+            assert_eq!(
+                manager.host_read_in(),
+                [expected],
+                "Unexpected idle value for {idle}ms"
+            );
+        }
+
+        assert!(matches!(
+            InterfaceBuilder::<InBytes64, OutBytes64, ReportSingle>::new(&[])
+                .unwrap()
+                .idle_default(MillisDurationU32::millis(1024)),
+            Err(UsbHidBuilderError::ValueOverflow)
+        ));
+    }
+
+    #[test]
+    fn request_strictness() {
+        const IDLE_DEFAULT: MillisDurationU32 = MillisDurationU32::millis(40);
+        const IDLE: u8 = 10;
+
+        init_logging();
+
+        // (strictness, recipient, length, expect response)
+        for (strictness, recipient, length, expected) in [
+            (RequestStrictness::Strict, Recipient::Interface, 1, true),
+            (RequestStrictness::Strict, Recipient::Interface, 2, false),
+            (RequestStrictness::Strict, Recipient::Device, 1, false),
+            (RequestStrictness::Tolerant, Recipient::Interface, 2, true),
+            (RequestStrictness::Tolerant, Recipient::Device, 1, false),
+            (RequestStrictness::Lenient, Recipient::Interface, 2, true),
+            (RequestStrictness::Lenient, Recipient::Device, 1, true),
+            (RequestStrictness::Lenient, Recipient::Endpoint, 1, false),
+        ] {
+            let manager = UsbTestManager::default();
+
+            let usb_alloc = UsbBusAllocator::new(TestUsbBus::new(&manager));
+
+            let mut hid = UsbHidClassBuilder::new()
+                .add_device(
+                    InterfaceBuilder::<InBytes64, OutBytes64, ReportSingle>::new(&[])
+                        .unwrap()
+                        .idle_default(IDLE_DEFAULT)
+                        .unwrap()
+                        .build(),
+                )
+                .strictness(strictness)
+                .build(&usb_alloc);
+
+            let mut usb_dev = UsbDeviceBuilder::new(&usb_alloc, UsbVidPid(0x1209, 0x0001))
+                .device_class(USB_CLASS_HID)
+                .build();
+
+            manager
+                .host_write_setup(
+                    &UsbRequest {
+                        direction: UsbDirection::In != UsbDirection::Out,
+                        request_type: RequestType::Class as u8,
+                        recipient: recipient as u8,
+                        request: HidRequest::GetIdle.into(),
+                        value: 0x0,
+                        index: 0x0,
+                        length,
+                    }
+                    .pack()
+                    .unwrap(),
+                )
+                .unwrap();
+
+            usb_dev.poll(&mut [&mut hid]);
+
+            let data = manager.host_read_in();
+            if expected {
+                assert_eq!(
+                    data,
+                    [IDLE],
+                    "Expected response for {strictness:?} {recipient:?} length {length}"
+                );
+            } else {
+                assert!(
+                    data.is_empty(),
+                    "Unexpected response for {strictness:?} {recipient:?} length {length}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn localized_description() {
+        init_logging();
+
+        let manager = UsbTestManager::default();
+
+        let usb_alloc = UsbBusAllocator::new(TestUsbBus::new(&manager));
+
+        let mut hid = UsbHidClassBuilder::new()
+            .add_device(
+                InterfaceBuilder::<InBytes64, OutBytes64, ReportSingle>::new(&[])
+                    .unwrap()
+                    .description("Key")
+                    .localized_descriptions(&[(0x0407, "Tast")])
+                    .build(),
+            )
+            .lang_ids(&[0x0409, 0x0407])
+            .unwrap()
+            .build(&usb_alloc);
+
+        let mut usb_dev = UsbDeviceBuilder::new(&usb_alloc, UsbVidPid(0x1209, 0x0001))
+            .device_class(USB_CLASS_HID)
+            .build();
+
+        let mut get_string = |index: u8, lang_id: u16| {
+            manager
+                .host_write_setup(
+                    &UsbRequest {
+                        direction: UsbDirection::In != UsbDirection::Out,
+                        request_type: RequestType::Standard as u8,
+                        recipient: Recipient::Device as u8,
+                        request: Request::GET_DESCRIPTOR,
+                        value: u16::from(descriptor_type::STRING) << 8 | u16::from(index),
+                        index: lang_id,
+                        length: 0xFF,
+                    }
+                    .pack()
+                    .unwrap(),
+                )
+                .unwrap();
+
+            assert!(usb_dev.poll(&mut [&mut hid]));
+
+            let mut data = Vec::new();
+            loop {
+                let read = manager.host_read_in();
+                if read.is_empty() {
+                    break;
+                }
+                data.extend_from_slice(&read);
+                assert!(usb_dev.poll(&mut [&mut hid]));
+            }
+            data
+        };
+
+        assert_eq!(get_string(0, 0), [6, 3, 0x09, 0x04, 0x07, 0x04]);
+        assert_eq!(get_string(4, 0x0409), [8, 3, b'K', 0, b'e', 0, b'y', 0]);
+        assert_eq!(
+            get_string(4, 0x0407),
+            [10, 3, b'T', 0, b'a', 0, b's', 0, b't', 0]
+        );
+
+        assert!(matches!(
+            UsbHidClassBuilder::<TestUsbBus, _>::new().lang_ids(&[0x0409; MAX_LANG_IDS + 1]),
+            Err(UsbHidBuilderError::SliceLengthOverflow)
+        ));
+    }
+
+    #[test]
+    fn static_report_descriptor_is_streamed() {
+        static DESCRIPTOR: [u8; 200] = {
+            let mut descriptor = [0u8; 200];
+            let mut i = 1;
+            while i < descriptor.len() {
+                descriptor[i] = descriptor[i - 1].wrapping_add(1);
+                i += 1;
+            }
+            descriptor
+        };
+
+        init_logging();
+
+        let manager = UsbTestManager::default();
+        let usb_alloc = UsbBusAllocator::new(TestUsbBus::new(&manager));
+
+        let mut hid = UsbHidClassBuilder::new()
+            .add_device(
+                InterfaceBuilder::<InBytes64, OutBytes64, ReportSingle>::new_static(&DESCRIPTOR)
+                    .unwrap()
+                    .build(),
+            )
+            .build(&usb_alloc);
+
+        let mut usb_dev = UsbDeviceBuilder::new(&usb_alloc, UsbVidPid(0x1209, 0x0001))
+            .device_class(USB_CLASS_HID)
+            .build();
+
+        manager
+            .host_write_setup(
+                &UsbRequest {
+                    direction: UsbDirection::In != UsbDirection::Out,
+                    request_type: RequestType::Standard as u8,
+                    recipient: Recipient::Interface as u8,
+                    request: Request::GET_DESCRIPTOR,
+                    value: u16::from(u8::from(DescriptorType::Report)) << 8,
+                    index: 0x0,
+                    length: 0xFF,
+                }
+                .pack()
+                .unwrap(),
+            )
+            .unwrap();
+
+        assert!(usb_dev.poll(&mut [&mut hid]));
+
+        // Longer than the control pipe buffer, so only possible without a copy
+        let mut data = Vec::new();
+        loop {
+            let read = manager.host_read_in();
+            if read.is_empty() {
+                break;
+            }
+            data.extend_from_slice(&read);
+            assert!(usb_dev.poll(&mut [&mut hid]));
+        }
+        assert_eq!(data, DESCRIPTOR);
+    }
+
+    #[test]
+    fn descriptor_hash_feature_report() {
+        init_logging();
+
+        let manager = UsbTestManager::default();
+        let usb_alloc = UsbBusAllocator::new(TestUsbBus::new(&manager));
+
+        let mut descriptor = std::vec![0x85, 0x07];
+        descriptor.extend_from_slice(DESCRIPTOR_HASH_FEATURE_ITEMS);
+        let mut hid = UsbHidClassBuilder::new()
+            .add_device(
+                InterfaceBuilder::<InBytes8, OutNone, ReportSingle>::new(&descriptor)
+                    .unwrap()
+                    .descriptor_hash_report(7)
+                    .build(),
+            )
+            .build(&usb_alloc);
+
+        let mut usb_dev = UsbDeviceBuilder::new(&usb_alloc, UsbVidPid(0x1209, 0x0001))
+            .device_class(USB_CLASS_HID)
+            .build();
+
+        manager
+            .host_write_setup(
+                &UsbRequest {
+                    direction: UsbDirection::In != UsbDirection::Out,
+                    request_type: RequestType::Class as u8,
+                    recipient: Recipient::Interface as u8,
+                    request: HidRequest::GetReport.into(),
+                    value: u16::from(REPORT_TYPE_FEATURE) << 8 | 0x07,
+                    index: 0x0,
+                    length: 0x5,
+                }
+                .pack()
+                .unwrap(),
+            )
+            .unwrap();
+
+        assert!(usb_dev.poll(&mut [&mut hid]));
+
+        let hash = report_descriptor_hash(&descriptor);
+        let interface = hid.device::<Interface<_, InBytes8, OutNone, ReportSingle>, _>();
+        assert_eq!(interface.report_descriptor_hash(), hash);
+
+        let data = manager.host_read_in();
+        assert_eq!(data[0], 0x07);
+        assert_eq!(data[1..], hash.to_le_bytes());
+        assert_eq!(report_descriptor_hash(b"a"), 0xE40C_292C);
+    }
+
+    #[test]
+    fn feature_report_sized_from_descriptor() {
+        #[rustfmt::skip]
+        const DESCRIPTOR: &[u8] = &[
+            0x06, 0x00, 0xFF, // Usage Page (Vendor Defined 0xFF00),
+            0x09, 0x01,       // Usage (0x01),
+            0xA1, 0x01,       // Collection (Application),
+            0x85, 0x05,       //     Report ID (5),
+            0x09, 0x02,       //     Usage (0x02),
+            0x75, 0x08,       //     Report Size (8),
+            0x95, 0x0B,       //     Report Count (11),
+            0xB1, 0x02,       //     Feature (Variable),
+            0xC0,             // End Collection
+        ];
+
+        init_logging();
+
+        let manager = UsbTestManager::default();
+        let usb_alloc = UsbBusAllocator::new(TestUsbBus::new(&manager));
+
+        let initial = [5; 12];
+        let mut hid = UsbHidClassBuilder::new()
+            .add_device(
+                InterfaceBuilder::<InBytes8, OutBytes8, ReportSingle>::new(DESCRIPTOR)
+                    .unwrap()
+                    .feature_report(5, &initial)
+                    .unwrap()
+                    .build(),
+            )
+            .build(&usb_alloc);
+
+        let interface = hid.device::<Interface<_, InBytes8, OutBytes8, ReportSingle>, _>();
+        assert_eq!(interface.feature_report_with_id(5), Some(&initial[..]));
+
+        let mut data = [5; 13];
+        data[1] = 1;
+        assert!(!interface.set_feature_report(5, &data));
+        assert_eq!(interface.feature_report_with_id(5), Some(&initial[..]));
+        assert_eq!(
+            interface.health().last_error.map(|e| e.operation),
+            Some(InterfaceOperation::SetReport)
+        );
+        assert!(interface.set_feature_report(5, &data[..12]));
+        assert_eq!(interface.feature_report_with_id(5), Some(&data[..12]));
+
+        assert!(matches!(
+            InterfaceBuilder::<InBytes8, OutBytes8, ReportSingle>::new(DESCRIPTOR)
+                .unwrap()
+                .feature_report(5, &[5; 13]),
+            Err(UsbHidBuilderError::SliceLengthOverflow)
+        ));
+    }
+
+    #[test]
+    fn devices_as_trait_objects() {
+        init_logging();
+
+        let manager = UsbTestManager::default();
+        let usb_alloc = UsbBusAllocator::new(TestUsbBus::new(&manager));
+
+        let mut hid = UsbHidClassBuilder::new()
+            .add_device(BootKeyboardConfig::default())
+            .add_device(BootMouseConfig::default())
+            .build(&usb_alloc);
+
+        let mut devices = hid.devices_dyn::<4>().unwrap();
+        let ids: Vec<u8> = devices
+            .iter_mut()
+            .map(|d| u8::from(d.interface_dyn().id()))
+            .collect();
+        assert_eq!(ids, [0, 1]);
+        for device in &mut devices {
+            device.tick().unwrap();
+        }
+        drop(devices);
+
+        assert!(matches!(
+            hid.devices_dyn::<1>(),
+            Err(UsbHidError::UsbError(UsbError::BufferOverflow))
+        ));
+    }
+
+    #[test]
+    fn trait_object_tick_advances_interface_clock() {
+        init_logging();
+
+        let manager = UsbTestManager::default();
+        let usb_alloc = UsbBusAllocator::new(TestUsbBus::new(&manager));
+
+        let mut hid = UsbHidClassBuilder::new()
+            .add_device(
+                InterfaceBuilder::<InBytes64, OutBytes64, ReportSingle>::new(&[])
+                    .unwrap()
+                    .build(),
+            )
+            .build(&usb_alloc);
+
+        for _ in 0..3 {
+            for device in &mut hid.devices_dyn::<1>().unwrap() {
+                device.tick().unwrap();
+            }
+        }
+
+        let interface = hid.device::<Interface<_, InBytes64, OutBytes64, ReportSingle>, _>();
+        assert_eq!(interface.uptime(), MillisDurationU32::millis(3));
+    }
+
+    #[test]
+    fn nkro_keyboard_boot_protocol_report() {
+        init_logging();
+
+        let manager = UsbTestManager::default();
+        let usb_alloc = UsbBusAllocator::new(TestUsbBus::new(&manager));
+
+        let mut hid = UsbHidClassBuilder::new()
+            .add_device(NKROBootKeyboardConfig::default())
+            .build(&usb_alloc);
+
+        let mut usb_dev = UsbDeviceBuilder::new(&usb_alloc, UsbVidPid(0x1209, 0x0001)).build();
+
+        let keyboard = hid.device::<NKROBootKeyboard<_>, _>();
+        keyboard.write_report([Keyboard::A]).unwrap();
+        assert_eq!(manager.host_read_in().len(), 25);
+
+        manager
+            .host_write_setup(
+                &UsbRequest {
+                    direction: false,
+                    request_type: RequestType::Class as u8,
+                    recipient: Recipient::Interface as u8,
+                    request: HidRequest::SetProtocol.into(),
+                    value: HidProtocol::Boot as u16,
+                    index: 0x0,
+                    length: 0x0,
+                }
+                .pack()
+                .unwrap(),
+            )
+            .unwrap();
+        assert!(usb_dev.poll(&mut [&mut hid]));
+
+        let keyboard = hid.device::<NKROBootKeyboard<_>, _>();
+        keyboard.write_report([Keyboard::B]).unwrap();
+        assert_eq!(
+            manager.host_read_in(),
+            [0, 0, u8::from(Keyboard::B), 0, 0, 0, 0, 0]
+        );
+    }
+
+    #[test]
+    fn nkro_keyboard_report_protocol_after_deconfiguration() {
+        init_logging();
+
+        let manager = UsbTestManager::default();
+        let usb_alloc = UsbBusAllocator::new(TestUsbBus::new(&manager));
+
+        let mut hid = UsbHidClassBuilder::new()
+            .add_device(NKROBootKeyboardConfig::default())
+            .build(&usb_alloc);
+
+        let mut usb_dev = UsbDeviceBuilder::new(&usb_alloc, UsbVidPid(0x1209, 0x0001)).build();
+
+        let set_boot_protocol = UsbRequest {
+            direction: false,
+            request_type: RequestType::Class as u8,
+            recipient: Recipient::Interface as u8,
+            request: HidRequest::SetProtocol.into(),
+            value: HidProtocol::Boot as u16,
+            index: 0x0,
+            length: 0x0,
+        }
+        .pack()
+        .unwrap();
+        let deconfigure = UsbRequest {
+            direction: false,
+            request_type: RequestType::Standard as u8,
+            recipient: Recipient::Device as u8,
+            request: Request::SET_CONFIGURATION,
+            value: 0,
+            index: 0,
+            length: 0,
+        }
+        .pack()
+        .unwrap();
+
+        manager.host_write_setup(&set_boot_protocol).unwrap();
+        assert!(usb_dev.poll(&mut [&mut hid]));
+        manager.host_write_setup(&deconfigure).unwrap();
+        usb_dev.poll(&mut [&mut hid]);
+
+        let keyboard = hid.device::<NKROBootKeyboard<_>, _>();
+        assert_eq!(keyboard.interface().protocol(), HidProtocol::Report);
+        keyboard.write_report([Keyboard::A]).unwrap();
+        assert_eq!(manager.host_read_in().len(), 25);
+
+        manager.host_write_setup(&set_boot_protocol).unwrap();
+        assert!(usb_dev.poll(&mut [&mut hid]));
+        UsbClass::reset(&mut hid);
+
+        let keyboard = hid.device::<NKROBootKeyboard<_>, _>();
+        keyboard.write_report([Keyboard::B]).unwrap();
+        assert_eq!(manager.host_read_in().len(), 25);
+    }
+
+    #[test]
+    fn mouse_boot_protocol_report() {
+        init_logging();
+
+        let manager = UsbTestManager::default();
+        let usb_alloc = UsbBusAllocator::new(TestUsbBus::new(&manager));
+
+        let mut hid = UsbHidClassBuilder::new()
+            .add_device(BootMouseConfig::default())
+            .add_device(WheelMouseConfig::default())
+            .build(&usb_alloc);
+
+        let mut usb_dev = UsbDeviceBuilder::new(&usb_alloc, UsbVidPid(0x1209, 0x0001)).build();
+
+        let report = BootMouseReport::new().left(true).middle(true).x(-2).y(3);
+        assert_eq!(report.buttons, 0b101);
+        assert_eq!(report.right(true).left(false).buttons, 0b110);
+
+        let mouse = hid.device::<BootMouse<_>, _>();
+        assert_eq!(mouse.protocol(), HidProtocol::Report);
+        mouse.write_report(&report).unwrap();
+        assert_eq!(manager.host_read_in(), [0b101, 0xFE, 3]);
+
+        let wheel = WheelMouseReport {
+            buttons: 1,
+            x: 4,
+            y: 5,
+            vertical_wheel: 1,
+            horizontal_wheel: 0,
+        };
+        hid.device::<WheelMouse<_>, _>()
+            .write_report(&wheel)
+            .unwrap();
+        assert_eq!(manager.host_read_in().len(), 5);
+
+        for interface in 0..2 {
+            manager
+                .host_write_setup(
+                    &UsbRequest {
+                        direction: false,
+                        request_type: RequestType::Class as u8,
+                        recipient: Recipient::Interface as u8,
+                        request: HidRequest::SetProtocol.into(),
+                        value: HidProtocol::Boot as u16,
+                        index: interface,
+                        length: 0x0,
+                    }
+                    .pack()
+                    .unwrap(),
+                )
+                .unwrap();
+            assert!(usb_dev.poll(&mut [&mut hid]));
+        }
+
+        let mouse = hid.device::<BootMouse<_>, _>();
+        assert_eq!(mouse.protocol(), HidProtocol::Boot);
+        mouse.write_report(&report).unwrap();
+        assert_eq!(manager.host_read_in(), [0b101, 0xFE, 3]);
+
+        hid.device::<WheelMouse<_>, _>()
+            .write_report(&wheel)
+            .unwrap();
+        assert_eq!(manager.host_read_in(), [1, 4, 5]);
+    }
+
+    #[test]
+    fn consumer_usage_list_report() {
+        init_logging();
+
+        let manager = UsbTestManager::default();
+        let usb_alloc = UsbBusAllocator::new(TestUsbBus::new(&manager));
+
+        let usages: Vec<ConsumerCode> = (0xB0..0xBA)
+            .map(ConsumerCode)
+            .chain([Consumer::Mute.into()])
+            .collect();
+        let descriptor = ConsumerUsageListDescriptor::new(&usages).unwrap();
+
+        let mut hid = UsbHidClassBuilder::new()
+            .add_device(ConsumerUsageListConfig::for_descriptor(&descriptor))
+            .build(&usb_alloc);
+
+        let _usb_dev = UsbDeviceBuilder::new(&usb_alloc, UsbVidPid(0x1209, 0x0001)).build();
+
+        let consumer = hid.device::<ConsumerUsageList<_>, _>();
+        consumer.press(Consumer::Mute).unwrap();
+        assert_eq!(manager.host_read_in(), [0x00, 0x04]);
+        consumer.press(ConsumerCode(0xB1)).unwrap();
+        assert_eq!(manager.host_read_in(), [0x02, 0x04]);
+        assert!(consumer.is_pressed(Consumer::Mute));
+        consumer.release(Consumer::Mute).unwrap();
+        assert_eq!(manager.host_read_in(), [0x02, 0x00]);
+        assert!(matches!(
+            consumer.press(Consumer::PlayPause),
+            Err(UsbHidError::SerializationError)
+        ));
+        consumer.release_all().unwrap();
+        assert_eq!(manager.host_read_in(), [0x00, 0x00]);
+    }
+
+    #[test]
+    fn hi_res_wheel_mouse_resolution_multiplier() {
+        init_logging();
+
+        let manager = UsbTestManager::default();
+        let usb_alloc = UsbBusAllocator::new(TestUsbBus::new(&manager));
+
+        let mut hid = UsbHidClassBuilder::new()
+            .add_device(HiResWheelMouseConfig::default())
+            .build(&usb_alloc);
+
+        let mut usb_dev = UsbDeviceBuilder::new(&usb_alloc, UsbVidPid(0x1209, 0x0001)).build();
+
+        let get_multipliers = UsbRequest {
+            direction: true,
+            request_type: RequestType::Class as u8,
+            recipient: Recipient::Interface as u8,
+            request: HidRequest::GetReport.into(),
+            value: u16::from(REPORT_TYPE_FEATURE) << 8 | 0x02,
+            index: 0x0,
+            length: 0x2,
+        }
+        .pack()
+        .unwrap();
+        manager.host_write_setup(&get_multipliers).unwrap();
+        assert!(usb_dev.poll(&mut [&mut hid]));
+        assert_eq!(manager.host_read_in(), [0x02, 0x00]);
+
+        // Low resolution, whole detents only
+        let mouse = hid.device::<HiResWheelMouse<_>, _>();
+        let scroll = WheelMouseReport {
+            vertical_wheel: 3,
+            horizontal_wheel: -5,
+            ..WheelMouseReport::new()
+        };
+        mouse.write_report(&scroll).unwrap();
+        assert_eq!(manager.host_read_in(), [0x01, 0, 0, 0, 0, 0xFF]);
+        mouse.write_report(&scroll).unwrap();
+        assert_eq!(manager.host_read_in(), [0x01, 0, 0, 0, 1, 0xFF]);
+
+        // Host enables the multiplier for the vertical wheel only
+        assert!(mouse.interface().set_feature_report(0x02, &[0x02, 0b01]));
+        assert!(!mouse.interface().set_feature_report(0x03, &[0x03, 0b01]));
+        assert!(mouse.vertical_hi_res());
+        assert!(!mouse.horizontal_hi_res());
+        mouse.write_report(&scroll).unwrap();
+        assert_eq!(manager.host_read_in(), [0x01, 0, 0, 0, 3, 0xFF]);
+
+        manager.host_write_setup(&get_multipliers).unwrap();
+        assert!(usb_dev.poll(&mut [&mut hid]));
+        assert_eq!(manager.host_read_in(), [0x02, 0b01]);
+
+        // Bus reset returns to low resolution
+        InterfaceClass::reset(hid.device::<HiResWheelMouse<_>, _>().interface());
+        assert!(!hid.device::<HiResWheelMouse<_>, _>().vertical_hi_res());
+    }
+
+    #[test]
+    fn scroll_wheel_report() {
+        init_logging();
+
+        let manager = UsbTestManager::default();
+        let usb_alloc = UsbBusAllocator::new(TestUsbBus::new(&manager));
+
+        let mut hid = UsbHidClassBuilder::new()
+            .add_device(ScrollWheelConfig::default())
+            .build(&usb_alloc);
+
+        let _usb_dev = UsbDeviceBuilder::new(&usb_alloc, UsbVidPid(0x1209, 0x0001)).build();
+
+        let wheel = hid.device::<ScrollWheel<_>, _>();
+        let scroll = ScrollWheelReport::new()
+            .buttons(0xFF)
+            .scroll_v(-6)
+            .scroll_h(4);
+        wheel.write_report(&scroll).unwrap();
+        assert_eq!(manager.host_read_in(), [0x01, 0b11, 0xFF, 1]);
+        wheel
+            .write_report(&ScrollWheelReport::new().scroll_v(-2))
+            .unwrap();
+        assert_eq!(manager.host_read_in(), [0x01, 0, 0xFF, 0]);
+
+        assert!(wheel.interface().set_feature_report(0x02, &[0x02, 0b0101]));
+        assert!(wheel.vertical_hi_res());
+        assert!(wheel.horizontal_hi_res());
+        wheel.write_report(&scroll).unwrap();
+        assert_eq!(manager.host_read_in(), [0x01, 0b11, 0xFA, 4]);
+    }
+
+    #[test]
+    fn keyboard_pointing_stick_preset() {
+        init_logging();
+
+        let manager = UsbTestManager::default();
+        let usb_alloc = UsbBusAllocator::new(TestUsbBus::new(&manager));
+
+        let mut hid: KeyboardPointingStick<_> = keyboard_pointing_stick().build(&usb_alloc);
+
+        let _usb_dev = UsbDeviceBuilder::new(&usb_alloc, UsbVidPid(0x1209, 0x0001)).build();
+
+        let keyboard = hid.device::<BootKeyboard<_>, _>();
+        assert_eq!(u8::from(keyboard.interface().id()), 0);
+        keyboard.write_report([Keyboard::A]).unwrap();
+        assert_eq!(manager.host_read_in(), [0, 0, 0x04, 0, 0, 0, 0, 0]);
+
+        let mut scroll = MiddleButtonScroll::new(2);
+        let stick = hid.device::<WheelMouse<_>, _>();
+        assert_eq!(u8::from(stick.interface().id()), 1);
+        stick
+            .write_report(&scroll.update(MIDDLE_BUTTON, 0, -4))
+            .unwrap();
+        assert_eq!(manager.host_read_in(), [0, 0, 0, 2, 0]);
+    }
+
+    #[test]
+    fn precision_touchpad_feature_reports() {
+        init_logging();
+
+        let manager = UsbTestManager::default();
+        let usb_alloc = UsbBusAllocator::new(TestUsbBus::new(&manager));
+
+        let mut hid = UsbHidClassBuilder::new()
+            .add_device(TouchpadConfig::default())
+            .build(&usb_alloc);
+
+        let mut usb_dev = UsbDeviceBuilder::new(&usb_alloc, UsbVidPid(0x1209, 0x0001)).build();
+
+        let get_feature = |report_id: u8, length: u16| {
+            UsbRequest {
+                direction: true,
+                request_type: RequestType::Class as u8,
+                recipient: Recipient::Interface as u8,
+                request: HidRequest::GetReport.into(),
+                value: u16::from(REPORT_TYPE_FEATURE) << 8 | u16::from(report_id),
+                index: 0x0,
+                length,
+            }
+            .pack()
+            .unwrap()
+        };
+
+        manager
+            .host_write_setup(&get_feature(CAPABILITIES_REPORT_ID, 2))
+            .unwrap();
+        assert!(usb_dev.poll(&mut [&mut hid]));
+        assert_eq!(manager.host_read_in(), [CAPABILITIES_REPORT_ID, 0x05]);
+
+        // Longer than the control pipe buffer, sent from the static blob
+        manager
+            .host_write_setup(&get_feature(CERTIFICATION_REPORT_ID, 257))
+            .unwrap();
+        assert!(usb_dev.poll(&mut [&mut hid]));
+        let mut blob = Vec::new();
+        loop {
+            let read = manager.host_read_in();
+            if read.is_empty() {
+                break;
+            }
+            blob.extend_from_slice(&read);
+            assert!(usb_dev.poll(&mut [&mut hid]));
+        }
+        assert_eq!(blob, EMPTY_CERTIFICATION_REPORT);
+
+        // Mouse reports until the host selects the touchpad input mode
+        let touchpad = hid.device::<Touchpad<_>, _>();
+        assert_eq!(touchpad.input_mode(), InputMode::Mouse);
+        let mut report = TouchpadReport::new(touchpad.scan_time());
+        report.push(TouchpadContact::new(0, 100, 100)).unwrap();
+        touchpad.write_report(&report).unwrap();
+        assert_eq!(manager.host_read_in(), [0x06, 0, 0, 0]);
+        report.contacts[0] = TouchpadContact::new(0, 142, 60);
+        report.button = true;
+        touchpad.write_report(&report).unwrap();
+        assert_eq!(manager.host_read_in(), [0x06, 1, 10, 0xF6]);
+
+        assert!(touchpad
+            .interface()
+            .set_feature_report(INPUT_MODE_REPORT_ID, &[INPUT_MODE_REPORT_ID, 0x03]));
+        assert_eq!(touchpad.input_mode(), InputMode::Touchpad);
+        touchpad.write_report(&report).unwrap();
+        let data = manager.host_read_in();
+        assert_eq!(data[..2], [TOUCHPAD_REPORT_ID, 0b11]);
+        assert_eq!(data[33..], [1, 1]);
+
+        // Host turns contact reporting off
+        assert!(touchpad.interface().set_feature_report(
+            FUNCTION_SWITCH_REPORT_ID,
+            &[FUNCTION_SWITCH_REPORT_ID, 0b10]
+        ));
+        assert!(!touchpad.surface_switch());
+        touchpad.write_report(&report).unwrap();
+        assert_eq!(manager.host_read_in()[31..], [0, 0, 0, 1]);
+
+        // Bus reset returns to mouse mode
+        InterfaceClass::reset(touchpad.interface());
+        assert_eq!(touchpad.input_mode(), InputMode::Mouse);
+        assert!(touchpad.surface_switch());
+    }
+
+    #[test]
+    fn touchpad_scan_time_under_tick_until() {
+        init_logging();
+
+        let manager = UsbTestManager::default();
+        let usb_alloc = UsbBusAllocator::new(TestUsbBus::new(&manager));
+
+        let mut hid = UsbHidClassBuilder::new()
+            .add_device(TouchpadConfig::default())
+            .build(&usb_alloc);
+
+        assert_eq!(hid.device::<Touchpad<_>, _>().scan_time(), 0);
+        hid.tick().unwrap();
+        assert_eq!(hid.device::<Touchpad<_>, _>().scan_time(), 10);
+
+        let touchpad = hid.device::<Touchpad<_>, _>();
+        assert!(touchpad
+            .interface()
+            .set_feature_report(INPUT_MODE_REPORT_ID, &[INPUT_MODE_REPORT_ID, 0x03]));
+        assert_eq!(hid.tick_until(Instant::from_ticks(0)).unwrap(), None);
+        assert_eq!(hid.tick_until(Instant::from_ticks(249)).unwrap(), None);
+        assert_eq!(hid.device::<Touchpad<_>, _>().scan_time(), 2500);
+
+        // Wraps at 6.5536s
+        hid.tick_until(Instant::from_ticks(6553)).unwrap();
+        assert_eq!(hid.device::<Touchpad<_>, _>().scan_time(), 4);
+    }
+
+    #[test]
+    fn autofire_settings_from_host_under_tick_until() {
+        init_logging();
+
+        let manager = UsbTestManager::default();
+        let usb_alloc = UsbBusAllocator::new(TestUsbBus::new(&manager));
+
+        let mut hid = UsbHidClassBuilder::new()
+            .add_device(AutofireGamepadConfig::default())
+            .build(&usb_alloc);
+
+        let _usb_dev = UsbDeviceBuilder::new(&usb_alloc, UsbVidPid(0x1209, 0x0001)).build();
+
+        let gamepad = hid.device::<AutofireGamepad<_>, _>();
+        gamepad
+            .write_report(&GamepadReport::new().buttons(0b01))
+            .unwrap();
+        assert_eq!(manager.host_read_in()[..2], [0b01, 0]);
+        assert_eq!(hid.tick_until(Instant::from_ticks(0)).unwrap(), None);
+
+        // The host enables autofire for button 1, 25 presses per second
+        let settings = AutofireSettings::new(0b01, 25, 50);
+        let gamepad = hid.device::<AutofireGamepad<_>, _>();
+        assert!(gamepad
+            .interface()
+            .set_feature_report(0, &settings.to_bytes()));
+        assert_eq!(
+            hid.tick_until(Instant::from_ticks(0)).unwrap(),
+            Some(Instant::from_ticks(1))
+        );
+        hid.tick_until(Instant::from_ticks(1)).unwrap();
+        assert_eq!(hid.device::<AutofireGamepad<_>, _>().settings(), settings);
+        assert_eq!(manager.host_read_in()[..2], [0b01, 0]);
+
+        hid.tick_until(Instant::from_ticks(20)).unwrap();
+        assert_eq!(manager.host_read_in()[..2], [0, 0]);
+    }
+
+    #[test]
+    fn write_report_latency_contract() {
+        init_logging();
+
+        let manager = UsbTestManager::default();
+        let usb_alloc = UsbBusAllocator::new(TestUsbBus::new(&manager));
+
+        let mut hid = UsbHidClassBuilder::new()
+            .add_device(WheelMouseConfig::default())
+            .build(&usb_alloc);
+
+        let mut usb_dev = UsbDeviceBuilder::new(&usb_alloc, UsbVidPid(0x1209, 0x0001)).build();
+
+        let mouse = hid.device::<WheelMouse<_>, _>();
+        assert_eq!(
+            mouse.interface().max_report_latency(),
+            MillisDurationU32::millis(10)
+        );
+        assert!(mouse.interface().max_report_latency() <= MAX_WRITE_REPORT_LATENCY);
+        let slowest = InterfaceBuilder::<InBytes8, OutNone, ReportSingle>::new(&[])
+            .unwrap()
+            .in_endpoint(MAX_WRITE_REPORT_LATENCY)
+            .unwrap();
+        assert!(slowest
+            .in_endpoint(MAX_WRITE_REPORT_LATENCY + MillisDurationU32::millis(1))
+            .is_err());
+
+        // Armed within the call with a single endpoint write, without a tick or poll
+        let report = |buttons| WheelMouseReport::new().buttons(buttons);
+        mouse.write_report(&report(1)).unwrap();
+        assert_eq!(manager.in_writes(), 1);
+        assert_eq!(manager.host_read_in(), [1, 0, 0, 0, 0]);
+
+        // Rejected while the endpoint is busy after a single attempt, rather than retried
+        mouse.write_report(&report(2)).unwrap();
+        assert!(matches!(
+            mouse.write_report(&report(3)),
+            Err(UsbHidError::WouldBlock)
+        ));
+        assert_eq!(manager.in_writes(), 3);
+        assert_eq!(manager.host_read_in(), [2, 0, 0, 0, 0]);
+
+        // Neither queued nor sent again
+        for _ in 0..100 {
+            hid.tick().unwrap();
+            usb_dev.poll(&mut [&mut hid]);
+        }
+        assert_eq!(manager.in_writes(), 3);
+        assert!(manager.host_read_in().is_empty());
+    }
+
+    #[test]
+    fn keyboard_min_report_spacing() {
+        init_logging();
+
+        let manager = UsbTestManager::default();
+        let usb_alloc = UsbBusAllocator::new(TestUsbBus::new(&manager));
+
+        let mut hid = UsbHidClassBuilder::new()
+            .add_device(
+                BootKeyboardConfig::default().min_report_spacing(MillisDurationU32::millis(2)),
+            )
+            .build(&usb_alloc);
+
+        let _usb_dev = UsbDeviceBuilder::new(&usb_alloc, UsbVidPid(0x1209, 0x0001)).build();
+
+        let keyboard = hid.device::<BootKeyboard<_>, _>();
+        keyboard.write_report([Keyboard::A]).unwrap();
+        manager.host_read_in();
+
+        assert!(matches!(
+            keyboard.write_report([Keyboard::B]),
+            Err(UsbHidError::WouldBlock)
+        ));
+        hid.tick().unwrap();
+        let keyboard = hid.device::<BootKeyboard<_>, _>();
+        assert!(matches!(
+            keyboard.write_report([Keyboard::B]),
+            Err(UsbHidError::WouldBlock)
+        ));
+        hid.tick().unwrap();
+        let keyboard = hid.device::<BootKeyboard<_>, _>();
+        keyboard.write_report([Keyboard::B]).unwrap();
+        assert_eq!(manager.host_read_in()[2], u8::from(Keyboard::B));
+    }
+
+    #[test]
+    fn keyboard_remap_feature_report() {
+        init_logging();
+
+        let manager = UsbTestManager::default();
+        let usb_alloc = UsbBusAllocator::new(TestUsbBus::new(&manager));
+
+        let mut hid = UsbHidClassBuilder::new()
+            .add_device(BootKeyboardConfig::with_remap_table())
+            .build(&usb_alloc);
+
+        let mut usb_dev = UsbDeviceBuilder::new(&usb_alloc, UsbVidPid(0x1209, 0x0001)).build();
+        let mut table = RemapTable::<8>::new();
+
+        // The host swaps Caps Lock for Escape
+        let keyboard = hid.device::<BootKeyboard<_>, _>();
+        assert!(!table.service(keyboard.interface()));
+        assert!(keyboard
+            .interface()
+            .set_feature_report(0, &[REMAP_SET, 0x39, 0x29]));
+        assert!(table.service(keyboard.interface()));
+        assert!(!table.service(keyboard.interface()));
+
+        let get_response = UsbRequest {
+            direction: true,
+            request_type: RequestType::Class as u8,
+            recipient: Recipient::Interface as u8,
+            request: HidRequest::GetReport.into(),
+            value: u16::from(REPORT_TYPE_FEATURE) << 8,
+            index: 0x0,
+            length: 0x8,
+        }
+        .pack()
+        .unwrap();
+        manager.host_write_setup(&get_response).unwrap();
+        assert!(usb_dev.poll(&mut [&mut hid]));
+        assert_eq!(manager.host_read_in(), [0x82, 0, 0x39, 0x29, 1, 8, 0, 0]);
+
+        let keyboard = hid.device::<BootKeyboard<_>, _>();
+        keyboard
+            .write_report(table.remap_keys([Keyboard::CapsLock]))
+            .unwrap();
+        assert_eq!(manager.host_read_in()[2], u8::from(Keyboard::Escape));
+    }
+
+    #[test]
+    fn tick_until_idle_deadline() {
+        init_logging();
+
+        let manager = UsbTestManager::default();
+        let usb_alloc = UsbBusAllocator::new(TestUsbBus::new(&manager));
+
+        let mut hid = UsbHidClassBuilder::new()
+            .add_device(
+                BootKeyboardConfig::default().min_report_spacing(MillisDurationU32::millis(2)),
+            )
+            .add_device(WheelMouseConfig::default())
+            .build(&usb_alloc);
+
+        let _usb_dev = UsbDeviceBuilder::new(&usb_alloc, UsbVidPid(0x1209, 0x0001)).build();
+
+        // Nothing scheduled, time is skipped
+        let mut now = Instant::from_ticks(1000);
+        assert_eq!(hid.tick_until(now).unwrap(), None);
+        now += MillisDurationU32::millis(10_000);
+        assert_eq!(hid.tick_until(now).unwrap(), None);
+
+        hid.device::<BootKeyboard<_>, _>()
+            .write_report([Keyboard::A])
+            .unwrap();
+        manager.host_read_in();
+
+        // Minimum report spacing, then the 500ms idle retransmission
+        assert_eq!(
+            hid.tick_until(now).unwrap(),
+            Some(now + MillisDurationU32::millis(2))
+        );
+        now += MillisDurationU32::millis(2);
+        assert_eq!(
+            hid.tick_until(now).unwrap(),
+            Some(now + MillisDurationU32::millis(499))
+        );
+        now += MillisDurationU32::millis(498);
+        hid.tick_until(now).unwrap();
+        assert!(manager.host_read_in().is_empty());
+        now += MillisDurationU32::millis(1);
+        assert_eq!(
+            hid.tick_until(now).unwrap(),
+            Some(now + MillisDurationU32::millis(501))
+        );
+        assert_eq!(manager.host_read_in()[2], u8::from(Keyboard::A));
+    }
+
+    /// Device with work due every 100ms, counting its ticks
+    struct Periodic<'a, B: UsbBus> {
+        interface: Interface<'a, B, InBytes8, OutNone, ReportSingle>,
+        since_work: MillisDurationU32,
+        ticks: u32,
+        work: u32,
+    }
+
+    impl<'a, B: UsbBus> DeviceClass<'a> for Periodic<'a, B> {
+        type I = Interface<'a, B, InBytes8, OutNone, ReportSingle>;
+
+        fn interface(&mut self) -> &mut Self::I {
+            &mut self.interface
+        }
+
+        fn reset(&mut self) {}
+
+        fn tick(&mut self) -> core::result::Result<(), UsbHidError> {
+            self.ticks += 1;
+            self.since_work += MillisDurationU32::millis(1);
+            if self.since_work >= MillisDurationU32::millis(100) {
+                self.since_work = MillisDurationU32::millis(0);
+                self.work += 1;
+            }
+            Ok(())
+        }
+
+        fn next_deadline(&mut self) -> Option<MillisDurationU32> {
+            Some(MillisDurationU32::millis(100) - self.since_work)
+        }
+
+        fn advance(&mut self, elapsed: MillisDurationU32) {
+            self.since_work += elapsed;
+        }
+    }
+
+    struct PeriodicConfig;
+
+    impl<'a, B: UsbBus + 'a> UsbAllocatable<'a, B> for PeriodicConfig {
+        type Allocated = Periodic<'a, B>;
+
+        fn allocate(self, usb_alloc: &'a UsbBusAllocator<B>) -> Self::Allocated {
+            Periodic {
+                interface: Interface::new(usb_alloc, InterfaceBuilder::new(&[]).unwrap().build()),
+                since_work: MillisDurationU32::millis(0),
+                ticks: 0,
+                work: 0,
+            }
+        }
+    }
+
+    #[test]
+    fn tick_until_only_ticks_deadlines() {
+        init_logging();
+
+        let manager = UsbTestManager::default();
+        let usb_alloc = UsbBusAllocator::new(TestUsbBus::new(&manager));
+
+        let mut hid = UsbHidClassBuilder::new()
+            .add_device(PeriodicConfig)
+            .build(&usb_alloc);
+
+        let mut now = Instant::from_ticks(0);
+        assert_eq!(
+            hid.tick_until(now).unwrap(),
+            Some(now + MillisDurationU32::millis(100))
+        );
+        now += MillisDurationU32::millis(10_050);
+        assert_eq!(
+            hid.tick_until(now).unwrap(),
+            Some(now + MillisDurationU32::millis(50))
+        );
+
+        let device = hid.device::<Periodic<_>, _>();
+        assert_eq!(device.work, 100);
+        assert_eq!(device.ticks, 100);
+        assert_eq!(device.interface.uptime(), MillisDurationU32::millis(10_050));
+    }
+
+    #[test]
+    fn keyboard_settle_time_after_configuration() {
+        init_logging();
+
+        let manager = UsbTestManager::default();
+        let usb_alloc = UsbBusAllocator::new(TestUsbBus::new(&manager));
+
+        let mut hid = UsbHidClassBuilder::new()
+            .add_device(BootKeyboardConfig::default().settle_time(MillisDurationU32::millis(2)))
+            .build(&usb_alloc);
+
+        let mut usb_dev = UsbDeviceBuilder::new(&usb_alloc, UsbVidPid(0x1209, 0x0001)).build();
+
+        let keyboard = hid.device::<BootKeyboard<_>, _>();
+        assert!(matches!(
+            keyboard.write_report([Keyboard::A]),
+            Err(UsbHidError::WouldBlock)
+        ));
+
+        manager
+            .host_write_setup(
+                &UsbRequest {
+                    direction: false,
+                    request_type: RequestType::Standard as u8,
+                    recipient: Recipient::Device as u8,
+                    request: Request::SET_CONFIGURATION,
+                    value: 1,
+                    index: 0,
+                    length: 0,
+                }
+                .pack()
+                .unwrap(),
+            )
+            .unwrap();
+        usb_dev.poll(&mut [&mut hid]);
+
+        for _ in 0..2 {
+            let keyboard = hid.device::<BootKeyboard<_>, _>();
+            assert!(matches!(
+                keyboard.write_report([Keyboard::A]),
+                Err(UsbHidError::WouldBlock)
+            ));
+            hid.tick().unwrap();
+        }
+
+        let keyboard = hid.device::<BootKeyboard<_>, _>();
+        keyboard.write_report([Keyboard::A]).unwrap();
+        assert_eq!(manager.host_read_in()[2], u8::from(Keyboard::A));
+    }
+
+    #[test]
+    fn keyboard_settle_time_restarts_after_deconfiguration() {
+        init_logging();
+
+        let manager = UsbTestManager::default();
+        let usb_alloc = UsbBusAllocator::new(TestUsbBus::new(&manager));
+
+        let mut hid = UsbHidClassBuilder::new()
+            .add_device(BootKeyboardConfig::default().settle_time(MillisDurationU32::millis(2)))
+            .build(&usb_alloc);
+
+        let mut usb_dev = UsbDeviceBuilder::new(&usb_alloc, UsbVidPid(0x1209, 0x0001)).build();
+
+        let set_configuration = |value| {
+            UsbRequest {
+                direction: false,
+                request_type: RequestType::Standard as u8,
+                recipient: Recipient::Device as u8,
+                request: Request::SET_CONFIGURATION,
+                value,
+                index: 0,
+                length: 0,
+            }
+            .pack()
+            .unwrap()
+        };
+
+        manager.host_write_setup(&set_configuration(1)).unwrap();
+        usb_dev.poll(&mut [&mut hid]);
+        hid.tick().unwrap();
+        hid.tick().unwrap();
+        let keyboard = hid.device::<BootKeyboard<_>, _>();
+        keyboard.write_report([Keyboard::A]).unwrap();
+        manager.host_read_in();
+
+        manager.host_write_setup(&set_configuration(0)).unwrap();
+        usb_dev.poll(&mut [&mut hid]);
+        hid.tick().unwrap();
+        hid.tick().unwrap();
+        let keyboard = hid.device::<BootKeyboard<_>, _>();
+        assert!(matches!(
+            keyboard.write_report([Keyboard::B]),
+            Err(UsbHidError::WouldBlock)
+        ));
+
+        manager.host_write_setup(&set_configuration(1)).unwrap();
+        usb_dev.poll(&mut [&mut hid]);
+        for _ in 0..2 {
+            let keyboard = hid.device::<BootKeyboard<_>, _>();
+            assert!(matches!(
+                keyboard.write_report([Keyboard::B]),
+                Err(UsbHidError::WouldBlock)
+            ));
+            hid.tick().unwrap();
+        }
+
+        let keyboard = hid.device::<BootKeyboard<_>, _>();
+        keyboard.write_report([Keyboard::B]).unwrap();
+        assert_eq!(manager.host_read_in()[2], u8::from(Keyboard::B));
+    }
+
+    #[test]
+    fn endpoint_halt_clear_allows_resending_report() {
+        init_logging();
+
+        let manager = UsbTestManager::default();
+        let usb_alloc = UsbBusAllocator::new(TestUsbBus::new(&manager));
+
+        let mut hid = UsbHidClassBuilder::new()
+            .add_device(BootKeyboardConfig::default())
+            .build(&usb_alloc);
+
+        let mut usb_dev = UsbDeviceBuilder::new(&usb_alloc, UsbVidPid(0x1209, 0x0001)).build();
+
+        let keyboard = hid.device::<BootKeyboard<_>, _>();
+        keyboard.write_report([Keyboard::A]).unwrap();
+        manager.host_read_in();
+        assert!(matches!(
+            keyboard.write_report([Keyboard::A]),
+            Err(UsbHidError::Duplicate)
+        ));
+
+        // The keyboard's IN endpoint is the first endpoint allocated
+        let in_endpoint = EndpointAddress::from_parts(0, UsbDirection::In);
+        manager
+            .host_write_setup(
+                &UsbRequest {
+                    direction: false,
+                    request_type: RequestType::Standard as u8,
+                    recipient: Recipient::Endpoint as u8,
+                    request: Request::CLEAR_FEATURE,
+                    value: Request::FEATURE_ENDPOINT_HALT,
+                    index: u8::from(in_endpoint).into(),
+                    length: 0,
+                }
+                .pack()
+                .unwrap(),
+            )
+            .unwrap();
+        assert!(usb_dev.poll(&mut [&mut hid]));
+
+        let keyboard = hid.device::<BootKeyboard<_>, _>();
+        keyboard.write_report([Keyboard::A]).unwrap();
+        assert_eq!(manager.host_read_in()[2], u8::from(Keyboard::A));
+    }
+
+    #[test]
+    fn interface_health_records_last_error() {
+        init_logging();
+
+        let manager = UsbTestManager::default();
+        let usb_alloc = UsbBusAllocator::new(TestUsbBus::new(&manager));
+
+        let mut hid = UsbHidClassBuilder::new()
+            .add_device(
+                InterfaceBuilder::<InBytes8, OutBytes8, ReportSingle>::new(&[])
+                    .unwrap()
+                    .build(),
+            )
+            .build(&usb_alloc);
+
+        assert_eq!(hid.health(0), Some(InterfaceHealth::default()));
+        assert_eq!(hid.health(1), None);
+
+        hid.tick().unwrap();
+        hid.tick().unwrap();
+        let interface = hid.device::<Interface<_, InBytes8, OutBytes8, ReportSingle>, _>();
+        assert!(interface.set_report(&[0; 9]).is_err());
+        interface.set_report(&[1]).unwrap();
+        // Would block isn't an error
+        assert!(interface.set_report(&[2]).is_err());
+
+        let health = hid.health(0).unwrap();
+        assert_eq!(health.error_count, 1);
+        assert_eq!(
+            health.last_error,
+            Some(InterfaceError {
+                operation: InterfaceOperation::SetReport,
+                code: crate::usb_error_code(&UsbError::BufferOverflow),
+                at: MillisDurationU32::millis(2),
+            })
+        );
+    }
+
+    #[test]
+    fn out_report_mode_drop_keeps_unread_report() {
+        init_logging();
+
+        let manager = UsbTestManager::default();
+        let usb_alloc = UsbBusAllocator::new(TestUsbBus::new(&manager));
+
+        let mut hid = UsbHidClassBuilder::new()
+            .add_device(
+                InterfaceBuilder::<InBytes8, OutBytes8, ReportSingle>::new(&[])
+                    .unwrap()
+                    .build(),
+            )
+            .build(&usb_alloc);
+
+        let interface = hid.device::<Interface<_, InBytes8, OutBytes8, ReportSingle>, _>();
+        interface.set_report(&[1]).unwrap();
+        assert!(matches!(
+            interface.set_report(&[2]),
+            Err(UsbError::WouldBlock)
+        ));
+
+        let mut data = [0; 8];
+        assert_eq!(interface.read_report(&mut data).unwrap(), 1);
+        assert_eq!(data[0], 1);
+        assert!(matches!(
+            interface.read_report(&mut data),
+            Err(UsbError::WouldBlock)
+        ));
+    }
+
+    #[test]
+    fn out_report_mode_latch_keeps_latest_report() {
+        init_logging();
+
+        let manager = UsbTestManager::default();
+        let usb_alloc = UsbBusAllocator::new(TestUsbBus::new(&manager));
+
+        let mut hid = UsbHidClassBuilder::new()
+            .add_device(
+                InterfaceBuilder::<InBytes8, OutBytes8, ReportSingle>::new(&[])
+                    .unwrap()
+                    .out_report_mode(OutReportMode::Latch)
+                    .build(),
+            )
+            .build(&usb_alloc);
+
+        let interface = hid.device::<Interface<_, InBytes8, OutBytes8, ReportSingle>, _>();
+        interface.set_report(&[1]).unwrap();
+        interface.set_report(&[2]).unwrap();
+
+        let mut data = [0; 8];
+        assert_eq!(interface.read_report(&mut data).unwrap(), 1);
+        assert_eq!(data[0], 2);
+        assert!(matches!(
+            interface.read_report(&mut data),
+            Err(UsbError::WouldBlock)
+        ));
+    }
+
+    #[test]
+    fn out_report_mode_queue_keeps_reports_in_order() {
+        init_logging();
+
+        let manager = UsbTestManager::default();
+        let usb_alloc = UsbBusAllocator::new(TestUsbBus::new(&manager));
+
+        let mut hid = UsbHidClassBuilder::new()
+            .add_device(
+                InterfaceBuilder::<InBytes8, OutQueue<OutBytes8, 2>, ReportSingle>::new(&[])
+                    .unwrap()
+                    .out_report_mode(OutReportMode::Queue)
+                    .build(),
+            )
+            .build(&usb_alloc);
+
+        let interface =
+            hid.device::<Interface<_, InBytes8, OutQueue<OutBytes8, 2>, ReportSingle>, _>();
+        interface.set_report(&[1]).unwrap();
+        interface.set_report(&[2, 2]).unwrap();
+        assert!(matches!(
+            interface.set_report(&[3]),
+            Err(UsbError::WouldBlock)
+        ));
+
+        let mut data = [0; 8];
+        assert_eq!(interface.read_report(&mut data).unwrap(), 1);
+        assert_eq!(data[0], 1);
+        assert_eq!(interface.read_report(&mut data).unwrap(), 2);
+        assert_eq!(data[..2], [2, 2]);
+        assert!(matches!(
+            interface.read_report(&mut data),
+            Err(UsbError::WouldBlock)
+        ));
+    }
+
+    #[test]
+    fn in_endpoint_watchdog_flags_stalled_endpoint() {
+        init_logging();
+
+        let manager = UsbTestManager::default();
+        let usb_alloc = UsbBusAllocator::new(TestUsbBus::new(&manager));
+
+        let mut hid = UsbHidClassBuilder::new()
+            .add_device(BootKeyboardConfig::default())
+            .in_endpoint_watchdog(MillisDurationU32::millis(3))
+            .build(&usb_alloc);
+
+        let mut usb_dev = UsbDeviceBuilder::new(&usb_alloc, UsbVidPid(0x1209, 0x0001)).build();
+
+        // Not watched before the device is configured
+        let keyboard = hid.device::<BootKeyboard<_>, _>();
+        keyboard.write_report([Keyboard::A]).unwrap();
+        for _ in 0..5 {
+            hid.tick().unwrap();
+        }
+        assert!(!hid.in_endpoint_stalled());
+
+        manager
+            .host_write_setup(
+                &UsbRequest {
+                    direction: false,
+                    request_type: RequestType::Standard as u8,
+                    recipient: Recipient::Device as u8,
+                    request: Request::SET_CONFIGURATION,
+                    value: 1,
+                    index: 0,
+                    length: 0,
+                }
+                .pack()
+                .unwrap(),
+            )
+            .unwrap();
+        usb_dev.poll(&mut [&mut hid]);
+
+        // Nor while suspended
+        hid.set_suspended(true);
+        for _ in 0..5 {
+            hid.tick().unwrap();
+        }
+        assert!(!hid.in_endpoint_stalled());
+        hid.set_suspended(false);
+
+        // Reports read by the host in time
+        let in_endpoint = EndpointAddress::from_parts(0, UsbDirection::In);
+        for _ in 0..2 {
+            hid.tick().unwrap();
+        }
+        manager.host_read_in();
+        hid.endpoint_in_complete(in_endpoint);
+        for _ in 0..5 {
+            hid.tick().unwrap();
+        }
+        assert!(!hid.in_endpoint_stalled());
+
+        let keyboard = hid.device::<BootKeyboard<_>, _>();
+        keyboard.write_report([Keyboard::B]).unwrap();
+        hid.tick().unwrap();
+        hid.tick().unwrap();
+        assert!(!hid.in_endpoint_stalled());
+        hid.tick().unwrap();
+        assert!(hid.in_endpoint_stalled());
+
+        UsbClass::reset(&mut hid);
+        assert!(!hid.in_endpoint_stalled());
+    }
+
+    #[test]
+    fn temperature_sensor_report_interval_and_sensitivity() {
+        init_logging();
+
+        let manager = UsbTestManager::default();
+        let usb_alloc = UsbBusAllocator::new(TestUsbBus::new(&manager));
+
+        let mut hid = UsbHidClassBuilder::new()
+            .add_device(TemperatureSensorConfig::default().settings(SensorSettings {
+                report_interval: 5,
+                change_sensitivity: 10,
+                ..Default::default()
+            }))
+            .build(&usb_alloc);
+
+        let _usb_dev = UsbDeviceBuilder::new(&usb_alloc, UsbVidPid(0x1209, 0x0001)).build();
+
+        let sensor = hid.device::<TemperatureSensor<_>, _>();
+        sensor.set_temperature(2150);
+        hid.tick().unwrap();
+        // Ready state changed, with the first reading
+        assert_eq!(manager.host_read_in(), [1, 1, 0x66, 0x08]);
+
+        // Within the change sensitivity
+        hid.device::<TemperatureSensor<_>, _>()
+            .set_temperature(2155);
+        for _ in 0..10 {
+            hid.tick().unwrap();
+        }
+        assert!(manager.host_read_in().is_empty());
+
+        // Changed enough, then not again before the report interval
+        hid.device::<TemperatureSensor<_>, _>()
+            .set_temperature(2170);
+        hid.tick().unwrap();
+        let report = manager.host_read_in();
+        assert_eq!(report.len(), TEMPERATURE_REPORT_LEN);
+        assert_eq!(report, [1, 3, 0x7A, 0x08]);
+        hid.device::<TemperatureSensor<_>, _>()
+            .set_temperature(2000);
+        for _ in 0..4 {
+            hid.tick().unwrap();
+            assert!(manager.host_read_in().is_empty());
+        }
+        hid.tick().unwrap();
+        assert_eq!(manager.host_read_in(), [1, 3, 0xD0, 0x07]);
+    }
+
+    #[test]
+    fn temperature_sensor_tick_until() {
+        init_logging();
+
+        let manager = UsbTestManager::default();
+        let usb_alloc = UsbBusAllocator::new(TestUsbBus::new(&manager));
+
+        let mut hid = UsbHidClassBuilder::new()
+            .add_device(TemperatureSensorConfig::default().settings(SensorSettings {
+                reporting_state: ReportingState::NoEvents,
+                report_interval: 50,
+                change_sensitivity: 10,
+                ..Default::default()
+            }))
+            .build(&usb_alloc);
+
+        let _usb_dev = UsbDeviceBuilder::new(&usb_alloc, UsbVidPid(0x1209, 0x0001)).build();
+
+        hid.device::<TemperatureSensor<_>, _>()
+            .set_temperature(2150);
+        assert_eq!(hid.tick_until(Instant::from_ticks(0)).unwrap(), None);
+
+        // The host turns reporting on
+        let sensor = hid.device::<TemperatureSensor<_>, _>();
+        let settings = SensorSettings {
+            reporting_state: ReportingState::AllEvents,
+            ..sensor.settings()
+        };
+        let properties = settings.to_bytes(ConnectionType::External, SensorState::Ready);
+        assert!(sensor.interface().set_feature_report(0, &properties));
+        assert_eq!(
+            hid.tick_until(Instant::from_ticks(0)).unwrap(),
+            Some(Instant::from_ticks(1))
+        );
+        hid.tick_until(Instant::from_ticks(1)).unwrap();
+        assert_eq!(manager.host_read_in(), [1, 2, 0x66, 0x08]);
+
+        // Nothing scheduled for changes within the change sensitivity
+        hid.device::<TemperatureSensor<_>, _>()
+            .set_temperature(2151);
+        assert_eq!(hid.tick_until(Instant::from_ticks(1)).unwrap(), None);
+
+        // A change waits for the rest of the report interval, counting time skipped while idle
+        hid.tick_until(Instant::from_ticks(31)).unwrap();
+        hid.device::<TemperatureSensor<_>, _>()
+            .set_temperature(2200);
+        assert_eq!(
+            hid.tick_until(Instant::from_ticks(31)).unwrap(),
+            Some(Instant::from_ticks(51))
+        );
+        hid.tick_until(Instant::from_ticks(50)).unwrap();
+        assert!(manager.host_read_in().is_empty());
+        hid.tick_until(Instant::from_ticks(51)).unwrap();
+        assert_eq!(manager.host_read_in(), [1, 3, 0x98, 0x08]);
+    }
+
+    #[test]
+    fn ups_sends_changed_measurements() {
+        init_logging();
+
+        let manager = UsbTestManager::default();
+        let usb_alloc = UsbBusAllocator::new(TestUsbBus::new(&manager));
+
+        let mut hid = UsbHidClassBuilder::new()
+            .add_device(UpsConfig::default())
+            .build(&usb_alloc);
+
+        let _usb_dev = UsbDeviceBuilder::new(&usb_alloc, UsbVidPid(0x1209, 0x0001)).build();
+
+        let status = UpsStatus {
+            present: PresentStatus {
+                discharging: true,
+                battery_present: true,
+                ..Default::default()
+            },
+            remaining_capacity: 8,
+            run_time_to_empty: 300,
+        };
+        hid.device::<Ups<_>, _>().update(status);
+        hid.tick().unwrap();
+        // Below the default remaining capacity limit of 10%
+        assert_eq!(manager.host_read_in(), [1, 0b0001_1010]);
+        hid.tick().unwrap();
+        assert_eq!(manager.host_read_in(), [2, 8, 0x2C, 0x01]);
+
+        // Only changed reports are sent
+        hid.device::<Ups<_>, _>().update(UpsStatus {
+            run_time_to_empty: 240,
+            ..status
+        });
+        hid.tick().unwrap();
+        assert_eq!(manager.host_read_in(), [2, 8, 0xF0, 0x00]);
+        hid.tick().unwrap();
+        assert!(manager.host_read_in().is_empty());
+    }
 }